@@ -32,6 +32,7 @@ use databend_common_metrics::system::set_system_version;
 use databend_common_storage::DataOperator;
 use databend_common_tracing::set_panic_hook;
 use databend_enterprise_background_service::get_background_service_handler;
+use databend_query::audit::verify_audit_log_files;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::local;
 use databend_query::servers::admin::AdminService;
@@ -64,6 +65,11 @@ pub async fn run_cmd(conf: &InnerConfig) -> Result<bool, MainError> {
         }) => local::query_local(query, output_format)
             .await
             .with_context(make_error)?,
+        Some(Commands::VerifyAuditLog { files }) => {
+            let events = verify_audit_log_files(files, conf.log.audit.hmac_key.as_bytes())
+                .with_context(make_error)?;
+            println!("verified {} audit events", events);
+        }
     }
 
     Ok(true)
@@ -272,6 +278,9 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
     if conf.log.profile.on {
         println!("    profile: {}", conf.log.profile);
     }
+    if conf.log.audit.on {
+        println!("    audit: {}", conf.log.audit);
+    }
     if conf.log.structlog.on {
         println!("    structlog: {}", conf.log.structlog);
     }
@@ -21,3 +21,4 @@ pub use profile::ProfileLabel;
 pub use profiles::get_statistics_desc;
 pub use profiles::ProfileDesc;
 pub use profiles::ProfileStatisticsName;
+pub use profiles::StatisticsUnit;
@@ -58,6 +58,12 @@ pub enum ProfileStatisticsName {
     MemoryUsage,
     ExternalServerRetryCount,
     ExternalServerRequestCount,
+
+    HashJoinBuildRows,
+    HashJoinBuildBytes,
+
+    RuntimeFilterBloomCheckedRows,
+    RuntimeFilterBloomFilteredRows,
 }
 
 #[derive(Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize, Debug)]
@@ -312,6 +318,34 @@ pub fn get_statistics_desc() -> Arc<BTreeMap<ProfileStatisticsName, ProfileDesc>
                 unit: StatisticsUnit::Count,
                 plain_statistics: true,
             }),
+            (ProfileStatisticsName::HashJoinBuildRows, ProfileDesc {
+                display_name: "hash table rows",
+                desc: "The number of rows of the build side inserted into the hash table of hash join",
+                index: ProfileStatisticsName::HashJoinBuildRows as usize,
+                unit: StatisticsUnit::Rows,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::HashJoinBuildBytes, ProfileDesc {
+                display_name: "hash table bytes",
+                desc: "The bytes of the build side data and the hash table entries of hash join",
+                index: ProfileStatisticsName::HashJoinBuildBytes as usize,
+                unit: StatisticsUnit::Bytes,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::RuntimeFilterBloomCheckedRows, ProfileDesc {
+                display_name: "rows checked by bloom runtime filter",
+                desc: "The number of rows scanned and checked by the bloom runtime filter of hash join",
+                index: ProfileStatisticsName::RuntimeFilterBloomCheckedRows as usize,
+                unit: StatisticsUnit::Rows,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::RuntimeFilterBloomFilteredRows, ProfileDesc {
+                display_name: "rows filtered by bloom runtime filter",
+                desc: "The number of rows filtered out by the bloom runtime filter of hash join",
+                index: ProfileStatisticsName::RuntimeFilterBloomFilteredRows as usize,
+                unit: StatisticsUnit::Rows,
+                plain_statistics: true,
+            }),
         ]))
     }).clone()
 }
@@ -20,6 +20,8 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
 use crate::notification_client::NotificationClient;
+use crate::pb::notification_service_server::NotificationService;
+use crate::pb::task_service_server::TaskService;
 use crate::task_client::TaskClient;
 
 pub const CLOUD_REQUEST_TIMEOUT_SEC: u64 = 5; // 5 seconds
@@ -31,12 +33,16 @@ pub struct CloudControlApiProvider {
 }
 
 impl CloudControlApiProvider {
-    pub async fn new(endpoint: String, timeout: u64) -> Result<Arc<CloudControlApiProvider>> {
-        let timeout = if timeout == 0 {
+    fn request_timeout(timeout: u64) -> Duration {
+        if timeout == 0 {
             Duration::from_secs(CLOUD_REQUEST_TIMEOUT_SEC)
         } else {
             Duration::from_secs(timeout)
-        };
+        }
+    }
+
+    pub async fn new(endpoint: String, timeout: u64) -> Result<Arc<CloudControlApiProvider>> {
+        let timeout = Self::request_timeout(timeout);
 
         let endpoint = Self::get_endpoint(endpoint, timeout).await?;
         let channel = endpoint.connect_lazy();
@@ -49,6 +55,19 @@ impl CloudControlApiProvider {
         }))
     }
 
+    /// Create an api provider whose requests are served by services in this process.
+    pub fn new_builtin(
+        task_service: Arc<dyn TaskService>,
+        notification_service: Arc<dyn NotificationService>,
+        timeout: u64,
+    ) -> Arc<CloudControlApiProvider> {
+        Arc::new(CloudControlApiProvider {
+            task_client: TaskClient::new_builtin(task_service),
+            notification_client: NotificationClient::new_builtin(notification_service),
+            timeout: Self::request_timeout(timeout),
+        })
+    }
+
     async fn get_endpoint(
         endpoint: String,
         timeout: Duration,
@@ -73,6 +92,18 @@ impl CloudControlApiProvider {
         Ok(())
     }
 
+    pub fn init_builtin(
+        task_service: Arc<dyn TaskService>,
+        notification_service: Arc<dyn NotificationService>,
+        timeout: u64,
+    ) {
+        GlobalInstance::set(Self::new_builtin(
+            task_service,
+            notification_service,
+            timeout,
+        ));
+    }
+
     pub fn instance() -> Arc<CloudControlApiProvider> {
         GlobalInstance::get()
     }
@@ -18,6 +18,7 @@ use tonic::transport::Channel;
 use tonic::Request;
 
 use crate::pb::notification_service_client::NotificationServiceClient;
+use crate::pb::notification_service_server::NotificationService;
 use crate::pb::CreateNotificationRequest;
 use crate::pb::CreateNotificationResponse;
 use crate::pb::DropNotificationRequest;
@@ -29,8 +30,28 @@ use crate::pb::ListNotificationResponse;
 
 pub(crate) const NOTIFICATION_CLIENT_VERSION: &str = "v1";
 pub(crate) const NOTIFICATION_CLIENT_VERSION_NAME: &str = "NOTIFICATION_CLIENT_VERSION";
+
+/// Where the requests of a [`NotificationClient`] are served.
+enum NotificationBackend {
+    /// The cloud control service at `cloud_control_grpc_server_address`.
+    Remote(NotificationServiceClient<Channel>),
+    /// A service that runs in the same process, delivering the webhooks by itself.
+    Builtin(Arc<dyn NotificationService>),
+}
+
+/// Send a request to the backend and unwrap the response.
+macro_rules! call_backend {
+    ($backend: expr, $method: ident, $req: expr) => {{
+        let resp = match $backend {
+            NotificationBackend::Remote(client) => client.clone().$method($req).await?,
+            NotificationBackend::Builtin(service) => service.$method($req).await?,
+        };
+        Ok(resp.into_inner())
+    }};
+}
+
 pub struct NotificationClient {
-    pub client: NotificationServiceClient<Channel>,
+    backend: NotificationBackend,
 }
 
 impl NotificationClient {
@@ -39,7 +60,16 @@ impl NotificationClient {
         channel: Channel,
     ) -> databend_common_exception::Result<Arc<NotificationClient>> {
         let client = NotificationServiceClient::new(channel);
-        Ok(Arc::new(NotificationClient { client }))
+        Ok(Arc::new(NotificationClient {
+            backend: NotificationBackend::Remote(client),
+        }))
+    }
+
+    /// Create a client that serves the requests with a service in the same process.
+    pub fn new_builtin(service: Arc<dyn NotificationService>) -> Arc<NotificationClient> {
+        Arc::new(NotificationClient {
+            backend: NotificationBackend::Builtin(service),
+        })
     }
 
     // TODO: richer error handling on Task Error
@@ -47,53 +77,41 @@ impl NotificationClient {
         &self,
         req: Request<CreateNotificationRequest>,
     ) -> databend_common_exception::Result<CreateNotificationResponse> {
-        let mut client = self.client.clone();
-        let resp = client.create_notification(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, create_notification, req)
     }
 
     pub async fn drop_notification(
         &self,
         req: Request<DropNotificationRequest>,
     ) -> databend_common_exception::Result<DropNotificationResponse> {
-        let mut client = self.client.clone();
-        let resp = client.drop_notification(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, drop_notification, req)
     }
 
     pub async fn desc_notification(
         &self,
         req: Request<GetNotificationRequest>,
     ) -> databend_common_exception::Result<GetNotificationResponse> {
-        let mut client = self.client.clone();
-        let resp = client.get_notification(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, get_notification, req)
     }
 
     pub async fn alter_notification(
         &self,
         req: Request<crate::pb::AlterNotificationRequest>,
     ) -> databend_common_exception::Result<crate::pb::AlterNotificationResponse> {
-        let mut client = self.client.clone();
-        let resp = client.alter_notification(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, alter_notification, req)
     }
 
     pub async fn list_notifications(
         &self,
         req: Request<ListNotificationRequest>,
     ) -> databend_common_exception::Result<ListNotificationResponse> {
-        let mut client = self.client.clone();
-        let resp = client.list_notification(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, list_notification, req)
     }
 
     pub async fn list_notification_histories(
         &self,
         req: Request<crate::pb::ListNotificationHistoryRequest>,
     ) -> databend_common_exception::Result<crate::pb::ListNotificationHistoryResponse> {
-        let mut client = self.client.clone();
-        let resp = client.list_notification_history(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, list_notification_history, req)
     }
 }
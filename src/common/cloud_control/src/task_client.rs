@@ -21,6 +21,7 @@ use tonic::Request;
 use crate::client_config::make_request;
 use crate::client_config::ClientConfig;
 use crate::pb::task_service_client::TaskServiceClient;
+use crate::pb::task_service_server::TaskService;
 use crate::pb::AlterTaskRequest;
 use crate::pb::AlterTaskResponse;
 use crate::pb::CreateTaskRequest;
@@ -37,22 +38,48 @@ use crate::pb::ShowTasksResponse;
 pub(crate) const TASK_CLIENT_VERSION: &str = "v1";
 pub(crate) const TASK_CLIENT_VERSION_NAME: &str = "TASK_CLIENT_VERSION";
 
+/// Where the requests of a [`TaskClient`] are served.
+enum TaskBackend {
+    /// The cloud control service at `cloud_control_grpc_server_address`.
+    Remote(TaskServiceClient<Channel>),
+    /// A service that runs in the same process, i.e., the built-in task scheduler.
+    Builtin(Arc<dyn TaskService>),
+}
+
+/// Send a request to the backend and unwrap the response.
+macro_rules! call_backend {
+    ($backend: expr, $method: ident, $req: expr) => {{
+        let resp = match $backend {
+            TaskBackend::Remote(client) => client.clone().$method($req).await?,
+            TaskBackend::Builtin(service) => service.$method($req).await?,
+        };
+        Ok(resp.into_inner())
+    }};
+}
+
 pub struct TaskClient {
-    pub task_client: TaskServiceClient<Channel>,
+    backend: TaskBackend,
 }
 
 impl TaskClient {
     // TODO: add auth interceptor
     pub async fn new(channel: Channel) -> Result<Arc<TaskClient>> {
         let task_client = TaskServiceClient::new(channel);
-        Ok(Arc::new(TaskClient { task_client }))
+        Ok(Arc::new(TaskClient {
+            backend: TaskBackend::Remote(task_client),
+        }))
+    }
+
+    /// Create a client that serves the requests with a service in the same process.
+    pub fn new_builtin(service: Arc<dyn TaskService>) -> Arc<TaskClient> {
+        Arc::new(TaskClient {
+            backend: TaskBackend::Builtin(service),
+        })
     }
 
     // TODO: richer error handling on Task Error
     pub async fn create_task(&self, req: Request<CreateTaskRequest>) -> Result<CreateTaskResponse> {
-        let mut client = self.task_client.clone();
-        let resp = client.create_task(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, create_task, req)
     }
 
     // TODO: richer error handling on Task Error
@@ -60,9 +87,7 @@ impl TaskClient {
         &self,
         req: Request<DescribeTaskRequest>,
     ) -> Result<DescribeTaskResponse> {
-        let mut client = self.task_client.clone();
-        let resp = client.describe_task(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, describe_task, req)
     }
 
     // TODO: richer error handling on Task Error
@@ -70,30 +95,22 @@ impl TaskClient {
         &self,
         req: Request<ExecuteTaskRequest>,
     ) -> Result<ExecuteTaskResponse> {
-        let mut client = self.task_client.clone();
-        let resp = client.execute_task(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, execute_task, req)
     }
 
     // TODO: richer error handling on Task Error
     pub async fn drop_task(&self, req: Request<DropTaskRequest>) -> Result<DropTaskResponse> {
-        let mut client = self.task_client.clone();
-        let resp = client.drop_task(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, drop_task, req)
     }
 
     // TODO: richer error handling on Task Error
     pub async fn alter_task(&self, req: Request<AlterTaskRequest>) -> Result<AlterTaskResponse> {
-        let mut client = self.task_client.clone();
-        let resp = client.alter_task(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, alter_task, req)
     }
 
     // TODO: richer error handling on Task Error
     pub async fn show_tasks(&self, req: Request<ShowTasksRequest>) -> Result<ShowTasksResponse> {
-        let mut client = self.task_client.clone();
-        let resp = client.show_tasks(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, show_tasks, req)
     }
 
     // TODO: richer error handling on Task Error
@@ -101,9 +118,7 @@ impl TaskClient {
         &self,
         req: Request<crate::pb::ShowTaskRunsRequest>,
     ) -> Result<crate::pb::ShowTaskRunsResponse> {
-        let mut client = self.task_client.clone();
-        let resp = client.show_task_runs(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, show_task_runs, req)
     }
 
     pub async fn show_task_runs_full(
@@ -111,19 +126,17 @@ impl TaskClient {
         config: ClientConfig,
         req: crate::pb::ShowTaskRunsRequest,
     ) -> Result<Vec<crate::pb::ShowTaskRunsResponse>> {
-        let mut client = self.task_client.clone();
         let request = make_request(req.clone(), config.clone());
-        let resp = client.show_task_runs(request).await?;
-        let mut has_next = resp.get_ref().next_page_token.is_some();
+        let resp = self.show_task_runs(request).await?;
+        let mut has_next = resp.next_page_token.is_some();
         // it is a pagination request, so we need to handle the response
-        let mut result = vec![resp.into_inner()];
+        let mut result = vec![resp];
         while has_next {
             let mut req = req.clone();
             req.next_page_token = result.last().unwrap().next_page_token;
-            let resp = client
+            let resp = self
                 .show_task_runs(make_request(req.clone(), config.clone()))
                 .await?;
-            let resp = resp.into_inner();
             has_next = resp.next_page_token.is_some();
             result.push(resp);
         }
@@ -134,17 +147,13 @@ impl TaskClient {
         &self,
         req: Request<crate::pb::GetTaskDependentsRequest>,
     ) -> Result<crate::pb::GetTaskDependentsResponse> {
-        let mut client = self.task_client.clone();
-        let resp = client.get_task_dependents(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, get_task_dependents, req)
     }
 
     pub async fn enable_task_dependents(
         &self,
         req: Request<crate::pb::EnableTaskDependentsRequest>,
     ) -> Result<crate::pb::EnableTaskDependentsResponse> {
-        let mut client = self.task_client.clone();
-        let resp = client.enable_task_dependents(req).await?;
-        Ok(resp.into_inner())
+        call_backend!(&self.backend, enable_task_dependents, req)
     }
 }
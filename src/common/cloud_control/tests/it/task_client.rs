@@ -82,9 +82,12 @@ impl TaskService for MockTaskService {
                 updated_at: Default::default(),
                 last_suspended_at: None,
                 error_integration: None,
+                success_integration: None,
                 after: vec![],
                 when_condition: None,
                 session_parameters: Default::default(),
+                task_sql_type: 0,
+                script_sql: None,
             }),
             error: None,
         }))
@@ -197,6 +200,7 @@ async fn test_task_client_success_cases() -> Result<()> {
         comment: None,
         schedule_options: None,
         error_integration: None,
+        success_integration: None,
         task_sql_type: 0,
         warehouse_options: None,
         suspend_task_after_num_failures: None,
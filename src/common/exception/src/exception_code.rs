@@ -157,6 +157,11 @@ build_exceptions! {
     WrongSequenceCount(1125),
     UnknownSequence(1126),
     UnknownQuery(1127),
+    // partition
+    InvalidPartitionKey(1128),
+    // The table is not a partitioned table.
+    UnpartitionedTable(1129),
+    UnknownPartition(1130),
 
     // Data Related Errors
 
@@ -208,6 +213,10 @@ build_exceptions! {
     CloudControlConnectError(1701),
     CloudControlNotEnabled(1702),
     IllegalCloudControlMessageFormat(1703),
+    UnknownTask(1704),
+    TaskAlreadyExists(1705),
+    UnknownNotification(1706),
+    NotificationAlreadyExists(1707),
 
     // Geometry errors.
     GeometryError(1801),
@@ -256,6 +265,8 @@ build_exceptions! {
     RoleAlreadyExists(2216),
     IllegalRole(2217),
     IllegalUser(2218),
+    UnknownQueryHint(2219),
+    QueryHintAlreadyExists(2220),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -392,6 +403,9 @@ build_exceptions! {
     UnknownProcedure(3130),
     ProcedureAlreadyExists(3131),
     IllegalProcedureFormat(3132),
+    // Row access policy
+    UnknownRowAccessPolicy(3140),
+    RowAccessPolicyAlreadyExists(3141),
 }
 
 // Storage errors [3001, 4000].
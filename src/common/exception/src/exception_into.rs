@@ -272,6 +272,15 @@ impl From<prost::EncodeError> for ErrorCode {
     }
 }
 
+impl From<prost::DecodeError> for ErrorCode {
+    fn from(error: prost::DecodeError) -> Self {
+        ErrorCode::BadBytes(format!(
+            "Bad bytes, cannot parse bytes with prost, cause: {}",
+            error
+        ))
+    }
+}
+
 // ===  ser/de to/from tonic::Status ===
 #[derive(thiserror::Error, serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct SerializedError {
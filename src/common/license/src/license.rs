@@ -59,6 +59,10 @@ pub enum Feature {
     AggregateIndex,
     #[serde(alias = "inverted_index", alias = "INVERTED_INDEX")]
     InvertedIndex,
+    #[serde(alias = "vector_index", alias = "VECTOR_INDEX")]
+    VectorIndex,
+    #[serde(alias = "row_access_policy", alias = "ROW_ACCESS_POLICY")]
+    RowAccessPolicy,
     #[serde(alias = "computed_column", alias = "COMPUTED_COLUMN")]
     ComputedColumn,
     #[serde(alias = "storage_encryption", alias = "STORAGE_ENCRYPTION")]
@@ -88,6 +92,8 @@ impl fmt::Display for Feature {
             Feature::DataMask => write!(f, "data_mask"),
             Feature::AggregateIndex => write!(f, "aggregate_index"),
             Feature::InvertedIndex => write!(f, "inverted_index"),
+            Feature::VectorIndex => write!(f, "vector_index"),
+            Feature::RowAccessPolicy => write!(f, "row_access_policy"),
             Feature::ComputedColumn => write!(f, "computed_column"),
             Feature::StorageEncryption => write!(f, "storage_encryption"),
             Feature::Stream => write!(f, "stream"),
@@ -164,6 +170,8 @@ impl Feature {
             | (Feature::BackgroundService, Feature::BackgroundService)
             | (Feature::DataMask, Feature::DataMask)
             | (Feature::InvertedIndex, Feature::InvertedIndex)
+            | (Feature::VectorIndex, Feature::VectorIndex)
+            | (Feature::RowAccessPolicy, Feature::RowAccessPolicy)
             | (Feature::VirtualColumn, Feature::VirtualColumn)
             | (Feature::AttacheTable, Feature::AttacheTable)
             | (Feature::StorageEncryption, Feature::StorageEncryption) => Ok(true),
@@ -287,6 +295,14 @@ mod tests {
             Feature::InvertedIndex,
             serde_json::from_str::<Feature>("\"InvertedIndex\"").unwrap()
         );
+        assert_eq!(
+            Feature::VectorIndex,
+            serde_json::from_str::<Feature>("\"VectorIndex\"").unwrap()
+        );
+        assert_eq!(
+            Feature::RowAccessPolicy,
+            serde_json::from_str::<Feature>("\"ROW_ACCESS_POLICY\"").unwrap()
+        );
         assert_eq!(
             Feature::ComputedColumn,
             serde_json::from_str::<Feature>("\"ComputedColumn\"").unwrap()
@@ -355,6 +371,8 @@ mod tests {
                 Feature::DataMask,
                 Feature::AggregateIndex,
                 Feature::InvertedIndex,
+                Feature::VectorIndex,
+                Feature::RowAccessPolicy,
                 Feature::ComputedColumn,
                 Feature::StorageEncryption,
                 Feature::Stream,
@@ -371,7 +389,7 @@ mod tests {
         };
 
         assert_eq!(
-            "LicenseInfo{ type: enterprise, org: databend, tenants: [databend_tenant,foo], features: [aggregate_index,amend_table,attach_table,background_service,compute_quota(threads_num: 1, memory_usage: 1),computed_column,data_mask,inverted_index,license_info,storage_encryption,storage_quota(storage_usage: 1),stream,vacuum,virtual_column] }",
+            "LicenseInfo{ type: enterprise, org: databend, tenants: [databend_tenant,foo], features: [aggregate_index,amend_table,attach_table,background_service,compute_quota(threads_num: 1, memory_usage: 1),computed_column,data_mask,inverted_index,license_info,row_access_policy,storage_encryption,storage_quota(storage_usage: 1),stream,vacuum,vector_index,virtual_column] }",
             license_info.to_string()
         );
     }
@@ -66,6 +66,7 @@ mod copy;
 mod histogram;
 mod merge;
 mod metrics_layer;
+mod most_common_values;
 mod multi_table_insert;
 mod statistics;
 
@@ -76,6 +77,8 @@ pub use histogram::Histogram;
 pub use histogram::HistogramBucket;
 pub use histogram::DEFAULT_HISTOGRAM_BUCKETS;
 pub use merge::MutationStatus;
+pub use most_common_values::MostCommonValue;
+pub use most_common_values::MostCommonValues;
 pub use multi_table_insert::MultiTableInsertStatus;
 pub use statistics::Datum;
 pub use statistics::F64;
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::Datum;
+
+/// The most common values of a column and their frequencies.
+///
+/// Histograms describe the distribution of a column by ranges, which is not
+/// precise enough to estimate equality predicates on skewed data. The most
+/// common values keep the frequency of the heaviest values, and the rest of
+/// the values are assumed to be uniformly distributed.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct MostCommonValues {
+    /// Values ordered by frequency in descending order.
+    pub values: Vec<MostCommonValue>,
+    /// Fraction of the rows that are null, the frequencies are relative to the other rows.
+    #[serde(default)]
+    pub null_fraction: f64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MostCommonValue {
+    pub value: Datum,
+    /// Fraction of the non-null rows that equal to the value.
+    pub frequency: f64,
+}
+
+impl MostCommonValues {
+    pub fn new(mut values: Vec<MostCommonValue>, null_fraction: f64) -> Self {
+        values.sort_by(|l, r| {
+            r.frequency
+                .partial_cmp(&l.frequency)
+                .unwrap_or(Ordering::Equal)
+        });
+        Self {
+            values,
+            null_fraction,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Get the sum of the frequencies of all the most common values.
+    pub fn total_frequency(&self) -> f64 {
+        self.values
+            .iter()
+            .map(|value| value.frequency)
+            .sum::<f64>()
+            .min(1.0)
+    }
+
+    /// Get the frequency of `datum` if it is one of the most common values.
+    pub fn frequency_of(&self, datum: &Datum) -> Option<f64> {
+        self.values
+            .iter()
+            .find(|value| {
+                value.value.type_comparable(datum)
+                    && matches!(value.value.compare(datum), Ok(Ordering::Equal))
+            })
+            .map(|value| value.frequency)
+    }
+
+    /// Get iterator of the most common values.
+    pub fn values_iter(&self) -> impl Iterator<Item = &MostCommonValue> {
+        self.values.iter()
+    }
+}
+
+impl fmt::Display for MostCommonValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for value in &self.values {
+            writeln!(f, "{}: {}", value.value, value.frequency)?;
+        }
+        Ok(())
+    }
+}
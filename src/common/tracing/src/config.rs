@@ -24,6 +24,7 @@ pub struct Config {
     pub otlp: OTLPConfig,
     pub query: QueryLogConfig,
     pub profile: ProfileLogConfig,
+    pub audit: AuditLogConfig,
     pub structlog: StructLogConfig,
     pub tracing: TracingConfig,
 }
@@ -198,6 +199,43 @@ impl Default for ProfileLogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct AuditLogConfig {
+    pub on: bool,
+    pub dir: String,
+    /// The max number of audit log files kept in `dir`, 0 keeps all of them.
+    pub limit: usize,
+    /// The secret key of the HMAC that chains the audit events.
+    pub hmac_key: String,
+    pub otlp: Option<OTLPEndpointConfig>,
+}
+
+impl Display for AuditLogConfig {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "enabled={}, dir={}, limit={}",
+            self.on, self.dir, self.limit
+        )?;
+        if let Some(endpoint) = &self.otlp {
+            write!(f, ", otlp={}", endpoint)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        Self {
+            on: false,
+            dir: "".to_string(),
+            limit: 0,
+            hmac_key: "".to_string(),
+            otlp: None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
 pub struct StructLogConfig {
     pub on: bool,
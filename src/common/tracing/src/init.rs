@@ -60,6 +60,24 @@ pub fn start_trace_for_remote_request<T>(name: &'static str, request: &tonic::Re
     }
 }
 
+/// Start a root span under the W3C `traceparent` sent by a client, e.g., in a request header
+/// or a session setting, so that the spans of the query are reported into the client trace.
+///
+/// The root span is started under `fallback` if `traceparent` is absent, empty or invalid.
+pub fn start_trace_for_client(
+    name: &'static str,
+    traceparent: Option<&str>,
+    fallback: impl FnOnce() -> SpanContext,
+) -> Span {
+    if let Some(traceparent) = traceparent.filter(|traceparent| !traceparent.is_empty()) {
+        match SpanContext::decode_w3c_traceparent(traceparent) {
+            Some(span_context) => return Span::root(name, span_context),
+            None => log::warn!("failed to decode trace parent: {}", traceparent),
+        }
+    }
+    Span::root(name, fallback())
+}
+
 pub fn inject_span_to_tonic_request<T>(msg: impl tonic::IntoRequest<T>) -> tonic::Request<T> {
     let mut request = msg.into_request();
     if let Some(current) = SpanContext::current_local_parent() {
@@ -187,6 +205,10 @@ pub fn init_logging(
                 "databend::log::profile",
                 LevelFilter::Off,
             ))
+            .filter(TargetFilter::level_for(
+                "databend::log::audit",
+                LevelFilter::Off,
+            ))
             .filter(TargetFilter::level_for(
                 "databend::log::structlog",
                 LevelFilter::Off,
@@ -209,6 +231,10 @@ pub fn init_logging(
                 "databend::log::profile",
                 LevelFilter::Off,
             ))
+            .filter(TargetFilter::level_for(
+                "databend::log::audit",
+                LevelFilter::Off,
+            ))
             .filter(TargetFilter::level_for(
                 "databend::log::structlog",
                 LevelFilter::Off,
@@ -246,6 +272,10 @@ pub fn init_logging(
                 "databend::log::profile",
                 LevelFilter::Off,
             ))
+            .filter(TargetFilter::level_for(
+                "databend::log::audit",
+                LevelFilter::Off,
+            ))
             .filter(TargetFilter::level_for(
                 "databend::log::structlog",
                 LevelFilter::Off,
@@ -273,6 +303,10 @@ pub fn init_logging(
                 "databend::log::profile",
                 LevelFilter::Off,
             ))
+            .filter(TargetFilter::level_for(
+                "databend::log::audit",
+                LevelFilter::Off,
+            ))
             .filter(TargetFilter::level_for(
                 "databend::log::structlog",
                 LevelFilter::Off,
@@ -366,6 +400,48 @@ pub fn init_logging(
         }
     }
 
+    // audit logger
+    if cfg.audit.on {
+        if !cfg.audit.dir.is_empty() {
+            let (audit_log_file, flush_guard) =
+                new_rolling_file_appender(&cfg.audit.dir, log_name, cfg.audit.limit);
+            _drop_guards.push(flush_guard);
+
+            let dispatch = Dispatch::new()
+                .filter(TargetFilter::level_for_not(
+                    "databend::log::audit",
+                    LevelFilter::Off,
+                ))
+                .append(audit_log_file);
+            logger = logger.dispatch(dispatch);
+        }
+        if let Some(endpoint) = &cfg.audit.otlp {
+            let labels = labels
+                .iter()
+                .chain(&endpoint.labels)
+                .map(|(k, v)| (Cow::from(k.clone()), Cow::from(v.clone())))
+                .chain([(Cow::from("category"), Cow::from("audit"))]);
+            let mut otel_builder = logforth::append::opentelemetry::OpentelemetryLogBuilder::new(
+                log_name,
+                format!("{}/v1/logs", &endpoint.endpoint),
+            )
+            .with_protocol(endpoint.protocol.into());
+            for (k, v) in labels {
+                otel_builder = otel_builder.add_label(k, v);
+            }
+            let otel = otel_builder
+                .build()
+                .expect("initialize opentelemetry logger");
+            let dispatch = Dispatch::new()
+                .filter(TargetFilter::level_for_not(
+                    "databend::log::audit",
+                    LevelFilter::Off,
+                ))
+                .append(otel);
+            logger = logger.dispatch(dispatch);
+        }
+    }
+
     // structured logger
     if cfg.structlog.on && !cfg.structlog.dir.is_empty() {
         let (structlog_log_file, flush_guard) =
@@ -421,3 +497,17 @@ fn is_severe(meta: &Metadata) -> bool {
     // For other component, output logs with level <= WARN
     meta.level() <= LevelFilter::Warn
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::init::start_trace_for_client;
+
+    #[test]
+    fn test_start_trace_for_client() {
+        assert!(start_trace_for_client("test", "").is_none());
+        assert!(start_trace_for_client("test", "not-a-traceparent").is_none());
+
+        let traceparent = "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01";
+        assert!(start_trace_for_client("test", traceparent).is_some());
+    }
+}
@@ -27,6 +27,7 @@ mod structlog;
 pub use crash_hook::pipe_file;
 pub use crash_hook::SignalListener;
 
+pub use crate::config::AuditLogConfig;
 pub use crate::config::Config;
 pub use crate::config::FileConfig;
 pub use crate::config::OTLPConfig;
@@ -40,6 +41,7 @@ pub use crate::config::TracingConfig;
 pub use crate::crash_hook::set_crash_hook;
 pub use crate::init::init_logging;
 pub use crate::init::inject_span_to_tonic_request;
+pub use crate::init::start_trace_for_client;
 pub use crate::init::start_trace_for_remote_request;
 pub use crate::init::GlobalLogger;
 pub use crate::panic_hook::log_panic;
@@ -33,10 +33,14 @@ pub(crate) fn new_rolling_file_appender(
     name: impl ToString,
     max_files: usize,
 ) -> (RollingFile, Box<dyn Send + Sync + 'static>) {
-    let rolling = RollingFileWriter::builder()
+    let mut builder = RollingFileWriter::builder()
         .rotation(Rotation::Hourly)
-        .filename_prefix(name.to_string())
-        .max_log_files(max_files)
+        .filename_prefix(name.to_string());
+    // 0 keeps all of the files.
+    if max_files > 0 {
+        builder = builder.max_log_files(max_files);
+    }
+    let rolling = builder
         .build(dir)
         .expect("failed to initialize rolling file appender");
     let (non_blocking, guard) = NonBlockingBuilder::default()
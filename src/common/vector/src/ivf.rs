@@ -0,0 +1,648 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+const KMEANS_MAX_ITERATIONS: usize = 10;
+
+/// The distance function an [`IvfIndex`] is built for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DistanceType {
+    Cosine,
+    L2,
+}
+
+impl DistanceType {
+    /// Returns the distance type of the scalar function `func_name`.
+    pub fn from_func_name(func_name: &str) -> Option<Self> {
+        match func_name {
+            "cosine_distance" => Some(DistanceType::Cosine),
+            "l2_distance" => Some(DistanceType::L2),
+            _ => None,
+        }
+    }
+
+    pub fn from_option(value: &str) -> Option<Self> {
+        match value {
+            "cosine" => Some(DistanceType::Cosine),
+            "l2" => Some(DistanceType::L2),
+            _ => None,
+        }
+    }
+
+    pub fn as_option(&self) -> &'static str {
+        match self {
+            DistanceType::Cosine => "cosine",
+            DistanceType::L2 => "l2",
+        }
+    }
+
+    /// Same as `cosine_distance` and `l2_distance`, except that the lengths are not checked.
+    #[inline]
+    pub fn distance(&self, from: &[f32], to: &[f32]) -> f32 {
+        match self {
+            DistanceType::Cosine => {
+                let mut ab = 0.0;
+                let mut aa = 0.0;
+                let mut bb = 0.0;
+                for (a, b) in from.iter().zip(to.iter()) {
+                    ab += a * b;
+                    aa += a * a;
+                    bb += b * b;
+                }
+                1.0 - ab / (aa.sqrt() * bb.sqrt())
+            }
+            DistanceType::L2 => from
+                .iter()
+                .zip(to.iter())
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f32>()
+                .sqrt(),
+        }
+    }
+
+    fn code(&self) -> u8 {
+        match self {
+            DistanceType::Cosine => 0,
+            DistanceType::L2 => 1,
+        }
+    }
+
+    fn from_code(code: u8) -> Result<Self> {
+        match code {
+            0 => Ok(DistanceType::Cosine),
+            1 => Ok(DistanceType::L2),
+            _ => Err(invalid_data(format!("unknown distance type {}", code))),
+        }
+    }
+}
+
+/// An inverted file index with scalar quantized storage.
+///
+/// The vectors are clustered by k-means into `lists` partitions, and each value of the
+/// vectors is quantized into a `u8` code. A search computes the distances between the
+/// query and the centroids, then scans the codes of the `probes` nearest partitions, the
+/// distances of the candidates are approximate.
+///
+/// The serialized index starts with the [`IvfIndexMeta`], followed by the posting lists,
+/// so that a search only reads the meta and the probed lists.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IvfIndex {
+    meta: IvfIndexMeta,
+    lists: Vec<IvfList>,
+}
+
+impl IvfIndex {
+    /// The version of the serialized format.
+    pub const VERSION: u8 = 2;
+
+    /// Build the index of `row_ids.len()` vectors stored continuously in `vectors`.
+    ///
+    /// If `lists` is 0, the square root of the number of vectors is used.
+    pub fn build(
+        distance_type: DistanceType,
+        dimension: usize,
+        row_ids: Vec<u32>,
+        vectors: Vec<f32>,
+        lists: usize,
+    ) -> Result<Self> {
+        if dimension == 0 {
+            return Err(ErrorCode::InvalidArgument(
+                "Vector dimension must be greater than 0",
+            ));
+        }
+        if row_ids.len() * dimension != vectors.len() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                vectors.len(),
+                row_ids.len() * dimension,
+            )));
+        }
+
+        let num_vectors = row_ids.len();
+        let num_lists = if num_vectors == 0 {
+            0
+        } else if lists == 0 {
+            ((num_vectors as f64).sqrt().ceil() as usize).clamp(1, num_vectors)
+        } else {
+            lists.min(num_vectors)
+        };
+
+        // Initialize the centroids with vectors evenly spaced in the input,
+        // so that the same input always produces the same index.
+        let mut centroids = Vec::with_capacity(num_lists * dimension);
+        for i in 0..num_lists {
+            let row = i * num_vectors / num_lists;
+            centroids.extend_from_slice(&vectors[row * dimension..(row + 1) * dimension]);
+        }
+
+        let mut assignments = (0..num_vectors)
+            .map(|row| {
+                let vector = &vectors[row * dimension..(row + 1) * dimension];
+                nearest_centroid(distance_type, dimension, &centroids, vector)
+            })
+            .collect::<Vec<_>>();
+        for _ in 0..KMEANS_MAX_ITERATIONS {
+            let mut sums = vec![0.0f32; num_lists * dimension];
+            let mut counts = vec![0usize; num_lists];
+            for (row, list) in assignments.iter().enumerate() {
+                counts[*list] += 1;
+                let vector = &vectors[row * dimension..(row + 1) * dimension];
+                for (sum, value) in sums[list * dimension..(list + 1) * dimension]
+                    .iter_mut()
+                    .zip(vector)
+                {
+                    *sum += value;
+                }
+            }
+            for (list, count) in counts.into_iter().enumerate() {
+                // keep the old centroid of an empty list.
+                if count == 0 {
+                    continue;
+                }
+                for (centroid, sum) in centroids[list * dimension..(list + 1) * dimension]
+                    .iter_mut()
+                    .zip(&sums[list * dimension..(list + 1) * dimension])
+                {
+                    *centroid = sum / count as f32;
+                }
+            }
+
+            let mut changed = false;
+            for (row, assignment) in assignments.iter_mut().enumerate() {
+                let vector = &vectors[row * dimension..(row + 1) * dimension];
+                let nearest = nearest_centroid(distance_type, dimension, &centroids, vector);
+                if nearest != *assignment {
+                    *assignment = nearest;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let quantizer = ScalarQuantizer::train(dimension, &vectors);
+        let mut lists = (0..num_lists)
+            .map(|_| IvfList::default())
+            .collect::<Vec<_>>();
+        for (row, list) in assignments.into_iter().enumerate() {
+            let list = &mut lists[list];
+            list.row_ids.push(row_ids[row]);
+            quantizer.encode(
+                &vectors[row * dimension..(row + 1) * dimension],
+                &mut list.codes,
+            );
+        }
+
+        let mut list_offsets = Vec::with_capacity(num_lists + 1);
+        list_offsets.push(0);
+        for list in &lists {
+            list_offsets.push(list_offsets.last().unwrap() + list.row_ids.len() as u32);
+        }
+
+        Ok(Self {
+            meta: IvfIndexMeta {
+                distance_type,
+                dimension,
+                centroids,
+                quantizer,
+                list_offsets,
+            },
+            lists,
+        })
+    }
+
+    pub fn meta(&self) -> &IvfIndexMeta {
+        &self.meta
+    }
+
+    pub fn distance_type(&self) -> DistanceType {
+        self.meta.distance_type
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.meta.dimension
+    }
+
+    pub fn num_lists(&self) -> usize {
+        self.meta.num_lists()
+    }
+
+    pub fn num_vectors(&self) -> usize {
+        self.meta.num_vectors()
+    }
+
+    /// Search the approximate `k` nearest vectors of `query` in the `probes` nearest lists.
+    ///
+    /// Returns the row ids and the approximate distances, ordered by distance.
+    pub fn search(&self, query: &[f32], k: usize, probes: usize) -> Result<Vec<(u32, f32)>> {
+        let lists = self
+            .meta
+            .probe_lists(query, probes)?
+            .into_iter()
+            .map(|list| &self.lists[list])
+            .collect::<Vec<_>>();
+        self.meta.search(query, &lists, k)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = self.meta.to_bytes();
+        for list in &self.lists {
+            buf.extend_from_slice(&list.to_bytes());
+        }
+        buf
+    }
+
+    pub fn from_bytes(buf: &[u8]) -> Result<Self> {
+        let meta_size = IvfIndexMeta::meta_size(buf)?;
+        if buf.len() < meta_size {
+            return Err(invalid_data("unexpected end of data"));
+        }
+        let meta = IvfIndexMeta::from_bytes(&buf[..meta_size])?;
+        let lists = (0..meta.num_lists())
+            .map(|list| {
+                let range = meta.list_range(list);
+                let (start, end) = (range.start as usize, range.end as usize);
+                if buf.len() < end {
+                    return Err(invalid_data("unexpected end of data"));
+                }
+                IvfList::from_bytes(meta.dimension, &buf[start..end])
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if meta.list_range(meta.num_lists()).start as usize != buf.len() {
+            return Err(invalid_data("unexpected trailing bytes"));
+        }
+        Ok(Self { meta, lists })
+    }
+}
+
+/// The meta of an [`IvfIndex`]: the centroids, the quantizer and the layout of the lists.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IvfIndexMeta {
+    distance_type: DistanceType,
+    dimension: usize,
+    // `num_lists * dimension` values.
+    centroids: Vec<f32>,
+    quantizer: ScalarQuantizer,
+    // The vectors of list `i` are in `list_offsets[i]..list_offsets[i + 1]`.
+    list_offsets: Vec<u32>,
+}
+
+impl IvfIndexMeta {
+    /// The size of the fixed header: the version, the distance type,
+    /// the dimension, the number of lists and the number of vectors.
+    pub const HEADER_SIZE: usize = 14;
+
+    /// Returns the serialized size of the meta, `header` is the head of an index file
+    /// at least [`Self::HEADER_SIZE`] long.
+    pub fn meta_size(header: &[u8]) -> Result<usize> {
+        let mut reader = ByteReader {
+            buf: header,
+            pos: 0,
+        };
+        let version = reader.read_u8()?;
+        if version != IvfIndex::VERSION {
+            return Err(invalid_data(format!("unknown format version {}", version)));
+        }
+        DistanceType::from_code(reader.read_u8()?)?;
+        let dimension = reader.read_u32()? as usize;
+        let num_lists = reader.read_u32()? as usize;
+
+        // centroids, the min and scale of the quantizer, and the list offsets.
+        checked_len(num_lists, dimension)?
+            .checked_add(2 * dimension + num_lists + 1)
+            .and_then(|len| len.checked_mul(4))
+            .and_then(|len| len.checked_add(Self::HEADER_SIZE))
+            .ok_or_else(|| invalid_data("vector length overflow"))
+    }
+
+    pub fn distance_type(&self) -> DistanceType {
+        self.distance_type
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn num_lists(&self) -> usize {
+        self.list_offsets.len().saturating_sub(1)
+    }
+
+    pub fn num_vectors(&self) -> usize {
+        self.list_offsets.last().copied().unwrap_or(0) as usize
+    }
+
+    /// The serialized size of the meta, the lists start right after the meta.
+    pub fn size(&self) -> usize {
+        Self::HEADER_SIZE
+            + 4 * (self.centroids.len()
+                + self.quantizer.min.len()
+                + self.quantizer.scale.len()
+                + self.list_offsets.len())
+    }
+
+    /// Returns the byte range of the list `list` in the index file,
+    /// `list` may be `num_lists` to get the end of the file.
+    pub fn list_range(&self, list: usize) -> Range<u64> {
+        let entry_size = (4 + self.dimension) as u64;
+        let offset = |list: usize| {
+            let pos = self.list_offsets[list.min(self.num_lists())] as u64;
+            self.size() as u64 + pos * entry_size
+        };
+        offset(list)..offset(list + 1)
+    }
+
+    /// Returns the `probes` nearest lists of `query`.
+    pub fn probe_lists(&self, query: &[f32], probes: usize) -> Result<Vec<usize>> {
+        if query.len() != self.dimension {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                query.len(),
+                self.dimension,
+            )));
+        }
+        let dimension = self.dimension;
+        let mut lists = (0..self.num_lists())
+            .map(|list| {
+                let centroid = &self.centroids[list * dimension..(list + 1) * dimension];
+                (list, self.distance_type.distance(query, centroid))
+            })
+            .collect::<Vec<_>>();
+        lists.sort_by(|a, b| cmp_distance(a.1, b.1));
+        lists.truncate(probes.max(1));
+        Ok(lists.into_iter().map(|(list, _)| list).collect())
+    }
+
+    /// Search the approximate `k` nearest vectors of `query` in `lists`.
+    ///
+    /// Returns the row ids and the approximate distances, ordered by distance.
+    pub fn search(&self, query: &[f32], lists: &[&IvfList], k: usize) -> Result<Vec<(u32, f32)>> {
+        if query.len() != self.dimension {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                query.len(),
+                self.dimension,
+            )));
+        }
+        let dimension = self.dimension;
+        let mut vector = vec![0.0; dimension];
+        let mut result = Vec::new();
+        for list in lists {
+            for (row_id, codes) in list.row_ids.iter().zip(list.codes.chunks_exact(dimension)) {
+                self.quantizer.decode(codes, &mut vector);
+                let distance = self.distance_type.distance(query, &vector);
+                result.push((*row_id, distance));
+            }
+        }
+        result.sort_by(|a, b| cmp_distance(a.1, b.1));
+        result.truncate(k);
+        Ok(result)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.size());
+        buf.push(IvfIndex::VERSION);
+        buf.push(self.distance_type.code());
+        buf.extend_from_slice(&(self.dimension as u32).to_le_bytes());
+        buf.extend_from_slice(&(self.num_lists() as u32).to_le_bytes());
+        buf.extend_from_slice(&(self.num_vectors() as u32).to_le_bytes());
+        for v in self
+            .centroids
+            .iter()
+            .chain(&self.quantizer.min)
+            .chain(&self.quantizer.scale)
+        {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        for v in &self.list_offsets {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        buf
+    }
+
+    pub fn from_bytes(buf: &[u8]) -> Result<Self> {
+        let meta_size = Self::meta_size(buf)?;
+        if buf.len() != meta_size {
+            return Err(invalid_data("invalid meta size"));
+        }
+        let mut reader = ByteReader { buf, pos: 1 };
+        let distance_type = DistanceType::from_code(reader.read_u8()?)?;
+        let dimension = reader.read_u32()? as usize;
+        let num_lists = reader.read_u32()? as usize;
+        let num_vectors = reader.read_u32()? as usize;
+
+        let centroids = reader.read_f32s(checked_len(num_lists, dimension)?)?;
+        let min = reader.read_f32s(dimension)?;
+        let scale = reader.read_f32s(dimension)?;
+        let list_offsets = reader.read_u32s(num_lists + 1)?;
+        if list_offsets.windows(2).any(|w| w[0] > w[1])
+            || list_offsets.last().map(|v| *v as usize) != Some(num_vectors)
+        {
+            return Err(invalid_data("invalid list offsets"));
+        }
+
+        Ok(Self {
+            distance_type,
+            dimension,
+            centroids,
+            quantizer: ScalarQuantizer { min, scale },
+            list_offsets,
+        })
+    }
+}
+
+/// A posting list of an [`IvfIndex`]: the row ids and the quantized codes of its vectors.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IvfList {
+    row_ids: Vec<u32>,
+    // `row_ids.len() * dimension` codes.
+    codes: Vec<u8>,
+}
+
+impl IvfList {
+    pub fn len(&self) -> usize {
+        self.row_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.row_ids.is_empty()
+    }
+
+    pub fn mem_bytes(&self) -> usize {
+        std::mem::size_of::<Self>() + 4 * self.row_ids.len() + self.codes.len()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(4 * self.row_ids.len() + self.codes.len());
+        for v in &self.row_ids {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        buf.extend_from_slice(&self.codes);
+        buf
+    }
+
+    /// Decode a list read from [`IvfIndexMeta::list_range`].
+    pub fn from_bytes(dimension: usize, buf: &[u8]) -> Result<Self> {
+        let entry_size = 4 + dimension;
+        if buf.len() % entry_size != 0 {
+            return Err(invalid_data("invalid list size"));
+        }
+        let len = buf.len() / entry_size;
+        let mut reader = ByteReader { buf, pos: 0 };
+        let row_ids = reader.read_u32s(len)?;
+        let codes = reader.take(len * dimension)?.to_vec();
+        Ok(Self { row_ids, codes })
+    }
+}
+
+// Each dimension is mapped linearly from `[min, min + 255 * scale]` to the `u8` codes.
+#[derive(Clone, Debug, PartialEq)]
+struct ScalarQuantizer {
+    min: Vec<f32>,
+    scale: Vec<f32>,
+}
+
+impl ScalarQuantizer {
+    fn train(dimension: usize, vectors: &[f32]) -> Self {
+        let mut min = vec![f32::MAX; dimension];
+        let mut max = vec![f32::MIN; dimension];
+        for vector in vectors.chunks_exact(dimension) {
+            for (i, v) in vector.iter().enumerate() {
+                // Non-finite values are clamped into the range.
+                if v.is_finite() {
+                    min[i] = min[i].min(*v);
+                    max[i] = max[i].max(*v);
+                }
+            }
+        }
+        let scale = min
+            .iter_mut()
+            .zip(&max)
+            .map(|(min, max)| {
+                if *min > *max {
+                    *min = 0.0;
+                    return 0.0;
+                }
+                ((*max as f64 - *min as f64) / u8::MAX as f64) as f32
+            })
+            .collect();
+        Self { min, scale }
+    }
+
+    fn encode(&self, vector: &[f32], codes: &mut Vec<u8>) {
+        codes.extend(vector.iter().zip(self.min.iter().zip(&self.scale)).map(
+            |(v, (min, scale))| {
+                if *scale == 0.0 {
+                    0
+                } else {
+                    // `as` saturates and maps NaN to 0.
+                    ((v - min) / scale).round() as u8
+                }
+            },
+        ));
+    }
+
+    fn decode(&self, codes: &[u8], vector: &mut [f32]) {
+        for (v, (code, (min, scale))) in vector
+            .iter_mut()
+            .zip(codes.iter().zip(self.min.iter().zip(&self.scale)))
+        {
+            *v = min + *code as f32 * scale;
+        }
+    }
+}
+
+fn nearest_centroid(
+    distance_type: DistanceType,
+    dimension: usize,
+    centroids: &[f32],
+    vector: &[f32],
+) -> usize {
+    let mut nearest = 0;
+    let mut min_distance = f32::MAX;
+    for (list, centroid) in centroids.chunks_exact(dimension).enumerate() {
+        let distance = distance_type.distance(vector, centroid);
+        if cmp_distance(distance, min_distance) == Ordering::Less {
+            nearest = list;
+            min_distance = distance;
+        }
+    }
+    nearest
+}
+
+// NaN distances, e.g. the cosine distance of zero vectors, are ordered last.
+#[inline]
+fn cmp_distance(a: f32, b: f32) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (false, false) => a.total_cmp(&b),
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (true, true) => Ordering::Equal,
+    }
+}
+
+fn checked_len(num: usize, dimension: usize) -> Result<usize> {
+    num.checked_mul(dimension)
+        .ok_or_else(|| invalid_data("vector length overflow"))
+}
+
+fn invalid_data(reason: impl ToString) -> ErrorCode {
+    ErrorCode::Internal(format!("Invalid vector index data: {}", reason.to_string()))
+}
+
+struct ByteReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.buf.len() - self.pos < len {
+            return Err(invalid_data("unexpected end of data"));
+        }
+        let bytes = &self.buf[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn read_u32s(&mut self, len: usize) -> Result<Vec<u32>> {
+        let bytes = self.take(checked_len(len, 4)?)?;
+        Ok(bytes
+            .chunks_exact(4)
+            .map(|v| u32::from_le_bytes(v.try_into().unwrap()))
+            .collect())
+    }
+
+    fn read_f32s(&mut self, len: usize) -> Result<Vec<f32>> {
+        let bytes = self.take(checked_len(len, 4)?)?;
+        Ok(bytes
+            .chunks_exact(4)
+            .map(|v| f32::from_le_bytes(v.try_into().unwrap()))
+            .collect())
+    }
+}
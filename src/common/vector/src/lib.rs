@@ -13,8 +13,13 @@
 // limitations under the License.
 
 mod distance;
+mod ivf;
 
 pub use distance::cosine_distance;
 pub use distance::cosine_distance_64;
 pub use distance::l2_distance;
 pub use distance::l2_distance_64;
+pub use ivf::DistanceType;
+pub use ivf::IvfIndex;
+pub use ivf::IvfIndexMeta;
+pub use ivf::IvfList;
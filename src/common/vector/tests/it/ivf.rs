@@ -0,0 +1,139 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_vector::cosine_distance;
+use databend_common_vector::l2_distance;
+use databend_common_vector::DistanceType;
+use databend_common_vector::IvfIndex;
+use databend_common_vector::IvfIndexMeta;
+use databend_common_vector::IvfList;
+
+fn vectors(num: usize, dimension: usize) -> Vec<f32> {
+    // points on a few well separated clusters.
+    (0..num * dimension)
+        .map(|i| {
+            let row = i / dimension;
+            let cluster = (row % 4) as f32 * 100.0;
+            cluster + ((i * 7919) % 13) as f32
+        })
+        .collect()
+}
+
+#[test]
+fn test_ivf_search() {
+    let dimension = 8;
+    let num = 200;
+    let data = vectors(num, dimension);
+    let row_ids = (0..num as u32).collect::<Vec<_>>();
+
+    for distance_type in [DistanceType::L2, DistanceType::Cosine] {
+        let index =
+            IvfIndex::build(distance_type, dimension, row_ids.clone(), data.clone(), 0).unwrap();
+        assert_eq!(index.num_vectors(), num);
+        assert_eq!(index.num_lists(), 15);
+
+        let exact_distance = |query: &[f32], row: usize| {
+            let vector = &data[row * dimension..(row + 1) * dimension];
+            match distance_type {
+                DistanceType::Cosine => cosine_distance(query, vector).unwrap(),
+                DistanceType::L2 => l2_distance(query, vector).unwrap(),
+            }
+        };
+        // the error of the quantized distances.
+        let epsilon = match distance_type {
+            DistanceType::Cosine => 0.05,
+            DistanceType::L2 => 2.0,
+        };
+
+        let query = &data[3 * dimension..4 * dimension];
+        // probe all the lists, the candidates are found with approximate distances.
+        let result = index.search(query, 10, index.num_lists()).unwrap();
+        assert_eq!(result.len(), 10);
+        assert!(result.windows(2).all(|w| w[0].1 <= w[1].1));
+        for (row, distance) in &result {
+            approx::assert_relative_eq!(
+                *distance,
+                exact_distance(query, *row as usize),
+                epsilon = epsilon
+            );
+        }
+
+        let mut expected = (0..num)
+            .map(|row| (row as u32, exact_distance(query, row)))
+            .collect::<Vec<_>>();
+        expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+        // the exact nearest rows are in the candidates.
+        for (row, _) in &expected[..3] {
+            assert!(result.iter().any(|(r, _)| r == row));
+        }
+
+        // the nearest vector of a vector in the index is itself, or a vector as near as it.
+        let result = index.search(query, 1, 1).unwrap();
+        approx::assert_relative_eq!(
+            exact_distance(query, result[0].0 as usize),
+            0.0,
+            epsilon = epsilon
+        );
+
+        assert!(index.search(&query[1..], 1, 1).is_err());
+    }
+}
+
+#[test]
+fn test_ivf_serialize() {
+    let dimension = 4;
+    let data = vectors(50, dimension);
+    let row_ids = (100..150).collect::<Vec<_>>();
+    let index = IvfIndex::build(DistanceType::Cosine, dimension, row_ids, data.clone(), 6).unwrap();
+    assert_eq!(index.num_lists(), 6);
+
+    let bytes = index.to_bytes();
+    let decoded = IvfIndex::from_bytes(&bytes).unwrap();
+    assert_eq!(index, decoded);
+
+    assert!(IvfIndex::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    // the meta and the probed lists are enough to search.
+    let meta_size = IvfIndexMeta::meta_size(&bytes[..IvfIndexMeta::HEADER_SIZE]).unwrap();
+    assert_eq!(meta_size, index.meta().size());
+    let meta = IvfIndexMeta::from_bytes(&bytes[..meta_size]).unwrap();
+    assert_eq!(&meta, index.meta());
+    assert_eq!(meta.list_range(0).start, meta_size as u64);
+    assert_eq!(meta.list_range(meta.num_lists()).start, bytes.len() as u64);
+
+    let query = &data[..dimension];
+    let lists = meta
+        .probe_lists(query, 2)
+        .unwrap()
+        .into_iter()
+        .map(|list| {
+            let range = meta.list_range(list);
+            IvfList::from_bytes(dimension, &bytes[range.start as usize..range.end as usize])
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let lists = lists.iter().collect::<Vec<_>>();
+    assert_eq!(
+        meta.search(query, &lists, 5).unwrap(),
+        index.search(query, 5, 2).unwrap()
+    );
+
+    // 4 bytes of row id and 1 byte per dimension for each vector.
+    assert_eq!(bytes.len() - meta_size, 50 * (4 + dimension));
+
+    let empty = IvfIndex::build(DistanceType::L2, dimension, vec![], vec![], 0).unwrap();
+    let decoded = IvfIndex::from_bytes(&empty.to_bytes()).unwrap();
+    assert_eq!(decoded.num_lists(), 0);
+    assert!(decoded.search(&[0.0; 4], 10, 1).unwrap().is_empty());
+}
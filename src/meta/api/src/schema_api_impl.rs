@@ -56,6 +56,7 @@ use databend_common_meta_app::app_error::UnknownTableId;
 use databend_common_meta_app::app_error::ViewAlreadyExists;
 use databend_common_meta_app::data_mask::MaskPolicyTableIdListIdent;
 use databend_common_meta_app::id_generator::IdGenerator;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdListIdent;
 use databend_common_meta_app::schema::catalog_id_ident::CatalogId;
 use databend_common_meta_app::schema::catalog_name_ident::CatalogNameIdentRaw;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
@@ -135,6 +136,9 @@ use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyAction;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableCopiedFileNameIdent;
 use databend_common_meta_app::schema::TableId;
 use databend_common_meta_app::schema::TableIdHistoryIdent;
@@ -2431,6 +2435,77 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall]
+    #[fastrace::trace]
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+        let tbid = TableId {
+            table_id: req.table_id,
+        };
+        let req_seq = req.seq;
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let seq_meta = self.get_pb(&tbid).await?;
+
+            debug!(ident :% =(&tbid); "set_table_row_access_policy");
+
+            let Some(seq_meta) = seq_meta else {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(req.table_id, "set_table_row_access_policy"),
+                )));
+            };
+
+            if req_seq.match_seq(seq_meta.seq).is_err() {
+                return Err(KVAppError::AppError(AppError::from(
+                    TableVersionMismatched::new(
+                        req.table_id,
+                        req.seq,
+                        seq_meta.seq,
+                        "set_table_row_access_policy",
+                    ),
+                )));
+            }
+
+            let mut new_table_meta = seq_meta.data;
+            new_table_meta.row_access_policy = match &req.action {
+                SetTableRowAccessPolicyAction::Set(policy, _) => Some(policy.clone()),
+                SetTableRowAccessPolicyAction::Unset(_) => None,
+            };
+
+            let mut txn_req = TxnRequest {
+                condition: vec![
+                    // table is not changed
+                    txn_cond_seq(&tbid, Eq, seq_meta.seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&tbid, serialize_struct(&new_table_meta)?), // tb_id -> tb_meta
+                ],
+                else_then: vec![],
+            };
+
+            update_row_access_policy(self, &req.action, &mut txn_req, &req.tenant, req.table_id)
+                .await?;
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                id :? =(&tbid),
+                succ = succ;
+                "set_table_row_access_policy"
+            );
+
+            if succ {
+                return Ok(SetTableRowAccessPolicyReply {});
+            }
+        }
+    }
+
     #[logcall::logcall]
     #[fastrace::trace]
     async fn create_table_index(&self, req: CreateTableIndexReq) -> Result<(), KVAppError> {
@@ -2478,9 +2553,9 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 }
             }
 
-            // column_id can not be duplicated
+            // column_id can not be duplicated in the indexes of the same type
             for (name, index) in indexes.iter() {
-                if *name == req.name {
+                if *name == req.name || index.index_type != req.index_type {
                     continue;
                 }
                 for column_id in &req.column_ids {
@@ -2496,13 +2571,17 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             // use the old index version, otherwise create a new index version.
             let mut old_version = None;
             if let Some(old_index) = indexes.get(&req.name) {
-                if old_index.column_ids == req.column_ids && old_index.options == req.options {
+                if old_index.index_type == req.index_type
+                    && old_index.column_ids == req.column_ids
+                    && old_index.options == req.options
+                {
                     old_version = Some(old_index.version.clone());
                 }
             }
             let version = old_version.unwrap_or(Uuid::new_v4().simple().to_string());
 
             let index = TableIndex {
+                index_type: req.index_type.clone(),
                 name: req.name.clone(),
                 column_ids: req.column_ids.clone(),
                 sync_creation: req.sync_creation,
@@ -3830,6 +3909,67 @@ async fn update_mask_policy(
     Ok(())
 }
 
+async fn update_row_access_policy(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    action: &SetTableRowAccessPolicyAction,
+    txn_req: &mut TxnRequest,
+    tenant: &Tenant,
+    table_id: u64,
+) -> Result<(), KVAppError> {
+    /// Fetch and update the table id list with `f`, and fill in the txn preconditions and operations.
+    async fn update_table_ids(
+        kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+        txn_req: &mut TxnRequest,
+        key: RowAccessPolicyTableIdListIdent,
+        f: impl FnOnce(&mut BTreeSet<u64>),
+    ) -> Result<(), KVAppError> {
+        let Some(mut seq_list) = kv_api.get_pb(&key).await? else {
+            return Ok(());
+        };
+
+        f(&mut seq_list.data.id_list);
+
+        txn_replace_exact(txn_req, &key, seq_list.seq, &seq_list.data)?;
+
+        Ok(())
+    }
+
+    let (new_policy, old_policy) = match action {
+        SetTableRowAccessPolicyAction::Set(new_policy, old_policy) => {
+            (Some(&new_policy.policy), old_policy.as_ref())
+        }
+        SetTableRowAccessPolicyAction::Unset(old_policy) => (None, Some(old_policy)),
+    };
+
+    if let Some(old) = old_policy {
+        if new_policy != Some(old) {
+            update_table_ids(
+                kv_api,
+                txn_req,
+                RowAccessPolicyTableIdListIdent::new(tenant.clone(), old),
+                |list: &mut BTreeSet<u64>| {
+                    list.remove(&table_id);
+                },
+            )
+            .await?;
+        }
+    }
+
+    if let Some(new) = new_policy {
+        update_table_ids(
+            kv_api,
+            txn_req,
+            RowAccessPolicyTableIdListIdent::new(tenant.clone(), new),
+            |list: &mut BTreeSet<u64>| {
+                list.insert(table_id);
+            },
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
 #[tonic::async_trait]
 pub(crate) trait UndropTableStrategy {
     fn table_name_ident(&self) -> &TableNameIdent;
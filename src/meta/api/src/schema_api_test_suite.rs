@@ -45,6 +45,8 @@ use databend_common_meta_app::schema::index_id_ident::IndexIdIdent;
 use databend_common_meta_app::schema::index_id_to_name_ident::IndexIdToNameIdent;
 use databend_common_meta_app::schema::least_visible_time_ident::LeastVisibleTimeIdent;
 use databend_common_meta_app::schema::table_niv::TableNIV;
+use databend_common_meta_app::schema::AlterSequenceAction;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CatalogMeta;
 use databend_common_meta_app::schema::CatalogNameIdent;
 use databend_common_meta_app::schema::CatalogOption;
@@ -110,6 +112,7 @@ use databend_common_meta_app::schema::TableIdHistoryIdent;
 use databend_common_meta_app::schema::TableIdList;
 use databend_common_meta_app::schema::TableIdToName;
 use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
@@ -5538,6 +5541,9 @@ impl SchemaApiTestSuite {
                 ident: SequenceIdent::new(&tenant, sequence_name),
                 create_on,
                 comment: Some("seq".to_string()),
+                start: 1,
+                step: 1,
+                cache: 0,
             };
 
             let _resp = mt.create_sequence(req).await?;
@@ -5579,6 +5585,9 @@ impl SchemaApiTestSuite {
                 ident: SequenceIdent::new(&tenant, sequence_name),
                 create_on,
                 comment: Some("seq1".to_string()),
+                start: 1,
+                step: 1,
+                cache: 0,
             };
 
             let _resp = mt.create_sequence(req).await?;
@@ -5591,6 +5600,79 @@ impl SchemaApiTestSuite {
             assert_eq!(resp.current, 1);
         }
 
+        info!("--- alter sequence increment and cache");
+        {
+            for action in [
+                AlterSequenceAction::SetIncrement(3),
+                AlterSequenceAction::SetCache(5),
+            ] {
+                let req = AlterSequenceReq {
+                    if_exists: false,
+                    ident: SequenceIdent::new(&tenant, sequence_name),
+                    action,
+                };
+                mt.alter_sequence(req).await?;
+            }
+
+            // the reserved range is extended to the cache size
+            let req = GetSequenceNextValueReq {
+                ident: SequenceIdent::new(&tenant, sequence_name),
+                count: 2,
+            };
+            let resp = mt.get_sequence_next_value(req).await?;
+            assert_eq!(resp.start, 1);
+            assert_eq!(resp.step, 3);
+            assert_eq!(resp.end, 13);
+
+            let req = GetSequenceNextValueReq {
+                ident: SequenceIdent::new(&tenant, sequence_name),
+                count: 10,
+            };
+            let resp = mt.get_sequence_next_value(req).await?;
+            assert_eq!(resp.start, 16);
+            assert_eq!(resp.end, 43);
+        }
+
+        info!("--- alter sequence restart");
+        {
+            let req = AlterSequenceReq {
+                if_exists: false,
+                ident: SequenceIdent::new(&tenant, sequence_name),
+                action: AlterSequenceAction::Restart(Some(100)),
+            };
+            mt.alter_sequence(req).await?;
+
+            let req = SequenceIdent::new(&tenant, sequence_name);
+            let resp = mt.get_sequence(&req).await?;
+            let resp = resp.unwrap().data;
+            assert_eq!(resp.current, 100);
+            assert_eq!(resp.step, 3);
+            assert_eq!(resp.cache, 5);
+
+            let req = AlterSequenceReq {
+                if_exists: false,
+                ident: SequenceIdent::new(&tenant, "unknown_seq"),
+                action: AlterSequenceAction::Restart(None),
+            };
+            let res = mt.alter_sequence(req).await;
+            assert!(res.is_err());
+
+            let req = AlterSequenceReq {
+                if_exists: true,
+                ident: SequenceIdent::new(&tenant, "unknown_seq"),
+                action: AlterSequenceAction::Restart(None),
+            };
+            mt.alter_sequence(req).await?;
+        }
+
+        info!("--- list sequences");
+        {
+            let resp = mt.list_sequences(&tenant).await?;
+            assert_eq!(resp.len(), 1);
+            assert_eq!(resp[0].0, sequence_name);
+            assert_eq!(resp[0].1.current, 100);
+        }
+
         {
             let req = DropSequenceReq {
                 ident: SequenceIdent::new(&tenant, sequence_name),
@@ -6000,6 +6082,7 @@ impl SchemaApiTestSuite {
                 column_ids: index_column_ids_1.clone(),
                 sync_creation: true,
                 options: BTreeMap::new(),
+                index_type: TableIndexType::Inverted,
             };
             let res = mt.create_table_index(req).await;
             assert!(res.is_ok());
@@ -6013,6 +6096,7 @@ impl SchemaApiTestSuite {
                 column_ids: index_column_ids_1.clone(),
                 sync_creation: true,
                 options: BTreeMap::new(),
+                index_type: TableIndexType::Inverted,
             };
             let res = mt.create_table_index(req).await;
             assert!(res.is_err());
@@ -6026,6 +6110,7 @@ impl SchemaApiTestSuite {
                 column_ids: index_column_ids_2.clone(),
                 sync_creation: true,
                 options: BTreeMap::new(),
+                index_type: TableIndexType::Inverted,
             };
             let res = mt.create_table_index(req).await;
             assert!(res.is_ok());
@@ -6041,6 +6126,7 @@ impl SchemaApiTestSuite {
                 column_ids: index_column_ids_1.clone(),
                 sync_creation: true,
                 options: BTreeMap::new(),
+                index_type: TableIndexType::Inverted,
             };
 
             let res = mt.create_table_index(req).await;
@@ -6061,6 +6147,7 @@ impl SchemaApiTestSuite {
                 column_ids: index_column_ids_1.clone(),
                 sync_creation: true,
                 options: BTreeMap::new(),
+                index_type: TableIndexType::Inverted,
             };
 
             let res = mt.create_table_index(req).await;
@@ -6077,6 +6164,7 @@ impl SchemaApiTestSuite {
                 column_ids: index_column_ids_3.clone(),
                 sync_creation: true,
                 options: BTreeMap::new(),
+                index_type: TableIndexType::Inverted,
             };
             let res = mt.create_table_index(req).await;
             assert!(res.is_err());
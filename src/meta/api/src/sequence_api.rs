@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_meta_app::schema::AlterSequenceReply;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CreateSequenceReply;
 use databend_common_meta_app::schema::CreateSequenceReq;
 use databend_common_meta_app::schema::DropSequenceReply;
@@ -20,6 +22,7 @@ use databend_common_meta_app::schema::GetSequenceNextValueReply;
 use databend_common_meta_app::schema::GetSequenceNextValueReq;
 use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::SequenceMeta;
+use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::MetaError;
 use databend_common_meta_types::SeqV;
 
@@ -37,10 +40,18 @@ pub trait SequenceApi: Send + Sync {
         req: &SequenceIdent,
     ) -> Result<Option<SeqV<SequenceMeta>>, MetaError>;
 
+    async fn list_sequences(
+        &self,
+        tenant: &Tenant,
+    ) -> Result<Vec<(String, SequenceMeta)>, MetaError>;
+
     async fn get_sequence_next_value(
         &self,
         req: GetSequenceNextValueReq,
     ) -> Result<GetSequenceNextValueReply, KVAppError>;
 
+    async fn alter_sequence(&self, req: AlterSequenceReq)
+    -> Result<AlterSequenceReply, KVAppError>;
+
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply, KVAppError>;
 }
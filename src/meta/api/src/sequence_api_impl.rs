@@ -17,6 +17,9 @@ use databend_common_meta_app::app_error::AppError;
 use databend_common_meta_app::app_error::OutofSequenceRange;
 use databend_common_meta_app::app_error::SequenceError;
 use databend_common_meta_app::app_error::WrongSequenceCount;
+use databend_common_meta_app::schema::AlterSequenceAction;
+use databend_common_meta_app::schema::AlterSequenceReply;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateSequenceReply;
 use databend_common_meta_app::schema::CreateSequenceReq;
@@ -26,7 +29,9 @@ use databend_common_meta_app::schema::GetSequenceNextValueReply;
 use databend_common_meta_app::schema::GetSequenceNextValueReq;
 use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::SequenceMeta;
+use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::DirName;
 use databend_common_meta_types::MatchSeq;
 use databend_common_meta_types::MetaError;
 use databend_common_meta_types::SeqV;
@@ -121,20 +126,24 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
 
             let start = sequence_meta.current;
             let count = req.count;
-            if u64::MAX - sequence_meta.current < count {
+            // reserve a whole cache block if the caller asks for less.
+            let Some((end, next)) =
+                reserve_range(start, sequence_meta.step, count.max(sequence_meta.cache))
+                    .or_else(|| reserve_range(start, sequence_meta.step, count))
+            else {
                 return Err(KVAppError::AppError(AppError::SequenceError(
                     SequenceError::OutofSequenceRange(OutofSequenceRange::new(
                         sequence_name,
                         format!(
-                            "{:?}: current: {}, count: {}",
-                            sequence_name, sequence_meta.current, count
+                            "{:?}: current: {}, step: {}, count: {}",
+                            sequence_name, sequence_meta.current, sequence_meta.step, count
                         ),
                     )),
                 )));
-            }
+            };
 
             // update meta
-            sequence_meta.current += count;
+            sequence_meta.current = next;
             sequence_meta.update_on = Utc::now();
 
             let condition = vec![txn_cond_eq_seq(&ident, sequence_seq)];
@@ -160,12 +169,80 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
                 return Ok(GetSequenceNextValueReply {
                     start,
                     step: sequence_meta.step,
-                    end: sequence_meta.current - 1,
+                    end,
                 });
             }
         }
     }
 
+    async fn list_sequences(
+        &self,
+        tenant: &Tenant,
+    ) -> Result<Vec<(String, SequenceMeta)>, MetaError> {
+        debug!(req :? =(tenant); "SchemaApi: {}", func_name!());
+
+        let dir = DirName::new(SequenceIdent::new(tenant, "dummy"));
+        let sequences = self.list_pb_vec(&dir).await?;
+        Ok(sequences
+            .into_iter()
+            .map(|(ident, seq_meta)| (ident.name().to_string(), seq_meta.data))
+            .collect())
+    }
+
+    async fn alter_sequence(
+        &self,
+        req: AlterSequenceReq,
+    ) -> Result<AlterSequenceReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+
+        let ident = req.ident.clone();
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+            let Some(seq_meta) = self.get_pb(&ident).await? else {
+                if req.if_exists {
+                    return Ok(AlterSequenceReply {});
+                }
+                return Err(AppError::SequenceError(SequenceError::UnknownSequence(
+                    ident.unknown_error(func_name!()),
+                ))
+                .into());
+            };
+            let sequence_seq = seq_meta.seq;
+            let mut sequence_meta = seq_meta.data;
+
+            match req.action {
+                AlterSequenceAction::Restart(start) => {
+                    sequence_meta.current = start.unwrap_or(sequence_meta.start);
+                }
+                AlterSequenceAction::SetIncrement(step) => sequence_meta.step = step,
+                AlterSequenceAction::SetCache(cache) => sequence_meta.cache = cache,
+            }
+            sequence_meta.update_on = Utc::now();
+
+            let condition = vec![txn_cond_eq_seq(&ident, sequence_seq)];
+            let if_then = vec![txn_op_put_pb(&ident, &sequence_meta, None)?];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                ident :?= (req.ident),
+                action :? = (req.action),
+                succ = succ;
+                "alter_sequence"
+            );
+            if succ {
+                return Ok(AlterSequenceReply {});
+            }
+        }
+    }
+
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply, KVAppError> {
         debug!(req :? =(&req); "SchemaApi: {}", func_name!());
 
@@ -185,3 +262,12 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
         Ok(DropSequenceReply { prev })
     }
 }
+
+/// Returns the last value of `count` values from `start` by `step`, and the value
+/// after it, or `None` if the range does not fit in `u64`.
+fn reserve_range(start: u64, step: i64, count: u64) -> Option<(u64, u64)> {
+    let step = u64::try_from(step).ok().filter(|step| *step > 0)?;
+    let end = start.checked_add((count - 1).checked_mul(step)?)?;
+    let next = end.checked_add(step)?;
+    Some((end, next))
+}
@@ -21,6 +21,7 @@ use crate::background::job_ident;
 use crate::data_mask::data_mask_name_ident;
 use crate::principal::procedure_name_ident;
 use crate::principal::ProcedureIdentity;
+use crate::row_access_policy::row_access_policy_name_ident;
 use crate::schema::catalog_name_ident;
 use crate::schema::dictionary_name_ident;
 use crate::schema::index_name_ident;
@@ -1047,6 +1048,12 @@ pub enum AppError {
     #[error(transparent)]
     UnknownDataMask(#[from] UnknownError<data_mask_name_ident::Resource>),
 
+    #[error(transparent)]
+    RowAccessPolicyAlreadyExists(#[from] ExistError<row_access_policy_name_ident::Resource>),
+
+    #[error(transparent)]
+    UnknownRowAccessPolicy(#[from] UnknownError<row_access_policy_name_ident::Resource>),
+
     #[error(transparent)]
     BackgroundJobAlreadyExists(#[from] ExistError<job_ident::BackgroundJobName>),
 
@@ -1570,6 +1577,12 @@ impl From<AppError> for ErrorCode {
 
             AppError::DatamaskAlreadyExists(err) => ErrorCode::DatamaskAlreadyExists(err.message()),
             AppError::UnknownDataMask(err) => ErrorCode::UnknownDatamask(err.message()),
+            AppError::RowAccessPolicyAlreadyExists(err) => {
+                ErrorCode::RowAccessPolicyAlreadyExists(err.message())
+            }
+            AppError::UnknownRowAccessPolicy(err) => {
+                ErrorCode::UnknownRowAccessPolicy(err.message())
+            }
 
             AppError::BackgroundJobAlreadyExists(err) => {
                 ErrorCode::BackgroundJobAlreadyExists(err.message())
@@ -27,6 +27,7 @@ pub mod data_id;
 pub mod data_mask;
 pub mod primitive;
 pub mod principal;
+pub mod row_access_policy;
 pub mod schema;
 pub mod storage;
 pub mod tenant;
@@ -20,6 +20,7 @@ mod network_policy;
 mod ownership_info;
 mod password_policy;
 mod principal_identity;
+mod query_hint;
 pub mod role_ident;
 mod role_info;
 mod stage_file_path;
@@ -47,6 +48,7 @@ pub mod procedure_id_ident;
 pub mod procedure_id_to_name;
 pub mod procedure_identity;
 pub mod procedure_name_ident;
+pub mod query_hint_ident;
 pub mod stage_file_ident;
 pub mod tenant_ownership_object_ident;
 pub mod tenant_user_ident;
@@ -80,6 +82,8 @@ pub use procedure_id_ident::ProcedureId;
 pub use procedure_id_to_name::ProcedureIdToNameIdent;
 pub use procedure_identity::ProcedureIdentity;
 pub use procedure_name_ident::ProcedureNameIdent;
+pub use query_hint::QueryHint;
+pub use query_hint_ident::QueryHintIdent;
 pub use role_ident::RoleIdent;
 pub use role_ident::RoleIdentRaw;
 pub use role_info::RoleInfo;
@@ -19,7 +19,8 @@ use databend_common_meta_kvapi::kvapi::KeyCodec;
 
 /// [`OwnershipObject`] is used to maintain the grant object that support rename by id. Using ID over name
 /// have many benefits, it can avoid lost privileges after the object get renamed.
-/// But Stage and UDF do not support the concept of renaming and do not have ids, so names can be used.
+/// But Stage, UDF, Sequence, Connection and FileFormat do not support the concept of renaming and do
+/// not have ids, so names can be used. Procedures are overloaded by argument types, so ids are used.
 ///
 /// It could be a tenant's database, a tenant's table etc.
 /// It is in form of `__fd_object_owners/<tenant>/<object>`.
@@ -30,6 +31,10 @@ use databend_common_meta_kvapi::kvapi::KeyCodec;
 /// - `table-by-catalog-id/<catalog>/<table_id>`
 /// - `stage-by-name/<stage_name>`
 /// - `udf-by-name/<udf_name>`
+/// - `sequence-by-name/<sequence_name>`
+/// - `procedure-by-id/<procedure_id>`
+/// - `connection-by-name/<connection_name>`
+/// - `file-format-by-name/<file_format_name>`
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum OwnershipObject {
     /// used on the fuse databases
@@ -52,6 +57,22 @@ pub enum OwnershipObject {
     UDF {
         name: String,
     },
+
+    Sequence {
+        name: String,
+    },
+
+    Procedure {
+        procedure_id: u64,
+    },
+
+    Connection {
+        name: String,
+    },
+
+    FileFormat {
+        name: String,
+    },
 }
 
 impl OwnershipObject {
@@ -80,6 +101,10 @@ impl fmt::Display for OwnershipObject {
             }
             OwnershipObject::UDF { name } => write!(f, "UDF {name}"),
             OwnershipObject::Stage { name } => write!(f, "STAGE {name}"),
+            OwnershipObject::Sequence { name } => write!(f, "SEQUENCE {name}"),
+            OwnershipObject::Procedure { procedure_id } => write!(f, "PROCEDURE {procedure_id}"),
+            OwnershipObject::Connection { name } => write!(f, "CONNECTION {name}"),
+            OwnershipObject::FileFormat { name } => write!(f, "FILE FORMAT {name}"),
         }
     }
 }
@@ -119,6 +144,14 @@ impl KeyCodec for OwnershipObject {
             }
             OwnershipObject::Stage { name } => b.push_raw("stage-by-name").push_str(name),
             OwnershipObject::UDF { name } => b.push_raw("udf-by-name").push_str(name),
+            OwnershipObject::Sequence { name } => b.push_raw("sequence-by-name").push_str(name),
+            OwnershipObject::Procedure { procedure_id } => {
+                b.push_raw("procedure-by-id").push_u64(*procedure_id)
+            }
+            OwnershipObject::Connection { name } => b.push_raw("connection-by-name").push_str(name),
+            OwnershipObject::FileFormat { name } => {
+                b.push_raw("file-format-by-name").push_str(name)
+            }
         }
     }
 
@@ -165,9 +198,25 @@ impl KeyCodec for OwnershipObject {
                 let name = p.next_str()?;
                 Ok(OwnershipObject::UDF { name })
             }
+            "sequence-by-name" => {
+                let name = p.next_str()?;
+                Ok(OwnershipObject::Sequence { name })
+            }
+            "procedure-by-id" => {
+                let procedure_id = p.next_u64()?;
+                Ok(OwnershipObject::Procedure { procedure_id })
+            }
+            "connection-by-name" => {
+                let name = p.next_str()?;
+                Ok(OwnershipObject::Connection { name })
+            }
+            "file-format-by-name" => {
+                let name = p.next_str()?;
+                Ok(OwnershipObject::FileFormat { name })
+            }
             _ => Err(kvapi::KeyError::InvalidSegment {
                 i: p.index(),
-                expect: "database-by-id|database-by-catalog-id|table-by-id|table-by-catalog-id|stage-by-name|udf-by-name"
+                expect: "database-by-id|database-by-catalog-id|table-by-id|table-by-catalog-id|stage-by-name|udf-by-name|sequence-by-name|procedure-by-id|connection-by-name|file-format-by-name"
                     .to_string(),
                 got: q.to_string(),
             }),
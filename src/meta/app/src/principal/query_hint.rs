@@ -0,0 +1,36 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A query hint pins join strategy and setting overrides to every query
+/// whose normalized fingerprint matches `fingerprint`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct QueryHint {
+    /// Hex encoded hash of the query text with all literals replaced.
+    pub fingerprint: String,
+    /// The query text the hint was created for.
+    pub query: String,
+    /// Forced join order, `FIXED` keeps the join order written in the query.
+    pub join_order: Option<String>,
+    /// Forced join distribution, `BROADCAST` or `SHUFFLE`.
+    pub join_distribution: Option<String>,
+    /// Setting overrides applied before the query is planned.
+    pub settings: BTreeMap<String, String>,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tenant_key::ident::TIdent;
+
+/// Defines the meta-service key for query hint.
+pub type QueryHintIdent = TIdent<Resource>;
+
+pub use kvapi_impl::Resource;
+
+mod kvapi_impl {
+
+    use databend_common_exception::ErrorCode;
+    use databend_common_meta_kvapi::kvapi;
+
+    use crate::principal::QueryHint;
+    use crate::principal::QueryHintIdent;
+    use crate::tenant_key::errors::ExistError;
+    use crate::tenant_key::errors::UnknownError;
+    use crate::tenant_key::resource::TenantResource;
+
+    pub struct Resource;
+
+    impl TenantResource for Resource {
+        const PREFIX: &'static str = "__fd_query_hints";
+        const TYPE: &'static str = "QueryHintIdent";
+        const HAS_TENANT: bool = true;
+        type ValueType = QueryHint;
+    }
+
+    impl kvapi::Value for QueryHint {
+        type KeyType = QueryHintIdent;
+        fn dependency_keys(&self, _key: &Self::KeyType) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+
+    impl kvapi::ValueWithName for QueryHint {
+        fn name(&self) -> &str {
+            &self.fingerprint
+        }
+    }
+
+    impl From<ExistError<Resource>> for ErrorCode {
+        fn from(err: ExistError<Resource>) -> Self {
+            ErrorCode::QueryHintAlreadyExists(err.to_string())
+        }
+    }
+
+    impl From<UnknownError<Resource>> for ErrorCode {
+        fn from(err: UnknownError<Resource>) -> Self {
+            ErrorCode::UnknownQueryHint(err.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_meta_kvapi::kvapi::Key;
+
+    use crate::principal::query_hint_ident::QueryHintIdent;
+    use crate::tenant::Tenant;
+    #[test]
+    fn test_query_hint_ident() {
+        let tenant = Tenant::new_literal("test");
+        let ident = QueryHintIdent::new(tenant.clone(), "test2");
+
+        assert_eq!(ident.to_string_key(), "__fd_query_hints/test/test2");
+        assert_eq!(
+            ident,
+            QueryHintIdent::from_str_key("__fd_query_hints/test/test2").unwrap()
+        );
+    }
+}
@@ -27,6 +27,10 @@ use crate::tenant_key::raw::TIdentRaw;
 /// - `table-by-catalog-id/<catalog>/<table_id>`
 /// - `stage-by-name/<stage_name>`
 /// - `udf-by-name/<udf_name>`
+/// - `sequence-by-name/<sequence_name>`
+/// - `procedure-by-id/<procedure_id>`
+/// - `connection-by-name/<connection_name>`
+/// - `file-format-by-name/<file_format_name>`
 pub type TenantOwnershipObjectIdent = TIdent<Resource, OwnershipObject>;
 pub type TenantOwnershipObjectIdentRaw = TIdentRaw<Resource, OwnershipObject>;
 
@@ -48,6 +52,10 @@ impl TenantOwnershipObjectIdent {
         //     }
         //     OwnershipObject::Stage { .. } => {}
         //     OwnershipObject::UDF { .. } => {}
+        //     OwnershipObject::Sequence { .. } => {}
+        //     OwnershipObject::Procedure { .. } => {}
+        //     OwnershipObject::Connection { .. } => {}
+        //     OwnershipObject::FileFormat { .. } => {}
         // }
 
         Self::new_unchecked(tenant, subject)
@@ -254,6 +262,68 @@ mod tests {
             let parsed = TenantOwnershipObjectIdent::from_str_key(&key).unwrap();
             assert_eq!(role_grantee, parsed);
         }
+
+        // sequence
+        {
+            let role_grantee = TenantOwnershipObjectIdent::new_unchecked(
+                Tenant::new_literal("test"),
+                OwnershipObject::Sequence {
+                    name: "foo".to_string(),
+                },
+            );
+
+            let key = role_grantee.to_string_key();
+            assert_eq!("__fd_object_owners/test/sequence-by-name/foo", key);
+
+            let parsed = TenantOwnershipObjectIdent::from_str_key(&key).unwrap();
+            assert_eq!(role_grantee, parsed);
+        }
+
+        // procedure
+        {
+            let role_grantee = TenantOwnershipObjectIdent::new_unchecked(
+                Tenant::new_literal("test"),
+                OwnershipObject::Procedure { procedure_id: 5 },
+            );
+
+            let key = role_grantee.to_string_key();
+            assert_eq!("__fd_object_owners/test/procedure-by-id/5", key);
+
+            let parsed = TenantOwnershipObjectIdent::from_str_key(&key).unwrap();
+            assert_eq!(role_grantee, parsed);
+        }
+
+        // connection
+        {
+            let role_grantee = TenantOwnershipObjectIdent::new_unchecked(
+                Tenant::new_literal("test"),
+                OwnershipObject::Connection {
+                    name: "foo".to_string(),
+                },
+            );
+
+            let key = role_grantee.to_string_key();
+            assert_eq!("__fd_object_owners/test/connection-by-name/foo", key);
+
+            let parsed = TenantOwnershipObjectIdent::from_str_key(&key).unwrap();
+            assert_eq!(role_grantee, parsed);
+        }
+
+        // file format
+        {
+            let role_grantee = TenantOwnershipObjectIdent::new_unchecked(
+                Tenant::new_literal("test"),
+                OwnershipObject::FileFormat {
+                    name: "foo".to_string(),
+                },
+            );
+
+            let key = role_grantee.to_string_key();
+            assert_eq!("__fd_object_owners/test/file-format-by-name/foo", key);
+
+            let parsed = TenantOwnershipObjectIdent::from_str_key(&key).unwrap();
+            assert_eq!(role_grantee, parsed);
+        }
     }
 
     #[test]
@@ -23,6 +23,7 @@ const NO_PASSWORD_STR: &str = "no_password";
 const SHA256_PASSWORD_STR: &str = "sha256_password";
 const DOUBLE_SHA1_PASSWORD_STR: &str = "double_sha1_password";
 const JWT_AUTH_STR: &str = "jwt";
+const LDAP_AUTH_STR: &str = "ldap";
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub enum AuthType {
@@ -30,6 +31,7 @@ pub enum AuthType {
     Sha256Password,
     DoubleSha1Password,
     JWT,
+    Ldap,
 }
 
 impl FromStr for AuthType {
@@ -41,6 +43,7 @@ impl FromStr for AuthType {
             DOUBLE_SHA1_PASSWORD_STR => Ok(AuthType::DoubleSha1Password),
             NO_PASSWORD_STR => Ok(AuthType::NoPassword),
             JWT_AUTH_STR => Ok(AuthType::JWT),
+            LDAP_AUTH_STR => Ok(AuthType::Ldap),
             _ => Err(ErrorCode::AuthenticateFailure(AuthType::bad_auth_types(s))),
         }
     }
@@ -53,6 +56,7 @@ impl AuthType {
             AuthType::Sha256Password => SHA256_PASSWORD_STR,
             AuthType::DoubleSha1Password => DOUBLE_SHA1_PASSWORD_STR,
             AuthType::JWT => JWT_AUTH_STR,
+            AuthType::Ldap => LDAP_AUTH_STR,
         }
     }
 
@@ -62,6 +66,7 @@ impl AuthType {
             SHA256_PASSWORD_STR,
             DOUBLE_SHA1_PASSWORD_STR,
             JWT_AUTH_STR,
+            LDAP_AUTH_STR,
         ];
         let all = all
             .iter()
@@ -87,6 +92,7 @@ impl From<databend_common_ast::ast::AuthType> for AuthType {
             databend_common_ast::ast::AuthType::Sha256Password => AuthType::Sha256Password,
             databend_common_ast::ast::AuthType::DoubleSha1Password => AuthType::DoubleSha1Password,
             databend_common_ast::ast::AuthType::JWT => AuthType::JWT,
+            databend_common_ast::ast::AuthType::Ldap => AuthType::Ldap,
         }
     }
 }
@@ -103,6 +109,8 @@ pub enum AuthInfo {
         need_change: bool,
     },
     JWT,
+    /// The password is verified by a bind against the configured LDAP server.
+    Ldap,
 }
 
 fn calc_sha1(v: &[u8]) -> [u8; 20] {
@@ -124,6 +132,7 @@ impl AuthInfo {
         match auth_type {
             AuthType::NoPassword => Ok(AuthInfo::None),
             AuthType::JWT => Ok(AuthInfo::JWT),
+            AuthType::Ldap => Ok(AuthInfo::Ldap),
             AuthType::Sha256Password | AuthType::DoubleSha1Password => match auth_string {
                 Some(p) => {
                     let method = auth_type.get_password_type().unwrap();
@@ -204,6 +213,7 @@ impl AuthInfo {
         match self {
             AuthInfo::None => AuthType::NoPassword,
             AuthInfo::JWT => AuthType::JWT,
+            AuthInfo::Ldap => AuthType::Ldap,
             AuthInfo::Password { hash_method: t, .. } => match t {
                 PasswordHashMethod::Sha256 => AuthType::Sha256Password,
                 PasswordHashMethod::DoubleSha1 => AuthType::DoubleSha1Password,
@@ -215,6 +225,7 @@ impl AuthInfo {
         match self {
             AuthInfo::None => false,
             AuthInfo::JWT => false,
+            AuthInfo::Ldap => false,
             AuthInfo::Password { need_change, .. } => *need_change,
         }
     }
@@ -226,7 +237,7 @@ impl AuthInfo {
                 hash_method: t,
                 ..
             } => t.to_string(p),
-            AuthInfo::None | AuthInfo::JWT => "".to_string(),
+            AuthInfo::None | AuthInfo::JWT | AuthInfo::Ldap => "".to_string(),
         }
     }
 
@@ -291,6 +302,22 @@ impl AuthInfo {
             ))),
         }
     }
+
+    /// Verifies a cleartext password, e.g. sent with the `mysql_clear_password` auth plugin.
+    pub fn auth_cleartext(&self, password_input: &[u8]) -> Result<bool> {
+        match self {
+            AuthInfo::None => Ok(true),
+            AuthInfo::Password {
+                hash_value: p,
+                hash_method: t,
+                ..
+            } => Ok(*p == t.hash(password_input)),
+            _ => Err(ErrorCode::AuthenticateFailure(format!(
+                "user require auth type {}",
+                self.get_type().to_str()
+            ))),
+        }
+    }
 }
 
 #[derive(
@@ -30,11 +30,22 @@ pub enum GrantObject {
     TableById(String, u64, u64),
     UDF(String),
     Stage(String),
+    /// A single column of a table: catalog, database id, table id and column id.
+    ///
+    /// The column is kept by id, so the privileges follow a renamed column,
+    /// and are not inherited by a new column with the name of a dropped one.
+    Column(String, u64, u64, u32),
+    Sequence(String),
+    /// Procedures are overloaded by argument types, so they are granted by id.
+    Procedure(u64),
+    Connection(String),
+    FileFormat(String),
 }
 
 impl GrantObject {
     /// Comparing the grant objects, the Database object contains all the Table objects inside it.
     /// Global object contains all the Database objects.
+    /// A Table object contains all the Column objects of it.
     pub fn contains(&self, object: &GrantObject) -> bool {
         match (self, object) {
             (GrantObject::Global, _) => true,
@@ -59,9 +70,31 @@ impl GrantObject {
                 GrantObject::TableById(lcat, lhs_db, lhs_table),
                 GrantObject::TableById(rcat, rhs_db, rhs_table),
             ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
+            (
+                GrantObject::TableById(lcat, lhs_db, lhs_table),
+                GrantObject::Column(rcat, rhs_db, rhs_table, _),
+            ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
+            (GrantObject::DatabaseById(lcat, ldb), GrantObject::Column(rcat, rdb, _, _)) => {
+                lcat == rcat && ldb == rdb
+            }
+            (
+                GrantObject::Column(lcat, lhs_db, lhs_table, lhs_column),
+                GrantObject::Column(rcat, rhs_db, rhs_table, rhs_column),
+            ) => {
+                lcat == rcat
+                    && (lhs_db == rhs_db)
+                    && (lhs_table == rhs_table)
+                    && (lhs_column == rhs_column)
+            }
             (GrantObject::Table(_, _, _), _) => false,
             (GrantObject::Stage(lstage), GrantObject::Stage(rstage)) => lstage == rstage,
             (GrantObject::UDF(udf), GrantObject::UDF(rudf)) => udf == rudf,
+            (GrantObject::Sequence(lseq), GrantObject::Sequence(rseq)) => lseq == rseq,
+            (GrantObject::Procedure(lid), GrantObject::Procedure(rid)) => lid == rid,
+            (GrantObject::Connection(lconn), GrantObject::Connection(rconn)) => lconn == rconn,
+            (GrantObject::FileFormat(lformat), GrantObject::FileFormat(rformat)) => {
+                lformat == rformat
+            }
             _ => false,
         }
     }
@@ -76,20 +109,40 @@ impl GrantObject {
             GrantObject::Table(_, _, _) | GrantObject::TableById(_, _, _) => {
                 UserPrivilegeSet::available_privileges_on_table(available_ownership)
             }
+            GrantObject::Column(_, _, _, _) => UserPrivilegeSet::available_privileges_on_column(),
             GrantObject::UDF(_) => {
                 UserPrivilegeSet::available_privileges_on_udf(available_ownership)
             }
             GrantObject::Stage(_) => {
                 UserPrivilegeSet::available_privileges_on_stage(available_ownership)
             }
+            GrantObject::Sequence(_) => {
+                UserPrivilegeSet::available_privileges_on_sequence(available_ownership)
+            }
+            GrantObject::Procedure(_) => {
+                UserPrivilegeSet::available_privileges_on_procedure(available_ownership)
+            }
+            GrantObject::Connection(_) => {
+                UserPrivilegeSet::available_privileges_on_connection(available_ownership)
+            }
+            GrantObject::FileFormat(_) => {
+                UserPrivilegeSet::available_privileges_on_file_format(available_ownership)
+            }
         }
     }
 
     pub fn catalog(&self) -> Option<String> {
         match self {
-            GrantObject::Global | GrantObject::Stage(_) | GrantObject::UDF(_) => None,
+            GrantObject::Global
+            | GrantObject::Stage(_)
+            | GrantObject::UDF(_)
+            | GrantObject::Sequence(_)
+            | GrantObject::Procedure(_)
+            | GrantObject::Connection(_)
+            | GrantObject::FileFormat(_) => None,
             GrantObject::Database(cat, _) | GrantObject::DatabaseById(cat, _) => Some(cat.clone()),
             GrantObject::Table(cat, _, _) | GrantObject::TableById(cat, _, _) => Some(cat.clone()),
+            GrantObject::Column(cat, _, _, _) => Some(cat.clone()),
         }
     }
 }
@@ -106,8 +159,15 @@ impl fmt::Display for GrantObject {
             GrantObject::TableById(ref cat, ref db, ref table) => {
                 write!(f, "'{}'.'{}'.'{}'", cat, db, table)
             }
+            GrantObject::Column(ref cat, ref db, ref table, ref column) => {
+                write!(f, "'{}'.'{}'.'{}'.'{}'", cat, db, table, column)
+            }
             GrantObject::UDF(udf) => write!(f, "UDF {udf}"),
             GrantObject::Stage(stage) => write!(f, "STAGE {stage}"),
+            GrantObject::Sequence(sequence) => write!(f, "SEQUENCE {sequence}"),
+            GrantObject::Procedure(procedure_id) => write!(f, "PROCEDURE {procedure_id}"),
+            GrantObject::Connection(connection) => write!(f, "CONNECTION {connection}"),
+            GrantObject::FileFormat(file_format) => write!(f, "FILE FORMAT {file_format}"),
         }
     }
 }
@@ -189,6 +189,8 @@ pub struct UserOption {
     password_policy: Option<String>,
     disabled: Option<bool>,
     must_change_password: Option<bool>,
+    rsa_public_key: Option<String>,
+    rsa_public_key_2: Option<String>,
 }
 
 impl UserOption {
@@ -200,6 +202,8 @@ impl UserOption {
             password_policy: None,
             disabled: None,
             must_change_password: None,
+            rsa_public_key: None,
+            rsa_public_key_2: None,
         }
     }
 
@@ -237,6 +241,16 @@ impl UserOption {
         self
     }
 
+    pub fn with_rsa_public_key(mut self, rsa_public_key: Option<String>) -> Self {
+        self.rsa_public_key = rsa_public_key;
+        self
+    }
+
+    pub fn with_rsa_public_key_2(mut self, rsa_public_key_2: Option<String>) -> Self {
+        self.rsa_public_key_2 = rsa_public_key_2;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -266,6 +280,22 @@ impl UserOption {
         self.must_change_password.as_ref()
     }
 
+    pub fn rsa_public_key(&self) -> Option<&String> {
+        self.rsa_public_key.as_ref()
+    }
+
+    pub fn rsa_public_key_2(&self) -> Option<&String> {
+        self.rsa_public_key_2.as_ref()
+    }
+
+    /// All the registered public keys, used to verify key-pair JWTs.
+    pub fn public_keys(&self) -> Vec<&String> {
+        self.rsa_public_key
+            .iter()
+            .chain(self.rsa_public_key_2.iter())
+            .collect()
+    }
+
     pub fn set_default_role(&mut self, default_role: Option<String>) {
         self.default_role = default_role;
     }
@@ -326,6 +356,10 @@ impl UserOption {
             UserOptionItem::UnsetPasswordPolicy => self.password_policy = None,
             UserOptionItem::Disabled(v) => self.disabled = Some(*v),
             UserOptionItem::MustChangePassword(v) => self.must_change_password = Some(*v),
+            UserOptionItem::SetRsaPublicKey(v) => self.rsa_public_key = Some(v.clone()),
+            UserOptionItem::UnsetRsaPublicKey => self.rsa_public_key = None,
+            UserOptionItem::SetRsaPublicKey2(v) => self.rsa_public_key_2 = Some(v.clone()),
+            UserOptionItem::UnsetRsaPublicKey2 => self.rsa_public_key_2 = None,
         }
     }
 }
@@ -240,6 +240,27 @@ impl UserPrivilegeSet {
         }
     }
 
+    pub fn available_privileges_on_sequence(available_ownership: bool) -> Self {
+        Self::available_privileges_on_udf(available_ownership)
+    }
+
+    pub fn available_privileges_on_procedure(available_ownership: bool) -> Self {
+        Self::available_privileges_on_udf(available_ownership)
+    }
+
+    pub fn available_privileges_on_connection(available_ownership: bool) -> Self {
+        Self::available_privileges_on_udf(available_ownership)
+    }
+
+    pub fn available_privileges_on_file_format(available_ownership: bool) -> Self {
+        Self::available_privileges_on_udf(available_ownership)
+    }
+
+    /// Columns can only be granted the privileges that read or write the column values.
+    pub fn available_privileges_on_column() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Select | Insert | Update }).into()
+    }
+
     // TODO: remove this, as ALL has different meanings on different objects
     pub fn all_privileges() -> Self {
         ALL_PRIVILEGES.into()
@@ -107,6 +107,9 @@ pub use table::RenameTableReq;
 pub use table::SetTableColumnMaskPolicyAction;
 pub use table::SetTableColumnMaskPolicyReply;
 pub use table::SetTableColumnMaskPolicyReq;
+pub use table::SetTableRowAccessPolicyAction;
+pub use table::SetTableRowAccessPolicyReply;
+pub use table::SetTableRowAccessPolicyReq;
 pub use table::TableCopiedFileInfo;
 pub use table::TableCopiedFileNameIdent;
 pub use table::TableId;
@@ -115,9 +118,11 @@ pub use table::TableIdList;
 pub use table::TableIdToName;
 pub use table::TableIdent;
 pub use table::TableIndex;
+pub use table::TableIndexType;
 pub use table::TableInfo;
 pub use table::TableMeta;
 pub use table::TableNameIdent;
+pub use table::TableRowAccessPolicy;
 pub use table::TableStatistics;
 pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
@@ -19,6 +19,19 @@ pub use kvapi_impl::SequenceRsc;
 use super::CreateOption;
 use crate::tenant_key::ident::TIdent;
 
+/// Name prefix of the hidden sequences that back `AUTOINCREMENT` columns.
+pub const AUTO_INCREMENT_SEQUENCE_PREFIX: &str = "_sys_identity_";
+
+/// `CACHE` of the hidden sequences, a query node reserves the values of an
+/// `AUTOINCREMENT` column from meta in batches of at least this size.
+pub const AUTO_INCREMENT_SEQUENCE_CACHE: u64 = 1000;
+
+/// Returns whether the sequence backs an `AUTOINCREMENT` column, such sequences
+/// are owned by the table and are not accessible by user DDL.
+pub fn is_auto_increment_sequence(name: &str) -> bool {
+    name.starts_with(AUTO_INCREMENT_SEQUENCE_PREFIX)
+}
+
 /// Defines the meta-service key for sequence.
 pub type SequenceIdent = TIdent<SequenceRsc>;
 
@@ -30,6 +43,8 @@ pub struct SequenceMeta {
     pub start: u64,
     pub step: i64,
     pub current: u64,
+    /// Number of values a query node reserves from meta at once, `0` means no cache.
+    pub cache: u64,
 }
 
 impl From<CreateSequenceReq> for SequenceMeta {
@@ -38,9 +53,10 @@ impl From<CreateSequenceReq> for SequenceMeta {
             comment: p.comment.clone(),
             create_on: p.create_on,
             update_on: p.create_on,
-            start: 1,
-            step: 1,
-            current: 1,
+            start: p.start,
+            step: p.step,
+            current: p.start,
+            cache: p.cache,
         }
     }
 }
@@ -51,6 +67,9 @@ pub struct CreateSequenceReq {
     pub ident: SequenceIdent,
     pub create_on: DateTime<Utc>,
     pub comment: Option<String>,
+    pub start: u64,
+    pub step: i64,
+    pub cache: u64,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -62,10 +81,13 @@ pub struct GetSequenceNextValueReq {
     pub count: u64,
 }
 
+/// The values `start, start + step, ..., end` are reserved for the caller.
+///
+/// If the sequence has a cache larger than the requested count, the reserved range
+/// is extended to the cache size, the extra values can be kept by the caller.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GetSequenceNextValueReply {
     pub start: u64,
-    // step has no use until now
     pub step: i64,
     pub end: u64,
 }
@@ -80,6 +102,24 @@ pub struct GetSequenceReply {
     pub meta: SequenceMeta,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AlterSequenceAction {
+    /// Reset the next value, to `start` of the sequence if `None`.
+    Restart(Option<u64>),
+    SetIncrement(i64),
+    SetCache(u64),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterSequenceReq {
+    pub if_exists: bool,
+    pub ident: SequenceIdent,
+    pub action: AlterSequenceAction,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterSequenceReply {}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropSequenceReq {
     pub if_exists: bool,
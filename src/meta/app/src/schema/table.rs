@@ -277,11 +277,46 @@ pub struct TableMeta {
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
+    pub row_access_policy: Option<TableRowAccessPolicy>,
     pub indexes: BTreeMap<String, TableIndex>,
 }
 
+/// The row access policy attached to a table, and the table columns passed to
+/// the policy as arguments, in the order of the policy arguments.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableRowAccessPolicy {
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TableIndexType {
+    #[default]
+    Inverted = 0,
+    Vector = 1,
+}
+
+impl Display for TableIndexType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TableIndexType::Inverted => write!(f, "INVERTED"),
+            TableIndexType::Vector => write!(f, "VECTOR"),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct TableIndex {
+    pub index_type: TableIndexType,
     pub name: String,
     pub column_ids: Vec<u32>,
     // if true, index will create after data written to databend,
@@ -425,6 +460,7 @@ impl Default for TableMeta {
             statistics: Default::default(),
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
+            row_access_policy: None,
             indexes: BTreeMap::new(),
         }
     }
@@ -835,6 +871,25 @@ pub struct SetTableColumnMaskPolicyReq {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SetTableColumnMaskPolicyReply {}
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SetTableRowAccessPolicyAction {
+    // new policy, old policy name(if any)
+    Set(TableRowAccessPolicy, Option<String>),
+    // prev policy name
+    Unset(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReq {
+    pub tenant: Tenant,
+    pub table_id: u64,
+    pub seq: MatchSeq,
+    pub action: SetTableRowAccessPolicyAction,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReply {}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UpsertTableOptionReply {}
 
@@ -850,6 +905,7 @@ pub struct CreateTableIndexReq {
     pub column_ids: Vec<u32>,
     pub sync_creation: bool,
     pub options: BTreeMap<String, String>,
+    pub index_type: TableIndexType,
 }
 
 impl Display for CreateTableIndexReq {
@@ -862,8 +918,8 @@ impl Display for CreateTableIndexReq {
 
         write!(
             f,
-            "{}: {} ColumnIds: {:?}, SyncCreation: {:?}, Options: {:?}",
-            typ, self.name, self.column_ids, self.sync_creation, self.options,
+            "{}: {} IndexType: {}, ColumnIds: {:?}, SyncCreation: {:?}, Options: {:?}",
+            typ, self.name, self.index_type, self.column_ids, self.sync_creation, self.options,
         )
     }
 }
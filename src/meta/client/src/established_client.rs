@@ -20,6 +20,11 @@ use databend_common_meta_types::protobuf::ClientInfo;
 use databend_common_meta_types::protobuf::ClusterStatus;
 use databend_common_meta_types::protobuf::Empty;
 use databend_common_meta_types::protobuf::ExportedChunk;
+use databend_common_meta_types::protobuf::LeaseGrantRequest;
+use databend_common_meta_types::protobuf::LeaseKeepAliveReply;
+use databend_common_meta_types::protobuf::LeaseKeepAliveRequest;
+use databend_common_meta_types::protobuf::LeaseReply;
+use databend_common_meta_types::protobuf::LeaseRevokeRequest;
 use databend_common_meta_types::protobuf::MemberListReply;
 use databend_common_meta_types::protobuf::MemberListRequest;
 use databend_common_meta_types::protobuf::RaftReply;
@@ -190,6 +195,30 @@ impl EstablishedClient {
         self.client.transaction(request).await.update_client(self)
     }
 
+    pub async fn lease_grant(
+        &mut self,
+        request: impl tonic::IntoRequest<LeaseGrantRequest>,
+    ) -> Result<Response<LeaseReply>, Status> {
+        self.client.lease_grant(request).await.update_client(self)
+    }
+
+    pub async fn lease_keep_alive(
+        &mut self,
+        request: impl tonic::IntoRequest<LeaseKeepAliveRequest>,
+    ) -> Result<Response<LeaseKeepAliveReply>, Status> {
+        self.client
+            .lease_keep_alive(request)
+            .await
+            .update_client(self)
+    }
+
+    pub async fn lease_revoke(
+        &mut self,
+        request: impl tonic::IntoRequest<LeaseRevokeRequest>,
+    ) -> Result<Response<LeaseReply>, Status> {
+        self.client.lease_revoke(request).await.update_client(self)
+    }
+
     pub async fn member_list(
         &mut self,
         request: impl tonic::IntoRequest<MemberListRequest>,
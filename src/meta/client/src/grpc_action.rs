@@ -26,6 +26,11 @@ use databend_common_meta_kvapi::kvapi::UpsertKVReply;
 use databend_common_meta_kvapi::kvapi::UpsertKVReq;
 use databend_common_meta_types::protobuf::ClientInfo;
 use databend_common_meta_types::protobuf::ClusterStatus;
+use databend_common_meta_types::protobuf::LeaseGrantRequest;
+use databend_common_meta_types::protobuf::LeaseKeepAliveReply;
+use databend_common_meta_types::protobuf::LeaseKeepAliveRequest;
+use databend_common_meta_types::protobuf::LeaseReply;
+use databend_common_meta_types::protobuf::LeaseRevokeRequest;
 use databend_common_meta_types::protobuf::RaftRequest;
 use databend_common_meta_types::protobuf::StreamItem;
 use databend_common_meta_types::protobuf::WatchRequest;
@@ -215,6 +220,18 @@ impl RequestFor for TxnRequest {
     type Reply = TxnReply;
 }
 
+impl RequestFor for LeaseGrantRequest {
+    type Reply = LeaseReply;
+}
+
+impl RequestFor for LeaseKeepAliveRequest {
+    type Reply = LeaseKeepAliveReply;
+}
+
+impl RequestFor for LeaseRevokeRequest {
+    type Reply = LeaseReply;
+}
+
 impl RequestFor for GetClusterStatus {
     type Reply = ClusterStatus;
 }
@@ -51,6 +51,11 @@ use databend_common_meta_types::protobuf::ClusterStatus;
 use databend_common_meta_types::protobuf::Empty;
 use databend_common_meta_types::protobuf::ExportedChunk;
 use databend_common_meta_types::protobuf::HandshakeRequest;
+use databend_common_meta_types::protobuf::LeaseGrantRequest;
+use databend_common_meta_types::protobuf::LeaseKeepAliveReply;
+use databend_common_meta_types::protobuf::LeaseKeepAliveRequest;
+use databend_common_meta_types::protobuf::LeaseReply;
+use databend_common_meta_types::protobuf::LeaseRevokeRequest;
 use databend_common_meta_types::protobuf::MemberListReply;
 use databend_common_meta_types::protobuf::MemberListRequest;
 use databend_common_meta_types::protobuf::RaftRequest;
@@ -370,6 +375,32 @@ impl ClientHandle {
         self.request(message::MakeEstablishedClient {}).await
     }
 
+    /// Grant a lease that expires after `ttl` unless it is kept alive.
+    ///
+    /// If `lease_id` is 0, the meta-service assigns a new id, which is returned in the reply.
+    pub async fn lease_grant(&self, lease_id: u64, ttl: Duration) -> Result<LeaseReply, MetaError> {
+        self.request(LeaseGrantRequest {
+            lease_id,
+            ttl_ms: ttl.as_millis() as u64,
+        })
+        .await
+    }
+
+    /// Extend the expiration time of the leases and the keys bound to them in one raft-log.
+    ///
+    /// The reply contains one entry for every lease, `ttl_ms` is 0 if the lease does not exist.
+    pub async fn lease_keep_alive(
+        &self,
+        lease_ids: Vec<u64>,
+    ) -> Result<LeaseKeepAliveReply, MetaError> {
+        self.request(LeaseKeepAliveRequest { lease_ids }).await
+    }
+
+    /// Revoke a lease and delete the keys bound to it.
+    pub async fn lease_revoke(&self, lease_id: u64) -> Result<LeaseReply, MetaError> {
+        self.request(LeaseRevokeRequest { lease_id }).await
+    }
+
     /// Return the endpoints list cached on this client.
     pub async fn get_cached_endpoints(&self) -> Result<Vec<String>, MetaError> {
         self.request(message::GetEndpoints {}).await
@@ -573,6 +604,30 @@ impl MetaGrpcClient {
                     .await;
                 Response::Txn(resp)
             }
+            message::Request::LeaseGrant(r) => {
+                let resp = self
+                    .lease_grant(r)
+                    .with_timing_threshold(threshold(), info_spent("MetaGrpcClient::lease_grant"))
+                    .await;
+                Response::Lease(resp)
+            }
+            message::Request::LeaseKeepAlive(r) => {
+                let resp = self
+                    .lease_keep_alive(r)
+                    .with_timing_threshold(
+                        threshold(),
+                        info_spent("MetaGrpcClient::lease_keep_alive"),
+                    )
+                    .await;
+                Response::LeaseKeepAlive(resp)
+            }
+            message::Request::LeaseRevoke(r) => {
+                let resp = self
+                    .lease_revoke(r)
+                    .with_timing_threshold(threshold(), info_spent("MetaGrpcClient::lease_revoke"))
+                    .await;
+                Response::Lease(resp)
+            }
             message::Request::Watch(r) => {
                 let resp = self.watch(r).await;
                 Response::Watch(resp)
@@ -965,6 +1020,62 @@ impl MetaGrpcClient {
         Ok(res.into_inner())
     }
 
+    /// The lease API is served since meta-service 1.2.600.
+    fn check_lease_supported(client: &EstablishedClient) -> Result<(), MetaError> {
+        let min_ver = 1002600;
+        if client.server_protocol_version() < min_ver {
+            let status = Status::unimplemented(format!(
+                "lease is not supported by meta-service {}, requires {} or above",
+                from_digit_ver(client.server_protocol_version()),
+                from_digit_ver(min_ver)
+            ));
+            return Err(status.into());
+        }
+        Ok(())
+    }
+
+    #[fastrace::trace]
+    pub(crate) async fn lease_grant(
+        &self,
+        req: LeaseGrantRequest,
+    ) -> Result<LeaseReply, MetaError> {
+        debug!("MetaGrpcClient::lease_grant: {:?}", req);
+
+        let mut client = self.get_established_client().await?;
+        Self::check_lease_supported(&client)?;
+
+        let res = client.lease_grant(traced_req(req)).await?;
+        Ok(res.into_inner())
+    }
+
+    #[fastrace::trace]
+    pub(crate) async fn lease_keep_alive(
+        &self,
+        req: LeaseKeepAliveRequest,
+    ) -> Result<LeaseKeepAliveReply, MetaError> {
+        debug!("MetaGrpcClient::lease_keep_alive: {:?}", req);
+
+        let mut client = self.get_established_client().await?;
+        Self::check_lease_supported(&client)?;
+
+        let res = client.lease_keep_alive(traced_req(req)).await?;
+        Ok(res.into_inner())
+    }
+
+    #[fastrace::trace]
+    pub(crate) async fn lease_revoke(
+        &self,
+        req: LeaseRevokeRequest,
+    ) -> Result<LeaseReply, MetaError> {
+        debug!("MetaGrpcClient::lease_revoke: {:?}", req);
+
+        let mut client = self.get_established_client().await?;
+        Self::check_lease_supported(&client)?;
+
+        let res = client.lease_revoke(traced_req(req)).await?;
+        Ok(res.into_inner())
+    }
+
     /// Export all data in json from metasrv.
     #[fastrace::trace]
     pub(crate) async fn get_client_info(&self) -> Result<ClientInfo, MetaError> {
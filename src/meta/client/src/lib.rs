@@ -105,6 +105,12 @@ pub static METACLI_COMMIT_SEMVER: LazyLock<Version> = LazyLock::new(|| {
 /// - 2024-03-04: since: 1.2.361
 ///   👥 client: `MetaSpec` use `ttl`, remove `expire_at`, require 1.2.258
 ///
+/// - 2026-10-19: since: 1.2.600
+///   🖥 server: add: lease_grant(), lease_keep_alive() and lease_revoke(),
+///              `TxnPutRequest.lease_id` and `MetaSpec.lease` to bind a record to a lease;
+///              served only if `raft_config.enable_lease` is set.
+///   👥 client: call the lease API only if server >= 1.2.600.
+///
 /// Server feature set:
 /// ```yaml
 /// server_features:
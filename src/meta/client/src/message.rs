@@ -24,6 +24,11 @@ use databend_common_meta_kvapi::kvapi::UpsertKVReq;
 use databend_common_meta_types::protobuf::ClientInfo;
 use databend_common_meta_types::protobuf::ClusterStatus;
 use databend_common_meta_types::protobuf::ExportedChunk;
+use databend_common_meta_types::protobuf::LeaseGrantRequest;
+use databend_common_meta_types::protobuf::LeaseKeepAliveReply;
+use databend_common_meta_types::protobuf::LeaseKeepAliveRequest;
+use databend_common_meta_types::protobuf::LeaseReply;
+use databend_common_meta_types::protobuf::LeaseRevokeRequest;
 use databend_common_meta_types::protobuf::StreamItem;
 use databend_common_meta_types::protobuf::WatchRequest;
 use databend_common_meta_types::protobuf::WatchResponse;
@@ -86,6 +91,15 @@ pub enum Request {
     /// Run a transaction on remote
     Txn(TxnRequest),
 
+    /// Grant a lease
+    LeaseGrant(LeaseGrantRequest),
+
+    /// Keep leases alive
+    LeaseKeepAlive(LeaseKeepAliveRequest),
+
+    /// Revoke a lease and delete the keys bound to it
+    LeaseRevoke(LeaseRevokeRequest),
+
     /// Watch KV changes, expecting a Stream that reports KV change events
     Watch(WatchRequest),
 
@@ -112,6 +126,9 @@ impl Request {
             Request::StreamList(_) => "StreamList",
             Request::Upsert(_) => "Upsert",
             Request::Txn(_) => "Txn",
+            Request::LeaseGrant(_) => "LeaseGrant",
+            Request::LeaseKeepAlive(_) => "LeaseKeepAlive",
+            Request::LeaseRevoke(_) => "LeaseRevoke",
             Request::Watch(_) => "Watch",
             Request::Export(_) => "Export",
             Request::MakeEstablishedClient(_) => "MakeClient",
@@ -129,6 +146,8 @@ pub enum Response {
     StreamList(Result<BoxStream<StreamItem>, MetaError>),
     Upsert(Result<UpsertKVReply, MetaError>),
     Txn(Result<TxnReply, MetaError>),
+    Lease(Result<LeaseReply, MetaError>),
+    LeaseKeepAlive(Result<LeaseKeepAliveReply, MetaError>),
     Watch(Result<tonic::codec::Streaming<WatchResponse>, MetaError>),
     Export(Result<tonic::codec::Streaming<ExportedChunk>, MetaError>),
     MakeEstablishedClient(Result<EstablishedClient, MetaClientError>),
@@ -152,6 +171,12 @@ impl fmt::Debug for Response {
             Response::Txn(x) => {
                 write!(f, "Txn({:?})", x)
             }
+            Response::Lease(x) => {
+                write!(f, "Lease({:?})", x)
+            }
+            Response::LeaseKeepAlive(x) => {
+                write!(f, "LeaseKeepAlive({:?})", x)
+            }
             Response::Watch(x) => {
                 write!(f, "Watch({:?})", x)
             }
@@ -193,6 +218,14 @@ impl Response {
                 .as_ref()
                 .err()
                 .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::Lease(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::LeaseKeepAlive(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
             Response::Watch(res) => res
                 .as_ref()
                 .err()
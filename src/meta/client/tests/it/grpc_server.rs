@@ -28,6 +28,11 @@ use databend_common_meta_types::protobuf::ClusterStatus;
 use databend_common_meta_types::protobuf::Empty;
 use databend_common_meta_types::protobuf::ExportedChunk;
 use databend_common_meta_types::protobuf::HandshakeResponse;
+use databend_common_meta_types::protobuf::LeaseGrantRequest;
+use databend_common_meta_types::protobuf::LeaseKeepAliveReply;
+use databend_common_meta_types::protobuf::LeaseKeepAliveRequest;
+use databend_common_meta_types::protobuf::LeaseReply;
+use databend_common_meta_types::protobuf::LeaseRevokeRequest;
 use databend_common_meta_types::protobuf::MemberListReply;
 use databend_common_meta_types::protobuf::MemberListRequest;
 use databend_common_meta_types::protobuf::RaftReply;
@@ -122,6 +127,27 @@ impl MetaService for GrpcServiceForTestImpl {
         unimplemented!()
     }
 
+    async fn lease_grant(
+        &self,
+        _request: Request<LeaseGrantRequest>,
+    ) -> Result<Response<LeaseReply>, Status> {
+        unimplemented!()
+    }
+
+    async fn lease_keep_alive(
+        &self,
+        _request: Request<LeaseKeepAliveRequest>,
+    ) -> Result<Response<LeaseKeepAliveReply>, Status> {
+        unimplemented!()
+    }
+
+    async fn lease_revoke(
+        &self,
+        _request: Request<LeaseRevokeRequest>,
+    ) -> Result<Response<LeaseReply>, Status> {
+        unimplemented!()
+    }
+
     async fn member_list(
         &self,
         _request: Request<MemberListRequest>,
@@ -127,6 +127,9 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
         match log_entry.cmd {
             Cmd::AddNode { .. } => Ok(None),
             Cmd::RemoveNode { .. } => Ok(None),
+            Cmd::GrantLease { .. } => Ok(None),
+            Cmd::KeepAliveLeases { .. } => Ok(None),
+            Cmd::RevokeLease { .. } => Ok(None),
             Cmd::UpsertKV(ups) => {
                 let x = LogEntry {
                     txid: log_entry.txid,
@@ -219,6 +222,7 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
             prev_value: p.prev_value,
             expire_at: p.expire_at,
             ttl_ms: p.ttl_ms,
+            lease_id: p.lease_id,
         };
 
         Ok(pr)
@@ -80,6 +80,7 @@ mod owner_from_to_protobuf_impl;
 mod ownership_from_to_protobuf_impl;
 mod procedure_from_to_protobuf_impl;
 mod role_from_to_protobuf_impl;
+mod row_access_policy_from_to_protobuf_impl;
 mod schema_from_to_protobuf_impl;
 mod sequence_from_to_protobuf_impl;
 mod stage_from_to_protobuf_impl;
@@ -90,6 +90,18 @@ impl FromToProto for mt::principal::OwnershipObject {
             pb::ownership_object::Object::Stage(pb::ownership_object::OwnershipStageObject {
                 stage,
             }) => Ok(mt::principal::OwnershipObject::Stage { name: stage }),
+            pb::ownership_object::Object::Sequence(
+                pb::ownership_object::OwnershipSequenceObject { sequence },
+            ) => Ok(mt::principal::OwnershipObject::Sequence { name: sequence }),
+            pb::ownership_object::Object::Procedure(
+                pb::ownership_object::OwnershipProcedureObject { procedure_id },
+            ) => Ok(mt::principal::OwnershipObject::Procedure { procedure_id }),
+            pb::ownership_object::Object::Connection(
+                pb::ownership_object::OwnershipConnectionObject { connection },
+            ) => Ok(mt::principal::OwnershipObject::Connection { name: connection }),
+            pb::ownership_object::Object::FileFormat(
+                pb::ownership_object::OwnershipFileFormatObject { file_format },
+            ) => Ok(mt::principal::OwnershipObject::FileFormat { name: file_format }),
         }
     }
 
@@ -125,6 +137,34 @@ impl FromToProto for mt::principal::OwnershipObject {
                     stage: name.clone(),
                 }),
             ),
+            mt::principal::OwnershipObject::Sequence { name } => {
+                Some(pb::ownership_object::Object::Sequence(
+                    pb::ownership_object::OwnershipSequenceObject {
+                        sequence: name.clone(),
+                    },
+                ))
+            }
+            mt::principal::OwnershipObject::Procedure { procedure_id } => {
+                Some(pb::ownership_object::Object::Procedure(
+                    pb::ownership_object::OwnershipProcedureObject {
+                        procedure_id: *procedure_id,
+                    },
+                ))
+            }
+            mt::principal::OwnershipObject::Connection { name } => {
+                Some(pb::ownership_object::Object::Connection(
+                    pb::ownership_object::OwnershipConnectionObject {
+                        connection: name.clone(),
+                    },
+                ))
+            }
+            mt::principal::OwnershipObject::FileFormat { name } => {
+                Some(pb::ownership_object::Object::FileFormat(
+                    pb::ownership_object::OwnershipFileFormatObject {
+                        file_format: name.clone(),
+                    },
+                ))
+            }
         };
         Ok(pb::OwnershipObject {
             ver: VER,
@@ -75,6 +75,10 @@ impl FromToProto for ex::TableField {
             Some(computed_expr) => Some(ex::ComputedExpr::from_pb(computed_expr)?),
             None => None,
         };
+        let auto_increment_expr = match p.auto_increment_expr {
+            Some(auto_increment_expr) => Some(ex::AutoIncrementExpr::from_pb(auto_increment_expr)?),
+            None => None,
+        };
 
         let v = ex::TableField::new_from_column_id(
             &p.name,
@@ -84,7 +88,8 @@ impl FromToProto for ex::TableField {
             p.column_id,
         )
         .with_default_expr(p.default_expr)
-        .with_computed_expr(computed_expr);
+        .with_computed_expr(computed_expr)
+        .with_auto_increment_expr(auto_increment_expr);
         Ok(v)
     }
 
@@ -93,6 +98,10 @@ impl FromToProto for ex::TableField {
             Some(computed_expr) => Some(computed_expr.to_pb()?),
             None => None,
         };
+        let auto_increment_expr = match self.auto_increment_expr() {
+            Some(auto_increment_expr) => Some(auto_increment_expr.to_pb()?),
+            None => None,
+        };
         let p = pb::DataField {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
@@ -101,6 +110,7 @@ impl FromToProto for ex::TableField {
             data_type: Some(self.data_type().to_pb()?),
             column_id: self.column_id(),
             computed_expr,
+            auto_increment_expr,
         };
         Ok(p)
     }
@@ -144,6 +154,34 @@ impl FromToProto for ex::ComputedExpr {
     }
 }
 
+impl FromToProto for ex::AutoIncrementExpr {
+    type PB = pb::AutoIncrementExpr;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::AutoIncrementExpr) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(Self {
+            sequence_name: p.sequence_name,
+            start: p.start,
+            step: p.step,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::AutoIncrementExpr, Incompatible> {
+        Ok(pb::AutoIncrementExpr {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            sequence_name: self.sequence_name.clone(),
+            start: self.start,
+            step: self.step,
+        })
+    }
+}
+
 impl FromToProto for ex::TableDataType {
     type PB = pb::DataType;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -41,6 +41,7 @@ impl FromToProto for SequenceMeta {
             start: p.start,
             current: p.current,
             step: p.step,
+            cache: p.cache,
         };
         Ok(v)
     }
@@ -55,6 +56,7 @@ impl FromToProto for SequenceMeta {
             start: self.start,
             current: self.current,
             step: self.step,
+            cache: self.cache,
         };
         Ok(p)
     }
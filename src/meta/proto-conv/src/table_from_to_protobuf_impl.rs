@@ -27,6 +27,7 @@ use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_protos::pb;
+use num::FromPrimitive;
 
 use crate::reader_check_msg;
 use crate::FromToProto;
@@ -224,6 +225,10 @@ impl FromToProto for mt::TableMeta {
             } else {
                 Some(p.column_mask_policy)
             },
+            row_access_policy: p
+                .row_access_policy
+                .map(mt::TableRowAccessPolicy::from_pb)
+                .transpose()?,
             indexes,
         };
         Ok(v)
@@ -265,6 +270,11 @@ impl FromToProto for mt::TableMeta {
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
             indexes,
+            row_access_policy: self
+                .row_access_policy
+                .as_ref()
+                .map(|policy| policy.to_pb())
+                .transpose()?,
         };
         Ok(p)
     }
@@ -327,6 +337,32 @@ impl FromToProto for mt::TableIdList {
     }
 }
 
+impl FromToProto for mt::TableRowAccessPolicy {
+    type PB = pb::TableRowAccessPolicy;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableRowAccessPolicy) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            policy: p.policy,
+            columns: p.columns,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableRowAccessPolicy, Incompatible> {
+        let p = pb::TableRowAccessPolicy {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            policy: self.policy.clone(),
+            columns: self.columns.clone(),
+        };
+        Ok(p)
+    }
+}
+
 impl FromToProto for mt::TableIndex {
     type PB = pb::TableIndex;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -336,6 +372,9 @@ impl FromToProto for mt::TableIndex {
         reader_check_msg(p.ver, p.min_reader_ver)?;
 
         let v = Self {
+            index_type: FromPrimitive::from_i32(p.index_type).ok_or_else(|| Incompatible {
+                reason: format!("invalid TableIndexType: {}", p.index_type),
+            })?,
             name: p.name,
             column_ids: p.column_ids,
             sync_creation: p.sync_creation,
@@ -354,6 +393,7 @@ impl FromToProto for mt::TableIndex {
             sync_creation: self.sync_creation,
             version: self.version.clone(),
             options: self.options.clone(),
+            index_type: self.index_type.clone() as i32,
         };
         Ok(p)
     }
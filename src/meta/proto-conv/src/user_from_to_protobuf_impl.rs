@@ -47,6 +47,9 @@ impl FromToProto for mt::principal::AuthInfo {
             Some(pb::auth_info::Info::Jwt(pb::auth_info::Jwt {})) => {
                 Ok(mt::principal::AuthInfo::JWT)
             }
+            Some(pb::auth_info::Info::Ldap(pb::auth_info::Ldap {})) => {
+                Ok(mt::principal::AuthInfo::Ldap)
+            }
             Some(pb::auth_info::Info::Password(pb::auth_info::Password {
                 hash_value,
                 hash_method,
@@ -70,6 +73,9 @@ impl FromToProto for mt::principal::AuthInfo {
                 Some(pb::auth_info::Info::None(pb::auth_info::None {}))
             }
             mt::principal::AuthInfo::JWT => Some(pb::auth_info::Info::Jwt(pb::auth_info::Jwt {})),
+            mt::principal::AuthInfo::Ldap => {
+                Some(pb::auth_info::Info::Ldap(pb::auth_info::Ldap {}))
+            }
             mt::principal::AuthInfo::Password {
                 hash_value,
                 hash_method,
@@ -106,7 +112,9 @@ impl FromToProto for mt::principal::UserOption {
             .with_network_policy(p.network_policy)
             .with_password_policy(p.password_policy)
             .with_disabled(p.disabled)
-            .with_must_change_password(p.must_change_password))
+            .with_must_change_password(p.must_change_password)
+            .with_rsa_public_key(p.rsa_public_key)
+            .with_rsa_public_key_2(p.rsa_public_key_2))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -119,6 +127,8 @@ impl FromToProto for mt::principal::UserOption {
             password_policy: self.password_policy().cloned(),
             disabled: self.disabled().cloned(),
             must_change_password: self.must_change_password().cloned(),
+            rsa_public_key: self.rsa_public_key().cloned(),
+            rsa_public_key_2: self.rsa_public_key_2().cloned(),
         })
     }
 }
@@ -187,12 +197,32 @@ impl FromToProto for mt::principal::GrantObject {
                 db,
                 table,
             }) => Ok(mt::principal::GrantObject::TableById(catalog, db, table)),
+            pb::grant_object::Object::Columnbyid(pb::grant_object::GrantColumnIdObject {
+                catalog,
+                db,
+                table,
+                column_id,
+            }) => Ok(mt::principal::GrantObject::Column(
+                catalog, db, table, column_id,
+            )),
             pb::grant_object::Object::Udf(pb::grant_object::GrantUdfObject { udf }) => {
                 Ok(mt::principal::GrantObject::UDF(udf))
             }
             pb::grant_object::Object::Stage(pb::grant_object::GrantStageObject { stage }) => {
                 Ok(mt::principal::GrantObject::Stage(stage))
             }
+            pb::grant_object::Object::Sequence(pb::grant_object::GrantSequenceObject {
+                sequence,
+            }) => Ok(mt::principal::GrantObject::Sequence(sequence)),
+            pb::grant_object::Object::Procedure(pb::grant_object::GrantProcedureObject {
+                procedure_id,
+            }) => Ok(mt::principal::GrantObject::Procedure(procedure_id)),
+            pb::grant_object::Object::Connection(pb::grant_object::GrantConnectionObject {
+                connection,
+            }) => Ok(mt::principal::GrantObject::Connection(connection)),
+            pb::grant_object::Object::FileFormat(pb::grant_object::GrantFileFormatObject {
+                file_format,
+            }) => Ok(mt::principal::GrantObject::FileFormat(file_format)),
         }
     }
 
@@ -227,6 +257,14 @@ impl FromToProto for mt::principal::GrantObject {
                     table: *table,
                 }),
             ),
+            mt::principal::GrantObject::Column(catalog, db, table, column_id) => Some(
+                pb::grant_object::Object::Columnbyid(pb::grant_object::GrantColumnIdObject {
+                    catalog: catalog.clone(),
+                    db: *db,
+                    table: *table,
+                    column_id: *column_id,
+                }),
+            ),
             mt::principal::GrantObject::UDF(udf) => Some(pb::grant_object::Object::Udf(
                 pb::grant_object::GrantUdfObject { udf: udf.clone() },
             )),
@@ -235,6 +273,26 @@ impl FromToProto for mt::principal::GrantObject {
                     stage: stage.clone(),
                 },
             )),
+            mt::principal::GrantObject::Sequence(sequence) => Some(
+                pb::grant_object::Object::Sequence(pb::grant_object::GrantSequenceObject {
+                    sequence: sequence.clone(),
+                }),
+            ),
+            mt::principal::GrantObject::Procedure(procedure_id) => Some(
+                pb::grant_object::Object::Procedure(pb::grant_object::GrantProcedureObject {
+                    procedure_id: *procedure_id,
+                }),
+            ),
+            mt::principal::GrantObject::Connection(connection) => Some(
+                pb::grant_object::Object::Connection(pb::grant_object::GrantConnectionObject {
+                    connection: connection.clone(),
+                }),
+            ),
+            mt::principal::GrantObject::FileFormat(file_format) => Some(
+                pb::grant_object::Object::FileFormat(pb::grant_object::GrantFileFormatObject {
+                    file_format: file_format.clone(),
+                }),
+            ),
         };
         Ok(pb::GrantObject {
             ver: VER,
@@ -527,3 +585,45 @@ impl FromToProto for mt::principal::PasswordPolicy {
         })
     }
 }
+
+impl FromToProto for mt::principal::QueryHint {
+    type PB = pb::QueryHint;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::QueryHint) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::QueryHint {
+            fingerprint: p.fingerprint,
+            query: p.query,
+            join_order: p.join_order,
+            join_distribution: p.join_distribution,
+            settings: p.settings,
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+            update_on: match p.update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::QueryHint, Incompatible> {
+        Ok(pb::QueryHint {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            fingerprint: self.fingerprint.clone(),
+            query: self.query.clone(),
+            join_order: self.join_order.clone(),
+            join_distribution: self.join_distribution.clone(),
+            settings: self.settings.clone(),
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+            update_on: match &self.update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        })
+    }
+}
@@ -140,6 +140,15 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (108, "2024-08-29: Add: procedure.proto: ProcedureMeta and ProcedureIdentity"),
     (109, "2024-08-29: Refactor: ProcedureMeta add arg_names"),
     (110, "2024-09-18: Add: database.proto: DatabaseMeta.gc_in_progress"),
+    (111, "2024-10-19: Add: table.proto: TableIndex add index_type"),
+    (112, "2024-10-21: Add: metadata.proto: DataField add auto_increment_expr"),
+    (113, "2024-10-22: Add: sequence.proto: SequenceMeta add cache"),
+    (114, "2024-10-23: Add: row_access_policy.proto and table.proto: TableMeta add row_access_policy"),
+    (115, "2024-10-24: Add: user.proto: GrantObject add columnbyid"),
+    (116, "2024-10-25: Add: user.proto and ownership.proto: sequence, procedure, connection, file_format objects"),
+    (117, "2024-10-28: Add: user.proto/AuthInfo: ldap"),
+    (118, "2024-10-29: Add: user.proto/UserOption: rsa_public_key, rsa_public_key_2"),
+    (119, "2024-10-30: Add: user.proto: QueryHint"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -108,3 +108,12 @@ mod v107_geography_datatype;
 mod v108_procedure;
 mod v109_procedure_with_args;
 mod v110_database_meta_gc_in_progress;
+mod v111_table_index_type;
+mod v112_auto_increment_field;
+mod v113_sequence_cache;
+mod v114_row_access_policy;
+mod v115_column_grant_object;
+mod v116_grant_object_sequence_procedure_connection_file_format;
+mod v117_user_ldap_auth;
+mod v118_user_option_public_keys;
+mod v119_query_hint;
@@ -83,6 +83,7 @@ fn new_sequence_meta() -> mt::SequenceMeta {
         start: 1,
         step: 1,
         current: 10,
+        cache: 0,
     }
 }
 
@@ -151,6 +152,7 @@ fn new_table_meta() -> mt::TableMeta {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        row_access_policy: None,
         indexes: btreemap! {},
     }
 }
@@ -216,6 +216,7 @@ fn test_decode_v80_table_meta() -> anyhow::Result<()> {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        row_access_policy: None,
         indexes: btreemap! {},
     };
     common::test_pb_from_to(func_name!(), want())?;
@@ -90,7 +90,9 @@ fn test_decode_v82_table_meta() -> anyhow::Result<()> {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        row_access_policy: None,
         indexes: btreemap! {s("idx1") => mt::TableIndex {
+            index_type: mt::TableIndexType::Inverted,
             name: "idx1".to_string(),
             column_ids: vec![1, 2],
             sync_creation: false,
@@ -0,0 +1,54 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::schema as mt;
+use fastrace::func_name;
+use maplit::btreemap;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v111_table_index() -> anyhow::Result<()> {
+    let table_index_v111 = vec![
+        160, 6, 111, 168, 6, 24, 10, 4, 105, 100, 120, 49, 18, 1, 1, 24, 1, 34, 3, 118, 101, 114,
+        42, 18, 10, 8, 100, 105, 115, 116, 97, 110, 99, 101, 18, 6, 99, 111, 115, 105, 110, 101,
+        42, 10, 10, 5, 108, 105, 115, 116, 115, 18, 1, 56, 48, 1,
+    ];
+
+    let want = || mt::TableIndex {
+        index_type: mt::TableIndexType::Vector,
+        name: "idx1".to_string(),
+        column_ids: vec![1],
+        sync_creation: true,
+        version: "ver".to_string(),
+        options: btreemap! {s("distance") => s("cosine"), s("lists") => s("8")},
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_index_v111.as_slice(), 111, want())
+}
+
+fn s(ss: impl ToString) -> String {
+    ss.to_string()
+}
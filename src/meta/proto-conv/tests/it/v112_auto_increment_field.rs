@@ -0,0 +1,55 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression as ce;
+use databend_common_expression::types::NumberDataType;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v112_auto_increment_field() -> anyhow::Result<()> {
+    let table_field_v112 = vec![
+        160, 6, 112, 168, 6, 24, 10, 2, 105, 100, 26, 17, 160, 6, 112, 168, 6, 24, 154, 2, 8, 160,
+        6, 112, 168, 6, 24, 66, 0, 32, 3, 50, 36, 160, 6, 112, 168, 6, 24, 10, 15, 95, 115, 121,
+        115, 95, 105, 100, 101, 110, 116, 105, 116, 121, 95, 49, 16, 100, 24, 254, 255, 255, 255,
+        255, 255, 255, 255, 255, 1,
+    ];
+
+    let want = || {
+        ce::TableField::new_from_column_id(
+            "id",
+            ce::TableDataType::Number(NumberDataType::Int64),
+            3,
+        )
+        .with_auto_increment_expr(Some(ce::AutoIncrementExpr {
+            sequence_name: "_sys_identity_1".to_string(),
+            start: 100,
+            step: -2,
+        }))
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_field_v112.as_slice(), 112, want())
+}
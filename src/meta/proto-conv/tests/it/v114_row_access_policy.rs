@@ -0,0 +1,69 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyMeta;
+use databend_common_meta_app::schema::TableRowAccessPolicy;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v114_row_access_policy_meta() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 6, 114, 101, 103, 105, 111, 110, 18, 6, 83, 116, 114, 105, 110, 103, 26, 44, 114, 101,
+        103, 105, 111, 110, 32, 61, 32, 39, 117, 115, 39, 32, 79, 82, 32, 105, 115, 95, 114, 111,
+        108, 101, 95, 105, 110, 95, 115, 101, 115, 115, 105, 111, 110, 40, 39, 97, 100, 109, 105,
+        110, 39, 41, 34, 12, 115, 111, 109, 101, 32, 99, 111, 109, 109, 101, 110, 116, 42, 23, 50,
+        48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 50,
+        23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84,
+        67, 160, 6, 114, 168, 6, 24,
+    ];
+
+    let want = || RowAccessPolicyMeta {
+        args: vec![("region".to_string(), "String".to_string())],
+        body: "region = 'us' OR is_role_in_session('admin')".to_string(),
+        comment: Some("some comment".to_string()),
+        create_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        update_on: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 114, want())
+}
+
+#[test]
+fn test_decode_v114_table_row_access_policy() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 2, 112, 49, 18, 6, 114, 101, 103, 105, 111, 110, 160, 6, 114, 168, 6, 24,
+    ];
+
+    let want = || TableRowAccessPolicy {
+        policy: "p1".to_string(),
+        columns: vec!["region".to_string()],
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 114, want())
+}
@@ -0,0 +1,63 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app as mt;
+use databend_common_meta_app::principal::UserGrantSet;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use enumflags2::make_bitflags;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+
+#[test]
+fn test_decode_v115_column_grant_object() -> anyhow::Result<()> {
+    let role_info_v115 = vec![
+        10, 2, 114, 49, 18, 41, 10, 33, 10, 23, 66, 15, 10, 7, 100, 101, 102, 97, 117, 108, 116,
+        16, 1, 24, 10, 32, 1, 160, 6, 115, 168, 6, 24, 16, 4, 160, 6, 115, 168, 6, 24, 160, 6, 115,
+        168, 6, 24, 26, 23, 50, 48, 50, 51, 45, 49, 50, 45, 49, 53, 32, 48, 49, 58, 50, 54, 58, 48,
+        57, 32, 85, 84, 67, 34, 23, 50, 48, 50, 51, 45, 49, 50, 45, 49, 53, 32, 48, 49, 58, 50, 54,
+        58, 49, 48, 32, 85, 84, 67, 160, 6, 115, 168, 6, 24,
+    ];
+
+    let want = || mt::principal::RoleInfo {
+        name: "r1".to_string(),
+        grants: UserGrantSet::new(
+            vec![mt::principal::GrantEntry::new(
+                mt::principal::GrantObject::Column("default".to_string(), 1, 10, 1),
+                make_bitflags!(UserPrivilegeType::{Select}),
+            )],
+            HashSet::new(),
+        ),
+        created_on: DateTime::<Utc>::from_timestamp(1702603569, 0).unwrap(),
+        update_on: DateTime::<Utc>::from_timestamp(1702603570, 0).unwrap(),
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), role_info_v115.as_slice(), 115, want())?;
+
+    Ok(())
+}
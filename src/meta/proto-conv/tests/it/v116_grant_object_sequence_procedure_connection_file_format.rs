@@ -0,0 +1,154 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app as mt;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::principal::UserGrantSet;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use enumflags2::make_bitflags;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+
+#[test]
+fn test_decode_v116_role_grant_objects() -> anyhow::Result<()> {
+    let role_info_v116 = vec![
+        10, 2, 114, 49, 18, 107, 10, 24, 10, 14, 74, 6, 10, 4, 115, 101, 113, 49, 160, 6, 116, 168,
+        6, 24, 16, 1, 160, 6, 116, 168, 6, 24, 10, 20, 10, 10, 82, 2, 8, 7, 160, 6, 116, 168, 6,
+        24, 16, 1, 160, 6, 116, 168, 6, 24, 10, 25, 10, 15, 90, 7, 10, 5, 99, 111, 110, 110, 49,
+        160, 6, 116, 168, 6, 24, 16, 1, 160, 6, 116, 168, 6, 24, 10, 24, 10, 14, 98, 6, 10, 4, 102,
+        109, 116, 49, 160, 6, 116, 168, 6, 24, 16, 1, 160, 6, 116, 168, 6, 24, 160, 6, 116, 168, 6,
+        24, 26, 23, 50, 48, 50, 51, 45, 49, 50, 45, 49, 53, 32, 48, 49, 58, 50, 54, 58, 48, 57, 32,
+        85, 84, 67, 34, 23, 50, 48, 50, 51, 45, 49, 50, 45, 49, 53, 32, 48, 49, 58, 50, 54, 58, 49,
+        48, 32, 85, 84, 67, 160, 6, 116, 168, 6, 24,
+    ];
+
+    let want = || mt::principal::RoleInfo {
+        name: "r1".to_string(),
+        grants: UserGrantSet::new(
+            vec![
+                mt::principal::GrantEntry::new(
+                    mt::principal::GrantObject::Sequence("seq1".to_string()),
+                    make_bitflags!(UserPrivilegeType::{Usage}),
+                ),
+                mt::principal::GrantEntry::new(
+                    mt::principal::GrantObject::Procedure(7),
+                    make_bitflags!(UserPrivilegeType::{Usage}),
+                ),
+                mt::principal::GrantEntry::new(
+                    mt::principal::GrantObject::Connection("conn1".to_string()),
+                    make_bitflags!(UserPrivilegeType::{Usage}),
+                ),
+                mt::principal::GrantEntry::new(
+                    mt::principal::GrantObject::FileFormat("fmt1".to_string()),
+                    make_bitflags!(UserPrivilegeType::{Usage}),
+                ),
+            ],
+            HashSet::new(),
+        ),
+        created_on: DateTime::<Utc>::from_timestamp(1702603569, 0).unwrap(),
+        update_on: DateTime::<Utc>::from_timestamp(1702603570, 0).unwrap(),
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), role_info_v116.as_slice(), 116, want())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v116_sequence_ownership() -> anyhow::Result<()> {
+    let ownership_info_v116 = vec![
+        10, 2, 114, 49, 18, 14, 42, 6, 10, 4, 115, 101, 113, 49, 160, 6, 116, 168, 6, 24, 160, 6,
+        116, 168, 6, 24,
+    ];
+
+    let want = || mt::principal::OwnershipInfo {
+        role: "r1".to_string(),
+        object: OwnershipObject::Sequence {
+            name: "seq1".to_string(),
+        },
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), ownership_info_v116.as_slice(), 116, want())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v116_procedure_ownership() -> anyhow::Result<()> {
+    let ownership_info_v116 = vec![
+        10, 2, 114, 49, 18, 10, 50, 2, 8, 7, 160, 6, 116, 168, 6, 24, 160, 6, 116, 168, 6, 24,
+    ];
+
+    let want = || mt::principal::OwnershipInfo {
+        role: "r1".to_string(),
+        object: OwnershipObject::Procedure { procedure_id: 7 },
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), ownership_info_v116.as_slice(), 116, want())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v116_connection_ownership() -> anyhow::Result<()> {
+    let ownership_info_v116 = vec![
+        10, 2, 114, 49, 18, 15, 58, 7, 10, 5, 99, 111, 110, 110, 49, 160, 6, 116, 168, 6, 24, 160,
+        6, 116, 168, 6, 24,
+    ];
+
+    let want = || mt::principal::OwnershipInfo {
+        role: "r1".to_string(),
+        object: OwnershipObject::Connection {
+            name: "conn1".to_string(),
+        },
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), ownership_info_v116.as_slice(), 116, want())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v116_file_format_ownership() -> anyhow::Result<()> {
+    let ownership_info_v116 = vec![
+        10, 2, 114, 49, 18, 14, 66, 6, 10, 4, 102, 109, 116, 49, 160, 6, 116, 168, 6, 24, 160, 6,
+        116, 168, 6, 24,
+    ];
+
+    let want = || mt::principal::OwnershipInfo {
+        role: "r1".to_string(),
+        object: OwnershipObject::FileFormat {
+            name: "fmt1".to_string(),
+        },
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), ownership_info_v116.as_slice(), 116, want())?;
+
+    Ok(())
+}
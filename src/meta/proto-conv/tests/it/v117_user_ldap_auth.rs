@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::principal::AuthInfo;
+use databend_common_meta_app::principal::UserGrantSet;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::UserOption;
+use databend_common_meta_app::principal::UserQuota;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v117_user_ldap_auth() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 9, 108, 100, 97, 112, 95, 117, 115, 101, 114, 18, 1, 37, 26, 8, 34, 0, 160, 6, 117,
+        168, 6, 24, 34, 6, 160, 6, 117, 168, 6, 24, 42, 6, 160, 6, 117, 168, 6, 24, 50, 6, 160, 6,
+        117, 168, 6, 24, 90, 23, 49, 57, 55, 48, 45, 48, 49, 45, 48, 49, 32, 48, 48, 58, 48, 48,
+        58, 48, 48, 32, 85, 84, 67, 98, 23, 49, 57, 55, 48, 45, 48, 49, 45, 48, 49, 32, 48, 48, 58,
+        48, 48, 58, 48, 48, 32, 85, 84, 67, 160, 6, 117, 168, 6, 24,
+    ];
+
+    let want = || UserInfo {
+        name: "ldap_user".to_string(),
+        hostname: "%".to_string(),
+        auth_info: AuthInfo::Ldap,
+        grants: UserGrantSet::empty(),
+        quota: UserQuota {
+            max_cpu: 0,
+            max_memory_in_bytes: 0,
+            max_storage_in_bytes: 0,
+        },
+        option: UserOption::default(),
+        history_auth_infos: vec![],
+        password_fails: vec![],
+        password_update_on: None,
+        lockout_time: None,
+        created_on: DateTime::<Utc>::default(),
+        update_on: DateTime::<Utc>::default(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 117, want())
+}
@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::principal::AuthInfo;
+use databend_common_meta_app::principal::UserGrantSet;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::UserOption;
+use databend_common_meta_app::principal::UserQuota;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v118_user_option_public_keys() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 2, 99, 105, 18, 1, 37, 26, 8, 26, 0, 160, 6, 118, 168, 6, 24, 34, 6, 160, 6, 118, 168,
+        6, 24, 42, 6, 160, 6, 118, 168, 6, 24, 50, 130, 1, 58, 60, 77, 67, 111, 119, 66, 81, 89,
+        68, 75, 50, 86, 119, 65, 121, 69, 65, 71, 98, 57, 69, 67, 87, 109, 69, 122, 102, 54, 70,
+        81, 98, 114, 66, 90, 57, 119, 55, 108, 115, 104, 81, 104, 113, 111, 119, 116, 114, 98, 76,
+        68, 70, 119, 52, 114, 88, 65, 120, 90, 117, 69, 61, 66, 60, 77, 67, 111, 119, 66, 81, 89,
+        68, 75, 50, 86, 119, 65, 121, 69, 65, 71, 57, 83, 83, 49, 121, 78, 97, 88, 90, 65, 86, 99,
+        74, 72, 74, 122, 104, 79, 108, 72, 115, 66, 108, 71, 87, 104, 47, 120, 47, 119, 52, 67, 48,
+        119, 56, 65, 73, 100, 80, 89, 78, 111, 61, 160, 6, 118, 168, 6, 24, 90, 23, 49, 57, 55, 48,
+        45, 48, 49, 45, 48, 49, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 98, 23, 49, 57,
+        55, 48, 45, 48, 49, 45, 48, 49, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 160, 6,
+        118, 168, 6, 24,
+    ];
+
+    let want = || UserInfo {
+        name: "ci".to_string(),
+        hostname: "%".to_string(),
+        auth_info: AuthInfo::JWT,
+        grants: UserGrantSet::empty(),
+        quota: UserQuota {
+            max_cpu: 0,
+            max_memory_in_bytes: 0,
+            max_storage_in_bytes: 0,
+        },
+        option: UserOption::default()
+            .with_rsa_public_key(Some(
+                "MCowBQYDK2VwAyEAGb9ECWmEzf6FQbrBZ9w7lshQhqowtrbLDFw4rXAxZuE=".to_string(),
+            ))
+            .with_rsa_public_key_2(Some(
+                "MCowBQYDK2VwAyEAG9SS1yNaXZAVcJHJzhOlHsBlGWh/x/w4C0w8AIdPYNo=".to_string(),
+            )),
+        history_auth_infos: vec![],
+        password_fails: vec![],
+        password_update_on: None,
+        lockout_time: None,
+        created_on: DateTime::<Utc>::default(),
+        update_on: DateTime::<Utc>::default(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 118, want())
+}
@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal::QueryHint;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v119_query_hint() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 32, 51, 99, 49, 97, 54, 102, 51, 101, 48, 102, 100, 51, 101, 48, 97, 53, 100, 48, 97,
+        51, 102, 49, 98, 52, 54, 99, 52, 98, 50, 99, 48, 101, 18, 54, 83, 69, 76, 69, 67, 84, 32,
+        42, 32, 70, 82, 79, 77, 32, 116, 49, 32, 74, 79, 73, 78, 32, 116, 50, 32, 79, 78, 32, 116,
+        49, 46, 97, 32, 61, 32, 116, 50, 46, 97, 32, 87, 72, 69, 82, 69, 32, 116, 49, 46, 98, 32,
+        61, 32, 49, 26, 5, 70, 73, 88, 69, 68, 34, 9, 66, 82, 79, 65, 68, 67, 65, 83, 84, 42, 32,
+        10, 27, 101, 110, 97, 98, 108, 101, 95, 98, 108, 111, 111, 109, 95, 114, 117, 110, 116,
+        105, 109, 101, 95, 102, 105, 108, 116, 101, 114, 18, 1, 49, 42, 16, 10, 11, 109, 97, 120,
+        95, 116, 104, 114, 101, 97, 100, 115, 18, 1, 56, 50, 18, 112, 105, 110, 32, 100, 97, 115,
+        104, 98, 111, 97, 114, 100, 32, 112, 108, 97, 110, 58, 23, 50, 48, 49, 52, 45, 49, 49, 45,
+        50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 66, 23, 50, 48, 49, 52, 45, 49,
+        49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 119, 168, 6,
+        24,
+    ];
+
+    let want = || QueryHint {
+        fingerprint: "3c1a6f3e0fd3e0a5d0a3f1b46c4b2c0e".to_string(),
+        query: "SELECT * FROM t1 JOIN t2 ON t1.a = t2.a WHERE t1.b = 1".to_string(),
+        join_order: Some("FIXED".to_string()),
+        join_distribution: Some("BROADCAST".to_string()),
+        settings: BTreeMap::from([
+            ("enable_bloom_runtime_filter".to_string(), "1".to_string()),
+            ("max_threads".to_string(), "8".to_string()),
+        ]),
+        comment: "pin dashboard plan".to_string(),
+        create_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        update_on: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 119, want())
+}
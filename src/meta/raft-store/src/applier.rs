@@ -29,10 +29,15 @@ use databend_common_meta_types::CmdContext;
 use databend_common_meta_types::ConditionResult;
 use databend_common_meta_types::Entry;
 use databend_common_meta_types::EntryPayload;
+use databend_common_meta_types::EvalExpireTime;
 use databend_common_meta_types::Interval;
+use databend_common_meta_types::Lease;
+use databend_common_meta_types::LeaseKeepAliveReply;
+use databend_common_meta_types::LeaseReply;
 use databend_common_meta_types::MatchSeq;
 use databend_common_meta_types::MetaSpec;
 use databend_common_meta_types::Node;
+use databend_common_meta_types::Operation;
 use databend_common_meta_types::StoredMembership;
 use databend_common_meta_types::TxnCondition;
 use databend_common_meta_types::TxnDeleteByPrefixRequest;
@@ -52,6 +57,7 @@ use futures::stream::TryStreamExt;
 use log::debug;
 use log::error;
 use log::info;
+use log::warn;
 use num::FromPrimitive;
 
 use crate::sm_v003::SMV003;
@@ -144,6 +150,14 @@ impl<'a> Applier<'a> {
             Cmd::UpsertKV(ref upsert_kv) => self.apply_upsert_kv(upsert_kv).await?,
 
             Cmd::Transaction(txn) => self.apply_txn(txn).await?,
+
+            Cmd::GrantLease { lease_id, ttl_ms } => {
+                self.apply_grant_lease(*lease_id, *ttl_ms).await?
+            }
+
+            Cmd::KeepAliveLeases { lease_ids } => self.apply_keep_alive_leases(lease_ids).await?,
+
+            Cmd::RevokeLease { lease_id } => self.apply_revoke_lease(*lease_id).await?,
         };
 
         debug!("apply_result: cmd: {}; res: {}", cmd, res);
@@ -206,6 +220,10 @@ impl<'a> Applier<'a> {
     /// Update or insert a kv entry.
     ///
     /// If the input entry has expired, it performs a delete operation.
+    ///
+    /// If the entry is bound to a lease, it expires along with the lease.
+    /// Nothing is changed if the lease does not exist,
+    /// or if the key belongs to the reserved lease keyspace, which is not writable by users.
     #[fastrace::trace]
     pub(crate) async fn upsert_kv(
         &mut self,
@@ -213,6 +231,41 @@ impl<'a> Applier<'a> {
     ) -> Result<(Option<SeqV>, Option<SeqV>), io::Error> {
         debug!(upsert_kv :? =(upsert_kv); "upsert_kv");
 
+        if Lease::is_reserved(&upsert_kv.key) {
+            warn!("upsert_kv: reserved key is not writable: {:?}", upsert_kv);
+            let curr = self.sm.get_maybe_expired_kv(&upsert_kv.key).await?;
+            return Ok((curr.clone(), curr));
+        }
+
+        let lease_id = upsert_kv.value_meta.as_ref().and_then(|m| m.lease());
+        let Some(lease_id) = lease_id else {
+            return self.upsert_kv_record(upsert_kv).await;
+        };
+
+        let Some((expire_at, _lease)) = self.get_lease(lease_id).await? else {
+            debug!("upsert_kv: lease {} not found: {:?}", lease_id, upsert_kv);
+            let curr = self.sm.get_maybe_expired_kv(&upsert_kv.key).await?;
+            return Ok((curr.clone(), curr));
+        };
+
+        let mut upsert_kv = upsert_kv.clone();
+        upsert_kv.value_meta = Some(MetaSpec::new_expire(expire_at).with_lease(lease_id));
+
+        let (prev, result) = self.upsert_kv_record(&upsert_kv).await?;
+
+        if result.is_some() {
+            self.put_binding(lease_id, &upsert_kv.key, expire_at)
+                .await?;
+        }
+
+        Ok((prev, result))
+    }
+
+    /// Update or insert a kv entry, without resolving the lease it is bound to.
+    async fn upsert_kv_record(
+        &mut self,
+        upsert_kv: &UpsertKV,
+    ) -> Result<(Option<SeqV>, Option<SeqV>), io::Error> {
         let (prev, result) = self
             .sm
             .upsert_kv_primary_index(upsert_kv, &self.cmd_ctx)
@@ -235,6 +288,223 @@ impl<'a> Applier<'a> {
         Ok((prev, result))
     }
 
+    /// Grant a new lease.
+    ///
+    /// If `lease_id` is 0, the next id in the lease id space is assigned.
+    /// Granting a lease id that is in use is rejected.
+    #[fastrace::trace]
+    async fn apply_grant_lease(
+        &mut self,
+        lease_id: u64,
+        ttl_ms: u64,
+    ) -> Result<AppliedState, io::Error> {
+        let lease_id = if lease_id == 0 {
+            self.next_lease_id().await?
+        } else if self.get_lease(lease_id).await?.is_some() {
+            warn!("apply_grant_lease: lease {} already exists", lease_id);
+            return Ok(AppliedState::Lease(LeaseReply {
+                lease_id,
+                error: format!("lease {} already exists", lease_id),
+                ..Default::default()
+            }));
+        } else {
+            lease_id
+        };
+
+        let lease = Lease::new(Interval::from_millis(ttl_ms));
+
+        info!("apply_grant_lease: {}: {}", lease_id, lease);
+
+        let reply = self.renew_lease(lease_id, lease).await?;
+        Ok(AppliedState::Lease(reply))
+    }
+
+    /// Keep alive every lease in one log, a lease that does not exist is replied with `ttl_ms` 0.
+    #[fastrace::trace]
+    async fn apply_keep_alive_leases(
+        &mut self,
+        lease_ids: &[u64],
+    ) -> Result<AppliedState, io::Error> {
+        let mut leases = Vec::with_capacity(lease_ids.len());
+
+        for lease_id in lease_ids.iter().copied() {
+            let reply = match self.get_lease(lease_id).await? {
+                Some((_expire_at, lease)) => self.renew_lease(lease_id, lease).await?,
+                None => {
+                    debug!("apply_keep_alive_leases: lease {} not found", lease_id);
+                    LeaseReply {
+                        lease_id,
+                        ..Default::default()
+                    }
+                }
+            };
+            leases.push(reply);
+        }
+
+        Ok(AppliedState::LeaseKeepAlive(LeaseKeepAliveReply { leases }))
+    }
+
+    /// Delete a lease and the keys that are still bound to it.
+    #[fastrace::trace]
+    async fn apply_revoke_lease(&mut self, lease_id: u64) -> Result<AppliedState, io::Error> {
+        let Some((_expire_at, lease)) = self.get_lease(lease_id).await? else {
+            debug!("apply_revoke_lease: lease {} not found", lease_id);
+            return Ok(AppliedState::Lease(LeaseReply {
+                lease_id,
+                ..Default::default()
+            }));
+        };
+
+        let mut deleted = vec![];
+        for key in self.list_bindings(lease_id).await? {
+            if self.is_bound_to_lease(&key, lease_id).await? {
+                self.upsert_kv_record(&UpsertKV::delete(&key)).await?;
+                deleted.push(key.clone());
+            }
+
+            self.upsert_kv_record(&UpsertKV::delete(Lease::binding_key(lease_id, &key)))
+                .await?;
+        }
+
+        self.upsert_kv_record(&UpsertKV::delete(Lease::key(lease_id)))
+            .await?;
+
+        info!(
+            "apply_revoke_lease: {}, deleted keys: {:?}",
+            lease_id, deleted
+        );
+
+        Ok(AppliedState::Lease(LeaseReply {
+            lease_id,
+            ttl_ms: lease.ttl.millis(),
+            expire_at: 0,
+            keys: deleted,
+            error: "".to_string(),
+        }))
+    }
+
+    /// Extend the expiration time of a lease and the keys still bound to it by the ttl of the lease.
+    ///
+    /// The bindings of the keys no longer bound to this lease are removed.
+    async fn renew_lease(&mut self, lease_id: u64, lease: Lease) -> Result<LeaseReply, io::Error> {
+        let expire_at = (self.cmd_ctx.time() + lease.ttl).seconds();
+        let meta = MetaSpec::new_expire(expire_at).with_lease(lease_id);
+
+        let mut keys = vec![];
+        for key in self.list_bindings(lease_id).await? {
+            if !self.is_bound_to_lease(&key, lease_id).await? {
+                self.upsert_kv_record(&UpsertKV::delete(Lease::binding_key(lease_id, &key)))
+                    .await?;
+                continue;
+            }
+
+            let upsert = UpsertKV::new(&key, MatchSeq::GE(1), Operation::AsIs, Some(meta.clone()));
+            self.upsert_kv_record(&upsert).await?;
+            self.put_binding(lease_id, &key, expire_at).await?;
+            keys.push(key);
+        }
+
+        self.put_lease(lease_id, &lease, expire_at).await?;
+
+        Ok(LeaseReply {
+            lease_id,
+            ttl_ms: lease.ttl.millis(),
+            expire_at,
+            keys,
+            error: "".to_string(),
+        })
+    }
+
+    /// Assign the next lease id.
+    ///
+    /// Lease ids are assigned from a dedicated counter and are never reused,
+    /// ids granted explicitly by the client are skipped.
+    async fn next_lease_id(&mut self) -> Result<u64, io::Error> {
+        let seq_v = self.sm.get_maybe_expired_kv(Lease::ID_GEN_KEY).await?;
+        let mut lease_id: u64 = match seq_v {
+            Some(seq_v) => serde_json::from_slice(&seq_v.data)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            None => 0,
+        };
+
+        loop {
+            lease_id += 1;
+            if self.get_lease(lease_id).await?.is_none() {
+                break;
+            }
+        }
+
+        let value = serde_json::to_vec(&lease_id)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        self.upsert_kv_record(&UpsertKV::update(Lease::ID_GEN_KEY, &value))
+            .await?;
+
+        Ok(lease_id)
+    }
+
+    /// Get a lease and its expiration time in seconds since 1970.
+    async fn get_lease(&self, lease_id: u64) -> Result<Option<(u64, Lease)>, io::Error> {
+        let seq_v = self.sm.get_maybe_expired_kv(&Lease::key(lease_id)).await?;
+        let Some(seq_v) = seq_v else {
+            return Ok(None);
+        };
+
+        let lease: Lease = serde_json::from_slice(&seq_v.data)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let expire_at = seq_v.meta.eval_expire_at_ms() / 1000;
+
+        Ok(Some((expire_at, lease)))
+    }
+
+    async fn put_lease(
+        &mut self,
+        lease_id: u64,
+        lease: &Lease,
+        expire_at: u64,
+    ) -> Result<(), io::Error> {
+        let value =
+            serde_json::to_vec(lease).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let upsert =
+            UpsertKV::update(Lease::key(lease_id), &value).with(MetaSpec::new_expire(expire_at));
+
+        self.upsert_kv_record(&upsert).await?;
+        Ok(())
+    }
+
+    /// Record that `key` is bound to a lease, the binding expires along with the lease.
+    async fn put_binding(
+        &mut self,
+        lease_id: u64,
+        key: &str,
+        expire_at: u64,
+    ) -> Result<(), io::Error> {
+        let upsert = UpsertKV::update(Lease::binding_key(lease_id, key), &[])
+            .with(MetaSpec::new_expire(expire_at));
+
+        self.upsert_kv_record(&upsert).await?;
+        Ok(())
+    }
+
+    /// List the keys that have been bound to a lease,
+    /// some of them may have been deleted, updated or re-bound to another lease since then.
+    async fn list_bindings(&self, lease_id: u64) -> Result<Vec<String>, io::Error> {
+        let prefix = Lease::binding_prefix(lease_id);
+
+        let strm = self.sm.list_kv(&prefix).await?;
+        let keys = strm
+            .map_ok(|(binding_key, _seq_v)| binding_key[prefix.len()..].to_string())
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        Ok(keys)
+    }
+
+    async fn is_bound_to_lease(&self, key: &str, lease_id: u64) -> Result<bool, io::Error> {
+        let seq_v = self.sm.get_maybe_expired_kv(key).await?;
+        let lease = seq_v.and_then(|seq_v| seq_v.meta).and_then(|m| m.lease());
+        Ok(lease == Some(lease_id))
+    }
+
     #[fastrace::trace]
     async fn apply_txn(&mut self, req: &TxnRequest) -> Result<AppliedState, io::Error> {
         debug!(txn :% =(req); "apply txn cmd");
@@ -383,10 +653,12 @@ impl<'a> Applier<'a> {
         put: &TxnPutRequest,
         resp: &mut TxnReply,
     ) -> Result<(), io::Error> {
-        let upsert = UpsertKV::update(&put.key, &put.value).with(MetaSpec::new(
-            put.expire_at,
-            put.ttl_ms.map(Interval::from_millis),
-        ));
+        let meta = MetaSpec::new(put.expire_at, put.ttl_ms.map(Interval::from_millis));
+        let meta = match put.lease_id {
+            Some(lease_id) => meta.with_lease(lease_id),
+            None => meta,
+        };
+        let upsert = UpsertKV::update(&put.key, &put.value).with(meta);
 
         let (prev, _result) = self.upsert_kv(&upsert).await?;
 
@@ -440,6 +712,10 @@ impl<'a> Applier<'a> {
         let mut count = 0;
 
         while let Some((key, _seq_v)) = strm.try_next().await? {
+            if Lease::is_reserved(&key) {
+                continue;
+            }
+
             let (prev, res) = self.upsert_kv(&UpsertKV::delete(&key)).await?;
             self.push_change(key, prev, res);
             count += 1;
@@ -488,7 +764,9 @@ impl<'a> Applier<'a> {
                 assert_eq!(expire_key.seq, seq_v.seq);
                 info!("clean expired: {}, {}", key, expire_key);
 
-                self.upsert_kv(&UpsertKV::delete(key.clone())).await?;
+                // The expired lease records are cleaned too, bypass the reserved key check.
+                self.upsert_kv_record(&UpsertKV::delete(key.clone()))
+                    .await?;
             } else {
                 unreachable!(
                     "trying to remove un-cleanable: {}, {}, kv-entry: {:?}",
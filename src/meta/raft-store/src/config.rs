@@ -131,6 +131,12 @@ pub struct RaftConfig {
 
     /// Max timeout(in milli seconds) when waiting a cluster leader.
     pub wait_leader_timeout: u64,
+
+    /// Whether to serve the lease API.
+    ///
+    /// Lease commands can not be applied by a meta-service older than `LEASE_MIN_META_SEMVER`,
+    /// it should be enabled only after every node in the cluster is upgraded.
+    pub enable_lease: bool,
 }
 
 pub fn get_default_raft_advertise_host() -> String {
@@ -172,6 +178,7 @@ impl Default for RaftConfig {
             sled_max_cache_size_mb: 10 * 1024,
             cluster_name: "foo_cluster".to_string(),
             wait_leader_timeout: 70000,
+            enable_lease: false,
         }
     }
 }
@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 use std::future;
 use std::io;
+use std::ops::Range;
 
 use databend_common_meta_kvapi::kvapi;
 use databend_common_meta_kvapi::kvapi::KVStream;
@@ -232,6 +233,33 @@ impl SMV003 {
         Ok(strm.boxed())
     }
 
+    /// List kv entries in the range `[start, end)`.
+    ///
+    /// It does not check expiration of the returned entries.
+    pub async fn range_kv(
+        &self,
+        rng: Range<String>,
+    ) -> Result<IOResultStream<(String, SeqV)>, io::Error> {
+        let strm = self
+            .levels
+            .str_map()
+            .range(rng)
+            .await?
+            // Skip tombstone
+            .try_filter_map(|(k, marked)| {
+                let seqv = Into::<Option<SeqV>>::into(marked);
+                let res = seqv.map(|x| (k, x));
+                future::ready(Ok(res))
+            });
+
+        // Make it static
+
+        let vs = strm.collect::<Vec<_>>().await;
+        let strm = futures::stream::iter(vs);
+
+        Ok(strm.boxed())
+    }
+
     pub(crate) fn update_expire_cursor(&mut self, log_time_ms: u64) {
         if log_time_ms < self.expire_cursor.time_ms {
             warn!(
@@ -12,9 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_meta_types::new_log_id;
+use databend_common_meta_types::seq_value::KVMeta;
 use databend_common_meta_types::seq_value::SeqV;
 use databend_common_meta_types::seq_value::SeqValue;
+use databend_common_meta_types::AppliedState;
+use databend_common_meta_types::Cmd;
+use databend_common_meta_types::Entry;
+use databend_common_meta_types::EntryPayload;
+use databend_common_meta_types::Lease;
+use databend_common_meta_types::LeaseKeepAliveReply;
+use databend_common_meta_types::LeaseReply;
+use databend_common_meta_types::LogEntry;
+use databend_common_meta_types::MetaSpec;
 use databend_common_meta_types::UpsertKV;
+use databend_common_meta_types::With;
 use futures_util::TryStreamExt;
 use pretty_assertions::assert_eq;
 
@@ -321,6 +333,210 @@ async fn test_inserting_expired_becomes_deleting() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_lease() -> anyhow::Result<()> {
+    let mut sm = SMV003::default();
+
+    // Grant a lease with an assigned id, bind `a` and `b` to it.
+    let got = apply_at(&mut sm, 1, 1_000, Cmd::GrantLease {
+        lease_id: 0,
+        ttl_ms: 10_000,
+    })
+    .await?;
+    assert_eq!(lease_reply(1, 10_000, 11, &[]), got);
+
+    let lease_1 = MetaSpec::new_lease(1);
+    let got = apply_at(
+        &mut sm,
+        2,
+        1_000,
+        Cmd::UpsertKV(UpsertKV::update("a", b"a0").with(lease_1.clone())),
+    )
+    .await?;
+    assert!(got.result_is_some());
+
+    apply_at(
+        &mut sm,
+        3,
+        1_000,
+        Cmd::UpsertKV(UpsertKV::update("b", b"b0").with(lease_1)),
+    )
+    .await?;
+
+    let got = sm.get_maybe_expired_kv("a").await?.unwrap();
+    assert_eq!(got.meta, Some(KVMeta::new_expire(11).with_lease(Some(1))));
+
+    // One binding record for every bound key.
+    let got = sm.get_maybe_expired_kv(&Lease::binding_key(1, "a")).await?;
+    assert_eq!(got.unwrap().meta, Some(KVMeta::new_expire(11)));
+
+    // A key can not be bound to a non-existent lease.
+    let got = apply_at(
+        &mut sm,
+        4,
+        1_000,
+        Cmd::UpsertKV(UpsertKV::update("c", b"c0").with(MetaSpec::new_lease(100))),
+    )
+    .await?;
+    assert!(!got.changed());
+    assert_eq!(None, sm.get_maybe_expired_kv("c").await?);
+
+    // `b` is no longer bound to the lease after an update without lease.
+    apply_at(
+        &mut sm,
+        5,
+        1_000,
+        Cmd::UpsertKV(UpsertKV::update("b", b"b1")),
+    )
+    .await?;
+
+    // Keep alive extends the expiration time of the keys still bound to the lease,
+    // a non-existent lease in the same batch is replied with ttl 0.
+    let got = apply_at(&mut sm, 6, 5_000, Cmd::KeepAliveLeases {
+        lease_ids: vec![1, 100],
+    })
+    .await?;
+    assert_eq!(
+        AppliedState::LeaseKeepAlive(LeaseKeepAliveReply {
+            leases: vec![
+                new_lease_reply(1, 10_000, 15, &["a"]),
+                new_lease_reply(100, 0, 0, &[])
+            ],
+        }),
+        got
+    );
+
+    let got = sm.get_maybe_expired_kv("a").await?.unwrap();
+    assert_eq!(got.meta, Some(KVMeta::new_expire(15).with_lease(Some(1))));
+    assert_eq!(got.data, b("a0"));
+
+    let got = sm.get_maybe_expired_kv("b").await?.unwrap();
+    assert_eq!(got.meta, None);
+    assert_eq!(
+        None,
+        sm.get_maybe_expired_kv(&Lease::binding_key(1, "b")).await?
+    );
+
+    // A lease id in use can not be granted again.
+    let got = apply_at(&mut sm, 7, 5_000, Cmd::GrantLease {
+        lease_id: 1,
+        ttl_ms: 1_000,
+    })
+    .await?;
+    let AppliedState::Lease(reply) = got else {
+        panic!("expect AppliedState::Lease");
+    };
+    assert_eq!("lease 1 already exists", reply.error);
+
+    // The reserved lease keyspace is not writable by users.
+    let got = apply_at(
+        &mut sm,
+        8,
+        5_000,
+        Cmd::UpsertKV(UpsertKV::delete(Lease::key(1))),
+    )
+    .await?;
+    assert!(!got.changed());
+    assert!(sm.get_maybe_expired_kv(&Lease::key(1)).await?.is_some());
+
+    // Revoke deletes the lease and the keys bound to it.
+    let got = apply_at(&mut sm, 9, 6_000, Cmd::RevokeLease { lease_id: 1 }).await?;
+    assert_eq!(lease_reply(1, 10_000, 0, &["a"]), got);
+
+    assert_eq!(None, sm.get_maybe_expired_kv("a").await?);
+    assert_eq!(None, sm.get_maybe_expired_kv(&Lease::key(1)).await?);
+    assert_eq!(
+        None,
+        sm.get_maybe_expired_kv(&Lease::binding_key(1, "a")).await?
+    );
+    assert!(sm.get_maybe_expired_kv("b").await?.is_some());
+
+    // The keys are deleted along with an expired lease.
+    apply_at(&mut sm, 10, 6_000, Cmd::GrantLease {
+        lease_id: 5,
+        ttl_ms: 2_000,
+    })
+    .await?;
+    apply_at(
+        &mut sm,
+        11,
+        6_000,
+        Cmd::UpsertKV(UpsertKV::update("d", b"d0").with(MetaSpec::new_lease(5))),
+    )
+    .await?;
+
+    let got = apply_at(&mut sm, 12, 9_000, Cmd::KeepAliveLeases {
+        lease_ids: vec![5],
+    })
+    .await?;
+    assert_eq!(
+        AppliedState::LeaseKeepAlive(LeaseKeepAliveReply {
+            leases: vec![new_lease_reply(5, 0, 0, &[])],
+        }),
+        got
+    );
+    assert_eq!(None, sm.get_maybe_expired_kv("d").await?);
+    assert_eq!(None, sm.get_maybe_expired_kv(&Lease::key(5)).await?);
+    assert_eq!(
+        None,
+        sm.get_maybe_expired_kv(&Lease::binding_key(5, "d")).await?
+    );
+
+    // Lease ids are assigned from their own id space, skipping the ids in use.
+    apply_at(&mut sm, 13, 9_000, Cmd::GrantLease {
+        lease_id: 3,
+        ttl_ms: 2_000,
+    })
+    .await?;
+    let got = apply_at(&mut sm, 14, 9_000, Cmd::GrantLease {
+        lease_id: 0,
+        ttl_ms: 2_000,
+    })
+    .await?;
+    assert_eq!(lease_reply(2, 2_000, 11, &[]), got);
+
+    let got = apply_at(&mut sm, 15, 9_000, Cmd::GrantLease {
+        lease_id: 0,
+        ttl_ms: 2_000,
+    })
+    .await?;
+    assert_eq!(lease_reply(4, 2_000, 11, &[]), got);
+
+    Ok(())
+}
+
+async fn apply_at(
+    sm: &mut SMV003,
+    index: u64,
+    time_ms: u64,
+    cmd: Cmd,
+) -> anyhow::Result<AppliedState> {
+    let entry = Entry {
+        log_id: new_log_id(1, 0, index),
+        payload: EntryPayload::Normal(LogEntry {
+            txid: None,
+            time_ms: Some(time_ms),
+            cmd,
+        }),
+    };
+    let mut res = sm.apply_entries([entry]).await?;
+    Ok(res.remove(0))
+}
+
+fn lease_reply(lease_id: u64, ttl_ms: u64, expire_at: u64, keys: &[&str]) -> AppliedState {
+    AppliedState::Lease(new_lease_reply(lease_id, ttl_ms, expire_at, keys))
+}
+
+fn new_lease_reply(lease_id: u64, ttl_ms: u64, expire_at: u64, keys: &[&str]) -> LeaseReply {
+    LeaseReply {
+        lease_id,
+        ttl_ms,
+        expire_at,
+        keys: keys.iter().map(s).collect(),
+        error: "".to_string(),
+    }
+}
+
 fn s(x: impl ToString) -> String {
     x.to_string()
 }
@@ -67,6 +67,7 @@ use databend_common_meta_types::With;
 use log::debug;
 use log::error;
 use log::info;
+use log::warn;
 use num::FromPrimitive;
 use serde::Deserialize;
 use serde::Serialize;
@@ -726,6 +727,13 @@ impl StateMachine {
             }
 
             Cmd::Transaction(txn) => self.apply_txn_cmd(txn, txn_tree, kv_pairs, log_time_ms),
+
+            // Leases are only served by the meta-service,
+            // this state machine does not receive lease commands.
+            Cmd::GrantLease { .. } | Cmd::KeepAliveLeases { .. } | Cmd::RevokeLease { .. } => {
+                warn!("apply_cmd: lease is not supported: {}", cmd);
+                Ok(AppliedState::None)
+            }
         };
 
         let elapsed = now.elapsed().as_micros();
@@ -34,6 +34,11 @@ use databend_common_meta_types::protobuf::Empty;
 use databend_common_meta_types::protobuf::ExportedChunk;
 use databend_common_meta_types::protobuf::HandshakeRequest;
 use databend_common_meta_types::protobuf::HandshakeResponse;
+use databend_common_meta_types::protobuf::LeaseGrantRequest;
+use databend_common_meta_types::protobuf::LeaseKeepAliveReply;
+use databend_common_meta_types::protobuf::LeaseKeepAliveRequest;
+use databend_common_meta_types::protobuf::LeaseReply;
+use databend_common_meta_types::protobuf::LeaseRevokeRequest;
 use databend_common_meta_types::protobuf::MemberListReply;
 use databend_common_meta_types::protobuf::MemberListRequest;
 use databend_common_meta_types::protobuf::RaftReply;
@@ -42,10 +47,12 @@ use databend_common_meta_types::protobuf::StreamItem;
 use databend_common_meta_types::protobuf::WatchRequest;
 use databend_common_meta_types::protobuf::WatchResponse;
 use databend_common_meta_types::seq_value::SeqV;
+use databend_common_meta_types::txn_op;
 use databend_common_meta_types::AppliedState;
 use databend_common_meta_types::Cmd;
 use databend_common_meta_types::Endpoint;
 use databend_common_meta_types::GrpcHelper;
+use databend_common_meta_types::Lease;
 use databend_common_meta_types::LogEntry;
 use databend_common_meta_types::TxnReply;
 use databend_common_meta_types::TxnRequest;
@@ -76,6 +83,7 @@ use crate::metrics::network_metrics;
 use crate::metrics::RequestInFlight;
 use crate::version::from_digit_ver;
 use crate::version::to_digit_ver;
+use crate::version::LEASE_MIN_META_SEMVER;
 use crate::version::METASRV_SEMVER;
 use crate::version::MIN_METACLI_SEMVER;
 use crate::watcher::WatchStream;
@@ -114,6 +122,8 @@ impl MetaServiceImpl {
         let m = &self.meta_node;
         let reply = match &req {
             MetaGrpcReq::UpsertKV(a) => {
+                self.check_lease_write(&a.key, a.value_meta.as_ref().and_then(|x| x.lease()))?;
+
                 let res = m
                     .upsert_kv(a.clone())
                     .log_elapsed_info(format!("UpsertKV: {:?}", a))
@@ -179,6 +189,18 @@ impl MetaServiceImpl {
 
         info!("{}: Received TxnRequest: {}", func_name!(), txn);
 
+        for op in txn.if_then.iter().chain(txn.else_then.iter()) {
+            match &op.request {
+                Some(txn_op::Request::Put(put)) => {
+                    self.check_lease_write(&put.key, put.lease_id)?
+                }
+                Some(txn_op::Request::Delete(delete)) => {
+                    self.check_lease_write(&delete.key, None)?
+                }
+                _ => {}
+            }
+        }
+
         let ent = LogEntry::new(Cmd::Transaction(txn.clone()));
 
         let forward_req = ForwardRequest::new(1, ForwardRequestBody::Write(ent));
@@ -212,6 +234,90 @@ impl MetaServiceImpl {
 
         Ok((endpoint, txn_reply))
     }
+
+    /// Reject writing a key in the reserved lease keyspace,
+    /// and binding a key to a lease if the lease API is disabled.
+    fn check_lease_write(&self, key: &str, lease_id: Option<u64>) -> Result<(), Status> {
+        if Lease::is_reserved(key) {
+            return Err(Status::invalid_argument(format!(
+                "key is reserved for leases: {}",
+                key
+            )));
+        }
+
+        if lease_id.is_some() {
+            self.check_lease_enabled()?;
+        }
+
+        Ok(())
+    }
+
+    /// Lease commands can not be applied by an older meta-service,
+    /// they are proposed only if leases are enabled, after the whole cluster is upgraded.
+    fn check_lease_enabled(&self) -> Result<(), Status> {
+        if !self.meta_node.sto.config.enable_lease {
+            return Err(Status::unimplemented(format!(
+                "lease is disabled, set `raft_config.enable_lease` after every node is upgraded to {} or above",
+                LEASE_MIN_META_SEMVER
+            )));
+        }
+        Ok(())
+    }
+
+    /// Propose a lease command to the leader and wait for it to be applied.
+    #[fastrace::trace]
+    async fn handle_lease<R>(&self, cmd: Cmd) -> Result<(Option<Endpoint>, R), Status>
+    where R: TryFrom<AppliedState> {
+        info!("{}: Received lease request: {}", func_name!(), cmd);
+
+        let ent = LogEntry::new(cmd.clone());
+        let forward_req = ForwardRequest::new(1, ForwardRequestBody::Write(ent));
+
+        let res = self
+            .meta_node
+            .handle_forwardable_request(forward_req)
+            .log_elapsed_info(format!("LeaseRequest: {}", cmd))
+            .await;
+
+        network_metrics::incr_request_result(res.is_ok());
+
+        let (endpoint, forward_resp) = res.map_err(GrpcHelper::internal_err)?;
+
+        let applied_state: AppliedState = forward_resp.try_into().expect("expect AppliedState");
+        let reply = R::try_from(applied_state)
+            .unwrap_or_else(|_| unreachable!("unexpected AppliedState for {}", cmd));
+
+        Ok((endpoint, reply))
+    }
+
+    /// Handle a lease rpc with the same tracking, tracing and metrics as other rpc.
+    async fn lease_rpc<T: Message, R: Message + TryFrom<AppliedState>>(
+        &self,
+        request: Request<T>,
+        cmd: Cmd,
+    ) -> Result<Response<R>, Status> {
+        self.check_token(request.metadata())?;
+        self.check_lease_enabled()?;
+
+        let _guard = thread_tracking_guard(&request);
+
+        ThreadTracker::tracking_future(async move {
+            network_metrics::incr_recv_bytes(request.get_ref().encoded_len() as u64);
+            let _guard = RequestInFlight::guard();
+
+            let root =
+                databend_common_tracing::start_trace_for_remote_request(func_path!(), &request);
+            let (endpoint, reply) = self.handle_lease::<R>(cmd).in_span(root).await?;
+
+            network_metrics::incr_sent_bytes(reply.encoded_len() as u64);
+
+            let mut resp = Response::new(reply);
+            GrpcHelper::add_response_meta_leader(&mut resp, endpoint.as_ref());
+
+            Ok(resp)
+        })
+        .await
+    }
 }
 
 impl NamedService for MetaServiceImpl {
@@ -349,6 +455,54 @@ impl MetaService for MetaServiceImpl {
         .await
     }
 
+    async fn lease_grant(
+        &self,
+        request: Request<LeaseGrantRequest>,
+    ) -> Result<Response<LeaseReply>, Status> {
+        let req = request.get_ref();
+
+        // Expiration time is stored in seconds.
+        if req.ttl_ms < 1_000 {
+            return Err(Status::invalid_argument(format!(
+                "lease ttl must be at least 1 second, got: {}ms",
+                req.ttl_ms
+            )));
+        }
+
+        let cmd = Cmd::GrantLease {
+            lease_id: req.lease_id,
+            ttl_ms: req.ttl_ms,
+        };
+        let resp: Response<LeaseReply> = self.lease_rpc(request, cmd).await?;
+
+        // The lease id is in use.
+        if !resp.get_ref().error.is_empty() {
+            return Err(Status::already_exists(resp.get_ref().error.clone()));
+        }
+
+        Ok(resp)
+    }
+
+    async fn lease_keep_alive(
+        &self,
+        request: Request<LeaseKeepAliveRequest>,
+    ) -> Result<Response<LeaseKeepAliveReply>, Status> {
+        let cmd = Cmd::KeepAliveLeases {
+            lease_ids: request.get_ref().lease_ids.clone(),
+        };
+        self.lease_rpc(request, cmd).await
+    }
+
+    async fn lease_revoke(
+        &self,
+        request: Request<LeaseRevokeRequest>,
+    ) -> Result<Response<LeaseReply>, Status> {
+        let cmd = Cmd::RevokeLease {
+            lease_id: request.get_ref().lease_id,
+        };
+        self.lease_rpc(request, cmd).await
+    }
+
     type ExportStream = Pin<Box<dyn Stream<Item = Result<ExportedChunk, Status>> + Send + 'static>>;
 
     /// Export all meta data.
@@ -415,18 +569,15 @@ impl MetaService for MetaServiceImpl {
 
         let mn = &self.meta_node;
 
-        let add_res = mn.add_watcher(request.into_inner(), tx).await;
+        let (watcher, initial) = mn.add_watcher(request.into_inner(), tx).await?;
 
-        match add_res {
-            Ok(watcher) => {
-                let stream = WatchStream::new(rx, watcher, mn.dispatcher_handle.clone());
-                Ok(Response::new(Box::pin(stream) as Self::WatchStream))
-            }
-            Err(e) => {
-                // TODO: test error return.
-                Err(Status::invalid_argument(e))
-            }
-        }
+        let stream = WatchStream::new(rx, watcher, mn.dispatcher_handle.clone());
+
+        // The initial events are sent before the change events.
+        let initial = futures::stream::iter(initial.into_iter().map(Ok));
+        let stream = initial.chain(stream);
+
+        Ok(Response::new(Box::pin(stream) as Self::WatchStream))
     }
 
     async fn member_list(
@@ -20,6 +20,7 @@ use clap::Parser;
 use databend_common_meta_raft_store::config::get_default_raft_advertise_host;
 use databend_common_meta_raft_store::config::RaftConfig as InnerRaftConfig;
 use databend_common_meta_types::MetaStartupError;
+use databend_common_tracing::AuditLogConfig;
 use databend_common_tracing::Config as InnerLogConfig;
 use databend_common_tracing::FileConfig as InnerFileLogConfig;
 use databend_common_tracing::OTLPConfig;
@@ -303,6 +304,7 @@ pub struct ConfigViaEnv {
     pub sled_tree_prefix: String,
     pub sled_max_cache_size_mb: u64,
     pub cluster_name: String,
+    pub raft_enable_lease: bool,
 }
 
 impl Default for ConfigViaEnv {
@@ -356,6 +358,7 @@ impl From<Config> for ConfigViaEnv {
             sled_tree_prefix: cfg.raft_config.sled_tree_prefix,
             sled_max_cache_size_mb: cfg.raft_config.sled_max_cache_size_mb,
             cluster_name: cfg.raft_config.cluster_name,
+            raft_enable_lease: cfg.raft_config.enable_lease,
         }
     }
 }
@@ -393,6 +396,7 @@ impl Into<Config> for ConfigViaEnv {
             sled_tree_prefix: self.sled_tree_prefix,
             sled_max_cache_size_mb: self.sled_max_cache_size_mb,
             cluster_name: self.cluster_name,
+            enable_lease: self.raft_enable_lease,
         };
         let log_config = LogConfig {
             file: FileLogConfig {
@@ -564,6 +568,13 @@ pub struct RaftConfig {
     /// Max timeout(in milli seconds) when waiting a cluster leader.
     #[clap(long, default_value = "180000")]
     pub wait_leader_timeout: u64,
+
+    /// Whether to serve the lease API.
+    ///
+    /// A meta-service older than the version that introduces leases can not apply lease commands,
+    /// enable it only after every node in the cluster is upgraded.
+    #[clap(long)]
+    pub enable_lease: bool,
 }
 
 // TODO(rotbl): should not be used.
@@ -602,6 +613,7 @@ impl From<RaftConfig> for InnerRaftConfig {
             sled_max_cache_size_mb: x.sled_max_cache_size_mb,
             cluster_name: x.cluster_name,
             wait_leader_timeout: x.wait_leader_timeout,
+            enable_lease: x.enable_lease,
         }
     }
 }
@@ -635,6 +647,7 @@ impl From<InnerRaftConfig> for RaftConfig {
             sled_max_cache_size_mb: inner.sled_max_cache_size_mb,
             cluster_name: inner.cluster_name,
             wait_leader_timeout: inner.wait_leader_timeout,
+            enable_lease: inner.enable_lease,
         }
     }
 }
@@ -664,6 +677,7 @@ impl Into<InnerLogConfig> for LogConfig {
             otlp: OTLPConfig::default(),
             query: QueryLogConfig::default(),
             profile: ProfileLogConfig::default(),
+            audit: AuditLogConfig::default(),
             structlog: StructLogConfig::default(),
             tracing: TracingConfig::default(),
         }
@@ -32,12 +32,18 @@ use databend_common_meta_client::reply_to_api_result;
 use databend_common_meta_client::RequestFor;
 use databend_common_meta_raft_store::config::RaftConfig;
 use databend_common_meta_raft_store::ondisk::DATA_VERSION;
+use databend_common_meta_raft_store::sm_v003::SMV003;
 use databend_common_meta_sled_store::openraft;
 use databend_common_meta_sled_store::openraft::ChangeMembers;
 use databend_common_meta_stoerr::MetaStorageError;
+use databend_common_meta_types::protobuf as pb;
 use databend_common_meta_types::protobuf::raft_service_client::RaftServiceClient;
 use databend_common_meta_types::protobuf::raft_service_server::RaftServiceServer;
+use databend_common_meta_types::protobuf::Event;
 use databend_common_meta_types::protobuf::WatchRequest;
+use databend_common_meta_types::protobuf::WatchResponse;
+use databend_common_meta_types::seq_value::SeqV;
+use databend_common_meta_types::seq_value::SeqValue;
 use databend_common_meta_types::AppliedState;
 use databend_common_meta_types::Cmd;
 use databend_common_meta_types::CommittedLeaderId;
@@ -61,6 +67,7 @@ use databend_common_meta_types::TypeConfig;
 use fastrace::func_name;
 use fastrace::prelude::*;
 use futures::channel::oneshot;
+use futures::TryStreamExt;
 use itertools::Itertools;
 use log::debug;
 use log::error;
@@ -71,6 +78,7 @@ use openraft::Config;
 use openraft::Raft;
 use openraft::ServerState;
 use openraft::SnapshotPolicy;
+use tonic::Status;
 
 use crate::configs::Config as MetaConfig;
 use crate::message::ForwardRequest;
@@ -92,6 +100,7 @@ use crate::version::METASRV_COMMIT_VERSION;
 use crate::watcher::DispatcherSender;
 use crate::watcher::EventDispatcher;
 use crate::watcher::EventDispatcherHandle;
+use crate::watcher::WatchReplay;
 use crate::watcher::Watcher;
 use crate::watcher::WatcherSender;
 use crate::Opened;
@@ -1116,22 +1125,148 @@ impl MetaNode {
         }
     }
 
+    /// Add a watcher and build the events to send before the change events.
+    ///
+    /// The events are the present key-values in the range if `initial_flush` is set,
+    /// or the missed changes if `since_seq` is set,
+    /// followed by a response without event to mark the beginning of change events.
     pub(crate) async fn add_watcher(
         &self,
         request: WatchRequest,
         tx: WatcherSender,
-    ) -> Result<Watcher, &'static str> {
+    ) -> Result<(Watcher, Vec<WatchResponse>), Status> {
+        let initial_flush = request.initial_flush;
+        let since_seq = request.since_seq;
+        let filter = request.filter_type();
+
+        if initial_flush && since_seq.is_some() {
+            return Err(Status::invalid_argument(
+                "initial_flush and since_seq can not be used together",
+            ));
+        }
+
+        let key_range = EventDispatcher::build_key_range(request.key.clone(), &request.key_end)
+            .map_err(Status::invalid_argument)?;
+
+        // Hold the state machine so that no change is applied until the watcher is added,
+        // thus no change is missed or duplicated between the initial key-values and the change events.
+        let sm = self.sto.get_state_machine().await;
+
+        let mut events = vec![];
+
+        if initial_flush {
+            let now_ms = SeqV::<()>::now_ms();
+
+            let present = if key_range.start == key_range.end {
+                let got = sm.get_maybe_expired_kv(&key_range.start).await;
+                let got = got.map_err(|e| Status::internal(e.to_string()))?;
+                got.map(|v| (key_range.start.clone(), v))
+                    .into_iter()
+                    .collect::<Vec<_>>()
+            } else {
+                let strm = sm.range_kv(key_range.clone()).await;
+                let strm = strm.map_err(|e| Status::internal(e.to_string()))?;
+                strm.try_collect::<Vec<_>>()
+                    .await
+                    .map_err(|e| Status::internal(e.to_string()))?
+            };
+
+            for (key, seq_v) in present {
+                if seq_v.is_expired(now_ms) {
+                    continue;
+                }
+
+                events.push(WatchResponse {
+                    event: Some(Event {
+                        key,
+                        current: Some(pb::SeqV::from(seq_v)),
+                        prev: None,
+                    }),
+                    is_initialization: true,
+                });
+            }
+        }
+
+        // The changes since `since_seq` are rebuilt from the last snapshot and the raft-logs after it.
+        // Collect them before the watcher is added, and replay them after the lock is released.
+        let replay = match since_seq {
+            Some(seq) => Some(self.watch_replay_since(&sm, seq).await?),
+            None => None,
+        };
+
         let (resp_tx, resp_rx) = oneshot::channel();
 
-        self.dispatcher_handle.request(|d: &mut EventDispatcher| {
-            let add_res = d.add_watcher(request, tx);
-            let _ = resp_tx.send(add_res);
-        });
+        self.dispatcher_handle
+            .request(move |d: &mut EventDispatcher| {
+                let add_res = d.add_watcher(request, tx);
+                let _ = resp_tx.send(add_res);
+            });
+
+        drop(sm);
 
         let recv_res = resp_rx.await;
-        match recv_res {
-            Ok(add_res) => add_res,
-            Err(_e) => Err("dispatcher closed"),
+        let watcher = match recv_res {
+            Ok(add_res) => add_res.map_err(Status::invalid_argument)?,
+            Err(_e) => return Err(Status::internal("dispatcher closed")),
+        };
+
+        if let (Some(replay), Some(seq)) = (replay, since_seq) {
+            let replayed = replay
+                .replay(seq, &key_range, filter)
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?;
+            events.extend(replayed);
+        }
+
+        if initial_flush || since_seq.is_some() {
+            events.push(WatchResponse {
+                event: None,
+                is_initialization: false,
+            });
+        }
+
+        Ok((watcher, events))
+    }
+
+    /// Collect the last snapshot and the raft-logs applied after it, to replay the changes since `since_seq`.
+    ///
+    /// It returns `OUT_OF_RANGE` if `since_seq` is before the last snapshot,
+    /// or the logs after the snapshot are not available on this node.
+    /// In such case the client should re-watch with `initial_flush`.
+    async fn watch_replay_since(&self, sm: &SMV003, since_seq: u64) -> Result<WatchReplay, Status> {
+        let snapshot = sm.get_snapshot();
+
+        let min_seq = WatchReplay::min_since_seq(snapshot.as_ref());
+        if since_seq < min_seq {
+            return Err(Status::out_of_range(format!(
+                "since_seq {} is before the last snapshot seq {}, re-watch with initial_flush",
+                since_seq, min_seq
+            )));
         }
+
+        let start = snapshot
+            .as_ref()
+            .and_then(|db| db.snapshot_meta().last_log_id)
+            .map_or(0, |log_id| log_id.index + 1);
+
+        let entries = match sm.sys_data_ref().last_applied_ref() {
+            Some(last_applied) if last_applied.index >= start => {
+                let log = self.sto.log.read().await;
+                let entries = log
+                    .range_values(start..=last_applied.index)
+                    .map_err(|e| Status::internal(e.to_string()))?;
+
+                if entries.len() as u64 != last_applied.index - start + 1 {
+                    return Err(Status::out_of_range(format!(
+                        "raft-logs since {} are purged, re-watch with initial_flush",
+                        start
+                    )));
+                }
+                entries
+            }
+            _ => vec![],
+        };
+
+        Ok(WatchReplay::new(snapshot, entries))
     }
 }
@@ -68,8 +68,18 @@ pub static MIN_METACLI_SEMVER: Version = Version::new(0, 9, 41);
 ///
 /// - 2023-11-16: since 1.2.212:
 ///   Add install_snapshot_v1
+///
+/// - 2026-10-19: since 1.2.600:
+///   Add raft-log commands `GrantLease`, `KeepAliveLeases`, `RevokeLease` and the `lease` field of `MetaSpec`,
+///   an older node can not apply them.
+///   They are proposed only if `raft_config.enable_lease` is set, see [`LEASE_MIN_META_SEMVER`].
 pub static MIN_META_SEMVER: Version = Version::new(0, 9, 41);
 
+/// The min meta-server version that can apply the lease commands.
+///
+/// `raft_config.enable_lease` must not be set until every node in the cluster runs this version or above.
+pub static LEASE_MIN_META_SEMVER: Version = Version::new(1, 2, 600);
+
 /// Defines the feature set provided and required by raft server and client.
 ///
 /// - The server depends on a sub set of the features provided by the client.
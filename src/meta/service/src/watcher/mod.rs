@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod watch_replay;
 mod watcher_manager;
 mod watcher_stream;
 
+pub(crate) use watch_replay::WatchReplay;
 pub(crate) use watcher_manager::DispatcherSender;
 pub(crate) use watcher_manager::EventDispatcher;
 pub use watcher_manager::EventDispatcherHandle;
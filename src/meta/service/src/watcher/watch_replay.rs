@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::ops::Range;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use databend_common_meta_raft_store::sm_v003::SMV003;
+use databend_common_meta_raft_store::state_machine::StateMachineSubscriber;
+use databend_common_meta_types::protobuf as pb;
+use databend_common_meta_types::protobuf::watch_request::FilterType;
+use databend_common_meta_types::protobuf::Event;
+use databend_common_meta_types::protobuf::WatchResponse;
+use databend_common_meta_types::snapshot_db::DB;
+use databend_common_meta_types::Change;
+use databend_common_meta_types::Entry;
+use databend_common_meta_types::StorageError;
+
+/// Collects the kv changes made when re-applying raft-logs.
+#[derive(Debug, Clone, Default)]
+struct ChangeCollector {
+    changes: Arc<Mutex<Vec<Change<Vec<u8>, String>>>>,
+}
+
+impl StateMachineSubscriber for ChangeCollector {
+    fn kv_changed(&self, change: Change<Vec<u8>, String>) {
+        self.changes.lock().unwrap().push(change);
+    }
+}
+
+/// Rebuilds the kv changes after a seq for a watcher that resumes from `since_seq`.
+///
+/// The changes are rebuilt by re-applying the raft-logs after the last snapshot onto the snapshot,
+/// in a temporary state machine.
+/// The logs after the last snapshot are never purged,
+/// thus a watcher can resume from any seq since the last snapshot, on any node.
+pub(crate) struct WatchReplay {
+    snapshot: Option<DB>,
+    entries: Vec<Entry>,
+}
+
+impl WatchReplay {
+    /// Create a replay from the last snapshot and all of the logs applied after it.
+    pub(crate) fn new(snapshot: Option<DB>, entries: Vec<Entry>) -> Self {
+        Self { snapshot, entries }
+    }
+
+    /// The seq of the state machine at the snapshot, the smallest seq a watcher can resume from.
+    pub(crate) fn min_since_seq(snapshot: Option<&DB>) -> u64 {
+        snapshot.map_or(0, |db| db.sys_data().curr_seq())
+    }
+
+    /// Build the events in `key_range` that happened after `since_seq`.
+    ///
+    /// An update is included if its seq is greater than `since_seq`,
+    /// a delete is included if it happens at or after `since_seq`.
+    /// Thus a watcher may receive a delete event it has already seen, which is harmless.
+    pub(crate) async fn replay(
+        self,
+        since_seq: u64,
+        key_range: &Range<String>,
+        filter: FilterType,
+    ) -> Result<Vec<WatchResponse>, StorageError> {
+        let mut last_seq = Self::min_since_seq(self.snapshot.as_ref());
+
+        let mut sm = SMV003::default();
+        if let Some(db) = self.snapshot {
+            sm.install_snapshot_v003(db)
+                .await
+                .map_err(|e| StorageError::read_snapshot(None, &e))?;
+        }
+
+        let collector = ChangeCollector::default();
+        sm.set_subscriber(Box::new(collector.clone()));
+        sm.apply_entries(self.entries).await?;
+
+        let changes = std::mem::take(&mut *collector.changes.lock().unwrap());
+
+        let mut events = vec![];
+
+        for change in changes {
+            // An update is tagged with the seq of the new value,
+            // a delete is tagged with the last seq when it happens.
+            if let Some(current) = &change.result {
+                last_seq = std::cmp::max(last_seq, current.seq);
+            }
+
+            let k = change.ident.as_ref().unwrap();
+            let in_range = if key_range.start == key_range.end {
+                k == &key_range.start
+            } else {
+                key_range.contains(k)
+            };
+
+            if !in_range {
+                continue;
+            }
+
+            let is_delete_event = change.result.is_none();
+            let is_missed = if is_delete_event {
+                last_seq >= since_seq
+            } else {
+                last_seq > since_seq
+            };
+
+            if !is_missed
+                || (filter == FilterType::Delete && !is_delete_event)
+                || (filter == FilterType::Update && is_delete_event)
+            {
+                continue;
+            }
+
+            events.push(WatchResponse {
+                event: Some(Event {
+                    key: k.to_string(),
+                    current: change.result.map(pb::SeqV::from),
+                    prev: change.prev.map(pb::SeqV::from),
+                }),
+                is_initialization: false,
+            });
+        }
+
+        Ok(events)
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test grpc API: lease_grant(), lease_keep_alive() and lease_revoke().
+
+use std::time::Duration;
+
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::Lease;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::TxnOp;
+use databend_common_meta_types::TxnRequest;
+use databend_common_meta_types::With;
+use pretty_assertions::assert_eq;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+use crate::tests::service::MetaSrvTestContext;
+use crate::tests::start_metasrv_with_context;
+
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_lease() -> anyhow::Result<()> {
+    // - Start a metasrv server with lease enabled.
+    // - Grant a lease and bind keys to it with upsert and transaction.
+    // - Keep alive the lease, then revoke it; the bound keys are removed.
+
+    let mut tc = MetaSrvTestContext::new(0);
+    tc.config.raft_config.enable_lease = true;
+    start_metasrv_with_context(&mut tc).await?;
+
+    let client = tc.grpc_client().await?;
+
+    let lease = client.lease_grant(0, Duration::from_secs(10)).await?;
+    let lease_id = lease.lease_id;
+    assert!(lease_id > 0);
+    assert_eq!(10_000, lease.ttl_ms);
+
+    client
+        .upsert_kv(UpsertKVReq::update("a", b"a").with(MetaSpec::new_lease(lease_id)))
+        .await?;
+    client
+        .transaction(TxnRequest::unconditional(vec![TxnOp::put_with_lease(
+            "b",
+            b"b".to_vec(),
+            lease_id,
+        )]))
+        .await?;
+
+    let got = client.get_kv("a").await?.unwrap();
+    let expire_at_ms = got.meta.unwrap().get_expire_at_ms();
+    assert_eq!(Some(lease.expire_at * 1000), expire_at_ms);
+
+    // A lease id in use can not be granted again.
+    let res = client.lease_grant(lease_id, Duration::from_secs(10)).await;
+    assert!(res.is_err());
+
+    // The reserved lease keyspace can not be written.
+    let res = client
+        .upsert_kv(UpsertKVReq::delete(Lease::key(lease_id)))
+        .await;
+    assert!(res.is_err());
+
+    // Keep alive more than one lease in one request.
+    let got = client
+        .lease_keep_alive(vec![lease_id, lease_id + 100])
+        .await?;
+    assert_eq!(2, got.leases.len());
+    assert_eq!(vec!["a".to_string(), "b".to_string()], got.leases[0].keys);
+    assert_eq!(10_000, got.leases[0].ttl_ms);
+    assert_eq!(0, got.leases[1].ttl_ms);
+
+    let got = client.lease_revoke(lease_id).await?;
+    assert_eq!(vec!["a".to_string(), "b".to_string()], got.keys);
+
+    assert!(client.get_kv("a").await?.is_none());
+    assert!(client.get_kv("b").await?.is_none());
+
+    // A revoked lease can not be kept alive.
+    let got = client.lease_keep_alive(vec![lease_id]).await?;
+    assert_eq!(0, got.leases[0].ttl_ms);
+
+    // A ttl shorter than one second is rejected.
+    let res = client.lease_grant(0, Duration::from_millis(100)).await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_lease_disabled() -> anyhow::Result<()> {
+    // Leases are disabled by default, until every node in the cluster is upgraded.
+
+    let (tc, _addr) = crate::tests::start_metasrv().await?;
+
+    let client = tc.grpc_client().await?;
+
+    let res = client.lease_grant(0, Duration::from_secs(10)).await;
+    assert!(res.is_err());
+
+    let res = client
+        .upsert_kv(UpsertKVReq::update("a", b"a").with(MetaSpec::new_lease(1)))
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
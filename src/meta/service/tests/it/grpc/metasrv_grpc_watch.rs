@@ -19,6 +19,7 @@ use std::time::UNIX_EPOCH;
 
 use databend_common_base::base::tokio;
 use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::base::Stoppable;
 use databend_common_meta_client::ClientHandle;
 use databend_common_meta_client::MetaGrpcClient;
 use databend_common_meta_kvapi::kvapi;
@@ -43,6 +44,9 @@ use log::info;
 use test_harness::test;
 
 use crate::testing::meta_service_test_harness;
+use crate::tests::service::start_metasrv_cluster;
+use crate::tests::service::MetaSrvTestContext;
+use crate::tests::start_metasrv_with_context;
 
 async fn test_watch_main(
     addr: String,
@@ -131,6 +135,8 @@ async fn test_watch() -> anyhow::Result<()> {
             key: "a".to_string(),
             key_end: Some("z".to_string()),
             filter_type: FilterType::All.into(),
+            initial_flush: false,
+            since_seq: None,
         };
 
         let key_a = s("a");
@@ -189,6 +195,8 @@ async fn test_watch() -> anyhow::Result<()> {
             key_end: None,
             // filter only delete events
             filter_type: FilterType::Delete.into(),
+            initial_flush: false,
+            since_seq: None,
         };
 
         let key = s(key_str);
@@ -252,6 +260,8 @@ async fn test_watch() -> anyhow::Result<()> {
             key: start,
             key_end: Some(end),
             filter_type: FilterType::All.into(),
+            initial_flush: false,
+            since_seq: None,
         };
 
         let conditions = vec![TxnCondition {
@@ -363,6 +373,8 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
             key: start,
             key_end: Some(end),
             filter_type: FilterType::All.into(),
+            initial_flush: false,
+            since_seq: None,
         };
         watch_client.request(watch).await?
     };
@@ -437,6 +449,238 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_watch_initial_flush() -> anyhow::Result<()> {
+    // - Write some data.
+    // - Watch with initial_flush, the present key-values are sent first.
+    // - Then the change events are sent.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = make_client(&addr)?;
+    client.upsert_kv(UpsertKVReq::update("a", b"a")).await?;
+    client.upsert_kv(UpsertKVReq::update("b", b"b")).await?;
+    client.upsert_kv(UpsertKVReq::update("z", b"z")).await?;
+
+    let watch = WatchRequest {
+        key: s("a"),
+        key_end: Some(s("c")),
+        filter_type: FilterType::All.into(),
+        initial_flush: true,
+        since_seq: None,
+    };
+
+    let mut client_stream = client.request(watch).await?;
+
+    let msg = client_stream.message().await?.unwrap();
+    assert_eq!(Some(add_event("a", 1, "a", None)), msg.event);
+    assert!(msg.is_initialization);
+
+    let msg = client_stream.message().await?.unwrap();
+    assert_eq!(Some(add_event("b", 2, "b", None)), msg.event);
+    assert!(msg.is_initialization);
+
+    // End of initialization
+    let msg = client_stream.message().await?.unwrap();
+    assert_eq!(None, msg.event);
+
+    client.upsert_kv(UpsertKVReq::update("a", b"a2")).await?;
+
+    let msg = client_stream.message().await?.unwrap();
+    let want = Event {
+        key: s("a"),
+        prev: pb_seqv(1, "a", None),
+        current: pb_seqv(4, "a2", None),
+    };
+    assert_eq!(Some(want), msg.event);
+    assert!(!msg.is_initialization);
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_watch_since_seq() -> anyhow::Result<()> {
+    // - Write some data.
+    // - Watch since a seq, the changes after it are replayed first.
+    // - Then the change events are sent.
+
+    let (tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = make_client(&addr)?;
+    client.upsert_kv(UpsertKVReq::update("a", b"a")).await?;
+    client.upsert_kv(UpsertKVReq::update("b", b"b")).await?;
+
+    let watch = |since_seq: u64| WatchRequest {
+        key: s("a"),
+        key_end: Some(s("c")),
+        filter_type: FilterType::All.into(),
+        initial_flush: false,
+        since_seq: Some(since_seq),
+    };
+
+    info!("changes since seq 0 are replayed from the raft-log");
+    {
+        let mut client_stream = client.request(watch(0)).await?;
+
+        let msg = client_stream.message().await?.unwrap();
+        assert_eq!(Some(add_event("a", 1, "a", None)), msg.event);
+
+        let msg = client_stream.message().await?.unwrap();
+        assert_eq!(Some(add_event("b", 2, "b", None)), msg.event);
+
+        let msg = client_stream.message().await?.unwrap();
+        assert_eq!(None, msg.event);
+    }
+
+    info!("can not be used with initial_flush");
+    {
+        let mut req = watch(1);
+        req.initial_flush = true;
+        let res = client.request(req).await;
+        assert!(res.is_err());
+    }
+
+    let mut client_stream = client.request(watch(1)).await?;
+
+    let msg = client_stream.message().await?.unwrap();
+    assert_eq!(Some(add_event("b", 2, "b", None)), msg.event);
+    assert!(!msg.is_initialization);
+
+    // End of replay
+    let msg = client_stream.message().await?.unwrap();
+    assert_eq!(None, msg.event);
+
+    client.upsert_kv(UpsertKVReq::delete("a")).await?;
+
+    let msg = client_stream.message().await?.unwrap();
+    assert_eq!(Some(del_event("a", 1, "a", None)), msg.event);
+
+    info!("changes before the last snapshot can not be replayed");
+    {
+        let mn = meta_node_of(&tc);
+        mn.raft.trigger().snapshot().await?;
+        mn.raft
+            .wait(timeout())
+            .metrics(|m| m.snapshot.is_some(), "snapshot is built")
+            .await?;
+
+        let res = client.request(watch(1)).await;
+        assert!(res.is_err());
+    }
+
+    Ok(())
+}
+
+/// - Write some data, then restart the node.
+/// - Watch since a seq on the restarted node, the changes are replayed from the raft-log.
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_watch_since_seq_restarted_node() -> anyhow::Result<()> {
+    let (mut tc, addr) = crate::tests::start_metasrv().await?;
+
+    {
+        let client = make_client(&addr)?;
+        client.upsert_kv(UpsertKVReq::update("a", b"a")).await?;
+        client.upsert_kv(UpsertKVReq::update("b", b"b")).await?;
+        client.upsert_kv(UpsertKVReq::delete("a")).await?;
+    }
+
+    let last_applied = meta_node_of(&tc).raft.metrics().borrow().last_applied;
+
+    info!("--- restart the node");
+    {
+        let mut srv = tc.grpc_srv.take().unwrap();
+        srv.stop(None).await?;
+
+        start_metasrv_with_context(&mut tc).await?;
+
+        meta_node_of(&tc)
+            .raft
+            .wait(timeout())
+            .applied_index(last_applied.map(|x| x.index), "logs are re-applied")
+            .await?;
+    }
+
+    let client = make_client(&addr)?;
+
+    let watch = WatchRequest {
+        key: s("a"),
+        key_end: Some(s("c")),
+        filter_type: FilterType::All.into(),
+        initial_flush: false,
+        since_seq: Some(1),
+    };
+
+    let mut client_stream = client.request(watch).await?;
+
+    let msg = client_stream.message().await?.unwrap();
+    assert_eq!(Some(add_event("b", 2, "b", None)), msg.event);
+
+    let msg = client_stream.message().await?.unwrap();
+    assert_eq!(Some(del_event("a", 1, "a", None)), msg.event);
+
+    // End of replay
+    let msg = client_stream.message().await?.unwrap();
+    assert_eq!(None, msg.event);
+
+    client.upsert_kv(UpsertKVReq::update("b", b"b2")).await?;
+
+    let msg = client_stream.message().await?.unwrap();
+    let want = Event {
+        key: s("b"),
+        prev: pb_seqv(2, "b", None),
+        current: pb_seqv(3, "b2", None),
+    };
+    assert_eq!(Some(want), msg.event);
+
+    Ok(())
+}
+
+/// - Write some data on the leader.
+/// - Watch since a seq on a follower, the changes are replayed from the follower's raft-log.
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_watch_since_seq_on_another_node() -> anyhow::Result<()> {
+    let tcs = start_metasrv_cluster(&[0, 1, 2]).await?;
+
+    {
+        let client = tcs[0].grpc_client().await?;
+        client.upsert_kv(UpsertKVReq::update("a", b"a")).await?;
+        client.upsert_kv(UpsertKVReq::update("b", b"b")).await?;
+    }
+
+    let last_applied = meta_node_of(&tcs[0]).raft.metrics().borrow().last_applied;
+
+    meta_node_of(&tcs[2])
+        .raft
+        .wait(timeout())
+        .applied_index(last_applied.map(|x| x.index), "follower applied the logs")
+        .await?;
+
+    let client = tcs[2].grpc_client().await?;
+
+    let watch = WatchRequest {
+        key: s("a"),
+        key_end: Some(s("c")),
+        filter_type: FilterType::All.into(),
+        initial_flush: false,
+        since_seq: Some(1),
+    };
+
+    let mut client_stream = client.request(watch).await?;
+
+    let msg = client_stream.message().await?.unwrap();
+    assert_eq!(Some(add_event("b", 2, "b", None)), msg.event);
+
+    // End of replay
+    let msg = client_stream.message().await?.unwrap();
+    assert_eq!(None, msg.event);
+
+    Ok(())
+}
+
 #[test(harness = meta_service_test_harness)]
 #[fastrace::trace]
 async fn test_watch_stream_count() -> anyhow::Result<()> {
@@ -448,6 +692,8 @@ async fn test_watch_stream_count() -> anyhow::Result<()> {
         key: "a".to_string(),
         key_end: Some("z".to_string()),
         filter_type: FilterType::All.into(),
+        initial_flush: false,
+        since_seq: None,
     };
 
     let client1 = make_client(&addr)?;
@@ -536,6 +782,14 @@ fn add_event(key: &str, res_seq: u64, res_val: &str, meta: Option<KvMeta>) -> Ev
     }
 }
 
+fn meta_node_of(tc: &MetaSrvTestContext) -> Arc<MetaNode> {
+    tc.grpc_srv.as_ref().unwrap().get_meta_node()
+}
+
+fn timeout() -> Option<Duration> {
+    Some(Duration::from_millis(30_000))
+}
+
 fn make_client(addr: impl ToString) -> anyhow::Result<Arc<ClientHandle>> {
     let client = MetaGrpcClient::try_create(
         vec![addr.to_string()],
@@ -130,6 +130,14 @@ fn build_proto() {
             "Event",
             "#[derive(Eq, serde::Serialize, serde::Deserialize, deepsize::DeepSizeOf)]",
         )
+        .type_attribute(
+            "LeaseReply",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize, deepsize::DeepSizeOf)]",
+        )
+        .type_attribute(
+            "LeaseKeepAliveReply",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize, deepsize::DeepSizeOf)]",
+        )
         .type_attribute(
             "KVMeta",
             "#[derive(Eq, serde::Serialize, serde::Deserialize, deepsize::DeepSizeOf)]",
@@ -138,6 +146,10 @@ fn build_proto() {
             "TxnPutRequest.ttl_ms",
             r#"#[serde(skip_serializing_if = "Option::is_none")]"#,
         )
+        .field_attribute(
+            "TxnPutRequest.lease_id",
+            r#"#[serde(skip_serializing_if = "Option::is_none")]"#,
+        )
         .compile_with_config(config, &protos, &[&proto_dir])
         .unwrap();
 }
@@ -17,6 +17,8 @@ use std::fmt::Formatter;
 
 use crate::protobuf::RaftReply;
 use crate::Change;
+use crate::LeaseKeepAliveReply;
+use crate::LeaseReply;
 use crate::Node;
 use crate::TxnReply;
 
@@ -42,6 +44,10 @@ pub enum AppliedState {
 
     TxnReply(TxnReply),
 
+    Lease(LeaseReply),
+
+    LeaseKeepAlive(LeaseKeepAliveReply),
+
     #[try_into(ignore)]
     None,
 }
@@ -59,6 +65,12 @@ impl fmt::Display for AppliedState {
             AppliedState::TxnReply(txnreply) => {
                 write!(f, "Txn: {}", txnreply)
             }
+            AppliedState::Lease(lease) => {
+                write!(f, "Lease: {}", lease)
+            }
+            AppliedState::LeaseKeepAlive(reply) => {
+                write!(f, "LeaseKeepAlive: {}", reply)
+            }
             AppliedState::None => {
                 write!(f, "None")
             }
@@ -77,6 +89,8 @@ impl AppliedState {
             AppliedState::KV(ref ch) => ch.is_changed(),
             AppliedState::None => false,
             AppliedState::TxnReply(txn) => txn.success,
+            AppliedState::Lease(lease) => lease.ttl_ms > 0,
+            AppliedState::LeaseKeepAlive(reply) => reply.leases.iter().any(|l| l.ttl_ms > 0),
         }
     }
 
@@ -102,6 +116,8 @@ impl AppliedState {
             AppliedState::KV(Change { ref prev, .. }) => prev.is_none(),
             AppliedState::None => true,
             AppliedState::TxnReply(_txn) => true,
+            AppliedState::Lease(_lease) => true,
+            AppliedState::LeaseKeepAlive(_reply) => true,
         }
     }
 
@@ -111,6 +127,8 @@ impl AppliedState {
             AppliedState::KV(Change { ref result, .. }) => result.is_none(),
             AppliedState::None => true,
             AppliedState::TxnReply(txn) => !txn.success,
+            AppliedState::Lease(lease) => lease.ttl_ms == 0,
+            AppliedState::LeaseKeepAlive(reply) => reply.leases.iter().all(|l| l.ttl_ms == 0),
         }
     }
 }
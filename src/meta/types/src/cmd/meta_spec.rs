@@ -40,6 +40,15 @@ pub struct MetaSpec {
     /// For backward compatibility, this field is not serialized if it `None`, as if it does not exist.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) ttl: Option<Interval>,
+
+    /// The lease to bind the record to.
+    ///
+    /// If it is not None, the record expires along with the lease,
+    /// `expire_at` and `ttl` are replaced with the expiration time of the lease when applied.
+    ///
+    /// For backward compatibility, this field is not serialized if it `None`, as if it does not exist.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) lease: Option<u64>,
 }
 
 impl deepsize::DeepSizeOf for MetaSpec {
@@ -51,7 +60,11 @@ impl deepsize::DeepSizeOf for MetaSpec {
 impl MetaSpec {
     /// Create a new KVMeta
     pub fn new(expire_at: Option<u64>, ttl: Option<Interval>) -> Self {
-        Self { expire_at, ttl }
+        Self {
+            expire_at,
+            ttl,
+            lease: None,
+        }
     }
 
     /// Create a KVMeta with a absolute expiration time in second since 1970-01-01.
@@ -59,6 +72,7 @@ impl MetaSpec {
         Self {
             expire_at: Some(expire_at_sec),
             ttl: None,
+            lease: None,
         }
     }
 
@@ -67,18 +81,39 @@ impl MetaSpec {
         Self {
             expire_at: None,
             ttl: Some(Interval::from_duration(ttl)),
+            lease: None,
         }
     }
 
+    /// Create a MetaSpec that binds the record to a lease.
+    pub fn new_lease(lease_id: u64) -> Self {
+        Self {
+            expire_at: None,
+            ttl: None,
+            lease: Some(lease_id),
+        }
+    }
+
+    /// Bind the record to a lease.
+    pub fn with_lease(mut self, lease_id: u64) -> Self {
+        self.lease = Some(lease_id);
+        self
+    }
+
+    /// Returns the id of the lease the record is bound to.
+    pub fn lease(&self) -> Option<u64> {
+        self.lease
+    }
+
     /// Convert meta spec into a [`KVMeta`] to be stored in storage.
     pub fn to_kv_meta(&self, cmd_ctx: &CmdContext) -> KVMeta {
         // If `ttl` is set, override `expire_at`
         if let Some(ttl) = self.ttl {
-            return KVMeta::new_expire((cmd_ctx.time() + ttl).seconds());
+            return KVMeta::new_expire((cmd_ctx.time() + ttl).seconds()).with_lease(self.lease);
         }
 
         // No `ttl`, check if absolute expire time `expire_at` is set.
-        KVMeta::new(self.expire_at)
+        KVMeta::new(self.expire_at).with_lease(self.lease)
     }
 }
 
@@ -103,6 +138,10 @@ mod tests {
         let meta = MetaSpec::new_ttl(Duration::from_millis(100));
         let s = serde_json::to_string(&meta).unwrap();
         assert_eq!(r#"{"expire_at":null,"ttl":{"millis":100}}"#, s);
+
+        let meta = MetaSpec::new_lease(3);
+        let s = serde_json::to_string(&meta).unwrap();
+        assert_eq!(r#"{"expire_at":null,"lease":3}"#, s);
     }
 
     #[test]
@@ -118,5 +157,11 @@ mod tests {
         let meta = MetaSpec::new_expire(5);
         let kv_meta = meta.to_kv_meta(&cmd_ctx);
         assert_eq!(kv_meta.get_expire_at_ms().unwrap(), 5_000);
+
+        // lease
+        let meta = MetaSpec::new_expire(5).with_lease(3);
+        let kv_meta = meta.to_kv_meta(&cmd_ctx);
+        assert_eq!(kv_meta.get_expire_at_ms().unwrap(), 5_000);
+        assert_eq!(kv_meta.lease(), Some(3));
     }
 }
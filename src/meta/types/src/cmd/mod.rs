@@ -51,6 +51,22 @@ pub enum Cmd {
 
     /// Update one or more kv with a transaction.
     Transaction(TxnRequest),
+
+    /// Grant a new lease that expires after `ttl_ms` since the log is applied.
+    ///
+    /// If `lease_id` is 0, a new lease id is assigned.
+    /// Granting a lease id that is already in use is rejected.
+    ///
+    /// Compatibility: the lease commands can not be applied by a meta-service older than
+    /// `LEASE_MIN_META_SEMVER`, thus they are only proposed when leases are enabled,
+    /// which requires every node in the cluster to be upgraded first.
+    GrantLease { lease_id: u64, ttl_ms: u64 },
+
+    /// Extend the expiration time of the leases and the keys bound to them by the ttl of each lease.
+    KeepAliveLeases { lease_ids: Vec<u64> },
+
+    /// Remove a lease and delete all of the keys bound to it.
+    RevokeLease { lease_id: u64 },
 }
 
 /// Update or insert a general purpose kv store
@@ -94,6 +110,15 @@ impl fmt::Display for Cmd {
             Cmd::Transaction(txn) => {
                 write!(f, "txn:{}", txn)
             }
+            Cmd::GrantLease { lease_id, ttl_ms } => {
+                write!(f, "grant_lease:{}(ttl: {}ms)", lease_id, ttl_ms)
+            }
+            Cmd::KeepAliveLeases { lease_ids } => {
+                write!(f, "keep_alive_leases:{:?}", lease_ids)
+            }
+            Cmd::RevokeLease { lease_id } => {
+                write!(f, "revoke_lease:{}", lease_id)
+            }
         }
     }
 }
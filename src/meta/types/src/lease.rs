@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use crate::time::Interval;
+
+/// A lease that keys can be bound to.
+///
+/// All of the lease data is stored in the state machine in a reserved keyspace `__fd_lease/`,
+/// which can not be written by users:
+///
+/// - `__fd_lease/leases/<lease_id>`: the json encoded `Lease`,
+///   whose expiration time is the expiration time of the lease.
+/// - `__fd_lease/bindings/<lease_id>/<key>`: one empty record for every key bound to the lease,
+///   with the same expiration time as the lease.
+/// - `__fd_lease/id_gen`: the last assigned lease id, lease ids do not share the kv seq.
+///
+/// The keys bound to a lease share its expiration time,
+/// thus they are all removed along with the lease record when it expires.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Lease {
+    /// The time to live of the lease, since it is granted or kept alive.
+    pub ttl: Interval,
+}
+
+impl Lease {
+    /// The prefix of the keyspace reserved for leases.
+    pub const RESERVED_PREFIX: &'static str = "__fd_lease/";
+
+    /// The key of the last assigned lease id.
+    pub const ID_GEN_KEY: &'static str = "__fd_lease/id_gen";
+
+    pub fn new(ttl: Interval) -> Self {
+        Self { ttl }
+    }
+
+    /// Returns true if the key belongs to the reserved lease keyspace.
+    pub fn is_reserved(key: &str) -> bool {
+        key.starts_with(Self::RESERVED_PREFIX)
+    }
+
+    /// Build the key of the lease record.
+    ///
+    /// The id is zero padded so that the lease records are listed in order of id.
+    pub fn key(lease_id: u64) -> String {
+        format!("{}leases/{:020}", Self::RESERVED_PREFIX, lease_id)
+    }
+
+    /// Build the prefix of the binding records of a lease.
+    pub fn binding_prefix(lease_id: u64) -> String {
+        format!("{}bindings/{:020}/", Self::RESERVED_PREFIX, lease_id)
+    }
+
+    /// Build the key of the record that binds `key` to a lease.
+    pub fn binding_key(lease_id: u64, key: &str) -> String {
+        format!("{}{}", Self::binding_prefix(lease_id), key)
+    }
+}
+
+impl fmt::Display for Lease {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Lease(ttl: {}ms)", self.ttl.millis())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Lease;
+    use crate::time::Interval;
+
+    #[test]
+    fn test_lease_key() {
+        assert_eq!("__fd_lease/leases/00000000000000000005", Lease::key(5));
+        assert_eq!(
+            "__fd_lease/bindings/00000000000000000005/a/b",
+            Lease::binding_key(5, "a/b")
+        );
+
+        assert!(Lease::is_reserved(&Lease::key(5)));
+        assert!(Lease::is_reserved(&Lease::binding_key(5, "a")));
+        assert!(Lease::is_reserved(Lease::ID_GEN_KEY));
+        assert!(!Lease::is_reserved("__fd_leases"));
+    }
+
+    #[test]
+    fn test_lease_serde() {
+        let lease = Lease::new(Interval::from_millis(1000));
+
+        let s = serde_json::to_string(&lease).unwrap();
+        assert_eq!(r#"{"ttl":{"millis":1000}}"#, s);
+
+        let got: Lease = serde_json::from_str(&s).unwrap();
+        assert_eq!(lease, got);
+    }
+}
@@ -24,6 +24,7 @@ mod endpoint;
 mod eval_expire_time;
 mod grpc_config;
 mod grpc_helper;
+mod lease;
 mod log_entry;
 mod match_seq;
 mod message;
@@ -80,6 +81,7 @@ pub use errors::meta_startup_errors::MetaStartupError;
 pub use errors::rpc_errors::ForwardRPCError;
 pub use eval_expire_time::EvalExpireTime;
 pub use grpc_config::GrpcConfig;
+pub use lease::Lease;
 pub use log_entry::LogEntry;
 pub use match_seq::MatchSeq;
 pub use match_seq::MatchSeqExt;
@@ -90,6 +92,11 @@ pub use protobuf::txn_condition;
 pub use protobuf::txn_condition::ConditionResult;
 pub use protobuf::txn_op;
 pub use protobuf::txn_op_response;
+pub use protobuf::LeaseGrantRequest;
+pub use protobuf::LeaseKeepAliveReply;
+pub use protobuf::LeaseKeepAliveRequest;
+pub use protobuf::LeaseReply;
+pub use protobuf::LeaseRevokeRequest;
 pub use protobuf::TxnCondition;
 pub use protobuf::TxnDeleteByPrefixRequest;
 pub use protobuf::TxnDeleteByPrefixResponse;
@@ -24,6 +24,8 @@ use crate::txn_op;
 use crate::txn_op::Request;
 use crate::txn_op_response::Response;
 use crate::ConditionResult;
+use crate::LeaseKeepAliveReply;
+use crate::LeaseReply;
 use crate::TxnCondition;
 use crate::TxnDeleteByPrefixRequest;
 use crate::TxnDeleteByPrefixResponse;
@@ -176,6 +178,9 @@ impl Display for TxnPutRequest {
         if let Some(ttl_ms) = self.ttl_ms {
             write!(f, "  ttl: {:?}", Duration::from_millis(ttl_ms))?;
         }
+        if let Some(lease_id) = self.lease_id {
+            write!(f, " lease: {}", lease_id)?;
+        }
         Ok(())
     }
 }
@@ -217,6 +222,33 @@ impl Display for TxnReply {
     }
 }
 
+impl Display for LeaseReply {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "LeaseReply{{ lease_id: {}, ttl: {:?}, expire_at: {}, keys: {}}}",
+            self.lease_id,
+            Duration::from_millis(self.ttl_ms),
+            Duration::from_secs(self.expire_at).display_unix_timestamp(),
+            VecDisplay::new_at_most(&self.keys, 5),
+        )?;
+        if !self.error.is_empty() {
+            write!(f, " error: {}", self.error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for LeaseKeepAliveReply {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "LeaseKeepAliveReply{{ leases: {}}}",
+            VecDisplay::new_at_most(&self.leases, 5),
+        )
+    }
+}
+
 impl Display for TxnOpResponse {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "TxnOpResponse: {}", OptionDisplay { t: &self.response })
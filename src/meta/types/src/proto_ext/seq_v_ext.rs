@@ -26,9 +26,7 @@ impl From<KVMeta> for pb::KvMeta {
 
 impl From<pb::KvMeta> for KVMeta {
     fn from(m: pb::KvMeta) -> Self {
-        Self {
-            expire_at: m.expire_at,
-        }
+        KVMeta::new(m.expire_at)
     }
 }
 
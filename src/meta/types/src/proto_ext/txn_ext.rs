@@ -65,6 +65,7 @@ impl pb::TxnOp {
                 prev_value: true,
                 expire_at,
                 ttl_ms: None,
+                lease_id: None,
             })),
         }
     }
@@ -80,6 +81,7 @@ impl pb::TxnOp {
                 prev_value: true,
                 expire_at: None,
                 ttl_ms: ttl.map(|d| d.as_millis() as u64),
+                lease_id: None,
             })),
         }
     }
@@ -91,6 +93,18 @@ impl pb::TxnOp {
         self
     }
 
+    /// Create a txn operation that puts a record bound to a lease.
+    pub fn put_with_lease(key: impl ToString, value: Vec<u8>, lease_id: u64) -> pb::TxnOp {
+        Self::put(key, value).with_lease(Some(lease_id))
+    }
+
+    pub fn with_lease(mut self, lease_id: Option<u64>) -> Self {
+        if let Some(pb::txn_op::Request::Put(p)) = &mut self.request {
+            p.lease_id = lease_id;
+        }
+        self
+    }
+
     /// Create a new `TxnOp` with a `Delete` operation.
     pub fn delete(key: impl ToString) -> Self {
         Self::delete_exact(key, None)
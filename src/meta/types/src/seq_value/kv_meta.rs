@@ -22,21 +22,44 @@ use crate::EvalExpireTime;
 pub struct KVMeta {
     /// expiration time in second since 1970
     pub(crate) expire_at: Option<u64>,
+
+    /// The lease this record is bound to.
+    ///
+    /// A record bound to a lease shares the expiration time of the lease.
+    ///
+    /// For backward compatibility, this field is not serialized if it `None`, as if it does not exist.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) lease: Option<u64>,
 }
 
 impl KVMeta {
     /// Create a new KVMeta
     pub fn new(expire_at: Option<u64>) -> Self {
-        Self { expire_at }
+        Self {
+            expire_at,
+            lease: None,
+        }
     }
 
     /// Create a KVMeta with a absolute expiration time in second since 1970-01-01.
     pub fn new_expire(expire_at: u64) -> Self {
         Self {
             expire_at: Some(expire_at),
+            lease: None,
         }
     }
 
+    /// Bind the record to a lease.
+    pub fn with_lease(mut self, lease: Option<u64>) -> Self {
+        self.lease = lease;
+        self
+    }
+
+    /// Returns the id of the lease this record is bound to.
+    pub fn lease(&self) -> Option<u64> {
+        self.lease
+    }
+
     /// Returns expire time in millisecond since 1970.
     pub fn get_expire_at_ms(&self) -> Option<u64> {
         self.expire_at.map(|t| t * 1000)
@@ -80,6 +80,13 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
+        .append(if let Some(partition_by) = stmt.partition_by {
+            RcDoc::line()
+                .append(RcDoc::text("PARTITION BY "))
+                .append(parenthesized(pretty_expr(partition_by)))
+        } else {
+            RcDoc::nil()
+        })
         .append(if let Some(cluster_by) = stmt.cluster_by {
             RcDoc::line()
                 .append(RcDoc::text("CLUSTER BY "))
@@ -224,6 +231,35 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::DropTableClusterKey => {
             RcDoc::line().append(RcDoc::text("DROP CLUSTER KEY"))
         }
+        AlterTableAction::DropPartition {
+            if_exists,
+            partition,
+        } => RcDoc::line()
+            .append(RcDoc::text("DROP PARTITION "))
+            .append(if if_exists {
+                RcDoc::text("IF EXISTS ")
+            } else {
+                RcDoc::nil()
+            })
+            .append(parenthesized(pretty_expr(partition))),
+        AlterTableAction::TruncatePartition { partition } => RcDoc::line()
+            .append(RcDoc::text("TRUNCATE PARTITION "))
+            .append(parenthesized(pretty_expr(partition))),
+        AlterTableAction::AddRowAccessPolicy { policy, columns } => RcDoc::line()
+            .append(RcDoc::text("ADD ROW ACCESS POLICY "))
+            .append(RcDoc::text(policy.to_string()))
+            .append(RcDoc::text(" ON "))
+            .append(parenthesized(
+                interweave_comma(
+                    columns
+                        .into_iter()
+                        .map(|column| RcDoc::text(column.to_string())),
+                )
+                .group(),
+            )),
+        AlterTableAction::DropRowAccessPolicy { policy } => RcDoc::line()
+            .append(RcDoc::text("DROP ROW ACCESS POLICY "))
+            .append(RcDoc::text(policy.to_string())),
         AlterTableAction::ReclusterTable {
             is_final,
             selection,
@@ -54,6 +54,14 @@ pub(crate) fn pretty_insert(insert_stmt: InsertStmt) -> RcDoc<'static> {
                     RcDoc::nil()
                 })
                 .append(RcDoc::text(insert_stmt.table.to_string()))
+                .append(if let Some(partition) = insert_stmt.partition {
+                    RcDoc::space()
+                        .append(RcDoc::text("PARTITION ("))
+                        .append(pretty_expr(partition))
+                        .append(RcDoc::text(")"))
+                } else {
+                    RcDoc::nil()
+                })
                 .append(if !insert_stmt.columns.is_empty() {
                     RcDoc::space()
                         .append(RcDoc::text("("))
@@ -154,3 +154,40 @@ impl Display for CreateDynamicTableStmt {
         Ok(())
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum AlterDynamicTableAction {
+    Refresh,
+}
+
+impl Display for AlterDynamicTableAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AlterDynamicTableAction::Refresh => {
+                write!(f, "REFRESH")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct AlterDynamicTableStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub action: AlterDynamicTableAction,
+}
+
+impl Display for AlterDynamicTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER DYNAMIC TABLE ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " {}", self.action)
+    }
+}
@@ -42,6 +42,7 @@ pub enum TableIndexType {
     Aggregating,
     // Join
     Inverted,
+    Vector,
 }
 
 impl Display for TableIndexType {
@@ -53,6 +54,9 @@ impl Display for TableIndexType {
             TableIndexType::Inverted => {
                 write!(f, "INVERTED")
             }
+            TableIndexType::Vector => {
+                write!(f, "VECTOR")
+            }
         }
     }
 }
@@ -112,6 +116,7 @@ impl Display for RefreshIndexStmt {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct CreateInvertedIndexStmt {
+    pub index_type: TableIndexType,
     pub create_option: CreateOption,
 
     pub index_name: Identifier,
@@ -134,7 +139,7 @@ impl Display for CreateInvertedIndexStmt {
         if !self.sync_creation {
             write!(f, "ASYNC ")?;
         }
-        write!(f, "INVERTED INDEX")?;
+        write!(f, "{} INDEX", self.index_type)?;
         if let CreateOption::CreateIfNotExists = self.create_option {
             write!(f, " IF NOT EXISTS")?;
         }
@@ -163,6 +168,7 @@ impl Display for CreateInvertedIndexStmt {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct DropInvertedIndexStmt {
+    pub index_type: TableIndexType,
     pub if_exists: bool,
     pub index_name: Identifier,
     pub catalog: Option<Identifier>,
@@ -172,7 +178,7 @@ pub struct DropInvertedIndexStmt {
 
 impl Display for DropInvertedIndexStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        write!(f, "DROP INVERTED INDEX")?;
+        write!(f, "DROP {} INDEX", self.index_type)?;
         if self.if_exists {
             write!(f, " IF EXISTS")?;
         }
@@ -192,6 +198,7 @@ impl Display for DropInvertedIndexStmt {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct RefreshInvertedIndexStmt {
+    pub index_type: TableIndexType,
     pub index_name: Identifier,
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
@@ -201,7 +208,7 @@ pub struct RefreshInvertedIndexStmt {
 
 impl Display for RefreshInvertedIndexStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        write!(f, "REFRESH INVERTED INDEX")?;
+        write!(f, "REFRESH {} INDEX", self.index_type)?;
         write!(f, " {}", self.index_name)?;
         write!(f, " ON ")?;
         write_dot_separated_list(
@@ -217,3 +224,28 @@ impl Display for RefreshInvertedIndexStmt {
         Ok(())
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct RefreshNgramIndexStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub limit: Option<u64>,
+}
+
+impl Display for RefreshNgramIndexStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "REFRESH NGRAM INDEX ON ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        if let Some(limit) = self.limit {
+            write!(f, " LIMIT {limit}")?;
+        }
+        Ok(())
+    }
+}
@@ -37,6 +37,8 @@ pub struct InsertStmt {
     pub columns: Vec<Identifier>,
     pub source: InsertSource,
     pub overwrite: bool,
+    /// The partition replaced by `INSERT OVERWRITE ... PARTITION (<expr>)`.
+    pub partition: Option<Expr>,
 }
 
 impl Display for InsertStmt {
@@ -60,6 +62,9 @@ impl Display for InsertStmt {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
+        if let Some(partition) = &self.partition {
+            write!(f, " PARTITION ({})", partition)?;
+        }
         if !self.columns.is_empty() {
             write!(f, " (")?;
             write_comma_separated_list(f, &self.columns)?;
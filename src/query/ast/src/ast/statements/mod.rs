@@ -38,7 +38,9 @@ mod presign;
 mod principal;
 mod priority;
 mod procedure;
+mod query_hint;
 mod replace;
+mod row_access_policy;
 mod script;
 mod sequence;
 mod set;
@@ -82,7 +84,9 @@ pub use presign::*;
 pub use principal::*;
 pub use priority::*;
 pub use procedure::*;
+pub use query_hint::*;
 pub use replace::*;
+pub use row_access_policy::*;
 pub use script::*;
 pub use sequence::*;
 pub use set::*;
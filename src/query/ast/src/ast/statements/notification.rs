@@ -52,6 +52,8 @@ pub struct NotificationWebhookOptions {
     pub url: Option<String>,
     pub method: Option<String>,
     pub authorization_header: Option<String>,
+    // secret to sign the webhook requests with HMAC-SHA256
+    pub signing_secret: Option<String>,
 }
 
 impl Display for NotificationWebhookOptions {
@@ -60,6 +62,7 @@ impl Display for NotificationWebhookOptions {
             url,
             method,
             authorization_header,
+            signing_secret,
         } = self;
         {
             write!(f, "WEBHOOK = (")?;
@@ -72,6 +75,9 @@ impl Display for NotificationWebhookOptions {
             if let Some(authorization_header) = authorization_header {
                 write!(f, " AUTHORIZATION_HEADER = '{}'", authorization_header)?;
             }
+            if let Some(signing_secret) = signing_secret {
+                write!(f, " SIGNING_SECRET = '{}'", signing_secret)?;
+            }
             write!(f, " )")?;
             Ok(())
         }
@@ -83,11 +89,13 @@ impl FromIterator<(String, String)> for NotificationWebhookOptions {
         let mut url = None;
         let mut method = None;
         let mut authorization_header = None;
+        let mut signing_secret = None;
         for (k, v) in iter {
             match k.to_uppercase().as_str() {
                 "URL" => url = Some(v),
                 "METHOD" => method = Some(v),
                 "AUTHORIZATION_HEADER" => authorization_header = Some(v),
+                "SIGNING_SECRET" => signing_secret = Some(v),
                 _ => {}
             }
         }
@@ -95,6 +103,7 @@ impl FromIterator<(String, String)> for NotificationWebhookOptions {
             url,
             method,
             authorization_header,
+            signing_secret,
         }
     }
 }
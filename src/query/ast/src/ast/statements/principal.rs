@@ -78,6 +78,7 @@ pub enum AuthType {
     Sha256Password,
     DoubleSha1Password,
     JWT,
+    Ldap,
 }
 
 impl Display for AuthType {
@@ -87,6 +88,7 @@ impl Display for AuthType {
             AuthType::Sha256Password => "sha256_password",
             AuthType::DoubleSha1Password => "double_sha1_password",
             AuthType::JWT => "jwt",
+            AuthType::Ldap => "ldap",
         })
     }
 }
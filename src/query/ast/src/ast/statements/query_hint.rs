@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+
+use crate::ast::quote::QuotedString;
+use crate::ast::write_comma_separated_string_map;
+use crate::ast::CreateOption;
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct CreateQueryHintStmt {
+    pub create_option: CreateOption,
+    pub join_order: Option<String>,
+    pub join_distribution: Option<String>,
+    pub settings: BTreeMap<String, String>,
+    pub comment: Option<String>,
+    pub query: String,
+}
+
+impl Display for CreateQueryHintStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE ")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "QUERY HINT ")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        if let Some(join_order) = &self.join_order {
+            write!(f, "JOIN_ORDER = '{join_order}' ")?;
+        }
+        if let Some(join_distribution) = &self.join_distribution {
+            write!(f, "JOIN_DISTRIBUTION = '{join_distribution}' ")?;
+        }
+        if !self.settings.is_empty() {
+            write!(f, "SETTINGS (")?;
+            write_comma_separated_string_map(f, &self.settings)?;
+            write!(f, ") ")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, "COMMENT = '{comment}' ")?;
+        }
+        write!(f, "FOR {}", QuotedString(&self.query, '\''))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct DropQueryHintStmt {
+    pub if_exists: bool,
+    pub query: String,
+}
+
+impl Display for DropQueryHintStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP QUERY HINT ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "FOR {}", QuotedString(&self.query, '\''))
+    }
+}
@@ -25,6 +25,9 @@ use crate::ast::Identifier;
 pub struct CreateSequenceStmt {
     pub create_option: CreateOption,
     pub sequence: Identifier,
+    pub start: Option<u64>,
+    pub increment: Option<u64>,
+    pub cache: Option<u64>,
     pub comment: Option<String>,
 }
 
@@ -39,6 +42,15 @@ impl Display for CreateSequenceStmt {
             write!(f, "IF NOT EXISTS ")?;
         }
         write!(f, " {}", self.sequence)?;
+        if let Some(start) = self.start {
+            write!(f, " START WITH {start}")?;
+        }
+        if let Some(increment) = self.increment {
+            write!(f, " INCREMENT BY {increment}")?;
+        }
+        if let Some(cache) = self.cache {
+            write!(f, " CACHE {cache}")?;
+        }
         if let Some(comment) = &self.comment {
             write!(f, " COMMENT = '{}'", comment)?;
         }
@@ -62,3 +74,54 @@ impl Display for DropSequenceStmt {
         Ok(())
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum AlterSequenceAction {
+    Restart { start: Option<u64> },
+    SetIncrement(u64),
+    SetCache(u64),
+}
+
+impl Display for AlterSequenceAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AlterSequenceAction::Restart { start } => {
+                write!(f, "RESTART")?;
+                if let Some(start) = start {
+                    write!(f, " WITH {start}")?;
+                }
+                Ok(())
+            }
+            AlterSequenceAction::SetIncrement(increment) => write!(f, "INCREMENT BY {increment}"),
+            AlterSequenceAction::SetCache(cache) => write!(f, "CACHE {cache}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct AlterSequenceStmt {
+    pub if_exists: bool,
+    pub sequence: Identifier,
+    pub action: AlterSequenceAction,
+}
+
+impl Display for AlterSequenceStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER SEQUENCE ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} {}", self.sequence, self.action)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct DescSequenceStmt {
+    pub sequence: Identifier,
+}
+
+impl Display for DescSequenceStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DESCRIBE SEQUENCE {}", self.sequence)
+    }
+}
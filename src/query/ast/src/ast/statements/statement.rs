@@ -44,8 +44,10 @@ pub enum Statement {
         query: Box<Statement>,
     },
     ExplainAnalyze {
-        // if partial is true, only scan/filter/join will be shown.
+        // if partial is true, only scan/filter/join and the row statistics will be shown.
         partial: bool,
+        // if json is true, the plan will be shown as JSON.
+        json: bool,
         query: Box<Statement>,
     },
 
@@ -180,6 +182,7 @@ pub enum Statement {
     CreateInvertedIndex(CreateInvertedIndexStmt),
     DropInvertedIndex(DropInvertedIndexStmt),
     RefreshInvertedIndex(RefreshInvertedIndexStmt),
+    RefreshNgramIndex(RefreshNgramIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumn(CreateVirtualColumnStmt),
@@ -266,6 +269,11 @@ pub enum Statement {
     DropDatamaskPolicy(DropDatamaskPolicyStmt),
     DescDatamaskPolicy(DescDatamaskPolicyStmt),
 
+    // row access policy
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt),
+    DropRowAccessPolicy(DropRowAccessPolicyStmt),
+    DescRowAccessPolicy(DescRowAccessPolicyStmt),
+
     // network policy
     CreateNetworkPolicy(CreateNetworkPolicyStmt),
     AlterNetworkPolicy(AlterNetworkPolicyStmt),
@@ -282,6 +290,11 @@ pub enum Statement {
         show_options: Option<ShowOptions>,
     },
 
+    // query hint
+    CreateQueryHint(CreateQueryHintStmt),
+    DropQueryHint(DropQueryHintStmt),
+    ShowQueryHints,
+
     // tasks
     CreateTask(CreateTaskStmt),
     AlterTask(AlterTaskStmt),
@@ -291,6 +304,7 @@ pub enum Statement {
     ShowTasks(ShowTasksStmt),
 
     CreateDynamicTable(CreateDynamicTableStmt),
+    AlterDynamicTable(AlterDynamicTableStmt),
 
     // pipes
     CreatePipe(CreatePipeStmt),
@@ -322,6 +336,11 @@ pub enum Statement {
     // Sequence
     CreateSequence(CreateSequenceStmt),
     DropSequence(DropSequenceStmt),
+    AlterSequence(AlterSequenceStmt),
+    ShowSequences {
+        show_options: Option<ShowOptions>,
+    },
+    DescSequence(DescSequenceStmt),
 
     // Set priority for query
     SetPriority {
@@ -411,12 +430,19 @@ impl Display for Statement {
                 }
                 write!(f, " {query}")?;
             }
-            Statement::ExplainAnalyze { partial, query } => {
+            Statement::ExplainAnalyze {
+                partial,
+                json,
+                query,
+            } => {
+                write!(f, "EXPLAIN ANALYZE")?;
                 if *partial {
-                    write!(f, "EXPLAIN ANALYZE PARTIAL {query}")?;
-                } else {
-                    write!(f, "EXPLAIN ANALYZE {query}")?;
+                    write!(f, " PARTIAL")?;
                 }
+                if *json {
+                    write!(f, " JSON")?;
+                }
+                write!(f, " {query}")?;
             }
             Statement::Query(stmt) => write!(f, "{stmt}")?,
             Statement::Insert(stmt) => write!(f, "{stmt}")?,
@@ -561,6 +587,7 @@ impl Display for Statement {
             Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshNgramIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumn(stmt) => write!(f, "{stmt}")?,
@@ -680,6 +707,9 @@ impl Display for Statement {
             Statement::CreateDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::CreateNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::AlterNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropNetworkPolicy(stmt) => write!(f, "{stmt}")?,
@@ -695,6 +725,9 @@ impl Display for Statement {
                     write!(f, " {show_options}")?;
                 }
             }
+            Statement::CreateQueryHint(stmt) => write!(f, "{stmt}")?,
+            Statement::DropQueryHint(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowQueryHints => write!(f, "SHOW QUERY HINTS")?,
             Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
             Statement::AlterTask(stmt) => write!(f, "{stmt}")?,
             Statement::ExecuteTask(stmt) => write!(f, "{stmt}")?,
@@ -728,7 +761,16 @@ impl Display for Statement {
             }
             Statement::CreateSequence(stmt) => write!(f, "{stmt}")?,
             Statement::DropSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowSequences { show_options } => {
+                write!(f, "SHOW SEQUENCES")?;
+                if let Some(show_options) = show_options {
+                    write!(f, " {show_options}")?;
+                }
+            }
+            Statement::DescSequence(stmt) => write!(f, "{stmt}")?,
             Statement::CreateDynamicTable(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterDynamicTable(stmt) => write!(f, "{stmt}")?,
             Statement::SetPriority {
                 priority,
                 object_id,
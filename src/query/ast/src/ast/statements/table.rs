@@ -177,6 +177,7 @@ pub struct CreateTableStmt {
     pub source: Option<CreateTableSource>,
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
+    pub partition_by: Option<Expr>,
     pub cluster_by: Option<ClusterOption>,
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
@@ -225,6 +226,10 @@ impl Display for CreateTableStmt {
             write!(f, " {uri_location}")?;
         }
 
+        if let Some(partition_by) = &self.partition_by {
+            write!(f, " PARTITION BY ({partition_by})")?;
+        }
+
         if let Some(cluster_by) = &self.cluster_by {
             write!(f, " {cluster_by}")?;
         }
@@ -415,6 +420,13 @@ pub enum AlterTableAction {
         cluster_by: ClusterOption,
     },
     DropTableClusterKey,
+    DropPartition {
+        if_exists: bool,
+        partition: Expr,
+    },
+    TruncatePartition {
+        partition: Expr,
+    },
     ReclusterTable {
         is_final: bool,
         selection: Option<Expr>,
@@ -429,6 +441,13 @@ pub enum AlterTableAction {
     UnsetOptions {
         targets: Vec<Identifier>,
     },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -467,6 +486,19 @@ impl Display for AlterTableAction {
             AlterTableAction::DropTableClusterKey => {
                 write!(f, "DROP CLUSTER KEY")?;
             }
+            AlterTableAction::DropPartition {
+                if_exists,
+                partition,
+            } => {
+                write!(f, "DROP PARTITION ")?;
+                if *if_exists {
+                    write!(f, "IF EXISTS ")?;
+                }
+                write!(f, "({partition})")?;
+            }
+            AlterTableAction::TruncatePartition { partition } => {
+                write!(f, "TRUNCATE PARTITION ({partition})")?;
+            }
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
@@ -498,6 +530,14 @@ impl Display for AlterTableAction {
                     write!(f, ")")?;
                 }
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")?;
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")?;
+            }
         };
         Ok(())
     }
@@ -829,6 +869,7 @@ pub enum ColumnExpr {
     Default(Box<Expr>),
     Virtual(Box<Expr>),
     Stored(Box<Expr>),
+    AutoIncrement { start: i64, step: i64 },
 }
 
 impl Display for ColumnExpr {
@@ -843,6 +884,9 @@ impl Display for ColumnExpr {
             ColumnExpr::Stored(expr) => {
                 write!(f, " AS ({expr}) STORED")?;
             }
+            ColumnExpr::AutoIncrement { start, step } => {
+                write!(f, " AUTOINCREMENT START {start} INCREMENT {step}")?;
+            }
         }
         Ok(())
     }
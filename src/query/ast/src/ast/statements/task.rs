@@ -56,6 +56,8 @@ pub struct CreateTaskStmt {
     pub suspend_task_after_num_failures: Option<u64>,
     // notification_integration name for error
     pub error_integration: Option<String>,
+    // notification_integration name for success
+    pub success_integration: Option<String>,
     pub comments: Option<String>,
     pub after: Vec<String>,
     pub when_condition: Option<Expr>,
@@ -94,6 +96,9 @@ impl Display for CreateTaskStmt {
         if let Some(error_integration) = &self.error_integration {
             write!(f, " ERROR_INTEGRATION = '{}'", error_integration)?;
         }
+        if let Some(success_integration) = &self.success_integration {
+            write!(f, " SUCCESS_INTEGRATION = '{}'", success_integration)?;
+        }
 
         if let Some(comments) = &self.comments {
             write!(f, " COMMENTS = '{}'", comments)?;
@@ -183,6 +188,7 @@ pub enum AlterTaskOptions {
         comments: Option<String>,
         session_parameters: Option<BTreeMap<String, String>>,
         error_integration: Option<String>,
+        success_integration: Option<String>,
     },
     Unset {
         warehouse: bool,
@@ -205,6 +211,7 @@ impl Display for AlterTaskOptions {
                 suspend_task_after_num_failures,
                 session_parameters,
                 error_integration,
+                success_integration,
                 comments,
             } => {
                 write!(f, "SET")?;
@@ -223,6 +230,9 @@ impl Display for AlterTaskOptions {
                 if let Some(error_integration) = error_integration {
                     write!(f, " ERROR_INTEGRATION = '{error_integration}'")?;
                 }
+                if let Some(success_integration) = success_integration {
+                    write!(f, " SUCCESS_INTEGRATION = '{success_integration}'")?;
+                }
                 if let Some(session) = session_parameters {
                     write!(f, " ")?;
                     write_comma_separated_string_map(f, session)?;
@@ -21,7 +21,9 @@ use derive_visitor::DriveMut;
 use crate::ast::write_comma_separated_list;
 use crate::ast::AuthType;
 use crate::ast::CreateOption;
+use crate::ast::Identifier;
 use crate::ast::PrincipalIdentity;
+use crate::ast::ProcedureIdentity;
 use crate::ast::ShowOptions;
 use crate::ast::UserIdentity;
 use crate::ast::UserPrivilegeType;
@@ -147,6 +149,10 @@ pub enum GrantObjectName {
     Table(Option<String>, String),
     UDF(String),
     Stage(String),
+    Sequence(Identifier),
+    Procedure(ProcedureIdentity),
+    Connection(String),
+    FileFormat(String),
 }
 
 impl Display for GrantObjectName {
@@ -164,6 +170,10 @@ impl Display for GrantObjectName {
             }
             GrantObjectName::UDF(udf) => write!(f, " UDF {udf}"),
             GrantObjectName::Stage(stage) => write!(f, " STAGE {stage}"),
+            GrantObjectName::Sequence(sequence) => write!(f, " SEQUENCE {sequence}"),
+            GrantObjectName::Procedure(procedure) => write!(f, " PROCEDURE {procedure}"),
+            GrantObjectName::Connection(connection) => write!(f, " CONNECTION {connection}"),
+            GrantObjectName::FileFormat(file_format) => write!(f, " FILE FORMAT {file_format}"),
         }
     }
 }
@@ -191,6 +201,11 @@ pub enum AccountMgrSource {
     ALL {
         level: AccountMgrLevel,
     },
+    ColumnPrivs {
+        privileges: Vec<UserPrivilegeType>,
+        columns: Vec<Identifier>,
+        level: AccountMgrLevel,
+    },
 }
 
 impl Display for AccountMgrSource {
@@ -208,6 +223,18 @@ impl Display for AccountMgrSource {
                 write!(f, " ON")?;
                 write!(f, " {}", level)?;
             }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                write!(f, " ")?;
+                write_comma_separated_list(f, privileges.iter().map(|p| p.to_string()))?;
+                write!(f, " (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") ON")?;
+                write!(f, " {}", level)?;
+            }
         }
         Ok(())
     }
@@ -220,6 +247,10 @@ pub enum AccountMgrLevel {
     Table(Option<String>, String),
     UDF(String),
     Stage(String),
+    Sequence(Identifier),
+    Procedure(ProcedureIdentity),
+    Connection(String),
+    FileFormat(String),
 }
 
 impl Display for AccountMgrLevel {
@@ -242,6 +273,10 @@ impl Display for AccountMgrLevel {
             }
             AccountMgrLevel::UDF(udf) => write!(f, " UDF {udf}"),
             AccountMgrLevel::Stage(stage) => write!(f, " STAGE {stage}"),
+            AccountMgrLevel::Sequence(sequence) => write!(f, " SEQUENCE {sequence}"),
+            AccountMgrLevel::Procedure(procedure) => write!(f, " PROCEDURE {procedure}"),
+            AccountMgrLevel::Connection(connection) => write!(f, " CONNECTION {connection}"),
+            AccountMgrLevel::FileFormat(file_format) => write!(f, " FILE FORMAT {file_format}"),
         }
     }
 }
@@ -262,6 +297,10 @@ pub enum UserOptionItem {
     SetPasswordPolicy(String),
     UnsetPasswordPolicy,
     MustChangePassword(bool),
+    SetRsaPublicKey(String),
+    UnsetRsaPublicKey,
+    SetRsaPublicKey2(String),
+    UnsetRsaPublicKey2,
 }
 
 impl Display for UserOptionItem {
@@ -276,6 +315,10 @@ impl Display for UserOptionItem {
             UserOptionItem::UnsetPasswordPolicy => write!(f, "UNSET PASSWORD POLICY"),
             UserOptionItem::Disabled(v) => write!(f, "DISABLED = {}", v),
             UserOptionItem::MustChangePassword(v) => write!(f, "MUST_CHANGE_PASSWORD = {}", v),
+            UserOptionItem::SetRsaPublicKey(v) => write!(f, "SET RSA_PUBLIC_KEY = '{}'", v),
+            UserOptionItem::UnsetRsaPublicKey => write!(f, "UNSET RSA_PUBLIC_KEY"),
+            UserOptionItem::SetRsaPublicKey2(v) => write!(f, "SET RSA_PUBLIC_KEY_2 = '{}'", v),
+            UserOptionItem::UnsetRsaPublicKey2 => write!(f, "UNSET RSA_PUBLIC_KEY_2"),
         }
     }
 }
@@ -18,6 +18,8 @@ use nom::combinator::map;
 use nom::combinator::value;
 use nom_rule::rule;
 
+use crate::ast::AlterDynamicTableAction;
+use crate::ast::AlterDynamicTableStmt;
 use crate::ast::ClusterOption;
 use crate::ast::ClusterType;
 use crate::ast::CreateDynamicTableStmt;
@@ -53,6 +55,7 @@ pub fn dynamic_table(i: Input) -> IResult<Statement> {
   [ COMMENT = '<string_literal>' ]
 AS
   <sql>`"
+        | #alter_dynamic_table : "`ALTER DYNAMIC TABLE [<database>.]<table> REFRESH`"
     )(i)
 }
 
@@ -105,6 +108,26 @@ fn create_dynamic_table(i: Input) -> IResult<Statement> {
     )(i)
 }
 
+fn alter_dynamic_table(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            ALTER ~ DYNAMIC ~ TABLE ~ #dot_separated_idents_1_to_3 ~ #alter_dynamic_table_action
+        },
+        |(_, _, _, (catalog, database, table), action)| {
+            Statement::AlterDynamicTable(AlterDynamicTableStmt {
+                catalog,
+                database,
+                table,
+                action,
+            })
+        },
+    )(i)
+}
+
+fn alter_dynamic_table_action(i: Input) -> IResult<AlterDynamicTableAction> {
+    value(AlterDynamicTableAction::Refresh, rule! { REFRESH })(i)
+}
+
 fn dynamic_table_options(
     i: Input,
 ) -> IResult<(
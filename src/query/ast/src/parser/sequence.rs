@@ -12,12 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use nom::combinator::map;
 use nom_rule::rule;
 
 use super::common::ident;
 use super::expr::literal_string;
+use super::expr::literal_u64;
 use super::statement::parse_create_option;
+use super::statement::show_options;
+use crate::ast::AlterSequenceAction;
+use crate::ast::AlterSequenceStmt;
 use crate::ast::CreateSequenceStmt;
+use crate::ast::DescSequenceStmt;
 use crate::ast::DropSequenceStmt;
 use crate::ast::Statement;
 use crate::parser::common::map_res;
@@ -28,8 +34,10 @@ use crate::parser::token::*;
 
 pub fn sequence(i: Input) -> IResult<Statement> {
     rule!(
-         #create_sequence: "`CREATE [OR REPLACE] SEQUENCE [IF NOT EXISTS] <sequence> [COMMENT = '<string_literal>']`"
+         #create_sequence: "`CREATE [OR REPLACE] SEQUENCE [IF NOT EXISTS] <sequence> [START [WITH] <n>] [INCREMENT [BY] <n>] [CACHE <n>] [COMMENT = '<string_literal>']`"
          | #drop_sequence: "`DROP [IF EXISTS] <sequence>`"
+         | #alter_sequence: "`ALTER SEQUENCE [IF EXISTS] <sequence> { RESTART [WITH <n>] | [SET] INCREMENT [BY] <n> | [SET] CACHE <n> }`"
+         | #show_sequences: "`SHOW SEQUENCES [<show_options>]`"
     )(i)
 }
 
@@ -38,14 +46,30 @@ fn create_sequence(i: Input) -> IResult<Statement> {
         rule! {
             CREATE ~ ( OR ~ ^REPLACE )? ~ SEQUENCE ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #ident
+            ~ ( START ~ WITH? ~ ^#literal_u64 )?
+            ~ ( INCREMENT ~ BY? ~ ^#literal_u64 )?
+            ~ ( CACHE ~ ^#literal_u64 )?
             ~ ( COMMENT ~ "=" ~ #literal_string )?
         },
-        |(_, opt_or_replace, _, opt_if_not_exists, sequence, opt_comment)| {
+        |(
+            _,
+            opt_or_replace,
+            _,
+            opt_if_not_exists,
+            sequence,
+            opt_start,
+            opt_increment,
+            opt_cache,
+            opt_comment,
+        )| {
             let create_option =
                 parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
             Ok(Statement::CreateSequence(CreateSequenceStmt {
                 create_option,
                 sequence,
+                start: opt_start.map(|(_, _, start)| start),
+                increment: opt_increment.map(|(_, _, increment)| increment),
+                cache: opt_cache.map(|(_, cache)| cache),
                 comment: opt_comment.map(|(_, _, comment)| comment),
             }))
         },
@@ -65,3 +89,66 @@ fn drop_sequence(i: Input) -> IResult<Statement> {
         },
     )(i)
 }
+
+fn alter_sequence(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            ALTER ~ SEQUENCE ~ ( IF ~ ^EXISTS )? ~ #ident ~ #alter_sequence_action
+        },
+        |(_, _, opt_if_exists, sequence, action)| {
+            Statement::AlterSequence(AlterSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                sequence,
+                action,
+            })
+        },
+    )(i)
+}
+
+fn alter_sequence_action(i: Input) -> IResult<AlterSequenceAction> {
+    let restart = map(
+        rule! {
+            RESTART ~ ( WITH ~ ^#literal_u64 )?
+        },
+        |(_, opt_start)| AlterSequenceAction::Restart {
+            start: opt_start.map(|(_, start)| start),
+        },
+    );
+    let set_increment = map(
+        rule! {
+            SET? ~ INCREMENT ~ BY? ~ ^#literal_u64
+        },
+        |(_, _, _, increment)| AlterSequenceAction::SetIncrement(increment),
+    );
+    let set_cache = map(
+        rule! {
+            SET? ~ CACHE ~ ^#literal_u64
+        },
+        |(_, _, cache)| AlterSequenceAction::SetCache(cache),
+    );
+
+    rule!(
+        #restart
+        | #set_increment
+        | #set_cache
+    )(i)
+}
+
+fn show_sequences(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            SHOW ~ SEQUENCES ~ #show_options?
+        },
+        |(_, _, show_options)| Statement::ShowSequences { show_options },
+    )(i)
+}
+
+// `DESC SEQUENCE` must be tried before `DESC <table>`, as `SEQUENCE` is not reserved.
+pub fn desc_sequence(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ SEQUENCE ~ #ident
+        },
+        |(_, _, sequence)| Statement::DescSequence(DescSequenceStmt { sequence }),
+    )(i)
+}
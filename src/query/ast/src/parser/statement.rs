@@ -23,6 +23,7 @@ use nom::combinator::value;
 use nom::Slice;
 use nom_rule::rule;
 
+use super::sequence::desc_sequence;
 use super::sequence::sequence;
 use crate::ast::*;
 use crate::parser::common::*;
@@ -85,10 +86,11 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     );
     let explain_analyze = map(
         rule! {
-            EXPLAIN ~ ANALYZE ~ PARTIAL? ~ #statement
+            EXPLAIN ~ ANALYZE ~ PARTIAL? ~ JSON? ~ #statement
         },
-        |(_, _, partial, statement)| Statement::ExplainAnalyze {
+        |(_, _, partial, json, statement)| Statement::ExplainAnalyze {
             partial: partial.is_some(),
+            json: json.is_some(),
             query: Box::new(statement.stmt),
         },
     );
@@ -103,6 +105,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ~ ( WHEN ~ #expr )?
             ~ ( SUSPEND_TASK_AFTER_NUM_FAILURES ~ "=" ~ #literal_u64 )?
             ~ ( ERROR_INTEGRATION ~  ^"=" ~ ^#literal_string )?
+            ~ ( SUCCESS_INTEGRATION ~  ^"=" ~ ^#literal_string )?
             ~ ( (COMMENT | COMMENTS) ~ ^"=" ~ ^#literal_string )?
             ~ #set_table_option?
             ~ AS ~ #task_sql_block
@@ -118,6 +121,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             when_conditions,
             suspend_opt,
             error_integration,
+            success_integration,
             comment_opt,
             session_opts,
             _,
@@ -136,6 +140,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     None => Vec::new(),
                 },
                 error_integration: error_integration.map(|(_, _, name)| name.to_string()),
+                success_integration: success_integration.map(|(_, _, name)| name.to_string()),
                 when_condition: when_conditions.map(|(_, cond)| cond),
                 sql,
                 session_parameters: session_opts,
@@ -709,6 +714,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ~ #create_table_source?
             ~ ( #engine )?
             ~ ( #uri_location )?
+            ~ ( PARTITION ~ ^BY ~ ^"(" ~ ^#expr ~ ^")" )?
             ~ ( CLUSTER ~ ^BY ~ ( #cluster_type )? ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
@@ -723,6 +729,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             source,
             engine,
             uri_location,
+            opt_partition_by,
             opt_cluster_by,
             opt_table_options,
             opt_as_query,
@@ -743,6 +750,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                 source,
                 engine,
                 uri_location,
+                partition_by: opt_partition_by.map(|(_, _, _, expr, _)| expr),
                 cluster_by: opt_cluster_by.map(|(_, _, typ, _, exprs, _)| ClusterOption {
                     cluster_type: typ.unwrap_or(ClusterType::Linear),
                     cluster_exprs: exprs,
@@ -1138,7 +1146,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             CREATE
             ~ ( OR ~ ^REPLACE )?
             ~ ASYNC?
-            ~ INVERTED ~ INDEX
+            ~ #table_index_type ~ INDEX
             ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #ident
             ~ ON ~ #dot_separated_idents_1_to_3
@@ -1149,7 +1157,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             _,
             opt_or_replace,
             opt_async,
-            _,
+            index_type,
             _,
             opt_if_not_exists,
             index_name,
@@ -1163,6 +1171,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             let create_option =
                 parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
             Ok(Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                index_type,
                 create_option,
                 index_name,
                 catalog,
@@ -1177,11 +1186,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
 
     let drop_inverted_index = map(
         rule! {
-            DROP ~ INVERTED ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
+            DROP ~ #table_index_type ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
             ~ ON ~ #dot_separated_idents_1_to_3
         },
-        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+        |(_, index_type, _, opt_if_exists, index_name, _, (catalog, database, table))| {
             Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                index_type,
                 if_exists: opt_if_exists.is_some(),
                 index_name,
                 catalog,
@@ -1193,10 +1203,11 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
 
     let refresh_inverted_index = map(
         rule! {
-            REFRESH ~ INVERTED ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3 ~ ( LIMIT ~ #literal_u64 )?
+            REFRESH ~ #table_index_type ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3 ~ ( LIMIT ~ #literal_u64 )?
         },
-        |(_, _, _, index_name, _, (catalog, database, table), opt_limit)| {
+        |(_, index_type, _, index_name, _, (catalog, database, table), opt_limit)| {
             Statement::RefreshInvertedIndex(RefreshInvertedIndexStmt {
+                index_type,
                 index_name,
                 catalog,
                 database,
@@ -1206,6 +1217,20 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    let refresh_ngram_index = map(
+        rule! {
+            REFRESH ~ NGRAM ~ INDEX ~ ON ~ #dot_separated_idents_1_to_3 ~ ( LIMIT ~ #literal_u64 )?
+        },
+        |(_, _, _, _, (catalog, database, table), opt_limit)| {
+            Statement::RefreshNgramIndex(RefreshNgramIndexStmt {
+                catalog,
+                database,
+                table,
+                limit: opt_limit.map(|(_, limit)| limit),
+            })
+        },
+    );
+
     let create_virtual_column = map_res(
         rule! {
             CREATE
@@ -1345,7 +1370,10 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         rule! {
             ALTER ~ USER ~ ( #map(rule! { USER ~ "(" ~ ")" }, |_| None) | #map(user_identity, Some) )
             ~ ( IDENTIFIED ~ ( WITH ~ ^#auth_type )? ~ ( BY ~ ^#literal_string )? )?
-            ~ ( WITH ~ ^#comma_separated_list1(user_option) )?
+            ~ (
+                #map(rule! { WITH ~ ^#comma_separated_list1(user_option) }, |(_, opts)| opts)
+                | #comma_separated_list1(public_key_option)
+            )?
         },
         |(_, _, user, opt_auth_option, opt_user_option)| {
             Statement::AlterUser(AlterUserStmt {
@@ -1354,9 +1382,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     auth_type: opt_auth_type.map(|(_, auth_type)| auth_type),
                     password: opt_password.map(|(_, password)| password),
                 }),
-                user_options: opt_user_option
-                    .map(|(_, user_options)| user_options)
-                    .unwrap_or_default(),
+                user_options: opt_user_option.unwrap_or_default(),
             })
         },
     );
@@ -1723,6 +1749,63 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    // row access policy
+    let create_row_access_policy = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ ROW ~ ^ACCESS ~ ^POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
+            ~ ^AS ~ ^"(" ~ ^#comma_separated_list1(row_access_policy_arg) ~ ^")"
+            ~ ^RETURNS ~ ^( BOOLEAN | BOOL ) ~ ^"->" ~ ^#expr
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            args,
+            _,
+            _,
+            _,
+            _,
+            body,
+            opt_comment,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateRowAccessPolicy(
+                CreateRowAccessPolicyStmt {
+                    create_option,
+                    name,
+                    args,
+                    body,
+                    comment: opt_comment.map(|(_, _, comment)| comment),
+                },
+            ))
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ^ACCESS ~ ^POLICY ~ ( IF ~ ^EXISTS )? ~ ^#ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            Statement::DropRowAccessPolicy(DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let describe_row_access_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ ROW ~ ^ACCESS ~ ^POLICY ~ ^#ident
+        },
+        |(_, _, _, _, name)| Statement::DescRowAccessPolicy(DescRowAccessPolicyStmt { name }),
+    );
+
     let create_network_policy = map_res(
         rule! {
             CREATE ~  ( OR ~ ^REPLACE )? ~ NETWORK ~ ^POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
@@ -1886,6 +1969,56 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         |(_, _, _, show_options)| Statement::ShowPasswordPolicies { show_options },
     );
 
+    let create_query_hint = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ QUERY ~ HINT ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ ( JOIN_ORDER ~ ^"=" ~ ^#literal_string )?
+            ~ ( JOIN_DISTRIBUTION ~ ^"=" ~ ^#literal_string )?
+            ~ ( SETTINGS ~ ^"(" ~ ^#set_table_option ~ ^")" )?
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+            ~ ^FOR ~ ^#literal_string
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            _,
+            opt_if_not_exists,
+            opt_join_order,
+            opt_join_distribution,
+            opt_settings,
+            opt_comment,
+            _,
+            query,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateQueryHint(CreateQueryHintStmt {
+                create_option,
+                join_order: opt_join_order.map(|(_, _, join_order)| join_order),
+                join_distribution: opt_join_distribution
+                    .map(|(_, _, join_distribution)| join_distribution),
+                settings: opt_settings
+                    .map(|(_, _, settings, _)| settings)
+                    .unwrap_or_default(),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+                query,
+            }))
+        },
+    );
+    let drop_query_hint = map(
+        rule! {
+            DROP ~ QUERY ~ HINT ~ ( IF ~ ^EXISTS )? ~ ^FOR ~ ^#literal_string
+        },
+        |(_, _, _, opt_if_exists, _, query)| {
+            Statement::DropQueryHint(DropQueryHintStmt {
+                if_exists: opt_if_exists.is_some(),
+                query,
+            })
+        },
+    );
+    let show_query_hints = value(Statement::ShowQueryHints, rule! { SHOW ~ QUERY ~ ^HINTS });
+
     let create_pipe = map(
         rule! {
             CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -2144,23 +2277,6 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         |(_, _, show_options)| Statement::ShowProcedures { show_options },
     );
 
-    fn procedure_type_name(i: Input) -> IResult<Vec<TypeName>> {
-        let procedure_type_names = map(
-            rule! {
-                "(" ~ #comma_separated_list1(type_name) ~ ")"
-            },
-            |(_, args, _)| args,
-        );
-        let procedure_empty_types = map(
-            rule! {
-                "(" ~ ")"
-            },
-            |(_, _)| vec![],
-        );
-        rule!(#procedure_empty_types: "()"
-            | #procedure_type_names: "(<type_name>, ...)")(i)
-    }
-
     let call_procedure = map(
         rule! {
             CALL ~ PROCEDURE ~ #ident ~ "(" ~ #comma_separated_list0(subexpr(0))? ~ ")"
@@ -2175,22 +2291,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
 
     let drop_procedure = map(
         rule! {
-            DROP ~ PROCEDURE ~ ( IF ~ ^EXISTS )? ~ #ident ~ #procedure_type_name
+            DROP ~ PROCEDURE ~ ( IF ~ ^EXISTS )? ~ #procedure_identity
         },
-        |(_, _, opt_if_exists, name, args)| {
+        |(_, _, opt_if_exists, name)| {
             Statement::DropProcedure(DropProcedureStmt {
                 if_exists: opt_if_exists.is_some(),
-                name: ProcedureIdentity {
-                    name: name.to_string(),
-                    args_type: if args.is_empty() {
-                        "".to_string()
-                    } else {
-                        args.iter()
-                            .map(|arg| arg.to_string())
-                            .collect::<Vec<String>>()
-                            .join(",")
-                    },
-                },
+                name,
             })
         },
     );
@@ -2213,7 +2319,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
             | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
-            | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
+            | #explain_analyze : "`EXPLAIN ANALYZE [PARTIAL | JSON] <statement>`"
             | #show_settings : "`SHOW SETTINGS [<show_limit>]`"
             | #show_variables : "`SHOW VARIABLES [<show_limit>]`"
             | #show_stages : "`SHOW STAGES`"
@@ -2238,7 +2344,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
             | #use_database : "`USE <database>`"
         ),
-        // network policy / password policy
+        // network policy / password policy / query hint
         rule!(
             #create_network_policy: "`CREATE NETWORK POLICY [IF NOT EXISTS] name ALLOWED_IP_LIST = ('ip1' [, 'ip2']) [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
             | #alter_network_policy: "`ALTER NETWORK POLICY [IF EXISTS] name SET [ALLOWED_IP_LIST = ('ip1' [, 'ip2'])] [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
@@ -2250,6 +2356,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #drop_password_policy: "`DROP PASSWORD POLICY [IF EXISTS] name`"
             | #describe_password_policy: "`DESC PASSWORD POLICY name`"
             | #show_password_policies: "`SHOW PASSWORD POLICIES [<show_options>]`"
+            | #create_query_hint: "`CREATE [OR REPLACE] QUERY HINT [IF NOT EXISTS] [JOIN_ORDER = 'FIXED'] [JOIN_DISTRIBUTION = 'BROADCAST' | 'SHUFFLE'] [SETTINGS (<name> = <value>, ...)] [COMMENT = '<string_literal>'] FOR '<sql>'`"
+            | #drop_query_hint: "`DROP QUERY HINT [IF EXISTS] FOR '<sql>'`"
+            | #show_query_hints: "`SHOW QUERY HINTS`"
         ),
         rule!(
             #conditional_multi_table_insert() : "`INSERT [OVERWRITE] {FIRST|ALL} { WHEN <condition> THEN intoClause [ ... ] } [ ... ] [ ELSE intoClause ] <subquery>`"
@@ -2267,7 +2376,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             #show_users : "`SHOW USERS`"
             | #describe_user: "`DESCRIBE USER <user_name>`"
             | #create_user : "`CREATE [OR REPLACE] USER [IF NOT EXISTS] '<username>' IDENTIFIED [WITH <auth_type>] [BY <password>] [WITH <user_option>, ...]`"
-            | #alter_user : "`ALTER USER ('<username>' | USER()) [IDENTIFIED [WITH <auth_type>] [BY <password>]] [WITH <user_option>, ...]`"
+            | #alter_user : "`ALTER USER ('<username>' | USER()) [IDENTIFIED [WITH <auth_type>] [BY <password>]] [WITH <user_option>, ... | {SET RSA_PUBLIC_KEY[_2] = '<key>' | UNSET RSA_PUBLIC_KEY[_2]}, ...]`"
             | #drop_user : "`DROP USER [IF EXISTS] '<username>'`"
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
@@ -2284,6 +2393,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #show_columns : "`SHOW [FULL] COLUMNS FROM <table> [FROM|IN <catalog>.<database>] [<show_limit>]`"
             | #show_create_table : "`SHOW CREATE TABLE [<database>.]<table>`"
             | #describe_view : "`DESCRIBE VIEW [<database>.]<view>`"
+            | #desc_sequence : "`DESC | DESCRIBE SEQUENCE <sequence>`"
             | #describe_table : "`DESCRIBE [<database>.]<table>`"
             | #show_fields : "`SHOW FIELDS FROM [<database>.]<table>`"
             | #show_tables_status : "`SHOW TABLES STATUS [FROM <database>] [<show_limit>]`"
@@ -2318,9 +2428,10 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #create_index: "`CREATE [OR REPLACE] AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP <index_type> INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH <index_type> INDEX <index> [LIMIT <limit>]`"
-            | #create_inverted_index: "`CREATE [OR REPLACE] INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>, ...)`"
-            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
-            | #refresh_inverted_index: "`REFRESH INVERTED INDEX <index> ON [<database>.]<table> [LIMIT <limit>]`"
+            | #create_inverted_index: "`CREATE [OR REPLACE] {INVERTED | VECTOR} INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>, ...)`"
+            | #drop_inverted_index: "`DROP {INVERTED | VECTOR} INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+            | #refresh_inverted_index: "`REFRESH {INVERTED | VECTOR} INDEX <index> ON [<database>.]<table> [LIMIT <limit>]`"
+            | #refresh_ngram_index: "`REFRESH NGRAM INDEX ON [<database>.]<table> [LIMIT <limit>]`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -2356,6 +2467,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #drop_data_mask_policy: "`DROP MASKING POLICY [IF EXISTS] mask_name`"
             | #describe_data_mask_policy: "`DESC MASKING POLICY mask_name`"
         ),
+        // row access policy
+        rule!(
+            #create_row_access_policy: "`CREATE [OR REPLACE] ROW ACCESS POLICY [IF NOT EXISTS] <name> AS (<arg> <type>, ...) RETURNS BOOLEAN -> <expr>`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] <name>`"
+            | #describe_row_access_policy: "`DESC ROW ACCESS POLICY <name>`"
+        ),
         rule!(
             #set_stmt : "`SET [variable] {<name> = <value> | (<name>, ...) = (<value>, ...)}`"
             | #unset_stmt : "`UNSET [variable] {<name> | (<name>, ...)}`"
@@ -2375,6 +2492,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
   [ WHEN boolean_expr ]
   [ SUSPEND_TASK_AFTER_NUM_FAILURES = <num> ]
   [ ERROR_INTEGRATION = <string_literal> ]
+  [ SUCCESS_INTEGRATION = <string_literal> ]
   [ COMMENT = '<string_literal>' ]
 AS
   <sql>`"
@@ -2401,7 +2519,7 @@ AS
             | #create_notification : "`CREATE NOTIFICATION INTEGRATION [ IF NOT EXISTS ] <name>
     TYPE = <type>
     ENABLED = <bool>
-    [ WEBHOOK = ( url = <string_literal>, method = <string_literal>, authorization_header = <string_literal> ) ]
+    [ WEBHOOK = ( url = <string_literal>, method = <string_literal>, authorization_header = <string_literal>, signing_secret = <string_literal> ) ]
     [ COMMENT = '<string_literal>' ]`"
             | #alter_notification : "`ALTER NOTIFICATION INTEGRATION [ IF EXISTS ] <name> SET <option> = <value>`"
             | #desc_notification : "`DESC | DESCRIBE NOTIFICATION INTEGRATION <name>`"
@@ -2459,6 +2577,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
             rule! {
                 #with? ~ INSERT ~ #hint? ~ ( INTO | OVERWRITE ) ~ TABLE?
                 ~ #dot_separated_idents_1_to_3
+                ~ ( PARTITION ~ ^"(" ~ ^#expr ~ ^")" )?
                 ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
                 ~ #insert_source_parser
             },
@@ -2469,6 +2588,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                 overwrite,
                 _,
                 (catalog, database, table),
+                opt_partition,
                 opt_columns,
                 source,
             )| {
@@ -2483,6 +2603,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                         .unwrap_or_default(),
                     source,
                     overwrite: overwrite.kind == OVERWRITE,
+                    partition: opt_partition.map(|(_, _, partition, _)| partition),
                 })
             },
         )(i)
@@ -2802,8 +2923,32 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         DefaultExpr(Box<Expr>),
         VirtualExpr(Box<Expr>),
         StoredExpr(Box<Expr>),
+        AutoIncrement(i64, i64),
     }
 
+    let auto_increment = alt((
+        map(
+            rule! {
+                ( AUTOINCREMENT | IDENTITY ) ~ ( #auto_increment_start_step )?
+            },
+            |(_, opt_start_step)| {
+                let (start, step) = opt_start_step.unwrap_or((1, 1));
+                ColumnConstraint::AutoIncrement(start, step)
+            },
+        ),
+        map(
+            rule! {
+                GENERATED
+                ~ ( #map(rule! { ALWAYS }, |_| ()) | #map(rule! { BY ~ DEFAULT }, |_| ()) )
+                ~ AS ~ IDENTITY ~ ( "(" ~ #auto_increment_start_step ~ ")" )?
+            },
+            |(_, _, _, _, opt_start_step)| {
+                let (start, step) = opt_start_step.map(|(_, v, _)| v).unwrap_or((1, 1));
+                ColumnConstraint::AutoIncrement(start, step)
+            },
+        ),
+    ));
+
     let nullable = alt((
         value(ColumnConstraint::Nullable(true), rule! { NULL }),
         value(ColumnConstraint::Nullable(false), rule! { NOT ~ ^NULL }),
@@ -2815,6 +2960,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             },
             |(_, default_expr)| ColumnConstraint::DefaultExpr(Box::new(default_expr)),
         ),
+        auto_increment,
         map(
             rule! {
                 (GENERATED ~ ^ALWAYS)? ~ AS ~ ^"(" ~ ^#subexpr(NOT_PREC) ~ ^")" ~ VIRTUAL
@@ -2842,7 +2988,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ~ #type_name
             ~ ( #nullable | #expr )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <expr>] [AUTOINCREMENT [START <n> INCREMENT <n>]] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let def = ColumnDefinition {
@@ -2881,12 +3027,58 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ColumnConstraint::StoredExpr(stored_expr) => {
                 def.expr = Some(ColumnExpr::Stored(stored_expr))
             }
+            ColumnConstraint::AutoIncrement(start, step) => {
+                if step == 0 {
+                    return Err(nom::Err::Error(Error::from_error_kind(
+                        i,
+                        ErrorKind::Other("AUTOINCREMENT step can not be zero"),
+                    )));
+                }
+                def.expr = Some(ColumnExpr::AutoIncrement { start, step })
+            }
         }
     }
 
     Ok((i, def))
 }
 
+// `(<start>, <step>)` or `START [WITH] <start> INCREMENT [BY] <step>`
+fn auto_increment_start_step(i: Input) -> IResult<(i64, i64)> {
+    let start_increment = map(
+        rule! {
+            START ~ WITH? ~ #literal_i64 ~ INCREMENT ~ BY? ~ #literal_i64
+        },
+        |(_, _, start, _, _, step)| (start, step),
+    );
+    let start_step = map(
+        rule! {
+            "(" ~ #literal_i64 ~ "," ~ #literal_i64 ~ ")"
+        },
+        |(_, start, _, step, _)| (start, step),
+    );
+
+    rule!(
+        #start_step
+        | #start_increment
+    )(i)
+}
+
+fn literal_i64(i: Input) -> IResult<i64> {
+    map_res(rule! { "-"? ~ #literal_u64 }, |(minus, value)| {
+        let value = i128::from(value);
+        let value = if minus.is_some() { -value } else { value };
+        i64::try_from(value)
+            .map_err(|_| nom::Err::Failure(ErrorKind::Other("integer out of range")))
+    })(i)
+}
+
+pub fn table_index_type(i: Input) -> IResult<TableIndexType> {
+    alt((
+        value(TableIndexType::Inverted, rule! { INVERTED }),
+        value(TableIndexType::Vector, rule! { VECTOR }),
+    ))(i)
+}
+
 pub fn inverted_index_def(i: Input) -> IResult<InvertedIndexDefinition> {
     map_res(
         rule! {
@@ -2953,6 +3145,16 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
             level,
         },
     );
+    let column_privs = map(
+        rule! {
+            #comma_separated_list1(column_priv_type) ~ "(" ~ #comma_separated_list1(ident) ~ ")" ~ ON ~ #grant_level
+        },
+        |(privileges, _, columns, _, _, level)| AccountMgrSource::ColumnPrivs {
+            privileges,
+            columns,
+            level,
+        },
+    );
     let all = map(
         rule! { ALL ~ PRIVILEGES? ~ ON ~ #grant_all_level },
         |(_, _, _, level)| AccountMgrSource::ALL { level },
@@ -2978,6 +3180,26 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
         },
     );
 
+    let object_privs = map(
+        rule! {
+            USAGE ~ ON ~ #usage_object_level
+        },
+        |(_, _, level)| AccountMgrSource::Privs {
+            privileges: vec![UserPrivilegeType::Usage],
+            level,
+        },
+    );
+
+    let object_all_privs = map(
+        rule! {
+            ALL ~ PRIVILEGES? ~ ON ~ #usage_object_level
+        },
+        |(_, _, _, level)| AccountMgrSource::Privs {
+            privileges: vec![UserPrivilegeType::Usage],
+            level,
+        },
+    );
+
     let stage_privs = map(
         rule! {
             #comma_separated_list1(stage_priv_type) ~ ON ~ STAGE ~ #ident
@@ -2991,9 +3213,12 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
     rule!(
         #role : "ROLE <role_name>"
         | #udf_privs: "USAGE ON UDF <udf_name>"
+        | #object_privs: "USAGE ON { SEQUENCE | PROCEDURE | CONNECTION | FILE FORMAT } <object_name>"
+        | #column_privs : "<column_privileges> (<column>, ...) ON <database>.<table>"
         | #privs : "<privileges> ON <privileges_level>"
         | #stage_privs : "<stage_privileges> ON STAGE <stage_name>"
         | #udf_all_privs: "ALL [ PRIVILEGES ] ON UDF <udf_name>"
+        | #object_all_privs: "ALL [ PRIVILEGES ] ON { SEQUENCE | PROCEDURE | CONNECTION | FILE FORMAT } <object_name>"
         | #all : "ALL [ PRIVILEGES ] ON <privileges_level>"
     )(i)
 }
@@ -3023,6 +3248,14 @@ pub fn priv_type(i: Input) -> IResult<UserPrivilegeType> {
     ))(i)
 }
 
+pub fn column_priv_type(i: Input) -> IResult<UserPrivilegeType> {
+    alt((
+        value(UserPrivilegeType::Select, rule! { SELECT }),
+        value(UserPrivilegeType::Insert, rule! { INSERT }),
+        value(UserPrivilegeType::Update, rule! { UPDATE }),
+    ))(i)
+}
+
 pub fn stage_priv_type(i: Input) -> IResult<UserPrivilegeType> {
     alt((
         value(UserPrivilegeType::Read, rule! { READ }),
@@ -3071,11 +3304,32 @@ pub fn on_object_name(i: Input) -> IResult<GrantObjectName> {
         GrantObjectName::UDF(udf_name.to_string())
     });
 
+    let sequence = map(rule! { SEQUENCE ~ #ident }, |(_, sequence)| {
+        GrantObjectName::Sequence(sequence)
+    });
+
+    let procedure = map(
+        rule! { PROCEDURE ~ #procedure_identity },
+        |(_, procedure)| GrantObjectName::Procedure(procedure),
+    );
+
+    let connection = map(rule! { CONNECTION ~ #ident }, |(_, connection)| {
+        GrantObjectName::Connection(connection.to_string())
+    });
+
+    let file_format = map(rule! { FILE ~ FORMAT ~ #ident }, |(_, _, file_format)| {
+        GrantObjectName::FileFormat(file_format.to_string())
+    });
+
     rule!(
         #database : "DATABASE <database>"
         | #table : "TABLE <database>.<table>"
         | #stage : "STAGE <stage_name>"
         | #udf : "UDF <udf_name>"
+        | #sequence : "SEQUENCE <sequence_name>"
+        | #procedure : "PROCEDURE <procedure_name>(<type_name>, ...)"
+        | #connection : "CONNECTION <connection_name>"
+        | #file_format : "FILE FORMAT <file_format_name>"
     )(i)
 }
 
@@ -3181,12 +3435,73 @@ pub fn grant_ownership_level(i: Input) -> IResult<AccountMgrLevel> {
     );
 
     rule!(
-        #db : "<database>.*"
+        #usage_object_level : "{ SEQUENCE | PROCEDURE | CONNECTION | FILE FORMAT } <object_name>"
+        | #db : "<database>.*"
         | #table : "<database>.<table>"
         | #object : "STAGE | UDF <object_name>"
     )(i)
 }
 
+/// The objects that can only be granted the `USAGE` privilege besides the `UDF`.
+pub fn usage_object_level(i: Input) -> IResult<AccountMgrLevel> {
+    let sequence = map(rule! { SEQUENCE ~ #ident }, |(_, sequence)| {
+        AccountMgrLevel::Sequence(sequence)
+    });
+
+    let procedure = map(
+        rule! { PROCEDURE ~ #procedure_identity },
+        |(_, procedure)| AccountMgrLevel::Procedure(procedure),
+    );
+
+    let connection = map(rule! { CONNECTION ~ #ident }, |(_, connection)| {
+        AccountMgrLevel::Connection(connection.to_string())
+    });
+
+    let file_format = map(rule! { FILE ~ FORMAT ~ #ident }, |(_, _, file_format)| {
+        AccountMgrLevel::FileFormat(file_format.to_string())
+    });
+
+    rule!(
+        #sequence : "SEQUENCE <sequence_name>"
+        | #procedure : "PROCEDURE <procedure_name>(<type_name>, ...)"
+        | #connection : "CONNECTION <connection_name>"
+        | #file_format : "FILE FORMAT <file_format_name>"
+    )(i)
+}
+
+pub fn procedure_type_name(i: Input) -> IResult<Vec<TypeName>> {
+    let procedure_type_names = map(
+        rule! {
+            "(" ~ #comma_separated_list1(type_name) ~ ")"
+        },
+        |(_, args, _)| args,
+    );
+    let procedure_empty_types = map(
+        rule! {
+            "(" ~ ")"
+        },
+        |(_, _)| vec![],
+    );
+    rule!(#procedure_empty_types: "()"
+        | #procedure_type_names: "(<type_name>, ...)")(i)
+}
+
+pub fn procedure_identity(i: Input) -> IResult<ProcedureIdentity> {
+    map(
+        rule! {
+            #ident ~ #procedure_type_name
+        },
+        |(name, args)| ProcedureIdentity {
+            name: name.to_string(),
+            args_type: args
+                .iter()
+                .map(|arg| arg.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        },
+    )(i)
+}
+
 pub fn show_grant_option(i: Input) -> IResult<ShowGrantOption> {
     let grant_role = map(
         rule! {
@@ -3204,7 +3519,7 @@ pub fn show_grant_option(i: Input) -> IResult<ShowGrantOption> {
 
     rule!(
         #grant_role: "FOR  { ROLE <role_name> | [USER] <user> }"
-        | #share_object_name: "ON {DATABASE <db_name> | TABLE <db_name>.<table_name> | UDF <udf_name> | STAGE <stage_name> }"
+        | #share_object_name: "ON {DATABASE <db_name> | TABLE <db_name>.<table_name> | UDF <udf_name> | STAGE <stage_name> | SEQUENCE <sequence_name> | PROCEDURE <procedure_name>(<type_name>, ...) | CONNECTION <connection_name> | FILE FORMAT <file_format_name> }"
     )(i)
 }
 
@@ -3351,6 +3666,12 @@ pub fn modify_column_type(i: Input) -> IResult<ColumnDefinition> {
     )(i)
 }
 
+fn row_access_policy_arg(i: Input) -> IResult<RowAccessPolicyArg> {
+    map(rule! { #ident ~ #type_name }, |(arg_name, arg_type)| {
+        RowAccessPolicyArg { arg_name, arg_type }
+    })(i)
+}
+
 pub fn modify_column_action(i: Input) -> IResult<ModifyColumnAction> {
     let set_mask_policy = map(
         rule! {
@@ -3435,6 +3756,23 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, action)| AlterTableAction::ModifyColumn { action },
     );
 
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ ^POLICY ~ ^#ident ~ ^ON ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ ^POLICY ~ ^#ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
     let drop_column = map(
         rule! {
             DROP ~ COLUMN? ~ #ident
@@ -3460,6 +3798,23 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _)| AlterTableAction::DropTableClusterKey,
     );
 
+    let drop_partition = map(
+        rule! {
+            DROP ~ PARTITION ~ ( IF ~ ^EXISTS )? ~ ^"(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, opt_if_exists, _, partition, _)| AlterTableAction::DropPartition {
+            if_exists: opt_if_exists.is_some(),
+            partition,
+        },
+    );
+
+    let truncate_partition = map(
+        rule! {
+            TRUNCATE ~ ^PARTITION ~ ^"(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, _, partition, _)| AlterTableAction::TruncatePartition { partition },
+    );
+
     let recluster_table = map(
         rule! {
             RECLUSTER ~ FINAL? ~ ( WHERE ~ ^#expr )? ~ ( LIMIT ~ #literal_u64 )?
@@ -3495,9 +3850,13 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     rule!(
         #alter_table_cluster_key
         | #drop_table_cluster_key
+        | #drop_partition
+        | #truncate_partition
         | #rename_table
         | #rename_column
         | #modify_table_comment
+        | #add_row_access_policy
+        | #drop_row_access_policy
         | #add_column
         | #drop_column
         | #modify_column
@@ -3747,6 +4106,7 @@ pub fn alter_task_option(i: Input) -> IResult<AlterTaskOptions> {
              ~ ( SUSPEND_TASK_AFTER_NUM_FAILURES ~ ^"=" ~ ^#literal_u64 )?
              ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
              ~ ( ERROR_INTEGRATION  ~ ^"=" ~ ^#literal_string )?
+             ~ ( SUCCESS_INTEGRATION  ~ ^"=" ~ ^#literal_string )?
              ~ #set_table_option?
         },
         |(
@@ -3756,6 +4116,7 @@ pub fn alter_task_option(i: Input) -> IResult<AlterTaskOptions> {
             suspend_opts,
             comment,
             err_integration,
+            success_integration,
             session_opts,
         )| {
             AlterTaskOptions::Set {
@@ -3764,6 +4125,7 @@ pub fn alter_task_option(i: Input) -> IResult<AlterTaskOptions> {
                 suspend_task_after_num_failures: suspend_opts.map(|(_, _, num)| num),
                 comments: comment.map(|(_, _, comment)| comment),
                 error_integration: err_integration.map(|(_, _, integration)| integration),
+                success_integration: success_integration.map(|(_, _, integration)| integration),
                 session_parameters: session_opts,
             }
         },
@@ -4084,9 +4446,31 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         | #unset_password_policy
         | #set_disabled_option
         | #must_change_password
+        | #public_key_option
     )(i)
 }
 
+pub fn public_key_option(i: Input) -> IResult<UserOptionItem> {
+    alt((
+        map(
+            rule! { SET ~ RSA_PUBLIC_KEY ~ ^"=" ~ ^#literal_string },
+            |(_, _, _, key)| UserOptionItem::SetRsaPublicKey(key),
+        ),
+        map(
+            rule! { SET ~ RSA_PUBLIC_KEY_2 ~ ^"=" ~ ^#literal_string },
+            |(_, _, _, key)| UserOptionItem::SetRsaPublicKey2(key),
+        ),
+        value(
+            UserOptionItem::UnsetRsaPublicKey,
+            rule! { UNSET ~ RSA_PUBLIC_KEY },
+        ),
+        value(
+            UserOptionItem::UnsetRsaPublicKey2,
+            rule! { UNSET ~ RSA_PUBLIC_KEY_2 },
+        ),
+    ))(i)
+}
+
 pub fn user_identity(i: Input) -> IResult<UserIdentity> {
     map(
         rule! {
@@ -4105,6 +4489,7 @@ pub fn auth_type(i: Input) -> IResult<AuthType> {
         value(AuthType::Sha256Password, rule! { SHA256_PASSWORD }),
         value(AuthType::DoubleSha1Password, rule! { DOUBLE_SHA1_PASSWORD }),
         value(AuthType::JWT, rule! { JWT }),
+        value(AuthType::Ldap, rule! { LDAP }),
     ))(i)
 }
 
@@ -4413,12 +4798,19 @@ pub fn notification_webhook_options(i: Input) -> IResult<NotificationWebhookOpti
         },
         |(_, _, v)| ("authorization_header".to_string(), v.to_string()),
     );
+    let signing_secret_option = map(
+        rule! {
+            SIGNING_SECRET ~ "=" ~ #literal_string
+        },
+        |(_, _, v)| ("signing_secret".to_string(), v.to_string()),
+    );
 
     map(
         rule! { ((
         #url_option
         | #method_option
-        | #auth_option) ~ ","?)* },
+        | #auth_option
+        | #signing_secret_option) ~ ","?)* },
         |opts| {
             NotificationWebhookOptions::from_iter(
                 opts.iter().map(|((k, v), _)| (k.to_uppercase(), v.clone())),
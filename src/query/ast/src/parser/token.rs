@@ -325,6 +325,8 @@ pub enum TokenKind {
     // 1. Add the keyword to token kind variants by alphabetical order.
     // 2. Search in this file to see if the new keyword is a commented out reserved keyword. If
     //    so, uncomment the keyword in the reserved list.
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
     #[token("ACCOUNT", ignore(ascii_case))]
     ACCOUNT,
     #[token("ALL", ignore(ascii_case))]
@@ -345,6 +347,8 @@ pub enum TokenKind {
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
     AUTO,
+    #[token("AUTOINCREMENT", ignore(ascii_case))]
+    AUTOINCREMENT,
     #[token("SOME", ignore(ascii_case))]
     SOME,
     #[token("ALTER", ignore(ascii_case))]
@@ -409,6 +413,8 @@ pub enum TokenKind {
     BZ2,
     #[token("BLOCK", ignore(ascii_case))]
     BLOCK,
+    #[token("CACHE", ignore(ascii_case))]
+    CACHE,
     #[token("CALL", ignore(ascii_case))]
     CALL,
     #[token("CASE", ignore(ascii_case))]
@@ -661,6 +667,10 @@ pub enum TokenKind {
     HAVING,
     #[token("HIGH", ignore(ascii_case))]
     HIGH,
+    #[token("HINT", ignore(ascii_case))]
+    HINT,
+    #[token("HINTS", ignore(ascii_case))]
+    HINTS,
     #[token("HILBERT", ignore(ascii_case))]
     HILBERT,
     #[token("HISTORY", ignore(ascii_case))]
@@ -679,12 +689,16 @@ pub enum TokenKind {
     IDENTIFIED,
     #[token("IDENTIFIER", ignore(ascii_case))]
     IDENTIFIER,
+    #[token("IDENTITY", ignore(ascii_case))]
+    IDENTITY,
     #[token("IF", ignore(ascii_case))]
     IF,
     #[token("IN", ignore(ascii_case))]
     IN,
     #[token("INCLUDE_QUERY_ID", ignore(ascii_case))]
     INCLUDE_QUERY_ID,
+    #[token("INCREMENT", ignore(ascii_case))]
+    INCREMENT,
     #[token("INCREMENTAL", ignore(ascii_case))]
     INCREMENTAL,
     #[token("INDEX", ignore(ascii_case))]
@@ -729,6 +743,10 @@ pub enum TokenKind {
     ISOYEAR,
     #[token("JOIN", ignore(ascii_case))]
     JOIN,
+    #[token("JOIN_DISTRIBUTION", ignore(ascii_case))]
+    JOIN_DISTRIBUTION,
+    #[token("JOIN_ORDER", ignore(ascii_case))]
+    JOIN_ORDER,
     #[token("JSON", ignore(ascii_case))]
     JSON,
     #[token("JULIAN", ignore(ascii_case))]
@@ -741,6 +759,8 @@ pub enum TokenKind {
     KILL,
     #[token("LATERAL", ignore(ascii_case))]
     LATERAL,
+    #[token("LDAP", ignore(ascii_case))]
+    LDAP,
     #[token("LINEAR", ignore(ascii_case))]
     LINEAR,
     #[token("LOCATION_PREFIX", ignore(ascii_case))]
@@ -812,6 +832,8 @@ pub enum TokenKind {
     NATURAL,
     #[token("NETWORK", ignore(ascii_case))]
     NETWORK,
+    #[token("NGRAM", ignore(ascii_case))]
+    NGRAM,
     #[token("DISABLED", ignore(ascii_case))]
     DISABLED,
     #[token("NDJSON", ignore(ascii_case))]
@@ -960,6 +982,10 @@ pub enum TokenKind {
     ROWS,
     #[token("ROW_TAG", ignore(ascii_case))]
     ROW_TAG,
+    #[token("RSA_PUBLIC_KEY", ignore(ascii_case))]
+    RSA_PUBLIC_KEY,
+    #[token("RSA_PUBLIC_KEY_2", ignore(ascii_case))]
+    RSA_PUBLIC_KEY_2,
     #[token("GRANT", ignore(ascii_case))]
     GRANT,
     #[token("REPEAT", ignore(ascii_case))]
@@ -1062,6 +1088,8 @@ pub enum TokenKind {
     SPLIT_SIZE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
     #[token("USAGE", ignore(ascii_case))]
@@ -1074,6 +1102,8 @@ pub enum TokenKind {
     UPLOAD,
     #[token("SEQUENCE", ignore(ascii_case))]
     SEQUENCE,
+    #[token("SEQUENCES", ignore(ascii_case))]
+    SEQUENCES,
     #[token("SHARE", ignore(ascii_case))]
     SHARE,
     #[token("SHARES", ignore(ascii_case))]
@@ -1180,6 +1210,8 @@ pub enum TokenKind {
     METHOD,
     #[token("AUTHORIZATION_HEADER", ignore(ascii_case))]
     AUTHORIZATION_HEADER,
+    #[token("SIGNING_SECRET", ignore(ascii_case))]
+    SIGNING_SECRET,
     #[token("USE", ignore(ascii_case))]
     USE,
     #[token("USER", ignore(ascii_case))]
@@ -1202,6 +1234,8 @@ pub enum TokenKind {
     VARIANT,
     #[token("VARIABLE", ignore(ascii_case))]
     VARIABLE,
+    #[token("VECTOR", ignore(ascii_case))]
+    VECTOR,
     #[token("VERBOSE", ignore(ascii_case))]
     VERBOSE,
     #[token("VIEW", ignore(ascii_case))]
@@ -1288,6 +1322,8 @@ pub enum TokenKind {
     EXECUTE,
     #[token("SUSPEND", ignore(ascii_case))]
     SUSPEND,
+    #[token("RESTART", ignore(ascii_case))]
+    RESTART,
     #[token("RESUME", ignore(ascii_case))]
     RESUME,
     #[token("PIPE", ignore(ascii_case))]
@@ -1302,6 +1338,8 @@ pub enum TokenKind {
     WEBHOOK,
     #[token("ERROR_INTEGRATION", ignore(ascii_case))]
     ERROR_INTEGRATION,
+    #[token("SUCCESS_INTEGRATION", ignore(ascii_case))]
+    SUCCESS_INTEGRATION,
     #[token("AUTO_INGEST", ignore(ascii_case))]
     AUTO_INGEST,
     #[token("PIPE_EXECUTION_PAUSED", ignore(ascii_case))]
@@ -110,6 +110,8 @@ fn test_statement() {
         r#"explain replace into test on(c) select sum(c) as c from source group by v;"#,
         r#"explain pipeline select a from t1 ignore_result;"#,
         r#"explain(verbose, logical, optimized) select * from t where a = 1"#,
+        r#"explain analyze json select a from b;"#,
+        r#"explain analyze partial json select a from b;"#,
         r#"describe a;"#,
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
         r#"CREATE AGGREGATING INDEX idx1 AS SELECT SUM(a), b FROM t1 WHERE b > 3 GROUP BY b;"#,
@@ -117,6 +119,7 @@ fn test_statement() {
         r#"CREATE OR REPLACE INVERTED INDEX idx2 ON t1 (a, b);"#,
         r#"create table a (c decimal(38, 0))"#,
         r#"create table a (c decimal(38))"#,
+        r#"create table a (c int) partition by (c)"#,
         r#"create or replace table a (c decimal(38))"#,
         r#"create or replace table a (c int(10) unsigned)"#,
         r#"create table if not exists a.b (c integer not null default 1, b varchar);"#,
@@ -176,6 +179,8 @@ fn test_statement() {
         r#"CREATE TABLE t(c1 int not null, c2 bigint not null, c3 varchar not null);"#,
         r#"CREATE TABLE t(c1 varbinary, c2 binary(10));"#,
         r#"CREATE TABLE t(c1 int default 1);"#,
+        r#"CREATE TABLE t(id bigint autoincrement, c1 int);"#,
+        r#"CREATE TABLE t(id bigint generated by default as identity (start with 10 increment by -2), c1 int);"#,
         r#"create table abc as (select * from xyz limit 10)"#,
         r#"ALTER USER u1 IDENTIFIED BY '123456';"#,
         r#"ALTER USER u1 WITH disabled = false;"#,
@@ -183,6 +188,7 @@ fn test_statement() {
         r#"ALTER USER u1 WITH DEFAULT_ROLE = role1, DISABLED=true, TENANTSETTING;"#,
         r#"ALTER USER u1 WITH SET NETWORK POLICY = 'policy1';"#,
         r#"ALTER USER u1 WITH UNSET NETWORK POLICY;"#,
+        r#"ALTER USER ci SET RSA_PUBLIC_KEY = 'MCowBQYDK2VwAyEA', UNSET RSA_PUBLIC_KEY_2;"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH SET NETWORK POLICY='policy1'"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH disabled=true"#,
@@ -231,6 +237,7 @@ fn test_statement() {
         r#"select * from numbers(1000) sample block (99) row (10);"#,
         r#"insert into t (c1, c2) values (1, 2), (3, 4);"#,
         r#"insert into t (c1, c2) values (1, 2);"#,
+        r#"insert overwrite t partition (1) values (1, 2);"#,
         r#"insert into table t select * from t2;"#,
         r#"select parse_json('{"k1": [0, 1, 2]}').k1[0];"#,
         r#"SELECT avg((number > 314)::UInt32);"#,
@@ -261,6 +268,9 @@ fn test_statement() {
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
         r#"ALTER TABLE t COMMENT='t1-commnet';"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
+        r#"ALTER TABLE t DROP PARTITION (1);"#,
+        r#"ALTER TABLE t DROP PARTITION IF EXISTS ('2024-01-01');"#,
+        r#"ALTER TABLE t TRUNCATE PARTITION (1);"#,
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0 LIMIT 10;"#,
         r#"ALTER TABLE t ADD c int null;"#,
         r#"ALTER TABLE t ADD COLUMN c int null;"#,
@@ -605,9 +615,14 @@ fn test_statement() {
         r#"ALTER VIRTUAL COLUMN (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"DROP VIRTUAL COLUMN FOR t"#,
         r#"REFRESH VIRTUAL COLUMN FOR t"#,
+        r#"REFRESH NGRAM INDEX ON db.t LIMIT 10"#,
         r#"CREATE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"CREATE OR REPLACE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"ALTER NETWORK POLICY mypolicy SET ALLOWED_IP_LIST=('192.168.10.0/24','192.168.255.1') BLOCKED_IP_LIST=('192.168.1.99') COMMENT='test'"#,
+        // query hints
+        r#"CREATE OR REPLACE QUERY HINT JOIN_ORDER = 'FIXED' JOIN_DISTRIBUTION = 'BROADCAST' SETTINGS (max_threads = 8, enable_bloom_runtime_filter = 1) COMMENT = 'pin dashboard plan' FOR 'SELECT * FROM t1 JOIN t2 ON t1.a = t2.a WHERE t1.b = 1'"#,
+        r#"DROP QUERY HINT IF EXISTS FOR 'SELECT * FROM t1 JOIN t2 ON t1.a = t2.a WHERE t1.b = 1'"#,
+        r#"SHOW QUERY HINTS"#,
         // dynamic tables
         r#"
             CREATE OR REPLACE DYNAMIC TABLE db.MyDynamic LIKE t
@@ -654,6 +669,7 @@ fn test_statement() {
             AS
                 SELECT avg(a), d FROM db.t GROUP BY d
         "#,
+        r#"ALTER DYNAMIC TABLE db.MyDynamic REFRESH"#,
         // tasks
         r#"CREATE TASK IF NOT EXISTS MyTask1 WAREHOUSE = 'MyWarehouse' SCHEDULE = 15 MINUTE SUSPEND_TASK_AFTER_NUM_FAILURES = 3 ERROR_INTEGRATION = 'notification_name' COMMENT = 'This is test task 1' DATABASE = 'target', TIMEZONE = 'America/Los Angeles' AS SELECT * FROM MyTable1"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 WAREHOUSE = 'MyWarehouse' SCHEDULE = 15 SECOND SUSPEND_TASK_AFTER_NUM_FAILURES = 3 COMMENT = 'This is test task 1' AS SELECT * FROM MyTable1"#,
@@ -704,6 +720,7 @@ fn test_statement() {
         r#"ALTER TASK MyTask1 SET WAREHOUSE= 'MyWarehouse' SCHEDULE = 5 SECOND SUSPEND_TASK_AFTER_NUM_FAILURES = 10 COMMENT = 'serverless + cron'"#,
         r#"ALTER TASK MyTask1 SET DATABASE='newDB', TIMEZONE='America/Los_Angeles'"#,
         r#"ALTER TASK MyTask1 SET ERROR_INTEGRATION = 'candidate_notifictaion'"#,
+        r#"ALTER TASK MyTask1 SET ERROR_INTEGRATION = 'on_error' SUCCESS_INTEGRATION = 'on_success'"#,
         r#"ALTER TASK MyTask2 MODIFY AS SELECT CURRENT_VERSION()"#,
         r#"
             ALTER TASK MyTask2 MODIFY AS
@@ -740,6 +757,7 @@ fn test_statement() {
         // notification
         r#"CREATE NOTIFICATION INTEGRATION IF NOT EXISTS SampleNotification type = webhook enabled = true webhook = (url = 'https://example.com', method = 'GET', authorization_header = 'bearer auth')"#,
         r#"CREATE NOTIFICATION INTEGRATION SampleNotification type = webhook enabled = true webhook = (url = 'https://example.com') COMMENT = 'notify'"#,
+        r#"CREATE NOTIFICATION INTEGRATION SampleNotification type = webhook enabled = true webhook = (url = 'https://example.com', method = 'POST', signing_secret = 'secret')"#,
         r#"ALTER NOTIFICATION INTEGRATION SampleNotification SET enabled = true"#,
         r#"ALTER NOTIFICATION INTEGRATION SampleNotification SET webhook = (url = 'https://example.com')"#,
         r#"ALTER NOTIFICATION INTEGRATION SampleNotification SET comment = '1'"#,
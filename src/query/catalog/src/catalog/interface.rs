@@ -21,6 +21,8 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
+use databend_common_meta_app::schema::AlterSequenceReply;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::CommitTableMetaReply;
 use databend_common_meta_app::schema::CommitTableMetaReq;
@@ -76,8 +78,11 @@ use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
+use databend_common_meta_app::schema::SequenceMeta;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -421,6 +426,11 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
         req: SetTableColumnMaskPolicyReq,
     ) -> Result<SetTableColumnMaskPolicyReply>;
 
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply>;
+
     async fn create_table_index(&self, req: CreateTableIndexReq) -> Result<()>;
 
     async fn drop_table_index(&self, req: DropTableIndexReq) -> Result<()>;
@@ -499,11 +509,17 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
     async fn create_sequence(&self, req: CreateSequenceReq) -> Result<CreateSequenceReply>;
     async fn get_sequence(&self, req: GetSequenceReq) -> Result<GetSequenceReply>;
 
+    async fn list_sequences(&self, tenant: &Tenant) -> Result<Vec<(String, SequenceMeta)>>;
+
+    /// Returns exactly `req.count` values, which may be served from the values
+    /// cached by this node if the sequence has a `CACHE`.
     async fn get_sequence_next_value(
         &self,
         req: GetSequenceNextValueReq,
     ) -> Result<GetSequenceNextValueReply>;
 
+    async fn alter_sequence(&self, req: AlterSequenceReq) -> Result<AlterSequenceReply>;
+
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply>;
 
     fn set_session_state(&self, _state: SessionState) -> Arc<dyn Catalog> {
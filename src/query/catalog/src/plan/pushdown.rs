@@ -18,6 +18,7 @@ use std::fmt::Debug;
 use databend_common_ast::ast::SampleConfig;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::F32;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataSchema;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
@@ -113,6 +114,25 @@ pub struct InvertedIndexInfo {
     pub inverted_index_option: Option<InvertedIndexOption>,
 }
 
+/// Information about vector index.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorIndexInfo {
+    /// The index name.
+    pub index_name: String,
+    /// The index version.
+    pub index_version: String,
+    /// The index options: distance, lists, probes.
+    pub index_options: BTreeMap<String, String>,
+    /// The id of the indexed column.
+    pub column_id: ColumnId,
+    /// The distance function, `cosine_distance` or `l2_distance`.
+    pub func_name: String,
+    /// The query vector to compute the distance with.
+    pub query_values: Vec<F32>,
+    /// The number of nearest rows required by the query.
+    pub limit: usize,
+}
+
 /// Extras is a wrapper for push down items.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, PartialEq, Eq)]
 pub struct PushDownInfo {
@@ -142,6 +162,7 @@ pub struct PushDownInfo {
     /// Identifies the type of data change we are looking for
     pub change_type: Option<ChangeType>,
     pub inverted_index: Option<InvertedIndexInfo>,
+    pub vector_index: Option<VectorIndexInfo>,
     /// Used by table sample
     pub sample: Option<SampleConfig>,
 }
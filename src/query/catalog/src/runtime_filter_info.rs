@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
+use parking_lot::RwLock;
 use xorf::BinaryFuse16;
 
 #[derive(Clone, Debug, Default)]
@@ -20,6 +24,7 @@ pub struct RuntimeFilterInfo {
     inlist: Vec<Expr<String>>,
     min_max: Vec<Expr<String>>,
     bloom: Vec<(String, BinaryFuse16)>,
+    topn: Option<Arc<TopNRuntimeFilter>>,
 }
 
 impl RuntimeFilterInfo {
@@ -35,6 +40,10 @@ impl RuntimeFilterInfo {
         self.min_max.push(expr);
     }
 
+    pub fn set_topn(&mut self, topn: Arc<TopNRuntimeFilter>) {
+        self.topn = Some(topn);
+    }
+
     pub fn get_inlist(&self) -> &Vec<Expr<String>> {
         &self.inlist
     }
@@ -47,6 +56,10 @@ impl RuntimeFilterInfo {
         &self.min_max
     }
 
+    pub fn get_topn(&self) -> Option<&Arc<TopNRuntimeFilter>> {
+        self.topn.as_ref()
+    }
+
     pub fn blooms(self) -> Vec<(String, BinaryFuse16)> {
         self.bloom
     }
@@ -60,6 +73,65 @@ impl RuntimeFilterInfo {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.inlist.is_empty() && self.bloom.is_empty() && self.min_max.is_empty()
+        self.inlist.is_empty()
+            && self.bloom.is_empty()
+            && self.min_max.is_empty()
+            && self.topn.is_none()
+    }
+}
+
+/// Runtime filter of `ORDER BY column LIMIT n` on a table scan.
+///
+/// The threshold is the n-th value of the first sort key seen so far, it's tightened
+/// by the sort operator during execution. A block can be skipped if all its values
+/// of the column are sorted after the threshold.
+#[derive(Debug)]
+pub struct TopNRuntimeFilter {
+    column_name: String,
+    asc: bool,
+    nulls_first: bool,
+    threshold: RwLock<Option<Scalar>>,
+}
+
+impl TopNRuntimeFilter {
+    pub fn new(column_name: String, asc: bool, nulls_first: bool) -> Self {
+        Self {
+            column_name,
+            asc,
+            nulls_first,
+            threshold: RwLock::new(None),
+        }
+    }
+
+    pub fn column_name(&self) -> &str {
+        &self.column_name
+    }
+
+    pub fn asc(&self) -> bool {
+        self.asc
+    }
+
+    pub fn nulls_first(&self) -> bool {
+        self.nulls_first
+    }
+
+    pub fn threshold(&self) -> Option<Scalar> {
+        self.threshold.read().clone()
+    }
+
+    /// Update the threshold if `value` is sorted before the current one.
+    pub fn update(&self, value: Scalar) {
+        if value.is_null() {
+            return;
+        }
+        let mut threshold = self.threshold.write();
+        let tighter = match threshold.as_ref() {
+            None => true,
+            Some(current) if self.asc => value < *current,
+            Some(current) => value > *current,
+        };
+        if tighter {
+            *threshold = Some(value);
+        }
     }
 }
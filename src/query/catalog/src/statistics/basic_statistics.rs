@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
+use databend_common_expression::ColumnId;
 use databend_common_storage::Datum;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 
@@ -29,6 +32,16 @@ pub struct BasicColumnStatistics {
     pub null_count: u64,
 }
 
+/// Extended statistics of a group of columns
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default)]
+pub struct BasicColumnGroupStatistics {
+    pub column_ids: Vec<ColumnId>,
+    /// Number of distinct values of the columns together
+    pub ndv: u64,
+    /// Degree of the functional dependency of each column on the others
+    pub dependencies: BTreeMap<ColumnId, f64>,
+}
+
 impl From<ColumnStatistics> for BasicColumnStatistics {
     fn from(value: ColumnStatistics) -> Self {
         Self {
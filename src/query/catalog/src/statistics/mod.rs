@@ -13,5 +13,6 @@
 // limitations under the License.
 
 pub mod basic_statistics;
+pub use basic_statistics::BasicColumnGroupStatistics;
 pub use basic_statistics::BasicColumnStatistics;
 pub mod data_cache_statistics;
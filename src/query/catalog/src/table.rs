@@ -37,6 +37,7 @@ use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_types::MetaId;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::Histogram;
+use databend_common_storage::MostCommonValues;
 use databend_common_storage::StorageMetrics;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::TableSnapshot;
@@ -51,6 +52,7 @@ use crate::plan::Partitions;
 use crate::plan::PushDownInfo;
 use crate::plan::ReclusterParts;
 use crate::plan::StreamColumn;
+use crate::statistics::BasicColumnGroupStatistics;
 use crate::statistics::BasicColumnStatistics;
 use crate::table_args::TableArgs;
 use crate::table_context::TableContext;
@@ -546,6 +548,16 @@ pub trait ColumnStatisticsProvider: Send {
     fn histogram(&self, _column_id: ColumnId) -> Option<Histogram> {
         None
     }
+
+    // return the most common values if any
+    fn most_common_values(&self, _column_id: ColumnId) -> Option<MostCommonValues> {
+        None
+    }
+
+    // return the extended statistics of column groups
+    fn column_groups(&self) -> Vec<BasicColumnGroupStatistics> {
+        vec![]
+    }
 }
 
 pub struct DummyColumnStatisticsProvider;
@@ -76,6 +76,7 @@ use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
 use crate::query_kind::QueryKind;
 use crate::runtime_filter_info::RuntimeFilterInfo;
+use crate::runtime_filter_info::TopNRuntimeFilter;
 use crate::statistics::data_cache_statistics::DataCacheMetrics;
 use crate::table::Table;
 
@@ -182,6 +183,10 @@ pub trait TableContext: Send + Sync {
     fn get_compaction_num_block_hint(&self, _table_name: &str) -> u64 {
         unimplemented!()
     }
+    /// Record that a column of the table is read through its masking policy.
+    fn add_masked_column(&self, _table_name: &str, _column_name: &str) {
+        unimplemented!()
+    }
     fn set_table_snapshot(&self, snapshot: Arc<TableSnapshot>);
     fn get_table_snapshot(&self) -> Option<Arc<TableSnapshot>>;
     fn set_lazy_mutation_delete(&self, lazy: bool);
@@ -339,6 +344,8 @@ pub trait TableContext: Send + Sync {
 
     fn get_min_max_runtime_filter_with_id(&self, id: usize) -> Vec<Expr<String>>;
 
+    fn get_topn_runtime_filter_with_id(&self, id: usize) -> Option<Arc<TopNRuntimeFilter>>;
+
     fn has_bloom_runtime_filters(&self, id: usize) -> bool;
     fn txn_mgr(&self) -> TxnManagerRef;
 
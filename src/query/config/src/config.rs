@@ -44,6 +44,7 @@ use databend_common_meta_app::storage::StorageWebhdfsConfig as InnerStorageWebhd
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_app::tenant::TenantQuota;
 use databend_common_storage::StorageConfig as InnerStorageConfig;
+use databend_common_tracing::AuditLogConfig as InnerAuditLogConfig;
 use databend_common_tracing::Config as InnerLogConfig;
 use databend_common_tracing::FileConfig as InnerFileLogConfig;
 use databend_common_tracing::OTLPConfig as InnerOTLPLogConfig;
@@ -66,9 +67,11 @@ use super::inner;
 use super::inner::CatalogConfig as InnerCatalogConfig;
 use super::inner::CatalogHiveConfig as InnerCatalogHiveConfig;
 use super::inner::InnerConfig;
+use super::inner::LdapConfig;
 use super::inner::LocalConfig as InnerLocalConfig;
 use super::inner::MetaConfig as InnerMetaConfig;
 use super::inner::QueryConfig as InnerQueryConfig;
+use super::inner::QueryHistoryConfig;
 use crate::background_config::BackgroundConfig;
 use crate::builtin::BuiltInConfig;
 use crate::builtin::UDFConfig;
@@ -164,6 +167,12 @@ pub enum Commands {
         #[clap(long, default_value_t)]
         output_format: String,
     },
+    /// Verify the hash chain of audit log files with `log.audit.hmac_key`,
+    /// the files of a node are given in the order they are written.
+    VerifyAuditLog {
+        #[clap(required = true)]
+        files: Vec<String>,
+    },
 }
 
 impl Default for Config {
@@ -1518,6 +1527,23 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "10000")]
     pub max_query_log_size: usize,
 
+    /// Persist the query log, profile log and login events into the tables of
+    /// the `system_history` database, so they survive restarts and are visible to every node.
+    #[clap(long, value_name = "VALUE", default_value = "false")]
+    pub enable_query_history: bool,
+
+    /// Interval of writing the buffered history events into the tables.
+    #[clap(long, value_name = "VALUE", default_value = "5")]
+    pub query_history_flush_interval_secs: u64,
+
+    /// Events are written before the flush interval once this many events are buffered.
+    #[clap(long, value_name = "VALUE", default_value = "1000")]
+    pub query_history_batch_size: usize,
+
+    /// Events older than this are removed when the history tables are compacted.
+    #[clap(long, value_name = "VALUE", default_value = "7")]
+    pub query_history_retention_days: u64,
+
     #[clap(long, value_name = "VALUE")]
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
@@ -1532,6 +1558,42 @@ pub struct QueryConfig {
     #[clap(skip)]
     pub jwt_key_files: Vec<String>,
 
+    /// LDAP server url used to verify users created with `IDENTIFIED WITH ldap`,
+    /// e.g. `ldap://127.0.0.1:389` or `ldaps://ldap.example.com`. Empty disables LDAP auth.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub ldap_url: String,
+
+    /// DN template for simple bind, `{user}` is replaced with the login name,
+    /// e.g. `uid={user},ou=people,dc=example,dc=com`.
+    /// If empty, search+bind is used with the `ldap_search_*` options.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub ldap_bind_dn_template: String,
+
+    /// DN of the service account used to search for the user entry. Empty means anonymous search.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub ldap_search_bind_dn: String,
+
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub ldap_search_bind_password: String,
+
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub ldap_search_base: String,
+
+    /// Filter used to find the user entry, `{user}` is replaced with the login name.
+    #[clap(long, value_name = "VALUE", default_value = "(uid={user})")]
+    pub ldap_search_filter: String,
+
+    /// Attribute of the user entry listing its groups.
+    #[clap(long, value_name = "VALUE", default_value = "memberOf")]
+    pub ldap_group_attribute: String,
+
+    /// Maps LDAP groups (full DN or CN) to roles granted for the session at login.
+    #[clap(skip)]
+    pub ldap_group_role_mapping: HashMap<String, String>,
+
+    #[clap(long, value_name = "VALUE", default_value = "10")]
+    pub ldap_timeout_secs: u64,
+
     #[clap(long, value_name = "VALUE", default_value = "auto")]
     pub default_storage_format: String,
 
@@ -1684,6 +1746,15 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub cloud_control_grpc_timeout: u64,
 
+    /// Run tasks with the scheduler built into the query nodes,
+    /// task definitions and runs are stored in the meta service.
+    /// Notification integrations are also served by the query nodes,
+    /// webhooks are delivered directly to their urls.
+    ///
+    /// Only takes effect if `cloud_control_grpc_server_address` is not set.
+    #[clap(long, value_name = "VALUE", default_value = "false")]
+    pub enable_builtin_task_scheduler: bool,
+
     #[clap(long, value_name = "VALUE", default_value = "50")]
     pub max_cached_queries_profiles: usize,
 
@@ -1744,12 +1815,29 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: self.shutdown_wait_timeout_ms,
             max_query_log_size: self.max_query_log_size,
+            query_history: QueryHistoryConfig {
+                enabled: self.enable_query_history,
+                flush_interval_secs: self.query_history_flush_interval_secs,
+                batch_size: self.query_history_batch_size,
+                retention_days: self.query_history_retention_days,
+            },
             databend_enterprise_license: self.databend_enterprise_license,
             management_mode: self.management_mode,
             parquet_fast_read_bytes: self.parquet_fast_read_bytes,
             max_storage_io_requests: self.max_storage_io_requests,
             jwt_key_file: self.jwt_key_file,
             jwt_key_files: self.jwt_key_files,
+            ldap: LdapConfig {
+                url: self.ldap_url,
+                bind_dn_template: self.ldap_bind_dn_template,
+                search_bind_dn: self.ldap_search_bind_dn,
+                search_bind_password: self.ldap_search_bind_password,
+                search_base: self.ldap_search_base,
+                search_filter: self.ldap_search_filter,
+                group_attribute: self.ldap_group_attribute,
+                group_role_mapping: self.ldap_group_role_mapping,
+                timeout_secs: self.ldap_timeout_secs,
+            },
             default_storage_format: self.default_storage_format,
             default_compression: self.default_compression,
             builtin: BuiltInConfig {
@@ -1775,6 +1863,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             udf_server_allow_insecure: self.udf_server_allow_insecure,
             cloud_control_grpc_server_address: self.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: self.cloud_control_grpc_timeout,
+            enable_builtin_task_scheduler: self.enable_builtin_task_scheduler,
             max_cached_queries_profiles: self.max_cached_queries_profiles,
             settings: self
                 .settings
@@ -1834,12 +1923,25 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: inner.shutdown_wait_timeout_ms,
             max_query_log_size: inner.max_query_log_size,
+            enable_query_history: inner.query_history.enabled,
+            query_history_flush_interval_secs: inner.query_history.flush_interval_secs,
+            query_history_batch_size: inner.query_history.batch_size,
+            query_history_retention_days: inner.query_history.retention_days,
             databend_enterprise_license: inner.databend_enterprise_license,
             management_mode: inner.management_mode,
             parquet_fast_read_bytes: inner.parquet_fast_read_bytes,
             max_storage_io_requests: inner.max_storage_io_requests,
             jwt_key_file: inner.jwt_key_file,
             jwt_key_files: inner.jwt_key_files,
+            ldap_url: inner.ldap.url,
+            ldap_bind_dn_template: inner.ldap.bind_dn_template,
+            ldap_search_bind_dn: inner.ldap.search_bind_dn,
+            ldap_search_bind_password: inner.ldap.search_bind_password,
+            ldap_search_base: inner.ldap.search_base,
+            ldap_search_filter: inner.ldap.search_filter,
+            ldap_group_attribute: inner.ldap.group_attribute,
+            ldap_group_role_mapping: inner.ldap.group_role_mapping,
+            ldap_timeout_secs: inner.ldap.timeout_secs,
             default_storage_format: inner.default_storage_format,
             default_compression: inner.default_compression,
             users: inner.builtin.users,
@@ -1877,6 +1979,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             udf_server_allow_insecure: inner.udf_server_allow_insecure,
             cloud_control_grpc_server_address: inner.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: inner.cloud_control_grpc_timeout,
+            enable_builtin_task_scheduler: inner.enable_builtin_task_scheduler,
             max_cached_queries_profiles: inner.max_cached_queries_profiles,
             settings: HashMap::new(),
         }
@@ -1929,6 +2032,9 @@ pub struct LogConfig {
     #[clap(flatten)]
     pub profile: ProfileLogConfig,
 
+    #[clap(flatten)]
+    pub audit: AuditLogConfig,
+
     #[clap(flatten)]
     pub structlog: StructLogConfig,
 
@@ -2004,6 +2110,22 @@ impl TryInto<InnerLogConfig> for LogConfig {
             }
         }
 
+        let mut audit: InnerAuditLogConfig = self.audit.try_into()?;
+        if audit.on && audit.hmac_key.is_empty() {
+            return Err(ErrorCode::InvalidConfig(
+                "`audit.hmac_key` must be set when audit log is on".to_string(),
+            ));
+        }
+        if audit.on && audit.dir.is_empty() && audit.otlp.is_none() {
+            if file.dir.is_empty() {
+                return Err(ErrorCode::InvalidConfig(
+                    "`dir` or `file.dir` must be set when `audit.dir` is empty".to_string(),
+                ));
+            } else {
+                audit.dir = format!("{}/audit", &file.dir);
+            }
+        }
+
         let mut structlog: InnerStructLogConfig = self.structlog.try_into()?;
         if structlog.on && structlog.dir.is_empty() {
             if file.dir.is_empty() {
@@ -2023,6 +2145,7 @@ impl TryInto<InnerLogConfig> for LogConfig {
             otlp,
             query,
             profile,
+            audit,
             structlog,
             tracing,
         })
@@ -2039,6 +2162,7 @@ impl From<InnerLogConfig> for LogConfig {
             otlp: inner.otlp.into(),
             query: inner.query.into(),
             profile: inner.profile.into(),
+            audit: inner.audit.into(),
             structlog: inner.structlog.into(),
             tracing: inner.tracing.into(),
 
@@ -2329,6 +2453,67 @@ impl From<InnerProfileLogConfig> for ProfileLogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct AuditLogConfig {
+    #[clap(
+        long = "log-audit-on", value_name = "VALUE", default_value = "false", action = ArgAction::Set, num_args = 0..=1, require_equals = true, default_missing_value = "true"
+    )]
+    #[serde(rename = "on")]
+    pub log_audit_on: bool,
+
+    /// Audit log file dir
+    #[clap(long = "log-audit-dir", value_name = "VALUE", default_value = "")]
+    #[serde(rename = "dir")]
+    pub log_audit_dir: String,
+
+    /// The max number of audit log files to keep, 0 (the default) never deletes them
+    #[clap(long = "log-audit-limit", value_name = "VALUE", default_value = "0")]
+    #[serde(rename = "limit")]
+    pub log_audit_limit: usize,
+
+    /// The secret key of the HMAC-SHA256 that chains the audit events, required if audit log is on
+    #[clap(long = "log-audit-hmac-key", value_name = "VALUE", default_value = "")]
+    #[serde(rename = "hmac_key")]
+    pub log_audit_hmac_key: String,
+
+    #[clap(skip)]
+    #[serde(flatten, with = "prefix_otlp")]
+    pub log_audit_otlp: Option<OTLPEndpointConfig>,
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        InnerAuditLogConfig::default().into()
+    }
+}
+
+impl TryInto<InnerAuditLogConfig> for AuditLogConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerAuditLogConfig> {
+        Ok(InnerAuditLogConfig {
+            on: self.log_audit_on,
+            dir: self.log_audit_dir,
+            limit: self.log_audit_limit,
+            hmac_key: self.log_audit_hmac_key,
+            otlp: self.log_audit_otlp.map(|cfg| cfg.try_into()).transpose()?,
+        })
+    }
+}
+
+impl From<InnerAuditLogConfig> for AuditLogConfig {
+    fn from(inner: InnerAuditLogConfig) -> Self {
+        Self {
+            log_audit_on: inner.on,
+            log_audit_dir: inner.dir,
+            log_audit_limit: inner.limit,
+            log_audit_hmac_key: inner.hmac_key,
+            log_audit_otlp: inner.otlp.map(|cfg| cfg.into()),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct StructLogConfig {
@@ -2779,6 +2964,22 @@ pub struct CacheConfig {
     )]
     pub inverted_index_filter_memory_ratio: u64,
 
+    /// Max number of cached vector index meta objects. Set it to 0 to disable it.
+    #[clap(
+        long = "cache-vector-index-meta-count",
+        value_name = "VALUE",
+        default_value = "3000"
+    )]
+    pub vector_index_meta_count: u64,
+
+    /// Max bytes of cached vector index lists used. Set it to 0 to disable it.
+    #[clap(
+        long = "cache-vector-index-filter-size",
+        value_name = "VALUE",
+        default_value = "1073741824"
+    )]
+    pub vector_index_filter_size: u64,
+
     #[clap(
         long = "cache-table-prune-partitions-count",
         value_name = "VALUE",
@@ -3057,6 +3258,8 @@ mod cache_config_converters {
                 inverted_index_meta_count: value.inverted_index_meta_count,
                 inverted_index_filter_size: value.inverted_index_filter_size,
                 inverted_index_filter_memory_ratio: value.inverted_index_filter_memory_ratio,
+                vector_index_meta_count: value.vector_index_meta_count,
+                vector_index_filter_size: value.vector_index_filter_size,
                 table_prune_partitions_count: value.table_prune_partitions_count,
                 data_cache_storage: value.data_cache_storage.try_into()?,
                 table_data_cache_population_queue_size: value
@@ -3084,6 +3287,8 @@ mod cache_config_converters {
                 inverted_index_meta_count: value.inverted_index_meta_count,
                 inverted_index_filter_size: value.inverted_index_filter_size,
                 inverted_index_filter_memory_ratio: value.inverted_index_filter_memory_ratio,
+                vector_index_meta_count: value.vector_index_meta_count,
+                vector_index_filter_size: value.vector_index_filter_size,
                 table_prune_partitions_count: value.table_prune_partitions_count,
                 data_cache_storage: value.data_cache_storage.into(),
                 data_cache_key_reload_policy: value.data_cache_key_reload_policy.into(),
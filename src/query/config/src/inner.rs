@@ -206,6 +206,7 @@ pub struct QueryConfig {
     /// Graceful shutdown timeout
     pub shutdown_wait_timeout_ms: u64,
     pub max_query_log_size: usize,
+    pub query_history: QueryHistoryConfig,
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
@@ -215,6 +216,7 @@ pub struct QueryConfig {
 
     pub jwt_key_file: String,
     pub jwt_key_files: Vec<String>,
+    pub ldap: LdapConfig,
     pub default_storage_format: String,
     pub default_compression: String,
     pub builtin: BuiltInConfig,
@@ -245,6 +247,7 @@ pub struct QueryConfig {
 
     pub cloud_control_grpc_server_address: Option<String>,
     pub cloud_control_grpc_timeout: u64,
+    pub enable_builtin_task_scheduler: bool,
     pub max_cached_queries_profiles: usize,
     pub settings: HashMap<String, UserSettingValue>,
 }
@@ -292,12 +295,14 @@ impl Default for QueryConfig {
             table_engine_memory_enabled: true,
             shutdown_wait_timeout_ms: 5000,
             max_query_log_size: 10_000,
+            query_history: QueryHistoryConfig::default(),
             databend_enterprise_license: None,
             management_mode: false,
             parquet_fast_read_bytes: None,
             max_storage_io_requests: None,
             jwt_key_file: "".to_string(),
             jwt_key_files: Vec::new(),
+            ldap: LdapConfig::default(),
             default_storage_format: "auto".to_string(),
             default_compression: "auto".to_string(),
             builtin: BuiltInConfig::default(),
@@ -320,6 +325,7 @@ impl Default for QueryConfig {
             udf_server_allow_insecure: false,
             cloud_control_grpc_server_address: None,
             cloud_control_grpc_timeout: 0,
+            enable_builtin_task_scheduler: false,
             data_retention_time_in_days_max: 90,
             max_cached_queries_profiles: 50,
             settings: HashMap::new(),
@@ -328,6 +334,16 @@ impl Default for QueryConfig {
 }
 
 impl QueryConfig {
+    /// Whether tasks are served, either by cloud control or by the built-in task scheduler.
+    pub fn task_service_enabled(&self) -> bool {
+        self.cloud_control_grpc_server_address.is_some() || self.enable_builtin_task_scheduler
+    }
+
+    /// Whether notification integrations are served, either by cloud control or by the query nodes.
+    pub fn notification_service_enabled(&self) -> bool {
+        self.cloud_control_grpc_server_address.is_some() || self.enable_builtin_task_scheduler
+    }
+
     pub fn to_rpc_client_tls_config(&self) -> RpcClientTlsConfig {
         RpcClientTlsConfig {
             rpc_tls_server_root_ca_cert: self.rpc_tls_query_server_root_ca_cert.clone(),
@@ -343,10 +359,90 @@ impl QueryConfig {
             .clone()
             .map(|s| mask_string(&s, 3));
         sanitized.openai_api_key = mask_string(&self.openai_api_key, 3);
+        sanitized.ldap.search_bind_password = mask_string(&self.ldap.search_bind_password, 3);
         sanitized
     }
 }
 
+/// Settings of the LDAP server that verifies users created with `IDENTIFIED WITH ldap`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct LdapConfig {
+    /// Empty means LDAP authentication is disabled.
+    pub url: String,
+    /// Simple bind with the DN built from this template, `{user}` is replaced with the login name.
+    pub bind_dn_template: String,
+    /// Used to search for the user entry when `bind_dn_template` is empty.
+    pub search_bind_dn: String,
+    pub search_bind_password: String,
+    pub search_base: String,
+    pub search_filter: String,
+    pub group_attribute: String,
+    /// LDAP group (full DN or CN) -> role granted for the session at login.
+    pub group_role_mapping: HashMap<String, String>,
+    pub timeout_secs: u64,
+}
+
+impl LdapConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.url.is_empty()
+    }
+}
+
+impl Default for LdapConfig {
+    fn default() -> Self {
+        Self {
+            url: "".to_string(),
+            bind_dn_template: "".to_string(),
+            search_bind_dn: "".to_string(),
+            search_bind_password: "".to_string(),
+            search_base: "".to_string(),
+            search_filter: "(uid={user})".to_string(),
+            group_attribute: "memberOf".to_string(),
+            group_role_mapping: HashMap::new(),
+            timeout_secs: 10,
+        }
+    }
+}
+
+impl Debug for LdapConfig {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("LdapConfig")
+            .field("url", &self.url)
+            .field("bind_dn_template", &self.bind_dn_template)
+            .field("search_bind_dn", &self.search_bind_dn)
+            .field(
+                "search_bind_password",
+                &mask_string(&self.search_bind_password, 3),
+            )
+            .field("search_base", &self.search_base)
+            .field("search_filter", &self.search_filter)
+            .field("group_attribute", &self.group_attribute)
+            .field("group_role_mapping", &self.group_role_mapping)
+            .field("timeout_secs", &self.timeout_secs)
+            .finish()
+    }
+}
+
+/// Settings of persisting the query log, profile log and login events into the `system_history` database.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueryHistoryConfig {
+    pub enabled: bool,
+    pub flush_interval_secs: u64,
+    pub batch_size: usize,
+    pub retention_days: u64,
+}
+
+impl Default for QueryHistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            flush_interval_secs: 5,
+            batch_size: 1000,
+            retention_days: 7,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq)]
 pub struct MetaConfig {
     /// The dir to store persisted meta state for a embedded meta store
@@ -574,6 +670,12 @@ pub struct CacheConfig {
     /// Max percentage of in memory inverted index filters cache relative to whole memory. By default it is 0 (disabled).
     pub inverted_index_filter_memory_ratio: u64,
 
+    /// Max number of cached vector index meta objects. Set it to 0 to disable it.
+    pub vector_index_meta_count: u64,
+
+    /// Max bytes of cached vector index lists used. Set it to 0 to disable it.
+    pub vector_index_filter_size: u64,
+
     pub data_cache_storage: CacheStorageTypeConfig,
 
     /// Max size of external cache population queue length
@@ -697,6 +799,8 @@ impl Default for CacheConfig {
             inverted_index_meta_count: 3000,
             inverted_index_filter_size: 2147483648,
             inverted_index_filter_memory_ratio: 0,
+            vector_index_meta_count: 3000,
+            vector_index_filter_size: 1073741824,
             table_prune_partitions_count: 256,
             data_cache_storage: Default::default(),
             table_data_cache_population_queue_size: 0,
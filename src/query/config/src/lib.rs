@@ -49,6 +49,8 @@ pub use inner::CatalogConfig;
 pub use inner::CatalogHiveConfig;
 pub use inner::DiskCacheKeyReloadPolicy;
 pub use inner::InnerConfig;
+pub use inner::LdapConfig;
+pub use inner::QueryHistoryConfig;
 pub use inner::SpillConfig;
 pub use inner::ThriftProtocol;
 pub use version::DATABEND_COMMIT_VERSION;
@@ -17,6 +17,7 @@ use crate::config::CosStorageConfig;
 use crate::config::FsStorageConfig;
 use crate::config::GcsStorageConfig;
 use crate::config::HdfsConfig;
+use crate::config::LogConfig;
 use crate::config::MetaConfig;
 use crate::config::ObsStorageConfig;
 use crate::config::OssStorageConfig;
@@ -46,7 +47,7 @@ impl Config {
             cmd: self.cmd,
             config_file: self.config_file,
             query: self.query.mask_display(),
-            log: self.log,
+            log: self.log.mask_display(),
             meta: self.meta.mask_display(),
             storage: self.storage.mask_display(),
             catalog: self.catalog,
@@ -71,6 +72,19 @@ impl QueryConfig {
         // Mask OpenAI API key
         masked_config.openai_api_key = mask_sensitive_field(&self.openai_api_key);
 
+        // Mask LDAP search bind password
+        masked_config.ldap_search_bind_password =
+            mask_sensitive_field(&self.ldap_search_bind_password);
+
+        masked_config
+    }
+}
+
+impl LogConfig {
+    fn mask_display(&self) -> Self {
+        let mut masked_config = self.clone();
+        masked_config.audit.log_audit_hmac_key =
+            mask_sensitive_field(&self.audit.log_audit_hmac_key);
         masked_config
     }
 }
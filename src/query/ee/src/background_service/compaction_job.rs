@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -25,6 +26,7 @@ use databend_common_base::base::tokio::sync::mpsc::Sender;
 use databend_common_base::base::tokio::sync::Mutex;
 use databend_common_base::base::tokio::time::Instant;
 use databend_common_base::base::uuid::Uuid;
+use databend_common_catalog::table_context::TableContext;
 use databend_common_config::InnerConfig;
 use databend_common_exception::Result;
 use databend_common_meta_api::BackgroundApi;
@@ -49,6 +51,7 @@ use databend_common_users::UserApiProvider;
 use databend_query::sessions::QueryContext;
 use databend_query::sessions::Session;
 use databend_query::table_functions::SuggestedBackgroundTasksSource;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use log::debug;
 use log::error;
 use log::info;
@@ -170,6 +173,13 @@ impl CompactionJob {
         // guarantee at least once for maunal job
         self.update_job_params(params).await?;
 
+        let ttl_tables = Self::do_get_ttl_tables(ctx.clone()).await?;
+        let ttl_table_ids = ttl_tables
+            .iter()
+            .map(|(_, _, _, tb_id)| *tb_id)
+            .collect::<HashSet<_>>();
+
+        let mut target_tables = vec![];
         for records in Self::do_get_target_tables_from_config(&self.conf, ctx.clone()).await? {
             debug!(records :? =(&records); "target_tables");
             let db_names = records
@@ -197,29 +207,47 @@ impl CompactionJob {
                 let db_id = db_ids.value(i);
                 let tb_name = tb_names.value(i).to_owned();
                 let tb_id = tb_ids.value(i);
-                match self
-                    .compact_table(
-                        session.clone(),
-                        db_name.clone(),
-                        tb_name.clone(),
-                        db_id,
-                        tb_id,
-                        manual.clone(),
-                    )
-                    .await
-                {
-                    Ok(_) => {
-                        info!(
-                            "compaction job success, db: {}, table: {}",
-                            db_name, tb_name
-                        );
-                    }
-                    Err(e) => {
-                        error!(
-                            "compaction job failed, db: {}, table: {}, err: {}",
-                            db_name, tb_name, e
-                        );
-                    }
+                target_tables.push((db_name, db_id, tb_name, tb_id));
+            }
+        }
+        // The tables with ttl are always visited, so that the expired data is removed
+        // even if the table does not need compaction.
+        if !self.conf.background.compaction.has_target_tables() {
+            let target_table_ids = target_tables
+                .iter()
+                .map(|(_, _, _, tb_id)| *tb_id)
+                .collect::<HashSet<_>>();
+            target_tables.extend(
+                ttl_tables
+                    .into_iter()
+                    .filter(|(_, _, _, tb_id)| !target_table_ids.contains(tb_id)),
+            );
+        }
+
+        for (db_name, db_id, tb_name, tb_id) in target_tables {
+            match self
+                .compact_table(
+                    session.clone(),
+                    db_name.clone(),
+                    tb_name.clone(),
+                    db_id,
+                    tb_id,
+                    manual.clone(),
+                    ttl_table_ids.contains(&tb_id),
+                )
+                .await
+            {
+                Ok(_) => {
+                    info!(
+                        "compaction job success, db: {}, table: {}",
+                        db_name, tb_name
+                    );
+                }
+                Err(e) => {
+                    error!(
+                        "compaction job failed, db: {}, table: {}, err: {}",
+                        db_name, tb_name, e
+                    );
                 }
             }
         }
@@ -282,6 +310,7 @@ impl CompactionJob {
         db_id: u64,
         tb_id: u64,
         manual: Option<ManualTriggerParams>,
+        has_ttl: bool,
     ) -> Result<()> {
         let (seg, blk, stats) = Self::do_check_table(
             session.clone(),
@@ -293,7 +322,7 @@ impl CompactionJob {
         )
         .await?;
         let (seg, blk, stats) = if !self.conf.background.compaction.has_target_tables() {
-            if !seg && !blk {
+            if !seg && !blk && !has_ttl {
                 info!(job = "compaction", background = true, database = database.clone(), table = table.clone(), should_compact_segment = seg, should_compact_blk = blk, table_stats :? =(&stats); "skip compact");
                 return Ok(());
             }
@@ -301,6 +330,8 @@ impl CompactionJob {
         } else {
             (true, true, stats)
         };
+        // Block compaction also removes the expired data of the table with ttl.
+        let blk = blk || has_ttl;
 
         if !seg && !blk {
             info!(job = "compaction", background = true, database = database.clone(), table = table.clone(), should_compact_segment = seg, should_compact_blk = blk, table_stats :? =(&stats); "skip compact");
@@ -342,7 +373,7 @@ impl CompactionJob {
         let start = Instant::now();
 
         match self
-            .do_compact_table(session.clone(), database.clone(), table.clone())
+            .do_compact_table(session.clone(), database.clone(), table.clone(), has_ttl)
             .await
         {
             Ok(_) => {
@@ -388,6 +419,7 @@ impl CompactionJob {
         session: Arc<Session>,
         database: String,
         table: String,
+        has_ttl: bool,
     ) -> Result<bool> {
         let (seg, blk, stats) = Self::do_check_table(
             session.clone(),
@@ -398,6 +430,7 @@ impl CompactionJob {
             PER_BLOCK_SIZE,
         )
         .await?;
+        let blk = blk || has_ttl;
         if !seg && !blk {
             return Ok(false);
         }
@@ -553,6 +586,50 @@ impl CompactionJob {
         Ok(res)
     }
 
+    /// Returns the fuse tables with ttl, as `(database, database_id, table, table_id)`.
+    ///
+    /// The tables are looked up in the ttl table registry, and resolved by id so that
+    /// renamed tables are still found. The registered tables that are gone for good, or
+    /// whose ttl is unset, are removed from the registry.
+    pub async fn do_get_ttl_tables(
+        ctx: Arc<QueryContext>,
+    ) -> Result<Vec<(String, u64, String, u64)>> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_default_catalog()?;
+        let registry = UserApiProvider::instance().ttl_table_api(&tenant)?;
+        let mut res = vec![];
+        for ttl_table in registry.list().await? {
+            let (database_id, table_id) = (ttl_table.database_id, ttl_table.table_id);
+            // The name of a dropped table is kept until the table is vacuumed.
+            let Some(table_name) = catalog.get_table_name_by_id(table_id).await? else {
+                registry.unregister(table_id).await?;
+                continue;
+            };
+            // The table or its database is dropped, or another table took its name.
+            let Ok(db_name) = catalog.get_db_name_by_id(database_id).await else {
+                continue;
+            };
+            let Ok(table) = catalog.get_table(&tenant, &db_name, &table_name).await else {
+                continue;
+            };
+            if table.get_id() != table_id {
+                continue;
+            }
+            if !table.options().contains_key(OPT_KEY_TTL) {
+                registry.unregister(table_id).await?;
+                continue;
+            }
+            res.push((db_name, database_id, table_name, table_id));
+        }
+        info!(
+            job = "compaction",
+            background = true,
+            tables = res.len();
+            "get all tables with ttl"
+        );
+        Ok(res)
+    }
+
     pub async fn do_check_table(
         session: Arc<Session>,
         database: String,
@@ -13,4 +13,6 @@
 // limitations under the License.
 
 mod data_mask_handler;
+mod row_access_policy_handler;
 pub use data_mask_handler::RealDatamaskHandler;
+pub use row_access_policy_handler::RealRowAccessPolicyHandler;
@@ -35,6 +35,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableIndexReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_sql::plans::RefreshTableIndexPlan;
 use databend_common_sql::BloomIndexColumns;
@@ -69,9 +70,18 @@ async fn apply_block_pruning(
     let segment_locs = table_snapshot.segments.clone();
     let segment_locs = create_segment_location_vector(segment_locs, None);
 
-    FusePruner::create(&ctx, dal, schema, push_down, bloom_index_cols, None)?
-        .read_pruning(segment_locs)
-        .await
+    FusePruner::create(
+        &ctx,
+        dal,
+        schema,
+        push_down,
+        bloom_index_cols,
+        BloomIndexColumns::None,
+        None,
+        None,
+    )?
+    .read_pruning(segment_locs)
+    .await
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -113,6 +123,7 @@ async fn test_block_pruner() -> Result<()> {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        partition_key: None,
         inverted_indexes: None,
     };
 
@@ -509,6 +520,7 @@ async fn test_block_pruner() -> Result<()> {
         column_ids: vec![1, 2, 3],
         sync_creation: false,
         options: index_options.clone(),
+        index_type: TableIndexType::Inverted,
     };
 
     let res = handler.do_create_table_index(catalog.clone(), req).await;
@@ -521,6 +533,7 @@ async fn test_block_pruner() -> Result<()> {
     ]);
 
     let refresh_index_plan = RefreshTableIndexPlan {
+        index_type: TableIndexType::Inverted,
         catalog: fixture.default_catalog_name(),
         database: fixture.default_db_name(),
         table: test_tbl_name.to_string(),
@@ -13,7 +13,11 @@
 // limitations under the License.
 
 pub mod data_mask_handler;
+pub mod row_access_policy_handler;
 
 pub use data_mask_handler::get_datamask_handler;
 pub use data_mask_handler::DatamaskHandler;
 pub use data_mask_handler::DatamaskHandlerWrapper;
+pub use row_access_policy_handler::get_row_access_policy_handler;
+pub use row_access_policy_handler::RowAccessPolicyHandler;
+pub use row_access_policy_handler::RowAccessPolicyHandlerWrapper;
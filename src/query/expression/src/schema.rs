@@ -152,12 +152,32 @@ impl ComputedExpr {
     }
 }
 
+/// Identity column backed by a hidden sequence, the n-th value allocated
+/// from the sequence is `start + (n - 1) * step`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AutoIncrementExpr {
+    pub sequence_name: String,
+    pub start: i64,
+    pub step: i64,
+}
+
+impl AutoIncrementExpr {
+    /// Returns the column value of the given sequence value, which starts from 1.
+    #[inline]
+    pub fn value_of(&self, sequence_value: u64) -> i64 {
+        let offset = (sequence_value as i64).wrapping_sub(1);
+        self.start.wrapping_add(offset.wrapping_mul(self.step))
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct DataField {
     name: String,
     default_expr: Option<String>,
     data_type: DataType,
     computed_expr: Option<ComputedExpr>,
+    #[serde(default)]
+    auto_increment_expr: Option<AutoIncrementExpr>,
 }
 
 fn uninit_column_id() -> ColumnId {
@@ -181,6 +201,8 @@ pub struct TableField {
     #[serde(default = "uninit_column_id")]
     pub column_id: ColumnId,
     pub computed_expr: Option<ComputedExpr>,
+    #[serde(default)]
+    pub auto_increment_expr: Option<AutoIncrementExpr>,
 }
 
 /// DataType with more information that is only available for table field, e.g, the
@@ -983,6 +1005,7 @@ impl DataField {
             default_expr: None,
             data_type,
             computed_expr: None,
+            auto_increment_expr: None,
         }
     }
 
@@ -992,6 +1015,7 @@ impl DataField {
             default_expr: None,
             data_type: data_type.wrap_nullable(),
             computed_expr: None,
+            auto_increment_expr: None,
         }
     }
 
@@ -1006,6 +1030,14 @@ impl DataField {
         self
     }
 
+    pub fn with_auto_increment_expr(
+        mut self,
+        auto_increment_expr: Option<AutoIncrementExpr>,
+    ) -> Self {
+        self.auto_increment_expr = auto_increment_expr;
+        self
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
@@ -1022,6 +1054,10 @@ impl DataField {
         self.computed_expr.as_ref()
     }
 
+    pub fn auto_increment_expr(&self) -> Option<&AutoIncrementExpr> {
+        self.auto_increment_expr.as_ref()
+    }
+
     #[inline]
     pub fn is_nullable(&self) -> bool {
         self.data_type.is_nullable()
@@ -1041,6 +1077,7 @@ impl TableField {
             data_type,
             column_id: 0,
             computed_expr: None,
+            auto_increment_expr: None,
         }
     }
 
@@ -1051,6 +1088,7 @@ impl TableField {
             data_type,
             column_id,
             computed_expr: None,
+            auto_increment_expr: None,
         }
     }
 
@@ -1097,6 +1135,7 @@ impl TableField {
             data_type: self.data_type.clone(),
             column_id,
             computed_expr: self.computed_expr.clone(),
+            auto_increment_expr: self.auto_increment_expr.clone(),
         }
     }
 
@@ -1142,6 +1181,14 @@ impl TableField {
         self
     }
 
+    pub fn with_auto_increment_expr(
+        mut self,
+        auto_increment_expr: Option<AutoIncrementExpr>,
+    ) -> Self {
+        self.auto_increment_expr = auto_increment_expr;
+        self
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
@@ -1158,6 +1205,10 @@ impl TableField {
         self.computed_expr.as_ref()
     }
 
+    pub fn auto_increment_expr(&self) -> Option<&AutoIncrementExpr> {
+        self.auto_increment_expr.as_ref()
+    }
+
     #[inline]
     pub fn is_nullable(&self) -> bool {
         self.data_type.is_nullable()
@@ -1383,6 +1434,7 @@ impl From<&TableField> for DataField {
         DataField::new(&name, DataType::from(&data_type))
             .with_default_expr(f.default_expr.clone())
             .with_computed_expr(f.computed_expr.clone())
+            .with_auto_increment_expr(f.auto_increment_expr.clone())
     }
 }
 
@@ -1406,6 +1458,7 @@ impl From<&DataField> for TableField {
         TableField::new(&name, ty)
             .with_default_expr(f.default_expr.clone())
             .with_computed_expr(f.computed_expr.clone())
+            .with_auto_increment_expr(f.auto_increment_expr.clone())
     }
 }
 
@@ -1469,7 +1522,8 @@ pub fn infer_table_schema(data_schema: &DataSchema) -> Result<TableSchemaRef> {
         fields.push(
             TableField::new(field.name(), field_type)
                 .with_default_expr(field.default_expr.clone())
-                .with_computed_expr(field.computed_expr.clone()),
+                .with_computed_expr(field.computed_expr.clone())
+                .with_auto_increment_expr(field.auto_increment_expr.clone()),
         );
     }
     Ok(TableSchemaRefExt::create(fields))
@@ -0,0 +1,183 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::InputColumns;
+use databend_common_expression::Scalar;
+
+use super::AggregateFunctionFactory;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionCreator;
+use crate::aggregates::aggregate_function_factory::CombinatorDescription;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// `<func>_merge(state, arguments...)` merges the states produced by `<func>_state(arguments...)`
+/// and returns the result of `<func>`.
+///
+/// The state is stored as binary, so the trailing arguments are only used to tell the types
+/// that the states are built for. Their values are never read.
+#[derive(Clone)]
+pub struct AggregateMergeCombinator {
+    name: String,
+    nested: AggregateFunctionRef,
+}
+
+impl AggregateMergeCombinator {
+    pub fn try_create(
+        nested_name: &str,
+        params: Vec<Scalar>,
+        arguments: Vec<DataType>,
+        _nested_creator: &AggregateFunctionCreator,
+    ) -> Result<AggregateFunctionRef> {
+        let name = format!("MergeCombinator({nested_name})");
+        if arguments.is_empty() || arguments[0].remove_nullable() != DataType::Binary {
+            return Err(ErrorCode::BadArguments(format!(
+                "The first argument of {name} must be the binary state of {nested_name}"
+            )));
+        }
+
+        // Keep in sync with `AggregateStateCombinator`, the states must be built by the same function.
+        let nested = AggregateFunctionFactory::instance().get(
+            nested_name,
+            params,
+            arguments[1..].to_vec(),
+        )?;
+
+        Ok(Arc::new(AggregateMergeCombinator { name, nested }))
+    }
+
+    pub fn combinator_desc() -> CombinatorDescription {
+        CombinatorDescription::creator(Box::new(Self::try_create))
+    }
+
+    fn merge_row(&self, place: StateAddr, column: &Column, row: usize) -> Result<()> {
+        let (column, validity) = match column {
+            Column::Nullable(column) => (&column.column, Some(&column.validity)),
+            column => (column, None),
+        };
+        if validity.is_some_and(|validity| !validity.get_bit(row)) {
+            return Ok(());
+        }
+        let states = column.as_binary().ok_or_else(|| {
+            ErrorCode::BadDataValueType(format!(
+                "{} expects binary aggregate states, but got {:?}",
+                self.name,
+                column.data_type()
+            ))
+        })?;
+        let mut data = states.index(row).ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "{} reads row {} of {} aggregate states",
+                self.name,
+                row,
+                states.len()
+            ))
+        })?;
+        // The state of a group with only NULL values is stored as an empty binary.
+        if data.is_empty() {
+            return Ok(());
+        }
+        self.nested.merge(place, &mut data)
+    }
+}
+
+impl AggregateFunction for AggregateMergeCombinator {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        self.nested.return_type()
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        self.nested.init_state(place);
+    }
+
+    fn state_layout(&self) -> Layout {
+        self.nested.state_layout()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: InputColumns,
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        for row in 0..input_rows {
+            if validity.map_or(true, |validity| validity.get_bit(row)) {
+                self.merge_row(place, &columns[0], row)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: InputColumns,
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            self.merge_row(place.next(offset), &columns[0], row)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: InputColumns, row: usize) -> Result<()> {
+        self.merge_row(place, &columns[0], row)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        self.nested.serialize(place, writer)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        self.nested.merge(place, reader)
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        self.nested.merge_states(place, rhs)
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        self.nested.merge_result(place, builder)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        self.nested.need_manual_drop_state()
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        self.nested.drop_state(place);
+    }
+}
+
+impl fmt::Display for AggregateMergeCombinator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
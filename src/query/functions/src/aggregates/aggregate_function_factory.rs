@@ -27,6 +27,7 @@ use crate::aggregates::AggregateFunctionRef;
 use crate::aggregates::Aggregators;
 
 const STATE_SUFFIX: &str = "_state";
+const MERGE_SUFFIX: &str = "_merge";
 
 pub type AggregateFunctionCreator =
     Box<dyn Fn(&str, Vec<Scalar>, Vec<DataType>) -> Result<AggregateFunctionRef> + Sync + Send>;
@@ -185,6 +186,12 @@ impl AggregateFunctionFactory {
             return Ok(agg);
         }
 
+        // The arguments of merge functions only carry the types of the states,
+        // the nested function handles the NULL values itself.
+        if name.to_lowercase().ends_with(MERGE_SUFFIX) {
+            return self.get_impl(name, params, arguments, &mut features);
+        }
+
         if !arguments.is_empty() && arguments.iter().any(|f| f.is_nullable_or_null()) {
             let (new_params, new_arguments) = match name.to_lowercase().strip_suffix(STATE_SUFFIX) {
                 Some(_) => (params.clone(), arguments.clone()),
@@ -38,6 +38,7 @@ use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use super::AggregateCountFunction;
 use super::AggregateFunctionFactory;
 use super::AggregateIfCombinator;
+use super::AggregateMergeCombinator;
 use crate::aggregates::aggregate_array_agg_function_desc;
 use crate::aggregates::aggregate_array_moving_avg_function_desc;
 use crate::aggregates::aggregate_array_moving_sum_function_desc;
@@ -150,5 +151,6 @@ impl Aggregators {
         factory.register_combinator("_if", AggregateIfCombinator::combinator_desc());
         factory.register_combinator("_distinct", aggregate_combinator_distinct_desc());
         factory.register_combinator("_state", AggregateStateCombinator::combinator_desc());
+        factory.register_combinator("_merge", AggregateMergeCombinator::combinator_desc());
     }
 }
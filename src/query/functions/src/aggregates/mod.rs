@@ -25,6 +25,7 @@ mod aggregate_avg;
 mod aggregate_bitmap;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
+mod aggregate_combinator_merge;
 mod aggregate_combinator_state;
 mod aggregate_covariance;
 mod aggregate_distinct_state;
@@ -56,6 +57,7 @@ pub use aggregate_array_agg::*;
 pub use aggregate_array_moving::*;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
+pub use aggregate_combinator_merge::AggregateMergeCombinator;
 pub use aggregate_count::AggregateCountFunction;
 pub use aggregate_covariance::AggregateCovarianceFunction;
 pub use aggregate_function::*;
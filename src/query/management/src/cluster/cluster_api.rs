@@ -15,6 +15,25 @@
 use databend_common_exception::Result;
 use databend_common_meta_types::MatchSeq;
 use databend_common_meta_types::NodeInfo;
+use futures::stream::BoxStream;
+
+/// An event of the tenant's cluster nodes, returned by [`ClusterApi::watch_nodes`].
+#[derive(Clone, Debug)]
+pub enum NodeEvent {
+    /// A node is present when the watch starts, or it is added or updated.
+    Upsert {
+        seq: u64,
+        expire_at_ms: Option<u64>,
+        node: NodeInfo,
+    },
+
+    /// A node is removed.
+    Delete { node_id: String },
+
+    /// The present nodes are all sent, or the missed changes are all replayed.
+    /// The following events are live changes.
+    Ready,
+}
 
 #[async_trait::async_trait]
 pub trait ClusterApi: Sync + Send {
@@ -31,4 +50,13 @@ pub trait ClusterApi: Sync + Send {
     async fn heartbeat(&self, node: &NodeInfo, seq: MatchSeq) -> Result<u64>;
 
     async fn get_local_addr(&self) -> Result<Option<String>>;
+
+    // Watch the tenant's cluster nodes.
+    // If `since_seq` is None, the present nodes are sent first,
+    // otherwise the changes after `since_seq` are replayed first.
+    // Returns None if the meta store does not support watching.
+    async fn watch_nodes(
+        &self,
+        since_seq: Option<u64>,
+    ) -> Result<Option<BoxStream<'static, Result<NodeEvent>>>>;
 }
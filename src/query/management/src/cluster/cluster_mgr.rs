@@ -22,13 +22,19 @@ use databend_common_meta_kvapi::kvapi::KVApi;
 use databend_common_meta_kvapi::kvapi::UpsertKVReply;
 use databend_common_meta_kvapi::kvapi::UpsertKVReq;
 use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::protobuf::watch_request::FilterType;
+use databend_common_meta_types::protobuf::WatchRequest;
+use databend_common_meta_types::protobuf::WatchResponse;
 use databend_common_meta_types::seq_value::SeqV;
 use databend_common_meta_types::MatchSeq;
 use databend_common_meta_types::MetaSpec;
 use databend_common_meta_types::NodeInfo;
 use databend_common_meta_types::Operation;
+use futures::stream::BoxStream;
+use futures::StreamExt;
 
 use crate::cluster::ClusterApi;
+use crate::cluster::NodeEvent;
 
 pub static CLUSTER_API_KEY_PREFIX: &str = "__fd_clusters_v4";
 
@@ -66,6 +72,33 @@ impl ClusterMgr {
     fn new_lift_time(&self) -> MetaSpec {
         MetaSpec::new_ttl(self.lift_time)
     }
+
+    fn node_id_of(cluster_prefix: &str, node_key: &str) -> Result<String> {
+        Ok(unescape_for_key(&node_key[cluster_prefix.len() + 1..])?)
+    }
+
+    fn to_node_event(cluster_prefix: &str, resp: WatchResponse) -> Result<NodeEvent> {
+        let Some(event) = resp.event else {
+            return Ok(NodeEvent::Ready);
+        };
+
+        let node_id = Self::node_id_of(cluster_prefix, &event.key)?;
+
+        match event.current {
+            None => Ok(NodeEvent::Delete { node_id }),
+            Some(current) => {
+                let seq_v = SeqV::from(current);
+                let mut node = serde_json::from_slice::<NodeInfo>(&seq_v.data)?;
+                node.id = node_id;
+
+                Ok(NodeEvent::Upsert {
+                    seq: seq_v.seq,
+                    expire_at_ms: seq_v.meta.and_then(|m| m.get_expire_at_ms()),
+                    node,
+                })
+            }
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -101,7 +134,7 @@ impl ClusterApi for ClusterMgr {
         for (node_key, value) in values {
             let mut node_info = serde_json::from_slice::<NodeInfo>(&value.data)?;
 
-            node_info.id = unescape_for_key(&node_key[self.cluster_prefix.len() + 1..])?;
+            node_info.id = Self::node_id_of(&self.cluster_prefix, &node_key)?;
             nodes_info.push(node_info);
         }
 
@@ -154,4 +187,30 @@ impl ClusterApi for ClusterMgr {
     async fn get_local_addr(&self) -> Result<Option<String>> {
         Ok(self.metastore.get_local_addr().await?)
     }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    async fn watch_nodes(
+        &self,
+        since_seq: Option<u64>,
+    ) -> Result<Option<BoxStream<'static, Result<NodeEvent>>>> {
+        if self.metastore.is_local() {
+            return Ok(None);
+        }
+
+        // Watch the keys in `[<prefix>/, <prefix>0)`, '0' is the next char of '/'.
+        let req = WatchRequest {
+            key: format!("{}/", self.cluster_prefix),
+            key_end: Some(format!("{}0", self.cluster_prefix)),
+            filter_type: FilterType::All.into(),
+            initial_flush: since_seq.is_none(),
+            since_seq,
+        };
+
+        let cluster_prefix = self.cluster_prefix.clone();
+        let strm = self.metastore.watch(req).await?;
+        let strm = strm.map(move |resp| Self::to_node_event(&cluster_prefix, resp?));
+
+        Ok(Some(strm.boxed()))
+    }
 }
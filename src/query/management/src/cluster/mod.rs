@@ -16,4 +16,5 @@ mod cluster_api;
 mod cluster_mgr;
 
 pub use cluster_api::ClusterApi;
+pub use cluster_api::NodeEvent;
 pub use cluster_mgr::ClusterMgr;
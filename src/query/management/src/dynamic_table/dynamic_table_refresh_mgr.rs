@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub static DYNAMIC_TABLE_REFRESH_API_KEY_PREFIX: &str = "__fd_dynamic_table_refreshes";
+
+/// How long the records of dynamic table refreshes are kept in meta.
+pub const DYNAMIC_TABLE_REFRESH_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// The record of a refresh of a dynamic table.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DynamicTableRefresh {
+    /// The id of the query that refreshes the table.
+    pub refresh_id: String,
+    pub table_id: u64,
+    pub database: String,
+    pub table: String,
+    /// `NO_DATA`, `INCREMENTAL` or `FULL`.
+    pub refresh_action: String,
+    /// `CREATION`, `MANUAL` or `SCHEDULED`.
+    pub refresh_trigger: String,
+    /// `SUCCEEDED` or `FAILED`.
+    pub state: String,
+    /// Why the refresh action is chosen, or the error if the refresh failed.
+    pub message: String,
+    /// Microseconds since the unix epoch.
+    pub started_at: i64,
+    /// Microseconds since the unix epoch.
+    pub completed_at: i64,
+}
+
+/// Stores the history of dynamic table refreshes of a tenant in meta:
+/// - `__fd_dynamic_table_refreshes/<tenant>/<table_id>/<refresh_id>` -> [`DynamicTableRefresh`] in JSON,
+///   expires after [`DYNAMIC_TABLE_REFRESH_RETENTION`]
+pub struct DynamicTableRefreshMgr {
+    metastore: MetaStore,
+    refresh_prefix: String,
+}
+
+impl DynamicTableRefreshMgr {
+    pub fn create(metastore: MetaStore, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while dynamic table refresh mgr create)",
+            ));
+        }
+
+        let tenant = escape_for_key(tenant)?;
+        Ok(DynamicTableRefreshMgr {
+            metastore,
+            refresh_prefix: format!("{}/{}", DYNAMIC_TABLE_REFRESH_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn refresh_key(&self, table_id: u64, refresh_id: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{:020}/{}",
+            self.refresh_prefix,
+            table_id,
+            escape_for_key(refresh_id)?
+        ))
+    }
+
+    /// Add or update the record of a refresh.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn upsert_refresh(&self, refresh: &DynamicTableRefresh) -> Result<()> {
+        let key = self.refresh_key(refresh.table_id, &refresh.refresh_id)?;
+
+        let value = Operation::Update(serde_json::to_vec(refresh)?);
+        let meta = Some(MetaSpec::new_ttl(DYNAMIC_TABLE_REFRESH_RETENTION));
+        self.metastore
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::GE(0), value, meta))
+            .await?;
+
+        Ok(())
+    }
+
+    /// List the refreshes of a dynamic table, or of all dynamic tables if `table_id` is None.
+    ///
+    /// The records are ordered by the time they started.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list_refreshes(&self, table_id: Option<u64>) -> Result<Vec<DynamicTableRefresh>> {
+        let prefix = match table_id {
+            None => format!("{}/", self.refresh_prefix),
+            Some(table_id) => format!("{}/{:020}/", self.refresh_prefix, table_id),
+        };
+        let values = self.metastore.prefix_list_kv(&prefix).await?;
+
+        let mut refreshes = Vec::with_capacity(values.len());
+        for (_key, seq_v) in values {
+            refreshes.push(serde_json::from_slice::<DynamicTableRefresh>(&seq_v.data)?);
+        }
+        refreshes.sort_by_key(|refresh| refresh.started_at);
+
+        Ok(refreshes)
+    }
+}
@@ -16,14 +16,19 @@
 
 mod cluster;
 mod connection;
+mod dynamic_table;
 mod file_format;
 mod network_policy;
+mod notification;
 mod password_policy;
+mod query_hint;
 mod quota;
 mod role;
 mod serde;
 mod setting;
 mod stage;
+mod task;
+mod ttl;
 pub mod udf;
 mod user;
 
@@ -34,11 +39,18 @@ mod procedure;
 pub use client_session::ClientSessionMgr;
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
+pub use cluster::NodeEvent;
 pub use connection::ConnectionMgr;
+pub use dynamic_table::DynamicTableRefresh;
+pub use dynamic_table::DynamicTableRefreshMgr;
+pub use dynamic_table::DYNAMIC_TABLE_REFRESH_RETENTION;
 pub use file_format::FileFormatMgr;
 pub use network_policy::NetworkPolicyMgr;
+pub use notification::NotificationMgr;
+pub use notification::NOTIFICATION_HISTORY_RETENTION;
 pub use password_policy::PasswordPolicyMgr;
 pub use procedure::ProcedureMgr;
+pub use query_hint::QueryHintMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
@@ -50,5 +62,10 @@ pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use task::TaskMgr;
+pub use task::TaskState;
+pub use task::TASK_RUN_RETENTION;
+pub use ttl::TtlTable;
+pub use ttl::TtlTableMgr;
 pub use user::UserApi;
 pub use user::UserMgr;
@@ -0,0 +1,247 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_cloud_control::pb;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::fetch_id;
+use databend_common_meta_app::id_generator::IdGenerator;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use prost::Message;
+
+pub static NOTIFICATION_API_KEY_PREFIX: &str = "__fd_notifications";
+pub static NOTIFICATION_HISTORY_API_KEY_PREFIX: &str = "__fd_notification_history";
+
+/// How long the records of sent notifications are kept in meta.
+pub const NOTIFICATION_HISTORY_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Stores the notification integrations of a tenant in meta, for the built-in notification service.
+///
+/// The records are the same protobuf messages that cloud control serves:
+/// - `__fd_notifications/<tenant>/<name>` -> `pb::Notification`
+/// - `__fd_notification_history/<tenant>/<name>/<created_time>/<message_id>` -> `pb::NotificationHistory`,
+///   expires after [`NOTIFICATION_HISTORY_RETENTION`]
+pub struct NotificationMgr {
+    metastore: MetaStore,
+    notification_prefix: String,
+    history_prefix: String,
+}
+
+impl NotificationMgr {
+    pub fn create(metastore: MetaStore, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while notification mgr create)",
+            ));
+        }
+
+        let tenant = escape_for_key(tenant)?;
+        Ok(NotificationMgr {
+            metastore,
+            notification_prefix: format!("{}/{}", NOTIFICATION_API_KEY_PREFIX, tenant),
+            history_prefix: format!("{}/{}", NOTIFICATION_HISTORY_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn notification_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.notification_prefix,
+            escape_for_key(name)?
+        ))
+    }
+
+    fn history_key(&self, history: &pb::NotificationHistory, message_id: &str) -> Result<String> {
+        let created_time = history.created_time.clone().unwrap_or_default();
+        let created_micros = created_time.seconds * 1_000_000 + (created_time.nanos / 1000) as i64;
+        Ok(format!(
+            "{}/{}/{:020}/{}",
+            self.history_prefix,
+            escape_for_key(&history.name)?,
+            created_micros.max(0),
+            escape_for_key(message_id)?
+        ))
+    }
+
+    /// Create a notification integration and return its id.
+    ///
+    /// The id is assigned by meta, the `notification_id` in the given one is ignored.
+    /// If it exists, the id of the existing one is returned if `if_not_exists` is true.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn create_notification(
+        &self,
+        mut notification: pb::Notification,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let key = self.notification_key(&notification.name)?;
+
+        if let Some(seq_v) = self.metastore.get_kv(&key).await? {
+            return match if_not_exists {
+                true => Ok(pb::Notification::decode(seq_v.data.as_slice())?.notification_id),
+                false => Err(ErrorCode::NotificationAlreadyExists(format!(
+                    "Notification '{}' already exists",
+                    notification.name
+                ))),
+            };
+        }
+
+        notification.notification_id =
+            fetch_id(&self.metastore, IdGenerator::notification_id()).await?;
+
+        let value = Operation::Update(notification.encode_to_vec());
+        let reply = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::Exact(0), value, None))
+            .await?;
+
+        if !reply.is_changed() {
+            return Err(ErrorCode::NotificationAlreadyExists(format!(
+                "Notification '{}' already exists",
+                notification.name
+            )));
+        }
+
+        Ok(notification.notification_id)
+    }
+
+    /// Get a notification integration by name, along with the seq of its record.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn get_notification(&self, name: &str) -> Result<Option<(u64, pb::Notification)>> {
+        let key = self.notification_key(name)?;
+
+        match self.metastore.get_kv(&key).await? {
+            None => Ok(None),
+            Some(seq_v) => Ok(Some((
+                seq_v.seq,
+                pb::Notification::decode(seq_v.data.as_slice())?,
+            ))),
+        }
+    }
+
+    /// Update a notification integration if its record still matches `seq`.
+    ///
+    /// Returns false if it has been changed or removed since it was read.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn update_notification(
+        &self,
+        notification: &pb::Notification,
+        seq: MatchSeq,
+    ) -> Result<bool> {
+        let key = self.notification_key(&notification.name)?;
+
+        let value = Operation::Update(notification.encode_to_vec());
+        let reply = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(&key, seq, value, None))
+            .await?;
+
+        Ok(reply.is_changed())
+    }
+
+    /// Drop a notification integration by name, return the dropped one or None if nothing is dropped.
+    ///
+    /// The history of the notification is kept until it expires.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn drop_notification(&self, name: &str) -> Result<Option<pb::Notification>> {
+        let key = self.notification_key(name)?;
+
+        let reply = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(1),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+
+        match reply.prev {
+            Some(seq_v) if reply.result.is_none() => {
+                Ok(Some(pb::Notification::decode(seq_v.data.as_slice())?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// List all notification integrations of the tenant.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list_notifications(&self) -> Result<Vec<pb::Notification>> {
+        let prefix = format!("{}/", self.notification_prefix);
+        let values = self.metastore.prefix_list_kv(&prefix).await?;
+
+        let mut notifications = Vec::with_capacity(values.len());
+        for (_key, seq_v) in values {
+            notifications.push(pb::Notification::decode(seq_v.data.as_slice())?);
+        }
+
+        Ok(notifications)
+    }
+
+    /// Add or update the record of a sent notification.
+    ///
+    /// `message_id` identifies the message among the ones sent at the same time.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn upsert_notification_history(
+        &self,
+        history: &pb::NotificationHistory,
+        message_id: &str,
+    ) -> Result<()> {
+        let key = self.history_key(history, message_id)?;
+
+        let value = Operation::Update(history.encode_to_vec());
+        let meta = Some(MetaSpec::new_ttl(NOTIFICATION_HISTORY_RETENTION));
+        self.metastore
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::GE(0), value, meta))
+            .await?;
+
+        Ok(())
+    }
+
+    /// List the history of a notification integration, or of all of them if `name` is None.
+    ///
+    /// The records of each integration are ordered by their created time.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list_notification_history(
+        &self,
+        name: Option<&str>,
+    ) -> Result<Vec<pb::NotificationHistory>> {
+        let prefix = match name {
+            None => format!("{}/", self.history_prefix),
+            Some(name) => format!("{}/{}/", self.history_prefix, escape_for_key(name)?),
+        };
+        let values = self.metastore.prefix_list_kv(&prefix).await?;
+
+        let mut histories = Vec::with_capacity(values.len());
+        for (_key, seq_v) in values {
+            histories.push(pb::NotificationHistory::decode(seq_v.data.as_slice())?);
+        }
+
+        Ok(histories)
+    }
+}
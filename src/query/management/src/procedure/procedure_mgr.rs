@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_meta_api::kv_app_error::KVAppError;
+use databend_common_meta_api::kv_pb_api::KVPbApi;
 use databend_common_meta_api::name_id_value_api::NameIdValueApi;
 use databend_common_meta_api::serialize_struct;
 use databend_common_meta_app::app_error::AppError;
@@ -30,6 +31,7 @@ use databend_common_meta_app::principal::ProcedureIdToNameIdent;
 use databend_common_meta_app::principal::ProcedureIdentity;
 use databend_common_meta_app::principal::ProcedureMeta;
 use databend_common_meta_app::principal::ProcedureNameIdent;
+use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_app::KeyWithTenant;
 use databend_common_meta_kvapi::kvapi;
 use databend_common_meta_kvapi::kvapi::DirName;
@@ -110,6 +112,21 @@ impl ProcedureMgr {
             procedure_meta: seq_meta.data,
         }))
     }
+
+    /// Get the name of a PROCEDURE by id, return None if it does not exist.
+    #[fastrace::trace]
+    pub async fn get_procedure_name_by_id(
+        &self,
+        tenant: &Tenant,
+        procedure_id: u64,
+    ) -> Result<Option<ProcedureIdentity>, KVAppError> {
+        debug!(procedure_id :? =(procedure_id); "SchemaApi: {}", func_name!());
+
+        let ident = ProcedureIdToNameIdent::new_generic(tenant, ProcedureId::new(procedure_id));
+        let seq_name = self.kv_api.get_pb(&ident).await?;
+        Ok(seq_name.map(|seq_name| seq_name.data))
+    }
+
     #[fastrace::trace]
     pub async fn list_procedures(
         &self,
@@ -516,6 +516,10 @@ fn convert_to_grant_obj(owner_obj: &OwnershipObject) -> GrantObject {
         } => GrantObject::TableById(catalog_name.to_string(), *db_id, *table_id),
         OwnershipObject::Stage { name } => GrantObject::Stage(name.to_string()),
         OwnershipObject::UDF { name } => GrantObject::UDF(name.to_string()),
+        OwnershipObject::Sequence { name } => GrantObject::Sequence(name.to_string()),
+        OwnershipObject::Procedure { procedure_id } => GrantObject::Procedure(*procedure_id),
+        OwnershipObject::Connection { name } => GrantObject::Connection(name.to_string()),
+        OwnershipObject::FileFormat { name } => GrantObject::FileFormat(name.to_string()),
     }
 }
 
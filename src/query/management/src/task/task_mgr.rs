@@ -0,0 +1,315 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_cloud_control::pb;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::fetch_id;
+use databend_common_meta_app::id_generator::IdGenerator;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use prost::Message;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub static TASK_API_KEY_PREFIX: &str = "__fd_tasks";
+pub static TASK_RUN_API_KEY_PREFIX: &str = "__fd_task_runs";
+pub static TASK_SCHEDULER_API_KEY_PREFIX: &str = "__fd_task_scheduler";
+pub static TASK_STATE_API_KEY_PREFIX: &str = "__fd_task_states";
+
+/// How long the records of task runs are kept in meta.
+pub const TASK_RUN_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Stores the tasks of a tenant in meta, for the built-in task scheduler.
+///
+/// The records are the same protobuf messages that cloud control serves:
+/// - `__fd_tasks/<tenant>/<task_name>` -> `pb::Task`
+/// - `__fd_task_runs/<tenant>/<task_id>/<run_id>` -> `pb::TaskRun`, expires after [`TASK_RUN_RETENTION`]
+/// - `__fd_task_scheduler/<tenant>` -> id of the node that runs the tasks, expires unless renewed
+/// - `__fd_task_states/<tenant>/<task_name>` -> [`TaskState`] as JSON, removed with the task
+pub struct TaskMgr {
+    metastore: MetaStore,
+    task_prefix: String,
+    task_run_prefix: String,
+    task_state_prefix: String,
+    scheduler_key: String,
+}
+
+/// The state of a task across the runs of its DAG, kept by the built-in task scheduler,
+/// so that it survives a restart or a change of the scheduler node.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskState {
+    /// Number of consecutive failed runs.
+    pub failures: i32,
+    /// The DAG run that the task succeeded in most recently.
+    pub succeeded_run: Option<String>,
+    /// The DAG run that the task is triggered for most recently, if it is a dependent task.
+    pub triggered_run: Option<String>,
+}
+
+impl TaskMgr {
+    pub fn create(metastore: MetaStore, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while task mgr create)",
+            ));
+        }
+
+        let tenant = escape_for_key(tenant)?;
+        Ok(TaskMgr {
+            metastore,
+            task_prefix: format!("{}/{}", TASK_API_KEY_PREFIX, tenant),
+            task_run_prefix: format!("{}/{}", TASK_RUN_API_KEY_PREFIX, tenant),
+            task_state_prefix: format!("{}/{}", TASK_STATE_API_KEY_PREFIX, tenant),
+            scheduler_key: format!("{}/{}", TASK_SCHEDULER_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn task_key(&self, task_name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.task_prefix,
+            escape_for_key(task_name)?
+        ))
+    }
+
+    fn task_state_key(&self, task_name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.task_state_prefix,
+            escape_for_key(task_name)?
+        ))
+    }
+
+    fn task_run_key(&self, task_id: u64, run_id: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{:020}/{}",
+            self.task_run_prefix,
+            task_id,
+            escape_for_key(run_id)?
+        ))
+    }
+
+    /// Create a task and return its id.
+    ///
+    /// The id is assigned by meta, the `task_id` in the given task is ignored.
+    /// If the task exists, the id of the existing one is returned if `if_not_exists` is true.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn create_task(&self, mut task: pb::Task, if_not_exists: bool) -> Result<u64> {
+        let key = self.task_key(&task.task_name)?;
+
+        if let Some(seq_v) = self.metastore.get_kv(&key).await? {
+            return match if_not_exists {
+                true => Ok(pb::Task::decode(seq_v.data.as_slice())?.task_id),
+                false => Err(ErrorCode::TaskAlreadyExists(format!(
+                    "Task '{}' already exists",
+                    task.task_name
+                ))),
+            };
+        }
+
+        task.task_id = fetch_id(&self.metastore, IdGenerator::task_id()).await?;
+
+        let value = Operation::Update(task.encode_to_vec());
+        let reply = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::Exact(0), value, None))
+            .await?;
+
+        if !reply.is_changed() {
+            return Err(ErrorCode::TaskAlreadyExists(format!(
+                "Task '{}' already exists",
+                task.task_name
+            )));
+        }
+
+        Ok(task.task_id)
+    }
+
+    /// Get a task by name, along with the seq of its record.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn get_task(&self, task_name: &str) -> Result<Option<(u64, pb::Task)>> {
+        let key = self.task_key(task_name)?;
+
+        match self.metastore.get_kv(&key).await? {
+            None => Ok(None),
+            Some(seq_v) => Ok(Some((seq_v.seq, pb::Task::decode(seq_v.data.as_slice())?))),
+        }
+    }
+
+    /// Update a task if its record still matches `seq`.
+    ///
+    /// Returns false if the task has been changed or removed since it was read.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn update_task(&self, task: &pb::Task, seq: MatchSeq) -> Result<bool> {
+        let key = self.task_key(&task.task_name)?;
+
+        let value = Operation::Update(task.encode_to_vec());
+        let reply = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(&key, seq, value, None))
+            .await?;
+
+        Ok(reply.is_changed())
+    }
+
+    /// Drop a task by name, return the dropped one or None if nothing is dropped.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn drop_task(&self, task_name: &str) -> Result<Option<pb::Task>> {
+        let key = self.task_key(task_name)?;
+
+        let reply = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(1),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+
+        let state_key = self.task_state_key(task_name)?;
+        self.metastore
+            .upsert_kv(UpsertKVReq::new(
+                &state_key,
+                MatchSeq::GE(1),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+
+        match reply.prev {
+            Some(seq_v) if reply.result.is_none() => {
+                Ok(Some(pb::Task::decode(seq_v.data.as_slice())?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// List all tasks of the tenant, along with the seq of their records.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list_tasks(&self) -> Result<Vec<(u64, pb::Task)>> {
+        let prefix = format!("{}/", self.task_prefix);
+        let values = self.metastore.prefix_list_kv(&prefix).await?;
+
+        let mut tasks = Vec::with_capacity(values.len());
+        for (_key, seq_v) in values {
+            tasks.push((seq_v.seq, pb::Task::decode(seq_v.data.as_slice())?));
+        }
+
+        Ok(tasks)
+    }
+
+    /// Get the state of a task, along with the seq of its record, which is 0 if there is none.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn get_task_state(&self, task_name: &str) -> Result<(u64, TaskState)> {
+        let key = self.task_state_key(task_name)?;
+
+        match self.metastore.get_kv(&key).await? {
+            None => Ok((0, TaskState::default())),
+            Some(seq_v) => Ok((seq_v.seq, serde_json::from_slice(&seq_v.data)?)),
+        }
+    }
+
+    /// Update the state of a task if its record still matches `seq`.
+    ///
+    /// Returns false if the state has been changed since it was read.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn update_task_state(
+        &self,
+        task_name: &str,
+        state: &TaskState,
+        seq: MatchSeq,
+    ) -> Result<bool> {
+        let key = self.task_state_key(task_name)?;
+
+        let value = Operation::Update(serde_json::to_vec(state)?);
+        let reply = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(&key, seq, value, None))
+            .await?;
+
+        Ok(reply.is_changed())
+    }
+
+    /// Add or update the record of a task run.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn upsert_task_run(&self, task_run: &pb::TaskRun) -> Result<()> {
+        let key = self.task_run_key(task_run.task_id, &task_run.run_id)?;
+
+        let value = Operation::Update(task_run.encode_to_vec());
+        let meta = Some(MetaSpec::new_ttl(TASK_RUN_RETENTION));
+        self.metastore
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::GE(0), value, meta))
+            .await?;
+
+        Ok(())
+    }
+
+    /// List the runs of a task, or of all tasks if `task_id` is None.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list_task_runs(&self, task_id: Option<u64>) -> Result<Vec<pb::TaskRun>> {
+        let prefix = match task_id {
+            None => format!("{}/", self.task_run_prefix),
+            Some(task_id) => format!("{}/{:020}/", self.task_run_prefix, task_id),
+        };
+        let values = self.metastore.prefix_list_kv(&prefix).await?;
+
+        let mut task_runs = Vec::with_capacity(values.len());
+        for (_key, seq_v) in values {
+            task_runs.push(pb::TaskRun::decode(seq_v.data.as_slice())?);
+        }
+
+        Ok(task_runs)
+    }
+
+    /// Try to become, or to remain, the node that schedules the tasks of the tenant.
+    ///
+    /// The scheduler record expires after `ttl` unless it is renewed by calling this method again.
+    /// Returns true if `node_id` is the scheduler after this call.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn acquire_scheduler(&self, node_id: &str, ttl: Duration) -> Result<bool> {
+        let seq = match self.metastore.get_kv(&self.scheduler_key).await? {
+            None => MatchSeq::Exact(0),
+            Some(seq_v) if seq_v.data == node_id.as_bytes() => MatchSeq::Exact(seq_v.seq),
+            Some(_) => return Ok(false),
+        };
+
+        let value = Operation::Update(node_id.as_bytes().to_vec());
+        let meta = Some(MetaSpec::new_ttl(ttl));
+        let reply = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(&self.scheduler_key, seq, value, meta))
+            .await?;
+
+        Ok(reply.is_changed())
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::Operation;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub static TTL_TABLE_API_KEY_PREFIX: &str = "__fd_ttl_tables";
+
+/// A table that has the `ttl` option.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TtlTable {
+    pub database_id: u64,
+    pub table_id: u64,
+}
+
+/// Keeps the registry of the tables with ttl of a tenant in meta, so that the expired rows
+/// can be removed without listing every table of the tenant:
+/// - `__fd_ttl_tables/<tenant>/<table_id>` -> [`TtlTable`] in JSON
+///
+/// A table is registered when it is created with ttl or when ttl is set, and unregistered
+/// when ttl is unset. The entries of the tables that are dropped are left to the readers
+/// of the registry to remove.
+pub struct TtlTableMgr {
+    metastore: MetaStore,
+    ttl_table_prefix: String,
+}
+
+impl TtlTableMgr {
+    pub fn create(metastore: MetaStore, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while ttl table mgr create)",
+            ));
+        }
+
+        let tenant = escape_for_key(tenant)?;
+        Ok(TtlTableMgr {
+            metastore,
+            ttl_table_prefix: format!("{}/{}", TTL_TABLE_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn ttl_table_key(&self, table_id: u64) -> String {
+        format!("{}/{:020}", self.ttl_table_prefix, table_id)
+    }
+
+    /// Add a table to the registry, registering a table twice is a no-op.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn register(&self, database_id: u64, table_id: u64) -> Result<()> {
+        let key = self.ttl_table_key(table_id);
+        let value = Operation::Update(serde_json::to_vec(&TtlTable {
+            database_id,
+            table_id,
+        })?);
+        self.metastore
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::GE(0), value, None))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Remove a table from the registry, unregistering an unknown table is a no-op.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn unregister(&self, table_id: u64) -> Result<()> {
+        let key = self.ttl_table_key(table_id);
+        self.metastore
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(0),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    /// List the registered tables, ordered by table id.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list(&self) -> Result<Vec<TtlTable>> {
+        let prefix = format!("{}/", self.ttl_table_prefix);
+        let values = self.metastore.prefix_list_kv(&prefix).await?;
+
+        let mut tables = Vec::with_capacity(values.len());
+        for (_key, seq_v) in values {
+            tables.push(serde_json::from_slice::<TtlTable>(&seq_v.data)?);
+        }
+
+        Ok(tables)
+    }
+}
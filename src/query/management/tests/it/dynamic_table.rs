@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_management::*;
+use databend_common_meta_embedded::MetaEmbedded;
+use databend_common_meta_store::MetaStore;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_dynamic_table_refreshes() -> Result<()> {
+    let refresh_mgr = new_refresh_mgr().await?;
+
+    for (table_id, refresh_id, started_at) in [(1, "q2", 20), (1, "q1", 10), (2, "q3", 30)] {
+        let refresh = create_test_refresh(table_id, refresh_id, started_at);
+        refresh_mgr.upsert_refresh(&refresh).await?;
+    }
+
+    let refreshes = refresh_mgr.list_refreshes(None).await?;
+    assert_eq!(3, refreshes.len());
+
+    // Ordered by the time they started.
+    let refreshes = refresh_mgr.list_refreshes(Some(1)).await?;
+    let refresh_ids = refreshes
+        .iter()
+        .map(|r| r.refresh_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["q1", "q2"], refresh_ids);
+
+    // Update the record of a refresh.
+    let mut refresh = create_test_refresh(2, "q3", 30);
+    refresh.state = "FAILED".to_string();
+    refresh_mgr.upsert_refresh(&refresh).await?;
+    assert_eq!(vec![refresh], refresh_mgr.list_refreshes(Some(2)).await?);
+
+    Ok(())
+}
+
+fn create_test_refresh(table_id: u64, refresh_id: &str, started_at: i64) -> DynamicTableRefresh {
+    DynamicTableRefresh {
+        refresh_id: refresh_id.to_string(),
+        table_id,
+        database: "default".to_string(),
+        table: "dt".to_string(),
+        refresh_action: "FULL".to_string(),
+        refresh_trigger: "MANUAL".to_string(),
+        state: "SUCCEEDED".to_string(),
+        message: "".to_string(),
+        started_at,
+        completed_at: started_at + 1,
+    }
+}
+
+async fn new_refresh_mgr() -> Result<DynamicTableRefreshMgr> {
+    let test_api = MetaStore::L(Arc::new(MetaEmbedded::new_temp().await?));
+    DynamicTableRefreshMgr::create(test_api, "test-tenant-id")
+}
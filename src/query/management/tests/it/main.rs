@@ -15,9 +15,12 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod cluster;
+mod dynamic_table;
+mod notification;
 mod quota;
 mod role;
 mod setting;
 mod stage;
+mod task;
 mod udf;
 mod user;
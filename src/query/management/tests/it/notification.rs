@@ -0,0 +1,119 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_cloud_control::pb;
+use databend_common_cloud_control::utils::Timestamp;
+use databend_common_exception::Result;
+use databend_common_management::*;
+use databend_common_meta_embedded::MetaEmbedded;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_notification_crud() -> Result<()> {
+    let notification_mgr = new_notification_mgr().await?;
+
+    let id = notification_mgr
+        .create_notification(create_test_notification("n1"), false)
+        .await?;
+    assert!(id > 0);
+
+    // Create an existing notification.
+    match notification_mgr
+        .create_notification(create_test_notification("n1"), false)
+        .await
+    {
+        Ok(_) => panic!("Already exists create notification must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 1707),
+    }
+    assert_eq!(
+        id,
+        notification_mgr
+            .create_notification(create_test_notification("n1"), true)
+            .await?
+    );
+
+    // Update with a stale seq is rejected.
+    let (seq, mut notification) = notification_mgr.get_notification("n1").await?.unwrap();
+    notification.enabled = false;
+    assert!(
+        notification_mgr
+            .update_notification(&notification, MatchSeq::Exact(seq))
+            .await?
+    );
+    assert!(
+        !notification_mgr
+            .update_notification(&notification, MatchSeq::Exact(seq))
+            .await?
+    );
+
+    let notifications = notification_mgr.list_notifications().await?;
+    assert_eq!(1, notifications.len());
+    assert!(!notifications[0].enabled);
+
+    let dropped = notification_mgr.drop_notification("n1").await?;
+    assert_eq!(Some(id), dropped.map(|n| n.notification_id));
+    assert!(notification_mgr.drop_notification("n1").await?.is_none());
+    assert!(notification_mgr.get_notification("n1").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_notification_history() -> Result<()> {
+    let notification_mgr = new_notification_mgr().await?;
+
+    for (name, seconds, message_id) in [("n1", 2, "a"), ("n1", 1, "b"), ("n2", 1, "c")] {
+        let history = pb::NotificationHistory {
+            created_time: Some(Timestamp { seconds, nanos: 0 }),
+            name: name.to_string(),
+            message: message_id.to_string(),
+            ..Default::default()
+        };
+        notification_mgr
+            .upsert_notification_history(&history, message_id)
+            .await?;
+    }
+
+    let histories = notification_mgr.list_notification_history(None).await?;
+    assert_eq!(3, histories.len());
+
+    let histories = notification_mgr
+        .list_notification_history(Some("n1"))
+        .await?;
+    let messages = histories
+        .iter()
+        .map(|h| h.message.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["b", "a"], messages);
+
+    Ok(())
+}
+
+fn create_test_notification(name: &str) -> pb::Notification {
+    pb::Notification {
+        name: name.to_string(),
+        enabled: true,
+        webhook_url: "http://127.0.0.1:8080/hook".to_string(),
+        ..Default::default()
+    }
+}
+
+async fn new_notification_mgr() -> Result<NotificationMgr> {
+    let test_api = MetaStore::L(Arc::new(MetaEmbedded::new_temp().await?));
+    NotificationMgr::create(test_api, "test-tenant-id")
+}
@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_cloud_control::pb;
+use databend_common_exception::Result;
+use databend_common_management::*;
+use databend_common_meta_embedded::MetaEmbedded;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_crud() -> Result<()> {
+    let (_, task_mgr) = new_task_mgr().await?;
+
+    let task_id = task_mgr.create_task(create_test_task("t1"), false).await?;
+    assert!(task_id > 0);
+
+    // Create an existing task.
+    match task_mgr.create_task(create_test_task("t1"), false).await {
+        Ok(_) => panic!("Already exists create task must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 1705),
+    }
+    assert_eq!(
+        task_id,
+        task_mgr.create_task(create_test_task("t1"), true).await?
+    );
+
+    let (seq, mut task) = task_mgr.get_task("t1").await?.unwrap();
+    assert_eq!(task_id, task.task_id);
+    assert_eq!("SELECT 1", task.query_text);
+
+    // Update with a stale seq is rejected.
+    task.query_text = "SELECT 2".to_string();
+    assert!(task_mgr.update_task(&task, MatchSeq::Exact(seq)).await?);
+    assert!(!task_mgr.update_task(&task, MatchSeq::Exact(seq)).await?);
+
+    let tasks = task_mgr.list_tasks().await?;
+    assert_eq!(1, tasks.len());
+    assert_eq!("SELECT 2", tasks[0].1.query_text);
+
+    let dropped = task_mgr.drop_task("t1").await?;
+    assert_eq!(Some(task_id), dropped.map(|t| t.task_id));
+    assert!(task_mgr.drop_task("t1").await?.is_none());
+    assert!(task_mgr.get_task("t1").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_runs() -> Result<()> {
+    let (_, task_mgr) = new_task_mgr().await?;
+
+    for (task_id, run_id) in [(1, "a"), (1, "b"), (2, "c")] {
+        let task_run = pb::TaskRun {
+            task_id,
+            run_id: run_id.to_string(),
+            ..Default::default()
+        };
+        task_mgr.upsert_task_run(&task_run).await?;
+    }
+
+    let runs = task_mgr.list_task_runs(None).await?;
+    assert_eq!(3, runs.len());
+
+    let runs = task_mgr.list_task_runs(Some(1)).await?;
+    let run_ids = runs.iter().map(|r| r.run_id.as_str()).collect::<Vec<_>>();
+    assert_eq!(vec!["a", "b"], run_ids);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_state() -> Result<()> {
+    let (_, task_mgr) = new_task_mgr().await?;
+    task_mgr.create_task(create_test_task("t1"), false).await?;
+
+    let (seq, mut state) = task_mgr.get_task_state("t1").await?;
+    assert_eq!(0, seq);
+    assert_eq!(TaskState::default(), state);
+
+    state.failures = 2;
+    state.succeeded_run = Some("run-1".to_string());
+    assert!(
+        task_mgr
+            .update_task_state("t1", &state, MatchSeq::Exact(seq))
+            .await?
+    );
+    // Update with a stale seq is rejected.
+    assert!(
+        !task_mgr
+            .update_task_state("t1", &state, MatchSeq::Exact(seq))
+            .await?
+    );
+
+    let (seq, stored) = task_mgr.get_task_state("t1").await?;
+    assert!(seq > 0);
+    assert_eq!(state, stored);
+
+    // The state is removed with the task.
+    task_mgr.drop_task("t1").await?;
+    let (seq, stored) = task_mgr.get_task_state("t1").await?;
+    assert_eq!(0, seq);
+    assert_eq!(TaskState::default(), stored);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_acquire_scheduler() -> Result<()> {
+    let (_, task_mgr) = new_task_mgr().await?;
+
+    let ttl = Duration::from_secs(60);
+    assert!(task_mgr.acquire_scheduler("node-1", ttl).await?);
+    assert!(!task_mgr.acquire_scheduler("node-2", ttl).await?);
+
+    // Renew by the same node.
+    assert!(task_mgr.acquire_scheduler("node-1", ttl).await?);
+
+    Ok(())
+}
+
+fn create_test_task(name: &str) -> pb::Task {
+    pb::Task {
+        task_name: name.to_string(),
+        query_text: "SELECT 1".to_string(),
+        owner: "account_admin".to_string(),
+        ..Default::default()
+    }
+}
+
+async fn new_task_mgr() -> Result<(MetaStore, TaskMgr)> {
+    let test_api = MetaStore::L(Arc::new(MetaEmbedded::new_temp().await?));
+    let task_mgr = TaskMgr::create(test_api.clone(), "test-tenant-id")?;
+    Ok((test_api, task_mgr))
+}
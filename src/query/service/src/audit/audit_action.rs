@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_sql::plans::Plan;
+
+use crate::audit::AuditCategory;
+
+/// The audited action of a statement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AuditAction {
+    pub category: AuditCategory,
+    pub action: &'static str,
+}
+
+/// Returns the audited action of `plan`, or None if the statement is not audited.
+pub fn audit_action(plan: &Plan) -> Option<AuditAction> {
+    let (category, action) = match plan {
+        // Privileges
+        Plan::GrantPriv { .. } => (AuditCategory::Privilege, "GrantPrivilege"),
+        Plan::RevokePriv { .. } => (AuditCategory::Privilege, "RevokePrivilege"),
+        Plan::GrantRole { .. } => (AuditCategory::Privilege, "GrantRole"),
+        Plan::RevokeRole { .. } => (AuditCategory::Privilege, "RevokeRole"),
+
+        // Users, roles and policies
+        Plan::CreateUser { .. } => (AuditCategory::AccessControl, "CreateUser"),
+        Plan::AlterUser { .. } => (AuditCategory::AccessControl, "AlterUser"),
+        Plan::DropUser { .. } => (AuditCategory::AccessControl, "DropUser"),
+        Plan::CreateRole { .. } => (AuditCategory::AccessControl, "CreateRole"),
+        Plan::DropRole { .. } => (AuditCategory::AccessControl, "DropRole"),
+        Plan::CreateDatamaskPolicy { .. } => (AuditCategory::AccessControl, "CreateDatamaskPolicy"),
+        Plan::DropDatamaskPolicy { .. } => (AuditCategory::AccessControl, "DropDatamaskPolicy"),
+        Plan::CreateRowAccessPolicy { .. } => {
+            (AuditCategory::AccessControl, "CreateRowAccessPolicy")
+        }
+        Plan::DropRowAccessPolicy { .. } => (AuditCategory::AccessControl, "DropRowAccessPolicy"),
+        Plan::AddTableRowAccessPolicy { .. } => {
+            (AuditCategory::AccessControl, "AddTableRowAccessPolicy")
+        }
+        Plan::DropTableRowAccessPolicy { .. } => {
+            (AuditCategory::AccessControl, "DropTableRowAccessPolicy")
+        }
+        Plan::CreateNetworkPolicy { .. } => (AuditCategory::AccessControl, "CreateNetworkPolicy"),
+        Plan::AlterNetworkPolicy { .. } => (AuditCategory::AccessControl, "AlterNetworkPolicy"),
+        Plan::DropNetworkPolicy { .. } => (AuditCategory::AccessControl, "DropNetworkPolicy"),
+        Plan::CreatePasswordPolicy { .. } => (AuditCategory::AccessControl, "CreatePasswordPolicy"),
+        Plan::AlterPasswordPolicy { .. } => (AuditCategory::AccessControl, "AlterPasswordPolicy"),
+        Plan::DropPasswordPolicy { .. } => (AuditCategory::AccessControl, "DropPasswordPolicy"),
+
+        // DDL
+        Plan::CreateCatalog { .. } => (AuditCategory::Ddl, "CreateCatalog"),
+        Plan::DropCatalog { .. } => (AuditCategory::Ddl, "DropCatalog"),
+        Plan::CreateDatabase { .. } => (AuditCategory::Ddl, "CreateDatabase"),
+        Plan::DropDatabase { .. } => (AuditCategory::Ddl, "DropDatabase"),
+        Plan::UndropDatabase { .. } => (AuditCategory::Ddl, "UndropDatabase"),
+        Plan::RenameDatabase { .. } => (AuditCategory::Ddl, "RenameDatabase"),
+        Plan::CreateTable { .. } => (AuditCategory::Ddl, "CreateTable"),
+        Plan::DropTable { .. } => (AuditCategory::Ddl, "DropTable"),
+        Plan::UndropTable { .. } => (AuditCategory::Ddl, "UndropTable"),
+        Plan::RenameTable { .. } => (AuditCategory::Ddl, "RenameTable"),
+        Plan::ModifyTableComment { .. } => (AuditCategory::Ddl, "ModifyTableComment"),
+        Plan::RenameTableColumn { .. } => (AuditCategory::Ddl, "RenameTableColumn"),
+        Plan::AddTableColumn { .. } => (AuditCategory::Ddl, "AddTableColumn"),
+        Plan::DropTableColumn { .. } => (AuditCategory::Ddl, "DropTableColumn"),
+        Plan::ModifyTableColumn { .. } => (AuditCategory::Ddl, "ModifyTableColumn"),
+        Plan::AlterTableClusterKey { .. } => (AuditCategory::Ddl, "AlterTableClusterKey"),
+        Plan::DropTableClusterKey { .. } => (AuditCategory::Ddl, "DropTableClusterKey"),
+        Plan::RevertTable { .. } => (AuditCategory::Ddl, "RevertTable"),
+        Plan::TruncateTable { .. } => (AuditCategory::Ddl, "TruncateTable"),
+        Plan::DropTablePartition { .. } => (AuditCategory::Ddl, "DropTablePartition"),
+        Plan::VacuumDropTable { .. } => (AuditCategory::Ddl, "VacuumDropTable"),
+        Plan::SetOptions { .. } => (AuditCategory::Ddl, "SetOptions"),
+        Plan::UnsetOptions { .. } => (AuditCategory::Ddl, "UnsetOptions"),
+        Plan::CreateView { .. } => (AuditCategory::Ddl, "CreateView"),
+        Plan::AlterView { .. } => (AuditCategory::Ddl, "AlterView"),
+        Plan::DropView { .. } => (AuditCategory::Ddl, "DropView"),
+        Plan::CreateStream { .. } => (AuditCategory::Ddl, "CreateStream"),
+        Plan::DropStream { .. } => (AuditCategory::Ddl, "DropStream"),
+        Plan::CreateDynamicTable { .. } => (AuditCategory::Ddl, "CreateDynamicTable"),
+        Plan::CreateIndex { .. } => (AuditCategory::Ddl, "CreateIndex"),
+        Plan::DropIndex { .. } => (AuditCategory::Ddl, "DropIndex"),
+        Plan::CreateTableIndex { .. } => (AuditCategory::Ddl, "CreateTableIndex"),
+        Plan::DropTableIndex { .. } => (AuditCategory::Ddl, "DropTableIndex"),
+        Plan::CreateVirtualColumn { .. } => (AuditCategory::Ddl, "CreateVirtualColumn"),
+        Plan::AlterVirtualColumn { .. } => (AuditCategory::Ddl, "AlterVirtualColumn"),
+        Plan::DropVirtualColumn { .. } => (AuditCategory::Ddl, "DropVirtualColumn"),
+        Plan::CreateUDF { .. } => (AuditCategory::Ddl, "CreateUDF"),
+        Plan::AlterUDF { .. } => (AuditCategory::Ddl, "AlterUDF"),
+        Plan::DropUDF { .. } => (AuditCategory::Ddl, "DropUDF"),
+        Plan::CreateFileFormat { .. } => (AuditCategory::Ddl, "CreateFileFormat"),
+        Plan::DropFileFormat { .. } => (AuditCategory::Ddl, "DropFileFormat"),
+        Plan::CreateQueryHint { .. } => (AuditCategory::Ddl, "CreateQueryHint"),
+        Plan::DropQueryHint { .. } => (AuditCategory::Ddl, "DropQueryHint"),
+        Plan::CreateStage { .. } => (AuditCategory::Ddl, "CreateStage"),
+        Plan::DropStage { .. } => (AuditCategory::Ddl, "DropStage"),
+        Plan::CreateConnection { .. } => (AuditCategory::Ddl, "CreateConnection"),
+        Plan::DropConnection { .. } => (AuditCategory::Ddl, "DropConnection"),
+        Plan::CreateTask { .. } => (AuditCategory::Ddl, "CreateTask"),
+        Plan::AlterTask { .. } => (AuditCategory::Ddl, "AlterTask"),
+        Plan::DropTask { .. } => (AuditCategory::Ddl, "DropTask"),
+        Plan::CreateNotification { .. } => (AuditCategory::Ddl, "CreateNotification"),
+        Plan::AlterNotification { .. } => (AuditCategory::Ddl, "AlterNotification"),
+        Plan::DropNotification { .. } => (AuditCategory::Ddl, "DropNotification"),
+        Plan::CreateProcedure { .. } => (AuditCategory::Ddl, "CreateProcedure"),
+        Plan::DropProcedure { .. } => (AuditCategory::Ddl, "DropProcedure"),
+        Plan::CreateSequence { .. } => (AuditCategory::Ddl, "CreateSequence"),
+        Plan::AlterSequence { .. } => (AuditCategory::Ddl, "AlterSequence"),
+        Plan::DropSequence { .. } => (AuditCategory::Ddl, "DropSequence"),
+        Plan::CreateDictionary { .. } => (AuditCategory::Ddl, "CreateDictionary"),
+        Plan::DropDictionary { .. } => (AuditCategory::Ddl, "DropDictionary"),
+
+        // Loading and unloading
+        Plan::CopyIntoTable { .. } => (AuditCategory::DataTransfer, "CopyIntoTable"),
+        Plan::CopyIntoLocation { .. } => (AuditCategory::DataTransfer, "CopyIntoLocation"),
+
+        _ => return None,
+    };
+    Some(AuditAction { category, action })
+}
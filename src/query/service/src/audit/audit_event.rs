@@ -0,0 +1,265 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::path::Path;
+use std::time::SystemTime;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_storages_system::SystemLogElement;
+use hmac::Hmac;
+use hmac::Mac;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Sha256;
+
+use crate::sessions::convert_query_log_timestamp;
+
+/// The `prev_hash` of the first audit event of a chain.
+pub const AUDIT_GENESIS_HASH: &str =
+    "0000000000000000000000000000000000000000000000000000000000000000";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditCategory {
+    /// GRANT and REVOKE of privileges and roles.
+    Privilege,
+    /// Changes of users, roles and policies.
+    AccessControl,
+    Ddl,
+    /// COPY INTO a table and unloading with COPY INTO a location.
+    DataTransfer,
+    /// Reading columns protected by a masking policy.
+    DataAccess,
+    /// Login and logout of the sessions.
+    Session,
+}
+
+impl Display for AuditCategory {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AuditCategory::Privilege => write!(f, "privilege"),
+            AuditCategory::AccessControl => write!(f, "access_control"),
+            AuditCategory::Ddl => write!(f, "ddl"),
+            AuditCategory::DataTransfer => write!(f, "data_transfer"),
+            AuditCategory::DataAccess => write!(f, "data_access"),
+            AuditCategory::Session => write!(f, "session"),
+        }
+    }
+}
+
+/// An audited action of a user.
+///
+/// The events written by a node form a hash chain: `hash` is the HMAC of the whole event
+/// including `prev_hash`, which is the `hash` of the previous event, so removing or
+/// modifying an event breaks the chain, and the chain can not be rebuilt without the
+/// secret key, see [`verify_audit_chain`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub seq: u64,
+    pub event_time: i64,
+    pub tenant_id: String,
+    pub cluster_id: String,
+    pub node_id: String,
+    pub category: AuditCategory,
+    pub action: String,
+    pub user_name: String,
+    pub role_name: String,
+    pub client_address: String,
+    pub session_id: String,
+    pub query_id: String,
+    pub statement: String,
+    pub detail: String,
+    pub success: bool,
+    pub error_code: i32,
+    pub error_message: String,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+impl AuditEvent {
+    pub fn create(category: AuditCategory, action: &str) -> Self {
+        AuditEvent {
+            seq: 0,
+            event_time: convert_query_log_timestamp(SystemTime::now()),
+            tenant_id: String::new(),
+            cluster_id: String::new(),
+            node_id: String::new(),
+            category,
+            action: action.to_string(),
+            user_name: String::new(),
+            role_name: String::new(),
+            client_address: String::new(),
+            session_id: String::new(),
+            query_id: String::new(),
+            statement: String::new(),
+            detail: String::new(),
+            success: true,
+            error_code: 0,
+            error_message: String::new(),
+            prev_hash: String::new(),
+            hash: String::new(),
+        }
+    }
+
+    pub fn with_error(mut self, error: Option<&ErrorCode>) -> Self {
+        if let Some(error) = error {
+            self.success = false;
+            self.error_code = error.code() as i32;
+            self.error_message = error.message();
+        }
+        self
+    }
+
+    /// The HMAC-SHA256 with `key` of the event serialized with an empty `hash`, in hex.
+    pub fn compute_hash(&self, key: &[u8]) -> Result<String> {
+        let mut unhashed = self.clone();
+        unhashed.hash = String::new();
+        let serialized = serde_json::to_vec(&unhashed)?;
+        let mut mac = Hmac::<Sha256>::new_from_slice(key)
+            .map_err(|e| ErrorCode::InvalidConfig(format!("Invalid audit hmac key: {}", e)))?;
+        mac.update(&serialized);
+        Ok(hex::encode(mac.finalize().into_bytes()))
+    }
+}
+
+/// Check that `events`, written by the same node in order, are not modified and
+/// no event is missing between them.
+pub fn verify_audit_chain(events: &[AuditEvent], key: &[u8]) -> Result<()> {
+    let mut prev: Option<&AuditEvent> = None;
+    for event in events {
+        if event.compute_hash(key)? != event.hash {
+            return Err(ErrorCode::BadBytes(format!(
+                "Audit event {} does not match its hash",
+                event.seq
+            )));
+        }
+        if let Some(prev) = prev {
+            if event.seq != prev.seq + 1 || event.prev_hash != prev.hash {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Audit event {} does not follow audit event {}",
+                    event.seq, prev.seq
+                )));
+            }
+        }
+        prev = Some(event);
+    }
+    Ok(())
+}
+
+/// Verify the chain of the audit events in the audit log files of a node, given in the order
+/// they are written, returns the number of events verified.
+///
+/// Each line holds an event as JSON, possibly after a prefix added by the log layout.
+pub fn verify_audit_log_files(files: &[impl AsRef<Path>], key: &[u8]) -> Result<usize> {
+    let mut events = vec![];
+    for file in files {
+        let reader = BufReader::new(std::fs::File::open(file.as_ref())?);
+        for line in reader.lines() {
+            let line = line?;
+            let Some(start) = line.find('{') else {
+                continue;
+            };
+            events.push(serde_json::from_str::<AuditEvent>(&line[start..])?);
+        }
+    }
+    verify_audit_chain(&events, key)?;
+    Ok(events.len())
+}
+
+impl SystemLogElement for AuditEvent {
+    const TABLE_NAME: &'static str = "audit_log";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("seq", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("cluster_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("category", TableDataType::String),
+            TableField::new("action", TableDataType::String),
+            TableField::new("user_name", TableDataType::String),
+            TableField::new("role_name", TableDataType::String),
+            TableField::new("client_address", TableDataType::String),
+            TableField::new("session_id", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("statement", TableDataType::String),
+            TableField::new("detail", TableDataType::String),
+            TableField::new("success", TableDataType::Boolean),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("error_message", TableDataType::String),
+            TableField::new("prev_hash", TableDataType::String),
+            TableField::new("hash", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.seq)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        for value in [
+            &self.tenant_id,
+            &self.cluster_id,
+            &self.node_id,
+            &self.category.to_string(),
+            &self.action,
+            &self.user_name,
+            &self.role_name,
+            &self.client_address,
+            &self.session_id,
+            &self.query_id,
+            &self.statement,
+            &self.detail,
+        ] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.clone()).as_ref());
+        }
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.success).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Int32(self.error_code)).as_ref());
+        for value in [&self.error_message, &self.prev_hash, &self.hash] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.clone()).as_ref());
+        }
+        Ok(())
+    }
+}
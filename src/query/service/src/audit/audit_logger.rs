@@ -0,0 +1,356 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio::sync::oneshot;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::Thread;
+use databend_common_base::runtime::ThreadJoinHandle;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::audit::AuditAction;
+use crate::audit::AuditCategory;
+use crate::audit::AuditEvent;
+use crate::audit::AUDIT_GENESIS_HASH;
+use crate::history::QueryHistoryWriter;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionType;
+
+/// A destination of the audit events.
+pub trait AuditSink: Send + Sync {
+    fn write(&self, event: &AuditEvent) -> Result<()>;
+}
+
+/// Writes the audit events as JSON lines into the `databend::log::audit` target,
+/// which is sent to the file and the OTLP endpoint configured in `[log.audit]`.
+pub struct LogAuditSink;
+
+impl AuditSink for LogAuditSink {
+    fn write(&self, event: &AuditEvent) -> Result<()> {
+        let event_str = serde_json::to_string(event)?;
+        info!(target: "databend::log::audit", "{}", event_str);
+        Ok(())
+    }
+}
+
+/// Writes the audit events into the `system_history.audit_log` table.
+pub struct TableAuditSink {
+    writer: Arc<QueryHistoryWriter>,
+}
+
+impl AuditSink for TableAuditSink {
+    fn write(&self, event: &AuditEvent) -> Result<()> {
+        self.writer.append_audit(event.clone());
+        Ok(())
+    }
+}
+
+/// The name of the file in the audit log dir that keeps the head of the chain across restarts.
+pub const AUDIT_CHAIN_STATE_FILE: &str = "audit_chain.state";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct AuditChain {
+    next_seq: u64,
+    last_hash: String,
+}
+
+impl AuditChain {
+    fn genesis() -> Self {
+        AuditChain {
+            next_seq: 0,
+            last_hash: AUDIT_GENESIS_HASH.to_string(),
+        }
+    }
+
+    /// The head of the chain after `event`.
+    fn after(event: &AuditEvent) -> Self {
+        AuditChain {
+            next_seq: event.seq + 1,
+            last_hash: event.hash.clone(),
+        }
+    }
+
+    fn load(state_file: &Path) -> Result<Self> {
+        match std::fs::read(state_file) {
+            Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::genesis()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Replace the state file by a rename, so that it is never left half written,
+    /// and sync it to the disk before returning.
+    fn save(&self, state_file: &Path) -> Result<()> {
+        let tmp_file = state_file.with_extension("tmp");
+        let mut file = std::fs::File::create(&tmp_file)?;
+        file.write_all(&serde_json::to_vec(self)?)?;
+        file.sync_all()?;
+        std::fs::rename(&tmp_file, state_file)?;
+        if let Some(dir) = state_file
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+        {
+            std::fs::File::open(dir)?.sync_all()?;
+        }
+        Ok(())
+    }
+}
+
+enum AuditMessage {
+    Event(AuditEvent),
+    Flush(oneshot::Sender<()>),
+}
+
+/// Runs on a dedicated thread, so that the file I/O is kept off the async runtime.
+struct AuditWriter {
+    state_file: Option<PathBuf>,
+    sinks: Vec<Box<dyn AuditSink>>,
+}
+
+impl AuditWriter {
+    fn run(self, receiver: mpsc::Receiver<AuditMessage>) {
+        while let Ok(message) = receiver.recv() {
+            let mut events = vec![];
+            let mut flushes = vec![];
+            for message in std::iter::once(message).chain(receiver.try_iter()) {
+                match message {
+                    AuditMessage::Event(event) => events.push(event),
+                    AuditMessage::Flush(tx) => flushes.push(tx),
+                }
+            }
+
+            // The head of the chain is saved before the events are written, a crash in
+            // between leaves a gap in the sequence rather than a fork of the chain.
+            if let (Some(state_file), Some(last)) = (&self.state_file, events.last()) {
+                if let Err(cause) = AuditChain::after(last).save(state_file) {
+                    warn!(
+                        "Cannot save the audit chain after event {}, cause {:?}",
+                        last.seq, cause
+                    );
+                }
+            }
+
+            for event in &events {
+                for sink in &self.sinks {
+                    if let Err(cause) = sink.write(event) {
+                        warn!("Cannot write audit event {}, cause {:?}", event.seq, cause);
+                    }
+                }
+            }
+
+            for tx in flushes {
+                let _ = tx.send(());
+            }
+        }
+    }
+}
+
+/// Chains the audit events of this node and writes them into the sinks.
+///
+/// With a state file, the chain continues from the last event written before a restart,
+/// otherwise every start begins a new chain from [`AUDIT_GENESIS_HASH`].
+pub struct AuditLogger {
+    tenant_id: String,
+    cluster_id: String,
+    node_id: String,
+    hmac_key: Vec<u8>,
+    chain: Mutex<AuditChain>,
+    sender: Option<mpsc::Sender<AuditMessage>>,
+    writer: Option<ThreadJoinHandle<()>>,
+}
+
+impl AuditLogger {
+    pub fn create(
+        tenant_id: String,
+        cluster_id: String,
+        node_id: String,
+        hmac_key: Vec<u8>,
+        state_file: Option<PathBuf>,
+        sinks: Vec<Box<dyn AuditSink>>,
+    ) -> Result<Arc<Self>> {
+        let chain = match &state_file {
+            Some(state_file) => AuditChain::load(state_file)?,
+            None => AuditChain::genesis(),
+        };
+        let (sender, receiver) = mpsc::channel();
+        let writer = AuditWriter { state_file, sinks };
+        let writer = Thread::named_spawn(Some("audit-writer".to_string()), move || {
+            writer.run(receiver)
+        });
+        Ok(Arc::new(AuditLogger {
+            tenant_id,
+            cluster_id,
+            node_id,
+            hmac_key,
+            chain: Mutex::new(chain),
+            sender: Some(sender),
+            writer: Some(writer),
+        }))
+    }
+
+    /// The events are written into the log sink if `log.audit.on`,
+    /// and also into the table sink if query history is enabled.
+    /// The head of the chain is kept in `log.audit.dir`, if it is set.
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        let audit = &config.log.audit;
+        let logger = match audit.on {
+            false => None,
+            true => {
+                let mut sinks: Vec<Box<dyn AuditSink>> = vec![Box::new(LogAuditSink)];
+                if let Some(writer) = QueryHistoryWriter::try_instance() {
+                    sinks.push(Box::new(TableAuditSink { writer }));
+                }
+                let state_file = match audit.dir.is_empty() {
+                    true => {
+                        warn!("log.audit.dir is not set, the audit chain restarts on every start");
+                        None
+                    }
+                    false => {
+                        std::fs::create_dir_all(&audit.dir)?;
+                        Some(PathBuf::from(&audit.dir).join(AUDIT_CHAIN_STATE_FILE))
+                    }
+                };
+                Some(Self::create(
+                    config.query.tenant_id.tenant_name().to_string(),
+                    config.query.cluster_id.clone(),
+                    config.query.node_id.clone(),
+                    audit.hmac_key.as_bytes().to_vec(),
+                    state_file,
+                    sinks,
+                )?)
+            }
+        };
+        GlobalInstance::set(logger);
+        Ok(())
+    }
+
+    /// Returns the logger of this node, or None if audit log is not enabled.
+    pub fn try_instance() -> Option<Arc<AuditLogger>> {
+        GlobalInstance::try_get::<Option<Arc<AuditLogger>>>().flatten()
+    }
+
+    pub fn log(&self, mut event: AuditEvent) {
+        event.tenant_id = self.tenant_id.clone();
+        event.cluster_id = self.cluster_id.clone();
+        event.node_id = self.node_id.clone();
+
+        // The chain is locked until the event is queued, so that the writer receives
+        // the events in the order of the chain.
+        let mut chain = self.chain.lock();
+        event.seq = chain.next_seq;
+        event.prev_hash = chain.last_hash.clone();
+        event.hash = match event.compute_hash(&self.hmac_key) {
+            Ok(hash) => hash,
+            Err(cause) => {
+                warn!("Cannot hash audit event {:?}, cause {:?}", event, cause);
+                return;
+            }
+        };
+        *chain = AuditChain::after(&event);
+
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(AuditMessage::Event(event));
+        }
+    }
+
+    /// Wait until the events logged before are written into the sinks.
+    pub async fn flush(&self) {
+        let (tx, rx) = oneshot::channel();
+        if let Some(sender) = &self.sender {
+            if sender.send(AuditMessage::Flush(tx)).is_ok() {
+                let _ = rx.await;
+            }
+        }
+    }
+}
+
+impl Drop for AuditLogger {
+    fn drop(&mut self) {
+        // Closing the channel stops the writer after the queued events.
+        drop(self.sender.take());
+        if let Some(writer) = self.writer.take() {
+            if let Err(cause) = writer.join() {
+                warn!("Audit writer exited with error, cause {:?}", cause);
+            }
+        }
+    }
+}
+
+/// Record the audit event of the statement of `ctx`, if it is audited.
+pub fn audit_query(ctx: &QueryContext, error: Option<&ErrorCode>) {
+    let Some(logger) = AuditLogger::try_instance() else {
+        return;
+    };
+
+    let masked_columns = ctx.get_masked_columns();
+    let (action, detail) = match ctx.get_audit_action() {
+        Some(action) => (action, String::new()),
+        None if !masked_columns.is_empty() => (
+            AuditAction {
+                category: AuditCategory::DataAccess,
+                action: "ReadMaskedData",
+            },
+            format!("masked columns: {}", masked_columns.join(", ")),
+        ),
+        None => return,
+    };
+
+    let mut event = AuditEvent::create(action.category, action.action).with_error(error);
+    event.user_name = ctx
+        .get_current_user()
+        .map(|user| user.name)
+        .unwrap_or_default();
+    event.role_name = ctx
+        .get_current_role()
+        .map(|role| role.name)
+        .unwrap_or_default();
+    event.client_address = ctx.get_client_address().unwrap_or_default();
+    event.session_id = ctx.get_current_session_id();
+    event.query_id = ctx.get_id();
+    event.statement = ctx.get_query_str();
+    event.detail = detail;
+    logger.log(event);
+}
+
+/// Record the login or logout of a session.
+pub fn audit_session(
+    action: &str,
+    handler_type: &SessionType,
+    user_name: &str,
+    client_address: Option<&str>,
+    error: Option<&ErrorCode>,
+) {
+    let Some(logger) = AuditLogger::try_instance() else {
+        return;
+    };
+
+    let mut event = AuditEvent::create(AuditCategory::Session, action).with_error(error);
+    event.user_name = user_name.to_string();
+    event.client_address = client_address.unwrap_or_default().to_string();
+    event.detail = format!("handler: {}", handler_type);
+    logger.log(event);
+}
@@ -0,0 +1,32 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod audit_action;
+mod audit_event;
+mod audit_logger;
+
+pub use audit_action::audit_action;
+pub use audit_action::AuditAction;
+pub use audit_event::verify_audit_chain;
+pub use audit_event::verify_audit_log_files;
+pub use audit_event::AuditCategory;
+pub use audit_event::AuditEvent;
+pub use audit_event::AUDIT_GENESIS_HASH;
+pub use audit_logger::audit_query;
+pub use audit_logger::audit_session;
+pub use audit_logger::AuditLogger;
+pub use audit_logger::AuditSink;
+pub use audit_logger::LogAuditSink;
+pub use audit_logger::TableAuditSink;
+pub use audit_logger::AUDIT_CHAIN_STATE_FILE;
@@ -24,14 +24,19 @@ use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_users::JwtAuthenticator;
+use databend_common_users::KeyPairAuthenticator;
+use databend_common_users::LdapAuthenticator;
 use databend_common_users::UserApiProvider;
 use fastrace::func_name;
+use log::warn;
 
+use crate::history::log_login;
 use crate::servers::http::v1::ClientSessionManager;
 use crate::sessions::Session;
 
 pub struct AuthMgr {
     jwt_auth: Option<JwtAuthenticator>,
+    ldap_auth: Option<LdapAuthenticator>,
 }
 
 #[derive(Clone)]
@@ -43,6 +48,12 @@ pub enum Credential {
         token: String,
         client_ip: Option<String>,
     },
+    /// A short-lived JWT signed with the private key of a key pair registered on the user.
+    KeyPair {
+        name: String,
+        token: String,
+        client_ip: Option<String>,
+    },
     Password {
         name: String,
         password: Option<Vec<u8>>,
@@ -73,6 +84,54 @@ impl AuthMgr {
                 cfg.query.jwt_key_file.clone(),
                 cfg.query.jwt_key_files.clone(),
             ),
+            ldap_auth: LdapAuthenticator::create(&cfg.query.ldap),
+        })
+    }
+
+    /// Verifies a key-pair JWT with the public keys registered on the user.
+    #[async_backtrace::framed]
+    pub async fn auth_key_pair(
+        &self,
+        tenant: &Tenant,
+        name: &str,
+        token: &str,
+        client_ip: Option<&str>,
+    ) -> Result<UserInfo> {
+        let user_api = UserApiProvider::instance();
+        let identity = UserIdentity::new(name, "%");
+        let user = user_api
+            .get_user_with_client_ip(tenant, identity.clone(), client_ip)
+            .await?;
+        let authed = KeyPairAuthenticator::verify(token, name, &user.option.public_keys());
+        user_api
+            .update_user_login_result(tenant.clone(), identity, authed.is_ok(), &user)
+            .await?;
+        authed?;
+        Ok(user)
+    }
+
+    pub fn ldap_enabled(&self) -> bool {
+        self.ldap_auth.is_some()
+    }
+
+    /// Verifies the password of a user `IDENTIFIED WITH ldap` by a bind against the LDAP server.
+    /// Returns the roles mapped from the LDAP groups of the user, which should be granted
+    /// to the authed user of the session only.
+    ///
+    /// The cause of a failure is only logged, the client gets a generic error, so that it
+    /// does not learn about the directory, e.g. the DN of the user or the search filter.
+    #[async_backtrace::framed]
+    pub async fn auth_ldap(&self, user_name: &str, password: &[u8]) -> Result<Vec<String>> {
+        let res = match (&self.ldap_auth, std::str::from_utf8(password)) {
+            (None, _) => Err(ErrorCode::AuthenticateFailure("ldap auth not configured.")),
+            (Some(_), Err(_)) => Err(ErrorCode::AuthenticateFailure(
+                "ldap password is not valid utf-8",
+            )),
+            (Some(ldap_auth), Ok(password)) => ldap_auth.authenticate(user_name, password).await,
+        };
+        res.map_err(|e| {
+            warn!("LDAP authentication of user '{}' failed: {}", user_name, e);
+            ErrorCode::AuthenticateFailure("LDAP authentication failed")
         })
     }
 
@@ -82,6 +141,45 @@ impl AuthMgr {
         session: &mut Session,
         credential: &Credential,
         need_user_info: bool,
+    ) -> Result<(String, Option<String>)> {
+        let res = self
+            .auth_credential(session, credential, need_user_info)
+            .await;
+
+        let login = match credential {
+            Credential::Jwt { client_ip, .. } => Some(("", "jwt", client_ip)),
+            Credential::KeyPair {
+                name, client_ip, ..
+            } => Some((name.as_str(), "key_pair", client_ip)),
+            Credential::Password {
+                name, client_ip, ..
+            } => Some((name.as_str(), "password", client_ip)),
+            // The databend tokens are issued at login, using them is not a login.
+            Credential::DatabendToken { .. } | Credential::NoNeed => None,
+        };
+        if let Some((name, auth_type, client_ip)) = login {
+            let user_name = match &res {
+                Ok((user_name, _)) => user_name.as_str(),
+                Err(_) => name,
+            };
+            log_login(
+                &session.get_type(),
+                user_name,
+                auth_type,
+                client_ip.as_deref(),
+                res.as_ref().err(),
+            );
+        }
+
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn auth_credential(
+        &self,
+        session: &mut Session,
+        credential: &Credential,
+        need_user_info: bool,
     ) -> Result<(String, Option<String>)> {
         let user_api = UserApiProvider::instance();
         match credential {
@@ -159,6 +257,18 @@ impl AuthMgr {
                 session.set_authed_user(user, jwt.custom.role).await?;
                 Ok((user_name, None))
             }
+            Credential::KeyPair {
+                name,
+                token,
+                client_ip,
+            } => {
+                let tenant = session.get_current_tenant();
+                let user = self
+                    .auth_key_pair(&tenant, name, token, client_ip.as_deref())
+                    .await?;
+                session.set_authed_user(user, None).await?;
+                Ok((name.to_string(), None))
+            }
             Credential::Password {
                 name,
                 password: p,
@@ -177,6 +287,7 @@ impl AuthMgr {
                     user.update_auth_need_change_password();
                 }
 
+                let mut ldap_roles = vec![];
                 let authed = match &user.auth_info {
                     AuthInfo::None => Ok(()),
                     AuthInfo::Password {
@@ -193,6 +304,13 @@ impl AuthMgr {
                             }
                         }
                     },
+                    AuthInfo::Ldap => match p {
+                        None => Err(ErrorCode::AuthenticateFailure("password required")),
+                        Some(p) => self
+                            .auth_ldap(&user.name, p)
+                            .await
+                            .map(|roles| ldap_roles = roles),
+                    },
                     _ => Err(ErrorCode::AuthenticateFailure("wrong auth type")),
                 };
                 UserApiProvider::instance()
@@ -201,6 +319,9 @@ impl AuthMgr {
 
                 authed?;
 
+                for role in ldap_roles {
+                    user.grants.grant_role(role);
+                }
                 session.set_authed_user(user, None).await?;
                 Ok((name.to_string(), None))
             }
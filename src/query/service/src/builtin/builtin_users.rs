@@ -51,6 +51,9 @@ impl BuiltinUsers {
             AuthType::JWT => {
                 Self::check_no_auth_string(auth_config.auth_string.clone(), AuthInfo::JWT)
             }
+            AuthType::Ldap => {
+                Self::check_no_auth_string(auth_config.auth_string.clone(), AuthInfo::Ldap)
+            }
             AuthType::Sha256Password | AuthType::DoubleSha1Password => {
                 let password_type = auth_type.get_password_type().expect("must success");
                 match &auth_config.auth_string {
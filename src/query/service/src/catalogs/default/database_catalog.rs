@@ -27,6 +27,8 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
+use databend_common_meta_app::schema::AlterSequenceReply;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::CommitTableMetaReply;
 use databend_common_meta_app::schema::CommitTableMetaReq;
@@ -81,8 +83,11 @@ use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
+use databend_common_meta_app::schema::SequenceMeta;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -648,6 +653,14 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.set_table_column_mask_policy(req).await
     }
 
+    #[async_backtrace::framed]
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        self.mutable_catalog.set_table_row_access_policy(req).await
+    }
+
     // Table index
 
     #[async_backtrace::framed]
@@ -777,6 +790,10 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.get_sequence(req).await
     }
 
+    async fn list_sequences(&self, tenant: &Tenant) -> Result<Vec<(String, SequenceMeta)>> {
+        self.mutable_catalog.list_sequences(tenant).await
+    }
+
     async fn get_sequence_next_value(
         &self,
         req: GetSequenceNextValueReq,
@@ -784,6 +801,10 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.get_sequence_next_value(req).await
     }
 
+    async fn alter_sequence(&self, req: AlterSequenceReq) -> Result<AlterSequenceReply> {
+        self.mutable_catalog.alter_sequence(req).await
+    }
+
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply> {
         self.mutable_catalog.drop_sequence(req).await
     }
@@ -23,6 +23,8 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
+use databend_common_meta_app::schema::AlterSequenceReply;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::CommitTableMetaReply;
 use databend_common_meta_app::schema::CommitTableMetaReq;
@@ -74,8 +76,11 @@ use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
+use databend_common_meta_app::schema::SequenceMeta;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -413,6 +418,17 @@ impl Catalog for ImmutableCatalog {
         )))
     }
 
+    #[async_backtrace::framed]
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        Err(ErrorCode::Unimplemented(format!(
+            "set_table_row_access_policy not allowed for system database {:?}",
+            req
+        )))
+    }
+
     #[async_backtrace::framed]
     async fn list_lock_revisions(&self, _req: ListLockRevReq) -> Result<Vec<(u64, LockMeta)>> {
         Err(ErrorCode::Unimplemented(
@@ -521,6 +537,10 @@ impl Catalog for ImmutableCatalog {
         unimplemented!()
     }
 
+    async fn list_sequences(&self, _tenant: &Tenant) -> Result<Vec<(String, SequenceMeta)>> {
+        unimplemented!()
+    }
+
     async fn get_sequence_next_value(
         &self,
         _req: GetSequenceNextValueReq,
@@ -528,6 +548,10 @@ impl Catalog for ImmutableCatalog {
         unimplemented!()
     }
 
+    async fn alter_sequence(&self, _req: AlterSequenceReq) -> Result<AlterSequenceReply> {
+        unimplemented!()
+    }
+
     async fn drop_sequence(&self, _req: DropSequenceReq) -> Result<DropSequenceReply> {
         unimplemented!()
     }
@@ -16,6 +16,7 @@ mod catalog_context;
 mod database_catalog;
 mod immutable_catalog;
 mod mutable_catalog;
+mod sequence_cache;
 mod session_catalog;
 pub mod table_memory_meta;
 
@@ -30,6 +30,8 @@ use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
 use databend_common_meta_app::schema::index_id_ident::IndexId;
 use databend_common_meta_app::schema::index_id_ident::IndexIdIdent;
+use databend_common_meta_app::schema::AlterSequenceReply;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::CommitTableMetaReply;
 use databend_common_meta_app::schema::CommitTableMetaReq;
@@ -90,8 +92,11 @@ use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
+use databend_common_meta_app::schema::SequenceMeta;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -122,6 +127,7 @@ use log::info;
 use log::warn;
 
 use crate::catalogs::default::catalog_context::CatalogContext;
+use crate::catalogs::default::sequence_cache::SequenceCache;
 use crate::databases::Database;
 use crate::databases::DatabaseContext;
 use crate::databases::DatabaseFactory;
@@ -139,6 +145,8 @@ pub struct MutableCatalog {
     ctx: CatalogContext,
     tenant: Tenant,
     disable_table_info_refresh: bool,
+    // shared by the clones, the catalog is created once per process.
+    sequence_cache: Arc<SequenceCache>,
 }
 
 impl Debug for MutableCatalog {
@@ -193,10 +201,14 @@ impl MutableCatalog {
             storage_factory: Arc::new(storage_factory),
             database_factory: Arc::new(database_factory),
         };
+        let sequence_cache = Arc::new(SequenceCache::default());
+        sequence_cache.spawn_watcher(ctx.meta.clone(), tenant.clone());
+
         Ok(MutableCatalog {
             ctx,
             tenant,
             disable_table_info_refresh: false,
+            sequence_cache,
         })
     }
 
@@ -636,6 +648,13 @@ impl Catalog for MutableCatalog {
         Ok(self.ctx.meta.set_table_column_mask_policy(req).await?)
     }
 
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        Ok(self.ctx.meta.set_table_row_access_policy(req).await?)
+    }
+
     #[async_backtrace::framed]
     async fn get_table_copied_file_info(
         &self,
@@ -698,6 +717,7 @@ impl Catalog for MutableCatalog {
     }
 
     async fn create_sequence(&self, req: CreateSequenceReq) -> Result<CreateSequenceReply> {
+        self.sequence_cache.invalidate(&req.ident);
         Ok(self.ctx.meta.create_sequence(req).await?)
     }
 
@@ -715,14 +735,33 @@ impl Catalog for MutableCatalog {
         })
     }
 
+    async fn list_sequences(&self, tenant: &Tenant) -> Result<Vec<(String, SequenceMeta)>> {
+        Ok(self.ctx.meta.list_sequences(tenant).await?)
+    }
+
     async fn get_sequence_next_value(
         &self,
         req: GetSequenceNextValueReq,
     ) -> Result<GetSequenceNextValueReply> {
-        Ok(self.ctx.meta.get_sequence_next_value(req).await?)
+        if req.count > 0 {
+            if let Some(reply) = self.sequence_cache.take(&req.ident, req.count) {
+                return Ok(reply);
+            }
+        }
+
+        let ident = req.ident.clone();
+        let count = req.count;
+        let reserved = self.ctx.meta.get_sequence_next_value(req).await?;
+        Ok(self.sequence_cache.split(ident, reserved, count))
+    }
+
+    async fn alter_sequence(&self, req: AlterSequenceReq) -> Result<AlterSequenceReply> {
+        self.sequence_cache.invalidate(&req.ident);
+        Ok(self.ctx.meta.alter_sequence(req).await?)
     }
 
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply> {
+        self.sequence_cache.invalidate(&req.ident);
         Ok(self.ctx.meta.drop_sequence(req).await?)
     }
 
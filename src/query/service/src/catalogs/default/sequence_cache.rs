@@ -0,0 +1,218 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_meta_api::deserialize_struct;
+use databend_common_meta_app::schema::GetSequenceNextValueReply;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_meta_app::schema::SequenceMeta;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::DirName;
+use databend_common_meta_kvapi::kvapi::Key;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::protobuf as pb;
+use databend_common_meta_types::protobuf::watch_request::FilterType;
+use databend_common_meta_types::protobuf::Event;
+use databend_common_meta_types::protobuf::WatchRequest;
+use futures::StreamExt;
+use log::warn;
+use parking_lot::Mutex;
+
+// The values `next, next + step, ..., end` are not handed out yet.
+struct CachedRange {
+    next: u64,
+    step: i64,
+    end: u64,
+}
+
+/// Sequence values reserved by this query node but not handed out yet.
+///
+/// For a sequence with `CACHE n`, meta reserves at least `n` values per request. The
+/// values the request does not use are kept here and serve the following requests
+/// without a meta round trip. Cached values are lost when the node restarts, which
+/// leaves gaps in the sequence but never duplicates.
+///
+/// A sequence may be altered or dropped on another query node, the cache watches the
+/// sequences in meta and discards the values of a sequence once it is redefined.
+#[derive(Default)]
+pub struct SequenceCache {
+    ranges: Mutex<HashMap<SequenceIdent, CachedRange>>,
+}
+
+impl SequenceCache {
+    /// Takes `count` values of the sequence from the cache, returns `None` if there
+    /// are not enough cached values.
+    pub fn take(&self, ident: &SequenceIdent, count: u64) -> Option<GetSequenceNextValueReply> {
+        let mut ranges = self.ranges.lock();
+        let range = ranges.get_mut(ident)?;
+        let step = range.step as u64;
+        let remaining = (range.end - range.next) / step + 1;
+        if remaining < count {
+            return None;
+        }
+
+        let reply = GetSequenceNextValueReply {
+            start: range.next,
+            step: range.step,
+            end: range.next + (count - 1) * step,
+        };
+        if reply.end == range.end {
+            ranges.remove(ident);
+        } else {
+            range.next = reply.end + step;
+        }
+        Some(reply)
+    }
+
+    /// Returns the first `count` values of the range reserved from meta, and caches
+    /// the rest, which replace the values cached before.
+    pub fn split(
+        &self,
+        ident: SequenceIdent,
+        reserved: GetSequenceNextValueReply,
+        count: u64,
+    ) -> GetSequenceNextValueReply {
+        let step = reserved.step as u64;
+        let reply = GetSequenceNextValueReply {
+            start: reserved.start,
+            step: reserved.step,
+            end: reserved.start + (count - 1) * step,
+        };
+
+        let mut ranges = self.ranges.lock();
+        if reply.end < reserved.end {
+            ranges.insert(ident, CachedRange {
+                next: reply.end + step,
+                step: reserved.step,
+                end: reserved.end,
+            });
+        } else {
+            ranges.remove(&ident);
+        }
+        reply
+    }
+
+    /// Discards the cached values of the sequence, they are stale after the sequence
+    /// is altered or dropped.
+    pub fn invalidate(&self, ident: &SequenceIdent) {
+        self.ranges.lock().remove(ident);
+    }
+
+    fn clear(&self) {
+        self.ranges.lock().clear();
+    }
+
+    /// Watches the sequences of the tenant in meta, and invalidates the cached values
+    /// of a sequence when it is altered or dropped by any query node.
+    ///
+    /// The watch stops when the cache is dropped. Embedded meta serves only this node,
+    /// where [`SequenceCache::invalidate`] is called by the DDL itself.
+    pub fn spawn_watcher(self: &Arc<Self>, meta: MetaStore, tenant: Tenant) {
+        if !matches!(meta, MetaStore::R(_)) {
+            return;
+        }
+        let cache = Arc::downgrade(self);
+        GlobalIORuntime::instance()
+            .spawn(async move { Self::watch_loop(cache, meta, tenant).await });
+    }
+
+    async fn watch_loop(cache: Weak<SequenceCache>, meta: MetaStore, tenant: Tenant) {
+        let dir = DirName::new(SequenceIdent::new(&tenant, "dummy"));
+        let prefix = format!("{}/", dir.to_string_key());
+        let key_end = kvapi::prefix_to_range(&prefix).map(|(_, end)| end).ok();
+
+        loop {
+            let req = WatchRequest {
+                key: prefix.clone(),
+                key_end: key_end.clone(),
+                filter_type: FilterType::All.into(),
+                initial_flush: false,
+                since_seq: None,
+            };
+
+            match meta.watch(req).await {
+                Ok(mut stream) => {
+                    // the changes before the watch is established are unknown.
+                    match cache.upgrade() {
+                        Some(cache) => cache.clear(),
+                        None => return,
+                    }
+                    while let Some(resp) = stream.next().await {
+                        let Some(cache) = cache.upgrade() else {
+                            return;
+                        };
+                        match resp {
+                            Ok(resp) => {
+                                if let Some(event) = resp.event {
+                                    cache.on_change(event);
+                                }
+                            }
+                            Err(e) => {
+                                warn!("sequence cache watch stream error: {}", e);
+                                break;
+                            }
+                        }
+                    }
+                }
+                Err(e) => warn!("failed to watch sequences: {}", e),
+            }
+
+            // the changes are missed until the watch is re-established.
+            match cache.upgrade() {
+                Some(cache) => cache.clear(),
+                None => return,
+            }
+            sleep(Duration::from_secs(1)).await;
+        }
+    }
+
+    fn on_change(&self, event: Event) {
+        let Ok(ident) = SequenceIdent::from_str_key(&event.key) else {
+            return;
+        };
+        let decode =
+            |v: Option<pb::SeqV>| v.and_then(|v| deserialize_struct::<SequenceMeta>(&v.data).ok());
+        if is_redefined(decode(event.prev), decode(event.current)) {
+            self.invalidate(&ident);
+        }
+    }
+}
+
+/// Returns whether the sequence is altered, dropped or replaced, rather than values
+/// being reserved from it.
+///
+/// A reservation only moves `current` forward by a multiple of `step`. A `RESTART`
+/// that looks the same is not detected, the cached values are all below the new
+/// position and thus still unique.
+fn is_redefined(prev: Option<SequenceMeta>, current: Option<SequenceMeta>) -> bool {
+    let (Some(prev), Some(current)) = (prev, current) else {
+        return true;
+    };
+    let step = prev.step as u64;
+    let is_reservation = prev.create_on == current.create_on
+        && prev.start == current.start
+        && prev.step == current.step
+        && prev.cache == current.cache
+        && step > 0
+        && current.current > prev.current
+        && (current.current - prev.current) % step == 0;
+    !is_reservation
+}
@@ -25,6 +25,8 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
+use databend_common_meta_app::schema::AlterSequenceReply;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::CommitTableMetaReply;
 use databend_common_meta_app::schema::CommitTableMetaReq;
@@ -79,8 +81,11 @@ use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
+use databend_common_meta_app::schema::SequenceMeta;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -491,6 +496,19 @@ impl Catalog for SessionCatalog {
         self.inner.set_table_column_mask_policy(req).await
     }
 
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        if is_temp_table_id(req.table_id) {
+            return Err(ErrorCode::StorageUnsupported(format!(
+                "SetTableRowAccessPolicy: table id {} is a temporary table id",
+                req.table_id
+            )));
+        }
+        self.inner.set_table_row_access_policy(req).await
+    }
+
     async fn create_table_index(&self, req: CreateTableIndexReq) -> Result<()> {
         if is_temp_table_id(req.table_id) {
             return Err(ErrorCode::StorageUnsupported(format!(
@@ -663,6 +681,10 @@ impl Catalog for SessionCatalog {
         self.inner.get_sequence(req).await
     }
 
+    async fn list_sequences(&self, tenant: &Tenant) -> Result<Vec<(String, SequenceMeta)>> {
+        self.inner.list_sequences(tenant).await
+    }
+
     async fn get_sequence_next_value(
         &self,
         req: GetSequenceNextValueReq,
@@ -670,6 +692,10 @@ impl Catalog for SessionCatalog {
         self.inner.get_sequence_next_value(req).await
     }
 
+    async fn alter_sequence(&self, req: AlterSequenceReq) -> Result<AlterSequenceReply> {
+        self.inner.alter_sequence(req).await
+    }
+
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply> {
         self.inner.drop_sequence(req).await
     }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::ops::RangeInclusive;
@@ -19,6 +20,7 @@ use std::str::FromStr;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::Weak;
 use std::time::Duration;
 use std::time::Instant;
 
@@ -40,8 +42,10 @@ use databend_common_exception::Result;
 use databend_common_grpc::ConnectionFactory;
 use databend_common_management::ClusterApi;
 use databend_common_management::ClusterMgr;
+use databend_common_management::NodeEvent;
 use databend_common_meta_store::MetaStore;
 use databend_common_meta_store::MetaStoreProvider;
+use databend_common_meta_types::seq_value::SeqV;
 use databend_common_meta_types::MatchSeq;
 use databend_common_meta_types::NodeInfo;
 use databend_common_metrics::cluster::*;
@@ -50,7 +54,9 @@ use futures::future::Either;
 use futures::Future;
 use futures::StreamExt;
 use log::error;
+use log::info;
 use log::warn;
+use parking_lot::RwLock;
 use rand::thread_rng;
 use rand::Rng;
 use serde::Deserialize;
@@ -63,11 +69,25 @@ pub struct ClusterDiscovery {
     local_secret: String,
     heartbeat: Mutex<ClusterHeartbeat>,
     api_provider: Arc<dyn ClusterApi>,
+    nodes_cache: Arc<NodesCache>,
     cluster_id: String,
     tenant_id: String,
     flight_address: String,
 }
 
+/// A node in [`NodesCache`], with the expiration time in milliseconds.
+#[derive(Clone)]
+struct CachedNode {
+    expire_at_ms: Option<u64>,
+    node: NodeInfo,
+}
+
+/// The nodes of the cluster, kept up to date by watching the meta store.
+///
+/// It is `None` when the watch stream is not established,
+/// in which case the nodes have to be read from the meta store.
+type NodesCache = RwLock<Option<BTreeMap<String, CachedNode>>>;
+
 // avoid leak FlightClient to common-xxx
 #[async_trait::async_trait]
 pub trait ClusterHelper {
@@ -187,10 +207,17 @@ impl ClusterDiscovery {
     ) -> Result<Arc<ClusterDiscovery>> {
         let (lift_time, provider) = Self::create_provider(cfg, metastore)?;
 
+        let nodes_cache = Arc::new(RwLock::new(None));
+        databend_common_base::runtime::spawn(Self::watch_nodes_loop(
+            provider.clone(),
+            Arc::downgrade(&nodes_cache),
+        ));
+
         Ok(Arc::new(ClusterDiscovery {
             local_id: cfg.query.node_id.clone(),
             local_secret: cfg.query.node_secret.clone(),
             api_provider: provider.clone(),
+            nodes_cache,
             heartbeat: Mutex::new(ClusterHeartbeat::create(
                 lift_time,
                 provider,
@@ -221,9 +248,104 @@ impl ClusterDiscovery {
         Ok((lift_time, Arc::new(cluster_manager)))
     }
 
+    /// Keep `nodes_cache` up to date with the watch stream of the cluster nodes,
+    /// until the cache is dropped.
+    ///
+    /// When the stream breaks, it resumes from the last seen seq,
+    /// or re-watches from scratch if resuming fails.
+    #[async_backtrace::framed]
+    async fn watch_nodes_loop(cluster_api: Arc<dyn ClusterApi>, cache: Weak<NodesCache>) {
+        let mut nodes = BTreeMap::new();
+        let mut last_seq = None;
+
+        while cache.strong_count() > 0 {
+            let mut strm = match cluster_api.watch_nodes(last_seq).await {
+                Ok(Some(strm)) => strm,
+                Ok(None) => {
+                    info!("Meta store does not support watching, cluster nodes are not cached");
+                    return;
+                }
+                Err(cause) => {
+                    warn!(
+                        "Cannot watch cluster nodes since seq {:?}, cause {:?}",
+                        last_seq, cause
+                    );
+
+                    // The changes since `last_seq` may be no longer available, watch from scratch.
+                    last_seq = None;
+                    tokio_async_sleep(Duration::from_secs(1)).await;
+                    continue;
+                }
+            };
+
+            if last_seq.is_none() {
+                nodes.clear();
+            }
+
+            while let Some(event) = strm.next().await {
+                let Some(cache) = cache.upgrade() else {
+                    return;
+                };
+
+                let is_ready = matches!(event, Ok(NodeEvent::Ready));
+
+                match event {
+                    Ok(NodeEvent::Upsert {
+                        seq,
+                        expire_at_ms,
+                        node,
+                    }) => {
+                        last_seq = Some(std::cmp::max(last_seq.unwrap_or_default(), seq));
+                        nodes.insert(node.id.clone(), CachedNode { expire_at_ms, node });
+                    }
+                    Ok(NodeEvent::Delete { node_id }) => {
+                        nodes.remove(&node_id);
+                    }
+                    Ok(NodeEvent::Ready) => {}
+                    Err(cause) => {
+                        warn!("Cluster nodes watch stream error, cause {:?}", cause);
+                        break;
+                    }
+                }
+
+                // The cache is published once all the present or missed nodes are received.
+                let mut cached = cache.write();
+                if is_ready || cached.is_some() {
+                    *cached = Some(nodes.clone());
+                }
+            }
+
+            // Until the stream is re-established, read nodes from the meta store.
+            if let Some(cache) = cache.upgrade() {
+                *cache.write() = None;
+            }
+            tokio_async_sleep(Duration::from_secs(1)).await;
+        }
+    }
+
+    /// Returns the non-expired nodes in the cache, or `None` if the cache is not ready.
+    fn cached_nodes(&self) -> Option<Vec<NodeInfo>> {
+        let cache = self.nodes_cache.read();
+        let nodes = cache.as_ref()?;
+
+        let now_ms = SeqV::<()>::now_ms();
+        let nodes = nodes
+            .values()
+            .filter(|n| n.expire_at_ms.map_or(true, |t| t > now_ms))
+            .map(|n| n.node.clone())
+            .collect();
+
+        Some(nodes)
+    }
+
     #[async_backtrace::framed]
     pub async fn discover(&self, config: &InnerConfig) -> Result<Arc<Cluster>> {
-        match self.api_provider.get_nodes().await {
+        let nodes = match self.cached_nodes() {
+            Some(nodes) => Ok(nodes),
+            None => self.api_provider.get_nodes().await,
+        };
+
+        match nodes {
             Err(cause) => {
                 metric_incr_cluster_error_count(
                     &self.local_id,
@@ -0,0 +1,724 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_ast::ast::quote::display_ident;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TableExt;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::DynamicTableRefresh;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::DynamicTableQuery;
+use databend_common_sql::DynamicTableQueryShape;
+use databend_common_sql::DynamicTableSourceRead;
+use databend_common_sql::MaterializedViewRegistry;
+use databend_common_sql::NameResolutionContext;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::DynamicTableRefreshState;
+use databend_storages_common_table_meta::table::OPT_KEY_AS_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_MODE;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_STATE;
+use databend_storages_common_table_meta::table::OPT_KEY_TARGET_LAG;
+use futures_util::StreamExt;
+use itertools::Itertools;
+use log::info;
+use log::warn;
+
+use crate::interpreters::interpreter_plan_sql;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+
+/// What starts a refresh of a dynamic table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshTrigger {
+    /// `INITIALIZE = ON_CREATE`.
+    Creation,
+    /// `ALTER DYNAMIC TABLE ... REFRESH`.
+    Manual,
+    /// The task that keeps the table within its `TARGET_LAG`.
+    Scheduled,
+}
+
+impl Display for RefreshTrigger {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RefreshTrigger::Creation => write!(f, "CREATION"),
+            RefreshTrigger::Manual => write!(f, "MANUAL"),
+            RefreshTrigger::Scheduled => write!(f, "SCHEDULED"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RefreshAction {
+    /// The base tables are not changed.
+    NoData,
+    /// Apply the changes of the base tables to the table.
+    Incremental,
+    /// Overwrite the table with the result of its query.
+    Full,
+}
+
+impl Display for RefreshAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RefreshAction::NoData => write!(f, "NO_DATA"),
+            RefreshAction::Incremental => write!(f, "INCREMENTAL"),
+            RefreshAction::Full => write!(f, "FULL"),
+        }
+    }
+}
+
+/// A base table of a dynamic table, at the start of a refresh.
+struct SourceTable {
+    name: String,
+    ident: String,
+    table_id: u64,
+    snapshot: Option<String>,
+    is_fuse: bool,
+    change_tracking: bool,
+}
+
+pub fn is_dynamic_table(table: &dyn Table) -> bool {
+    table.options().contains_key(OPT_KEY_TARGET_LAG)
+}
+
+/// Refreshes dynamic tables by running their queries over the snapshots of the base tables.
+///
+/// The changes of the base tables since the last refresh are read through `CHANGES`,
+/// and applied with `INSERT` for filters and projections, or with `MERGE` for aggregations,
+/// by recomputing the groups they touch. Other queries are refreshed with `INSERT OVERWRITE`.
+pub struct DynamicTableRefresher {
+    ctx: Arc<QueryContext>,
+    trigger: RefreshTrigger,
+}
+
+impl DynamicTableRefresher {
+    pub fn create(ctx: Arc<QueryContext>, trigger: RefreshTrigger) -> Self {
+        DynamicTableRefresher { ctx, trigger }
+    }
+
+    /// Refresh a dynamic table, after refreshing the dynamic tables with
+    /// `TARGET_LAG = DOWNSTREAM` that it reads from.
+    ///
+    /// The table is resolved by name once, then by id, so that a concurrent rename
+    /// does not make the refresh save its state to another table.
+    #[async_backtrace::framed]
+    pub async fn refresh(&self, catalog: &str, database: &str, table: &str) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let table = self
+            .ctx
+            .get_catalog(catalog)
+            .await?
+            .get_table(&tenant, database, table)
+            .await?;
+        let mut refreshed = HashSet::new();
+        self.refresh_table(catalog, database, table, &mut refreshed)
+            .await
+    }
+
+    #[async_recursion::async_recursion]
+    async fn refresh_table(
+        &self,
+        catalog_name: &str,
+        database: &str,
+        table: Arc<dyn Table>,
+        refreshed: &mut HashSet<u64>,
+    ) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let table_name = table.name().to_string();
+        if !is_dynamic_table(table.as_ref()) {
+            return Err(ErrorCode::IllegalDynamicTable(format!(
+                "Table {database}.{table_name} is not a dynamic table"
+            )));
+        }
+        // A table read by several dynamic tables is refreshed once.
+        if !refreshed.insert(table.get_id()) {
+            return Ok(());
+        }
+
+        let mut record = DynamicTableRefresh {
+            refresh_id: self.ctx.get_id(),
+            table_id: table.get_id(),
+            database: database.to_string(),
+            table: table_name.clone(),
+            refresh_action: "".to_string(),
+            refresh_trigger: self.trigger.to_string(),
+            state: "".to_string(),
+            message: "".to_string(),
+            started_at: Utc::now().timestamp_micros(),
+            completed_at: 0,
+        };
+
+        let result = self
+            .refresh_once(catalog_name, database, table, refreshed, &mut record)
+            .await;
+        record.completed_at = Utc::now().timestamp_micros();
+        match &result {
+            Ok(_) => record.state = "SUCCEEDED".to_string(),
+            Err(cause) => {
+                record.state = "FAILED".to_string();
+                record.message = cause.message();
+            }
+        }
+        info!(
+            "Refresh dynamic table {}.{}: action {}, state {}, {}",
+            database, table_name, record.refresh_action, record.state, record.message
+        );
+
+        let refresh_api = UserApiProvider::instance().dynamic_table_refresh_api(&tenant)?;
+        if let Err(cause) = refresh_api.upsert_refresh(&record).await {
+            warn!(
+                "Failed to record the refresh of dynamic table {}.{}: {:?}",
+                database, table_name, cause
+            );
+        }
+
+        result
+    }
+
+    /// Enable change tracking on the fuse base tables of a dynamic table that
+    /// may be refreshed incrementally, so that their changes can be read.
+    #[async_backtrace::framed]
+    pub async fn enable_change_tracking(
+        &self,
+        catalog_name: &str,
+        database: &str,
+        table_name: &str,
+    ) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let table = catalog.get_table(&tenant, database, table_name).await?;
+        let query = self.dynamic_table_query(table.as_ref())?;
+        let full_only = table
+            .options()
+            .get(OPT_KEY_REFRESH_MODE)
+            .is_some_and(|mode| mode == "FULL");
+        if full_only || query.shape().is_err() {
+            return Ok(());
+        }
+
+        for source in query.sources() {
+            let source_catalog = self
+                .ctx
+                .get_catalog(source.catalog.as_deref().unwrap_or(catalog_name))
+                .await?;
+            let source_database = source.database.as_deref().unwrap_or(database);
+            let source_table = source_catalog
+                .get_table(&tenant, source_database, &source.table)
+                .await?;
+            if FuseTable::try_from_table(source_table.as_ref()).is_err()
+                || source_table.change_tracking_enabled()
+            {
+                continue;
+            }
+
+            let table_seq = source_table.get_table_info().ident.seq;
+            let req = UpsertTableOptionReq {
+                table_id: source_table.get_id(),
+                seq: MatchSeq::Exact(table_seq),
+                options: HashMap::from([
+                    (
+                        OPT_KEY_CHANGE_TRACKING.to_string(),
+                        Some("true".to_string()),
+                    ),
+                    (
+                        OPT_KEY_CHANGE_TRACKING_BEGIN_VER.to_string(),
+                        Some(table_seq.to_string()),
+                    ),
+                ]),
+            };
+            source_catalog
+                .upsert_table_option(&tenant, source_database, req)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn refresh_once(
+        &self,
+        catalog_name: &str,
+        database: &str,
+        table: Arc<dyn Table>,
+        refreshed: &mut HashSet<u64>,
+        record: &mut DynamicTableRefresh,
+    ) -> Result<()> {
+        let query = self.dynamic_table_query(table.as_ref())?;
+
+        let tenant = self.ctx.get_tenant();
+        for source in query.sources() {
+            let source_catalog = source.catalog.as_deref().unwrap_or(catalog_name);
+            let source_database = source.database.as_deref().unwrap_or(database);
+            let Ok(source_table) = self
+                .ctx
+                .get_catalog(source_catalog)
+                .await?
+                .get_table(&tenant, source_database, &source.table)
+                .await
+            else {
+                // The error is reported when reading the table.
+                continue;
+            };
+            let downstream = source_table
+                .options()
+                .get(OPT_KEY_TARGET_LAG)
+                .is_some_and(|lag| lag == "DOWNSTREAM");
+            if downstream {
+                self.refresh_table(source_catalog, source_database, source_table, refreshed)
+                    .await?;
+            }
+        }
+
+        let sources = self.source_tables(catalog_name, database, &query).await?;
+        let snapshot = snapshot_id(table.as_ref()).await?;
+        let state = table
+            .options()
+            .get(OPT_KEY_REFRESH_STATE)
+            .and_then(|state| serde_json::from_str::<DynamicTableRefreshState>(state).ok());
+
+        let (action, reason) = self
+            .decide_action(table.as_ref(), &query, &sources, &snapshot, state.as_ref())
+            .await?;
+        record.refresh_action = action.to_string();
+        record.message = reason;
+
+        let target = self.display_name(&[database, table.name()])?;
+        match (action, state) {
+            (RefreshAction::NoData, _) => return Ok(()),
+            (RefreshAction::Incremental, Some(state)) => {
+                let result = self
+                    .refresh_incrementally(&target, table.as_ref(), &query, &sources, &state)
+                    .await;
+                if let Err(cause) = result {
+                    warn!(
+                        "Incremental refresh of dynamic table {} failed, fall back to full refresh: {:?}",
+                        target, cause
+                    );
+                    record.refresh_action = RefreshAction::Full.to_string();
+                    record.message = format!("incremental refresh failed: {}", cause.message());
+                    self.refresh_fully(&target, &query, &sources).await?;
+                }
+            }
+            _ => self.refresh_fully(&target, &query, &sources).await?,
+        }
+
+        self.save_state(catalog_name, database, table.as_ref(), &sources)
+            .await
+    }
+
+    async fn decide_action(
+        &self,
+        table: &dyn Table,
+        query: &DynamicTableQuery,
+        sources: &[SourceTable],
+        snapshot: &Option<String>,
+        state: Option<&DynamicTableRefreshState>,
+    ) -> Result<(RefreshAction, String)> {
+        let full = |reason: String| Ok((RefreshAction::Full, reason));
+
+        if table
+            .options()
+            .get(OPT_KEY_REFRESH_MODE)
+            .is_some_and(|mode| mode == "FULL")
+        {
+            return full("REFRESH_MODE is FULL".to_string());
+        }
+        let shape = match query.shape() {
+            Ok(DynamicTableQueryShape::Aggregate { keys }) if keys.is_empty() => {
+                return full("the query has no group keys".to_string());
+            }
+            Ok(shape) => shape,
+            Err(reason) => {
+                return full(format!(
+                    "the query can not be refreshed incrementally: {reason}"
+                ));
+            }
+        };
+        let Some(state) = state else {
+            return full("the table has not been refreshed".to_string());
+        };
+        if &state.snapshot != snapshot {
+            return full("the table has been modified since the last refresh".to_string());
+        }
+        for source in sources {
+            if !source.is_fuse {
+                return full(format!("base table {} is not a fuse table", source.name));
+            }
+            if !state.sources.contains_key(&source.table_id) {
+                return full(format!(
+                    "base table {} has been replaced since the last refresh",
+                    source.name
+                ));
+            }
+        }
+
+        let changed = sources
+            .iter()
+            .filter(|source| state.sources[&source.table_id] != source.snapshot)
+            .collect::<Vec<_>>();
+        if changed.is_empty() {
+            return Ok((
+                RefreshAction::NoData,
+                "the base tables have not changed since the last refresh".to_string(),
+            ));
+        }
+
+        for source in changed.iter() {
+            if !source.change_tracking {
+                return full(format!(
+                    "change tracking is not enabled on base table {}",
+                    source.name
+                ));
+            }
+            // The rows derived from the deleted rows can not be located in the table.
+            if shape == &DynamicTableQueryShape::Project {
+                let (Some(begin), Some(end)) = (&state.sources[&source.table_id], &source.snapshot)
+                else {
+                    continue;
+                };
+                let sql = format!(
+                    "SELECT 1 FROM {} CHANGES(INFORMATION => DEFAULT) AT (SNAPSHOT => '{}') END (SNAPSHOT => '{}') WHERE change$action = 'DELETE' LIMIT 1",
+                    source.ident, begin, end
+                );
+                if self.execute_sql(&sql).await? > 0 {
+                    return full(format!(
+                        "rows of base table {} have been updated or deleted",
+                        source.name
+                    ));
+                }
+            }
+        }
+
+        Ok((
+            RefreshAction::Incremental,
+            format!(
+                "base tables {} have changed",
+                changed.iter().map(|source| &source.name).join(", ")
+            ),
+        ))
+    }
+
+    async fn refresh_fully(
+        &self,
+        target: &str,
+        query: &DynamicTableQuery,
+        sources: &[SourceTable],
+    ) -> Result<()> {
+        let reads = sources
+            .iter()
+            .map(|source| DynamicTableSourceRead::Snapshot(source.snapshot.clone()))
+            .collect::<Vec<_>>();
+        let sql = format!(
+            "INSERT OVERWRITE {} {}",
+            target,
+            query.query_with_sources(&reads)
+        );
+        self.execute_sql(&sql).await?;
+        Ok(())
+    }
+
+    async fn refresh_incrementally(
+        &self,
+        target: &str,
+        table: &dyn Table,
+        query: &DynamicTableQuery,
+        sources: &[SourceTable],
+        state: &DynamicTableRefreshState,
+    ) -> Result<()> {
+        let append_only = matches!(query.shape(), Ok(DynamicTableQueryShape::Project));
+        // The delta of a join of the sources is the union of the join of each source's changes
+        // with the new version of the sources before it and the old version of the sources after it.
+        let mut terms = vec![];
+        for (i, source) in sources.iter().enumerate() {
+            if state.sources[&source.table_id] == source.snapshot {
+                continue;
+            }
+            let mut reads = Vec::with_capacity(sources.len());
+            for (j, other) in sources.iter().enumerate() {
+                let old = &state.sources[&other.table_id];
+                let read = match (j.cmp(&i), old, &other.snapshot) {
+                    (Ordering::Less, _, Some(_)) => {
+                        DynamicTableSourceRead::Snapshot(other.snapshot.clone())
+                    }
+                    (Ordering::Equal, _, Some(end)) => DynamicTableSourceRead::Changes {
+                        begin: old.clone(),
+                        end: end.clone(),
+                        append_only,
+                    },
+                    (Ordering::Greater, Some(_), _) => {
+                        DynamicTableSourceRead::Snapshot(old.clone())
+                    }
+                    // Joined with an empty table.
+                    _ => break,
+                };
+                reads.push(read);
+            }
+            if reads.len() == sources.len() {
+                terms.push(reads);
+            }
+        }
+        if terms.is_empty() {
+            return Ok(());
+        }
+
+        let sql = match query.shape() {
+            Ok(DynamicTableQueryShape::Aggregate { keys }) => {
+                self.merge_groups_sql(target, table, query, sources, keys, &terms)?
+            }
+            _ => format!(
+                "INSERT INTO {} {}",
+                target,
+                terms
+                    .iter()
+                    .map(|reads| query.query_with_sources(reads).to_string())
+                    .join(" UNION ALL ")
+            ),
+        };
+        self.execute_sql(&sql).await?;
+        Ok(())
+    }
+
+    /// Build the `MERGE` that recomputes the groups touched by the changes of the base tables:
+    /// the groups that are gone are deleted, the others are updated or inserted.
+    fn merge_groups_sql(
+        &self,
+        target: &str,
+        table: &dyn Table,
+        query: &DynamicTableQuery,
+        sources: &[SourceTable],
+        keys: &[usize],
+        terms: &[Vec<DynamicTableSourceRead>],
+    ) -> Result<String> {
+        let schema = table.schema();
+        let fields = schema.fields();
+        let column = |i: usize| format!("_dt_col_{i}");
+        let key_names = keys
+            .iter()
+            .map(|key| Identifier::from_name(None, column(*key)))
+            .collect::<Vec<_>>();
+        let key_eq = |left: &dyn Fn(usize) -> String, right: &str| {
+            keys.iter()
+                .map(|key| {
+                    let (l, r) = (left(*key), format!("{right}.{}", column(*key)));
+                    if fields[*key].data_type().is_nullable() {
+                        // NULL is a group of its own, distinct from every value.
+                        format!("{l} IS NOT DISTINCT FROM {r}")
+                    } else {
+                        format!("{l} = {r}")
+                    }
+                })
+                .join(" AND ")
+        };
+
+        let affected = terms
+            .iter()
+            .map(|reads| query.group_keys_with_sources(reads, &key_names).to_string())
+            .join(" UNION ");
+        let new_reads = sources
+            .iter()
+            .map(|source| DynamicTableSourceRead::Snapshot(source.snapshot.clone()))
+            .collect::<Vec<_>>();
+        let recomputed = query.query_in_groups(
+            &new_reads,
+            &Identifier::from_name(None, "_dt_affected"),
+            &key_names,
+        );
+
+        let source_columns = (0..fields.len())
+            .map(|i| match keys.contains(&i) {
+                true => format!("_dt_affected.{}", column(i)),
+                false => format!("_dt_new.{}", column(i)),
+            })
+            .join(", ");
+        let target_names = fields
+            .iter()
+            .map(|field| self.display_name(&[field.name().as_str()]))
+            .collect::<Result<Vec<_>>>()?;
+        let updates = (0..fields.len())
+            .filter(|i| !keys.contains(i))
+            .map(|i| format!("{} = _dt_source.{}", target_names[i], column(i)))
+            .join(", ");
+
+        let mut sql = format!(
+            "MERGE INTO {target} AS _dt_target USING (\
+             WITH _dt_affected AS ({affected}) \
+             SELECT {source_columns}, _dt_new._dt_present FROM _dt_affected \
+             LEFT OUTER JOIN (SELECT *, TRUE AS _dt_present FROM ({recomputed}) AS _dt_recomputed({})) AS _dt_new \
+             ON {}) AS _dt_source ON {} \
+             WHEN MATCHED AND _dt_source._dt_present IS NULL THEN DELETE",
+            (0..fields.len()).map(column).join(", "),
+            key_eq(&|key| format!("_dt_affected.{}", column(key)), "_dt_new"),
+            key_eq(
+                &|key| format!("_dt_target.{}", target_names[key]),
+                "_dt_source"
+            ),
+        );
+        if !updates.is_empty() {
+            sql.push_str(&format!(" WHEN MATCHED THEN UPDATE SET {updates}"));
+        }
+        sql.push_str(&format!(
+            " WHEN NOT MATCHED AND _dt_source._dt_present IS NOT NULL THEN INSERT ({}) VALUES ({})",
+            target_names.join(", "),
+            (0..fields.len())
+                .map(|i| format!("_dt_source.{}", column(i)))
+                .join(", ")
+        ));
+        Ok(sql)
+    }
+
+    /// Record the snapshots that the table is refreshed to.
+    async fn save_state(
+        &self,
+        catalog_name: &str,
+        database: &str,
+        table: &dyn Table,
+        sources: &[SourceTable],
+    ) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        // Reload the table by id, the refresh has committed a new snapshot.
+        let table = table.refresh(self.ctx.as_ref()).await?;
+
+        let state = DynamicTableRefreshState {
+            snapshot: snapshot_id(table.as_ref()).await?,
+            sources: sources
+                .iter()
+                .map(|source| (source.table_id, source.snapshot.clone()))
+                .collect(),
+            refreshed_on: Some(Utc::now()),
+        };
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_REFRESH_STATE.to_string(),
+                Some(serde_json::to_string(&state)?),
+            )]),
+        };
+        catalog.upsert_table_option(&tenant, database, req).await?;
+        // The refreshed table may answer other queries now.
+        MaterializedViewRegistry::instance().invalidate(&tenant, catalog_name, database);
+        Ok(())
+    }
+
+    fn dynamic_table_query(&self, table: &dyn Table) -> Result<DynamicTableQuery> {
+        let Some(as_query) = table.options().get(OPT_KEY_AS_QUERY) else {
+            return Err(ErrorCode::IllegalDynamicTable(format!(
+                "Dynamic table {} has no query",
+                table.name()
+            )));
+        };
+        let settings = self.ctx.get_settings();
+        let tokens = tokenize_sql(as_query)?;
+        let (stmt, _) = parse_sql(&tokens, settings.get_sql_dialect()?)?;
+        let Statement::Query(query) = stmt else {
+            return Err(ErrorCode::IllegalDynamicTable(format!(
+                "The AS query of dynamic table {} is not a query",
+                table.name()
+            )));
+        };
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        Ok(DynamicTableQuery::new(*query, &name_resolution_ctx))
+    }
+
+    async fn source_tables(
+        &self,
+        catalog_name: &str,
+        database: &str,
+        query: &DynamicTableQuery,
+    ) -> Result<Vec<SourceTable>> {
+        let tenant = self.ctx.get_tenant();
+        let mut sources = Vec::with_capacity(query.sources().len());
+        for source in query.sources() {
+            let source_catalog = source.catalog.as_deref().unwrap_or(catalog_name);
+            let source_database = source.database.as_deref().unwrap_or(database);
+            let table = self
+                .ctx
+                .get_catalog(source_catalog)
+                .await?
+                .get_table(&tenant, source_database, &source.table)
+                .await?;
+
+            let mut names = vec![source_database, source.table.as_str()];
+            if let Some(catalog) = &source.catalog {
+                names.insert(0, catalog.as_str());
+            }
+            sources.push(SourceTable {
+                name: format!("{}.{}", source_database, source.table),
+                ident: self.display_name(&names)?,
+                table_id: table.get_id(),
+                snapshot: snapshot_id(table.as_ref()).await?,
+                is_fuse: FuseTable::try_from_table(table.as_ref()).is_ok(),
+                change_tracking: table.change_tracking_enabled(),
+            });
+        }
+        Ok(sources)
+    }
+
+    fn display_name(&self, names: &[&str]) -> Result<String> {
+        let settings = self.ctx.get_settings();
+        let quoted_ident_case_sensitive = settings.get_quoted_ident_case_sensitive()?;
+        let sql_dialect = settings.get_sql_dialect()?;
+        Ok(names
+            .iter()
+            .map(|name| display_ident(name, quoted_ident_case_sensitive, sql_dialect))
+            .join("."))
+    }
+
+    /// Execute a sql in a new query of the session and return the number of result rows.
+    async fn execute_sql(&self, sql: &str) -> Result<usize> {
+        let ctx = self
+            .ctx
+            .get_current_session()
+            .create_query_context()
+            .await?;
+        let (plan, _) = interpreter_plan_sql(ctx.clone(), sql).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let mut stream = interpreter.execute(ctx).await?;
+
+        let mut num_rows = 0;
+        while let Some(block) = stream.next().await {
+            num_rows += block?.num_rows();
+        }
+        Ok(num_rows)
+    }
+}
+
+/// The id of the current snapshot of a fuse table, in the format of `AT (SNAPSHOT => '<id>')`.
+async fn snapshot_id(table: &dyn Table) -> Result<Option<String>> {
+    let Ok(fuse_table) = FuseTable::try_from_table(table) else {
+        return Ok(None);
+    };
+    Ok(fuse_table
+        .read_table_snapshot()
+        .await?
+        .map(|snapshot| snapshot.snapshot_id.simple().to_string()))
+}
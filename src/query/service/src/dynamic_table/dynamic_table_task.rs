@@ -0,0 +1,196 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::quote::display_ident;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_cloud_control::client_config::make_request;
+use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
+use databend_common_cloud_control::pb;
+use databend_common_cloud_control::pb::alter_task_request::AlterTaskType;
+use databend_common_cloud_control::pb::AlterTaskRequest;
+use databend_common_cloud_control::pb::CreateTaskRequest;
+use databend_common_cloud_control::pb::DropTaskRequest;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::Result;
+
+use crate::interpreters::common::get_task_client_config;
+use crate::sessions::QueryContext;
+
+/// The name of the task that refreshes a dynamic table on schedule.
+pub fn refresh_task_name(table_id: u64) -> String {
+    format!("_sys_dynamic_table_refresh_{table_id}")
+}
+
+/// Create and resume the task that refreshes a dynamic table every half of its target lag,
+/// so that the table is never staler than the target lag.
+///
+/// Returns false if there is no task service to run the task.
+#[async_backtrace::framed]
+pub async fn create_refresh_task(
+    ctx: Arc<QueryContext>,
+    database: &str,
+    table: &str,
+    table_id: u64,
+    target_lag_secs: u64,
+) -> Result<bool> {
+    let config = GlobalConfig::instance();
+    if !config.query.task_service_enabled() {
+        return Ok(false);
+    }
+
+    let query_text = refresh_task_query(&ctx, database, table)?;
+    let task_name = refresh_task_name(table_id);
+    let tenant_id = ctx.get_tenant().tenant_name().to_string();
+    let owner = ctx
+        .get_current_role()
+        .unwrap_or_default()
+        .identity()
+        .to_string();
+    let interval = (target_lag_secs / 2).clamp(1, i32::MAX as u64) as i32;
+
+    let cloud_api = CloudControlApiProvider::instance();
+    let task_client = cloud_api.get_task_client();
+    let req = CreateTaskRequest {
+        task_name: task_name.clone(),
+        tenant_id: tenant_id.clone(),
+        query_text,
+        owner: owner.clone(),
+        comment: Some(format!("Refresh dynamic table {database}.{table}")),
+        schedule_options: Some(pb::ScheduleOptions {
+            interval: Some(interval),
+            milliseconds_interval: None,
+            cron: None,
+            time_zone: None,
+            schedule_type: i32::from(pb::schedule_options::ScheduleType::IntervalType),
+        }),
+        warehouse_options: None,
+        error_integration: None,
+        success_integration: None,
+        task_sql_type: i32::from(pb::TaskSqlType::Sql),
+        suspend_task_after_num_failures: None,
+        if_not_exist: true,
+        after: vec![],
+        when_condition: None,
+        session_parameters: Default::default(),
+        script_sql: None,
+    };
+    let config = get_task_client_config(ctx.clone(), cloud_api.get_timeout())?;
+    task_client.create_task(make_request(req, config)).await?;
+
+    // Tasks are created suspended.
+    let req = AlterTaskRequest {
+        task_name,
+        tenant_id,
+        owner,
+        alter_task_type: AlterTaskType::Resume as i32,
+        if_exist: false,
+        error_integration: None,
+        success_integration: None,
+        task_sql_type: 0,
+        query_text: None,
+        comment: None,
+        schedule_options: None,
+        warehouse_options: None,
+        suspend_task_after_num_failures: None,
+        when_condition: None,
+        add_after: vec![],
+        remove_after: vec![],
+        set_session_parameters: false,
+        session_parameters: Default::default(),
+        script_sql: None,
+    };
+    let config = get_task_client_config(ctx, cloud_api.get_timeout())?;
+    task_client.alter_task(make_request(req, config)).await?;
+    Ok(true)
+}
+
+/// Point the task that refreshes a dynamic table to its new name, after the table or
+/// its database is renamed.
+#[async_backtrace::framed]
+pub async fn rename_refresh_task(
+    ctx: Arc<QueryContext>,
+    database: &str,
+    table: &str,
+    table_id: u64,
+) -> Result<()> {
+    let config = GlobalConfig::instance();
+    if !config.query.task_service_enabled() {
+        return Ok(());
+    }
+
+    let query_text = refresh_task_query(&ctx, database, table)?;
+    let cloud_api = CloudControlApiProvider::instance();
+    let task_client = cloud_api.get_task_client();
+    let req = AlterTaskRequest {
+        task_name: refresh_task_name(table_id),
+        tenant_id: ctx.get_tenant().tenant_name().to_string(),
+        owner: ctx
+            .get_current_role()
+            .unwrap_or_default()
+            .identity()
+            .to_string(),
+        alter_task_type: AlterTaskType::ModifyAs as i32,
+        if_exist: true,
+        error_integration: None,
+        success_integration: None,
+        task_sql_type: i32::from(pb::TaskSqlType::Sql),
+        query_text: Some(query_text),
+        comment: None,
+        schedule_options: None,
+        warehouse_options: None,
+        suspend_task_after_num_failures: None,
+        when_condition: None,
+        add_after: vec![],
+        remove_after: vec![],
+        set_session_parameters: false,
+        session_parameters: Default::default(),
+        script_sql: None,
+    };
+    let config = get_task_client_config(ctx, cloud_api.get_timeout())?;
+    task_client.alter_task(make_request(req, config)).await?;
+    Ok(())
+}
+
+/// Drop the task that refreshes a dynamic table, if any.
+#[async_backtrace::framed]
+pub async fn drop_refresh_task(ctx: Arc<QueryContext>, table_id: u64) -> Result<()> {
+    let config = GlobalConfig::instance();
+    if !config.query.task_service_enabled() {
+        return Ok(());
+    }
+
+    let cloud_api = CloudControlApiProvider::instance();
+    let task_client = cloud_api.get_task_client();
+    let req = DropTaskRequest {
+        task_name: refresh_task_name(table_id),
+        tenant_id: ctx.get_tenant().tenant_name().to_string(),
+        if_exist: true,
+    };
+    let config = get_task_client_config(ctx, cloud_api.get_timeout())?;
+    task_client.drop_task(make_request(req, config)).await?;
+    Ok(())
+}
+
+fn refresh_task_query(ctx: &QueryContext, database: &str, table: &str) -> Result<String> {
+    let settings = ctx.get_settings();
+    let quoted_ident_case_sensitive = settings.get_quoted_ident_case_sensitive()?;
+    let sql_dialect = settings.get_sql_dialect()?;
+    Ok(format!(
+        "ALTER DYNAMIC TABLE {}.{} REFRESH",
+        display_ident(database, quoted_ident_case_sensitive, sql_dialect),
+        display_ident(table, quoted_ident_case_sensitive, sql_dialect)
+    ))
+}
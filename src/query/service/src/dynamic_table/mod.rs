@@ -0,0 +1,24 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod dynamic_table_refresher;
+mod dynamic_table_task;
+
+pub use dynamic_table_refresher::is_dynamic_table;
+pub use dynamic_table_refresher::DynamicTableRefresher;
+pub use dynamic_table_refresher::RefreshTrigger;
+pub use dynamic_table_task::create_refresh_task;
+pub use dynamic_table_task::drop_refresh_task;
+pub use dynamic_table_task::refresh_task_name;
+pub use dynamic_table_task::rename_refresh_task;
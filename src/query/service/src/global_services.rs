@@ -37,12 +37,16 @@ use databend_common_users::UserApiProvider;
 use databend_storages_common_cache::CacheManager;
 use databend_storages_common_cache::TempDirManager;
 
+use crate::audit::AuditLogger;
 use crate::auth::AuthMgr;
 use crate::builtin::BuiltinUDFs;
 use crate::builtin::BuiltinUsers;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::history::QueryHistoryWriter;
 use crate::locks::LockManager;
+use crate::notification::BuiltinNotificationService;
+use crate::notification::NotificationSender;
 #[cfg(feature = "enable_queries_executor")]
 use crate::pipelines::executor::GlobalQueriesExecutor;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
@@ -50,6 +54,7 @@ use crate::servers::http::v1::ClientSessionManager;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionManager;
+use crate::task::BuiltinTaskService;
 
 pub struct GlobalServices;
 
@@ -151,9 +156,22 @@ impl GlobalServices {
 
         if let Some(addr) = config.query.cloud_control_grpc_server_address.clone() {
             CloudControlApiProvider::init(addr, config.query.cloud_control_grpc_timeout).await?;
+            NotificationSender::init_disabled();
+        } else if config.query.enable_builtin_task_scheduler {
+            let notification_sender = NotificationSender::init(config)?;
+            let task_service = BuiltinTaskService::init(config, notification_sender.clone())?;
+            CloudControlApiProvider::init_builtin(
+                task_service,
+                BuiltinNotificationService::create(notification_sender),
+                config.query.cloud_control_grpc_timeout,
+            );
+        } else {
+            NotificationSender::init_disabled();
         }
 
         ProfilesLogQueue::init(config.query.max_cached_queries_profiles);
+        QueryHistoryWriter::init(config)?;
+        AuditLogger::init(config)?;
 
         #[cfg(feature = "enable_queries_executor")]
         {
@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::SystemTime;
+
+use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_storages_system::SystemLogElement;
+
+use crate::audit::audit_session;
+use crate::history::QueryHistoryWriter;
+use crate::sessions::convert_query_log_timestamp;
+use crate::sessions::SessionType;
+
+/// A login attempt of a user, whether it succeeds or not.
+#[derive(Clone, Debug)]
+pub struct LoginHistoryElement {
+    pub event_time: i64,
+    pub tenant_id: String,
+    pub cluster_id: String,
+    pub node_id: String,
+    pub handler_type: String,
+    pub user_name: String,
+    pub auth_type: String,
+    pub client_address: String,
+    pub success: bool,
+    pub error_code: i32,
+    pub error_message: String,
+}
+
+impl SystemLogElement for LoginHistoryElement {
+    const TABLE_NAME: &'static str = "login_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("cluster_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("handler_type", TableDataType::String),
+            TableField::new("user_name", TableDataType::String),
+            TableField::new("auth_type", TableDataType::String),
+            TableField::new("client_address", TableDataType::String),
+            TableField::new("success", TableDataType::Boolean),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("error_message", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        for value in [
+            &self.tenant_id,
+            &self.cluster_id,
+            &self.node_id,
+            &self.handler_type,
+            &self.user_name,
+            &self.auth_type,
+            &self.client_address,
+        ] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.clone()).as_ref());
+        }
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.success).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Int32(self.error_code)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.error_message.clone()).as_ref());
+        Ok(())
+    }
+}
+
+/// Record a login attempt into `system_history.login_history`, if query history is enabled,
+/// and into the audit log, if it is enabled.
+pub fn log_login(
+    handler_type: &SessionType,
+    user_name: &str,
+    auth_type: &str,
+    client_address: Option<&str>,
+    error: Option<&ErrorCode>,
+) {
+    audit_session("Login", handler_type, user_name, client_address, error);
+
+    let Some(writer) = QueryHistoryWriter::try_instance() else {
+        return;
+    };
+
+    let config = GlobalConfig::instance();
+    writer.append_login(LoginHistoryElement {
+        event_time: convert_query_log_timestamp(SystemTime::now()),
+        tenant_id: config.query.tenant_id.tenant_name().to_string(),
+        cluster_id: config.query.cluster_id.clone(),
+        node_id: config.query.node_id.clone(),
+        handler_type: handler_type.to_string(),
+        user_name: user_name.to_string(),
+        auth_type: auth_type.to_string(),
+        client_address: client_address.unwrap_or_default().to_string(),
+        success: error.is_none(),
+        error_code: error.map(|e| e.code() as i32).unwrap_or_default(),
+        error_message: error.map(|e| e.message()).unwrap_or_default(),
+    });
+}
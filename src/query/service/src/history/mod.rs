@@ -0,0 +1,23 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod login_history;
+mod profile_history;
+mod query_history_writer;
+
+pub use login_history::log_login;
+pub use login_history::LoginHistoryElement;
+pub use profile_history::ProfileHistoryElement;
+pub use query_history_writer::QueryHistoryWriter;
+pub use query_history_writer::HISTORY_DATABASE;
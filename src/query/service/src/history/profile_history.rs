@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_pipeline_core::processors::PlanProfile;
+use databend_common_storages_system::SystemLogElement;
+
+/// The profiles of the plan nodes of a finished query.
+#[derive(Clone)]
+pub struct ProfileHistoryElement {
+    pub event_time: i64,
+    pub node_id: String,
+    pub query_id: String,
+    pub profiles: Vec<PlanProfile>,
+}
+
+impl SystemLogElement for ProfileHistoryElement {
+    const TABLE_NAME: &'static str = "profile_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("profiles", TableDataType::Variant),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let profiles = serde_json::to_value(&self.profiles)?;
+        let profiles: jsonb::Value = (&profiles).into();
+
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.node_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Variant(profiles.to_vec()).as_ref());
+        Ok(())
+    }
+}
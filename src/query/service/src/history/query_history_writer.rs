@@ -0,0 +1,523 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio::sync::Mutex as AsyncMutex;
+use databend_common_base::base::tokio::sync::Notify;
+use databend_common_base::base::tokio::time::timeout;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_config::QueryHistoryConfig;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::TableDataType;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::UpsertKV;
+use databend_common_pipeline_sources::OneBlockSource;
+use databend_common_storages_system::QueryLogElement;
+use databend_common_storages_system::SystemLogElement;
+use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use futures_util::StreamExt;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::audit::AuditEvent;
+use crate::history::LoginHistoryElement;
+use crate::history::ProfileHistoryElement;
+use crate::interpreters::interpreter_plan_sql;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// The database of the history tables.
+pub const HISTORY_DATABASE: &str = "system_history";
+
+/// How often the expired events are removed from the history tables.
+const COMPACT_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// The meta key held by the node that compacts the history tables of the tenant,
+/// it expires a bit earlier than the next compaction, so that any node may take it then.
+const COMPACTOR_KEY_PREFIX: &str = "__fd_query_history_compactor";
+const COMPACTOR_KEY_TTL: Duration = Duration::from_secs(3000);
+
+/// The oldest events are dropped once this many batches are buffered,
+/// e.g., when the history tables can not be written for a while.
+const MAX_BUFFERED_BATCHES: usize = 10;
+
+/// The events of a history table that are not written yet.
+struct HistoryBuffer<Event: SystemLogElement> {
+    table_name: &'static str,
+    /// The column the retention of the events is counted from.
+    time_column: &'static str,
+    batch_size: usize,
+    events: Mutex<VecDeque<Event>>,
+}
+
+impl<Event: SystemLogElement> HistoryBuffer<Event> {
+    fn create(table_name: &'static str, time_column: &'static str, batch_size: usize) -> Self {
+        HistoryBuffer {
+            table_name,
+            time_column,
+            batch_size,
+            events: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    fn max_events(&self) -> usize {
+        self.batch_size * MAX_BUFFERED_BATCHES
+    }
+
+    /// Returns true if a whole batch is buffered.
+    fn push(&self, event: Event) -> bool {
+        let mut events = self.events.lock();
+        if events.len() >= self.max_events() {
+            events.pop_front();
+        }
+        events.push_back(event);
+        events.len() >= self.batch_size
+    }
+
+    /// Put back the events that are failed to write, before the events buffered since then.
+    fn restore(&self, failed: Vec<Event>) {
+        let mut events = self.events.lock();
+        let buffered = std::mem::take(&mut *events);
+        events.extend(failed);
+        events.extend(buffered);
+        while events.len() > self.max_events() {
+            events.pop_front();
+        }
+    }
+
+    fn create_table_sql(&self) -> String {
+        let columns = Event::schema()
+            .fields()
+            .iter()
+            .map(|field| format!("`{}` {}", field.name(), column_type(field.data_type())))
+            .collect::<Vec<_>>();
+
+        format!(
+            "CREATE TABLE IF NOT EXISTS {}.{} ({})",
+            HISTORY_DATABASE,
+            self.table_name,
+            columns.join(", ")
+        )
+    }
+
+    /// Brings a table created by another version up to the schema of the events.
+    ///
+    /// The missing columns are added. If a column changed its type, the table is renamed
+    /// aside with a `_before_<timestamp>` suffix, so that a new table is created.
+    #[async_backtrace::framed]
+    async fn migrate_table(&self, session: &Arc<Session>) -> Result<()> {
+        let ctx = session.create_query_context().await?;
+        let table = ctx
+            .get_table(CATALOG_DEFAULT, HISTORY_DATABASE, self.table_name)
+            .await?;
+        let table_schema = table.schema();
+
+        let mut sqls = vec![];
+        for field in Event::schema().fields() {
+            match table_schema.field_with_name(field.name()) {
+                Ok(existing) if existing.data_type() == field.data_type() => {}
+                Ok(existing) => {
+                    let renamed = format!(
+                        "{}_before_{}",
+                        self.table_name,
+                        chrono::Utc::now().format("%Y%m%d%H%M%S")
+                    );
+                    info!(
+                        "The type of column {}.{}.{} is changed from {} to {}, rename the table to {}",
+                        HISTORY_DATABASE,
+                        self.table_name,
+                        field.name(),
+                        existing.data_type().sql_name(),
+                        field.data_type().sql_name(),
+                        renamed
+                    );
+                    let sql = format!(
+                        "ALTER TABLE {}.{} RENAME TO {}.{}",
+                        HISTORY_DATABASE, self.table_name, HISTORY_DATABASE, renamed
+                    );
+                    execute_sql(session, &sql).await?;
+                    return execute_sql(session, &self.create_table_sql()).await;
+                }
+                Err(_) => sqls.push(format!(
+                    "ALTER TABLE {}.{} ADD COLUMN `{}` {}",
+                    HISTORY_DATABASE,
+                    self.table_name,
+                    field.name(),
+                    column_type(field.data_type())
+                )),
+            }
+        }
+        for sql in sqls {
+            execute_sql(session, &sql).await?;
+        }
+        Ok(())
+    }
+
+    /// The ttl option is set separately, so that the retention of an existing table follows the config.
+    fn set_retention_sql(&self, retention_days: u64) -> String {
+        format!(
+            "ALTER TABLE {}.{} SET OPTIONS(ttl = '{} + INTERVAL {} DAY')",
+            HISTORY_DATABASE, self.table_name, self.time_column, retention_days
+        )
+    }
+
+    fn compact_sql(&self) -> String {
+        format!(
+            "OPTIMIZE TABLE {}.{} COMPACT",
+            HISTORY_DATABASE, self.table_name
+        )
+    }
+
+    #[async_backtrace::framed]
+    async fn flush(&self, session: &Arc<Session>) {
+        let events = self.events.lock().drain(..).collect::<Vec<_>>();
+        if events.is_empty() {
+            return;
+        }
+
+        if let Err(cause) = self.write(session, &events).await {
+            warn!(
+                "Cannot write {} events into {}.{}, cause {:?}",
+                events.len(),
+                HISTORY_DATABASE,
+                self.table_name,
+                cause
+            );
+            self.restore(events);
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&self, session: &Arc<Session>, events: &[Event]) -> Result<()> {
+        let schema = Event::schema();
+        let mut columns = Vec::with_capacity(schema.num_fields());
+        for field in schema.fields() {
+            let data_type: DataType = field.data_type().into();
+            columns.push(ColumnBuilder::with_capacity(&data_type, events.len()));
+        }
+        for event in events {
+            event.fill_to_data_block(&mut columns)?;
+        }
+        let block = DataBlock::new_from_columns(columns.into_iter().map(|c| c.build()).collect());
+
+        let ctx = session.create_query_context().await?;
+        let table = ctx
+            .get_table(CATALOG_DEFAULT, HISTORY_DATABASE, self.table_name)
+            .await?;
+
+        let mut build_res = PipelineBuildResult::create();
+        build_res
+            .main_pipeline
+            .add_source(|output| OneBlockSource::create(output, block.clone()), 1)?;
+        let source_schema: DataSchemaRef = Arc::new(schema.clone().into());
+        PipelineBuilder::build_append2table_with_commit_pipeline(
+            ctx.clone(),
+            &mut build_res.main_pipeline,
+            table,
+            source_schema,
+            None,
+            vec![],
+            false,
+            None,
+        )?;
+
+        let executor_settings = ExecutorSettings::try_create(ctx.clone())?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        ctx.set_executor(executor.get_inner())?;
+        executor.execute()
+    }
+}
+
+fn column_type(data_type: &TableDataType) -> String {
+    match data_type.is_nullable() {
+        true => data_type.sql_name(),
+        false => format!("{} NOT NULL", data_type.sql_name()),
+    }
+}
+
+/// Persists the query log, profile log and login events into the fuse tables of the
+/// `system_history` database, so that they survive restarts and can be queried from every node.
+///
+/// Events are buffered in memory and appended to the tables in batches in the background,
+/// every `flush_interval_secs` or once a whole batch is buffered, and on graceful shutdown.
+/// The tables are created on start with a ttl of `retention_days`, and the expired events are
+/// removed by compacting the tables periodically, by one node of the tenant at a time.
+pub struct QueryHistoryWriter {
+    node_id: String,
+    tenant: String,
+    config: QueryHistoryConfig,
+    query_logs: HistoryBuffer<QueryLogElement>,
+    profiles: HistoryBuffer<ProfileHistoryElement>,
+    logins: HistoryBuffer<LoginHistoryElement>,
+    audit_logs: HistoryBuffer<AuditEvent>,
+    batch_ready: Notify,
+    /// The session the events are written with, created with the tables.
+    /// Held during a flush, so that the buffers are flushed by one task at a time.
+    session: AsyncMutex<Option<Arc<Session>>>,
+}
+
+impl QueryHistoryWriter {
+    pub fn create(node_id: String, tenant: String, config: QueryHistoryConfig) -> Arc<Self> {
+        let batch_size = config.batch_size.max(1);
+        Arc::new(QueryHistoryWriter {
+            node_id,
+            tenant,
+            query_logs: HistoryBuffer::create("query_history", "event_time", batch_size),
+            profiles: HistoryBuffer::create(
+                ProfileHistoryElement::TABLE_NAME,
+                "event_time",
+                batch_size,
+            ),
+            logins: HistoryBuffer::create(
+                LoginHistoryElement::TABLE_NAME,
+                "event_time",
+                batch_size,
+            ),
+            audit_logs: HistoryBuffer::create(AuditEvent::TABLE_NAME, "event_time", batch_size),
+            config,
+            batch_ready: Notify::new(),
+            session: AsyncMutex::new(None),
+        })
+    }
+
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        let writer = config.query.query_history.enabled.then(|| {
+            let writer = Self::create(
+                config.query.node_id.clone(),
+                config.query.tenant_id.tenant_name().to_string(),
+                config.query.query_history.clone(),
+            );
+            writer.start();
+            writer
+        });
+        GlobalInstance::set(writer);
+        Ok(())
+    }
+
+    /// Returns the writer of this node, or None if query history is not enabled.
+    pub fn try_instance() -> Option<Arc<QueryHistoryWriter>> {
+        GlobalInstance::try_get::<Option<Arc<QueryHistoryWriter>>>().flatten()
+    }
+
+    pub fn append_query_log(&self, event: QueryLogElement) {
+        if self.query_logs.push(event) {
+            self.batch_ready.notify_one();
+        }
+    }
+
+    pub fn append_profiles(&self, event: ProfileHistoryElement) {
+        if self.profiles.push(event) {
+            self.batch_ready.notify_one();
+        }
+    }
+
+    pub fn append_login(&self, event: LoginHistoryElement) {
+        if self.logins.push(event) {
+            self.batch_ready.notify_one();
+        }
+    }
+
+    pub fn append_audit(&self, event: AuditEvent) {
+        if self.audit_logs.push(event) {
+            self.batch_ready.notify_one();
+        }
+    }
+
+    /// Write all of the buffered events into the history tables, creating the tables if needed.
+    ///
+    /// Called by the background task, on graceful shutdown, and by tests that read the tables.
+    /// The events that fail to write stay buffered.
+    #[async_backtrace::framed]
+    pub async fn flush(&self) -> Result<()> {
+        let mut session = self.session.lock().await;
+        if session.is_none() {
+            let created = self.create_tables().await?;
+            info!(
+                "Query history is written into database {} every {}s",
+                HISTORY_DATABASE,
+                self.config.flush_interval_secs.max(1)
+            );
+            *session = Some(created);
+        }
+        let Some(session) = session.as_ref() else {
+            return Ok(());
+        };
+
+        self.query_logs.flush(session).await;
+        self.profiles.flush(session).await;
+        self.logins.flush(session).await;
+        self.audit_logs.flush(session).await;
+        Ok(())
+    }
+
+    /// Flush the events of the queries finished before the shutdown.
+    #[async_backtrace::framed]
+    pub async fn shutdown(&self) {
+        if let Err(cause) = self.flush().await {
+            warn!("Cannot flush query history on shutdown, cause {:?}", cause);
+        }
+    }
+
+    fn start(self: &Arc<Self>) {
+        let writer = self.clone();
+        GlobalIORuntime::instance().spawn(async move { writer.write_loop().await });
+    }
+
+    #[async_backtrace::framed]
+    async fn write_loop(self: Arc<Self>) {
+        let flush_interval = Duration::from_secs(self.config.flush_interval_secs.max(1));
+        let mut compacted_at = Instant::now();
+
+        loop {
+            let _ = timeout(flush_interval, self.batch_ready.notified()).await;
+
+            if let Err(cause) = self.flush().await {
+                warn!("Cannot create the query history tables, cause {:?}", cause);
+                continue;
+            }
+
+            if compacted_at.elapsed() >= COMPACT_INTERVAL {
+                compacted_at = Instant::now();
+                if let Err(cause) = self.compact().await {
+                    warn!("Cannot compact query history, cause {:?}", cause);
+                }
+            }
+        }
+    }
+
+    /// Compact the history tables, if no other node of the tenant has done it in this interval.
+    #[async_backtrace::framed]
+    async fn compact(&self) -> Result<()> {
+        if !self.acquire_compactor().await? {
+            return Ok(());
+        }
+
+        let Some(session) = self.session.lock().await.clone() else {
+            return Ok(());
+        };
+        for sql in [
+            self.query_logs.compact_sql(),
+            self.profiles.compact_sql(),
+            self.logins.compact_sql(),
+            self.audit_logs.compact_sql(),
+        ] {
+            if let Err(cause) = execute_sql(&session, &sql).await {
+                warn!("Cannot compact query history, cause {:?}", cause);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns true if this node takes the compactor key, which is only inserted if absent.
+    #[async_backtrace::framed]
+    async fn acquire_compactor(&self) -> Result<bool> {
+        let meta_store = UserApiProvider::instance().get_meta_store_client();
+        let reply = meta_store
+            .upsert_kv(UpsertKV {
+                key: format!("{}/{}", COMPACTOR_KEY_PREFIX, self.tenant),
+                seq: MatchSeq::Exact(0),
+                value: Operation::Update(self.node_id.as_bytes().to_vec()),
+                value_meta: Some(MetaSpec::new_ttl(COMPACTOR_KEY_TTL)),
+            })
+            .await?;
+        Ok(reply.is_changed())
+    }
+
+    /// Create the history tables if they do not exist, and return the session the events are written with.
+    #[async_backtrace::framed]
+    async fn create_tables(&self) -> Result<Arc<Session>> {
+        let session = self.create_session().await?;
+
+        let retention_days = self.config.retention_days;
+        execute_sql(
+            &session,
+            &format!("CREATE DATABASE IF NOT EXISTS {}", HISTORY_DATABASE),
+        )
+        .await?;
+
+        execute_sql(&session, &self.query_logs.create_table_sql()).await?;
+        self.query_logs.migrate_table(&session).await?;
+        execute_sql(&session, &self.query_logs.set_retention_sql(retention_days)).await?;
+
+        execute_sql(&session, &self.profiles.create_table_sql()).await?;
+        self.profiles.migrate_table(&session).await?;
+        execute_sql(&session, &self.profiles.set_retention_sql(retention_days)).await?;
+
+        execute_sql(&session, &self.logins.create_table_sql()).await?;
+        self.logins.migrate_table(&session).await?;
+        execute_sql(&session, &self.logins.set_retention_sql(retention_days)).await?;
+
+        execute_sql(&session, &self.audit_logs.create_table_sql()).await?;
+        self.audit_logs.migrate_table(&session).await?;
+        execute_sql(&session, &self.audit_logs.set_retention_sql(retention_days)).await?;
+
+        Ok(session)
+    }
+
+    #[async_backtrace::framed]
+    async fn create_session(&self) -> Result<Arc<Session>> {
+        let session_manager = SessionManager::instance();
+        let session = session_manager.create_session(SessionType::Dummy).await?;
+        let session = session_manager.register_session(session)?;
+
+        let user = UserInfo::new_no_auth(
+            &format!("{}-{}-query-history", self.tenant, self.node_id),
+            "0.0.0.0",
+        );
+        session
+            .set_authed_user(user, Some(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()))
+            .await?;
+        Ok(session)
+    }
+}
+
+#[async_backtrace::framed]
+async fn execute_sql(session: &Arc<Session>, sql: &str) -> Result<()> {
+    let ctx: Arc<QueryContext> = session.create_query_context().await?;
+    let (plan, _) = interpreter_plan_sql(ctx.clone(), sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let mut stream = interpreter.execute(ctx).await?;
+    while let Some(block) = stream.next().await {
+        block?;
+    }
+    Ok(())
+}
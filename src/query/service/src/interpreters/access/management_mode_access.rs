@@ -87,6 +87,7 @@ impl AccessChecker for ManagementModeAccess {
 
                 // Dynamic table.
                 | Plan::CreateDynamicTable(_)
+                | Plan::RefreshDynamicTable(_)
 
                 // User.
                 | Plan::AlterUser(_)
@@ -115,6 +116,10 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreatePasswordPolicy(_)
                 | Plan::AlterPasswordPolicy(_)
                 | Plan::DropPasswordPolicy(_)
+                // Query hint.
+                | Plan::CreateQueryHint(_)
+                | Plan::DropQueryHint(_)
+                | Plan::ShowQueryHints(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::collections::HashSet;
 use std::sync::Arc;
 
@@ -21,6 +22,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::GetProcedureReq;
 use databend_common_meta_app::principal::GrantObject;
 use databend_common_meta_app::principal::OwnershipInfo;
 use databend_common_meta_app::principal::OwnershipObject;
@@ -29,6 +31,7 @@ use databend_common_meta_app::principal::StageType;
 use databend_common_meta_app::principal::UserGrantSet;
 use databend_common_meta_app::principal::UserPrivilegeSet;
 use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_meta_app::schema::GetSequenceReq;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::seq_value::SeqV;
 use databend_common_sql::binder::MutationType;
@@ -39,7 +42,13 @@ use databend_common_sql::plans::OptimizeCompactBlock;
 use databend_common_sql::plans::PresignAction;
 use databend_common_sql::plans::Recluster;
 use databend_common_sql::plans::RewriteKind;
+use databend_common_sql::BaseTableColumn;
+use databend_common_sql::ColumnEntry;
+use databend_common_sql::ColumnSet;
+use databend_common_sql::IndexType;
+use databend_common_sql::Metadata;
 use databend_common_sql::Planner;
+use databend_common_sql::VirtualColumn;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
@@ -170,12 +179,30 @@ impl PrivilegeAccess {
                 db_id: *db_id,
                 table_id: *table_id,
             },
+            // the owner of a table owns all of its columns
+            GrantObject::Column(catalog_name, db_id, table_id, _) => OwnershipObject::Table {
+                catalog_name: catalog_name.clone(),
+                db_id: *db_id,
+                table_id: *table_id,
+            },
             GrantObject::Stage(name) => OwnershipObject::Stage {
                 name: name.to_string(),
             },
             GrantObject::UDF(name) => OwnershipObject::UDF {
                 name: name.to_string(),
             },
+            GrantObject::Sequence(name) => OwnershipObject::Sequence {
+                name: name.to_string(),
+            },
+            GrantObject::Procedure(procedure_id) => OwnershipObject::Procedure {
+                procedure_id: *procedure_id,
+            },
+            GrantObject::Connection(name) => OwnershipObject::Connection {
+                name: name.to_string(),
+            },
+            GrantObject::FileFormat(name) => OwnershipObject::FileFormat {
+                name: name.to_string(),
+            },
             GrantObject::Global => return Ok(None),
         };
 
@@ -402,6 +429,54 @@ impl PrivilegeAccess {
         Ok(())
     }
 
+    // Fall back to the column-level privileges when the privilege on the whole table is missing,
+    // the access is allowed only if the privilege has been granted on every involved column.
+    async fn validate_table_columns_access(
+        &self,
+        catalog_name: &str,
+        db_name: &str,
+        table_name: &str,
+        columns: &[String],
+        privilege: UserPrivilegeType,
+    ) -> Result<()> {
+        let Err(err) = self
+            .validate_table_access(catalog_name, db_name, table_name, privilege, false, false)
+            .await
+        else {
+            return Ok(());
+        };
+        if err.code() != ErrorCode::PERMISSION_DENIED || columns.is_empty() {
+            return Err(err);
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let ObjectId::Table(db_id, table_id) = self
+            .convert_to_id(&tenant, &catalog, db_name, Some(table_name), false)
+            .await?
+        else {
+            return Err(err);
+        };
+        let schema = catalog
+            .get_table(&tenant, db_name, table_name)
+            .await?
+            .schema();
+        for column in columns {
+            let Ok(field) = schema.field_with_name(column) else {
+                return Err(err);
+            };
+            let object =
+                GrantObject::Column(catalog_name.to_string(), db_id, table_id, field.column_id());
+            if let Err(column_err) = self.validate_access(&object, privilege, false, false).await {
+                if column_err.code() != ErrorCode::PERMISSION_DENIED {
+                    return Err(column_err);
+                }
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
     async fn has_ownership(
         &self,
         session: &Arc<Session>,
@@ -465,7 +540,12 @@ impl PrivilegeAccess {
             | GrantObject::DatabaseById(_, _)
             | GrantObject::UDF(_)
             | GrantObject::Stage(_)
-            | GrantObject::TableById(_, _, _) => true,
+            | GrantObject::TableById(_, _, _)
+            | GrantObject::Column(_, _, _, _)
+            | GrantObject::Sequence(_)
+            | GrantObject::Procedure(_)
+            | GrantObject::Connection(_)
+            | GrantObject::FileFormat(_) => true,
             GrantObject::Global => false,
         };
 
@@ -513,9 +593,14 @@ impl PrivilegeAccess {
                 match grant_object {
                     GrantObject::TableById(_, _, _) => Err(ErrorCode::PermissionDenied("")),
                     GrantObject::DatabaseById(_, _) => Err(ErrorCode::PermissionDenied("")),
+                    GrantObject::Column(_, _, _, _) => Err(ErrorCode::PermissionDenied("")),
                     GrantObject::Global
                     | GrantObject::UDF(_)
                     | GrantObject::Stage(_)
+                    | GrantObject::Sequence(_)
+                    | GrantObject::Procedure(_)
+                    | GrantObject::Connection(_)
+                    | GrantObject::FileFormat(_)
                     | GrantObject::Database(_, _)
                     | GrantObject::Table(_, _, _) => Err(ErrorCode::PermissionDenied(format!(
                         "Permission denied: privilege [{:?}] is required on {} for user {} with roles [{}]. \
@@ -580,6 +665,87 @@ impl PrivilegeAccess {
         Ok(())
     }
 
+    async fn validate_sequence_access(&self, sequence_names: HashSet<&String>) -> Result<()> {
+        // Note: validate_sequence_access is not used for validate Create Sequence
+        for sequence in sequence_names {
+            self.validate_access(
+                &GrantObject::Sequence(sequence.clone()),
+                UserPrivilegeType::Usage,
+                false,
+                false,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    // Without the experimental rbac check, the statements on these objects still require Super.
+    // The owner of the object always passes, `Drop` and `Alter` are only granted globally.
+    async fn validate_object_privilege(
+        &self,
+        grant_object: &GrantObject,
+        privilege: UserPrivilegeType,
+        enable_experimental_rbac_check: bool,
+    ) -> Result<()> {
+        if enable_experimental_rbac_check {
+            self.validate_access(grant_object, privilege, false, false)
+                .await
+        } else {
+            self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
+                .await
+        }
+    }
+
+    async fn validate_object_usage(
+        &self,
+        grant_object: &GrantObject,
+        enable_experimental_rbac_check: bool,
+    ) -> Result<()> {
+        self.validate_object_privilege(
+            grant_object,
+            UserPrivilegeType::Usage,
+            enable_experimental_rbac_check,
+        )
+        .await
+    }
+
+    async fn validate_sequence_privilege(
+        &self,
+        sequence: &str,
+        privilege: UserPrivilegeType,
+        enable_experimental_rbac_check: bool,
+    ) -> Result<()> {
+        self.validate_object_privilege(
+            &GrantObject::Sequence(sequence.to_string()),
+            privilege,
+            enable_experimental_rbac_check,
+        )
+        .await
+    }
+
+    // The connections and file formats referenced by the statement are recorded while binding.
+    async fn validate_connection_and_file_format_access(&self) -> Result<()> {
+        for connection in self.ctx.get_used_connections() {
+            self.validate_access(
+                &GrantObject::Connection(connection),
+                UserPrivilegeType::Usage,
+                false,
+                false,
+            )
+            .await?;
+        }
+        for file_format in self.ctx.get_used_file_formats() {
+            self.validate_access(
+                &GrantObject::FileFormat(file_format),
+                UserPrivilegeType::Usage,
+                false,
+                false,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
     async fn validate_table_function_access(&self, table_func_name: &str) -> Result<()> {
         if SYSTEM_TABLE_FUNCTIONS.iter().any(|x| x == &table_func_name) {
             // need Super privilege to invoke system table functions
@@ -699,11 +865,16 @@ impl AccessChecker for PrivilegeAccess {
         let tenant = self.ctx.get_tenant();
         let ctl_name = self.ctx.get_current_catalog();
 
+        if enable_experimental_rbac_check {
+            self.validate_connection_and_file_format_access().await?;
+        }
+
         match plan {
             Plan::Query {
                 metadata,
                 rewrite_kind,
                 s_expr,
+                bind_context,
                 ..
             } => {
                 match rewrite_kind {
@@ -792,9 +963,15 @@ impl AccessChecker for PrivilegeAccess {
                             return Err(err.add_message("get udf error on validating access"));
                         }
                     }
+                    let sequences = s_expr.get_sequences();
+                    if !sequences.is_empty() {
+                        self.validate_sequence_access(sequences).await?;
+                    }
                 }
 
                 let metadata = metadata.read().clone();
+                let mut used_columns = s_expr.get_used_columns()?;
+                used_columns.extend(bind_context.columns.iter().map(|column| column.index));
 
                 for table in metadata.tables() {
                     if enable_experimental_rbac_check && table.is_source_of_stage() {
@@ -811,7 +988,8 @@ impl AccessChecker for PrivilegeAccess {
                             DataSourceInfo::TableSource(_) | DataSourceInfo::ResultScanSource(_) => {}
                         }
                     }
-                    if table.is_source_of_view()||table.table().is_temp() {
+                    // The tables bound for indexes and materialized views are read on behalf of the query tables.
+                    if table.is_source_of_view() || table.is_source_of_index() || table.table().is_temp() {
                         continue;
                     }
 
@@ -819,7 +997,8 @@ impl AccessChecker for PrivilegeAccess {
                     // like this sql: copy into t from (select * from @s3); will bind a mock table with name `system.read_parquet(s3)`
                     // this is no means to check table `system.read_parquet(s3)` privilege
                     if !table.is_source_of_stage() {
-                        self.validate_table_access(catalog_name, table.database(), table.name(), UserPrivilegeType::Select, false, false).await?
+                        let columns = referenced_table_columns(&metadata, &used_columns, table.index());
+                        self.validate_table_columns_access(catalog_name, table.database(), table.name(), &columns, UserPrivilegeType::Select).await?
                     }
                 }
             }
@@ -988,6 +1167,12 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropTableClusterKey(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Drop, false, false).await?
             }
+            Plan::AddTableRowAccessPolicy(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
+            Plan::DropTableRowAccessPolicy(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
             Plan::ReclusterTable{s_expr, ..} => {
                 let plan: Recluster = s_expr.plan().clone().try_into()?;
                 // UDF has been disabled in recluster, no need to check udf privilege access.
@@ -996,6 +1181,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::TruncateTable(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Delete, false, false).await?
             }
+            Plan::DropTablePartition(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Delete, false, false).await?
+            }
             Plan::OptimizePurge(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Super, false, false).await?
             },
@@ -1032,8 +1220,10 @@ impl AccessChecker for PrivilegeAccess {
                 } else {
                     vec![UserPrivilegeType::Insert]
                 };
+                let insert_columns = plan.schema.fields().iter().map(|field| field.name().clone()).collect::<Vec<_>>();
                 for privilege in target_table_privileges {
-                    self.validate_table_access(&plan.catalog, &plan.database, &plan.table, privilege, false, false).await?;
+                    let columns = if privilege == UserPrivilegeType::Insert { insert_columns.as_slice() } else { &[] };
+                    self.validate_table_columns_access(&plan.catalog, &plan.database, &plan.table, columns, privilege).await?;
                 }
                 self.validate_insert_source(ctx, &plan.source).await?;
             }
@@ -1102,8 +1292,42 @@ impl AccessChecker for PrivilegeAccess {
                     MutationType::Update => vec![UserPrivilegeType::Update],
                     MutationType::Delete => vec![UserPrivilegeType::Delete],
                 };
+                // UPDATE could be allowed by the privileges granted on the updated columns.
+                let update_columns = if plan.mutation_type == MutationType::Update {
+                    let metadata = plan.metadata.read();
+                    let schema = metadata.table(plan.target_table_index).table().schema();
+                    plan.matched_evaluators
+                        .iter()
+                        .filter_map(|evaluator| evaluator.update.as_ref())
+                        .flat_map(|update| update.keys())
+                        .map(|index| schema.field(*index).name().clone())
+                        .collect::<HashSet<_>>()
+                        .into_iter()
+                        .collect::<Vec<_>>()
+                } else {
+                    vec![]
+                };
                 for privilege in privileges {
-                    self.validate_table_access(&plan.catalog_name, &plan.database_name, &plan.table_name, privilege, false, false).await?;
+                    self.validate_table_columns_access(&plan.catalog_name, &plan.database_name, &plan.table_name, &update_columns, privilege).await?;
+                }
+                // UPDATE reads the columns in the WHERE clause and on the right-hand side of the assignments.
+                if plan.mutation_type == MutationType::Update {
+                    let mut read_columns = s_expr.child(0)?.get_used_columns()?;
+                    if let Some(filter) = &plan.direct_filter {
+                        read_columns.extend(filter.used_columns());
+                    }
+                    for evaluator in &plan.matched_evaluators {
+                        if let Some(condition) = &evaluator.condition {
+                            read_columns.extend(condition.used_columns());
+                        }
+                        for scalar in evaluator.update.iter().flat_map(|update| update.values()) {
+                            read_columns.extend(scalar.used_columns());
+                        }
+                    }
+                    let read_columns = referenced_table_columns(&plan.metadata.read(), &read_columns, plan.target_table_index);
+                    if !read_columns.is_empty() {
+                        self.validate_table_columns_access(&plan.catalog_name, &plan.database_name, &plan.table_name, &read_columns, UserPrivilegeType::Select).await?;
+                    }
                 }
             }
             Plan::CreateView(plan) => {
@@ -1132,6 +1356,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::CreateDynamicTable(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?;
             }
+            Plan::RefreshDynamicTable(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
             Plan::CreateUser(_) => {
                 self.validate_access(
                     &GrantObject::Global,
@@ -1183,6 +1410,7 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterUDF(_)
             | Plan::RefreshIndex(_)
             | Plan::RefreshTableIndex(_)
+            | Plan::RefreshTableNgramIndex(_)
             | Plan::AlterUser(_) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Alter, false, false)
                     .await?;
@@ -1206,7 +1434,6 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::CreateCatalog(_)
             | Plan::DropCatalog(_)
             | Plan::CreateFileFormat(_)
-            | Plan::DropFileFormat(_)
             | Plan::ShowFileFormats(_)
             | Plan::CreateNetworkPolicy(_)
             | Plan::AlterNetworkPolicy(_)
@@ -1217,10 +1444,11 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterPasswordPolicy(_)
             | Plan::DropPasswordPolicy(_)
             | Plan::DescPasswordPolicy(_)
+            | Plan::CreateQueryHint(_)
+            | Plan::DropQueryHint(_)
+            | Plan::ShowQueryHints(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
-            | Plan::DescConnection(_)
-            | Plan::DropConnection(_)
             | Plan::CreateIndex(_)
             | Plan::CreateTableIndex(_)
             | Plan::CreateNotification(_)
@@ -1234,11 +1462,39 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::ExecuteTask(_)  // TODO: need to build ownership info for task
             | Plan::DropTask(_)     // TODO: need to build ownership info for task
             | Plan::AlterTask(_)
-            | Plan::CreateSequence(_)
-            | Plan::DropSequence(_) => {
+            | Plan::CreateSequence(_) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
                     .await?;
             }
+            Plan::DropSequence(plan) => {
+                let catalog = self.ctx.get_default_catalog()?;
+                let req = GetSequenceReq { ident: plan.ident.clone() };
+                if plan.if_exists && catalog.get_sequence(req).await.is_err() {
+                    return Ok(());
+                }
+                self.validate_sequence_privilege(plan.ident.name(), UserPrivilegeType::Drop, enable_experimental_rbac_check).await?;
+            }
+            Plan::AlterSequence(plan) => {
+                self.validate_sequence_privilege(plan.ident.name(), UserPrivilegeType::Alter, enable_experimental_rbac_check).await?;
+            }
+            Plan::DescSequence(plan) => {
+                self.validate_sequence_privilege(plan.ident.name(), UserPrivilegeType::Usage, enable_experimental_rbac_check).await?;
+            }
+            Plan::DescConnection(plan) => {
+                self.validate_object_usage(&GrantObject::Connection(plan.name.clone()), enable_experimental_rbac_check).await?;
+            }
+            Plan::DropConnection(plan) => {
+                if plan.if_exists && UserApiProvider::instance().get_connection(&tenant, &plan.name).await.is_err() {
+                    return Ok(());
+                }
+                self.validate_object_privilege(&GrantObject::Connection(plan.name.clone()), UserPrivilegeType::Drop, enable_experimental_rbac_check).await?;
+            }
+            Plan::DropFileFormat(plan) => {
+                if plan.if_exists && UserApiProvider::instance().get_file_format(&tenant, &plan.name).await.is_err() {
+                    return Ok(());
+                }
+                self.validate_object_privilege(&GrantObject::FileFormat(plan.name.clone()), UserPrivilegeType::Drop, enable_experimental_rbac_check).await?;
+            }
             Plan::CreateDatamaskPolicy(_) | Plan::DropDatamaskPolicy(_) => {
                 self.validate_access(
                     &GrantObject::Global,
@@ -1247,6 +1503,10 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::CreateRowAccessPolicy(_) | Plan::DropRowAccessPolicy(_) => {
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
+                    .await?;
+            }
             // Note: No need to check privileges
             // SET ROLE & SHOW ROLES is a session-local statement (have same semantic with the SET ROLE in postgres), no need to check privileges
             Plan::SetRole(_) => {}
@@ -1264,11 +1524,40 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            Plan::DescRowAccessPolicy(_) => {}
             Plan::Begin => {}
-            Plan::ExecuteImmediate(_)
-            | Plan::CallProcedure(_)
-            | Plan::CreateProcedure(_)
-            | Plan::DropProcedure(_)
+            Plan::ExecuteImmediate(plan) => {
+                match plan.procedure_id {
+                    Some(procedure_id) => {
+                        self.validate_object_usage(&GrantObject::Procedure(procedure_id), enable_experimental_rbac_check).await?;
+                    }
+                    None => {
+                        self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
+                            .await?;
+                    }
+                }
+            }
+            Plan::CallProcedure(plan) => {
+                self.validate_object_usage(&GrantObject::Procedure(plan.procedure_id), enable_experimental_rbac_check).await?;
+            }
+            Plan::DropProcedure(plan) => {
+                let req = GetProcedureReq { inner: plan.name.clone() };
+                let procedure = UserApiProvider::instance()
+                    .procedure_api(&tenant)
+                    .get_procedure(&req)
+                    .await?;
+                match procedure {
+                    Some(procedure) => {
+                        self.validate_object_privilege(&GrantObject::Procedure(procedure.id), UserPrivilegeType::Drop, enable_experimental_rbac_check).await?;
+                    }
+                    None if plan.if_exists => return Ok(()),
+                    None => {
+                        self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
+                            .await?;
+                    }
+                }
+            }
+            Plan::CreateProcedure(_)
             /*| Plan::ShowCreateProcedure(_)
             | Plan::RenameProcedure(_)*/ => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
@@ -1316,7 +1605,12 @@ fn check_ownership_access(
                         return Ok(());
                     }
                 }
-                OwnershipObject::UDF { .. } | OwnershipObject::Stage { .. } => {}
+                OwnershipObject::UDF { .. }
+                | OwnershipObject::Stage { .. }
+                | OwnershipObject::Sequence { .. }
+                | OwnershipObject::Procedure { .. }
+                | OwnershipObject::Connection { .. }
+                | OwnershipObject::FileFormat { .. } => {}
             }
         }
     }
@@ -1327,6 +1621,41 @@ fn check_ownership_access(
     )))
 }
 
+// Collect the names of the columns of the table that are referenced by the query, inner fields
+// of a tuple column and virtual columns are resolved to the column they come from.
+fn referenced_table_columns(
+    metadata: &Metadata,
+    used_columns: &ColumnSet,
+    table_index: IndexType,
+) -> Vec<String> {
+    let mut columns = BTreeSet::new();
+    for index in used_columns {
+        match metadata.column(*index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                table_index: column_table_index,
+                column_name,
+                path_indices,
+                ..
+            }) if *column_table_index == table_index => {
+                let column_name = match path_indices {
+                    Some(_) => column_name.split(':').next().unwrap_or(column_name),
+                    None => column_name,
+                };
+                columns.insert(column_name.to_string());
+            }
+            ColumnEntry::VirtualColumn(VirtualColumn {
+                table_index: column_table_index,
+                source_column_name,
+                ..
+            }) if *column_table_index == table_index => {
+                columns.insert(source_column_name.clone());
+            }
+            _ => {}
+        }
+    }
+    columns.into_iter().collect()
+}
+
 // TODO(liyz): replace it with verify_access
 async fn has_priv(
     tenant: &Tenant,
@@ -1376,7 +1705,7 @@ async fn has_priv(
                         *ldb == db_name
                     }
                 }
-                GrantObject::TableById(_, ldb, ltab) => {
+                GrantObject::TableById(_, ldb, ltab) | GrantObject::Column(_, ldb, ltab, _) => {
                     if let Some(table) = table_id {
                         *ldb == db_id && *ltab == table
                     } else {
@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateSequenceReq;
+use databend_common_meta_app::schema::DropSequenceReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_meta_app::schema::AUTO_INCREMENT_SEQUENCE_CACHE;
+
+/// Returns the names of the hidden sequences of the `AUTOINCREMENT` columns.
+pub fn auto_increment_sequences(schema: &TableSchema) -> Vec<String> {
+    schema
+        .fields()
+        .iter()
+        .filter_map(|f| f.auto_increment_expr())
+        .map(|expr| expr.sequence_name.clone())
+        .collect()
+}
+
+/// Creates the hidden sequences of the `AUTOINCREMENT` columns, before the table is created.
+///
+/// The sequences are created with a `CACHE`, so that inserts reserve the values from meta
+/// in batches rather than once per block.
+pub async fn create_auto_increment_sequences(
+    ctx: Arc<dyn TableContext>,
+    sequences: &[String],
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_default_catalog()?;
+    for sequence_name in sequences {
+        let req = CreateSequenceReq {
+            create_option: CreateOption::CreateIfNotExists,
+            ident: SequenceIdent::new(&tenant, sequence_name),
+            create_on: Utc::now(),
+            comment: Some("AUTOINCREMENT column sequence".to_string()),
+            start: 1,
+            step: 1,
+            cache: AUTO_INCREMENT_SEQUENCE_CACHE,
+        };
+        catalog.create_sequence(req).await?;
+    }
+    Ok(())
+}
+
+/// Drops the hidden sequences of the `AUTOINCREMENT` columns, when the table is not created,
+/// or when the dropped table is purged.
+pub async fn drop_auto_increment_sequences(
+    ctx: Arc<dyn TableContext>,
+    sequences: &[String],
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_default_catalog()?;
+    for sequence_name in sequences {
+        let req = DropSequenceReq {
+            if_exists: true,
+            ident: SequenceIdent::new(&tenant, sequence_name),
+        };
+        catalog.drop_sequence(req).await?;
+    }
+    Ok(())
+}
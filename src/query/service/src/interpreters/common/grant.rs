@@ -17,6 +17,8 @@ use std::sync::Arc;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::schema::GetSequenceReq;
+use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_users::UserApiProvider;
 
 use crate::sessions::QueryContext;
@@ -76,6 +78,27 @@ pub async fn validate_grant_object_exists(
                 ));
             }
         }
+        GrantObject::Column(catalog_name, db_id, table_id, column_id) => {
+            let catalog = ctx.get_catalog(catalog_name).await?;
+
+            let Some(table_meta) = catalog.get_table_meta_by_id(*table_id).await? else {
+                return Err(databend_common_exception::ErrorCode::UnknownTableId(
+                    format!(
+                        "table id `{}`.`{}` not exists in catalog '{}'",
+                        db_id, table_id, catalog_name,
+                    ),
+                ));
+            };
+            let schema = &table_meta.data.schema;
+            if !schema.fields().iter().any(|f| f.column_id() == *column_id) {
+                return Err(databend_common_exception::ErrorCode::UnknownColumn(
+                    format!(
+                        "column id `{}` not exists in table id `{}`.`{}`",
+                        column_id, db_id, table_id,
+                    ),
+                ));
+            }
+        }
         GrantObject::UDF(udf) => {
             if !UserApiProvider::instance().exists_udf(&tenant, udf).await? {
                 return Err(databend_common_exception::ErrorCode::UnknownFunction(
@@ -93,6 +116,34 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
+        GrantObject::Sequence(sequence) => {
+            let req = GetSequenceReq {
+                ident: SequenceIdent::new(&tenant, sequence),
+            };
+            ctx.get_default_catalog()?.get_sequence(req).await?;
+        }
+        GrantObject::Procedure(procedure_id) => {
+            let procedure_api = UserApiProvider::instance().procedure_api(&tenant);
+            if procedure_api
+                .get_procedure_name_by_id(&tenant, *procedure_id)
+                .await?
+                .is_none()
+            {
+                return Err(databend_common_exception::ErrorCode::UnknownProcedure(
+                    format!("procedure id {procedure_id} not exists"),
+                ));
+            }
+        }
+        GrantObject::Connection(connection) => {
+            UserApiProvider::instance()
+                .get_connection(&tenant, connection)
+                .await?;
+        }
+        GrantObject::FileFormat(file_format) => {
+            UserApiProvider::instance()
+                .get_file_format(&tenant, file_format)
+                .await?;
+        }
         GrantObject::Global => (),
     }
 
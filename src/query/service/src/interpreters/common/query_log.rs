@@ -27,6 +27,7 @@ use log::error;
 use log::info;
 use serde_json;
 
+use crate::history::QueryHistoryWriter;
 use crate::sessions::convert_query_log_timestamp;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -70,6 +71,9 @@ impl InterpreterQueryLog {
         info!(target: "databend::log::query", "{}", event_str);
         // log the query event in the system log
         info!("query: {} becomes {:?}", event.query_id, event.log_type);
+        if let Some(writer) = QueryHistoryWriter::try_instance() {
+            writer.append_query_log(event.clone());
+        }
         QueryLogQueue::instance()?.append_data(event)
     }
 
@@ -14,15 +14,19 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::sync::Arc;
 use std::sync::LazyLock;
 
 use chrono::Duration;
+use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use databend_common_io::constants::DEFAULT_MIN_TABLE_LEVEL_DATA_RETENTION_PERIOD_IN_HOURS;
 use databend_common_settings::Settings;
+use databend_common_sql::parse_ttl_expr;
 use databend_common_sql::BloomIndexColumns;
+use databend_common_sql::StatisticsColumnGroups;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
@@ -30,6 +34,8 @@ use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::NgramIndex;
+use databend_storages_common_table_meta::table::OPT_KEY_AS_QUERY;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
@@ -37,11 +43,17 @@ use databend_storages_common_table_meta::table::OPT_KEY_COMMENT;
 use databend_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
+use databend_storages_common_table_meta::table::OPT_KEY_INITIALIZE;
 use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_SEED;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_MODE;
+use databend_storages_common_table_meta::table::OPT_KEY_STATISTICS_COLUMN_GROUPS;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TARGET_LAG;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use log::error;
 
 /// Table option keys that can occur in 'create table statement'.
@@ -55,12 +67,15 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_NGRAM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_STATISTICS_COLUMN_GROUPS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
     r.insert(OPT_KEY_CLUSTER_TYPE);
+    r.insert(OPT_KEY_TTL);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -82,6 +97,19 @@ pub static UNSET_TABLE_OPTIONS_WHITE_LIST: LazyLock<HashSet<&'static str>> = Laz
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
     r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
+    r.insert(OPT_KEY_TTL);
+    r.insert(OPT_KEY_NGRAM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_STATISTICS_COLUMN_GROUPS);
+    r
+});
+
+/// Table option keys that are only set by 'create dynamic table statement'.
+pub static DYNAMIC_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    let mut r = HashSet::new();
+    r.insert(OPT_KEY_AS_QUERY);
+    r.insert(OPT_KEY_TARGET_LAG);
+    r.insert(OPT_KEY_REFRESH_MODE);
+    r.insert(OPT_KEY_INITIALIZE);
     r
 });
 
@@ -89,6 +117,10 @@ pub fn is_valid_create_opt<S: AsRef<str>>(opt_key: S) -> bool {
     CREATE_TABLE_OPTIONS.contains(opt_key.as_ref().to_lowercase().as_str())
 }
 
+pub fn is_valid_dynamic_table_opt<S: AsRef<str>>(opt_key: S) -> bool {
+    DYNAMIC_TABLE_OPTIONS.contains(opt_key.as_ref().to_lowercase().as_str())
+}
+
 pub fn is_valid_block_per_segment(
     options: &BTreeMap<String, String>,
 ) -> databend_common_exception::Result<()> {
@@ -159,6 +191,37 @@ pub fn is_valid_bloom_index_columns(
     Ok(())
 }
 
+pub fn is_valid_ngram_index_columns(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> databend_common_exception::Result<()> {
+    if let Some(value) = options.get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+        BloomIndexColumns::verify_definition(value, schema, NgramIndex::supported_type)?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_statistics_column_groups(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> databend_common_exception::Result<()> {
+    if let Some(value) = options.get(OPT_KEY_STATISTICS_COLUMN_GROUPS) {
+        StatisticsColumnGroups::verify_definition(value, schema)?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_ttl(
+    ctx: Arc<dyn TableContext>,
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> databend_common_exception::Result<()> {
+    if let Some(value) = options.get(OPT_KEY_TTL) {
+        parse_ttl_expr(ctx, schema, value)?;
+    }
+    Ok(())
+}
+
 pub fn is_valid_change_tracking(
     options: &BTreeMap<String, String>,
 ) -> databend_common_exception::Result<()> {
@@ -269,6 +269,7 @@ async fn generate_refresh_inverted_index_plan(
             continue;
         }
         let plan = RefreshTableIndexPlan {
+            index_type: index.index_type.clone(),
             catalog: desc.catalog.clone(),
             database: desc.database.clone(),
             table: desc.table.clone(),
@@ -16,7 +16,6 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::SystemTime;
 
-use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::Statement;
 use databend_common_base::base::short_sql;
 use databend_common_base::runtime::profile::get_statistics_desc;
@@ -33,12 +32,11 @@ use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_pipeline_core::SourcePipeBuilder;
 use databend_common_sql::plans::Plan;
+use databend_common_sql::query_fingerprint;
 use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
 use databend_common_storages_system::ProfilesLogElement;
 use databend_common_storages_system::ProfilesLogQueue;
-use derive_visitor::DriveMut;
-use derive_visitor::VisitorMut;
 use log::error;
 use log::info;
 use md5::Digest;
@@ -49,11 +47,15 @@ use super::hook::vacuum_hook::hook_vacuum_temp_files;
 use super::interpreter_txn_commit::CommitInterpreter;
 use super::InterpreterMetrics;
 use super::InterpreterQueryLog;
+use crate::audit::audit_query;
+use crate::history::ProfileHistoryElement;
+use crate::history::QueryHistoryWriter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::executor::PipelinePullingExecutor;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::ServiceQueryExecutor;
+use crate::sessions::convert_query_log_timestamp;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
 use crate::stream::DataBlockStream;
@@ -192,6 +194,8 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
         SessionManager::instance().status.write().query_finish(now);
     }
 
+    audit_query(ctx, error.as_ref());
+
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error, has_profiles) {
         error!("interpreter.finish.error: {:?}", error)
     }
@@ -212,7 +216,7 @@ pub async fn interpreter_plan_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<(
         sql.to_string(),
         ctx.get_settings().get_short_sql_max_length()?,
     );
-    let mut stmt = if let Ok((_, extras)) = &result {
+    let stmt = if let Ok((_, extras)) = &result {
         Some(extras.statement.clone())
     } else {
         // Only log if there's an error
@@ -222,30 +226,15 @@ pub async fn interpreter_plan_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<(
         None
     };
 
-    attach_query_hash(&ctx, &mut stmt, &short_sql);
+    attach_query_hash(&ctx, &stmt, &short_sql);
 
     result
 }
 
-fn attach_query_hash(ctx: &Arc<QueryContext>, stmt: &mut Option<Statement>, sql: &str) {
+fn attach_query_hash(ctx: &Arc<QueryContext>, stmt: &Option<Statement>, sql: &str) {
     let (query_hash, query_parameterized_hash) = if let Some(stmt) = stmt {
         let query_hash = format!("{:x}", Md5::digest(stmt.to_string()));
-        // Use Literal::Null replace literal. Ignore Literal.
-        // SELECT * FROM t1 WHERE name = 'data' => SELECT * FROM t1 WHERE name = NULL
-        // SELECT * FROM t1 WHERE name = 'bend' => SELECT * FROM t1 WHERE name = NULL
-        #[derive(VisitorMut)]
-        #[visitor(Literal(enter))]
-        struct AstVisitor;
-
-        impl AstVisitor {
-            fn enter_literal(&mut self, lit: &mut Literal) {
-                *lit = Literal::Null;
-            }
-        }
-
-        stmt.drive_mut(&mut AstVisitor);
-
-        (query_hash, format!("{:x}", Md5::digest(stmt.to_string())))
+        (query_hash, query_fingerprint(stmt))
     } else {
         let hash = format!("{:x}", Md5::digest(sql));
         (hash.to_string(), hash)
@@ -277,6 +266,14 @@ pub fn on_execution_finished(info: &ExecutionInfo, query_ctx: Arc<QueryContext>)
                 statistics_desc: get_statistics_desc(),
             })?
         );
+        if let Some(writer) = QueryHistoryWriter::try_instance() {
+            writer.append_profiles(ProfileHistoryElement {
+                event_time: convert_query_log_timestamp(SystemTime::now()),
+                node_id: query_ctx.get_cluster().local_id.clone(),
+                query_id: query_ctx.get_id(),
+                profiles: query_profiles.clone(),
+            });
+        }
         let profiles_queue = ProfilesLogQueue::instance()?;
         profiles_queue.append_data(ProfilesLogElement {
             query_id: query_ctx.get_id(),
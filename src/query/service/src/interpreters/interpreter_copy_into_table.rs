@@ -23,6 +23,8 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_pipeline_core::always_callback;
+use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::executor::physical_plans::CopyIntoTable;
 use databend_common_sql::executor::physical_plans::CopyIntoTableSource;
@@ -42,6 +44,7 @@ use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
+use crate::notification::NotificationSender;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
@@ -289,6 +292,54 @@ impl CopyIntoTableInterpreter {
         Ok(())
     }
 
+    /// Send the errors of the copy to `copy_error_integration` when the pipeline finishes,
+    /// either the copy fails or some files have errors with `ON_ERROR = continue`.
+    fn notify_errors_on_finished(&self, main_pipeline: &mut Pipeline) -> Result<()> {
+        let Some(sender) = NotificationSender::try_instance() else {
+            return Ok(());
+        };
+        let integration = self.ctx.get_settings().get_copy_error_integration()?;
+        if integration.is_empty() {
+            return Ok(());
+        }
+
+        let ctx = self.ctx.clone();
+        let database = self.plan.database_name.clone();
+        let table = self.plan.table_name.clone();
+        main_pipeline.set_on_finished(always_callback(move |info: &ExecutionInfo| {
+            let files = ctx
+                .get_copy_status()
+                .files
+                .iter()
+                .filter_map(|entry| {
+                    let err = entry.value().error.as_ref()?;
+                    Some(serde_json::json!({
+                        "file": entry.key(),
+                        "rows_loaded": entry.value().num_rows_loaded,
+                        "errors_seen": err.num_errors,
+                        "first_error": err.first_error.error.to_string(),
+                        "first_error_line": err.first_error.line + 1,
+                    }))
+                })
+                .collect::<Vec<_>>();
+
+            let error = info.res.as_ref().err().map(|cause| cause.message());
+            if error.is_some() || !files.is_empty() {
+                let message = serde_json::json!({
+                    "source": "copy",
+                    "query_id": ctx.get_id(),
+                    "database": database,
+                    "table": table,
+                    "error": error,
+                    "files": files,
+                });
+                sender.send_in_background(integration, "copy", message);
+            }
+            Ok(())
+        }));
+        Ok(())
+    }
+
     async fn on_no_files_to_copy(&self) -> Result<PipelineBuildResult> {
         // currently, there is only one thing that we care about:
         //
@@ -390,6 +441,8 @@ impl Interpreter for CopyIntoTableInterpreter {
             hook_operator.execute(&mut build_res.main_pipeline).await;
         }
 
+        self.notify_errors_on_finished(&mut build_res.main_pipeline)?;
+
         Ok(build_res)
     }
 
@@ -18,7 +18,10 @@ use databend_common_exception::Result;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_sql::plans::RenameDatabasePlan;
+use log::warn;
 
+use crate::dynamic_table::is_dynamic_table;
+use crate::dynamic_table::rename_refresh_task;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -57,6 +60,28 @@ impl Interpreter for RenameDatabaseInterpreter {
                     new_db_name: entity.new_database.clone(),
                 })
                 .await?;
+
+            // keep refreshing the dynamic tables of the renamed database.
+            let Ok(tables) = catalog.list_tables(&tenant, &entity.new_database).await else {
+                continue;
+            };
+            for table in tables.iter().filter(|t| is_dynamic_table(t.as_ref())) {
+                if let Err(cause) = rename_refresh_task(
+                    self.ctx.clone(),
+                    &entity.new_database,
+                    table.name(),
+                    table.get_id(),
+                )
+                .await
+                {
+                    warn!(
+                        "Failed to rename the refresh task of dynamic table {}.{}: {:?}",
+                        entity.new_database,
+                        table.name(),
+                        cause
+                    );
+                }
+            }
         }
 
         Ok(PipelineBuildResult::create())
@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::Engine;
+use databend_common_ast::ast::InitializeMode;
+use databend_common_ast::ast::TargetLag;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_sql::plans::CreateDynamicTablePlan;
+use databend_common_sql::plans::CreateTablePlan;
+use log::info;
+
+use crate::dynamic_table::create_refresh_task;
+use crate::dynamic_table::drop_refresh_task;
+use crate::dynamic_table::is_dynamic_table;
+use crate::dynamic_table::DynamicTableRefresher;
+use crate::dynamic_table::RefreshTrigger;
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CreateDynamicTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateDynamicTablePlan,
+}
+
+impl CreateDynamicTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateDynamicTablePlan) -> Result<Self> {
+        Ok(CreateDynamicTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateDynamicTableInterpreter {
+    fn name(&self) -> &str {
+        "CreateDynamicTableInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+        let old_table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await
+            .ok();
+        if old_table.is_some() && plan.create_option == CreateOption::CreateIfNotExists {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let create_plan = CreateTablePlan {
+            create_option: plan.create_option,
+            tenant: plan.tenant.clone(),
+            catalog: plan.catalog.clone(),
+            database: plan.database.clone(),
+            table: plan.table.clone(),
+            schema: plan.schema.clone(),
+            engine: Engine::Fuse,
+            engine_options: Default::default(),
+            storage_params: None,
+            part_prefix: "".to_string(),
+            options: plan.options.clone(),
+            field_comments: plan.field_comments.clone(),
+            cluster_key: plan.cluster_key.clone(),
+            partition_key: None,
+            as_select: None,
+            inverted_indexes: None,
+        };
+        CreateTableInterpreter::try_create(self.ctx.clone(), create_plan)?
+            .execute2()
+            .await?;
+
+        // The replaced dynamic table is not refreshed anymore.
+        if let Some(old_table) = old_table.filter(|table| is_dynamic_table(table.as_ref())) {
+            drop_refresh_task(self.ctx.clone(), old_table.get_id()).await?;
+        }
+
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+        let refresher = DynamicTableRefresher::create(self.ctx.clone(), RefreshTrigger::Creation);
+        refresher
+            .enable_change_tracking(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+        if plan.initialize == InitializeMode::OnCreate {
+            refresher
+                .refresh(&plan.catalog, &plan.database, &plan.table)
+                .await?;
+        }
+
+        // Tables with `TARGET_LAG = DOWNSTREAM` are refreshed along with the tables that read them.
+        if let TargetLag::IntervalSecs(secs) = plan.target_lag {
+            let created = create_refresh_task(
+                self.ctx.clone(),
+                &plan.database,
+                &plan.table,
+                table.get_id(),
+                secs,
+            )
+            .await?;
+            if !created {
+                info!(
+                    "Dynamic table {}.{} is only refreshed manually, as the task service is not enabled",
+                    plan.database, plan.table
+                );
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
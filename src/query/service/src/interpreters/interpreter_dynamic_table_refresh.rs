@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_sql::plans::RefreshDynamicTablePlan;
+
+use crate::dynamic_table::DynamicTableRefresher;
+use crate::dynamic_table::RefreshTrigger;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionType;
+
+pub struct RefreshDynamicTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshDynamicTablePlan,
+}
+
+impl RefreshDynamicTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshDynamicTablePlan) -> Result<Self> {
+        Ok(RefreshDynamicTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshDynamicTableInterpreter {
+    fn name(&self) -> &str {
+        "RefreshDynamicTableInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The refresh task of a dynamic table runs in a task session.
+        let trigger = match self.ctx.get_current_session().get_type() {
+            SessionType::Task => RefreshTrigger::Scheduled,
+            _ => RefreshTrigger::Manual,
+        };
+        DynamicTableRefresher::create(self.ctx.clone(), trigger)
+            .refresh(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
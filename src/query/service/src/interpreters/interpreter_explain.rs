@@ -60,6 +60,7 @@ pub struct ExplainInterpreter {
     config: ExplainConfig,
     kind: ExplainKind,
     partial: bool,
+    json: bool,
     plan: Plan,
 }
 
@@ -75,7 +76,7 @@ impl Interpreter for ExplainInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        let blocks = match &self.kind {
+        let mut blocks = match &self.kind {
             ExplainKind::Raw | ExplainKind::Optimized => self.explain_plan(&self.plan)?,
             ExplainKind::Plan if self.config.logical => self.explain_plan(&self.plan)?,
             ExplainKind::Plan => match &self.plan {
@@ -248,6 +249,15 @@ impl Interpreter for ExplainInterpreter {
             }
         };
 
+        // The JSON output is kept parseable, the query hint is only shown in the text output.
+        if let Some(fingerprint) = self.config.query_hint.as_ref().filter(|_| !self.json) {
+            let line = format!("query hint: {fingerprint}");
+            blocks.insert(
+                0,
+                DataBlock::new_from_columns(vec![StringType::from_data(vec![line])]),
+            );
+        }
+
         PipelineBuildResult::from_blocks(blocks)
     }
 }
@@ -259,6 +269,7 @@ impl ExplainInterpreter {
         kind: ExplainKind,
         config: ExplainConfig,
         partial: bool,
+        json: bool,
     ) -> Result<Self> {
         Ok(ExplainInterpreter {
             ctx,
@@ -266,6 +277,7 @@ impl ExplainInterpreter {
             kind,
             config,
             partial,
+            json,
         })
     }
 
@@ -392,6 +404,12 @@ impl ExplainInterpreter {
         // Drain the data
         let query_profiles = self.execute_and_get_profiles(build_res)?;
 
+        if self.json {
+            let result = plan.format_json(&query_profiles, self.partial)?;
+            let formatted_plan = StringType::from_data(vec![result]);
+            return Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])]);
+        }
+
         let result = if self.partial {
             format_partial_tree(&plan, metadata, &query_profiles)?.format_pretty()?
         } else {
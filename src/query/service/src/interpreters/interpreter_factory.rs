@@ -32,9 +32,12 @@ use super::interpreter_mutation::MutationInterpreter;
 use super::interpreter_table_index_create::CreateTableIndexInterpreter;
 use super::interpreter_table_index_drop::DropTableIndexInterpreter;
 use super::interpreter_table_index_refresh::RefreshTableIndexInterpreter;
+use super::interpreter_table_ngram_index_refresh::RefreshTableNgramIndexInterpreter;
 use super::interpreter_table_set_options::SetOptionsInterpreter;
 use super::interpreter_user_stage_drop::DropUserStageInterpreter;
 use super::*;
+use crate::audit::audit_action;
+use crate::audit::audit_query;
 use crate::interpreters::access::Accessor;
 use crate::interpreters::interpreter_catalog_drop::DropCatalogInterpreter;
 use crate::interpreters::interpreter_connection_create::CreateConnectionInterpreter;
@@ -43,6 +46,8 @@ use crate::interpreters::interpreter_connection_drop::DropConnectionInterpreter;
 use crate::interpreters::interpreter_connection_show::ShowConnectionsInterpreter;
 use crate::interpreters::interpreter_copy_into_location::CopyIntoLocationInterpreter;
 use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
+use crate::interpreters::interpreter_dynamic_table_create::CreateDynamicTableInterpreter;
+use crate::interpreters::interpreter_dynamic_table_refresh::RefreshDynamicTableInterpreter;
 use crate::interpreters::interpreter_file_format_create::CreateFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_drop::DropFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_show::ShowFileFormatsInterpreter;
@@ -87,18 +92,24 @@ pub struct InterpreterFactory;
 impl InterpreterFactory {
     #[async_backtrace::framed]
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
+        // The audit event is recorded when the query is finished.
+        if let Some(action) = audit_action(plan) {
+            ctx.set_audit_action(action);
+        }
+
         // Check the access permission.
         let access_checker = Accessor::create(ctx.clone());
-        access_checker
-            .check(plan)
-            .await
-            .map_err(|e| match e.code() {
+        access_checker.check(plan).await.map_err(|e| {
+            // The query is not executed, so the denied access is recorded here.
+            audit_query(&ctx, Some(&e));
+            match e.code() {
                 ErrorCode::PERMISSION_DENIED => {
                     error!("Access.denied(v2): {:?}", e);
                     e
                 }
                 _ => e,
-            })?;
+            }
+        })?;
         Self::get_inner(ctx, plan)
     }
 
@@ -125,6 +136,7 @@ impl InterpreterFactory {
                 kind.clone(),
                 config.clone(),
                 false,
+                false,
             )?)),
             Plan::ExplainAst { formatted_string } => Ok(Arc::new(ExplainInterpreter::try_create(
                 ctx,
@@ -132,6 +144,7 @@ impl InterpreterFactory {
                 ExplainKind::Ast(formatted_string.clone()),
                 ExplainConfig::default(),
                 false,
+                false,
             )?)),
             Plan::ExplainSyntax { formatted_sql } => Ok(Arc::new(ExplainInterpreter::try_create(
                 ctx,
@@ -139,13 +152,23 @@ impl InterpreterFactory {
                 ExplainKind::Syntax(formatted_sql.clone()),
                 ExplainConfig::default(),
                 false,
+                false,
             )?)),
-            Plan::ExplainAnalyze { partial, plan } => Ok(Arc::new(ExplainInterpreter::try_create(
+            Plan::ExplainAnalyze {
+                partial,
+                json,
+                plan,
+                query_hint,
+            } => Ok(Arc::new(ExplainInterpreter::try_create(
                 ctx,
                 *plan.clone(),
                 ExplainKind::AnalyzePlan,
-                ExplainConfig::default(),
+                ExplainConfig {
+                    query_hint: query_hint.clone(),
+                    ..Default::default()
+                },
                 *partial,
+                *json,
             )?)),
 
             Plan::CopyIntoTable(copy_plan) => Ok(Arc::new(CopyIntoTableInterpreter::try_create(
@@ -239,6 +262,12 @@ impl InterpreterFactory {
             Plan::DropTableClusterKey(drop_table_cluster_key) => Ok(Arc::new(
                 DropTableClusterKeyInterpreter::try_create(ctx, *drop_table_cluster_key.clone())?,
             )),
+            Plan::AddTableRowAccessPolicy(p) => Ok(Arc::new(
+                AddTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropTableRowAccessPolicy(p) => Ok(Arc::new(
+                DropTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
             Plan::ReclusterTable { s_expr, is_final } => {
                 Ok(Arc::new(ReclusterTableInterpreter::try_create(
                     ctx,
@@ -250,6 +279,9 @@ impl InterpreterFactory {
             Plan::TruncateTable(truncate_table) => Ok(Arc::new(
                 TruncateTableInterpreter::try_create(ctx, *truncate_table.clone())?,
             )),
+            Plan::DropTablePartition(drop_partition) => Ok(Arc::new(
+                DropTablePartitionInterpreter::try_create(ctx, *drop_partition.clone())?,
+            )),
             Plan::OptimizePurge(purge) => Ok(Arc::new(OptimizePurgeInterpreter::try_create(
                 ctx,
                 *purge.clone(),
@@ -313,7 +345,12 @@ impl InterpreterFactory {
             )?)),
 
             // dynamic tables
-            Plan::CreateDynamicTable(_) => Err(ErrorCode::Unimplemented("todo")),
+            Plan::CreateDynamicTable(create_dynamic_table) => Ok(Arc::new(
+                CreateDynamicTableInterpreter::try_create(ctx, *create_dynamic_table.clone())?,
+            )),
+            Plan::RefreshDynamicTable(refresh_dynamic_table) => Ok(Arc::new(
+                RefreshDynamicTableInterpreter::try_create(ctx, *refresh_dynamic_table.clone())?,
+            )),
 
             // Indexes
             Plan::CreateIndex(index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
@@ -339,6 +376,9 @@ impl InterpreterFactory {
             Plan::RefreshTableIndex(index) => Ok(Arc::new(
                 RefreshTableIndexInterpreter::try_create(ctx, *index.clone())?,
             )),
+            Plan::RefreshTableNgramIndex(index) => Ok(Arc::new(
+                RefreshTableNgramIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
             // Virtual columns
             Plan::CreateVirtualColumn(create_virtual_column) => Ok(Arc::new(
                 CreateVirtualColumnInterpreter::try_create(ctx, *create_virtual_column.clone())?,
@@ -483,6 +523,15 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateRowAccessPolicy(p) => Ok(Arc::new(
+                CreateRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropRowAccessPolicy(p) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DescRowAccessPolicy(p) => Ok(Arc::new(
+                DescRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
 
             Plan::CreateNetworkPolicy(p) => Ok(Arc::new(
                 CreateNetworkPolicyInterpreter::try_create(ctx, *p.clone())?,
@@ -516,6 +565,15 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateQueryHint(p) => Ok(Arc::new(CreateQueryHintInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropQueryHint(p) => Ok(Arc::new(DropQueryHintInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ShowQueryHints(_) => Ok(Arc::new(ShowQueryHintsInterpreter::try_create(ctx)?)),
 
             Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
                 ctx,
@@ -580,6 +638,14 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::AlterSequence(p) => Ok(Arc::new(AlterSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DescSequence(p) => Ok(Arc::new(DescSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
             Plan::SetPriority(p) => Ok(Arc::new(SetPriorityInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -15,8 +15,11 @@
 use std::sync::Arc;
 
 use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::principal::UserDefinedFileFormat;
 use databend_common_sql::plans::CreateFileFormatPlan;
+use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use log::debug;
 
@@ -65,6 +68,20 @@ impl Interpreter for CreateFileFormatInterpreter {
             .add_file_format(&tenant, user_defined_file_format, &plan.create_option)
             .await?;
 
+        // Grant ownership as the current role
+        if let Some(current_role) = self.ctx.get_current_role() {
+            let role_api = UserApiProvider::instance().role_api(&tenant);
+            role_api
+                .grant_ownership(
+                    &OwnershipObject::FileFormat {
+                        name: plan.name.clone(),
+                    },
+                    &current_role.name,
+                )
+                .await?;
+            RoleCacheManager::instance().invalidate_cache(&tenant);
+        }
+
         Ok(PipelineBuildResult::create())
     }
 }
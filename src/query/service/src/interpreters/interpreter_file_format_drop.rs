@@ -15,7 +15,10 @@
 use std::sync::Arc;
 
 use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_sql::plans::DropFileFormatPlan;
+use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use log::debug;
 
@@ -59,6 +62,15 @@ impl Interpreter for DropFileFormatInterpreter {
             .drop_file_format(&tenant, &plan.name, plan.if_exists)
             .await?;
 
+        // drop the ownership
+        let role_api = user_mgr.role_api(&tenant);
+        role_api
+            .revoke_ownership(&OwnershipObject::FileFormat {
+                name: plan.name.clone(),
+            })
+            .await?;
+        RoleCacheManager::instance().invalidate_cache(&tenant);
+
         Ok(PipelineBuildResult::create())
     }
 }
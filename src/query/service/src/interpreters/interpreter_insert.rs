@@ -15,10 +15,13 @@
 use std::sync::Arc;
 
 use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::executor::physical_plans::DistributedInsertSelect;
 use databend_common_sql::executor::physical_plans::MutationKind;
@@ -29,6 +32,7 @@ use databend_common_sql::plans::InsertInputSource;
 use databend_common_sql::plans::InsertValue;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::NameResolutionContext;
+use databend_common_storages_fuse::FuseTable;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
@@ -54,6 +58,35 @@ impl InsertInterpreter {
         Ok(Arc::new(InsertInterpreter { ctx, plan }))
     }
 
+    fn commit_insertion(
+        &self,
+        table: &Arc<dyn Table>,
+        pipeline: &mut Pipeline,
+        update_stream_meta: Vec<UpdateStreamMetaReq>,
+    ) -> Result<()> {
+        let deduplicated_label = unsafe { self.ctx.get_settings().get_deduplicate_label()? };
+        match &self.plan.overwrite_partition {
+            // Only the fuse table can be partitioned.
+            Some(partition_value) => FuseTable::try_from_table(table.as_ref())?
+                .do_commit_overwrite_partition(
+                    self.ctx.clone(),
+                    pipeline,
+                    update_stream_meta,
+                    partition_value.clone(),
+                    deduplicated_label,
+                ),
+            None => table.commit_insertion(
+                self.ctx.clone(),
+                pipeline,
+                None,
+                update_stream_meta,
+                self.plan.overwrite,
+                None,
+                deduplicated_label,
+            ),
+        }
+    }
+
     fn check_schema_cast(&self, plan: &Plan) -> Result<bool> {
         let output_schema = &self.plan.schema;
         let select_schema = plan.schema();
@@ -205,15 +238,7 @@ impl Interpreter for InsertInterpreter {
                     build_query_pipeline_without_render_result_set(&self.ctx, &insert_select_plan)
                         .await?;
 
-                table.commit_insertion(
-                    self.ctx.clone(),
-                    &mut build_res.main_pipeline,
-                    None,
-                    update_stream_meta,
-                    self.plan.overwrite,
-                    None,
-                    unsafe { self.ctx.get_settings().get_deduplicate_label()? },
-                )?;
+                self.commit_insertion(&table, &mut build_res.main_pipeline, update_stream_meta)?;
 
                 //  Execute the hook operator.
                 {
@@ -232,16 +257,13 @@ impl Interpreter for InsertInterpreter {
             }
         };
 
-        PipelineBuilder::build_append2table_with_commit_pipeline(
+        PipelineBuilder::build_append2table_without_commit_pipeline(
             self.ctx.clone(),
             &mut build_res.main_pipeline,
             table.clone(),
             self.plan.dest_schema(),
-            None,
-            vec![],
-            self.plan.overwrite,
-            unsafe { self.ctx.get_settings().get_deduplicate_label()? },
         )?;
+        self.commit_insertion(&table, &mut build_res.main_pipeline, vec![])?;
 
         //  Execute the hook operator.
         {
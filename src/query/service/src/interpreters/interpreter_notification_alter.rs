@@ -67,6 +67,11 @@ impl AlterNotificationInterpreter {
                         .map(|x| x.authorization_header.clone())
                         .unwrap_or_default(),
                     comments: set_options.comments,
+                    webhook_signing_secret: set_options
+                        .webhook_opts
+                        .as_ref()
+                        .map(|x| x.signing_secret.clone())
+                        .unwrap_or_default(),
                 };
                 req
             }
@@ -88,9 +93,9 @@ impl Interpreter for AlterNotificationInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
-        if config.query.cloud_control_grpc_server_address.is_none() {
+        if !config.query.notification_service_enabled() {
             return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create notification without cloud control enabled, please set cloud_control_grpc_server_address in config",
+                "cannot create notification without cloud control enabled, please set cloud_control_grpc_server_address or enable_builtin_task_scheduler in config",
             ));
         }
         let cloud_api = CloudControlApiProvider::instance();
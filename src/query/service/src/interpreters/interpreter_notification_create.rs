@@ -67,6 +67,11 @@ impl CreateNotificationInterpreter {
                 .as_ref()
                 .map(|x| x.authorization_header.clone())
                 .unwrap_or_default(),
+            webhook_signing_secret: plan
+                .webhook_opts
+                .as_ref()
+                .map(|x| x.signing_secret.clone())
+                .unwrap_or_default(),
         }
     }
 }
@@ -85,9 +90,9 @@ impl Interpreter for CreateNotificationInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
-        if config.query.cloud_control_grpc_server_address.is_none() {
+        if !config.query.notification_service_enabled() {
             return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create notification without cloud control enabled, please set cloud_control_grpc_server_address in config",
+                "cannot create notification without cloud control enabled, please set cloud_control_grpc_server_address or enable_builtin_task_scheduler in config",
             ));
         }
         let cloud_api = CloudControlApiProvider::instance();
@@ -64,9 +64,9 @@ impl Interpreter for DropNotificationInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
-        if config.query.cloud_control_grpc_server_address.is_none() {
+        if !config.query.notification_service_enabled() {
             return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot drop notification without cloud control enabled, please set cloud_control_grpc_server_address in config",
+                "cannot drop notification without cloud control enabled, please set cloud_control_grpc_server_address or enable_builtin_task_scheduler in config",
             ));
         }
         let cloud_api = CloudControlApiProvider::instance();
@@ -24,6 +24,7 @@ use databend_common_sql::executor::PhysicalPlanBuilder;
 use databend_common_sql::optimizer::SExpr;
 use databend_common_sql::plans::OptimizeCompactBlock;
 use databend_common_sql::MetadataRef;
+use databend_common_storages_fuse::FuseTable;
 
 use crate::interpreters::interpreter_optimize_purge::purge;
 use crate::interpreters::Interpreter;
@@ -81,6 +82,19 @@ impl Interpreter for OptimizeCompactBlockInterpreter {
             .acquire_table_lock(&catalog, &database, &table, &self.lock_opt)
             .await?;
 
+        // Remove the expired rows of the table with ttl before compaction, the fully expired
+        // blocks are dropped and the partially expired ones are rewritten.
+        let tbl = self
+            .ctx
+            .get_catalog(&catalog)
+            .await?
+            .get_table(&self.ctx.get_tenant(), &database, &table)
+            .await?;
+        if let Ok(fuse_table) = FuseTable::try_from_table(tbl.as_ref()) {
+            tbl.check_mutable()?;
+            fuse_table.do_expire(self.ctx.clone()).await?;
+        }
+
         let mut build_res = PipelineBuildResult::create();
         let mut builder = PhysicalPlanBuilder::new(MetadataRef::default(), self.ctx.clone(), false);
         match builder.build(&self.s_expr, HashSet::new()).await {
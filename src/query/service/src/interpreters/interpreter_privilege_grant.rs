@@ -101,7 +101,19 @@ impl GrantPrivilegeInterpreter {
             GrantObject::UDF(name) => Ok(OwnershipObject::UDF {
                 name: name.to_string(),
             }),
-            GrantObject::Global => Err(ErrorCode::IllegalGrant(
+            GrantObject::Sequence(name) => Ok(OwnershipObject::Sequence {
+                name: name.to_string(),
+            }),
+            GrantObject::Procedure(procedure_id) => Ok(OwnershipObject::Procedure {
+                procedure_id: *procedure_id,
+            }),
+            GrantObject::Connection(name) => Ok(OwnershipObject::Connection {
+                name: name.to_string(),
+            }),
+            GrantObject::FileFormat(name) => Ok(OwnershipObject::FileFormat {
+                name: name.to_string(),
+            }),
+            GrantObject::Global | GrantObject::Column(_, _, _, _) => Err(ErrorCode::IllegalGrant(
                 "Illegal GRANT/REVOKE command; please consult the manual to see which privileges can be used",
             )),
         }
@@ -179,8 +191,10 @@ impl Interpreter for GrantPrivilegeInterpreter {
 
         let plan = self.plan.clone();
 
-        validate_grant_privileges(&plan.on, plan.priv_types)?;
-        validate_grant_object_exists(&self.ctx, &plan.on).await?;
+        for object in &plan.on {
+            validate_grant_privileges(object, plan.priv_types)?;
+            validate_grant_object_exists(&self.ctx, object).await?;
+        }
 
         // TODO: check user existence
         // TODO: check privilege on granting on the grant object
@@ -191,13 +205,13 @@ impl Interpreter for GrantPrivilegeInterpreter {
         match plan.principal {
             PrincipalIdentity::User(user) => {
                 user_mgr
-                    .grant_privileges_to_user(&tenant, user, plan.on, plan.priv_types)
+                    .grant_privileges_on_objects_to_user(&tenant, user, plan.on, plan.priv_types)
                     .await?;
             }
             PrincipalIdentity::Role(role) => {
                 if plan.priv_types.has_privilege(Ownership) && plan.priv_types.len() == 1 {
                     let owner_object = self
-                        .convert_to_ownerobject(&tenant, &plan.on, plan.on.catalog())
+                        .convert_to_ownerobject(&tenant, &plan.on[0], plan.on[0].catalog())
                         .await?;
                     if self.ctx.get_current_role().is_some() {
                         self.grant_ownership(&self.ctx, &tenant, &owner_object, &role)
@@ -208,8 +222,14 @@ impl Interpreter for GrantPrivilegeInterpreter {
                         ));
                     }
                 } else {
+                    // the privileges on several columns are granted at once.
                     user_mgr
-                        .grant_privileges_to_role(&tenant, &role, plan.on, plan.priv_types)
+                        .grant_privileges_on_objects_to_role(
+                            &tenant,
+                            &role,
+                            plan.on,
+                            plan.priv_types,
+                        )
                         .await?;
                 }
                 // grant_ownership and grant_privileges_to_role will modify the kv in meta.
@@ -15,11 +15,14 @@
 use std::sync::Arc;
 
 use databend_common_exception::Result;
+use databend_common_management::RoleApi;
 use databend_common_meta_api::kv_app_error::KVAppError;
 use databend_common_meta_app::app_error::AppError;
 use databend_common_meta_app::principal::CreateProcedureReq;
+use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_sql::plans::CreateProcedurePlan;
+use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use log::debug;
 
@@ -60,11 +63,11 @@ impl Interpreter for CreateProcedureInterpreter {
         let create_procedure_req: CreateProcedureReq = self.plan.clone().into();
         let overriding = self.plan.create_option.is_overriding();
 
-        return if let Err(e) = UserApiProvider::instance()
+        match UserApiProvider::instance()
             .add_procedure(&tenant, create_procedure_req, overriding)
             .await?
         {
-            match e {
+            Err(e) => match e {
                 KVAppError::AppError(AppError::ProcedureAlreadyExists(_)) => {
                     if self.plan.create_option != CreateOption::CreateIfNotExists {
                         Err(e.into())
@@ -73,9 +76,23 @@ impl Interpreter for CreateProcedureInterpreter {
                     }
                 }
                 _ => Err(e.into()),
+            },
+            Ok(reply) => {
+                // Grant ownership as the current role
+                if let Some(current_role) = self.ctx.get_current_role() {
+                    let role_api = UserApiProvider::instance().role_api(&tenant);
+                    role_api
+                        .grant_ownership(
+                            &OwnershipObject::Procedure {
+                                procedure_id: reply.procedure_id,
+                            },
+                            &current_role.name,
+                        )
+                        .await?;
+                    RoleCacheManager::instance().invalidate_cache(&tenant);
+                }
+                Ok(PipelineBuildResult::create())
             }
-        } else {
-            Ok(PipelineBuildResult::create())
-        };
+        }
     }
 }
@@ -15,8 +15,11 @@
 use std::sync::Arc;
 
 use databend_common_exception::Result;
+use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::DropProcedureReq;
+use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_sql::plans::DropProcedurePlan;
+use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use log::debug;
 
@@ -55,10 +58,19 @@ impl Interpreter for DropProcedureInterpreter {
         let tenant = self.plan.tenant.clone();
 
         let drop_procedure_req: DropProcedureReq = self.plan.clone().into();
-        let _ = UserApiProvider::instance()
+        let dropped = UserApiProvider::instance()
             .drop_procedure(&tenant, drop_procedure_req, self.plan.if_exists)
             .await?;
 
+        // drop the ownership of the dropped procedure
+        if let Some(procedure_id) = dropped {
+            let role_api = UserApiProvider::instance().role_api(&tenant);
+            role_api
+                .revoke_ownership(&OwnershipObject::Procedure { procedure_id })
+                .await?;
+            RoleCacheManager::instance().invalidate_cache(&tenant);
+        }
+
         Ok(PipelineBuildResult::create())
     }
 }
@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::QueryHint;
+use databend_common_sql::plans::CreateQueryHintPlan;
+use databend_common_sql::QueryHintRegistry;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateQueryHintInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateQueryHintPlan,
+}
+
+impl CreateQueryHintInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateQueryHintPlan) -> Result<Self> {
+        Ok(CreateQueryHintInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateQueryHintInterpreter {
+    fn name(&self) -> &str {
+        "CreateQueryHintInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_query_hint_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+        let create_option = plan.create_option.clone();
+        let query_hint = QueryHint::from(plan);
+
+        let user_mgr = UserApiProvider::instance();
+        user_mgr
+            .add_query_hint(&tenant, query_hint, &create_option)
+            .await?;
+        QueryHintRegistry::instance().invalidate(&tenant);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
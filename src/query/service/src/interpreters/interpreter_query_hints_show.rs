@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowQueryHintsInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl ShowQueryHintsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(ShowQueryHintsInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowQueryHintsInterpreter {
+    fn name(&self) -> &str {
+        "ShowQueryHintsInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+        let query_hints = user_mgr.get_query_hints(&tenant).await?;
+
+        let mut fingerprints = Vec::with_capacity(query_hints.len());
+        let mut queries = Vec::with_capacity(query_hints.len());
+        let mut join_orders = Vec::with_capacity(query_hints.len());
+        let mut join_distributions = Vec::with_capacity(query_hints.len());
+        let mut settings = Vec::with_capacity(query_hints.len());
+        let mut comments = Vec::with_capacity(query_hints.len());
+        let mut created_ons = Vec::with_capacity(query_hints.len());
+        for query_hint in query_hints {
+            fingerprints.push(query_hint.fingerprint);
+            queries.push(query_hint.query);
+            join_orders.push(query_hint.join_order.unwrap_or_default());
+            join_distributions.push(query_hint.join_distribution.unwrap_or_default());
+            settings.push(
+                query_hint
+                    .settings
+                    .iter()
+                    .map(|(k, v)| format!("{k}={v}"))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            );
+            comments.push(query_hint.comment);
+            created_ons.push(query_hint.create_on.timestamp_micros());
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(fingerprints),
+            StringType::from_data(queries),
+            StringType::from_data(join_orders),
+            StringType::from_data(join_distributions),
+            StringType::from_data(settings),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_ons),
+        ])])
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::AlterSequenceReq;
+use databend_common_sql::plans::AlterSequencePlan;
+use databend_common_storages_fuse::TableContext;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct AlterSequenceInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterSequencePlan,
+}
+
+impl AlterSequenceInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterSequencePlan) -> Result<Self> {
+        Ok(AlterSequenceInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterSequenceInterpreter {
+    fn name(&self) -> &str {
+        "AlterSequenceInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let req = AlterSequenceReq {
+            if_exists: self.plan.if_exists,
+            ident: self.plan.ident.clone(),
+            action: self.plan.action.clone(),
+        };
+        let catalog = self.ctx.get_default_catalog()?;
+        let _reply = catalog.alter_sequence(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -16,9 +16,13 @@ use std::sync::Arc;
 
 use chrono::Utc;
 use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::CreateSequenceReq;
 use databend_common_sql::plans::CreateSequencePlan;
 use databend_common_storages_fuse::TableContext;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -52,10 +56,28 @@ impl Interpreter for CreateSequenceInterpreter {
             ident: self.plan.ident.clone(),
             comment: self.plan.comment.clone(),
             create_on: Utc::now(),
+            start: self.plan.start,
+            step: self.plan.step,
+            cache: self.plan.cache,
         };
         let catalog = self.ctx.get_default_catalog()?;
         let _reply = catalog.create_sequence(req).await?;
 
+        // Grant ownership as the current role
+        let tenant = self.ctx.get_tenant();
+        if let Some(current_role) = self.ctx.get_current_role() {
+            let role_api = UserApiProvider::instance().role_api(&tenant);
+            role_api
+                .grant_ownership(
+                    &OwnershipObject::Sequence {
+                        name: self.plan.ident.name().to_string(),
+                    },
+                    &current_role.name,
+                )
+                .await?;
+            RoleCacheManager::instance().invalidate_cache(&tenant);
+        }
+
         Ok(PipelineBuildResult::create())
     }
 }
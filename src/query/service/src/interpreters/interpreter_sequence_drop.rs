@@ -16,9 +16,13 @@ use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::DropSequenceReq;
 use databend_common_sql::plans::DropSequencePlan;
 use databend_common_storages_fuse::TableContext;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -53,11 +57,23 @@ impl Interpreter for DropSequenceInterpreter {
         };
         let catalog = self.ctx.get_default_catalog()?;
         let reply = catalog.drop_sequence(req).await?;
-        if reply.prev.is_none() && !self.plan.if_exists {
-            return Err(ErrorCode::UnknownSequence(format!(
-                "unknown sequence {:?}",
-                self.plan.ident.name()
-            )));
+        if reply.prev.is_none() {
+            if !self.plan.if_exists {
+                return Err(ErrorCode::UnknownSequence(format!(
+                    "unknown sequence {:?}",
+                    self.plan.ident.name()
+                )));
+            }
+        } else {
+            // drop the ownership of the dropped sequence
+            let tenant = self.ctx.get_tenant();
+            let role_api = UserApiProvider::instance().role_api(&tenant);
+            let owner_object = OwnershipObject::Sequence {
+                name: self.plan.ident.name().to_string(),
+            };
+
+            role_api.revoke_ownership(&owner_object).await?;
+            RoleCacheManager::instance().invalidate_cache(&tenant);
         }
 
         Ok(PipelineBuildResult::create())
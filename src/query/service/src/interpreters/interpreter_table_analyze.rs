@@ -32,6 +32,7 @@ use databend_common_sql::plans::AnalyzeTablePlan;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::BindContext;
 use databend_common_sql::Planner;
+use databend_common_sql::StatisticsColumnGroups;
 use databend_common_storage::DEFAULT_HISTOGRAM_BUCKETS;
 use databend_common_storages_factory::NavigationPoint;
 use databend_common_storages_factory::Table;
@@ -39,6 +40,7 @@ use databend_common_storages_fuse::operations::HistogramInfoSink;
 use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_index::Index;
 use databend_storages_common_index::RangeIndex;
+use databend_storages_common_table_meta::table::OPT_KEY_STATISTICS_COLUMN_GROUPS;
 use itertools::Itertools;
 use log::info;
 
@@ -124,22 +126,41 @@ impl Interpreter for AnalyzeTableInterpreter {
                 .read_table_snapshot_statistics(Some(&snapshot))
                 .await?;
 
+            let column_groups = match table
+                .get_table_info()
+                .options()
+                .get(OPT_KEY_STATISTICS_COLUMN_GROUPS)
+            {
+                Some(value) => value
+                    .parse::<StatisticsColumnGroups>()?
+                    .column_ids(schema.clone()),
+                None => vec![],
+            };
+
             let (is_full, temporal_str) = if let Some(table_statistics) = &table_statistics {
-                let is_full = match table
-                    .navigate_to_point(
-                        &NavigationPoint::SnapshotID(
-                            table_statistics.snapshot_id.simple().to_string(),
-                        ),
-                        self.ctx.clone().get_abort_checker(),
-                    )
-                    .await
-                {
-                    Ok(t) => !t
-                        .read_table_snapshot()
+                // The joint distinct values of the new column groups can't be merged incrementally.
+                let new_column_groups = column_groups.iter().any(|column_ids| {
+                    !table_statistics
+                        .column_groups
+                        .iter()
+                        .any(|group| &group.column_ids == column_ids)
+                });
+                let is_full = new_column_groups
+                    || match table
+                        .navigate_to_point(
+                            &NavigationPoint::SnapshotID(
+                                table_statistics.snapshot_id.simple().to_string(),
+                            ),
+                            self.ctx.clone().get_abort_checker(),
+                        )
                         .await
-                        .is_ok_and(|s| s.is_some_and(|s| s.prev_table_seq.is_some())),
-                    Err(_) => true,
-                };
+                    {
+                        Ok(t) => !t
+                            .read_table_snapshot()
+                            .await
+                            .is_ok_and(|s| s.is_some_and(|s| s.prev_table_seq.is_some())),
+                        Err(_) => true,
+                    };
 
                 let temporal_str = if is_full {
                     format!("AT (snapshot => '{}')", snapshot.snapshot_id.simple())
@@ -179,9 +200,23 @@ impl Interpreter for AnalyzeTableInterpreter {
                     )
                 })
                 .join(", ");
+            let group_ndv_select_expr = column_groups
+                .iter()
+                .enumerate()
+                .map(|(i, column_ids)| {
+                    let columns = column_ids
+                        .iter()
+                        .filter_map(|id| schema.fields().iter().find(|f| f.column_id() == *id))
+                        .map(|f| f.name.clone())
+                        .join(", ");
+                    format!(
+                        "approx_count_distinct_state({DISTINCT_ERROR_RATE})(({columns})) as group_ndv_{i}, "
+                    )
+                })
+                .join("");
 
             let sql = format!(
-                "SELECT {ndv_select_expr}, {is_full} as is_full from {}.{} {temporal_str}",
+                "SELECT {ndv_select_expr}, {group_ndv_select_expr}{is_full} as is_full from {}.{} {temporal_str}",
                 plan.database, plan.table,
             );
 
@@ -251,6 +286,61 @@ impl Interpreter for AnalyzeTableInterpreter {
                     histogram_info_receivers.insert(*col_id, rx);
                 }
             }
+            // The most common values are used to estimate the equality predicates on skewed data,
+            // the frequency is the fraction of the non-null rows of the snapshot.
+            let mut mcv_info_receivers = HashMap::new();
+            let mcv_size = self.ctx.get_settings().get_analyze_mcv_size()?;
+            let row_count = snapshot.summary.row_count;
+            if mcv_size > 0 && row_count > 0 {
+                for (col_id, col_name) in index_cols.iter() {
+                    let null_count = snapshot
+                        .summary
+                        .col_stats
+                        .get(col_id)
+                        .map_or(0, |stat| stat.null_count)
+                        .min(row_count);
+                    let non_null_count = row_count - null_count;
+                    if non_null_count == 0 {
+                        continue;
+                    }
+                    let null_fraction = null_count as f64 / row_count as f64;
+                    let sql = format!(
+                        "SELECT {col_name}, COUNT() / {non_null_count} AS frequency, {null_fraction}::DOUBLE AS null_fraction \
+                        FROM {}.{} AT (snapshot => '{}') \
+                        WHERE {col_name} IS NOT NULL GROUP BY {col_name} ORDER BY frequency DESC LIMIT {mcv_size}",
+                        plan.database,
+                        plan.table,
+                        snapshot.snapshot_id.simple(),
+                    );
+                    info!("Analyze most common values via sql {:?}", sql);
+                    let (mut mcv_plan, bind_context) = self.plan_sql(sql).await?;
+                    if !self.ctx.get_cluster().is_empty() {
+                        mcv_plan = remove_exchange(mcv_plan);
+                    }
+                    let mut mcv_build_res = build_query_pipeline(
+                        &QueryContext::create_from(self.ctx.clone()),
+                        &bind_context.columns,
+                        &mcv_plan,
+                        false,
+                    )
+                    .await?;
+                    let (tx, rx) = async_channel::unbounded();
+                    mcv_build_res.main_pipeline.add_sink(|input_port| {
+                        Ok(ProcessorPtr::create(HistogramInfoSink::create(
+                            Some(tx.clone()),
+                            input_port.clone(),
+                        )))
+                    })?;
+
+                    build_res
+                        .sources_pipelines
+                        .push(mcv_build_res.main_pipeline.finalize());
+                    build_res
+                        .sources_pipelines
+                        .extend(mcv_build_res.sources_pipelines);
+                    mcv_info_receivers.insert(*col_id, rx);
+                }
+            }
             FuseTable::do_analyze(
                 self.ctx.clone(),
                 bind_context.output_schema(),
@@ -260,6 +350,8 @@ impl Interpreter for AnalyzeTableInterpreter {
                 snapshot.snapshot_id,
                 &mut build_res.main_pipeline,
                 histogram_info_receivers,
+                mcv_info_receivers,
+                column_groups,
             )?;
             return Ok(build_res);
         }
@@ -331,6 +423,7 @@ fn remove_exchange(plan: PhysicalPlan) -> PhysicalPlan {
                 limit: plan.limit,
                 after_exchange: plan.after_exchange,
                 pre_projection: plan.pre_projection,
+                topn_runtime_filter: plan.topn_runtime_filter,
                 stat_info: plan.stat_info,
             }),
             PhysicalPlan::Exchange(plan) => traverse(*plan.input),
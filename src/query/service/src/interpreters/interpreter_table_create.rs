@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use chrono::Utc;
 use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_catalog::catalog::Catalog;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -29,6 +30,7 @@ use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::CommitTableMetaReq;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateTableReply;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
@@ -47,19 +49,29 @@ use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
 use databend_storages_common_table_meta::table::OPT_KEY_COMMENT;
+use databend_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TARGET_LAG;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use log::error;
 use log::info;
 
+use crate::interpreters::common::auto_increment_sequences;
+use crate::interpreters::common::create_auto_increment_sequences;
+use crate::interpreters::common::drop_auto_increment_sequences;
 use crate::interpreters::common::table_option_validation::is_valid_block_per_segment;
 use crate::interpreters::common::table_option_validation::is_valid_bloom_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_change_tracking;
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
+use crate::interpreters::common::table_option_validation::is_valid_dynamic_table_opt;
+use crate::interpreters::common::table_option_validation::is_valid_ngram_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_random_seed;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
+use crate::interpreters::common::table_option_validation::is_valid_statistics_column_groups;
+use crate::interpreters::common::table_option_validation::is_valid_ttl;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -173,7 +185,7 @@ impl CreateTableInterpreter {
         req.as_dropped = true;
         req.table_meta.drop_on = Some(Utc::now());
         let table_meta = req.table_meta.clone();
-        let reply = catalog.create_table(req.clone()).await?;
+        let reply = self.create_table_with_sequences(&catalog, &req).await?;
         if !reply.new_table && self.plan.create_option != CreateOption::CreateOrReplace {
             return Ok(PipelineBuildResult::create());
         }
@@ -204,6 +216,8 @@ impl CreateTableInterpreter {
                 RoleCacheManager::instance().invalidate_cache(&tenant);
             }
         }
+        self.register_ttl_table(&req.table_meta, db_id, table_id)
+            .await?;
 
         // If the table creation query contains column definitions, like 'CREATE TABLE t1(a int) AS SELECT * from t2',
         // we use the definitions to create the table schema. It may happen that the "AS SELECT" query's schema doesn't
@@ -228,6 +242,7 @@ impl CreateTableInterpreter {
             table: self.plan.table.clone(),
             schema: self.plan.schema.clone(),
             overwrite: false,
+            overwrite_partition: None,
             source: InsertInputSource::SelectPlan(select_plan),
             table_info: Some(table_info),
         };
@@ -321,7 +336,7 @@ impl CreateTableInterpreter {
             self.build_request(stat)
         }?;
 
-        let reply = catalog.create_table(req.clone()).await?;
+        let reply = self.create_table_with_sequences(&catalog, &req).await?;
 
         if !req.table_meta.options.contains_key(OPT_KEY_TEMP_PREFIX) {
             // grant the ownership of the table to the current role, the above req.table_meta.owner could be removed in future.
@@ -344,10 +359,55 @@ impl CreateTableInterpreter {
                 RoleCacheManager::instance().invalidate_cache(&tenant);
             }
         }
+        self.register_ttl_table(&req.table_meta, reply.db_id, reply.table_id)
+            .await?;
 
         Ok(PipelineBuildResult::create())
     }
 
+    /// Creates the table and the hidden sequences of its `AUTOINCREMENT` columns.
+    ///
+    /// The sequences are created first so that the table never exists without them,
+    /// and are dropped again if the table is not created.
+    #[async_backtrace::framed]
+    async fn create_table_with_sequences(
+        &self,
+        catalog: &Arc<dyn Catalog>,
+        req: &CreateTableReq,
+    ) -> Result<CreateTableReply> {
+        let sequences = auto_increment_sequences(&req.table_meta.schema);
+        create_auto_increment_sequences(self.ctx.clone(), &sequences).await?;
+
+        let res = catalog.create_table(req.clone()).await;
+        let created = match &res {
+            Ok(reply) => reply.new_table || req.create_option == CreateOption::CreateOrReplace,
+            Err(_) => false,
+        };
+        if !created {
+            drop_auto_increment_sequences(self.ctx.clone(), &sequences).await?;
+        }
+        res
+    }
+
+    /// Registers the table with ttl, so that the background compaction removes its expired rows.
+    #[async_backtrace::framed]
+    async fn register_ttl_table(
+        &self,
+        table_meta: &TableMeta,
+        db_id: u64,
+        table_id: u64,
+    ) -> Result<()> {
+        if !table_meta.options.contains_key(OPT_KEY_TTL)
+            || table_meta.options.contains_key(OPT_KEY_TEMP_PREFIX)
+        {
+            return Ok(());
+        }
+        UserApiProvider::instance()
+            .ttl_table_api(&self.ctx.get_tenant())?
+            .register(db_id, table_id)
+            .await
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
@@ -388,16 +448,25 @@ impl CreateTableInterpreter {
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        // check ngram_index_columns.
+        is_valid_ngram_index_columns(&table_meta.options, schema.clone())?;
+        // check statistics_column_groups.
+        is_valid_statistics_column_groups(&table_meta.options, schema.clone())?;
+        // check ttl.
+        is_valid_ttl(self.ctx.clone(), &table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
         // check random seed
         is_valid_random_seed(&table_meta.options)?;
         // check table level data_retention_period_in_hours
         is_valid_data_retention_period(&table_meta.options)?;
 
+        // the options of a dynamic table are set by the binder of `CREATE DYNAMIC TABLE`.
+        let is_dynamic_table = table_meta.options.contains_key(OPT_KEY_TARGET_LAG);
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
-            if !is_valid_create_opt(&key) {
+            if !is_valid_create_opt(&key) && !(is_dynamic_table && is_valid_dynamic_table_opt(&key))
+            {
                 error!("invalid opt for fuse table in create table statement");
                 return Err(ErrorCode::TableOptionInvalid(format!(
                     "table option {key} is invalid for create table statement",
@@ -409,6 +478,12 @@ impl CreateTableInterpreter {
             table_meta = table_meta.push_cluster_key(cluster_key.clone());
         }
 
+        if let Some(partition_key) = &self.plan.partition_key {
+            table_meta
+                .options
+                .insert(OPT_KEY_PARTITION_BY.to_owned(), partition_key.clone());
+        }
+
         let req = CreateTableReq {
             create_option: self.plan.create_option,
             name_ident: TableNameIdent {
@@ -28,7 +28,10 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use log::warn;
 
+use crate::dynamic_table::drop_refresh_task;
+use crate::dynamic_table::is_dynamic_table;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -148,6 +151,16 @@ impl Interpreter for DropTableInterpreter {
             RoleCacheManager::instance().invalidate_cache(&tenant);
         }
 
+        // stop refreshing the dropped dynamic table.
+        if is_dynamic_table(tbl.as_ref()) {
+            if let Err(cause) = drop_refresh_task(self.ctx.clone(), table_id).await {
+                warn!(
+                    "Failed to drop the refresh task of dynamic table {}.{}: {:?}",
+                    db_name, tbl_name, cause
+                );
+            }
+        }
+
         let mut build_res = PipelineBuildResult::create();
         // if `plan.all`, truncate, then purge the historical data
         if self.plan.all {
@@ -23,11 +23,15 @@ use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::DropTableColumnPlan;
 use databend_common_sql::BloomIndexColumns;
+use databend_common_sql::StatisticsColumnGroups;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_STATISTICS_COLUMN_GROUPS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::drop_auto_increment_sequences;
 use crate::interpreters::interpreter_table_add_column::generate_new_snapshot;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -102,8 +106,10 @@ impl Interpreter for DropTableColumnInterpreter {
             for (index_name, index) in &table_info.meta.indexes {
                 if index.column_ids.contains(&field.column_id) {
                     return Err(ErrorCode::ColumnReferencedByInvertedIndex(format!(
-                        "column `{}` is referenced by inverted index, drop inverted index `{}` first",
-                        field.name, index_name,
+                        "column `{}` is referenced by {} index, drop index `{}` first",
+                        field.name,
+                        index.index_type.to_string().to_lowercase(),
+                        index_name,
                     )));
                 }
             }
@@ -115,16 +121,23 @@ impl Interpreter for DropTableColumnInterpreter {
 
         // update table options
         let opts = &mut new_table_meta.options;
-        if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-            let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
-            if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
-                    // remove from the bloom index columns.
-                    cols.remove(pos);
-                    *value = cols.join(",");
+        for key in [OPT_KEY_BLOOM_INDEX_COLUMNS, OPT_KEY_NGRAM_INDEX_COLUMNS] {
+            if let Some(value) = opts.get_mut(key) {
+                let index_cols = value.parse::<BloomIndexColumns>()?;
+                if let BloomIndexColumns::Specify(mut cols) = index_cols {
+                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
+                        // remove from the index columns.
+                        cols.remove(pos);
+                        *value = cols.join(",");
+                    }
                 }
             }
         }
+        if let Some(value) = opts.get_mut(OPT_KEY_STATISTICS_COLUMN_GROUPS) {
+            let mut groups = value.parse::<StatisticsColumnGroups>()?;
+            groups.remove_column(&self.plan.column);
+            *value = groups.to_string();
+        }
 
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
@@ -139,6 +152,11 @@ impl Interpreter for DropTableColumnInterpreter {
 
         let _resp = catalog.update_single_table_meta(req, table_info).await?;
 
+        // the hidden sequence of an AUTOINCREMENT column is owned by the column.
+        if let Some(expr) = field.auto_increment_expr() {
+            drop_auto_increment_sequences(self.ctx.clone(), &[expr.sequence_name.clone()]).await?;
+        }
+
         Ok(PipelineBuildResult::create())
     }
 }
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::DropTablePartitionPlan;
+use databend_common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropTablePartitionInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTablePartitionPlan,
+}
+
+impl DropTablePartitionInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTablePartitionPlan) -> Result<Self> {
+        Ok(DropTablePartitionInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTablePartitionInterpreter {
+    fn name(&self) -> &str {
+        "DropTablePartitionInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // try add lock table.
+        let _lock_guard = self
+            .ctx
+            .clone()
+            .acquire_table_lock(
+                &self.plan.catalog,
+                &self.plan.database,
+                &self.plan.table,
+                &LockTableOption::LockWithRetry,
+            )
+            .await?;
+
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+        // check mutability
+        table.check_mutable()?;
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let removed_rows = fuse_table
+            .drop_partition(self.ctx.clone(), &self.plan.partition_value)
+            .await?;
+        if removed_rows == 0 && !self.plan.if_exists {
+            return Err(ErrorCode::UnknownPartition(format!(
+                "Unknown partition ({}) of table '{}.{}'",
+                self.plan.partition_value, self.plan.database, self.plan.table
+            )));
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
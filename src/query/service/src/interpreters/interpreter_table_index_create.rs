@@ -18,6 +18,7 @@ use databend_common_exception::Result;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_meta_app::schema::CreateTableIndexReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::CreateTableIndexPlan;
 use databend_common_storages_fuse::TableContext;
 use databend_enterprise_inverted_index::get_inverted_index_handler;
@@ -49,8 +50,12 @@ impl Interpreter for CreateTableIndexInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let feature = match self.plan.index_type {
+            TableIndexType::Inverted => Feature::InvertedIndex,
+            TableIndexType::Vector => Feature::VectorIndex,
+        };
         LicenseManagerSwitch::instance()
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+            .check_enterprise_enabled(self.ctx.get_license_key(), feature)?;
 
         let index_name = self.plan.index_name.clone();
         let column_ids = self.plan.column_ids.clone();
@@ -67,6 +72,7 @@ impl Interpreter for CreateTableIndexInterpreter {
             column_ids,
             sync_creation,
             options: self.plan.index_options.clone(),
+            index_type: self.plan.index_type.clone(),
         };
 
         let handler = get_inverted_index_handler();
@@ -20,6 +20,7 @@ use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::LicenseManagerSwitch;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::RefreshTableIndexPlan;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
@@ -51,8 +52,12 @@ impl Interpreter for RefreshTableIndexInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let feature = match self.plan.index_type {
+            TableIndexType::Inverted => Feature::InvertedIndex,
+            TableIndexType::Vector => Feature::VectorIndex,
+        };
         LicenseManagerSwitch::instance()
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+            .check_enterprise_enabled(self.ctx.get_license_key(), feature)?;
 
         let table = self
             .ctx
@@ -64,10 +69,18 @@ impl Interpreter for RefreshTableIndexInterpreter {
         let index_name = self.plan.index_name.clone();
         let segment_locs = self.plan.segment_locs.clone();
         let table_meta = &table.get_table_info().meta;
-        let Some(index) = table_meta.indexes.get(&index_name) else {
+        let index_desc = match self.plan.index_type {
+            TableIndexType::Inverted => "Inverted",
+            TableIndexType::Vector => "Vector",
+        };
+        let Some(index) = table_meta
+            .indexes
+            .get(&index_name)
+            .filter(|index| index.index_type == self.plan.index_type)
+        else {
             return Err(ErrorCode::RefreshIndexError(format!(
-                "Inverted index {} does not exist",
-                index_name
+                "{} index {} does not exist",
+                index_desc, index_name
             )));
         };
         let mut index_fields = Vec::with_capacity(index.column_ids.len());
@@ -81,8 +94,8 @@ impl Interpreter for RefreshTableIndexInterpreter {
         }
         if index_fields.len() != index.column_ids.len() {
             return Err(ErrorCode::RefreshIndexError(format!(
-                "Inverted index {} is invalid",
-                index_name
+                "{} index {} is invalid",
+                index_desc, index_name
             )));
         }
         let index_version = index.version.clone();
@@ -91,17 +104,34 @@ impl Interpreter for RefreshTableIndexInterpreter {
         let mut build_res = PipelineBuildResult::create();
 
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
-        fuse_table
-            .do_refresh_inverted_index(
-                self.ctx.clone(),
-                index_name,
-                index_version,
-                &index.options,
-                index_schema,
-                segment_locs,
-                &mut build_res.main_pipeline,
-            )
-            .await?;
+        match index.index_type {
+            TableIndexType::Inverted => {
+                fuse_table
+                    .do_refresh_inverted_index(
+                        self.ctx.clone(),
+                        index_name,
+                        index_version,
+                        &index.options,
+                        index_schema,
+                        segment_locs,
+                        &mut build_res.main_pipeline,
+                    )
+                    .await?;
+            }
+            TableIndexType::Vector => {
+                fuse_table
+                    .do_refresh_vector_index(
+                        self.ctx.clone(),
+                        index_name,
+                        index_version,
+                        &index.options,
+                        index_schema,
+                        segment_locs,
+                        &mut build_res.main_pipeline,
+                    )
+                    .await?;
+            }
+        }
 
         Ok(build_res)
     }
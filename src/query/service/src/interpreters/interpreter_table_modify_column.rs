@@ -48,7 +48,9 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_common_users::UserApiProvider;
 use databend_enterprise_data_mask_feature::get_datamask_handler;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::NgramIndex;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::Interpreter;
@@ -176,6 +178,12 @@ impl ModifyTableColumnInterpreter {
                 bloom_index_cols = cols;
             }
         }
+        let mut ngram_index_cols = vec![];
+        if let Some(v) = table_info.options().get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+            if let BloomIndexColumns::Specify(cols) = v.parse::<BloomIndexColumns>()? {
+                ngram_index_cols = cols;
+            }
+        }
 
         let mut table_info = table.get_table_info().clone();
         table_info.meta.fill_field_comments();
@@ -204,6 +212,14 @@ impl ModifyTableColumnInterpreter {
                             data_type
                         )));
                     }
+                    if ngram_index_cols.iter().any(|v| v.as_str() == column)
+                        && !NgramIndex::supported_type(data_type)
+                    {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "Unsupported data type '{}' for ngram index",
+                            data_type
+                        )));
+                    }
                     // If the column is inverted index column, the type can't be changed.
                     if !table_info.meta.indexes.is_empty() {
                         for (index_name, index) in &table_info.meta.indexes {
@@ -212,8 +228,10 @@ impl ModifyTableColumnInterpreter {
                                     != field.data_type.remove_nullable()
                             {
                                 return Err(ErrorCode::ColumnReferencedByInvertedIndex(format!(
-                                    "column `{}` is referenced by inverted index, drop inverted index `{}` first",
-                                    column, index_name,
+                                    "column `{}` is referenced by {} index, drop index `{}` first",
+                                    column,
+                                    index.index_type.to_string().to_lowercase(),
+                                    index_name,
                                 )));
                             }
                         }
@@ -300,6 +318,7 @@ impl ModifyTableColumnInterpreter {
                         data_type: old_data_type,
                         column_id: old_column_id,
                         computed_expr: old_computed_expr,
+                        auto_increment_expr: old_auto_increment_expr,
                     } = old_field;
                     let TableField {
                         name: new_name,
@@ -307,11 +326,13 @@ impl ModifyTableColumnInterpreter {
                         data_type: new_data_type,
                         column_id: new_column_id,
                         computed_expr: new_computed_expr,
+                        auto_increment_expr: new_auto_increment_expr,
                     } = new_field;
                     old_name == new_name
                         && old_default_expr == new_default_expr
                         && old_column_id == new_column_id
                         && old_computed_expr == new_computed_expr
+                        && old_auto_increment_expr == new_auto_increment_expr
                         && (old_data_type == new_data_type
                             || is_string_to_binary(&old_field.data_type, &new_field.data_type))
                 });
@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::RefreshTableNgramIndexPlan;
+use databend_common_sql::BloomIndexColumns;
+use databend_common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshTableNgramIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshTableNgramIndexPlan,
+}
+
+impl RefreshTableNgramIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshTableNgramIndexPlan) -> Result<Self> {
+        Ok(RefreshTableNgramIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshTableNgramIndexInterpreter {
+    fn name(&self) -> &str {
+        "RefreshTableNgramIndexInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+        // check mutability
+        table.check_mutable()?;
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        if matches!(fuse_table.ngram_index_cols(), BloomIndexColumns::None) {
+            return Err(ErrorCode::RefreshIndexError(format!(
+                "Table {}.{} has no ngram index columns",
+                self.plan.database, self.plan.table
+            )));
+        }
+
+        let mut build_res = PipelineBuildResult::create();
+        fuse_table
+            .do_refresh_ngram_index(
+                self.ctx.clone(),
+                self.plan.limit,
+                &mut build_res.main_pipeline,
+            )
+            .await?;
+
+        Ok(build_res)
+    }
+}
@@ -18,7 +18,10 @@ use databend_common_exception::Result;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_sql::plans::RenameTablePlan;
+use log::warn;
 
+use crate::dynamic_table::is_dynamic_table;
+use crate::dynamic_table::rename_refresh_task;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -64,6 +67,32 @@ impl Interpreter for RenameTableInterpreter {
             })
             .await?;
 
+        // keep refreshing the renamed dynamic table.
+        let renamed = catalog
+            .get_table(
+                &self.plan.tenant,
+                &self.plan.new_database,
+                &self.plan.new_table,
+            )
+            .await;
+        if let Ok(table) = renamed {
+            if is_dynamic_table(table.as_ref()) {
+                if let Err(cause) = rename_refresh_task(
+                    self.ctx.clone(),
+                    &self.plan.new_database,
+                    &self.plan.new_table,
+                    table.get_id(),
+                )
+                .await
+                {
+                    warn!(
+                        "Failed to rename the refresh task of dynamic table {}.{}: {:?}",
+                        self.plan.new_database, self.plan.new_table, cause
+                    );
+                }
+            }
+        }
+
         Ok(PipelineBuildResult::create())
     }
 }
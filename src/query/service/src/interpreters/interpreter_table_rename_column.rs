@@ -23,9 +23,12 @@ use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::RenameTableColumnPlan;
 use databend_common_sql::BloomIndexColumns;
+use databend_common_sql::StatisticsColumnGroups;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_STATISTICS_COLUMN_GROUPS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::interpreter_table_create::is_valid_column;
@@ -109,16 +112,23 @@ impl Interpreter for RenameTableColumnInterpreter {
 
             // update table options
             let opts = &mut new_table_meta.options;
-            if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-                let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
-                if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.old_column) {
-                        // replace the bloom index columns with new column name.
-                        cols[pos] = self.plan.new_column.clone();
-                        *value = cols.join(",");
+            for key in [OPT_KEY_BLOOM_INDEX_COLUMNS, OPT_KEY_NGRAM_INDEX_COLUMNS] {
+                if let Some(value) = opts.get_mut(key) {
+                    let index_cols = value.parse::<BloomIndexColumns>()?;
+                    if let BloomIndexColumns::Specify(mut cols) = index_cols {
+                        if let Some(pos) = cols.iter().position(|x| *x == self.plan.old_column) {
+                            // replace the index columns with new column name.
+                            cols[pos] = self.plan.new_column.clone();
+                            *value = cols.join(",");
+                        }
                     }
                 }
             }
+            if let Some(value) = opts.get_mut(OPT_KEY_STATISTICS_COLUMN_GROUPS) {
+                let mut groups = value.parse::<StatisticsColumnGroups>()?;
+                groups.rename_column(&self.plan.old_column, &self.plan.new_column);
+                *value = groups.to_string();
+            }
 
             let table_id = table_info.ident.table_id;
             let table_version = table_info.ident.seq;
@@ -0,0 +1,128 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::LicenseManagerSwitch;
+use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyAction;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
+use databend_common_meta_app::schema::TableRowAccessPolicy;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::AddTableRowAccessPolicyPlan;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_data_mask_feature::get_row_access_policy_handler;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AddTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableRowAccessPolicyPlan,
+}
+
+impl AddTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(AddTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AddTableRowAccessPolicyInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        LicenseManagerSwitch::instance()
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::RowAccessPolicy)?;
+
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+
+        let table_info = table.get_table_info();
+        let engine = table.engine();
+        if matches!(engine, VIEW_ENGINE | STREAM_ENGINE) || table.is_temp() {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support row access policy",
+                plan.database, plan.table, engine
+            )));
+        }
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support row access policy",
+                plan.database, plan.table
+            )));
+        }
+        if let Some(prev) = &table_info.meta.row_access_policy {
+            return Err(ErrorCode::BadArguments(format!(
+                "Table {}.{} already has row access policy {}",
+                plan.database, plan.table, prev.policy
+            )));
+        }
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let policy = get_row_access_policy_handler()
+            .get_row_access_policy(meta_api, &plan.tenant, plan.policy.clone())
+            .await?;
+        if policy.args.len() != plan.columns.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Row access policy {} expects {} columns, but got {}",
+                plan.policy,
+                policy.args.len(),
+                plan.columns.len()
+            )));
+        }
+        let schema = table.schema();
+        for column in plan.columns.iter() {
+            if schema.column_with_name(column).is_none() {
+                return Err(ErrorCode::UnknownColumn(format!(
+                    "Cannot find column {}",
+                    column
+                )));
+            }
+        }
+
+        let req = SetTableRowAccessPolicyReq {
+            tenant: plan.tenant.clone(),
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            action: SetTableRowAccessPolicyAction::Set(
+                TableRowAccessPolicy {
+                    policy: plan.policy.clone(),
+                    columns: plan.columns.clone(),
+                },
+                None,
+            ),
+        };
+        catalog.set_table_row_access_policy(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -22,19 +22,25 @@ use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::SetOptionsPlan;
 use databend_common_storages_fuse::TableContext;
+use databend_common_users::UserApiProvider;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use log::error;
 
 use crate::interpreters::common::table_option_validation::is_valid_block_per_segment;
 use crate::interpreters::common::table_option_validation::is_valid_bloom_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
+use crate::interpreters::common::table_option_validation::is_valid_ngram_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
+use crate::interpreters::common::table_option_validation::is_valid_statistics_column_groups;
+use crate::interpreters::common::table_option_validation::is_valid_ttl;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -101,6 +107,13 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_CLUSTER_TYPE
             )));
         }
+        if self.plan.set_options.contains_key(OPT_KEY_PARTITION_BY) {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_PARTITION_BY
+            )));
+        }
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
             if !is_valid_create_opt(&key) {
@@ -136,6 +149,12 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check ngram_index_columns.
+        is_valid_ngram_index_columns(&self.plan.set_options, table.schema())?;
+        // check statistics_column_groups.
+        is_valid_statistics_column_groups(&self.plan.set_options, table.schema())?;
+        // check ttl.
+        is_valid_ttl(self.ctx.clone(), &self.plan.set_options, table.schema())?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
@@ -146,6 +165,16 @@ impl Interpreter for SetOptionsInterpreter {
         let _resp = catalog
             .upsert_table_option(&self.ctx.get_tenant(), database, req)
             .await?;
+
+        // register the table with ttl, so that the background compaction removes its expired rows.
+        if self.plan.set_options.contains_key(OPT_KEY_TTL) && !table.is_temp() {
+            let tenant = self.ctx.get_tenant();
+            let db = catalog.get_database(&tenant, database).await?;
+            UserApiProvider::instance()
+                .ttl_table_api(&tenant)?
+                .register(db.get_db_info().database_id.db_id, table.get_id())
+                .await?;
+        }
         Ok(PipelineBuildResult::create())
     }
 }
@@ -26,6 +26,7 @@ use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
 use databend_common_expression::Value;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::ShowCreateTablePlan;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
@@ -34,6 +35,7 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_internal_opt_key;
 use databend_storages_common_table_meta::table::StreamMode;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
+use databend_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
@@ -180,6 +182,15 @@ impl ShowCreateTableInterpreter {
                     }
                     None => "".to_string(),
                 };
+                let auto_increment_expr = match field.auto_increment_expr() {
+                    Some(expr) => {
+                        format!(
+                            " AUTOINCREMENT START {} INCREMENT {}",
+                            expr.start, expr.step
+                        )
+                    }
+                    None => "".to_string(),
+                };
                 let computed_expr = match field.computed_expr() {
                     Some(ComputedExpr::Virtual(expr)) => {
                         format!(" AS ({expr}) VIRTUAL")
@@ -202,11 +213,12 @@ impl ShowCreateTableInterpreter {
                     "".to_string()
                 };
                 let column_str = format!(
-                    "  {} {}{}{}{}{}",
+                    "  {} {}{}{}{}{}{}",
                     display_ident(field.name(), quoted_ident_case_sensitive, sql_dialect),
                     field.data_type().remove_recursive_nullable().sql_name(),
                     nullable,
                     default_expr,
+                    auto_increment_expr,
                     computed_expr,
                     comment
                 );
@@ -215,6 +227,10 @@ impl ShowCreateTableInterpreter {
             }
 
             for index_field in table_info.meta.indexes.values() {
+                // only inverted indexes can be defined in the create table statement.
+                if index_field.index_type != TableIndexType::Inverted {
+                    continue;
+                }
                 let sync = if index_field.sync_creation {
                     "SYNC"
                 } else {
@@ -256,6 +272,10 @@ impl ShowCreateTableInterpreter {
         let table_engine = format!(") ENGINE={}", engine);
         table_create_sql.push_str(table_engine.as_str());
 
+        if let Some(partition_key_str) = table_info.options().get(OPT_KEY_PARTITION_BY) {
+            table_create_sql.push_str(format!(" PARTITION BY ({})", partition_key_str).as_str());
+        }
+
         if let Some((_, cluster_keys_str)) = table_info.meta.cluster_key() {
             let cluster_type = table_info
                 .options()
@@ -22,6 +22,8 @@ use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::UnsetOptionsPlan;
 use databend_common_storages_fuse::TableContext;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::interpreters::common::table_option_validation::UNSET_TABLE_OPTIONS_WHITE_LIST;
 use crate::interpreters::Interpreter;
@@ -92,6 +94,13 @@ impl Interpreter for UnsetOptionsInterpreter {
         catalog
             .upsert_table_option(&self.ctx.get_tenant(), database, req)
             .await?;
+
+        if self.plan.options.iter().any(|opt| opt == OPT_KEY_TTL) {
+            UserApiProvider::instance()
+                .ttl_table_api(&self.ctx.get_tenant())?
+                .unregister(table.get_id())
+                .await?;
+        }
         Ok(PipelineBuildResult::create())
     }
 }
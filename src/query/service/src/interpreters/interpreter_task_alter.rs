@@ -62,6 +62,7 @@ impl AlterTaskInterpreter {
             alter_task_type: 0,
             if_exist: plan.if_exists,
             error_integration: None,
+            success_integration: None,
             task_sql_type: 0,
             query_text: None,
             comment: None,
@@ -88,6 +89,7 @@ impl AlterTaskInterpreter {
                 warehouse,
                 suspend_task_after_num_failures,
                 error_integration,
+                success_integration,
                 session_parameters,
             } => {
                 req.alter_task_type = AlterTaskType::Set as i32;
@@ -98,6 +100,7 @@ impl AlterTaskInterpreter {
                     using_warehouse_size: None,
                 });
                 req.error_integration = error_integration;
+                req.success_integration = success_integration;
                 req.suspend_task_after_num_failures =
                     suspend_task_after_num_failures.map(|i| i as i32);
                 if let Some(session_parameters) = session_parameters {
@@ -153,9 +156,9 @@ impl Interpreter for AlterTaskInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
-        if config.query.cloud_control_grpc_server_address.is_none() {
+        if !config.query.task_service_enabled() {
             return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot alter task without cloud control enabled, please set cloud_control_grpc_server_address in config",
+                "cannot alter task without cloud control enabled, please set cloud_control_grpc_server_address or enable_builtin_task_scheduler in config",
             ));
         }
         let cloud_api = CloudControlApiProvider::instance();
@@ -62,6 +62,7 @@ impl CreateTaskInterpreter {
             schedule_options: plan.schedule_opts.map(make_schedule_options),
             warehouse_options: Some(make_warehouse_options(plan.warehouse_opts)),
             error_integration: plan.error_integration,
+            success_integration: plan.success_integration,
             task_sql_type: 0,
             suspend_task_after_num_failures: plan.suspend_task_after_num_failures.map(|x| x as i32),
             if_not_exist: plan.if_not_exists,
@@ -99,9 +100,9 @@ impl Interpreter for CreateTaskInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
-        if config.query.cloud_control_grpc_server_address.is_none() {
+        if !config.query.task_service_enabled() {
             return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create task without cloud control enabled, please set cloud_control_grpc_server_address in config",
+                "cannot create task without cloud control enabled, please set cloud_control_grpc_server_address or enable_builtin_task_scheduler in config",
             ));
         }
         let cloud_api = CloudControlApiProvider::instance();
@@ -34,6 +34,8 @@ use databend_common_sql::plans::VacuumDropTablePlan;
 use databend_enterprise_vacuum_handler::get_vacuum_handler;
 use log::info;
 
+use crate::interpreters::common::auto_increment_sequences;
+use crate::interpreters::common::drop_auto_increment_sequences;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -164,6 +166,13 @@ impl Interpreter for VacuumDropTablesInterpreter {
             .filter(|tbl| !tbl.as_ref().is_read_only())
             .collect::<Vec<_>>();
 
+        // the hidden sequences of AUTOINCREMENT columns are purged with the table.
+        let table_sequences = tables
+            .iter()
+            .map(|tbl| (tbl.get_id(), auto_increment_sequences(&tbl.schema())))
+            .filter(|(_, sequences)| !sequences.is_empty())
+            .collect::<Vec<_>>();
+
         let handler = get_vacuum_handler();
         let threads_nums = self.ctx.get_settings().get_max_threads()? as usize;
         let (files_opt, failed_tables) = handler
@@ -206,6 +215,12 @@ impl Interpreter for VacuumDropTablesInterpreter {
                 failed_db_ids, failed_tables, success_dropped_ids
             );
             self.gc_drop_tables(catalog, success_dropped_ids).await?;
+
+            for (table_id, sequences) in table_sequences {
+                if !failed_tables.contains(&table_id) {
+                    drop_auto_increment_sequences(self.ctx.clone(), &sequences).await?;
+                }
+            }
         }
 
         match files_opt {
@@ -39,6 +39,8 @@ mod interpreter_database_undrop;
 mod interpreter_dictionary_create;
 mod interpreter_dictionary_drop;
 mod interpreter_dictionary_show_create;
+mod interpreter_dynamic_table_create;
+mod interpreter_dynamic_table_refresh;
 mod interpreter_execute_immediate;
 mod interpreter_explain;
 mod interpreter_factory;
@@ -75,6 +77,9 @@ mod interpreter_privilege_revoke;
 mod interpreter_procedure_call;
 mod interpreter_procedure_create;
 mod interpreter_procedure_drop;
+mod interpreter_query_hint_create;
+mod interpreter_query_hint_drop;
+mod interpreter_query_hints_show;
 mod interpreter_replace;
 mod interpreter_role_create;
 mod interpreter_role_drop;
@@ -83,8 +88,13 @@ mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_set_secondary;
 mod interpreter_role_show;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_desc;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select;
+mod interpreter_sequence_alter;
 mod interpreter_sequence_create;
+mod interpreter_sequence_desc;
 mod interpreter_sequence_drop;
 mod interpreter_set;
 mod interpreter_set_priority;
@@ -97,16 +107,20 @@ mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_partition;
 mod interpreter_table_exists;
 mod interpreter_table_index_create;
 mod interpreter_table_index_drop;
 mod interpreter_table_index_refresh;
 mod interpreter_table_modify_column;
 mod interpreter_table_modify_comment;
+mod interpreter_table_ngram_index_refresh;
 mod interpreter_table_recluster;
 mod interpreter_table_rename;
 mod interpreter_table_rename_column;
 mod interpreter_table_revert;
+mod interpreter_table_row_access_policy_add;
+mod interpreter_table_row_access_policy_drop;
 mod interpreter_table_set_options;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
@@ -186,6 +200,9 @@ pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
 pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
+pub use interpreter_query_hint_create::CreateQueryHintInterpreter;
+pub use interpreter_query_hint_drop::DropQueryHintInterpreter;
+pub use interpreter_query_hints_show::ShowQueryHintsInterpreter;
 pub use interpreter_replace::ReplaceInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
@@ -193,8 +210,13 @@ pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
 pub use interpreter_role_set_secondary::SetSecondaryRolesInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_desc::DescRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select::SelectInterpreter;
+pub use interpreter_sequence_alter::AlterSequenceInterpreter;
 pub use interpreter_sequence_create::CreateSequenceInterpreter;
+pub use interpreter_sequence_desc::DescSequenceInterpreter;
 pub use interpreter_sequence_drop::DropSequenceInterpreter;
 pub use interpreter_set::SetInterpreter;
 pub use interpreter_set_priority::SetPriorityInterpreter;
@@ -207,15 +229,19 @@ pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_partition::DropTablePartitionInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
 pub use interpreter_table_index_create::CreateTableIndexInterpreter;
 pub use interpreter_table_index_drop::DropTableIndexInterpreter;
 pub use interpreter_table_index_refresh::RefreshTableIndexInterpreter;
 pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_modify_comment::ModifyTableCommentInterpreter;
+pub use interpreter_table_ngram_index_refresh::RefreshTableNgramIndexInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
+pub use interpreter_table_row_access_policy_add::AddTableRowAccessPolicyInterpreter;
+pub use interpreter_table_row_access_policy_drop::DropTableRowAccessPolicyInterpreter;
 pub use interpreter_table_show_create::ShowCreateQuerySettings;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
@@ -42,13 +42,17 @@
 
 extern crate core;
 
+pub mod audit;
 pub mod auth;
 pub mod catalogs;
 pub mod clusters;
 pub mod databases;
+pub mod dynamic_table;
+pub mod history;
 pub mod interpreters;
 pub mod local;
 pub mod locks;
+pub mod notification;
 pub mod pipelines;
 pub mod schedulers;
 pub mod servers;
@@ -56,6 +60,7 @@ pub mod sessions;
 pub mod spillers;
 pub mod stream;
 pub mod table_functions;
+pub mod task;
 pub mod test_kits;
 
 mod builtin;
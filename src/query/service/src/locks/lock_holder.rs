@@ -126,6 +126,8 @@ impl LockHolder {
                 key: watch_delete_ident.to_string_key(),
                 key_end: None,
                 filter_type: FilterType::Delete.into(),
+                initial_flush: false,
+                since_seq: None,
             };
             let mut watch_stream = meta_api.watch(req).await?;
 
@@ -0,0 +1,282 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_cloud_control::notification_utils::parse_timestamp;
+use databend_common_cloud_control::pb;
+use databend_common_cloud_control::pb::notification_service_server::NotificationService;
+use databend_common_cloud_control::utils::Timestamp;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::NotificationMgr;
+use databend_common_meta_types::MatchSeq;
+use tonic::Request;
+use tonic::Response;
+
+use crate::notification::NotificationSender;
+
+/// The default and the max number of records returned by `ListNotificationHistory`.
+const DEFAULT_HISTORY_LIMIT: usize = 100;
+const MAX_HISTORY_LIMIT: usize = 10000;
+
+/// Serves the notification requests of the query nodes with the integrations stored in meta,
+/// the same way as cloud control does.
+///
+/// The tenant in the requests is ignored, it always serves the integrations of the tenant of this node.
+pub struct BuiltinNotificationService {
+    sender: Arc<NotificationSender>,
+}
+
+impl BuiltinNotificationService {
+    pub fn create(sender: Arc<NotificationSender>) -> Arc<BuiltinNotificationService> {
+        Arc::new(BuiltinNotificationService { sender })
+    }
+
+    fn notification_mgr(&self) -> Arc<NotificationMgr> {
+        self.sender.notification_mgr()
+    }
+
+    fn unknown_notification(name: &str) -> ErrorCode {
+        ErrorCode::UnknownNotification(format!("Notification '{}' does not exist", name))
+    }
+
+    fn now() -> Option<Timestamp> {
+        let now = Utc::now();
+        Some(Timestamp {
+            seconds: now.timestamp(),
+            nanos: now.timestamp_subsec_nanos() as i32,
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn do_create_notification(
+        &self,
+        req: pb::CreateNotificationRequest,
+    ) -> Result<pb::CreateNotificationResponse> {
+        if req.webhook_url.is_empty() {
+            return Err(ErrorCode::BadArguments(
+                "Webhook url is required in Webhook Type Notification",
+            ));
+        }
+
+        let notification = pb::Notification {
+            notification_id: 0,
+            tenant_id: req.tenant_id,
+            name: req.name,
+            notification_type: req.notification_type,
+            enabled: req.enabled,
+            webhook_url: req.webhook_url,
+            webhook_method: req.webhook_method,
+            webhook_authorization_header: req.webhook_authorization_header,
+            webhook_signing_secret: req.webhook_signing_secret,
+            comments: req.comments,
+            created_time: Self::now(),
+            created_by: "".to_string(),
+            updated_time: Self::now(),
+            updated_by: "".to_string(),
+        };
+
+        let notification_id = self
+            .notification_mgr()
+            .create_notification(notification, req.if_not_exists)
+            .await?;
+        Ok(pb::CreateNotificationResponse {
+            error: None,
+            notification_id,
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn do_drop_notification(
+        &self,
+        req: pb::DropNotificationRequest,
+    ) -> Result<pb::DropNotificationResponse> {
+        let dropped = self.notification_mgr().drop_notification(&req.name).await?;
+
+        if dropped.is_none() && !req.if_exists {
+            return Err(Self::unknown_notification(&req.name));
+        }
+        Ok(pb::DropNotificationResponse::default())
+    }
+
+    #[async_backtrace::framed]
+    async fn do_get_notification(
+        &self,
+        req: pb::GetNotificationRequest,
+    ) -> Result<pb::GetNotificationResponse> {
+        match self.notification_mgr().get_notification(&req.name).await? {
+            Some((_seq, notification)) => Ok(pb::GetNotificationResponse {
+                error: None,
+                notification: Some(notification),
+            }),
+            None => Err(Self::unknown_notification(&req.name)),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn do_alter_notification(
+        &self,
+        req: pb::AlterNotificationRequest,
+    ) -> Result<pb::AlterNotificationResponse> {
+        if !req.operation_type.eq_ignore_ascii_case("SET") {
+            return Err(ErrorCode::BadArguments(format!(
+                "Unsupported notification operation {}",
+                req.operation_type
+            )));
+        }
+
+        let notification_mgr = self.notification_mgr();
+
+        // Retry if the integration is changed by others during altering.
+        loop {
+            let Some((seq, mut notification)) =
+                notification_mgr.get_notification(&req.name).await?
+            else {
+                return Err(Self::unknown_notification(&req.name));
+            };
+
+            if let Some(enabled) = req.enabled {
+                notification.enabled = enabled;
+            }
+            if let Some(webhook_url) = req.webhook_url.clone().filter(|url| !url.is_empty()) {
+                notification.webhook_url = webhook_url;
+            }
+            if req.webhook_method.is_some() {
+                notification.webhook_method = req.webhook_method.clone();
+            }
+            if req.webhook_authorization_header.is_some() {
+                notification.webhook_authorization_header =
+                    req.webhook_authorization_header.clone();
+            }
+            if req.webhook_signing_secret.is_some() {
+                notification.webhook_signing_secret = req.webhook_signing_secret.clone();
+            }
+            if req.comments.is_some() {
+                notification.comments = req.comments.clone();
+            }
+            notification.updated_time = Self::now();
+
+            if notification_mgr
+                .update_notification(&notification, MatchSeq::Exact(seq))
+                .await?
+            {
+                return Ok(pb::AlterNotificationResponse {
+                    error: None,
+                    notification_id: notification.notification_id,
+                });
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn do_list_notification_history(
+        &self,
+        req: pb::ListNotificationHistoryRequest,
+    ) -> Result<pb::ListNotificationHistoryResponse> {
+        let start_time = req
+            .start_time
+            .map(|t| parse_timestamp(Some(t)))
+            .transpose()?;
+        let end_time = req.end_time.map(|t| parse_timestamp(Some(t))).transpose()?;
+
+        let mut histories = vec![];
+        for history in self
+            .notification_mgr()
+            .list_notification_history(req.notification_name.as_deref())
+            .await?
+        {
+            let created_time = parse_timestamp(history.created_time.clone())?;
+            if matches!(start_time, Some(start) if created_time < start)
+                || matches!(end_time, Some(end) if created_time > end)
+            {
+                continue;
+            }
+            histories.push((created_time, history));
+        }
+
+        // Newest first.
+        histories.sort_by(|a, b| b.0.cmp(&a.0));
+
+        let limit = match req.result_limit {
+            Some(limit) if limit > 0 => (limit as usize).min(MAX_HISTORY_LIMIT),
+            _ => DEFAULT_HISTORY_LIMIT,
+        };
+        histories.truncate(limit);
+
+        Ok(pb::ListNotificationHistoryResponse {
+            error: None,
+            notification_histories: histories.into_iter().map(|(_, h)| h).collect(),
+            next_page_token: 0,
+            previous_page_token: 0,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationService for BuiltinNotificationService {
+    async fn create_notification(
+        &self,
+        request: Request<pb::CreateNotificationRequest>,
+    ) -> std::result::Result<Response<pb::CreateNotificationResponse>, tonic::Status> {
+        let resp = self.do_create_notification(request.into_inner()).await?;
+        Ok(Response::new(resp))
+    }
+
+    async fn drop_notification(
+        &self,
+        request: Request<pb::DropNotificationRequest>,
+    ) -> std::result::Result<Response<pb::DropNotificationResponse>, tonic::Status> {
+        let resp = self.do_drop_notification(request.into_inner()).await?;
+        Ok(Response::new(resp))
+    }
+
+    async fn list_notification(
+        &self,
+        _request: Request<pb::ListNotificationRequest>,
+    ) -> std::result::Result<Response<pb::ListNotificationResponse>, tonic::Status> {
+        let notifications = self.notification_mgr().list_notifications().await?;
+        Ok(Response::new(pb::ListNotificationResponse {
+            error: None,
+            notifications,
+        }))
+    }
+
+    async fn get_notification(
+        &self,
+        request: Request<pb::GetNotificationRequest>,
+    ) -> std::result::Result<Response<pb::GetNotificationResponse>, tonic::Status> {
+        let resp = self.do_get_notification(request.into_inner()).await?;
+        Ok(Response::new(resp))
+    }
+
+    async fn alter_notification(
+        &self,
+        request: Request<pb::AlterNotificationRequest>,
+    ) -> std::result::Result<Response<pb::AlterNotificationResponse>, tonic::Status> {
+        let resp = self.do_alter_notification(request.into_inner()).await?;
+        Ok(Response::new(resp))
+    }
+
+    async fn list_notification_history(
+        &self,
+        request: Request<pb::ListNotificationHistoryRequest>,
+    ) -> std::result::Result<Response<pb::ListNotificationHistoryResponse>, tonic::Status> {
+        let resp = self
+            .do_list_notification_history(request.into_inner())
+            .await?;
+        Ok(Response::new(resp))
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_base::base::tokio::time::sleep as tokio_async_sleep;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_cloud_control::pb;
+use databend_common_cloud_control::utils::Timestamp;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::NotificationMgr;
+use databend_common_users::UserApiProvider;
+use hmac::Hmac;
+use hmac::Mac;
+use log::info;
+use log::warn;
+use sha2::Sha256;
+use uuid::Uuid;
+
+/// Header of the HMAC-SHA256 signature of a webhook request, as `sha256=<hex>`.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Databend-Signature";
+/// Header of the unix timestamp in seconds at which a webhook request is signed.
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Databend-Timestamp";
+
+const WEBHOOK_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const WEBHOOK_MAX_ATTEMPTS: u32 = 3;
+const WEBHOOK_RETRY_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Delivers notifications to the webhooks of the notification integrations stored in meta,
+/// for deployments without cloud control.
+///
+/// Each delivery is recorded in the notification history, whether it succeeds or not.
+pub struct NotificationSender {
+    notification_mgr: Arc<NotificationMgr>,
+    client: reqwest::Client,
+    retry_interval: Duration,
+}
+
+impl NotificationSender {
+    pub fn create(
+        notification_mgr: Arc<NotificationMgr>,
+        retry_interval: Duration,
+    ) -> Result<Arc<NotificationSender>> {
+        let client = reqwest::Client::builder()
+            .timeout(WEBHOOK_REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| ErrorCode::Internal(format!("Cannot create webhook client: {e}")))?;
+
+        Ok(Arc::new(NotificationSender {
+            notification_mgr,
+            client,
+            retry_interval,
+        }))
+    }
+
+    pub fn init(config: &InnerConfig) -> Result<Arc<NotificationSender>> {
+        let tenant = config.query.tenant_id.tenant_name();
+        let meta_store = UserApiProvider::instance().get_meta_store_client();
+        let notification_mgr = NotificationMgr::create(meta_store.as_ref().clone(), tenant)?;
+
+        let sender = Self::create(Arc::new(notification_mgr), WEBHOOK_RETRY_INTERVAL)?;
+        GlobalInstance::set(Some(sender.clone()));
+        Ok(sender)
+    }
+
+    /// Record that notifications are not delivered by this node, e.g., they are delivered by cloud control.
+    pub fn init_disabled() {
+        GlobalInstance::set::<Option<Arc<NotificationSender>>>(None);
+    }
+
+    /// Returns the sender of this node, or None if notifications are not delivered by the query nodes.
+    pub fn try_instance() -> Option<Arc<NotificationSender>> {
+        GlobalInstance::try_get::<Option<Arc<NotificationSender>>>().flatten()
+    }
+
+    pub fn notification_mgr(&self) -> Arc<NotificationMgr> {
+        self.notification_mgr.clone()
+    }
+
+    /// Send a message to the webhook of an integration in the background.
+    pub fn send_in_background(
+        self: &Arc<Self>,
+        integration: String,
+        source: &'static str,
+        message: serde_json::Value,
+    ) {
+        let sender = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            if let Err(cause) = sender.send(&integration, source, &message).await {
+                warn!(
+                    "Cannot send {} notification to {}, cause {:?}",
+                    source, integration, cause
+                );
+            }
+        });
+    }
+
+    /// Send a message to the webhook of an integration, retrying on failures.
+    ///
+    /// Nothing is sent if the integration is disabled.
+    #[async_backtrace::framed]
+    pub async fn send(
+        &self,
+        integration: &str,
+        source: &str,
+        message: &serde_json::Value,
+    ) -> Result<()> {
+        let Some((_seq, notification)) =
+            self.notification_mgr.get_notification(integration).await?
+        else {
+            return Err(ErrorCode::UnknownNotification(format!(
+                "Notification '{}' does not exist",
+                integration
+            )));
+        };
+
+        if !notification.enabled {
+            info!(
+                "Skip sending {} notification, {} is disabled",
+                source, integration
+            );
+            return Ok(());
+        }
+
+        let created_time = Utc::now();
+        let body = message.to_string();
+        let res = self.deliver(&notification, &body).await;
+
+        let history = pb::NotificationHistory {
+            created_time: Some(to_timestamp(created_time)),
+            processed_time: Some(to_timestamp(Utc::now())),
+            message_source: source.to_string(),
+            name: notification.name.clone(),
+            message: body,
+            status: match res {
+                Ok(_) => "SUCCESS".to_string(),
+                Err(_) => "FAILED".to_string(),
+            },
+            error_message: match &res {
+                Ok(_) => "".to_string(),
+                Err(cause) => cause.message(),
+            },
+        };
+        let message_id = Uuid::new_v4().simple().to_string();
+        self.notification_mgr
+            .upsert_notification_history(&history, &message_id)
+            .await?;
+
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn deliver(&self, notification: &pb::Notification, body: &str) -> Result<()> {
+        let method = notification.webhook_method.as_deref().unwrap_or("POST");
+        let method = reqwest::Method::from_bytes(method.to_uppercase().as_bytes())
+            .map_err(|e| ErrorCode::BadArguments(format!("Invalid webhook method: {e}")))?;
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+
+            let timestamp = Utc::now().timestamp();
+            let mut request = self
+                .client
+                .request(method.clone(), &notification.webhook_url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string())
+                .body(body.to_string());
+            if let Some(authorization) = &notification.webhook_authorization_header {
+                request = request.header(reqwest::header::AUTHORIZATION, authorization);
+            }
+            if let Some(secret) = &notification.webhook_signing_secret {
+                let signature = sign_webhook_payload(secret, timestamp, body);
+                request = request.header(WEBHOOK_SIGNATURE_HEADER, format!("sha256={signature}"));
+            }
+
+            let cause = match request.send().await {
+                Ok(resp) if resp.status().is_success() => return Ok(()),
+                Ok(resp) => format!("webhook responded with status {}", resp.status()),
+                Err(e) => format!("cannot send webhook request: {e}"),
+            };
+
+            if attempt >= WEBHOOK_MAX_ATTEMPTS {
+                return Err(ErrorCode::Internal(format!(
+                    "Notification {} failed after {} attempts, {}",
+                    notification.name, attempt, cause
+                )));
+            }
+
+            warn!(
+                "Notification {} failed on attempt {}, {}",
+                notification.name, attempt, cause
+            );
+            tokio_async_sleep(self.retry_interval * 2u32.pow(attempt - 1)).await;
+        }
+    }
+}
+
+/// Returns the hex HMAC-SHA256 of `<timestamp>.<body>` with the signing secret of a webhook.
+pub fn sign_webhook_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(format!("{timestamp}.{body}").as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+fn to_timestamp(time: DateTime<Utc>) -> Timestamp {
+    Timestamp {
+        seconds: time.timestamp(),
+        nanos: time.timestamp_subsec_nanos() as i32,
+    }
+}
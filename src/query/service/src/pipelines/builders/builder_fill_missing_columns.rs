@@ -20,6 +20,7 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 
+use crate::pipelines::processors::transforms::TransformAddAutoIncrementColumns;
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuilder;
@@ -40,6 +41,20 @@ impl PipelineBuilder {
         let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
         let computed_schema: DataSchemaRef = Arc::new(table_computed_schema.into());
 
+        // Fill missing auto increment columns, the values are allocated from sequences.
+        let auto_increment_fields =
+            TransformAddAutoIncrementColumns::missing_fields(&source_schema, &default_schema);
+        let source_schema =
+            TransformAddAutoIncrementColumns::output_schema(&source_schema, &auto_increment_fields);
+        if !auto_increment_fields.is_empty() {
+            pipeline.try_add_async_transformer(|| {
+                TransformAddAutoIncrementColumns::try_new(
+                    ctx.clone(),
+                    auto_increment_fields.clone(),
+                )
+            })?;
+        }
+
         // Fill missing default columns and resort the columns.
         if source_schema != default_schema {
             pipeline.try_add_transformer(|| {
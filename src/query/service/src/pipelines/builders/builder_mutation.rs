@@ -26,6 +26,7 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipe;
 use databend_common_pipeline_transforms::processors::create_dummy_item;
 use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
+use databend_common_pipeline_transforms::processors::AsyncTransformer;
 use databend_common_pipeline_transforms::processors::BlockCompactBuilder;
 use databend_common_pipeline_transforms::processors::BlockMetaTransformer;
 use databend_common_pipeline_transforms::processors::TransformCompactBlock;
@@ -38,6 +39,7 @@ use databend_common_storages_fuse::operations::TransformSerializeSegment;
 use databend_common_storages_fuse::operations::UnMatchedExprs;
 use databend_common_storages_fuse::FuseTable;
 
+use crate::pipelines::processors::transforms::TransformAddAutoIncrementColumns;
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
 use crate::pipelines::processors::TransformResortAddOnWithoutSourceSchema;
 use crate::pipelines::PipelineBuilder;
@@ -184,15 +186,56 @@ impl PipelineBuilder {
     ) -> Result<()> {
         let table = FuseTable::try_from_table(tbl.as_ref())?;
 
-        // fill default columns
         let table_default_schema = &table.schema_with_stream().remove_computed_fields();
+        let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
+
+        // fill auto increment columns of the inserted blocks
+        let auto_increment_fields = unmatched
+            .iter()
+            .map(|(source_schema, _, _)| {
+                TransformAddAutoIncrementColumns::missing_fields(source_schema, &default_schema)
+            })
+            .collect::<Vec<_>>();
+        let unmatched = if auto_increment_fields.iter().any(|f| !f.is_empty()) {
+            let mut builder = self.main_pipeline.add_transform_with_specified_len(
+                |transform_input_port, transform_output_port| {
+                    Ok(ProcessorPtr::create(AsyncTransformer::create(
+                        transform_input_port,
+                        transform_output_port,
+                        TransformAddAutoIncrementColumns::try_new_with_source_schemas(
+                            self.ctx.clone(),
+                            auto_increment_fields.clone(),
+                        )?,
+                    )))
+                },
+                transform_len,
+            )?;
+            if need_match {
+                builder.add_items_prepend(vec![create_dummy_item()]);
+            }
+            self.main_pipeline.add_pipe(builder.finalize());
+
+            unmatched
+                .into_iter()
+                .zip(auto_increment_fields.iter())
+                .map(|((source_schema, condition, values), fields)| {
+                    let source_schema =
+                        TransformAddAutoIncrementColumns::output_schema(&source_schema, fields);
+                    (source_schema, condition, values)
+                })
+                .collect()
+        } else {
+            unmatched
+        };
+
+        // fill default columns
         let mut builder = self
             .main_pipeline
             .try_create_transform_pipeline_builder_with_len(
                 || {
                     TransformResortAddOnWithoutSourceSchema::try_new(
                         self.ctx.clone(),
-                        Arc::new(DataSchema::from(table_default_schema)),
+                        default_schema.clone(),
                         unmatched.clone(),
                         tbl.clone(),
                         Arc::new(DataSchema::from(table.schema_with_stream())),
@@ -207,7 +250,6 @@ impl PipelineBuilder {
 
         // fill computed columns
         let table_computed_schema = &table.schema_with_stream().remove_virtual_computed_fields();
-        let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
         let computed_schema: DataSchemaRef = Arc::new(table_computed_schema.into());
         if default_schema != computed_schema {
             builder = self
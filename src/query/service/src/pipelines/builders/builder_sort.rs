@@ -14,6 +14,8 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use databend_common_catalog::runtime_filter_info::TopNRuntimeFilter;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::LimitType;
@@ -34,6 +36,7 @@ use databend_common_storage::DataOperator;
 use databend_common_storages_fuse::TableContext;
 
 use crate::pipelines::processors::transforms::create_transform_sort_spill;
+use crate::pipelines::processors::transforms::TransformTopNRuntimeFilter;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
 use crate::spillers::Spiller;
@@ -88,7 +91,30 @@ impl PipelineBuilder {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        self.build_sort_pipeline(plan_schema, sort_desc, sort.limit, sort.after_exchange)
+        // Register the top-N runtime filter to the table scan, it's tightened by partial sort.
+        let topn_runtime_filter = match (&sort.topn_runtime_filter, sort.limit) {
+            (Some((column_name, table_index)), Some(_)) => {
+                let order_by = &sort.order_by[0];
+                let filter = Arc::new(TopNRuntimeFilter::new(
+                    column_name.clone(),
+                    order_by.asc,
+                    order_by.nulls_first,
+                ));
+                let mut runtime_filter = RuntimeFilterInfo::default();
+                runtime_filter.set_topn(filter.clone());
+                self.ctx.set_runtime_filter((*table_index, runtime_filter));
+                Some(filter)
+            }
+            _ => None,
+        };
+
+        self.build_sort_pipeline(
+            plan_schema,
+            sort_desc,
+            sort.limit,
+            sort.after_exchange,
+            topn_runtime_filter,
+        )
     }
 
     pub(crate) fn build_sort_pipeline(
@@ -97,6 +123,7 @@ impl PipelineBuilder {
         sort_desc: Vec<SortColumnDescription>,
         limit: Option<usize>,
         after_exchange: Option<bool>,
+        topn_runtime_filter: Option<Arc<TopNRuntimeFilter>>,
     ) -> Result<()> {
         let max_threads = self.settings.get_max_threads()? as usize;
         let sort_desc = Arc::new(sort_desc);
@@ -107,7 +134,8 @@ impl PipelineBuilder {
         }
 
         let builder = SortPipelineBuilder::create(self.ctx.clone(), plan_schema, sort_desc)?
-            .with_limit(limit);
+            .with_limit(limit)
+            .with_topn_runtime_filter(topn_runtime_filter);
 
         match after_exchange {
             Some(true) => {
@@ -149,6 +177,7 @@ pub struct SortPipelineBuilder {
     limit: Option<usize>,
     block_size: usize,
     remove_order_col_at_last: bool,
+    topn_runtime_filter: Option<Arc<TopNRuntimeFilter>>,
 }
 
 impl SortPipelineBuilder {
@@ -165,6 +194,7 @@ impl SortPipelineBuilder {
             limit: None,
             block_size,
             remove_order_col_at_last: false,
+            topn_runtime_filter: None,
         })
     }
 
@@ -173,6 +203,14 @@ impl SortPipelineBuilder {
         self
     }
 
+    pub fn with_topn_runtime_filter(
+        mut self,
+        topn_runtime_filter: Option<Arc<TopNRuntimeFilter>>,
+    ) -> Self {
+        self.topn_runtime_filter = topn_runtime_filter;
+        self
+    }
+
     // The expected output block size, the actual output block size will be equal to or less than the given value.
     pub fn with_block_size_hit(mut self, block_size: usize) -> Self {
         self.block_size = self.block_size.min(block_size);
@@ -193,6 +231,12 @@ impl SortPipelineBuilder {
             )
         });
 
+        if let (Some(filter), Some(limit)) = (&self.topn_runtime_filter, self.limit) {
+            let offset = self.sort_desc[0].offset;
+            pipeline
+                .add_transformer(|| TransformTopNRuntimeFilter::new(filter.clone(), offset, limit));
+        }
+
         self.build_merge_sort_pipeline(pipeline, false)
     }
 
@@ -22,6 +22,8 @@ use std::sync::Arc;
 
 use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_base::base::tokio::sync::Barrier;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -414,6 +416,13 @@ impl HashJoinBuildState {
                 HashMethodKind::DictionarySerializer(_) => unimplemented!(),
             };
             self.entry_size.store(entry_size, Ordering::Release);
+            let build_bytes = build_chunks
+                .iter()
+                .map(|chunk| chunk.memory_size())
+                .sum::<usize>()
+                + build_num_rows * entry_size;
+            Profile::record_usize_profile(ProfileStatisticsName::HashJoinBuildRows, build_num_rows);
+            Profile::record_usize_profile(ProfileStatisticsName::HashJoinBuildBytes, build_bytes);
             let hash_table = unsafe { &mut *self.hash_join_state.hash_table.get() };
             *hash_table = hash_join_hash_table;
             self.merge_into_try_generate_matched_memory();
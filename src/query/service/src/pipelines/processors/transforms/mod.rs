@@ -16,6 +16,7 @@ pub mod aggregator;
 pub mod group_by;
 mod hash_join;
 pub(crate) mod range_join;
+mod transform_add_auto_increment_columns;
 mod transform_add_computed_columns;
 mod transform_add_const_columns;
 mod transform_add_internal_columns;
@@ -37,11 +38,13 @@ mod transform_resort_addon;
 mod transform_resort_addon_without_source_schema;
 mod transform_sort_spill;
 mod transform_srf;
+mod transform_topn_runtime_filter;
 mod transform_udf_script;
 mod transform_udf_server;
 mod window;
 
 pub use hash_join::*;
+pub use transform_add_auto_increment_columns::TransformAddAutoIncrementColumns;
 pub use transform_add_computed_columns::TransformAddComputedColumns;
 pub use transform_add_const_columns::TransformAddConstColumns;
 pub use transform_add_internal_columns::TransformAddInternalColumns;
@@ -66,6 +69,7 @@ pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
 pub use transform_sort_spill::create_transform_sort_spill;
 pub use transform_srf::TransformSRF;
+pub use transform_topn_runtime_filter::TransformTopNRuntimeFilter;
 pub use transform_udf_script::TransformUdfScript;
 pub use transform_udf_server::TransformUdfServer;
 pub use window::*;
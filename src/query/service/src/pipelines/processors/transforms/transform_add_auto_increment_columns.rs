@@ -0,0 +1,201 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::AutoIncrementExpr;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::SourceSchemaIndex;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::GetSequenceNextValueReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_storages_fuse::TableContext;
+
+use crate::sessions::QueryContext;
+
+struct AutoIncrementColumn {
+    field: DataField,
+    auto_increment_expr: AutoIncrementExpr,
+    // cast the allocated Int64 values to the type of the column.
+    cast_expr: Expr,
+}
+
+/// Appends the values of the `AUTOINCREMENT` columns that are missing in the input blocks.
+///
+/// The values of a block are allocated from the hidden sequence of the column at once.
+/// The sequence is created with the table and has a `CACHE`, so most blocks are served
+/// by the values the query node has already reserved, without a meta round trip.
+pub struct TransformAddAutoIncrementColumns {
+    ctx: Arc<QueryContext>,
+    func_ctx: FunctionContext,
+    // the missing columns of each source schema, indexed by `SourceSchemaIndex`.
+    missing_columns: Vec<Vec<AutoIncrementColumn>>,
+    // blocks carry `SourceSchemaIndex` meta, this is the case of merge into.
+    by_source_schema_index: bool,
+}
+
+impl TransformAddAutoIncrementColumns {
+    /// Returns the `AUTOINCREMENT` fields of `table_schema` which `source_schema` lacks.
+    pub fn missing_fields(source_schema: &DataSchema, table_schema: &DataSchema) -> Vec<DataField> {
+        table_schema
+            .fields()
+            .iter()
+            .filter(|f| f.auto_increment_expr().is_some() && !source_schema.has_field(f.name()))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the schema of the blocks after the missing fields are appended.
+    pub fn output_schema(
+        source_schema: &DataSchemaRef,
+        missing_fields: &[DataField],
+    ) -> DataSchemaRef {
+        if missing_fields.is_empty() {
+            return source_schema.clone();
+        }
+        let mut fields = source_schema.fields().clone();
+        fields.extend(missing_fields.iter().cloned());
+        Arc::new(DataSchema::new(fields))
+    }
+
+    pub fn try_new(ctx: Arc<QueryContext>, missing_fields: Vec<DataField>) -> Result<Self> {
+        Self::create(ctx, vec![missing_fields], false)
+    }
+
+    /// Creates a transform for blocks from multiple source schemas, dispatched by the
+    /// `SourceSchemaIndex` meta. Blocks without the meta are passed through.
+    pub fn try_new_with_source_schemas(
+        ctx: Arc<QueryContext>,
+        missing_fields: Vec<Vec<DataField>>,
+    ) -> Result<Self> {
+        Self::create(ctx, missing_fields, true)
+    }
+
+    fn create(
+        ctx: Arc<QueryContext>,
+        missing_fields: Vec<Vec<DataField>>,
+        by_source_schema_index: bool,
+    ) -> Result<Self> {
+        let missing_columns = missing_fields
+            .into_iter()
+            .map(|fields| {
+                fields
+                    .into_iter()
+                    .map(|field| {
+                        let auto_increment_expr = field.auto_increment_expr().cloned().unwrap();
+                        let expr = Expr::ColumnRef {
+                            span: None,
+                            id: 0,
+                            data_type: DataType::Number(NumberDataType::Int64),
+                            display_name: field.name().clone(),
+                        };
+                        let cast_expr =
+                            check_cast(None, false, expr, field.data_type(), &BUILTIN_FUNCTIONS)?;
+                        Ok(AutoIncrementColumn {
+                            field,
+                            auto_increment_expr,
+                            cast_expr,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            func_ctx: ctx.get_function_context()?,
+            ctx,
+            missing_columns,
+            by_source_schema_index,
+        })
+    }
+
+    async fn next_values(&self, column: &AutoIncrementColumn, count: u64) -> Result<BlockEntry> {
+        let values = if count == 0 {
+            vec![]
+        } else {
+            let tenant = self.ctx.get_tenant();
+            let catalog = self.ctx.get_default_catalog()?;
+            let req = GetSequenceNextValueReq {
+                ident: SequenceIdent::new(&tenant, &column.auto_increment_expr.sequence_name),
+                count,
+            };
+            let resp = catalog.get_sequence_next_value(req).await?;
+            (0..count)
+                .map(|i| resp.start + i * resp.step as u64)
+                .map(|v| column.auto_increment_expr.value_of(v))
+                .collect()
+        };
+
+        let block = DataBlock::new(
+            vec![BlockEntry::new(
+                DataType::Number(NumberDataType::Int64),
+                Value::Column(Int64Type::from_data(values)),
+            )],
+            count as usize,
+        );
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let value = evaluator.run(&column.cast_expr).map_err(|err| {
+            err.add_message(format!(
+                "fail to fill AUTOINCREMENT column {} ({})",
+                column.field.name(),
+                column.field.data_type(),
+            ))
+        })?;
+        Ok(BlockEntry::new(column.field.data_type().clone(), value))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformAddAutoIncrementColumns {
+    const NAME: &'static str = "AddAutoIncrementColumnsTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        let index = if self.by_source_schema_index {
+            match data_block
+                .get_meta()
+                .and_then(SourceSchemaIndex::downcast_ref_from)
+            {
+                Some(index) => *index,
+                None => return Ok(data_block),
+            }
+        } else {
+            0
+        };
+        if self.missing_columns[index].is_empty() {
+            return Ok(data_block);
+        }
+        let num_rows = data_block.num_rows() as u64;
+        for column in self.missing_columns[index].iter() {
+            let entry = self.next_values(column, num_rows).await?;
+            data_block.add_column(entry);
+        }
+        Ok(data_block)
+    }
+}
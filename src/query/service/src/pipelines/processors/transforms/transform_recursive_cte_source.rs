@@ -305,6 +305,7 @@ async fn create_memory_table_for_cte_scan(
                 options: Default::default(),
                 field_comments: vec![],
                 cluster_key: None,
+                partition_key: None,
                 as_select: None,
                 inverted_indexes: None,
             };
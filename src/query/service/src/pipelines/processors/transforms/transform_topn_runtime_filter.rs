@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::Arc;
+
+use databend_common_catalog::runtime_filter_info::TopNRuntimeFilter;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_pipeline_transforms::processors::Transform;
+
+/// Publish the threshold of the top-N runtime filter after partial sort.
+///
+/// The first sort keys of the best `limit` rows seen so far are kept in a heap across
+/// blocks, once it's full its top bounds the n-th value of the whole result.
+/// NULLs are not kept, so the threshold is never tighter than the one of all rows.
+pub struct TransformTopNRuntimeFilter {
+    filter: Arc<TopNRuntimeFilter>,
+    // The offset of the first sort key in the block.
+    offset: usize,
+    limit: usize,
+    heap: BinaryHeap<HeapValue>,
+}
+
+impl TransformTopNRuntimeFilter {
+    pub fn new(filter: Arc<TopNRuntimeFilter>, offset: usize, limit: usize) -> Self {
+        Self {
+            filter,
+            offset,
+            limit,
+            heap: BinaryHeap::with_capacity(limit + 1),
+        }
+    }
+}
+
+impl Transform for TransformTopNRuntimeFilter {
+    const NAME: &'static str = "TransformTopNRuntimeFilter";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        if self.limit == 0 {
+            return Ok(data);
+        }
+
+        let asc = self.filter.asc();
+        let entry = data.get_by_offset(self.offset);
+        let mut changed = false;
+        for row in 0..data.num_rows() {
+            let Some(value) = entry.value.index(row) else {
+                continue;
+            };
+            if value.is_null() {
+                continue;
+            }
+            let value = HeapValue {
+                value: value.to_owned(),
+                asc,
+            };
+            if self.heap.len() >= self.limit && self.heap.peek().is_some_and(|top| value >= *top) {
+                continue;
+            }
+            self.heap.push(value);
+            if self.heap.len() > self.limit {
+                self.heap.pop();
+            }
+            changed = true;
+        }
+
+        if changed && self.heap.len() >= self.limit {
+            if let Some(top) = self.heap.peek() {
+                self.filter.update(top.value.clone());
+            }
+        }
+        Ok(data)
+    }
+}
+
+/// A value of the first sort key, ordered by the sort direction,
+/// so that the top of the heap is the value sorted last.
+struct HeapValue {
+    value: Scalar,
+    asc: bool,
+}
+
+impl Ord for HeapValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ordering = self
+            .value
+            .partial_cmp(&other.value)
+            .unwrap_or(Ordering::Equal);
+        if self.asc {
+            ordering
+        } else {
+            ordering.reverse()
+        }
+    }
+}
+
+impl PartialOrd for HeapValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for HeapValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for HeapValue {}
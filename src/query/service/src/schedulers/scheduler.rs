@@ -81,6 +81,7 @@ pub async fn build_query_pipeline_without_render_result_set(
 
 /// Build local pipeline.
 #[async_backtrace::framed]
+#[fastrace::trace]
 pub async fn build_local_pipeline(
     ctx: &Arc<QueryContext>,
     plan: &PhysicalPlan,
@@ -100,6 +101,7 @@ pub async fn build_local_pipeline(
 
 /// Build distributed pipeline via fragment and actions.
 #[async_backtrace::framed]
+#[fastrace::trace]
 pub async fn build_distributed_pipeline(
     ctx: &Arc<QueryContext>,
     plan: &PhysicalPlan,
@@ -863,8 +863,20 @@ impl QueryCoordinator {
             executor.get_inner(),
         );
 
+        let fragment_ids = self
+            .fragments_coordinator
+            .keys()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
         let span = if let Some(parent) = SpanContext::current_local_parent() {
-            Span::root("Distributed-Executor", parent)
+            Span::root("Distributed-Executor", parent).with_properties(|| {
+                [
+                    ("query_id", query_id.clone()),
+                    ("node_id", GlobalConfig::instance().query.node_id.clone()),
+                    ("fragment_ids", fragment_ids),
+                ]
+            })
         } else {
             Span::noop()
         };
@@ -969,6 +981,8 @@ impl FragmentCoordinator {
                 vec![],
             );
 
+            let _span = LocalSpan::enter_with_local_parent("FragmentCoordinator::prepare_pipeline")
+                .with_property(|| ("fragment_id", self.fragment_id.to_string()));
             let res = pipeline_builder.finalize(&self.physical_plan)?;
 
             self.pipeline_build_res = Some(res);
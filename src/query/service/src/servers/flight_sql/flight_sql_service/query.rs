@@ -37,6 +37,7 @@ use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
 use databend_common_storages_fuse::TableContext;
+use fastrace::prelude::*;
 use futures::Stream;
 use futures::StreamExt;
 use prost::bytes;
@@ -181,30 +182,37 @@ impl FlightSqlServiceImpl {
             .await;
 
         let s1 = sender.clone();
-        databend_common_base::runtime::spawn(async move {
-            let mut data_stream = data_stream;
-
-            while let Some(block) = data_stream.next().await {
-                match block {
-                    Ok(block) => {
-                        let res =
-                            match FlightSqlServiceImpl::block_to_flight_data(block, &data_schema) {
+        // The query is executed while the stream is polled, keep it in the trace of the request.
+        let span = Span::enter_with_local_parent("FlightSqlServiceImpl::execute_query::stream");
+        databend_common_base::runtime::spawn(
+            async move {
+                let mut data_stream = data_stream;
+
+                while let Some(block) = data_stream.next().await {
+                    match block {
+                        Ok(block) => {
+                            let res = match FlightSqlServiceImpl::block_to_flight_data(
+                                block,
+                                &data_schema,
+                            ) {
                                 Ok(flight_data) => Ok(flight_data),
                                 Err(err) => Err(status!("Could not convert batches", err)),
                             };
 
-                        let _ = s1.send(res).await;
-                    }
-                    Err(err) => {
-                        let _ = s1
-                            .send(Err(status!("Could not convert batches", err)))
-                            .await;
-                        break;
+                            let _ = s1.send(res).await;
+                        }
+                        Err(err) => {
+                            let _ = s1
+                                .send(Err(status!("Could not convert batches", err)))
+                                .await;
+                            break;
+                        }
                     }
                 }
+                is_finished_clone.store(true, Ordering::SeqCst);
             }
-            is_finished_clone.store(true, Ordering::SeqCst);
-        });
+            .in_span(span),
+        );
 
         if is_native_client {
             databend_common_base::runtime::spawn(async move {
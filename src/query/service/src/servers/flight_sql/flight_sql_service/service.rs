@@ -66,7 +66,10 @@ use arrow_flight::SchemaAsIpc;
 use arrow_flight::Ticket;
 use arrow_ipc::writer::IpcWriteOptions;
 use databend_common_base::base::uuid::Uuid;
+use databend_common_exception::ErrorCode;
 use databend_common_expression::DataSchema;
+use fastrace::func_path;
+use fastrace::prelude::*;
 use futures::Stream;
 use log::info;
 use prost::Message;
@@ -78,7 +81,9 @@ use tonic::Status;
 use tonic::Streaming;
 
 use super::status;
+use crate::history::log_login;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use crate::sessions::SessionType;
 
 fn try_unpack_any<T: ProstMessageExt>(message: Any) -> std::result::Result<T, Status> {
     message
@@ -143,11 +148,43 @@ impl FlightSqlService for FlightSqlServiceImpl {
         >,
         Status,
     > {
-        let (user, password) = FlightSqlServiceImpl::get_user_password(request.metadata())
-            .map_err(Status::invalid_argument)?;
         let client_ip = request.remote_addr().map(|a| a.ip().to_string());
-        let session =
-            FlightSqlServiceImpl::auth_user_password(user, password, client_ip.as_deref()).await?;
+        let (user_name, auth_type, session) =
+            match FlightSqlServiceImpl::get_key_pair_token(request.metadata()) {
+                Some((user, token)) => {
+                    let session = FlightSqlServiceImpl::auth_key_pair(
+                        user.clone(),
+                        token,
+                        client_ip.as_deref(),
+                    )
+                    .await;
+                    (user, "key_pair", session)
+                }
+                None => {
+                    let (user, password) =
+                        FlightSqlServiceImpl::get_user_password(request.metadata())
+                            .map_err(Status::invalid_argument)?;
+                    let session = FlightSqlServiceImpl::auth_user_password(
+                        user.clone(),
+                        password,
+                        client_ip.as_deref(),
+                    )
+                    .await;
+                    (user, "password", session)
+                }
+            };
+        let error = session
+            .as_ref()
+            .err()
+            .map(|status| ErrorCode::AuthenticateFailure(status.message().to_string()));
+        log_login(
+            &SessionType::FlightSQL,
+            &user_name,
+            auth_type,
+            client_ip.as_deref(),
+            error.as_ref(),
+        );
+        let session = session?;
         let token = Uuid::new_v4().to_string();
         let result = HandshakeResponse {
             protocol_version: 0,
@@ -186,6 +223,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         message: Any,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         let session = self.get_session(&request)?;
+        let root = Self::start_trace(func_path!(), &request, &session);
         let fetch_results: FetchResults = try_unpack_any(message)?;
 
         let handle = Uuid::try_parse(&fetch_results.handle).map_err(|e| {
@@ -200,6 +238,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         let handle_plan = self.statements.get(&handle).unwrap();
         let stream = self
             .execute_query(session, &handle_plan.value().0, &handle_plan.value().1)
+            .in_span(root)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let resp = Response::new(stream);
@@ -508,18 +547,23 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<PeekableFlightDataStream>,
     ) -> std::result::Result<i64, Status> {
         let session = self.get_session(&request)?;
+        let root = Self::start_trace(func_path!(), &request, &session);
         let query = ticket.query;
         info!("do_put_statement_update with query = {query}");
 
-        let (plan, plan_extras) = self
-            .plan_sql(&session, &query)
-            .await
-            .map_err(|e| status!("Error getting result schema", e))?;
-        let res = self
-            .execute_update(session, &plan, &plan_extras)
-            .await
-            .map_err(|e| status!("fail to execute", e))?;
-        Ok(res)
+        async {
+            let (plan, plan_extras) = self
+                .plan_sql(&session, &query)
+                .await
+                .map_err(|e| status!("Error getting result schema", e))?;
+            let res = self
+                .execute_update(session.clone(), &plan, &plan_extras)
+                .await
+                .map_err(|e| status!("fail to execute", e))?;
+            Ok(res)
+        }
+        .in_span(root)
+        .await
     }
 
     #[async_backtrace::framed]
@@ -534,9 +578,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_put_prepared_statement_query with handle={handle}");
 
+        let root = Self::start_trace(func_path!(), &request, &session);
         let handle_plan = self.statements.get(&handle).unwrap();
         let record_count = self
             .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .in_span(root)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let result = DoPutUpdateResult { record_count };
@@ -562,9 +608,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_put_prepared_statement_update with handle={handle}");
 
+        let root = Self::start_trace(func_path!(), &request, &session);
         let handle_plan = self.statements.get(&handle).unwrap();
         let res = self
             .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .in_span(root)
             .await
             .map_err(|e| status!("fail to execute", e))?;
 
@@ -579,10 +627,12 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<Action>,
     ) -> std::result::Result<ActionCreatePreparedStatementResult, Status> {
         let session = self.get_session(&request)?;
+        let root = Self::start_trace(func_path!(), &request, &session);
         let sql = query.query.clone();
         let handle = Uuid::new_v4();
         let plan = self
             .plan_sql(&session, &sql)
+            .in_span(root)
             .await
             .map_err(|e| status!("Error getting result schema", e))?;
         info!(
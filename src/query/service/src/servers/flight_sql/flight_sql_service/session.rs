@@ -18,12 +18,16 @@ use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::UserIdentity;
+use databend_common_tracing::start_trace_for_client;
+use databend_common_users::KeyPairAuthenticator;
 use databend_common_users::UserApiProvider;
+use fastrace::prelude::*;
 use tonic::metadata::MetadataMap;
 use tonic::Request;
 use tonic::Status;
 
 use super::status;
+use crate::auth::AuthMgr;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
@@ -54,6 +58,20 @@ impl FlightSqlServiceImpl {
         }
     }
 
+    /// The root span of a request, under the W3C `traceparent` header of the request,
+    /// or under the `trace_parent` setting of the session if the header is absent.
+    pub(super) fn start_trace<T>(name: &'static str, req: &Request<T>, session: &Session) -> Span {
+        let trace_parent = match Self::get_header_value(req.metadata(), "traceparent") {
+            Some(trace_parent) => trace_parent,
+            None => session
+                .get_settings()
+                .get_trace_parent()
+                .unwrap_or_default(),
+        };
+        start_trace_for_client(name, Some(&trace_parent), SpanContext::random)
+            .with_properties(|| session.to_fastrace_properties())
+    }
+
     pub(super) fn get_header_value(metadata: &MetadataMap, key: &str) -> Option<String> {
         metadata
             .get(key)
@@ -83,6 +101,41 @@ impl FlightSqlServiceImpl {
         Ok((user.to_string(), pass.to_string()))
     }
 
+    /// Returns `(user, token)` if the client presents a key-pair JWT as `Bearer <token>`.
+    pub(super) fn get_key_pair_token(metadata: &MetadataMap) -> Option<(String, String)> {
+        let authorization = Self::get_header_value(metadata, "authorization")?;
+        let token = authorization.strip_prefix("Bearer ")?;
+        let user = KeyPairAuthenticator::parse_user(token)?;
+        Some((user, token.to_string()))
+    }
+
+    #[async_backtrace::framed]
+    pub(super) async fn auth_key_pair(
+        user: String,
+        token: String,
+        client_ip: Option<&str>,
+    ) -> Result<Arc<Session>, Status> {
+        let session_manager = SessionManager::instance();
+        let session = session_manager
+            .create_session(SessionType::FlightSQL)
+            .await
+            .map_err(|e| status!("Could not create session", e))?;
+
+        let session = session_manager.register_session(session)?;
+
+        let tenant = session.get_current_tenant();
+        let user = AuthMgr::instance()
+            .auth_key_pair(&tenant, &user, &token, client_ip)
+            .await
+            .map_err(|e| Status::unauthenticated(e.message()))?;
+
+        session
+            .set_authed_user(user, None)
+            .await
+            .map_err(|e| status!("set_authed_user fail {}", e))?;
+        Ok(session)
+    }
+
     #[async_backtrace::framed]
     pub(super) async fn auth_user_password(
         user: String,
@@ -117,6 +170,7 @@ impl FlightSqlServiceImpl {
         let password = password.as_bytes().to_vec();
         let password = (!password.is_empty()).then_some(password);
 
+        let mut ldap_roles = vec![];
         let authed = match &user.auth_info {
             AuthInfo::None => Ok(()),
             AuthInfo::Password {
@@ -133,6 +187,14 @@ impl FlightSqlServiceImpl {
                     }
                 }
             },
+            AuthInfo::Ldap => match password {
+                None => Err(Status::unauthenticated("password required")),
+                Some(p) => AuthMgr::instance()
+                    .auth_ldap(&user.name, &p)
+                    .await
+                    .map(|roles| ldap_roles = roles)
+                    .map_err(|e| Status::unauthenticated(e.message())),
+            },
             _ => Err(Status::unauthenticated("wrong auth type")),
         };
 
@@ -141,6 +203,9 @@ impl FlightSqlServiceImpl {
             .await?;
         authed?;
 
+        for role in ldap_roles {
+            user.grants.grant_role(role);
+        }
         session
             .set_authed_user(user, None)
             .await
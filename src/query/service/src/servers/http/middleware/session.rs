@@ -28,6 +28,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::user_token::TokenType;
 use databend_common_meta_app::tenant::Tenant;
+use databend_common_users::KeyPairAuthenticator;
 use fastrace::func_name;
 use headers::authorization::Basic;
 use headers::authorization::Bearer;
@@ -234,6 +235,12 @@ fn get_credential_from_header(
                         }
                     }
                     Ok(Credential::DatabendToken { token })
+                } else if let Some(name) = KeyPairAuthenticator::parse_user(&token) {
+                    Ok(Credential::KeyPair {
+                        name,
+                        token,
+                        client_ip,
+                    })
                 } else {
                     Ok(Credential::Jwt { token, client_ip })
                 }
@@ -20,6 +20,7 @@ use databend_common_base::runtime::drop_guard;
 use databend_common_exception::ErrorCode;
 use databend_common_expression::DataSchemaRef;
 use databend_common_metrics::http::metrics_incr_http_response_errors_count;
+use databend_common_tracing::start_trace_for_client;
 use fastrace::func_path;
 use fastrace::prelude::*;
 use highway::HighwayHash;
@@ -492,22 +493,11 @@ impl Drop for SlowRequestLogTracker {
 }
 
 // get_http_tracing_span always return a valid span for tracing
-// it will try to decode w3 traceparent and if empty or failed, it will create a new root span and throw a warning
+// it will try to decode w3 traceparent and if empty or failed, it will create a new root span with
+// a trace id derived from the query id
 fn get_http_tracing_span(name: &'static str, ctx: &HttpQueryContext, query_id: &str) -> Span {
-    if let Some(parent) = ctx.trace_parent.as_ref() {
-        let trace = parent.as_str();
-        match SpanContext::decode_w3c_traceparent(trace) {
-            Some(span_context) => {
-                return Span::root(name, span_context)
-                    .with_properties(|| ctx.to_fastrace_properties());
-            }
-            None => {
-                warn!("failed to decode trace parent: {}", trace);
-            }
-        }
-    }
-
-    let trace_id = query_id_to_trace_id(query_id);
-    Span::root(name, SpanContext::new(trace_id, SpanId(rand::random())))
-        .with_properties(|| ctx.to_fastrace_properties())
+    start_trace_for_client(name, ctx.trace_parent.as_deref(), || {
+        SpanContext::new(query_id_to_trace_id(query_id), SpanId(rand::random()))
+    })
+    .with_properties(|| ctx.to_fastrace_properties())
 }
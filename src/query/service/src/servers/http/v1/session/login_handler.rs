@@ -41,7 +41,7 @@ pub struct LoginResponse {
     session_id: String,
     refresh_interval_in_secs: u64,
 
-    /// for now, only use session token when authed by user-password
+    /// for now, only use session token when authed by user-password or key pair
     session_token: Option<String>,
     refresh_token: Option<String>,
 }
@@ -98,7 +98,7 @@ pub async fn login_handler(
                 refresh_token: None,
             }))
         }
-        Credential::Password { .. } => {
+        Credential::Password { .. } | Credential::KeyPair { .. } => {
             let (session_id, token_pair) = ClientSessionManager::instance()
                 .new_token_pair(&ctx.session, None, None)
                 .await
@@ -112,6 +112,6 @@ pub async fn login_handler(
                 refresh_token: Some(token_pair.refresh.clone()),
             }))
         }
-        _ => unreachable!("/session/login expect password or JWT"),
+        _ => unreachable!("/session/login expect password, key pair or JWT"),
     }
 }
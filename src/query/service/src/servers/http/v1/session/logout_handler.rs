@@ -17,6 +17,7 @@ use poem::error::Result as PoemResult;
 use poem::web::Json;
 use poem::IntoResponse;
 
+use crate::audit::audit_session;
 use crate::auth::Credential;
 use crate::servers::http::error::HttpErrorCode;
 use crate::servers::http::error::QueryError;
@@ -36,6 +37,13 @@ pub async fn logout_handler(ctx: &HttpQueryContext) -> PoemResult<impl IntoRespo
             .drop_client_session(token)
             .await
             .map_err(HttpErrorCode::server_error)?;
+        audit_session(
+            "Logout",
+            &ctx.session.get_type(),
+            &ctx.user_name,
+            ctx.client_host.as_deref(),
+            None,
+        );
         None
     } else {
         // should not get here since request is already authed
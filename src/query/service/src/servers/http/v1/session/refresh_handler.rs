@@ -76,6 +76,10 @@ pub async fn refresh_handler(
                 refresh_token: Some(token_pair.refresh.clone()),
             }))
         }
+        Credential::KeyPair { .. } => Err(HttpErrorCode::bad_request(ErrorCode::BadArguments(
+            "key pair session should be refreshed with the refresh token returned by login",
+        ))
+        .into()),
         _ => {
             unreachable!("/session/refresh should be authed by databend refresh token or JWT token")
         }
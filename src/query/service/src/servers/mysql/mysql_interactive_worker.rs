@@ -29,8 +29,10 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_io::prelude::FormatSettings;
+use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_metrics::mysql::*;
+use databend_common_tracing::start_trace_for_client;
 use databend_common_users::CertifiedInfo;
 use databend_common_users::UserApiProvider;
 use fastrace::func_path;
@@ -47,6 +49,8 @@ use opensrv_mysql::StatementMetaWriter;
 use rand::RngCore;
 use uuid::Uuid;
 
+use crate::auth::AuthMgr;
+use crate::history::log_login;
 use crate::interpreters::interpreter_plan_sql;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
@@ -60,6 +64,7 @@ use crate::sessions::QueriesQueueManager;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryEntry;
 use crate::sessions::Session;
+use crate::sessions::SessionType;
 use crate::sessions::TableContext;
 use crate::stream::DataBlockStream;
 
@@ -67,12 +72,17 @@ struct InteractiveWorkerBase {
     session: Arc<Session>,
 }
 
+const MYSQL_NATIVE_PASSWORD: &str = "mysql_native_password";
+const MYSQL_CLEAR_PASSWORD: &str = "mysql_clear_password";
+
 pub struct InteractiveWorker {
     base: InteractiveWorkerBase,
     version: String,
     salt: [u8; 20],
     client_addr: String,
     keep_alive_task_started: bool,
+    // Whether the client upgraded the connection to TLS.
+    secure_connection: bool,
 }
 
 #[async_trait::async_trait]
@@ -94,12 +104,19 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
     }
 
     fn default_auth_plugin(&self) -> &str {
-        "mysql_native_password"
+        MYSQL_NATIVE_PASSWORD
     }
 
     #[async_backtrace::framed]
     async fn auth_plugin_for_username(&self, _user: &[u8]) -> &str {
-        "mysql_native_password"
+        // LDAP users need the cleartext password to bind against the LDAP server.
+        // The plugin does not depend on the user, not to reveal whether a user exists,
+        // and the cleartext password is only asked for over TLS.
+        if self.secure_connection && AuthMgr::instance().ldap_enabled() {
+            MYSQL_CLEAR_PASSWORD
+        } else {
+            MYSQL_NATIVE_PASSWORD
+        }
     }
 
     fn salt(&self) -> [u8; 20] {
@@ -109,7 +126,7 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
     #[async_backtrace::framed]
     async fn authenticate(
         &self,
-        _auth_plugin: &str,
+        auth_plugin: &str,
         username: &[u8],
         salt: &[u8],
         auth_data: &[u8],
@@ -118,8 +135,25 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         let client_addr = self.client_addr.clone();
         let info = CertifiedInfo::create(&username, auth_data, &client_addr);
 
-        let authenticate = self.base.authenticate(salt, info);
-        match authenticate.await {
+        let authenticate = self
+            .base
+            .authenticate(auth_plugin, salt, info, self.secure_connection);
+        let res = authenticate.await;
+
+        let error = match &res {
+            Ok(true) => None,
+            Ok(false) => Some(ErrorCode::AuthenticateFailure("wrong password")),
+            Err(failure) => Some(failure.clone()),
+        };
+        log_login(
+            &SessionType::MySQL,
+            &username,
+            auth_plugin,
+            Some(&client_addr),
+            error.as_ref(),
+        );
+
+        match res {
             Ok(res) => res,
             Err(failure) => {
                 error!(
@@ -193,7 +227,14 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         writer: QueryResultWriter<'a, W>,
     ) -> Result<()> {
         let query_id = Uuid::new_v4().to_string();
-        let root = Span::root(func_path!(), SpanContext::random())
+        // Trace the query under the client trace if the `trace_parent` setting is set.
+        let trace_parent = self
+            .base
+            .session
+            .get_settings()
+            .get_trace_parent()
+            .unwrap_or_default();
+        let root = start_trace_for_client(func_path!(), Some(&trace_parent), SpanContext::random)
             .with_properties(|| self.base.session.to_fastrace_properties());
 
         let mut tracking_payload = ThreadTracker::new_tracking_payload();
@@ -270,7 +311,13 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
 
 impl InteractiveWorkerBase {
     #[async_backtrace::framed]
-    async fn authenticate(&self, salt: &[u8], info: CertifiedInfo) -> Result<bool> {
+    async fn authenticate(
+        &self,
+        auth_plugin: &str,
+        salt: &[u8],
+        info: CertifiedInfo,
+        secure_connection: bool,
+    ) -> Result<bool> {
         let ctx = self.session.create_query_context().await?;
         let identity = UserIdentity::new(&info.user_name, "%");
         let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
@@ -286,11 +333,48 @@ impl InteractiveWorkerBase {
             user.update_auth_need_change_password();
         }
 
-        let authed = user.auth_info.auth_mysql(&info.user_password, salt)?;
+        let mut ldap_roles = vec![];
+        let authed = if auth_plugin == MYSQL_CLEAR_PASSWORD {
+            if !secure_connection {
+                return Err(ErrorCode::AuthenticateFailure(format!(
+                    "{} auth plugin is only accepted over TLS",
+                    MYSQL_CLEAR_PASSWORD
+                )));
+            }
+            // The cleartext password is sent null-terminated.
+            let password = info
+                .user_password
+                .strip_suffix(&[0])
+                .unwrap_or(&info.user_password);
+            match user.auth_info {
+                // The cause of the failure is logged by `auth_ldap`.
+                AuthInfo::Ldap => match AuthMgr::instance().auth_ldap(&user.name, password).await {
+                    Ok(roles) => {
+                        ldap_roles = roles;
+                        true
+                    }
+                    Err(_) => false,
+                },
+                _ => user.auth_info.auth_cleartext(password)?,
+            }
+        } else {
+            match user.auth_info {
+                AuthInfo::Ldap => {
+                    return Err(ErrorCode::AuthenticateFailure(format!(
+                        "ldap user must login with {} auth plugin over TLS, got {}",
+                        MYSQL_CLEAR_PASSWORD, auth_plugin
+                    )));
+                }
+                _ => user.auth_info.auth_mysql(&info.user_password, salt)?,
+            }
+        };
         UserApiProvider::instance()
             .update_user_login_result(ctx.get_tenant(), identity, authed, &user)
             .await?;
         if authed {
+            for role in ldap_roles {
+                user.grants.grant_role(role);
+            }
             self.session.set_authed_user(user, None).await?;
         }
         Ok(authed)
@@ -482,9 +566,15 @@ impl InteractiveWorker {
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
             keep_alive_task_started: false,
+            secure_connection: false,
         }
     }
 
+    /// Marks the connection upgraded to TLS, before the client is authenticated.
+    pub fn set_secure_connection(&mut self, secure_connection: bool) {
+        self.secure_connection = secure_connection;
+    }
+
     async fn start_keep_alive(&mut self) {
         let session = &self.base.session;
         let tenant = session.get_current_tenant();
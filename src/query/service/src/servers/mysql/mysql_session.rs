@@ -33,6 +33,7 @@ use opensrv_mysql::AsyncMysqlIntermediary;
 use opensrv_mysql::IntermediaryOptions;
 use rustls::ServerConfig;
 
+use crate::audit::audit_session;
 use crate::servers::mysql::mysql_interactive_worker::InteractiveWorker;
 use crate::sessions::Session;
 
@@ -67,7 +68,7 @@ impl MySQLConnection {
                 };
 
                 let mut interactive_worker =
-                    InteractiveWorker::create(session.clone(), client_addr);
+                    InteractiveWorker::create(session.clone(), client_addr.clone());
                 let opts = IntermediaryOptions {
                     process_use_statement_on_query: true,
                     reject_connection_on_dbname_absence: false,
@@ -83,6 +84,7 @@ impl MySQLConnection {
                     &tls,
                 )
                 .await?;
+                interactive_worker.set_secure_connection(use_ssl && tls.is_some());
 
                 match tls {
                     Some(config) if use_ssl => {
@@ -96,6 +98,13 @@ impl MySQLConnection {
                 let tenant = session.get_current_tenant();
                 let session_id = session.get_id();
                 let user = session.get_current_user()?.name;
+                audit_session(
+                    "Logout",
+                    &session.get_type(),
+                    &user,
+                    Some(&client_addr),
+                    None,
+                );
                 UserApiProvider::instance()
                     .client_session_api(&tenant)
                     .drop_client_session_id(&session_id, &user)
@@ -30,7 +30,9 @@ use log::error;
 use log::info;
 use tokio_stream::wrappers::TcpListenerStream;
 
+use crate::audit::AuditLogger;
 use crate::clusters::ClusterDiscovery;
+use crate::history::QueryHistoryWriter;
 use crate::sessions::SessionManager;
 
 pub type ListeningStream = Abortable<TcpListenerStream>;
@@ -75,6 +77,13 @@ impl ShutdownHandle {
             .unregister_to_metastore(&mut signal)
             .await;
         self.sessions.graceful_shutdown(signal, timeout).await;
+        // The audit events are written into the history tables too, flush them first.
+        if let Some(logger) = AuditLogger::try_instance() {
+            logger.flush().await;
+        }
+        if let Some(writer) = QueryHistoryWriter::try_instance() {
+            writer.shutdown().await;
+        }
         self.shutdown_services(false).await;
     }
 
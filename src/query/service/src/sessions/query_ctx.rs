@@ -49,6 +49,7 @@ use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use databend_common_catalog::runtime_filter_info::TopNRuntimeFilter;
 use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::ContextError;
@@ -119,6 +120,7 @@ use parking_lot::Mutex;
 use parking_lot::RwLock;
 use xorf::BinaryFuse16;
 
+use crate::audit::AuditAction;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
 use crate::locks::LockManager;
@@ -294,6 +296,26 @@ impl QueryContext {
         self.shared.get_affect()
     }
 
+    pub fn get_used_connections(&self) -> Vec<String> {
+        self.shared.get_used_connections()
+    }
+
+    pub fn get_used_file_formats(&self) -> Vec<String> {
+        self.shared.get_used_file_formats()
+    }
+
+    pub fn set_audit_action(&self, action: AuditAction) {
+        self.shared.set_audit_action(action)
+    }
+
+    pub fn get_audit_action(&self) -> Option<AuditAction> {
+        self.shared.get_audit_action()
+    }
+
+    pub fn get_masked_columns(&self) -> Vec<String> {
+        self.shared.get_masked_columns()
+    }
+
     pub fn pop_warnings(&self) -> Vec<String> {
         self.shared.pop_warnings()
     }
@@ -614,6 +636,10 @@ impl TableContext for QueryContext {
         );
     }
 
+    fn add_masked_column(&self, table_name: &str, column_name: &str) {
+        self.shared.add_masked_column(table_name, column_name);
+    }
+
     fn attach_query_str(&self, kind: QueryKind, query: String) {
         self.shared.attach_query_str(kind, query);
     }
@@ -905,10 +931,9 @@ impl TableContext for QueryContext {
             Err(_) => {
                 let user_mgr = UserApiProvider::instance();
                 let tenant = self.get_tenant();
-                Ok(user_mgr
-                    .get_file_format(&tenant, name)
-                    .await?
-                    .file_format_params)
+                let file_format = user_mgr.get_file_format(&tenant, name).await?;
+                self.shared.add_used_file_format(name);
+                Ok(file_format.file_format_params)
             }
         }
     }
@@ -1163,6 +1188,9 @@ impl TableContext for QueryContext {
                 for filter in filters.1.get_min_max() {
                     v.get_mut().add_min_max(filter.clone());
                 }
+                if let Some(topn) = filters.1.get_topn() {
+                    v.get_mut().set_topn(topn.clone());
+                }
                 for filter in filters.1.blooms() {
                     v.get_mut().add_bloom(filter);
                 }
@@ -1203,6 +1231,11 @@ impl TableContext for QueryContext {
         }
     }
 
+    fn get_topn_runtime_filter_with_id(&self, id: IndexType) -> Option<Arc<TopNRuntimeFilter>> {
+        let runtime_filters = self.shared.runtime_filters.read();
+        runtime_filters.get(&id).and_then(|v| v.get_topn().cloned())
+    }
+
     fn has_bloom_runtime_filters(&self, id: usize) -> bool {
         if let Some(runtime_filter) = self.shared.runtime_filters.read().get(&id) {
             return !runtime_filter.get_bloom().is_empty();
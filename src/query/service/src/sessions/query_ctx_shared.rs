@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -57,6 +59,7 @@ use parking_lot::Mutex;
 use parking_lot::RwLock;
 use uuid::Uuid;
 
+use crate::audit::AuditAction;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
@@ -136,6 +139,15 @@ pub struct QueryContextShared {
     pub(in crate::sessions) query_cache_metrics: DataCacheMetrics,
 
     pub(in crate::sessions) query_queued_duration: Arc<RwLock<Duration>>,
+
+    // The user-defined connections and file formats referenced by the query,
+    // the USAGE privilege on them is checked before the query is executed.
+    pub(in crate::sessions) used_connections: Arc<RwLock<HashSet<String>>>,
+    pub(in crate::sessions) used_file_formats: Arc<RwLock<HashSet<String>>>,
+
+    // The audited action of the query, and the columns read through masking policies.
+    pub(in crate::sessions) audit_action: Arc<RwLock<Option<AuditAction>>>,
+    pub(in crate::sessions) masked_columns: Arc<RwLock<BTreeSet<String>>>,
 }
 
 impl QueryContextShared {
@@ -189,6 +201,10 @@ impl QueryContextShared {
             merge_into_join: Default::default(),
             multi_table_insert_status: Default::default(),
             query_queued_duration: Arc::new(RwLock::new(Duration::from_secs(0))),
+            used_connections: Arc::new(RwLock::new(HashSet::new())),
+            used_file_formats: Arc::new(RwLock::new(HashSet::new())),
+            audit_action: Arc::new(RwLock::new(None)),
+            masked_columns: Arc::new(RwLock::new(BTreeSet::new())),
         }))
     }
 
@@ -592,7 +608,39 @@ impl QueryContextShared {
     pub async fn get_connection(&self, name: &str) -> Result<UserDefinedConnection> {
         let user_mgr = UserApiProvider::instance();
         let tenant = self.get_tenant();
-        user_mgr.get_connection(&tenant, name).await
+        let connection = user_mgr.get_connection(&tenant, name).await?;
+        self.used_connections.write().insert(name.to_string());
+        Ok(connection)
+    }
+
+    pub fn get_used_connections(&self) -> Vec<String> {
+        self.used_connections.read().iter().cloned().collect()
+    }
+
+    pub fn add_used_file_format(&self, name: &str) {
+        self.used_file_formats.write().insert(name.to_string());
+    }
+
+    pub fn get_used_file_formats(&self) -> Vec<String> {
+        self.used_file_formats.read().iter().cloned().collect()
+    }
+
+    pub fn set_audit_action(&self, action: AuditAction) {
+        *self.audit_action.write() = Some(action);
+    }
+
+    pub fn get_audit_action(&self) -> Option<AuditAction> {
+        *self.audit_action.read()
+    }
+
+    pub fn add_masked_column(&self, table_name: &str, column_name: &str) {
+        self.masked_columns
+            .write()
+            .insert(format!("{}.{}", table_name, column_name));
+    }
+
+    pub fn get_masked_columns(&self) -> Vec<String> {
+        self.masked_columns.read().iter().cloned().collect()
     }
 
     pub fn get_query_cache_metrics(&self) -> &DataCacheMetrics {
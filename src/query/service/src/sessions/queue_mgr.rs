@@ -402,7 +402,8 @@ impl QueryEntry {
             | Plan::VacuumTable(_)
             | Plan::VacuumTemporaryFiles(_)
             | Plan::RefreshIndex(_)
-            | Plan::TruncateTable(_) => {
+            | Plan::TruncateTable(_)
+            | Plan::DropTablePartition(_) => {
                 return true;
             }
             Plan::DropTable(v) if v.all => {
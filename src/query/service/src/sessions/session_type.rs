@@ -27,6 +27,7 @@ pub enum SessionType {
     Dummy,
     Fuzz,
     Local,
+    Task,
 }
 
 impl SessionType {
@@ -52,6 +53,7 @@ impl fmt::Display for SessionType {
             SessionType::HTTPAPI(usage) => format!("HTTPAPI({})", usage),
             SessionType::Fuzz => "Fuzz".to_string(),
             SessionType::Local => "Local".to_string(),
+            SessionType::Task => "Task".to_string(),
         };
         write!(f, "{}", name)
     }
@@ -145,6 +145,7 @@ impl Spiller {
     }
 
     /// Spill a [`DataBlock`] to storage.
+    #[fastrace::trace]
     pub async fn spill(&mut self, data_block: Vec<DataBlock>) -> Result<Location> {
         debug_assert!(!data_block.is_empty());
         let instant = Instant::now();
@@ -208,6 +209,7 @@ impl Spiller {
         Ok(())
     }
 
+    #[fastrace::trace]
     pub async fn spill_with_merged_partitions(
         &mut self,
         partitioned_data: Vec<(usize, Vec<DataBlock>)>,
@@ -254,6 +256,7 @@ impl Spiller {
 
     /// Read a certain file to a [`DataBlock`].
     /// We should guarantee that the file is managed by this spiller.
+    #[fastrace::trace]
     pub async fn read_spilled_file(&self, location: &Location) -> Result<DataBlock> {
         let columns_layout = self.columns_layout.get(location).unwrap();
 
@@ -307,6 +310,7 @@ impl Spiller {
         }
     }
 
+    #[fastrace::trace]
     pub async fn read_merged_partitions(
         &self,
         MergedPartition {
@@ -355,6 +359,7 @@ impl Spiller {
         Ok(partitioned_data)
     }
 
+    #[fastrace::trace]
     pub async fn read_chunk(&self, location: &Location, chunk: &Chunk) -> Result<DataBlock> {
         // Read spilled data from storage.
         let instant = Instant::now();
@@ -37,9 +37,11 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::GetProcedureReq;
 use databend_common_meta_app::principal::GrantEntry;
 use databend_common_meta_app::principal::GrantObject;
 use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::principal::ProcedureIdentity;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_meta_app::principal::UserPrivilegeSet;
 use databend_common_meta_app::principal::UserPrivilegeType;
@@ -67,7 +69,7 @@ pub struct ShowGrants {
 }
 
 // show grants for user/role name
-// show grants to table/database/stage/udf name
+// show grants to table/database/stage/udf/sequence/procedure/connection/file_format name
 
 impl ShowGrants {
     pub fn create(
@@ -236,7 +238,8 @@ impl AsyncSource for ShowGrantsSource {
             "role" | "user" => {
                 show_account_grants(self.ctx.clone(), &self.grant_type, &self.name).await?
             }
-            "table" | "database" | "udf" | "stage" => {
+            "table" | "database" | "udf" | "stage" | "sequence" | "procedure" | "connection"
+            | "file_format" => {
                 show_object_grant(
                     self.ctx.clone(),
                     &self.grant_type,
@@ -248,7 +251,7 @@ impl AsyncSource for ShowGrantsSource {
             }
             _ => {
                 return Err(ErrorCode::InvalidArgument(format!(
-                    "Expected 'user|role|table|database|udf|stage', but got {:?}",
+                    "Expected 'user|role|table|database|udf|stage|sequence|procedure|connection|file_format', but got {:?}",
                     self.grant_type
                 )));
             }
@@ -379,6 +382,8 @@ async fn show_account_grants(
     // It will re-write the exists key.
     let mut catalog_db_ids: HashMap<String, Vec<(u64, String)>> = HashMap::new();
     let mut catalog_table_ids: HashMap<String, Vec<(u64, u64, String)>> = HashMap::new();
+    let mut catalog_column_ids: HashMap<String, Vec<(u64, u64, u32, String)>> = HashMap::new();
+    let mut procedure_ids: Vec<(u64, String)> = Vec::new();
 
     for grant_entry in grant_entries {
         let object = grant_entry.object();
@@ -398,6 +403,15 @@ async fn show_account_grants(
                         )]);
                     }
                 }
+                GrantObject::Column(catalog_name, db_id, table_id, column_id) => {
+                    // column privileges are always listed one by one, as they are granted
+                    let privileges_str =
+                        UserPrivilegeSet::from(*grant_entry.privileges()).to_string();
+                    catalog_column_ids
+                        .entry(catalog_name.clone())
+                        .or_default()
+                        .push((*db_id, *table_id, *column_id, privileges_str));
+                }
                 GrantObject::DatabaseById(catalog_name, db_id) => {
                     let privileges_str = get_priv_str(&grant_entry);
                     if let Some(dbs_id_priv) = catalog_db_ids.get_mut(catalog_name) {
@@ -430,6 +444,18 @@ async fn show_account_grants(
                     privileges.push(get_priv_str(&grant_entry));
                     grant_list.push(format!("{} TO {}", grant_entry, identity));
                 }
+                GrantObject::Sequence(name)
+                | GrantObject::Connection(name)
+                | GrantObject::FileFormat(name) => {
+                    object_name.push(name.to_string());
+                    object_id.push(None);
+                    privileges.push(get_priv_str(&grant_entry));
+                    grant_list.push(format!("{} TO {}", grant_entry, identity));
+                }
+                GrantObject::Procedure(procedure_id) => {
+                    let privileges_str = get_priv_str(&grant_entry);
+                    procedure_ids.push((*procedure_id, privileges_str));
+                }
                 GrantObject::Global => {
                     // grant all on *.* to a
                     object_name.push("*.*".to_string());
@@ -491,6 +517,36 @@ async fn show_account_grants(
                         privileges.push("OWNERSHIP".to_string());
                         grant_list.push(format!("GRANT OWNERSHIP ON UDF {} TO {}", name, identity));
                     }
+                    OwnershipObject::Sequence { name } => {
+                        object_name.push(name.to_string());
+                        object_id.push(None);
+                        privileges.push("OWNERSHIP".to_string());
+                        grant_list.push(format!(
+                            "GRANT OWNERSHIP ON SEQUENCE {} TO {}",
+                            name, identity
+                        ));
+                    }
+                    OwnershipObject::Procedure { procedure_id } => {
+                        procedure_ids.push((procedure_id, "OWNERSHIP".to_string()));
+                    }
+                    OwnershipObject::Connection { name } => {
+                        object_name.push(name.to_string());
+                        object_id.push(None);
+                        privileges.push("OWNERSHIP".to_string());
+                        grant_list.push(format!(
+                            "GRANT OWNERSHIP ON CONNECTION {} TO {}",
+                            name, identity
+                        ));
+                    }
+                    OwnershipObject::FileFormat { name } => {
+                        object_name.push(name.to_string());
+                        object_id.push(None);
+                        privileges.push("OWNERSHIP".to_string());
+                        grant_list.push(format!(
+                            "GRANT OWNERSHIP ON FILE FORMAT {} TO {}",
+                            name, identity
+                        ));
+                    }
                 }
             }
         }
@@ -544,6 +600,66 @@ async fn show_account_grants(
         }
     }
 
+    for (catalog_name, columns_priv_id) in catalog_column_ids {
+        let catalog = ctx.get_catalog(&catalog_name).await?;
+        let db_ids = columns_priv_id
+            .iter()
+            .map(|res| res.0)
+            .collect::<Vec<u64>>();
+        let table_ids = columns_priv_id
+            .iter()
+            .map(|res| res.1)
+            .collect::<Vec<u64>>();
+        let dbs_name = catalog.mget_database_names_by_ids(&tenant, &db_ids).await?;
+        let tables_name = catalog.mget_table_names_by_ids(&tenant, &table_ids).await?;
+
+        for (i, (_, table_id, column_id, privileges_str)) in columns_priv_id.iter().enumerate() {
+            if let (Some(table_name), Some(db_name)) = (&tables_name[i], &dbs_name[i]) {
+                // the column may have been dropped.
+                let Some(table_meta) = catalog.get_table_meta_by_id(*table_id).await? else {
+                    continue;
+                };
+                let Some(column) = table_meta
+                    .data
+                    .schema
+                    .fields()
+                    .iter()
+                    .find(|f| f.column_id() == *column_id)
+                    .map(|f| f.name().clone())
+                else {
+                    continue;
+                };
+                object_name.push(format!(
+                    "{}.{}.{}.{}",
+                    catalog_name, db_name, table_name, column
+                ));
+                object_id.push(Some(*table_id));
+                privileges.push(privileges_str.to_string());
+                grant_list.push(format!(
+                    "GRANT {} ({}) ON '{}'.'{}'.'{}' TO {}",
+                    privileges_str, column, catalog_name, db_name, table_name, identity
+                ));
+            }
+        }
+    }
+
+    // procedures are granted by id, display them with name and argument types.
+    let procedure_api = user_api.procedure_api(&tenant);
+    for (procedure_id, privileges_str) in procedure_ids {
+        if let Some(procedure) = procedure_api
+            .get_procedure_name_by_id(&tenant, procedure_id)
+            .await?
+        {
+            object_name.push(procedure.to_string());
+            object_id.push(Some(procedure_id));
+            privileges.push(privileges_str.to_string());
+            grant_list.push(format!(
+                "GRANT {} ON PROCEDURE {} TO {}",
+                privileges_str, procedure, identity
+            ));
+        }
+    }
+
     let names: Vec<String> = vec![name; privileges.len()];
     let grant_tos: Vec<String> = vec![grant_to; privileges.len()];
     Ok(Some(DataBlock::new_from_columns(vec![
@@ -658,9 +774,90 @@ async fn show_object_grant(
                 name,
             )
         }
+        "sequence" => {
+            if !visibility_checker.check_sequence_visibility(name) {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied: privilege USAGE is required on sequence {} for user {}.",
+                    name, current_user
+                )));
+            }
+            (
+                GrantObject::Sequence(name.to_string()),
+                OwnershipObject::Sequence {
+                    name: name.to_string(),
+                },
+                None,
+                name,
+            )
+        }
+        "procedure" => {
+            // name is the procedure identity like `p1(Int32,String)`
+            let (procedure_name, args) = name
+                .strip_suffix(')')
+                .and_then(|name| name.split_once('('))
+                .ok_or_else(|| {
+                    ErrorCode::InvalidArgument(format!(
+                        "Expected procedure like 'name(arg_types)', but got {:?}",
+                        name
+                    ))
+                })?;
+            let req = GetProcedureReq::new(&tenant, ProcedureIdentity::new(procedure_name, args));
+            let Some(procedure) = user_api.procedure_api(&tenant).get_procedure(&req).await? else {
+                return Err(ErrorCode::UnknownProcedure(format!(
+                    "Unknown procedure {}",
+                    name
+                )));
+            };
+            if !visibility_checker.check_procedure_visibility(procedure.id) {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied: privilege USAGE is required on procedure {} for user {}.",
+                    name, current_user
+                )));
+            }
+            (
+                GrantObject::Procedure(procedure.id),
+                OwnershipObject::Procedure {
+                    procedure_id: procedure.id,
+                },
+                Some(procedure.id),
+                name,
+            )
+        }
+        "connection" => {
+            if !visibility_checker.check_connection_visibility(name) {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied: privilege USAGE is required on connection {} for user {}.",
+                    name, current_user
+                )));
+            }
+            (
+                GrantObject::Connection(name.to_string()),
+                OwnershipObject::Connection {
+                    name: name.to_string(),
+                },
+                None,
+                name,
+            )
+        }
+        "file_format" => {
+            if !visibility_checker.check_file_format_visibility(name) {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied: privilege USAGE is required on file format {} for user {}.",
+                    name, current_user
+                )));
+            }
+            (
+                GrantObject::FileFormat(name.to_string()),
+                OwnershipObject::FileFormat {
+                    name: name.to_string(),
+                },
+                None,
+                name,
+            )
+        }
         _ => {
             return Err(ErrorCode::InvalidArgument(format!(
-                "Expected 'table|database|udf|stage', but got {:?}",
+                "Expected 'table|database|udf|stage|sequence|procedure|connection|file_format', but got {:?}",
                 grant_type
             )));
         }
@@ -0,0 +1,465 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_cloud_control::pb;
+use databend_common_cloud_control::pb::alter_task_request::AlterTaskType;
+use databend_common_cloud_control::pb::task::Status;
+use databend_common_cloud_control::pb::task_run::State;
+use databend_common_cloud_control::pb::task_service_server::TaskService;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::TaskMgr;
+use databend_common_meta_types::MatchSeq;
+use databend_common_users::UserApiProvider;
+use tonic::Request;
+use tonic::Response;
+
+use crate::notification::NotificationSender;
+use crate::task::TaskScheduler;
+
+/// The default and the max number of task runs returned by `ShowTaskRuns`.
+const DEFAULT_TASK_RUNS_LIMIT: usize = 100;
+const MAX_TASK_RUNS_LIMIT: usize = 10000;
+
+/// Serves the task requests of the query nodes with the tasks stored in meta,
+/// the same way as cloud control does.
+///
+/// The tenant in the requests is ignored, it always serves the tasks of the tenant of this node.
+pub struct BuiltinTaskService {
+    scheduler: Arc<TaskScheduler>,
+}
+
+impl BuiltinTaskService {
+    pub fn create(scheduler: Arc<TaskScheduler>) -> Arc<BuiltinTaskService> {
+        Arc::new(BuiltinTaskService { scheduler })
+    }
+
+    /// Start the task scheduler of this node, and return the service that serves the task requests with it.
+    pub fn init(
+        config: &InnerConfig,
+        notification_sender: Arc<NotificationSender>,
+    ) -> Result<Arc<BuiltinTaskService>> {
+        let tenant = config.query.tenant_id.tenant_name().to_string();
+        let meta_store = UserApiProvider::instance().get_meta_store_client();
+        let task_mgr = TaskMgr::create(meta_store.as_ref().clone(), &tenant)?;
+
+        let scheduler = TaskScheduler::create(
+            config.query.node_id.clone(),
+            tenant,
+            Arc::new(task_mgr),
+            notification_sender,
+        );
+        scheduler.start();
+
+        Ok(Self::create(scheduler))
+    }
+
+    fn task_mgr(&self) -> Arc<TaskMgr> {
+        self.scheduler.task_mgr()
+    }
+
+    fn unknown_task(task_name: &str) -> ErrorCode {
+        ErrorCode::UnknownTask(format!("Task '{}' does not exist", task_name))
+    }
+
+    #[async_backtrace::framed]
+    async fn do_create_task(&self, req: pb::CreateTaskRequest) -> Result<pb::CreateTaskResponse> {
+        let task_mgr = self.task_mgr();
+
+        for after in req.after.iter() {
+            if task_mgr.get_task(after).await?.is_none() {
+                return Err(Self::unknown_task(after));
+            }
+        }
+
+        let now = Utc::now().to_rfc3339();
+        let task = pb::Task {
+            task_id: 0,
+            task_name: req.task_name,
+            query_text: req.query_text,
+            comment: req.comment,
+            owner: req.owner,
+            schedule_options: req.schedule_options,
+            warehouse_options: req.warehouse_options,
+            next_scheduled_at: None,
+            suspend_task_after_num_failures: req.suspend_task_after_num_failures,
+            status: Status::Suspended as i32,
+            created_at: now.clone(),
+            updated_at: now,
+            last_suspended_at: None,
+            after: req.after,
+            when_condition: req.when_condition,
+            session_parameters: req.session_parameters,
+            error_integration: req.error_integration,
+            success_integration: req.success_integration,
+            task_sql_type: req.task_sql_type,
+            script_sql: req.script_sql,
+        };
+
+        let task_id = task_mgr.create_task(task, req.if_not_exist).await?;
+        Ok(pb::CreateTaskResponse {
+            error: None,
+            task_id,
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn do_describe_task(
+        &self,
+        req: pb::DescribeTaskRequest,
+    ) -> Result<pb::DescribeTaskResponse> {
+        match self.task_mgr().get_task(&req.task_name).await? {
+            Some((_seq, task)) => Ok(pb::DescribeTaskResponse {
+                task: Some(task),
+                error: None,
+            }),
+            None if req.if_exist => Ok(pb::DescribeTaskResponse::default()),
+            None => Err(Self::unknown_task(&req.task_name)),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn do_execute_task(
+        &self,
+        req: pb::ExecuteTaskRequest,
+    ) -> Result<pb::ExecuteTaskResponse> {
+        let Some((_seq, task)) = self.task_mgr().get_task(&req.task_name).await? else {
+            return Err(Self::unknown_task(&req.task_name));
+        };
+
+        self.scheduler.execute(task);
+        Ok(pb::ExecuteTaskResponse::default())
+    }
+
+    #[async_backtrace::framed]
+    async fn do_drop_task(&self, req: pb::DropTaskRequest) -> Result<pb::DropTaskResponse> {
+        let dropped = self.task_mgr().drop_task(&req.task_name).await?;
+
+        if dropped.is_none() && !req.if_exist {
+            return Err(Self::unknown_task(&req.task_name));
+        }
+        Ok(pb::DropTaskResponse::default())
+    }
+
+    #[async_backtrace::framed]
+    async fn do_alter_task(&self, req: pb::AlterTaskRequest) -> Result<pb::AlterTaskResponse> {
+        let task_mgr = self.task_mgr();
+
+        for after in req.add_after.iter() {
+            if task_mgr.get_task(after).await?.is_none() {
+                return Err(Self::unknown_task(after));
+            }
+        }
+
+        // Retry if the task is changed by others, e.g., the scheduler, during altering.
+        loop {
+            let Some((seq, mut task)) = task_mgr.get_task(&req.task_name).await? else {
+                if req.if_exist {
+                    return Ok(pb::AlterTaskResponse::default());
+                }
+                return Err(Self::unknown_task(&req.task_name));
+            };
+
+            Self::apply_alter(&mut task, &req);
+
+            if task_mgr.update_task(&task, MatchSeq::Exact(seq)).await? {
+                return Ok(pb::AlterTaskResponse {
+                    error: None,
+                    task: Some(task),
+                });
+            }
+        }
+    }
+
+    fn apply_alter(task: &mut pb::Task, req: &pb::AlterTaskRequest) {
+        let now = Utc::now().to_rfc3339();
+
+        match req.alter_task_type() {
+            AlterTaskType::Suspend => {
+                task.status = Status::Suspended as i32;
+                task.last_suspended_at = Some(now.clone());
+            }
+            AlterTaskType::Resume => {
+                task.status = Status::Started as i32;
+                // The next scheduled time is computed by the scheduler.
+                task.next_scheduled_at = None;
+            }
+            AlterTaskType::Set => {
+                if req.schedule_options.is_some() {
+                    task.schedule_options = req.schedule_options.clone();
+                    task.next_scheduled_at = None;
+                }
+                if req.warehouse_options.is_some() {
+                    task.warehouse_options = req.warehouse_options.clone();
+                }
+                if req.suspend_task_after_num_failures.is_some() {
+                    task.suspend_task_after_num_failures = req.suspend_task_after_num_failures;
+                }
+                if req.comment.is_some() {
+                    task.comment = req.comment.clone();
+                }
+                if req.error_integration.is_some() {
+                    task.error_integration = req.error_integration.clone();
+                }
+                if req.success_integration.is_some() {
+                    task.success_integration = req.success_integration.clone();
+                }
+                if req.set_session_parameters {
+                    task.session_parameters = req.session_parameters.clone();
+                }
+            }
+            AlterTaskType::ModifyAs => {
+                task.query_text = req.query_text.clone().unwrap_or_default();
+                task.task_sql_type = req.task_sql_type;
+                task.script_sql = req.script_sql.clone();
+            }
+            AlterTaskType::ModifyWhen => {
+                task.when_condition = req.when_condition.clone();
+            }
+            AlterTaskType::AddAfter => {
+                for after in req.add_after.iter() {
+                    if !task.after.contains(after) {
+                        task.after.push(after.clone());
+                    }
+                }
+            }
+            AlterTaskType::RemoveAfter => {
+                task.after.retain(|after| !req.remove_after.contains(after));
+            }
+        }
+
+        task.updated_at = now;
+    }
+
+    #[async_backtrace::framed]
+    async fn do_show_tasks(&self, req: pb::ShowTasksRequest) -> Result<pb::ShowTasksResponse> {
+        let tasks = self.task_mgr().list_tasks().await?;
+
+        let mut tasks = tasks
+            .into_iter()
+            .map(|(_seq, task)| task)
+            .filter(|task| Self::is_visible(&req.owners, &req.task_ids, task))
+            .collect::<Vec<_>>();
+
+        if req.result_limit > 0 {
+            tasks.truncate(req.result_limit as usize);
+        }
+
+        Ok(pb::ShowTasksResponse { tasks, error: None })
+    }
+
+    /// A task is visible if its owner is one of the `owners` or its id is one of the `task_ids`.
+    fn is_visible(owners: &[String], task_ids: &[String], task: &pb::Task) -> bool {
+        if owners.is_empty() && task_ids.is_empty() {
+            return true;
+        }
+        owners.contains(&task.owner) || task_ids.contains(&task.task_id.to_string())
+    }
+
+    #[async_backtrace::framed]
+    async fn do_show_task_runs(
+        &self,
+        req: pb::ShowTaskRunsRequest,
+    ) -> Result<pb::ShowTaskRunsResponse> {
+        let parse_time = |s: &str| {
+            DateTime::parse_from_rfc3339(s)
+                .ok()
+                .map(|t| t.with_timezone(&Utc))
+        };
+        let start = parse_time(&req.scheduled_time_start);
+        let end = parse_time(&req.scheduled_time_end);
+
+        let mut task_runs = self
+            .task_mgr()
+            .list_task_runs(None)
+            .await?
+            .into_iter()
+            .filter(|run| {
+                let scheduled_time = parse_time(&run.scheduled_time);
+                let is_error =
+                    run.state == State::Failed as i32 || run.state == State::Cancelled as i32;
+
+                (!req.error_only || is_error)
+                    && (req.owners.is_empty() || req.owners.contains(&run.owner))
+                    && (req.task_ids.is_empty() || req.task_ids.contains(&run.task_id.to_string()))
+                    && (req.task_name.is_empty() || req.task_name == run.task_name)
+                    && (req.task_names.is_empty() || req.task_names.contains(&run.task_name))
+                    && (req.root_task_id.is_none()
+                        || req.root_task_id.as_ref() == Some(&run.root_task_id))
+                    && (start.is_none() || scheduled_time >= start)
+                    && (end.is_none() || scheduled_time <= end)
+            })
+            .collect::<Vec<_>>();
+
+        // The most recent runs first.
+        task_runs.sort_by(|a, b| b.scheduled_time.cmp(&a.scheduled_time));
+
+        let limit = match req.result_limit {
+            n if n <= 0 => DEFAULT_TASK_RUNS_LIMIT,
+            n => (n as usize).min(MAX_TASK_RUNS_LIMIT),
+        };
+        task_runs.truncate(limit);
+
+        Ok(pb::ShowTaskRunsResponse {
+            task_runs,
+            error: None,
+            next_page_token: None,
+            previous_page_token: None,
+        })
+    }
+
+    /// Returns the task and the tasks that run after it, in breadth-first order.
+    #[async_backtrace::framed]
+    async fn get_dependents(&self, task_name: &str, recursive: bool) -> Result<Vec<pb::Task>> {
+        let tasks = self.task_mgr().list_tasks().await?;
+        let tasks = tasks.into_iter().map(|(_seq, t)| t).collect::<Vec<_>>();
+
+        let Some(root) = tasks.iter().find(|t| t.task_name == task_name) else {
+            return Err(Self::unknown_task(task_name));
+        };
+
+        let mut visited = BTreeSet::from([root.task_name.clone()]);
+        let mut queue = VecDeque::from([(root.clone(), 0)]);
+        let mut dependents = vec![];
+
+        while let Some((task, depth)) = queue.pop_front() {
+            if recursive || depth == 0 {
+                for dependent in tasks.iter() {
+                    if dependent.after.contains(&task.task_name)
+                        && visited.insert(dependent.task_name.clone())
+                    {
+                        queue.push_back((dependent.clone(), depth + 1));
+                    }
+                }
+            }
+            dependents.push(task);
+        }
+
+        Ok(dependents)
+    }
+
+    #[async_backtrace::framed]
+    async fn do_enable_task_dependents(
+        &self,
+        req: pb::EnableTaskDependentsRequest,
+    ) -> Result<pb::EnableTaskDependentsResponse> {
+        let task_mgr = self.task_mgr();
+
+        for task in self.get_dependents(&req.task_name, true).await? {
+            loop {
+                let Some((seq, mut task)) = task_mgr.get_task(&task.task_name).await? else {
+                    break;
+                };
+                if task.status == Status::Started as i32 {
+                    break;
+                }
+
+                task.status = Status::Started as i32;
+                task.next_scheduled_at = None;
+                task.updated_at = Utc::now().to_rfc3339();
+                if task_mgr.update_task(&task, MatchSeq::Exact(seq)).await? {
+                    break;
+                }
+            }
+        }
+
+        Ok(pb::EnableTaskDependentsResponse::default())
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskService for BuiltinTaskService {
+    async fn create_task(
+        &self,
+        request: Request<pb::CreateTaskRequest>,
+    ) -> std::result::Result<Response<pb::CreateTaskResponse>, tonic::Status> {
+        let resp = self.do_create_task(request.into_inner()).await?;
+        Ok(Response::new(resp))
+    }
+
+    async fn describe_task(
+        &self,
+        request: Request<pb::DescribeTaskRequest>,
+    ) -> std::result::Result<Response<pb::DescribeTaskResponse>, tonic::Status> {
+        let resp = self.do_describe_task(request.into_inner()).await?;
+        Ok(Response::new(resp))
+    }
+
+    async fn execute_task(
+        &self,
+        request: Request<pb::ExecuteTaskRequest>,
+    ) -> std::result::Result<Response<pb::ExecuteTaskResponse>, tonic::Status> {
+        let resp = self.do_execute_task(request.into_inner()).await?;
+        Ok(Response::new(resp))
+    }
+
+    async fn drop_task(
+        &self,
+        request: Request<pb::DropTaskRequest>,
+    ) -> std::result::Result<Response<pb::DropTaskResponse>, tonic::Status> {
+        let resp = self.do_drop_task(request.into_inner()).await?;
+        Ok(Response::new(resp))
+    }
+
+    async fn alter_task(
+        &self,
+        request: Request<pb::AlterTaskRequest>,
+    ) -> std::result::Result<Response<pb::AlterTaskResponse>, tonic::Status> {
+        let resp = self.do_alter_task(request.into_inner()).await?;
+        Ok(Response::new(resp))
+    }
+
+    async fn show_tasks(
+        &self,
+        request: Request<pb::ShowTasksRequest>,
+    ) -> std::result::Result<Response<pb::ShowTasksResponse>, tonic::Status> {
+        let resp = self.do_show_tasks(request.into_inner()).await?;
+        Ok(Response::new(resp))
+    }
+
+    async fn show_task_runs(
+        &self,
+        request: Request<pb::ShowTaskRunsRequest>,
+    ) -> std::result::Result<Response<pb::ShowTaskRunsResponse>, tonic::Status> {
+        let resp = self.do_show_task_runs(request.into_inner()).await?;
+        Ok(Response::new(resp))
+    }
+
+    async fn get_task_dependents(
+        &self,
+        request: Request<pb::GetTaskDependentsRequest>,
+    ) -> std::result::Result<Response<pb::GetTaskDependentsResponse>, tonic::Status> {
+        let req = request.into_inner();
+        let task = self.get_dependents(&req.task_name, req.recursive).await?;
+        Ok(Response::new(pb::GetTaskDependentsResponse {
+            task,
+            error: None,
+        }))
+    }
+
+    async fn enable_task_dependents(
+        &self,
+        request: Request<pb::EnableTaskDependentsRequest>,
+    ) -> std::result::Result<Response<pb::EnableTaskDependentsResponse>, tonic::Status> {
+        let resp = self.do_enable_task_dependents(request.into_inner()).await?;
+        Ok(Response::new(resp))
+    }
+}
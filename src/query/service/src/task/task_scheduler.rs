@@ -0,0 +1,531 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_base::base::tokio::time::sleep as tokio_async_sleep;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_cloud_control::pb;
+use databend_common_cloud_control::pb::schedule_options::ScheduleType;
+use databend_common_cloud_control::pb::task::Status;
+use databend_common_cloud_control::pb::task_run::State;
+use databend_common_cloud_control::pb::TaskSqlType;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::TaskMgr;
+use databend_common_management::TaskState;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_types::MatchSeq;
+use futures_util::StreamExt;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+use crate::interpreters::interpreter_plan_sql;
+use crate::interpreters::InterpreterFactory;
+use crate::notification::NotificationSender;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// How often the scheduler checks for due tasks.
+const SCHEDULE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The node stops being the scheduler if it does not renew its record within this time.
+const SCHEDULER_TTL: Duration = Duration::from_secs(15);
+
+/// How often the scheduler renews its record.
+const SCHEDULER_RENEW_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The run of a DAG that a task run belongs to.
+#[derive(Clone, Debug)]
+struct DagRun {
+    root_task_id: String,
+    root_run_id: String,
+}
+
+#[derive(Default)]
+struct SchedulerState {
+    /// Ids of the tasks that are running on this node.
+    running: HashSet<u64>,
+}
+
+/// Runs the tasks stored in meta, for deployments without cloud control.
+///
+/// Every query node runs a scheduler, but only the one that holds the scheduler record in meta
+/// starts the scheduled tasks. A task may also be started on any node with `EXECUTE TASK`.
+///
+/// When a run finishes, its result is sent to the `SUCCESS_INTEGRATION` or `ERROR_INTEGRATION` of the task.
+/// The consecutive failures and the progress of the DAG runs are kept in the [`TaskState`] in meta.
+pub struct TaskScheduler {
+    node_id: String,
+    tenant: String,
+    task_mgr: Arc<TaskMgr>,
+    notification_sender: Arc<NotificationSender>,
+    state: Mutex<SchedulerState>,
+}
+
+impl TaskScheduler {
+    pub fn create(
+        node_id: String,
+        tenant: String,
+        task_mgr: Arc<TaskMgr>,
+        notification_sender: Arc<NotificationSender>,
+    ) -> Arc<TaskScheduler> {
+        Arc::new(TaskScheduler {
+            node_id,
+            tenant,
+            task_mgr,
+            notification_sender,
+            state: Mutex::new(SchedulerState::default()),
+        })
+    }
+
+    pub fn task_mgr(&self) -> Arc<TaskMgr> {
+        self.task_mgr.clone()
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(async move { scheduler.schedule_loop().await });
+    }
+
+    #[async_backtrace::framed]
+    async fn schedule_loop(self: Arc<Self>) {
+        let mut renewed_at: Option<Instant> = None;
+
+        loop {
+            tokio_async_sleep(SCHEDULE_INTERVAL).await;
+
+            let need_renew =
+                !matches!(renewed_at, Some(t) if t.elapsed() < SCHEDULER_RENEW_INTERVAL);
+            if need_renew {
+                match self
+                    .task_mgr
+                    .acquire_scheduler(&self.node_id, SCHEDULER_TTL)
+                    .await
+                {
+                    Ok(true) => {
+                        if renewed_at.is_none() {
+                            info!("Node {} becomes the task scheduler", self.node_id);
+                        }
+                        renewed_at = Some(Instant::now());
+                    }
+                    Ok(false) => {
+                        renewed_at = None;
+                    }
+                    Err(cause) => {
+                        warn!("Cannot acquire the task scheduler, cause {:?}", cause);
+                        renewed_at = None;
+                    }
+                }
+            }
+
+            if renewed_at.is_none() {
+                continue;
+            }
+
+            if let Err(cause) = self.schedule_due_tasks().await {
+                warn!("Cannot schedule tasks, cause {:?}", cause);
+            }
+        }
+    }
+
+    /// Start the root tasks whose next scheduled time has come.
+    ///
+    /// The next scheduled time is advanced in meta before a task is started,
+    /// so that a task is not started twice if another node takes over the scheduler.
+    #[async_backtrace::framed]
+    async fn schedule_due_tasks(self: &Arc<Self>) -> Result<()> {
+        let now = Utc::now();
+
+        for (seq, task) in self.task_mgr.list_tasks().await? {
+            if task.status != Status::Started as i32 || !task.after.is_empty() {
+                continue;
+            }
+
+            let Some(schedule) = task.schedule_options.as_ref() else {
+                continue;
+            };
+
+            let next = task
+                .next_scheduled_at
+                .as_deref()
+                .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                .map(|t| t.with_timezone(&Utc));
+
+            let is_due = matches!(next, Some(next) if next <= now);
+            if next.is_some() && !is_due {
+                continue;
+            }
+
+            let mut task = task.clone();
+            task.next_scheduled_at = match next_scheduled_at(schedule, now) {
+                Ok(next) => next.map(|t| t.to_rfc3339()),
+                Err(cause) => {
+                    warn!("Cannot schedule task {}, cause {:?}", task.task_name, cause);
+                    continue;
+                }
+            };
+
+            if !self
+                .task_mgr
+                .update_task(&task, MatchSeq::Exact(seq))
+                .await?
+            {
+                // The task is changed since it was listed, it will be checked in the next round.
+                continue;
+            }
+
+            if is_due {
+                self.spawn_run(task, None);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Run a task on this node in the background, as a new DAG run, e.g., for `EXECUTE TASK`.
+    pub fn execute(self: &Arc<Self>, task: pb::Task) {
+        self.spawn_run(task, None)
+    }
+
+    /// Run a task in the background, unless the task is still running on this node.
+    fn spawn_run(self: &Arc<Self>, task: pb::Task, dag_run: Option<DagRun>) {
+        if !self.state.lock().running.insert(task.task_id) {
+            info!(
+                "Skip running task {}, its previous run is not finished",
+                task.task_name
+            );
+            return;
+        }
+
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            let task_id = task.task_id;
+            let task_name = task.task_name.clone();
+
+            if let Err(cause) = scheduler.run_task(task, dag_run).await {
+                warn!("Cannot run task {}, cause {:?}", task_name, cause);
+            }
+
+            scheduler.state.lock().running.remove(&task_id);
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn run_task(self: &Arc<Self>, task: pb::Task, dag_run: Option<DagRun>) -> Result<()> {
+        let session = self.create_session(&task).await?;
+
+        if let Some(condition) = task.when_condition.as_ref().filter(|c| !c.is_empty()) {
+            let ctx = session.create_query_context().await?;
+            let sql = format!("SELECT 1 WHERE {}", condition);
+            if Self::execute_sql(ctx, &sql).await? == 0 {
+                info!(
+                    "Skip running task {}, condition {} is not met",
+                    task.task_name, condition
+                );
+                return Ok(());
+            }
+        }
+
+        let run_id = Uuid::new_v4().simple().to_string();
+        let dag_run = dag_run.unwrap_or_else(|| DagRun {
+            root_task_id: task.task_id.to_string(),
+            root_run_id: run_id.clone(),
+        });
+
+        let mut task_run = pb::TaskRun {
+            task_id: task.task_id,
+            task_name: task.task_name.clone(),
+            query_text: task.query_text.clone(),
+            comment: task.comment.clone(),
+            owner: task.owner.clone(),
+            schedule_options: task.schedule_options.clone(),
+            run_id,
+            attempt_number: 0,
+            warehouse_options: task.warehouse_options.clone(),
+            state: State::Executing as i32,
+            error_code: 0,
+            error_message: None,
+            scheduled_time: Utc::now().to_rfc3339(),
+            completed_time: None,
+            query_id: "".to_string(),
+            condition_text: task.when_condition.clone().unwrap_or_default(),
+            root_task_id: dag_run.root_task_id.clone(),
+            session_parameters: task.session_parameters.clone(),
+        };
+        self.task_mgr.upsert_task_run(&task_run).await?;
+
+        let res = self.execute_task(&session, &task, &mut task_run).await;
+
+        task_run.completed_time = Some(Utc::now().to_rfc3339());
+        match &res {
+            Ok(_) => {
+                task_run.state = State::Succeeded as i32;
+            }
+            Err(cause) => {
+                task_run.state = State::Failed as i32;
+                task_run.error_code = cause.code() as i64;
+                task_run.error_message = Some(cause.message());
+            }
+        }
+        self.task_mgr.upsert_task_run(&task_run).await?;
+        self.notify(&task, &task_run);
+
+        match res {
+            Ok(_) => self.on_succeeded(&task, &dag_run).await,
+            Err(_) => self.on_failed(&task).await,
+        }
+    }
+
+    /// Tasks run as the owner role of the task.
+    #[async_backtrace::framed]
+    async fn create_session(&self, task: &pb::Task) -> Result<Arc<Session>> {
+        let session_manager = SessionManager::instance();
+        let session = session_manager.create_session(SessionType::Task).await?;
+        let session = session_manager.register_session(session)?;
+
+        let user = UserInfo::new_no_auth(&format!("{}-task-scheduler", self.tenant), "0.0.0.0");
+        session
+            .set_authed_user(user, Some(task.owner.clone()))
+            .await?;
+
+        let session_parameters: HashMap<String, String> =
+            task.session_parameters.clone().into_iter().collect();
+        session
+            .get_settings()
+            .set_batch_settings(&session_parameters)?;
+
+        Ok(session)
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_task(
+        &self,
+        session: &Arc<Session>,
+        task: &pb::Task,
+        task_run: &mut pb::TaskRun,
+    ) -> Result<()> {
+        let sqls = match task.task_sql_type() {
+            TaskSqlType::Sql => vec![task.query_text.clone()],
+            TaskSqlType::Script => task
+                .script_sql
+                .as_ref()
+                .map(|s| s.sqls.clone())
+                .unwrap_or_default(),
+        };
+
+        for sql in sqls {
+            let ctx = session.create_query_context().await?;
+            task_run.query_id = ctx.get_id();
+            Self::execute_sql(ctx, &sql).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Execute a sql and return the number of result rows.
+    #[async_backtrace::framed]
+    async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<usize> {
+        let (plan, _) = interpreter_plan_sql(ctx.clone(), sql).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let mut stream = interpreter.execute(ctx).await?;
+
+        let mut num_rows = 0;
+        while let Some(block) = stream.next().await {
+            num_rows += block?.num_rows();
+        }
+        Ok(num_rows)
+    }
+
+    /// Send the result of a finished run to the integration of the task, if any.
+    fn notify(&self, task: &pb::Task, task_run: &pb::TaskRun) {
+        let (integration, state) = match task_run.state() {
+            State::Succeeded => (task.success_integration.as_ref(), "SUCCEEDED"),
+            _ => (task.error_integration.as_ref(), "FAILED"),
+        };
+        let Some(integration) = integration.filter(|i| !i.is_empty()) else {
+            return;
+        };
+
+        let message = serde_json::json!({
+            "source": "task",
+            "task_name": task_run.task_name,
+            "task_id": task_run.task_id,
+            "run_id": task_run.run_id,
+            "query_id": task_run.query_id,
+            "state": state,
+            "error_code": task_run.error_code,
+            "error_message": task_run.error_message,
+            "scheduled_time": task_run.scheduled_time,
+            "completed_time": task_run.completed_time,
+        });
+        self.notification_sender
+            .send_in_background(integration.clone(), "task", message);
+    }
+
+    /// Update the state of a task in meta by `f`, it is retried if the state is changed
+    /// concurrently by another run.
+    #[async_backtrace::framed]
+    async fn update_task_state<R>(
+        &self,
+        task_name: &str,
+        f: impl Fn(&mut TaskState) -> R,
+    ) -> Result<R> {
+        loop {
+            let (seq, mut state) = self.task_mgr.get_task_state(task_name).await?;
+            let res = f(&mut state);
+            if self
+                .task_mgr
+                .update_task_state(task_name, &state, MatchSeq::Exact(seq))
+                .await?
+            {
+                return Ok(res);
+            }
+        }
+    }
+
+    /// Start the dependents whose predecessors have all succeeded in this DAG run.
+    #[async_backtrace::framed]
+    async fn on_succeeded(self: &Arc<Self>, task: &pb::Task, dag_run: &DagRun) -> Result<()> {
+        let root_run_id = &dag_run.root_run_id;
+        self.update_task_state(&task.task_name, |state| {
+            state.failures = 0;
+            state.succeeded_run = Some(root_run_id.clone());
+        })
+        .await?;
+
+        for (_seq, dependent) in self.task_mgr.list_tasks().await? {
+            if dependent.status != Status::Started as i32
+                || !dependent.after.contains(&task.task_name)
+            {
+                continue;
+            }
+
+            let mut ready = true;
+            for name in &dependent.after {
+                let (_seq, state) = self.task_mgr.get_task_state(name).await?;
+                if state.succeeded_run.as_ref() != Some(root_run_id) {
+                    ready = false;
+                    break;
+                }
+            }
+            if !ready {
+                continue;
+            }
+
+            // The predecessors may finish at the same time, only one of them starts the dependent.
+            let triggered = self
+                .update_task_state(&dependent.task_name, |state| {
+                    state.triggered_run.replace(root_run_id.clone()).as_ref() != Some(root_run_id)
+                })
+                .await?;
+            if triggered {
+                self.spawn_run(dependent, Some(dag_run.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Suspend the task if it fails `suspend_task_after_num_failures` times in a row.
+    #[async_backtrace::framed]
+    async fn on_failed(&self, task: &pb::Task) -> Result<()> {
+        let limit = task.suspend_task_after_num_failures.unwrap_or_default();
+
+        let failures = self
+            .update_task_state(&task.task_name, |state| {
+                state.failures += 1;
+                let failures = state.failures;
+                if limit > 0 && failures >= limit {
+                    state.failures = 0;
+                }
+                failures
+            })
+            .await?;
+
+        if limit <= 0 || failures < limit {
+            return Ok(());
+        }
+
+        let Some((seq, mut task)) = self.task_mgr.get_task(&task.task_name).await? else {
+            return Ok(());
+        };
+
+        info!(
+            "Suspend task {} after {} consecutive failures",
+            task.task_name, failures
+        );
+
+        let now = Utc::now().to_rfc3339();
+        task.status = Status::Suspended as i32;
+        task.last_suspended_at = Some(now.clone());
+        task.updated_at = now;
+        self.task_mgr
+            .update_task(&task, MatchSeq::Exact(seq))
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Returns the first time that a task should run after `after`, or None if it never runs.
+pub fn next_scheduled_at(
+    schedule: &pb::ScheduleOptions,
+    after: DateTime<Utc>,
+) -> Result<Option<DateTime<Utc>>> {
+    match schedule.schedule_type() {
+        ScheduleType::IntervalType => {
+            let millis = schedule.interval.unwrap_or_default() as i64 * 1000
+                + schedule.milliseconds_interval.unwrap_or_default() as i64;
+            if millis <= 0 {
+                return Ok(None);
+            }
+            Ok(Some(after + chrono::Duration::milliseconds(millis)))
+        }
+        ScheduleType::CronType => {
+            let expr = schedule.cron.as_deref().unwrap_or_default();
+            let cron = cron::Schedule::from_str(expr).map_err(|e| {
+                ErrorCode::IllegalCloudControlMessageFormat(format!(
+                    "invalid cron expression {}, {}",
+                    expr, e
+                ))
+            })?;
+
+            let time_zone = match schedule.time_zone.as_deref() {
+                None | Some("") => chrono_tz::UTC,
+                Some(time_zone) => chrono_tz::Tz::from_str(time_zone).map_err(|e| {
+                    ErrorCode::IllegalCloudControlMessageFormat(format!(
+                        "invalid time zone {}, {}",
+                        time_zone, e
+                    ))
+                })?,
+            };
+
+            let next = cron.after(&after.with_timezone(&time_zone)).next();
+            Ok(next.map(|t| t.with_timezone(&Utc)))
+        }
+    }
+}
@@ -346,6 +346,7 @@ impl TestFixture {
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
             cluster_key: Some("(id)".to_string()),
+            partition_key: None,
             inverted_indexes: None,
         }
     }
@@ -371,6 +372,7 @@ impl TestFixture {
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
             cluster_key: None,
+            partition_key: None,
             inverted_indexes: None,
         }
     }
@@ -407,6 +409,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            partition_key: None,
             inverted_indexes: None,
         }
     }
@@ -443,6 +446,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            partition_key: None,
             inverted_indexes: None,
         }
     }
@@ -488,6 +492,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            partition_key: None,
             inverted_indexes: None,
         }
     }
@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_query::audit::verify_audit_chain;
+use databend_query::audit::verify_audit_log_files;
+use databend_query::audit::AuditCategory;
+use databend_query::audit::AuditEvent;
+use databend_query::audit::AuditLogger;
+use databend_query::audit::AuditSink;
+use databend_query::audit::AUDIT_CHAIN_STATE_FILE;
+use databend_query::audit::AUDIT_GENESIS_HASH;
+use parking_lot::Mutex;
+
+#[derive(Default)]
+struct MemorySink {
+    events: Arc<Mutex<Vec<AuditEvent>>>,
+    state_file: Option<std::path::PathBuf>,
+}
+
+impl AuditSink for MemorySink {
+    fn write(&self, event: &AuditEvent) -> Result<()> {
+        // The head of the chain is saved before the event reaches the sinks.
+        if let Some(state_file) = &self.state_file {
+            let state: serde_json::Value = serde_json::from_slice(&std::fs::read(state_file)?)?;
+            assert!(state["next_seq"].as_u64().unwrap() > event.seq);
+        }
+        self.events.lock().push(event.clone());
+        Ok(())
+    }
+}
+
+const HMAC_KEY: &[u8] = b"audit-secret";
+
+fn create_logger(
+    node_id: &str,
+    state_file: Option<std::path::PathBuf>,
+    sink: MemorySink,
+) -> Result<Arc<AuditLogger>> {
+    AuditLogger::create(
+        "test_tenant".to_string(),
+        "test_cluster".to_string(),
+        node_id.to_string(),
+        HMAC_KEY.to_vec(),
+        state_file,
+        vec![Box::new(sink)],
+    )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_audit_hash_chain() -> Result<()> {
+    let sink = MemorySink::default();
+    let events = sink.events.clone();
+    let logger = create_logger("test_node", None, sink)?;
+
+    let mut grant = AuditEvent::create(AuditCategory::Privilege, "GrantRole");
+    grant.user_name = "root".to_string();
+    grant.statement = "GRANT ROLE r1 TO u1".to_string();
+    logger.log(grant);
+
+    let denied = ErrorCode::PermissionDenied("Permission denied");
+    logger.log(AuditEvent::create(AuditCategory::Ddl, "DropTable").with_error(Some(&denied)));
+    logger.log(AuditEvent::create(AuditCategory::Session, "Logout"));
+    logger.flush().await;
+
+    let events = events.lock().clone();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[0].seq, 0);
+    assert_eq!(events[0].prev_hash, AUDIT_GENESIS_HASH);
+    assert_eq!(events[0].node_id, "test_node");
+    assert_eq!(events[1].prev_hash, events[0].hash);
+    assert!(!events[1].success);
+    assert_eq!(events[1].error_code, ErrorCode::PERMISSION_DENIED as i32);
+    verify_audit_chain(&events, HMAC_KEY)?;
+
+    // The chain can not be verified without the key.
+    assert!(verify_audit_chain(&events, b"another-secret").is_err());
+
+    // The hash of the event is serialized with it.
+    let line = serde_json::to_string(&events[0])?;
+    let parsed: AuditEvent = serde_json::from_str(&line)?;
+    assert_eq!(parsed, events[0]);
+    assert!(line.contains(r#""category":"privilege""#));
+
+    // A modified event does not match its hash.
+    let mut modified = events.clone();
+    modified[0].statement = "GRANT ROLE account_admin TO u1".to_string();
+    assert!(verify_audit_chain(&modified, HMAC_KEY).is_err());
+
+    // A removed event breaks the chain.
+    let removed = vec![events[0].clone(), events[2].clone()];
+    assert!(verify_audit_chain(&removed, HMAC_KEY).is_err());
+
+    // Rehashing a modified event does not fix the events after it.
+    let mut rehashed = events.clone();
+    rehashed[1].success = true;
+    rehashed[1].hash = rehashed[1].compute_hash(HMAC_KEY)?;
+    assert!(verify_audit_chain(&rehashed, HMAC_KEY).is_err());
+
+    // A chain rebuilt with another key does not verify.
+    let mut forged = events.clone();
+    forged[1].success = true;
+    let mut prev_hash = forged[0].hash.clone();
+    for event in forged.iter_mut().skip(1) {
+        event.prev_hash = prev_hash;
+        event.hash = event.compute_hash(b"guessed-secret")?;
+        prev_hash = event.hash.clone();
+    }
+    assert!(verify_audit_chain(&forged, HMAC_KEY).is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_audit_chain_across_restarts() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let state_file = dir.path().join(AUDIT_CHAIN_STATE_FILE);
+
+    let sink = MemorySink {
+        state_file: Some(state_file.clone()),
+        ..Default::default()
+    };
+    let events = sink.events.clone();
+    let logger = create_logger("node_1", Some(state_file.clone()), sink)?;
+    logger.log(AuditEvent::create(AuditCategory::Session, "Login"));
+    logger.log(AuditEvent::create(AuditCategory::Ddl, "CreateTable"));
+    drop(logger);
+
+    // The restarted node continues the chain from the state file.
+    let sink = MemorySink {
+        events: events.clone(),
+        state_file: Some(state_file.clone()),
+    };
+    let logger = create_logger("node_2", Some(state_file), sink)?;
+    logger.log(AuditEvent::create(AuditCategory::Ddl, "DropTable"));
+    logger.flush().await;
+
+    let events = events.lock().clone();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[2].seq, 2);
+    assert_eq!(events[2].prev_hash, events[1].hash);
+    verify_audit_chain(&events, HMAC_KEY)?;
+
+    // The events are verified from the log files, after the prefix of the log layout.
+    let file_1 = dir.path().join("audit.log.1");
+    let file_2 = dir.path().join("audit.log.2");
+    let line = |event: &AuditEvent| -> Result<String> {
+        Ok(format!(
+            "2024-01-01T00:00:00Z INFO {}\n",
+            serde_json::to_string(event)?
+        ))
+    };
+    std::fs::write(&file_1, line(&events[0])? + &line(&events[1])?)?;
+    std::fs::write(&file_2, line(&events[2])?)?;
+    assert_eq!(
+        verify_audit_log_files(&[file_1.clone(), file_2.clone()], HMAC_KEY)?,
+        3
+    );
+    assert!(verify_audit_log_files(&[file_2, file_1], HMAC_KEY).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_query::history::QueryHistoryWriter;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_history() -> Result<()> {
+    let mut config = ConfigBuilder::create().build();
+    config.query.query_history.enabled = true;
+    config.query.query_history.flush_interval_secs = 1;
+    let fixture = TestFixture::setup_with_config(&config).await?;
+
+    fixture.execute_command("SELECT 'query history'").await?;
+
+    // Write the buffered events, instead of waiting for the background task.
+    QueryHistoryWriter::try_instance().unwrap().flush().await?;
+
+    let sql = "SELECT query_id FROM system_history.query_history \
+        WHERE query_text = 'SELECT ''query history''' AND log_type_name = 'Finish'";
+    let blocks = fixture
+        .execute_query(sql)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let num_rows: usize = blocks.iter().map(|b| b.num_rows()).sum();
+    assert_eq!(1, num_rows);
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx
+        .get_table(CATALOG_DEFAULT, "system_history", "query_history")
+        .await?;
+    assert_eq!(
+        Some(&"event_time + INTERVAL 7 DAY".to_string()),
+        table.options().get("ttl")
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_history_schema_drift() -> Result<()> {
+    let mut config = ConfigBuilder::create().build();
+    config.query.query_history.enabled = true;
+    // Only written by the explicit flush below.
+    config.query.query_history.flush_interval_secs = 3600;
+    let fixture = TestFixture::setup_with_config(&config).await?;
+
+    // The tables left by an older version: a missing column, and a column of another type.
+    fixture
+        .execute_command("CREATE DATABASE system_history")
+        .await?;
+    fixture
+        .execute_command(
+            "CREATE TABLE system_history.query_history \
+            (`query_id` String NOT NULL, `event_time` Timestamp NOT NULL)",
+        )
+        .await?;
+    fixture
+        .execute_command("CREATE TABLE system_history.login_history (`event_time` String NULL)")
+        .await?;
+
+    fixture.execute_command("SELECT 'schema drift'").await?;
+    QueryHistoryWriter::try_instance().unwrap().flush().await?;
+
+    let sql = "SELECT query_id FROM system_history.query_history \
+        WHERE query_text = 'SELECT ''schema drift''' AND log_type_name = 'Finish'";
+    let blocks = fixture
+        .execute_query(sql)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let num_rows: usize = blocks.iter().map(|b| b.num_rows()).sum();
+    assert_eq!(1, num_rows);
+
+    let sql = "SELECT name FROM system.tables \
+        WHERE database = 'system_history' AND name LIKE 'login_history_before_%'";
+    let blocks = fixture
+        .execute_query(sql)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let num_rows: usize = blocks.iter().map(|b| b.num_rows()).sum();
+    assert_eq!(1, num_rows);
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx
+        .get_table(CATALOG_DEFAULT, "system_history", "login_history")
+        .await?;
+    assert_eq!(
+        &TableDataType::Timestamp,
+        table.schema().field_with_name("event_time")?.data_type()
+    );
+
+    Ok(())
+}
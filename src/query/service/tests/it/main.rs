@@ -20,6 +20,7 @@
 #![recursion_limit = "256"]
 
 extern crate core;
+mod audit;
 mod auth;
 mod catalogs;
 mod clusters;
@@ -27,7 +28,9 @@ mod configs;
 mod databases;
 mod distributed;
 mod frame;
+mod history;
 mod metrics;
+mod notification;
 mod parquet_rs;
 mod pipelines;
 mod servers;
@@ -37,3 +40,4 @@ mod sql;
 mod storages;
 mod table_functions;
 mod tests;
+mod trace_context;
@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_cloud_control::pb;
+use databend_common_exception::Result;
+use databend_common_management::NotificationMgr;
+use databend_common_meta_embedded::MetaEmbedded;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::MatchSeq;
+use databend_query::notification::sign_webhook_payload;
+use databend_query::notification::NotificationSender;
+use databend_query::notification::WEBHOOK_SIGNATURE_HEADER;
+use databend_query::notification::WEBHOOK_TIMESTAMP_HEADER;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_webhook_notification() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sender = new_notification_sender().await?;
+    let notification_mgr = sender.notification_mgr();
+    let notification = pb::Notification {
+        name: "n1".to_string(),
+        enabled: true,
+        webhook_url: format!("{}/hook", server.uri()),
+        webhook_method: Some("POST".to_string()),
+        webhook_authorization_header: Some("Bearer token".to_string()),
+        webhook_signing_secret: Some("secret".to_string()),
+        ..Default::default()
+    };
+    notification_mgr
+        .create_notification(notification, false)
+        .await?;
+
+    let message = serde_json::json!({"source": "task", "state": "FAILED"});
+    sender.send("n1", "task", &message).await?;
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(1, requests.len());
+    let request = &requests[0];
+    let body = String::from_utf8(request.body.clone()).unwrap();
+    assert_eq!(message.to_string(), body);
+
+    let header = |name: &str| request.headers.get(name).unwrap().to_str().unwrap();
+    assert_eq!("Bearer token", header("Authorization"));
+    let timestamp = header(WEBHOOK_TIMESTAMP_HEADER).parse::<i64>().unwrap();
+    assert_eq!(
+        format!(
+            "sha256={}",
+            sign_webhook_payload("secret", timestamp, &body)
+        ),
+        header(WEBHOOK_SIGNATURE_HEADER)
+    );
+
+    let histories = notification_mgr
+        .list_notification_history(Some("n1"))
+        .await?;
+    assert_eq!(1, histories.len());
+    assert_eq!("task", histories[0].message_source);
+    assert_eq!("SUCCESS", histories[0].status);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_webhook_notification_retry() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let sender = new_notification_sender().await?;
+    let notification_mgr = sender.notification_mgr();
+    let notification = pb::Notification {
+        name: "n1".to_string(),
+        enabled: true,
+        webhook_url: server.uri(),
+        ..Default::default()
+    };
+    notification_mgr
+        .create_notification(notification, false)
+        .await?;
+
+    let message = serde_json::json!({"source": "copy"});
+    assert!(sender.send("n1", "copy", &message).await.is_err());
+
+    let histories = notification_mgr.list_notification_history(None).await?;
+    assert_eq!(1, histories.len());
+    assert_eq!("FAILED", histories[0].status);
+    assert!(histories[0].error_message.contains("after 3 attempts"));
+
+    // Nothing is sent by a disabled integration.
+    let (seq, mut notification) = notification_mgr.get_notification("n1").await?.unwrap();
+    notification.enabled = false;
+    notification_mgr
+        .update_notification(&notification, MatchSeq::Exact(seq))
+        .await?;
+    sender.send("n1", "copy", &message).await?;
+    assert_eq!(3, server.received_requests().await.unwrap().len());
+
+    Ok(())
+}
+
+async fn new_notification_sender() -> Result<Arc<NotificationSender>> {
+    let meta_store = MetaStore::L(Arc::new(MetaEmbedded::new_temp().await?));
+    let notification_mgr = NotificationMgr::create(meta_store, "test-tenant-id")?;
+    NotificationSender::create(Arc::new(notification_mgr), Duration::from_millis(10))
+}
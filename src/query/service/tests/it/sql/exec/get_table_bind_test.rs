@@ -32,6 +32,7 @@ use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use databend_common_catalog::runtime_filter_info::TopNRuntimeFilter;
 use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::ContextError;
@@ -57,6 +58,8 @@ use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::principal::UserPrivilegeType;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
+use databend_common_meta_app::schema::AlterSequenceReply;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::CommitTableMetaReply;
 use databend_common_meta_app::schema::CommitTableMetaReq;
@@ -108,8 +111,11 @@ use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
+use databend_common_meta_app::schema::SequenceMeta;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -297,6 +303,13 @@ impl Catalog for FakedCatalog {
         todo!()
     }
 
+    async fn set_table_row_access_policy(
+        &self,
+        _req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        todo!()
+    }
+
     #[async_backtrace::framed]
     async fn create_table_index(&self, _req: CreateTableIndexReq) -> Result<()> {
         unimplemented!()
@@ -416,6 +429,10 @@ impl Catalog for FakedCatalog {
         unimplemented!()
     }
 
+    async fn list_sequences(&self, _tenant: &Tenant) -> Result<Vec<(String, SequenceMeta)>> {
+        unimplemented!()
+    }
+
     async fn get_sequence_next_value(
         &self,
         _req: GetSequenceNextValueReq,
@@ -423,6 +440,10 @@ impl Catalog for FakedCatalog {
         unimplemented!()
     }
 
+    async fn alter_sequence(&self, _req: AlterSequenceReq) -> Result<AlterSequenceReply> {
+        unimplemented!()
+    }
+
     async fn drop_sequence(&self, _req: DropSequenceReq) -> Result<DropSequenceReply> {
         unimplemented!()
     }
@@ -947,6 +968,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_topn_runtime_filter_with_id(&self, _id: usize) -> Option<Arc<TopNRuntimeFilter>> {
+        todo!()
+    }
+
     fn has_bloom_runtime_filters(&self, _id: usize) -> bool {
         todo!()
     }
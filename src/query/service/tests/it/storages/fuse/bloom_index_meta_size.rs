@@ -336,6 +336,9 @@ fn build_test_segment_info(
         inverted_index_size: None,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        partition_value: None,
+        ngram_index_location: None,
+        ngram_index_size: 0,
     };
 
     let block_metas = (0..num_blocks_per_seg)
@@ -351,6 +354,7 @@ fn build_test_segment_info(
         index_size: 0,
         col_stats: col_stats.clone(),
         cluster_stats: None,
+        partition_value: None,
     };
 
     Ok(SegmentInfo::new(block_metas, statistics))
@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_tracing::start_trace_for_client;
+use databend_query::test_kits::TestFixture;
+use fastrace::collector::Reporter;
+use fastrace::collector::SpanContext;
+use fastrace::collector::SpanRecord;
+use fastrace::collector::TraceId;
+use fastrace::future::FutureExt;
+use futures_util::TryStreamExt;
+use parking_lot::Mutex;
+
+#[derive(Clone, Default)]
+struct CollectReporter {
+    spans: Arc<Mutex<Vec<SpanRecord>>>,
+}
+
+impl Reporter for CollectReporter {
+    fn report(&mut self, spans: Vec<SpanRecord>) {
+        self.spans.lock().extend(spans);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_traceparent_reaches_storage_io() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let reporter = CollectReporter::default();
+    fastrace::set_reporter(reporter.clone(), fastrace::collector::Config::default());
+
+    let traceparent = "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01";
+    let root = start_trace_for_client("test_traceparent", Some(traceparent), SpanContext::random);
+    async {
+        fixture
+            .execute_command("CREATE TABLE default.t_trace(a INT, b STRING)")
+            .await?;
+        fixture
+            .execute_command("INSERT INTO default.t_trace VALUES (1, 'a'), (2, 'b')")
+            .await?;
+        fixture
+            .execute_query("SELECT * FROM default.t_trace WHERE b = 'a'")
+            .await?
+            .try_collect::<Vec<DataBlock>>()
+            .await?;
+        Ok::<_, ErrorCode>(())
+    }
+    .in_span(root)
+    .await?;
+    fastrace::flush();
+
+    let trace_id = TraceId(0x0af7651916cd43dd8448eb211c80319c);
+    let names = reporter
+        .spans
+        .lock()
+        .iter()
+        .filter(|span| span.trace_id == trace_id)
+        .map(|span| span.name.to_string())
+        .collect::<Vec<_>>();
+    let has_span = |expected: &[&str]| {
+        names
+            .iter()
+            .any(|name| expected.iter().any(|e| name.contains(e)))
+    };
+
+    // Block, bloom index and segment writes of the insert.
+    assert!(has_span(&["write_down_data_block"]), "{:?}", names);
+    assert!(has_span(&["write_down_bloom_index_state"]), "{:?}", names);
+    assert!(has_span(&["write_data"]), "{:?}", names);
+    // Block reads of the select.
+    assert!(
+        has_span(&["read_columns_data_by_merge_io", "read_native_columns_data",]),
+        "{:?}",
+        names
+    );
+
+    Ok(())
+}
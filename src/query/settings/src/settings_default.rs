@@ -29,6 +29,9 @@ static DEFAULT_SETTINGS: OnceCell<Arc<DefaultSettings>> = OnceCell::new();
 
 // Default value of cost factor settings
 #[allow(dead_code)]
+// The settings that only apply to the session they are set in, they can't be set globally.
+static SESSION_ONLY_SETTINGS: &[&str] = &["trace_parent"];
+
 static COST_FACTOR_COMPUTE_PER_ROW: u64 = 1;
 static COST_FACTOR_HASH_TABLE_PER_ROW: u64 = 10;
 static COST_FACTOR_AGGREGATE_PER_ROW: u64 = 5;
@@ -227,6 +230,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("copy_error_integration", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_string()),
+                    desc: "The notification integration that receives the errors of copy into table, only takes effect with the built-in notification service.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
                 ("timezone", DefaultSettingValue {
                     value: UserSettingValue::String("UTC".to_owned()),
                     desc: "Sets the timezone.",
@@ -275,6 +284,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_common_subexpression_elimination", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables evaluating identical deterministic expressions in projections and filters only once.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_dio", DefaultSettingValue{ 
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables Direct IO.",
@@ -347,6 +362,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_topn_runtime_filter", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables skipping the blocks that can not be in the result of ORDER BY ... LIMIT during table scan.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("max_execute_time_in_seconds", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum query execution time in seconds. Setting it to 0 means no limit.",
@@ -413,6 +434,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_query_hints", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables applying the query hints created by CREATE QUERY HINT to matching queries.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_query_result_cache", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables caching query results to improve performance for identical queries.",
@@ -579,6 +606,12 @@ impl DefaultSettings {
                     mode: SettingMode::Write,
                     range: None,
                 }),
+                ("trace_parent", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "The W3C traceparent of the client trace that the queries of this session are traced under, e.g., '00-<trace-id>-<parent-id>-01'. It can't be set globally.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
                 ("enable_distributed_copy_into", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables distributed execution for the 'COPY INTO'.",
@@ -621,12 +654,30 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("analyze_mcv_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the number of most common values collected for each column during analyzing table, 0 disables it.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1000)),
+                }),
                 ("enable_aggregating_index_scan", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables scanning aggregating index data while querying.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_materialized_view_rewrite", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables rewriting aggregation queries to read the dynamic tables that materialize them.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("materialized_view_rewrite_max_staleness", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum seconds since the last refresh for a dynamic table to be used by query rewriting after its base tables are modified, 0 means only up-to-date dynamic tables are used.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("enable_compact_after_write", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables compact after write(copy/insert/replace-into/merge-into), need more memory.",
@@ -777,6 +828,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_expand_star_to_permitted_columns", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Expands SELECT * only to the columns granted by column-level privileges when the table-level SELECT privilege is missing, otherwise the query fails on the denied columns.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("create_query_flight_client_with_current_rt", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Turns on (1) or off (0) the use of the current runtime for query operations.",
@@ -1113,6 +1170,10 @@ impl DefaultSettings {
         }
     }
 
+    pub fn is_session_only(key: &str) -> bool {
+        SESSION_ONLY_SETTINGS.contains(&key)
+    }
+
     pub fn check_setting_mode(key: &str, expect: SettingMode) -> Result<()> {
         let default_settings = DefaultSettings::instance()?;
         let setting_mode = default_settings
@@ -269,6 +269,14 @@ impl Settings {
         Ok(self.try_get_u64("purge_duplicated_files_in_copy")? != 0)
     }
 
+    pub fn get_copy_error_integration(&self) -> Result<String> {
+        self.try_get_string("copy_error_integration")
+    }
+
+    pub fn get_trace_parent(&self) -> Result<String> {
+        self.try_get_string("trace_parent")
+    }
+
     pub fn get_timezone(&self) -> Result<String> {
         self.try_get_string("timezone")
     }
@@ -302,6 +310,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_cbo")? != 0)
     }
 
+    pub fn get_enable_common_subexpression_elimination(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_common_subexpression_elimination")? != 0)
+    }
+
     pub fn get_enable_dio(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_dio")? != 0)
     }
@@ -343,6 +355,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_bloom_runtime_filter")? != 0)
     }
 
+    pub fn get_enable_topn_runtime_filter(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_topn_runtime_filter")? != 0)
+    }
+
     pub fn get_prefer_broadcast_join(&self) -> Result<bool> {
         Ok(self.try_get_u64("prefer_broadcast_join")? != 0)
     }
@@ -404,6 +420,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_planner_cache")? != 0)
     }
 
+    pub fn get_enable_query_hints(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_query_hints")? != 0)
+    }
+
     pub fn get_enable_experimental_procedure(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_experimental_procedure")? != 0)
     }
@@ -508,6 +528,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_experimental_rbac_check")? != 0)
     }
 
+    pub fn get_enable_expand_star_to_permitted_columns(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_expand_star_to_permitted_columns")? != 0)
+    }
+
     pub fn get_table_lock_expire_secs(&self) -> Result<u64> {
         self.try_get_u64("table_lock_expire_secs")
     }
@@ -565,10 +589,22 @@ impl Settings {
         Ok(self.try_get_u64("enable_analyze_histogram")? != 0)
     }
 
+    pub fn get_analyze_mcv_size(&self) -> Result<u64> {
+        self.try_get_u64("analyze_mcv_size")
+    }
+
     pub fn get_enable_aggregating_index_scan(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
     }
 
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_view_rewrite")? != 0)
+    }
+
+    pub fn get_materialized_view_rewrite_max_staleness(&self) -> Result<u64> {
+        self.try_get_u64("materialized_view_rewrite_max_staleness")
+    }
+
     pub fn get_enable_compact_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_compact_after_write")? != 0)
     }
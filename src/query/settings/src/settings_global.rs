@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::UserSetting;
 use databend_common_meta_app::principal::UserSettingValue;
@@ -50,6 +51,12 @@ impl Settings {
     #[async_backtrace::framed]
     pub async fn set_global_setting(&self, k: String, v: String) -> Result<()> {
         let (key, value) = DefaultSettings::convert_value(k.clone(), v)?;
+        if DefaultSettings::is_session_only(&key) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Setting {} can only be set for a session, not globally",
+                key
+            )));
+        }
         self.changes.insert(key.clone(), ChangeValue {
             value: value.clone(),
             level: ScopeLevel::Global,
@@ -94,6 +101,10 @@ impl Settings {
         for global_setting in global_settings {
             let name = global_setting.name;
             let val = global_setting.value.as_string();
+            if DefaultSettings::is_session_only(&name) {
+                warn!("Ignore session only global setting {} = {}", name, val);
+                continue;
+            }
 
             self.changes
                 .insert(name.clone(), match default_settings.settings.get(&name) {
@@ -130,6 +130,16 @@ async fn test_set_global_settings() {
         .await;
     let expect = "UnknownVariable. Code: 2801, Text = Unknown variable: \"query_flight_compression_notfound\".";
     assert_eq!(expect, format!("{}", result.unwrap_err()));
+
+    // A session only setting can't be set globally.
+    let result = settings
+        .set_global_setting(
+            "trace_parent".to_string(),
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01".to_string(),
+        )
+        .await;
+    let expect = "BadArguments. Code: 1006, Text = Setting trace_parent can only be set for a session, not globally.";
+    assert_eq!(expect, format!("{}", result.unwrap_err()));
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
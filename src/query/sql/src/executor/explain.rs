@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -19,3 +21,17 @@ use serde::Serialize;
 pub struct PlanStatsInfo {
     pub estimated_rows: f64,
 }
+
+/// A plan node of `EXPLAIN ANALYZE JSON`: the cardinality estimated by the optimizer
+/// and the profile statistics collected while executing the node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanProfileNode {
+    pub id: u32,
+    pub name: String,
+    pub desc: String,
+    pub estimated_rows: Option<f64>,
+    pub actual_rows: Option<usize>,
+    /// Non-zero statistics, keyed by the name of `ProfileStatisticsName`.
+    pub statistics: BTreeMap<String, usize>,
+    pub children: Vec<PlanProfileNode>,
+}
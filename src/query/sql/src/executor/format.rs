@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use databend_common_ast::ast::FormatTreeNode;
 use databend_common_base::base::format_byte_size;
 use databend_common_base::runtime::profile::get_statistics_desc;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_base::runtime::profile::StatisticsUnit;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -26,6 +29,7 @@ use databend_common_pipeline_core::processors::PlanProfile;
 use itertools::Itertools;
 
 use super::physical_plans::AddStreamColumn;
+use crate::executor::explain::PlanProfileNode;
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::physical_plans::AggregateExpand;
 use crate::executor::physical_plans::AggregateFinal;
@@ -82,6 +86,14 @@ impl PhysicalPlan {
         to_format_tree(self, &metadata, &profs)
     }
 
+    /// Format the plan with the estimated and actual rows and the profile
+    /// statistics of each node as JSON, used by `EXPLAIN ANALYZE [PARTIAL] JSON`.
+    /// If `partial` is true, only scan/filter/join nodes and the row statistics are kept.
+    pub fn format_json(&self, profs: &HashMap<u32, PlanProfile>, partial: bool) -> Result<String> {
+        let node = to_profile_node(self, profs, partial)?;
+        Ok(serde_json::to_string_pretty(&node)?)
+    }
+
     pub fn format_join(&self, metadata: &MetadataRef) -> Result<FormatTreeNode<String>> {
         match self {
             PhysicalPlan::TableScan(plan) => {
@@ -488,15 +500,73 @@ fn to_format_tree(
     }
 }
 
+#[recursive::recursive]
+fn to_profile_node(
+    plan: &PhysicalPlan,
+    profs: &HashMap<u32, PlanProfile>,
+    partial: bool,
+) -> Result<PlanProfileNode> {
+    if partial
+        && !matches!(
+            plan,
+            PhysicalPlan::TableScan(_)
+                | PhysicalPlan::Filter(_)
+                | PhysicalPlan::HashJoin(_)
+                | PhysicalPlan::RangeJoin(_)
+                | PhysicalPlan::UnionAll(_)
+        )
+    {
+        let mut children = plan.children();
+        if let (Some(child), None) = (children.next(), children.next()) {
+            return to_profile_node(child, profs, partial);
+        }
+    }
+
+    let plan_id = plan.get_id();
+    let mut actual_rows = None;
+    let mut statistics = BTreeMap::new();
+    if let Some(prof) = profs.get(&plan_id) {
+        for (name, desc) in get_statistics_desc().iter() {
+            let value = prof.statistics[desc.index];
+            if *name == ProfileStatisticsName::OutputRows {
+                actual_rows = Some(value);
+            }
+            if value != 0 && (!partial || desc.unit == StatisticsUnit::Rows) {
+                statistics.insert(name.to_string(), value);
+            }
+        }
+    }
+
+    let mut estimated_rows = plan.get_stat_info().map(|info| info.estimated_rows);
+    if partial {
+        estimated_rows = estimated_rows.map(|rows| (rows * 100.0).round() / 100.0);
+    }
+
+    Ok(PlanProfileNode {
+        id: plan_id,
+        name: plan.name(),
+        desc: plan.get_desc()?,
+        estimated_rows,
+        actual_rows,
+        statistics,
+        children: plan
+            .children()
+            .map(|child| to_profile_node(child, profs, partial))
+            .collect::<Result<Vec<_>>>()?,
+    })
+}
+
 /// Helper function to add profile info to the format tree.
+/// The output rows of an executed node are always shown, even if it is zero,
+/// so that they can be compared with the estimated rows.
 fn append_profile_info(
     children: &mut Vec<FormatTreeNode<String>>,
     profs: &HashMap<u32, PlanProfile>,
     plan_id: u32,
 ) {
     if let Some(prof) = profs.get(&plan_id) {
-        for (_, desc) in get_statistics_desc().iter() {
-            if prof.statistics[desc.index] != 0 {
+        for (name, desc) in get_statistics_desc().iter() {
+            if prof.statistics[desc.index] != 0 || *name == ProfileStatisticsName::OutputRows {
                 children.push(FormatTreeNode::new(format!(
                     "{}: {}",
                     desc.display_name.to_lowercase(),
@@ -513,18 +583,18 @@ fn append_output_rows_info(
     plan_id: u32,
 ) {
     if let Some(prof) = profs.get(&plan_id) {
-        for (_, desc) in get_statistics_desc().iter() {
-            if desc.display_name != "output rows" {
+        // Only the row statistics are shown, they are stable across runs.
+        for (name, desc) in get_statistics_desc().iter() {
+            if desc.unit != StatisticsUnit::Rows {
                 continue;
             }
-            if prof.statistics[desc.index] != 0 {
+            if prof.statistics[desc.index] != 0 || *name == ProfileStatisticsName::OutputRows {
                 children.push(FormatTreeNode::new(format!(
                     "{}: {}",
                     desc.display_name.to_lowercase(),
                     desc.human_format(prof.statistics[desc.index])
                 )));
             }
-            break;
         }
     }
 }
@@ -22,6 +22,7 @@ mod util;
 
 pub mod table_read_plan;
 
+pub use explain::PlanProfileNode;
 pub use format::format_partial_tree;
 pub use physical_plan::PhysicalPlan;
 pub use physical_plan_builder::MutationBuildInfo;
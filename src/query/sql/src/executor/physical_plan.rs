@@ -27,6 +27,7 @@ use super::physical_plans::MutationManipulate;
 use super::physical_plans::MutationOrganize;
 use super::physical_plans::MutationSource;
 use super::physical_plans::MutationSplit;
+use crate::executor::explain::PlanStatsInfo;
 use crate::executor::physical_plans::AggregateExpand;
 use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregatePartial;
@@ -465,6 +466,31 @@ impl PhysicalPlan {
         }
     }
 
+    /// Get the cardinality of the plan node estimated by the optimizer, if any.
+    pub fn get_stat_info(&self) -> Option<&PlanStatsInfo> {
+        match self {
+            PhysicalPlan::TableScan(v) => v.stat_info.as_ref(),
+            PhysicalPlan::Filter(v) => v.stat_info.as_ref(),
+            PhysicalPlan::EvalScalar(v) => v.stat_info.as_ref(),
+            PhysicalPlan::ProjectSet(v) => v.stat_info.as_ref(),
+            PhysicalPlan::AggregateExpand(v) => v.stat_info.as_ref(),
+            PhysicalPlan::AggregatePartial(v) => v.stat_info.as_ref(),
+            PhysicalPlan::AggregateFinal(v) => v.stat_info.as_ref(),
+            PhysicalPlan::WindowPartition(v) => v.stat_info.as_ref(),
+            PhysicalPlan::Sort(v) => v.stat_info.as_ref(),
+            PhysicalPlan::Limit(v) => v.stat_info.as_ref(),
+            PhysicalPlan::RowFetch(v) => v.stat_info.as_ref(),
+            PhysicalPlan::HashJoin(v) => v.stat_info.as_ref(),
+            PhysicalPlan::RangeJoin(v) => v.stat_info.as_ref(),
+            PhysicalPlan::UnionAll(v) => v.stat_info.as_ref(),
+            PhysicalPlan::Udf(v) => v.stat_info.as_ref(),
+            PhysicalPlan::AsyncFunction(v) => v.stat_info.as_ref(),
+            PhysicalPlan::CteScan(v) => Some(&v.stat),
+            PhysicalPlan::RecursiveCteScan(v) => Some(&v.stat),
+            _ => None,
+        }
+    }
+
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         match self {
             PhysicalPlan::AsyncFunction(plan) => plan.output_schema(),
@@ -316,6 +316,7 @@ pub trait PhysicalPlanReplacer {
             limit: plan.limit,
             after_exchange: plan.after_exchange,
             pre_projection: plan.pre_projection.clone(),
+            topn_runtime_filter: plan.topn_runtime_filter.clone(),
             stat_info: plan.stat_info.clone(),
         }))
     }
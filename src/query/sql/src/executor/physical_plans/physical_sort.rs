@@ -18,6 +18,7 @@ use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::TableDataType;
 use databend_common_pipeline_transforms::processors::sort::utils::ORDER_COL_NAME;
 use itertools::Itertools;
 
@@ -27,6 +28,8 @@ use crate::executor::physical_plans::WindowPartition;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::SExpr;
+use crate::plans::RelOperator;
+use crate::ColumnEntry;
 use crate::ColumnSet;
 use crate::IndexType;
 
@@ -42,6 +45,10 @@ pub struct Sort {
     /// It's [None] if the sorting plan is in single node mode.
     pub after_exchange: Option<bool>,
     pub pre_projection: Option<Vec<IndexType>>,
+    /// The base table column name and the table index of the first sort key, if the
+    /// rows of the sort come from the table scan without changing the sort key. It's
+    /// used to skip the blocks that can not be in the top-N rows during the scan.
+    pub topn_runtime_filter: Option<(String, IndexType)>,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -122,6 +129,15 @@ impl PhysicalPlanBuilder {
 
         let input_plan = self.build(s_expr.child(0)?, required).await?;
 
+        let topn_runtime_filter = if sort.limit.is_some()
+            && sort.window_partition.is_empty()
+            && self.ctx.get_settings().get_enable_topn_runtime_filter()?
+        {
+            self.topn_runtime_filter_column(s_expr.child(0)?, sort.items[0].index)
+        } else {
+            None
+        };
+
         let window_partition = sort
             .window_partition
             .iter()
@@ -159,7 +175,45 @@ impl PhysicalPlanBuilder {
             limit: sort.limit,
             after_exchange: sort.after_exchange,
             pre_projection,
+            topn_runtime_filter,
             stat_info: Some(stat_info),
         }))
     }
+
+    // Find the base table column of the sort key in the table scan. Only `EvalScalar` and
+    // `Filter` are allowed between the sort and the scan, other operators may change the
+    // rows that the sort key comes from, e.g. the nulls generated by outer join.
+    fn topn_runtime_filter_column(
+        &self,
+        mut s_expr: &SExpr,
+        index: IndexType,
+    ) -> Option<(String, IndexType)> {
+        loop {
+            match s_expr.plan() {
+                RelOperator::EvalScalar(_) | RelOperator::Filter(_) => {
+                    s_expr = s_expr.child(0).ok()?;
+                }
+                RelOperator::Scan(scan) => {
+                    let metadata = self.metadata.read();
+                    let ColumnEntry::BaseTableColumn(column) = metadata.column(index) else {
+                        return None;
+                    };
+                    // The min/max statistics of string column are truncated.
+                    let supported = matches!(
+                        column.data_type.remove_nullable(),
+                        TableDataType::Number(_)
+                            | TableDataType::Decimal(_)
+                            | TableDataType::Date
+                            | TableDataType::Timestamp
+                    );
+                    return (supported
+                        && column.table_index == scan.table_index
+                        && column.path_indices.is_none()
+                        && column.virtual_computed_expr.is_none())
+                    .then(|| (column.column_name.clone(), scan.table_index));
+                }
+                _ => return None,
+            }
+        }
+    }
 }
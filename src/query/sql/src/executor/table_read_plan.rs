@@ -243,6 +243,7 @@ impl ToReadDataSourcePlan for dyn Table {
                                 let scalar = type_checker.resolve(&ast_expr)?;
                                 let expr = scalar.0.as_expr()?.project_column_ref(|col| col.index);
                                 mask_policy_map.insert(i, expr.as_remote_expr());
+                                ctx.add_masked_column(&table_info.desc, field.name());
                             } else {
                                 info!(
                                     "cannot find mask policy {}/{}",
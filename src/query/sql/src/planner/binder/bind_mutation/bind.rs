@@ -32,6 +32,7 @@ use databend_common_expression::Scalar;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::ROW_VERSION_COL_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use indexmap::IndexMap;
 
 use crate::binder::bind_mutation::mutation_expression::MutationExpression;
@@ -41,6 +42,7 @@ use crate::binder::wrap_cast;
 use crate::binder::Binder;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
+use crate::parse_exprs;
 use crate::plans::BoundColumnRef;
 use crate::plans::ConstantExpr;
 use crate::plans::FunctionCall;
@@ -277,6 +279,25 @@ impl Binder {
             );
         }
 
+        // The rows can't be moved to another partition by updating the partition columns.
+        if let Some(partition_key) = table.options().get(OPT_KEY_PARTITION_BY) {
+            let partition_columns = parse_exprs(self.ctx.clone(), table.clone(), partition_key)?
+                .iter()
+                .flat_map(|expr| expr.column_refs().into_keys())
+                .collect::<HashSet<_>>();
+            for evaluator in &matched_evaluators {
+                let Some(update) = &evaluator.update else {
+                    continue;
+                };
+                if let Some(index) = update.keys().find(|i| partition_columns.contains(i)) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Partition column '{}' can't be updated",
+                        table_schema.field(*index).name()
+                    )));
+                }
+            }
+        }
+
         // Bind not matched clause columns and add insert exprs
         for clause in &unmatched_clauses {
             unmatched_evaluators.push(
@@ -39,6 +39,7 @@ use crate::optimizer::SExpr;
 use crate::optimizer::SubqueryRewriter;
 use crate::plans::BoundColumnRef;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::MaterializedCte;
 use crate::plans::MutationSource;
 use crate::plans::RelOperator;
@@ -191,6 +192,14 @@ impl MutationExpression {
                 let (mut s_expr, mut bind_context) =
                     binder.bind_table_reference(bind_context, target)?;
 
+                // The row access policy of target table is bound as a filter over the scan,
+                // take it out and merge it into the mutation filter.
+                let mut row_access_predicates = vec![];
+                if let RelOperator::Filter(policy_filter) = s_expr.plan() {
+                    row_access_predicates = policy_filter.predicates.clone();
+                    s_expr = s_expr.child(0)?.clone();
+                }
+
                 // Get target table index.
                 let target_table_index = binder
                     .metadata
@@ -203,7 +212,7 @@ impl MutationExpression {
 
                 // If the filter is a simple expression, change the mutation strategy to MutationStrategy::Direct.
                 let (mutation_strategy, filter) =
-                    binder.process_filter(&mut bind_context, filter)?;
+                    binder.process_filter(&mut bind_context, filter, row_access_predicates)?;
 
                 // Build bind result according to mutation strategy.
                 if mutation_strategy == MutationStrategy::Direct {
@@ -481,6 +490,7 @@ impl Binder {
         &self,
         bind_context: &mut BindContext,
         filter: &Option<Expr>,
+        mut predicates: Vec<ScalarExpr>,
     ) -> Result<(MutationStrategy, Option<ScalarExpr>)> {
         if let Some(expr) = filter {
             let mut scalar_binder = ScalarBinder::new(
@@ -493,6 +503,18 @@ impl Binder {
                 self.ctes_map.clone(),
             );
             let (scalar, _) = scalar_binder.bind(expr)?;
+            predicates.push(scalar);
+        }
+
+        let scalar = predicates.into_iter().reduce(|lhs, rhs| {
+            ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "and".to_string(),
+                params: vec![],
+                arguments: vec![lhs, rhs],
+            })
+        });
+        if let Some(scalar) = scalar {
             if !self.check_allowed_scalar_expr_with_subquery(&scalar)? {
                 return Err(ErrorCode::SemanticError(
                     "filter in mutation statement can't contain window|aggregate|udf functions"
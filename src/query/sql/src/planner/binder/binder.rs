@@ -147,6 +147,7 @@ impl<'a> Binder {
         let mut bind_context = BindContext::new();
         let plan = self.bind_statement(&mut bind_context, stmt).await?;
         self.bind_query_index(&mut bind_context, &plan).await?;
+        self.bind_query_materialized_views(&plan).await?;
         self.ctx.set_status_info(&format!(
             "bind stmt to plan done, time used: {:?}",
             start.elapsed()
@@ -199,9 +200,9 @@ impl<'a> Binder {
                 self.bind_explain(bind_context, kind, options, query).await?
             }
 
-            Statement::ExplainAnalyze {partial, query } => {
+            Statement::ExplainAnalyze {partial, json, query } => {
                 let plan = self.bind_statement(bind_context, query).await?;
-                Plan::ExplainAnalyze { partial: *partial, plan: Box::new(plan) }
+                Plan::ExplainAnalyze { partial: *partial, json: *json, plan: Box::new(plan), query_hint: None }
             }
 
             Statement::ShowFunctions { show_options } => {
@@ -304,6 +305,7 @@ impl<'a> Binder {
             Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(bind_context, stmt).await?,
             Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(bind_context, stmt).await?,
             Statement::RefreshInvertedIndex(stmt) => self.bind_refresh_inverted_index(bind_context, stmt).await?,
+            Statement::RefreshNgramIndex(stmt) => self.bind_refresh_ngram_index(bind_context, stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumn(stmt) => self.bind_create_virtual_column(stmt).await?,
@@ -507,6 +509,15 @@ impl<'a> Binder {
             Statement::DescDatamaskPolicy(stmt) => {
                 self.bind_desc_data_mask_policy(stmt).await?
             }
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy(stmt) => {
+                self.bind_drop_row_access_policy(stmt).await?
+            }
+            Statement::DescRowAccessPolicy(stmt) => {
+                self.bind_desc_row_access_policy(stmt).await?
+            }
             Statement::CreateNetworkPolicy(stmt) => {
                 self.bind_create_network_policy(stmt).await?
             }
@@ -535,6 +546,15 @@ impl<'a> Binder {
                 self.bind_desc_password_policy(stmt).await?
             }
             Statement::ShowPasswordPolicies{ show_options } => self.bind_show_password_policies(bind_context, show_options).await?,
+            Statement::CreateQueryHint(stmt) => {
+                self.bind_create_query_hint(stmt).await?
+            }
+            Statement::DropQueryHint(stmt) => {
+                self.bind_drop_query_hint(stmt).await?
+            }
+            Statement::ShowQueryHints => {
+                self.bind_show_query_hints().await?
+            }
             Statement::CreateTask(stmt) => {
                 self.bind_create_task(stmt).await?
             }
@@ -562,6 +582,7 @@ impl<'a> Binder {
 
             // Dynamic Table
             Statement::CreateDynamicTable(stmt) => self.bind_create_dynamic_table(stmt).await?,
+            Statement::AlterDynamicTable(stmt) => self.bind_alter_dynamic_table(stmt).await?,
 
             Statement::CreatePipe(_) => {
                 todo!()
@@ -593,6 +614,15 @@ impl<'a> Binder {
             Statement::DropSequence(stmt) => {
                 self.bind_drop_sequence(stmt).await?
             }
+            Statement::AlterSequence(stmt) => {
+                self.bind_alter_sequence(stmt).await?
+            }
+            Statement::ShowSequences { show_options } => {
+                self.bind_show_sequences(bind_context, show_options).await?
+            }
+            Statement::DescSequence(stmt) => {
+                self.bind_desc_sequence(stmt).await?
+            }
             Statement::Begin => Plan::Begin,
             Statement::Commit => Plan::Commit,
             Statement::Abort => Plan::Abort,
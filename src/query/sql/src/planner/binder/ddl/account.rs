@@ -19,21 +19,28 @@ use databend_common_ast::ast::AlterUserStmt;
 use databend_common_ast::ast::CreateUserStmt;
 use databend_common_ast::ast::GrantObjectName;
 use databend_common_ast::ast::GrantStmt;
+use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::PrincipalIdentity as AstPrincipalIdentity;
+use databend_common_ast::ast::ProcedureIdentity as AstProcedureIdentity;
 use databend_common_ast::ast::RevokeStmt;
 use databend_common_ast::ast::ShowObjectPrivilegesStmt;
 use databend_common_ast::ast::ShowOptions;
+use databend_common_ast::ast::UserOptionItem;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::AuthInfo;
+use databend_common_meta_app::principal::GetProcedureReq;
 use databend_common_meta_app::principal::GrantObject;
 use databend_common_meta_app::principal::PrincipalIdentity;
+use databend_common_meta_app::principal::ProcedureIdentity;
 use databend_common_meta_app::principal::UserOption;
 use databend_common_meta_app::principal::UserPrivilegeSet;
 use databend_common_users::UserApiProvider;
+use databend_common_users::UserPublicKey;
 
 use crate::binder::show::get_show_options;
 use crate::binder::util::illegal_ident_name;
+use crate::normalize_identifier;
 use crate::plans::AlterUserPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::GrantPrivilegePlan;
@@ -68,7 +75,7 @@ impl Binder {
                 let priv_types = grant_object.available_privileges(false);
                 let plan: GrantPrivilegePlan = GrantPrivilegePlan {
                     principal: principal.clone().into(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
@@ -81,7 +88,24 @@ impl Binder {
                 }
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone().into(),
-                    on: grant_object,
+                    on: vec![grant_object],
+                    priv_types,
+                };
+                Ok(Plan::GrantPriv(Box::new(plan)))
+            }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                let grant_objects = self.convert_to_column_grant_objects(level, columns).await?;
+                let mut priv_types = UserPrivilegeSet::empty();
+                for x in privileges {
+                    priv_types.set_privilege(x.clone().into());
+                }
+                let plan = GrantPrivilegePlan {
+                    principal: principal.clone().into(),
+                    on: grant_objects,
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
@@ -135,6 +159,23 @@ impl Binder {
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
             }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                let grant_objects = self.convert_to_column_grant_objects(level, columns).await?;
+                let mut priv_types = UserPrivilegeSet::empty();
+                for x in privileges {
+                    priv_types.set_privilege(x.clone().into());
+                }
+                let plan = RevokePrivilegePlan {
+                    principal: principal.clone().into(),
+                    on: grant_objects,
+                    priv_types,
+                };
+                Ok(Plan::RevokePriv(Box::new(plan)))
+            }
         }
     }
 
@@ -187,9 +228,88 @@ impl Binder {
             }
             AccountMgrLevel::UDF(udf) => Ok(GrantObject::UDF(udf.clone())),
             AccountMgrLevel::Stage(stage) => Ok(GrantObject::Stage(stage.clone())),
+            AccountMgrLevel::Sequence(sequence) => Ok(GrantObject::Sequence(
+                self.normalize_object_identifier(sequence),
+            )),
+            AccountMgrLevel::Procedure(procedure) => {
+                let procedure_id = self.get_procedure_id(procedure).await?;
+                Ok(GrantObject::Procedure(procedure_id))
+            }
+            AccountMgrLevel::Connection(connection) => {
+                Ok(GrantObject::Connection(connection.clone()))
+            }
+            AccountMgrLevel::FileFormat(file_format) => {
+                Ok(GrantObject::FileFormat(file_format.clone()))
+            }
         }
     }
 
+    // Procedures are overloaded by argument types, so they are granted by id.
+    async fn get_procedure_id(&self, procedure: &AstProcedureIdentity) -> Result<u64> {
+        let tenant = self.ctx.get_tenant();
+        let req = GetProcedureReq::new(&tenant, ProcedureIdentity::from(procedure.clone()));
+        let procedure = UserApiProvider::instance()
+            .get_procedure(&tenant, req)
+            .await?;
+        Ok(procedure.id)
+    }
+
+    // Column privileges are stored per column id, so one grant object is built for each column.
+    pub(in crate::planner::binder) async fn convert_to_column_grant_objects(
+        &self,
+        source: &AccountMgrLevel,
+        columns: &[Identifier],
+    ) -> Result<Vec<GrantObject>> {
+        let AccountMgrLevel::Table(database_name, table_name) = source else {
+            return Err(ErrorCode::IllegalGrant(
+                "Illegal GRANT/REVOKE command; column privileges can only be used on a table",
+            ));
+        };
+        let catalog_name = self.ctx.get_current_catalog();
+        let database_name = database_name
+            .clone()
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        if self
+            .ctx
+            .is_temp_table(&catalog_name, &database_name, table_name)
+        {
+            return Err(ErrorCode::StorageOther(format!(
+                "{}.{}.{} is a temporary table, cannot grant privileges on it",
+                catalog_name, database_name, table_name
+            )));
+        }
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&catalog_name).await?;
+        let db_id = catalog
+            .get_database(&tenant, &database_name)
+            .await?
+            .get_db_info()
+            .database_id
+            .db_id;
+        let table = catalog
+            .get_table(&tenant, &database_name, table_name)
+            .await?;
+        let schema = table.schema();
+
+        let mut grant_objects = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+            let Ok(field) = schema.field_with_name(&column) else {
+                return Err(ErrorCode::UnknownColumn(format!(
+                    "Column {} does not exist in table {}.{}",
+                    column, database_name, table_name
+                )));
+            };
+            grant_objects.push(GrantObject::Column(
+                catalog_name.clone(),
+                db_id,
+                table.get_id(),
+                field.column_id(),
+            ));
+        }
+        Ok(grant_objects)
+    }
+
     // Some old query version use GrantObject::Table store table name.
     // So revoke need compat the old version.
     pub(in crate::planner::binder) async fn convert_to_revoke_grant_object(
@@ -247,6 +367,12 @@ impl Binder {
             }
             AccountMgrLevel::UDF(udf) => Ok(vec![GrantObject::UDF(udf.clone())]),
             AccountMgrLevel::Stage(stage) => Ok(vec![GrantObject::Stage(stage.clone())]),
+            AccountMgrLevel::Sequence(_)
+            | AccountMgrLevel::Procedure(_)
+            | AccountMgrLevel::Connection(_)
+            | AccountMgrLevel::FileFormat(_) => {
+                Ok(vec![self.convert_to_grant_object(source).await?])
+            }
         }
     }
 
@@ -269,6 +395,7 @@ impl Binder {
         }
         let mut user_option = UserOption::default();
         for option in user_options {
+            verify_user_option(option)?;
             user_option.apply(option);
         }
         UserApiProvider::instance()
@@ -323,6 +450,7 @@ impl Binder {
         // TODO: Only user with OWNERSHIP privilege can change user options.
         let mut user_option = user_info.option.clone();
         for option in user_options {
+            verify_user_option(option)?;
             user_option.apply(option);
         }
 
@@ -446,6 +574,24 @@ impl Binder {
             GrantObjectName::Stage(name) => {
                 format!("SELECT * FROM show_grants('stage', '{}')", name)
             }
+            GrantObjectName::Sequence(sequence) => {
+                format!(
+                    "SELECT * FROM show_grants('sequence', '{}')",
+                    self.normalize_object_identifier(sequence)
+                )
+            }
+            GrantObjectName::Procedure(procedure) => {
+                format!(
+                    "SELECT * FROM show_grants('procedure', '{}')",
+                    ProcedureIdentity::from(procedure.clone())
+                )
+            }
+            GrantObjectName::Connection(name) => {
+                format!("SELECT * FROM show_grants('connection', '{}')", name)
+            }
+            GrantObjectName::FileFormat(name) => {
+                format!("SELECT * FROM show_grants('file_format', '{}')", name)
+            }
         };
 
         let (show_limit, limit_str) = get_show_options(show_option, Some("name".to_string()));
@@ -455,3 +601,11 @@ impl Binder {
             .await
     }
 }
+
+/// Rejects public keys that can not be used to verify key-pair JWTs at login.
+fn verify_user_option(option: &UserOptionItem) -> Result<()> {
+    if let UserOptionItem::SetRsaPublicKey(key) | UserOptionItem::SetRsaPublicKey2(key) = option {
+        UserPublicKey::parse(key)?;
+    }
+    Ok(())
+}
@@ -13,10 +13,20 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
 
+use chrono::Utc;
+use databend_common_ast::ast::AlterDynamicTableAction;
+use databend_common_ast::ast::AlterDynamicTableStmt;
 use databend_common_ast::ast::CreateDynamicTableStmt;
 use databend_common_ast::ast::CreateTableSource;
+use databend_common_ast::ast::ExplainKind;
+use databend_common_ast::ast::RefreshMode;
+use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TypeName;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -24,17 +34,28 @@ use databend_common_expression::infer_schema_type;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::storage::StorageParams;
+use databend_storages_common_table_meta::table::DynamicTableRefreshState;
 use databend_storages_common_table_meta::table::OPT_KEY_AS_QUERY;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_INITIALIZE;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_MODE;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_STATE;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TARGET_LAG;
+use log::info;
 
+use crate::optimizer::SExpr;
 use crate::plans::CreateDynamicTablePlan;
 use crate::plans::Plan;
+use crate::plans::RefreshDynamicTablePlan;
+use crate::plans::RelOperator;
 use crate::BindContext;
 use crate::Binder;
+use crate::DynamicTableQuery;
+use crate::MaterializedViewEntry;
+use crate::MaterializedViewRegistry;
 
 impl Binder {
     pub(in crate::planner::binder) async fn bind_create_dynamic_table(
@@ -60,6 +81,17 @@ impl Binder {
         let (catalog_name, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
+        let dynamic_query = DynamicTableQuery::new(as_query.clone(), &self.name_resolution_ctx)
+            .with_current_database(&self.ctx.get_current_database());
+        if *refresh_mode == RefreshMode::Incremental {
+            if let Err(reason) = dynamic_query.shape() {
+                return Err(ErrorCode::IllegalDynamicTable(format!(
+                    "Dynamic table {database}.{table} can not be refreshed incrementally: {reason}"
+                )));
+            }
+        }
+        let as_query = dynamic_query.query();
+
         let mut options: BTreeMap<String, String> = BTreeMap::new();
         {
             // If table is TRANSIENT, set a flag in table option
@@ -69,6 +101,8 @@ impl Binder {
 
             options.insert(OPT_KEY_AS_QUERY.to_owned(), format!("{as_query}"));
             options.insert(OPT_KEY_TARGET_LAG.to_owned(), format!("{target_lag}"));
+            options.insert(OPT_KEY_REFRESH_MODE.to_owned(), format!("{refresh_mode}"));
+            options.insert(OPT_KEY_INITIALIZE.to_owned(), format!("{initialize}"));
 
             let catalog = self.ctx.get_catalog(&catalog_name).await?;
             let db = catalog
@@ -204,4 +238,182 @@ impl Binder {
         };
         Ok(Plan::CreateDynamicTable(Box::new(plan)))
     }
+
+    pub(in crate::planner::binder) async fn bind_alter_dynamic_table(
+        &mut self,
+        stmt: &AlterDynamicTableStmt,
+    ) -> Result<Plan> {
+        let AlterDynamicTableStmt {
+            catalog,
+            database,
+            table,
+            action,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        match action {
+            AlterDynamicTableAction::Refresh => Ok(Plan::RefreshDynamicTable(Box::new(
+                RefreshDynamicTablePlan {
+                    catalog,
+                    database,
+                    table,
+                },
+            ))),
+        }
+    }
+
+    /// Bind the dynamic tables that may answer the aggregations of the query in place of
+    /// its base tables, they are matched against the query by the optimizer.
+    ///
+    /// A dynamic table is a candidate if it is refreshed and reads only tables of the query
+    /// without a row access policy, and if none of them has changed since the refresh,
+    /// unless the refresh is more recent than `materialized_view_rewrite_max_staleness`.
+    /// The candidates are looked up by the tables of the query in the `MaterializedViewRegistry`.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_query_materialized_views(
+        &mut self,
+        plan: &Plan,
+    ) -> Result<()> {
+        let (s_expr, metadata) = match plan {
+            Plan::Query {
+                s_expr, metadata, ..
+            } => (s_expr, metadata),
+            Plan::Explain { kind, plan, .. } if matches!(kind, ExplainKind::Plan) => {
+                match plan.as_ref() {
+                    Plan::Query {
+                        s_expr, metadata, ..
+                    } => (s_expr, metadata),
+                    _ => return Ok(()),
+                }
+            }
+            _ => return Ok(()),
+        };
+
+        let settings = self.ctx.get_settings();
+        if !settings.get_enable_materialized_view_rewrite()? || !contains_aggregate(s_expr) {
+            return Ok(());
+        }
+        let max_staleness = settings.get_materialized_view_rewrite_max_staleness()?;
+
+        let mut query_tables = HashMap::new();
+        let mut databases = BTreeSet::new();
+        for table_entry in metadata.read().tables() {
+            let table = table_entry.table();
+            if table_entry.is_source_of_index()
+                || table_entry.is_source_of_stage()
+                || table.is_temp()
+                || matches!(table.engine(), "VIEW" | "STREAM")
+            {
+                continue;
+            }
+            // The tables of a view are bound without their row access policies,
+            // so a view over a table with a policy is never a candidate.
+            if table.get_table_info().meta.row_access_policy.is_some() {
+                continue;
+            }
+            query_tables.insert(table.get_id(), table.options().clone());
+            databases.insert((
+                table_entry.catalog().to_string(),
+                table_entry.database().to_string(),
+            ));
+        }
+
+        let base_tables = query_tables.keys().copied().collect::<Vec<_>>();
+        let registry = MaterializedViewRegistry::instance();
+        for (catalog_name, database) in databases {
+            let tables = match registry
+                .views_of(self.ctx.as_ref(), &catalog_name, &database, &base_tables)
+                .await
+            {
+                Ok(tables) => tables,
+                Err(cause) => {
+                    info!("Skip materialized views in {catalog_name}.{database}: {cause}");
+                    continue;
+                }
+            };
+
+            for table in tables {
+                let options = table.options();
+                let (Some(as_query), Some(state)) = (
+                    options.get(OPT_KEY_AS_QUERY),
+                    options.get(OPT_KEY_REFRESH_STATE),
+                ) else {
+                    continue;
+                };
+                let Ok(state) = serde_json::from_str::<DynamicTableRefreshState>(state) else {
+                    continue;
+                };
+                if state.sources.is_empty()
+                    || !state.sources.keys().all(|id| query_tables.contains_key(id))
+                    || !DynamicTableRefreshState::is_at_snapshot(state.snapshot.as_deref(), options)
+                {
+                    continue;
+                }
+                let up_to_date = state.sources.iter().all(|(id, snapshot)| {
+                    DynamicTableRefreshState::is_at_snapshot(snapshot.as_deref(), &query_tables[id])
+                });
+                let within_staleness = state.refreshed_on.is_some_and(|refreshed_on| {
+                    let staleness = (Utc::now() - refreshed_on).num_seconds();
+                    staleness >= 0 && (staleness as u64) < max_staleness
+                });
+                if !up_to_date && !within_staleness {
+                    continue;
+                }
+
+                let tokens = tokenize_sql(as_query)?;
+                let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+                let Statement::Query(query) = &stmt else {
+                    continue;
+                };
+                // The tables of the view are bound as sources of an index,
+                // they are read only if the query is rewritten to the dynamic table.
+                let mut view_bind_context = BindContext::new();
+                view_bind_context.planning_agg_index = true;
+                let (view_s_expr, view_bind_context) =
+                    match self.bind_query(&mut view_bind_context, query) {
+                        Ok(result) => result,
+                        Err(cause) => {
+                            info!(
+                                "Skip materialized view {catalog_name}.{database}.{}: {cause}",
+                                table.name()
+                            );
+                            continue;
+                        }
+                    };
+                let output_columns = view_bind_context
+                    .columns
+                    .iter()
+                    .map(|column| column.index)
+                    .collect::<Vec<_>>();
+                if output_columns.len() != table.schema().num_fields() {
+                    continue;
+                }
+
+                let mut metadata = metadata.write();
+                let table_index = metadata.add_table(
+                    catalog_name.clone(),
+                    database.clone(),
+                    table.clone(),
+                    None,
+                    false,
+                    true,
+                    false,
+                    false,
+                );
+                metadata.add_materialized_view(MaterializedViewEntry {
+                    table_index,
+                    s_expr: view_s_expr,
+                    output_columns,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+fn contains_aggregate(s_expr: &SExpr) -> bool {
+    matches!(s_expr.plan(), RelOperator::Aggregate(_))
+        || s_expr.children().any(|child| contains_aggregate(child))
 }
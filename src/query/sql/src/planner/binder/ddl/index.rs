@@ -26,13 +26,16 @@ use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::RefreshIndexStmt;
 use databend_common_ast::ast::RefreshInvertedIndexStmt;
+use databend_common_ast::ast::RefreshNgramIndexStmt;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableIndexType;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::ColumnId;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableSchemaRef;
@@ -41,6 +44,8 @@ use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::IndexNameIdent;
+use databend_common_meta_app::schema::TableIndexType as MetaTableIndexType;
+use databend_common_vector::DistanceType;
 use databend_storages_common_table_meta::meta::Location;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
@@ -55,6 +60,7 @@ use crate::plans::DropTableIndexPlan;
 use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
 use crate::plans::RefreshTableIndexPlan;
+use crate::plans::RefreshTableNgramIndexPlan;
 use crate::AggregatingIndexChecker;
 use crate::AggregatingIndexRewriter;
 use crate::BindContext;
@@ -88,6 +94,15 @@ static INDEX_RECORD_VALUES: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
     r
 });
 
+// valid keys for vector index options
+static VECTOR_INDEX_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    let mut r = HashSet::new();
+    r.insert("distance");
+    r.insert("lists");
+    r.insert("probes");
+    r
+});
+
 fn is_valid_tokenizer_values<S: AsRef<str>>(opt_val: S) -> bool {
     INDEX_TOKENIZER_VALUES.contains(opt_val.as_ref())
 }
@@ -416,6 +431,7 @@ impl Binder {
         stmt: &CreateInvertedIndexStmt,
     ) -> Result<Plan> {
         let CreateInvertedIndexStmt {
+            index_type,
             create_option,
             index_name,
             catalog,
@@ -430,36 +446,54 @@ impl Binder {
             self.normalize_object_identifier_triple(catalog, database, table);
 
         let table = self.ctx.get_table(&catalog, &database, &table).await?;
+        let index_desc = index_type.to_string().to_lowercase();
 
         if table.is_read_only() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table {} is read-only, creating inverted index not allowed",
-                table.name()
+                "Table {} is read-only, creating {} index not allowed",
+                table.name(),
+                index_desc
             )));
         }
 
         if !table.support_index() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table engine {} does not support create inverted index",
-                table.engine()
+                "Table engine {} does not support create {} index",
+                table.engine(),
+                index_desc
             )));
         }
         if table.is_temp() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table {} is temporary table, creating inverted index not allowed",
-                table.name()
+                "Table {} is temporary table, creating {} index not allowed",
+                table.name(),
+                index_desc
             )));
         }
         let table_schema = table.schema();
         let table_id = table.get_id();
         let index_name = self.normalize_object_identifier(index_name);
-        let column_ids = self
-            .validate_inverted_index_columns(table_schema, columns)
-            .await?;
-        let index_options = self.validate_inverted_index_options(index_options).await?;
+        let index_type = table_index_type(index_type)?;
+        let (column_ids, index_options) = match index_type {
+            MetaTableIndexType::Inverted => {
+                let column_ids = self
+                    .validate_inverted_index_columns(table_schema, columns)
+                    .await?;
+                let index_options = self.validate_inverted_index_options(index_options).await?;
+                (column_ids, index_options)
+            }
+            MetaTableIndexType::Vector => {
+                let column_ids = self
+                    .validate_vector_index_columns(table_schema, columns)
+                    .await?;
+                let index_options = self.validate_vector_index_options(index_options).await?;
+                (column_ids, index_options)
+            }
+        };
 
         let plan = CreateTableIndexPlan {
             create_option: create_option.clone().into(),
+            index_type,
             catalog,
             index_name,
             column_ids,
@@ -558,6 +592,68 @@ impl Binder {
         Ok(options)
     }
 
+    pub(in crate::planner::binder) async fn validate_vector_index_columns(
+        &self,
+        table_schema: TableSchemaRef,
+        columns: &[Identifier],
+    ) -> Result<Vec<ColumnId>> {
+        if columns.len() != 1 {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index must be created on exactly one column, but got {}",
+                columns.len()
+            )));
+        }
+        let column = &columns[0];
+        let field = table_schema.field_with_name(&column.name).map_err(|_| {
+            ErrorCode::UnsupportedIndex(format!("Table does not have column {}", column))
+        })?;
+        let is_vector_type = match field.data_type.remove_nullable() {
+            TableDataType::Array(inner) => {
+                inner.as_ref() == &TableDataType::Number(NumberDataType::Float32)
+            }
+            _ => false,
+        };
+        if !is_vector_type {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index currently only support Array(Float32) type, but the type of column {} is {}",
+                column, field.data_type
+            )));
+        }
+        Ok(vec![field.column_id])
+    }
+
+    pub(in crate::planner::binder) async fn validate_vector_index_options(
+        &self,
+        index_options: &BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, String>> {
+        let mut options = BTreeMap::new();
+        for (opt, val) in index_options.iter() {
+            let key = opt.to_lowercase();
+            let value = val.to_lowercase();
+            if !VECTOR_INDEX_OPTION_KEYS.contains(key.as_str()) {
+                return Err(ErrorCode::IndexOptionInvalid(format!(
+                    "index option `{key}` is invalid key for create vector index statement",
+                )));
+            }
+            if key == "distance" {
+                if DistanceType::from_option(&value).is_none() {
+                    return Err(ErrorCode::IndexOptionInvalid(format!(
+                        "value `{value}` is invalid index distance, must be `cosine` or `l2`",
+                    )));
+                }
+            } else if value.parse::<u32>().map_or(true, |v| v == 0) {
+                return Err(ErrorCode::IndexOptionInvalid(format!(
+                    "value `{value}` is invalid index {key}, must be a positive integer",
+                )));
+            }
+            options.insert(key, value);
+        }
+        options
+            .entry("distance".to_string())
+            .or_insert_with(|| DistanceType::Cosine.as_option().to_string());
+        Ok(options)
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_inverted_index(
         &mut self,
@@ -565,6 +661,7 @@ impl Binder {
         stmt: &DropInvertedIndexStmt,
     ) -> Result<Plan> {
         let DropInvertedIndexStmt {
+            index_type,
             if_exists,
             index_name,
             catalog,
@@ -578,14 +675,27 @@ impl Binder {
         let table = self.ctx.get_table(&catalog, &database, &table).await?;
         if !table.support_index() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table engine {} does not support create inverted index",
-                table.engine()
+                "Table engine {} does not support create {} index",
+                table.engine(),
+                index_type.to_string().to_lowercase()
             )));
         }
         let table_id = table.get_id();
         let index_name = self.normalize_object_identifier(index_name);
+        let index_type = table_index_type(index_type)?;
+        if let Some(index) = table.get_table_info().meta.indexes.get(&index_name) {
+            if index.index_type != index_type {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "Index {} is a {} index, not a {} index",
+                    index_name,
+                    index.index_type.to_string().to_lowercase(),
+                    index_type.to_string().to_lowercase()
+                )));
+            }
+        }
 
         let plan = DropTableIndexPlan {
+            index_type,
             if_exists: *if_exists,
             catalog,
             index_name,
@@ -601,6 +711,7 @@ impl Binder {
         stmt: &RefreshInvertedIndexStmt,
     ) -> Result<Plan> {
         let RefreshInvertedIndexStmt {
+            index_type,
             index_name,
             catalog,
             database,
@@ -611,8 +722,10 @@ impl Binder {
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
         let index_name = self.normalize_object_identifier(index_name);
+        let index_type = table_index_type(index_type)?;
 
         let plan = RefreshTableIndexPlan {
+            index_type,
             catalog,
             database,
             table,
@@ -621,4 +734,39 @@ impl Binder {
         };
         Ok(Plan::RefreshTableIndex(Box::new(plan)))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_ngram_index(
+        &mut self,
+        _bind_context: &mut BindContext,
+        stmt: &RefreshNgramIndexStmt,
+    ) -> Result<Plan> {
+        let RefreshNgramIndexStmt {
+            catalog,
+            database,
+            table,
+            limit,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        let plan = RefreshTableNgramIndexPlan {
+            catalog,
+            database,
+            table,
+            limit: *limit,
+        };
+        Ok(Plan::RefreshTableNgramIndex(Box::new(plan)))
+    }
+}
+
+fn table_index_type(index_type: &TableIndexType) -> Result<MetaTableIndexType> {
+    match index_type {
+        TableIndexType::Inverted => Ok(MetaTableIndexType::Inverted),
+        TableIndexType::Vector => Ok(MetaTableIndexType::Vector),
+        TableIndexType::Aggregating => Err(ErrorCode::UnsupportedIndex(
+            "Aggregating index is not a table index",
+        )),
+    }
 }
@@ -25,7 +25,9 @@ mod network_policy;
 mod notification;
 mod password_policy;
 mod procedure;
+mod query_hint;
 mod role;
+mod row_access_policy;
 mod sequence;
 mod stage;
 mod stream;
@@ -52,6 +52,7 @@ impl Binder {
         let ExecuteImmediateStmt { script } = stmt;
         Ok(Plan::ExecuteImmediate(Box::new(ExecuteImmediatePlan {
             script: script.clone(),
+            procedure_id: None,
         })))
     }
 
@@ -151,9 +152,11 @@ impl Binder {
         if arg_types.is_empty() {
             Ok(Plan::ExecuteImmediate(Box::new(ExecuteImmediatePlan {
                 script: procedure.procedure_meta.script,
+                procedure_id: Some(procedure.id),
             })))
         } else {
             Ok(Plan::CallProcedure(Box::new(CallProcedurePlan {
+                procedure_id: procedure.id,
                 script: procedure.procedure_meta.script,
                 arg_names: procedure.procedure_meta.arg_names,
                 args: arguments.clone(),
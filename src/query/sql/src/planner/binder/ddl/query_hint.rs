@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_ast::ast::*;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_settings::Settings;
+
+use crate::binder::Binder;
+use crate::parse_query_fingerprint;
+use crate::plans::CreateQueryHintPlan;
+use crate::plans::DropQueryHintPlan;
+use crate::plans::Plan;
+use crate::plans::ShowQueryHintsPlan;
+use crate::QUERY_HINT_JOIN_DISTRIBUTION_BROADCAST;
+use crate::QUERY_HINT_JOIN_DISTRIBUTION_SHUFFLE;
+use crate::QUERY_HINT_JOIN_ORDER_FIXED;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_query_hint(
+        &mut self,
+        stmt: &CreateQueryHintStmt,
+    ) -> Result<Plan> {
+        let CreateQueryHintStmt {
+            create_option,
+            join_order,
+            join_distribution,
+            settings,
+            comment,
+            query,
+        } = stmt;
+
+        let join_order = join_order.as_ref().map(|v| v.to_uppercase());
+        if let Some(join_order) = &join_order {
+            if join_order != QUERY_HINT_JOIN_ORDER_FIXED {
+                return Err(ErrorCode::SemanticError(format!(
+                    "invalid query hint JOIN_ORDER '{}', expecting '{}'",
+                    join_order, QUERY_HINT_JOIN_ORDER_FIXED
+                )));
+            }
+        }
+
+        let join_distribution = join_distribution.as_ref().map(|v| v.to_uppercase());
+        if let Some(join_distribution) = &join_distribution {
+            if join_distribution != QUERY_HINT_JOIN_DISTRIBUTION_BROADCAST
+                && join_distribution != QUERY_HINT_JOIN_DISTRIBUTION_SHUFFLE
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "invalid query hint JOIN_DISTRIBUTION '{}', expecting '{}' or '{}'",
+                    join_distribution,
+                    QUERY_HINT_JOIN_DISTRIBUTION_BROADCAST,
+                    QUERY_HINT_JOIN_DISTRIBUTION_SHUFFLE
+                )));
+            }
+        }
+
+        let tenant = self.ctx.get_tenant();
+
+        // Check the setting names and values up front, so that a broken query hint
+        // can not fail the queries it is applied to.
+        let check_settings = Settings::create(tenant.clone());
+        for (k, v) in settings {
+            check_settings.set_setting(k.clone(), v.clone())?;
+        }
+
+        let fingerprint = parse_query_fingerprint(query, self.dialect)?;
+
+        let plan = CreateQueryHintPlan {
+            create_option: create_option.clone().into(),
+            tenant,
+            fingerprint,
+            query: query.clone(),
+            join_order,
+            join_distribution,
+            settings: settings.clone(),
+            comment: comment.clone(),
+        };
+        Ok(Plan::CreateQueryHint(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_query_hint(
+        &mut self,
+        stmt: &DropQueryHintStmt,
+    ) -> Result<Plan> {
+        let DropQueryHintStmt { if_exists, query } = stmt;
+
+        let plan = DropQueryHintPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            fingerprint: parse_query_fingerprint(query, self.dialect)?,
+        };
+        Ok(Plan::DropQueryHint(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_query_hints(&mut self) -> Result<Plan> {
+        let plan = ShowQueryHintsPlan {};
+        Ok(Plan::ShowQueryHints(Box::new(plan)))
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::*;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::plans::BoundColumnRef;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::Plan;
+use crate::resolve_type_name;
+use crate::BindContext;
+use crate::ColumnBindingBuilder;
+use crate::ScalarExpr;
+use crate::TypeChecker;
+use crate::Visibility;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_row_access_policy(
+        &mut self,
+        stmt: &CreateRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateRowAccessPolicyStmt {
+            create_option,
+            name,
+            args,
+            body,
+            comment,
+        } = stmt;
+
+        // check if the body is a boolean expression over the args
+        let column_not_null = !self.ctx.get_settings().get_ddl_column_type_nullable()?;
+        let mut plan_args = Vec::with_capacity(args.len());
+        let mut aliases = Vec::with_capacity(args.len());
+        for (i, arg) in args.iter().enumerate() {
+            let arg_name = normalize_identifier(&arg.arg_name, &self.name_resolution_ctx).name;
+            let data_type = DataType::from(&resolve_type_name(&arg.arg_type, column_not_null)?);
+            let column = ColumnBindingBuilder::new(
+                arg_name.clone(),
+                i,
+                Box::new(data_type),
+                Visibility::Visible,
+            )
+            .build();
+            aliases.push((
+                arg_name.clone(),
+                ScalarExpr::BoundColumnRef(BoundColumnRef { span: None, column }),
+            ));
+            plan_args.push((arg_name, arg.arg_type.to_string()));
+        }
+        let mut bind_context = BindContext::new();
+        let mut type_checker = TypeChecker::try_create(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &aliases,
+            false,
+        )?;
+        let box (_, data_type) = type_checker.resolve(body)?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "Row access policy {} must return BOOLEAN, but got {}",
+                name, data_type
+            ))
+            .set_span(body.span()));
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreateRowAccessPolicyPlan {
+            create_option: create_option.clone().into(),
+            tenant,
+            name: name.to_string(),
+            args: plan_args,
+            body: body.clone(),
+            comment: comment.clone(),
+        };
+        Ok(Plan::CreateRowAccessPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_row_access_policy(
+        &mut self,
+        stmt: &DropRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DropRowAccessPolicyStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropRowAccessPolicyPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropRowAccessPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_row_access_policy(
+        &mut self,
+        stmt: &DescRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DescRowAccessPolicyStmt { name } = stmt;
+
+        let plan = DescRowAccessPolicyPlan {
+            name: name.to_string(),
+        };
+        Ok(Plan::DescRowAccessPolicy(Box::new(plan)))
+    }
+}
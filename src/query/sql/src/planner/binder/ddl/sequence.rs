@@ -12,14 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::AlterSequenceAction;
+use databend_common_ast::ast::AlterSequenceStmt;
 use databend_common_ast::ast::CreateSequenceStmt;
+use databend_common_ast::ast::DescSequenceStmt;
 use databend_common_ast::ast::DropSequenceStmt;
+use databend_common_ast::ast::ShowOptions;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::schema;
+use databend_common_meta_app::schema::is_auto_increment_sequence;
 use databend_common_meta_app::schema::SequenceIdent;
 
+use crate::binder::show::get_show_options;
+use crate::plans::AlterSequencePlan;
 use crate::plans::CreateSequencePlan;
+use crate::plans::DescSequencePlan;
 use crate::plans::DropSequencePlan;
 use crate::plans::Plan;
+use crate::plans::RewriteKind;
+use crate::BindContext;
 use crate::Binder;
 
 impl Binder {
@@ -31,15 +43,22 @@ impl Binder {
         let CreateSequenceStmt {
             create_option,
             sequence,
+            start,
+            increment,
+            cache,
             comment,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
         let sequence = self.normalize_object_identifier(sequence);
+        check_user_sequence(&sequence)?;
 
         let plan = CreateSequencePlan {
             create_option: create_option.clone().into(),
             ident: SequenceIdent::new(tenant, sequence),
+            start: start.unwrap_or(1),
+            step: check_sequence_increment(increment.unwrap_or(1))?,
+            cache: cache.unwrap_or(0),
             comment: comment.clone(),
         };
         Ok(Plan::CreateSequence(plan.into()))
@@ -57,6 +76,7 @@ impl Binder {
 
         let tenant = self.ctx.get_tenant();
         let sequence = self.normalize_object_identifier(sequence);
+        check_user_sequence(&sequence)?;
 
         let plan = DropSequencePlan {
             ident: SequenceIdent::new(tenant, sequence),
@@ -64,4 +84,88 @@ impl Binder {
         };
         Ok(Plan::DropSequence(plan.into()))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_sequence(
+        &mut self,
+        stmt: &AlterSequenceStmt,
+    ) -> Result<Plan> {
+        let AlterSequenceStmt {
+            if_exists,
+            sequence,
+            action,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let sequence = self.normalize_object_identifier(sequence);
+        check_user_sequence(&sequence)?;
+
+        let action = match action {
+            AlterSequenceAction::Restart { start } => schema::AlterSequenceAction::Restart(*start),
+            AlterSequenceAction::SetIncrement(increment) => {
+                schema::AlterSequenceAction::SetIncrement(check_sequence_increment(*increment)?)
+            }
+            AlterSequenceAction::SetCache(cache) => schema::AlterSequenceAction::SetCache(*cache),
+        };
+        let plan = AlterSequencePlan {
+            ident: SequenceIdent::new(tenant, sequence),
+            if_exists: *if_exists,
+            action,
+        };
+        Ok(Plan::AlterSequence(plan.into()))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_sequences(
+        &mut self,
+        bind_context: &mut BindContext,
+        show_options: &Option<ShowOptions>,
+    ) -> Result<Plan> {
+        let (show_limit, limit_str) = get_show_options(show_options, None);
+        let query = format!(
+            "SELECT name, start, increment, next_value, cache, comment, created_on, updated_on FROM system.sequences {} ORDER BY name {}",
+            show_limit, limit_str,
+        );
+
+        self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowSequences)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_sequence(
+        &mut self,
+        stmt: &DescSequenceStmt,
+    ) -> Result<Plan> {
+        let DescSequenceStmt { sequence } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let sequence = self.normalize_object_identifier(sequence);
+        check_user_sequence(&sequence)?;
+
+        let plan = DescSequencePlan {
+            ident: SequenceIdent::new(tenant, sequence),
+        };
+        Ok(Plan::DescSequence(plan.into()))
+    }
+}
+
+// The hidden sequences of `AUTOINCREMENT` columns are created and dropped with the table.
+fn check_user_sequence(sequence: &str) -> Result<()> {
+    if is_auto_increment_sequence(sequence) {
+        return Err(ErrorCode::SemanticError(format!(
+            "sequence `{sequence}` is reserved for AUTOINCREMENT columns"
+        )));
+    }
+    Ok(())
+}
+
+// Sequence values are unsigned, only ascending sequences are supported.
+fn check_sequence_increment(increment: u64) -> Result<i64> {
+    match i64::try_from(increment) {
+        Ok(step) if step > 0 => Ok(step),
+        _ => Err(ErrorCode::SemanticError(format!(
+            "sequence INCREMENT must be between 1 and {}, but got {increment}",
+            i64::MAX
+        ))),
+    }
 }
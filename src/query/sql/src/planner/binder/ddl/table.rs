@@ -32,6 +32,7 @@ use databend_common_ast::ast::DescribeTableStmt;
 use databend_common_ast::ast::DropTableStmt;
 use databend_common_ast::ast::Engine;
 use databend_common_ast::ast::ExistsTableStmt;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::InvertedIndexDefinition;
 use databend_common_ast::ast::ModifyColumnAction;
@@ -61,6 +62,7 @@ use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::plan::Filters;
 use databend_common_catalog::table::CompactionLimits;
+use databend_common_catalog::table::Table;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -68,9 +70,12 @@ use databend_common_expression::infer_schema_type;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::type_check::check_function;
 use databend_common_expression::types::DataType;
+use databend_common_expression::AutoIncrementExpr;
 use databend_common_expression::ComputedExpr;
+use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
@@ -79,6 +84,8 @@ use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::TableIndexType;
+use databend_common_meta_app::schema::AUTO_INCREMENT_SEQUENCE_PREFIX;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
 use databend_common_storages_view::view_table::QUERY;
@@ -87,6 +94,7 @@ use databend_storages_common_table_meta::table::is_reserved_opt_key;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
+use databend_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
@@ -99,6 +107,7 @@ use opendal::Operator;
 use crate::binder::get_storage_params_from_options;
 use crate::binder::parse_storage_params_from_uri;
 use crate::binder::scalar::ScalarBinder;
+use crate::binder::wrap_cast;
 use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
@@ -106,18 +115,22 @@ use crate::executor::cast_expr_to_non_null_boolean;
 use crate::optimizer::SExpr;
 use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
+use crate::parse_exprs;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
@@ -426,6 +439,7 @@ impl Binder {
             table,
             source,
             table_options,
+            partition_by,
             cluster_by,
             as_query,
             table_type,
@@ -684,6 +698,20 @@ impl Binder {
             }
         }
 
+        let mut partition_key = None;
+        if let Some(partition_expr) = partition_by {
+            if engine != Engine::Fuse {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Table engine {} does not support PARTITION BY",
+                    engine
+                )));
+            }
+            partition_key = Some(
+                self.analyze_partition_key(partition_expr, schema.clone())
+                    .await?,
+            );
+        }
+
         let plan = CreateTablePlan {
             create_option: create_option.clone().into(),
             tenant: self.ctx.get_tenant(),
@@ -698,6 +726,7 @@ impl Binder {
             options,
             field_comments,
             cluster_key,
+            partition_key,
             as_select: if let Some(query) = as_query {
                 let mut bind_context = BindContext::new();
                 let stmt = Statement::Query(Box::new(*query.clone()));
@@ -777,6 +806,7 @@ impl Binder {
             options,
             field_comments: vec![],
             cluster_key: None,
+            partition_key: None,
             as_select: None,
             inverted_indexes: None,
         })))
@@ -1016,6 +1046,58 @@ impl Binder {
                     table,
                 },
             ))),
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let columns = columns
+                    .iter()
+                    .map(|column| self.normalize_object_identifier(column))
+                    .collect();
+                Ok(Plan::AddTableRowAccessPolicy(Box::new(
+                    AddTableRowAccessPolicyPlan {
+                        tenant,
+                        catalog,
+                        database,
+                        table,
+                        policy: policy.to_string(),
+                        columns,
+                    },
+                )))
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                }),
+            )),
+            AlterTableAction::DropPartition {
+                if_exists,
+                partition,
+            } => {
+                let tbl = self.ctx.get_table(&catalog, &database, &table).await?;
+                let partition_value = self.bind_partition_value(tbl, partition)?;
+                Ok(Plan::DropTablePartition(Box::new(DropTablePartitionPlan {
+                    catalog,
+                    database,
+                    table,
+                    if_exists: *if_exists,
+                    partition_value,
+                })))
+            }
+            // Truncating a partition removes its segments as DROP PARTITION does,
+            // an empty partition is not an error.
+            AlterTableAction::TruncatePartition { partition } => {
+                let tbl = self.ctx.get_table(&catalog, &database, &table).await?;
+                let partition_value = self.bind_partition_value(tbl, partition)?;
+                Ok(Plan::DropTablePartition(Box::new(DropTablePartitionPlan {
+                    catalog,
+                    database,
+                    table,
+                    if_exists: true,
+                    partition_value,
+                })))
+            }
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
@@ -1425,6 +1507,12 @@ impl Binder {
                         "can't add a stored computed column".to_string(),
                     ));
                 }
+                ColumnExpr::AutoIncrement { .. } => {
+                    // existing rows have no values allocated from the sequence.
+                    return Err(ErrorCode::SemanticError(
+                        "can't add an AUTOINCREMENT column".to_string(),
+                    ));
+                }
             }
         }
         let comment = column.comment.clone().unwrap_or_default();
@@ -1453,6 +1541,11 @@ impl Binder {
                             parse_default_expr_to_string(self.ctx.clone(), &field, default_expr)?;
                         field = field.with_default_expr(Some(expr));
                     }
+                    ColumnExpr::AutoIncrement { start, step } => {
+                        let auto_increment_expr =
+                            Self::analyze_auto_increment_expr(&field, *start, *step)?;
+                        field = field.with_auto_increment_expr(Some(auto_increment_expr));
+                    }
                     _ => has_computed = true,
                 }
             }
@@ -1531,6 +1624,7 @@ impl Binder {
                 .await?;
 
             let inverted_index = TableIndex {
+                index_type: TableIndexType::Inverted,
                 name: name.clone(),
                 column_ids,
                 sync_creation: inverted_index_def.sync_creation,
@@ -1585,12 +1679,50 @@ impl Binder {
                         ))
                     }
                 } else {
-                    Ok((table.schema(), table.field_comments().clone(), None))
+                    // the new table must not share the sequences of identity columns.
+                    let mut schema = table.schema().as_ref().clone();
+                    for field in schema.fields.iter_mut() {
+                        if let Some(expr) = field.auto_increment_expr.as_mut() {
+                            expr.sequence_name = Self::new_auto_increment_sequence_name();
+                        }
+                    }
+                    Ok((Arc::new(schema), table.field_comments().clone(), None))
                 }
             }
         }
     }
 
+    /// Identity columns allocate values from a hidden sequence owned by the column.
+    fn analyze_auto_increment_expr(
+        field: &TableField,
+        start: i64,
+        step: i64,
+    ) -> Result<AutoIncrementExpr> {
+        match field.data_type().remove_nullable() {
+            TableDataType::Number(num_ty) if num_ty.is_integer() => {}
+            data_type => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "AUTOINCREMENT column `{}` must be an integer type, but got {}",
+                    field.name(),
+                    data_type
+                )));
+            }
+        }
+        Ok(AutoIncrementExpr {
+            sequence_name: Self::new_auto_increment_sequence_name(),
+            start,
+            step,
+        })
+    }
+
+    fn new_auto_increment_sequence_name() -> String {
+        format!(
+            "{}{}",
+            AUTO_INCREMENT_SEQUENCE_PREFIX,
+            Uuid::new_v4().simple()
+        )
+    }
+
     /// Validate the schema of the table to be created.
     pub(in crate::planner::binder) fn validate_create_table_schema(
         schema: &TableSchemaRef,
@@ -1714,6 +1846,124 @@ impl Binder {
         Ok(cluster_keys)
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn analyze_partition_key(
+        &mut self,
+        partition_expr: &Expr,
+        schema: TableSchemaRef,
+    ) -> Result<String> {
+        // Build a temporary BindContext to resolve the expr
+        let mut bind_context = BindContext::new();
+        for (index, field) in schema.fields().iter().enumerate() {
+            let column = ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(DataType::from(field.data_type())),
+                Visibility::Visible,
+            )
+            .build();
+
+            bind_context.add_column_binding(column);
+        }
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        // partition key cannot be a udf expression.
+        scalar_binder.forbid_udf();
+
+        let (partition_key, _) = scalar_binder.bind(partition_expr)?;
+        if partition_key.used_columns().is_empty() || !partition_key.evaluable() {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Partition by expression `{:#}` is invalid",
+                partition_expr
+            )));
+        }
+
+        let expr = partition_key.as_expr()?;
+        if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Partition by expression `{:#}` is not deterministic",
+                partition_expr
+            )));
+        }
+
+        let data_type = expr.data_type();
+        if !Self::valid_cluster_key_type(data_type) {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Unsupported data type '{}' for partition by expression `{:#}`",
+                data_type, partition_expr
+            )));
+        }
+
+        let mut partition_expr = partition_expr.clone();
+        let mut normalizer = IdentifierNormalizer {
+            ctx: &self.name_resolution_ctx,
+        };
+        partition_expr.drive_mut(&mut normalizer);
+        Ok(format!("{:#}", &partition_expr))
+    }
+
+    /// Binds the value of a partition of the table, the value must be a constant
+    /// expression and is cast to the type of the partition key.
+    pub(in crate::planner::binder) fn bind_partition_value(
+        &mut self,
+        table: Arc<dyn Table>,
+        partition: &Expr,
+    ) -> Result<Scalar> {
+        let Some(partition_key) = table.options().get(OPT_KEY_PARTITION_BY) else {
+            return Err(ErrorCode::UnpartitionedTable(format!(
+                "Table '{}' is not partitioned",
+                table.name()
+            )));
+        };
+        let key_type = parse_exprs(self.ctx.clone(), table.clone(), partition_key)?
+            .pop()
+            .ok_or_else(|| {
+                ErrorCode::InvalidPartitionKey(format!(
+                    "Invalid partition by expression `{}`",
+                    partition_key
+                ))
+            })?
+            .data_type()
+            .clone();
+
+        let mut bind_context = BindContext::new();
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        scalar_binder.forbid_udf();
+        let (value, _) = scalar_binder.bind(partition)?;
+        if !value.used_columns().is_empty() || !value.evaluable() {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Partition value `{:#}` must be a constant expression",
+                partition
+            )));
+        }
+
+        let expr = wrap_cast(&value, &key_type).as_expr()?;
+        let (expr, _) =
+            ConstantFolder::fold(&expr, &self.ctx.get_function_context()?, &BUILTIN_FUNCTIONS);
+        match expr {
+            databend_common_expression::Expr::Constant { scalar, .. } => Ok(scalar),
+            _ => Err(ErrorCode::InvalidPartitionKey(format!(
+                "Partition value `{:#}` must be a constant expression",
+                partition
+            ))),
+        }
+    }
+
     fn valid_cluster_key_type(data_type: &DataType) -> bool {
         let inner_type = data_type.remove_nullable();
         matches!(
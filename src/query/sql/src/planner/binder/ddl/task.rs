@@ -120,6 +120,7 @@ impl Binder {
             after,
             when_condition,
             error_integration,
+            success_integration,
             sql,
             session_parameters,
         } = stmt;
@@ -147,6 +148,7 @@ impl Binder {
             comment: comments.clone(),
             session_parameters: session_parameters.clone(),
             error_integration: error_integration.clone(),
+            success_integration: success_integration.clone(),
             sql: sql.clone(),
         };
         Ok(Plan::CreateTask(Box::new(plan)))
@@ -170,6 +172,7 @@ impl Binder {
             comments,
             session_parameters,
             error_integration,
+            success_integration,
         } = options
         {
             if warehouse.is_none()
@@ -178,6 +181,7 @@ impl Binder {
                 && comments.is_none()
                 && session_parameters.is_none()
                 && error_integration.is_none()
+                && success_integration.is_none()
             {
                 return Err(ErrorCode::SyntaxException(
                     "alter task must set at least one option".to_string(),
@@ -28,6 +28,8 @@ pub struct ExplainConfig {
     pub verbose: bool,
     pub logical: bool,
     pub optimized: bool,
+    /// Fingerprint of the query hint applied to the explained statement.
+    pub query_hint: Option<String>,
 }
 
 struct ExplainConfigBuilder {
@@ -63,6 +65,7 @@ impl ExplainConfigBuilder {
             verbose: self.verbose,
             logical: self.logical,
             optimized: self.optimized,
+            query_hint: None,
         }
     }
 }
@@ -129,6 +129,12 @@ impl BindContext {
             if let AExpr::ColumnRef { column, .. } = expr {
                 if column.column.name().eq_ignore_ascii_case("default") {
                     let field = schema.field(i);
+                    if field.auto_increment_expr().is_some() {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "can't use DEFAULT for AUTOINCREMENT column `{}`, omit it from the insert columns instead",
+                            field.name()
+                        )));
+                    }
                     map_exprs.push(scalar_binder.get_default_value(field, schema).await?);
                     continue;
                 }
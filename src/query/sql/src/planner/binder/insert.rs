@@ -81,6 +81,7 @@ impl Binder {
             columns,
             source,
             overwrite,
+            partition,
             ..
         } = stmt;
 
@@ -101,6 +102,16 @@ impl Binder {
 
         let schema = self.schema_project(&table.schema(), columns)?;
 
+        let overwrite_partition = match partition {
+            Some(_) if !*overwrite => {
+                return Err(ErrorCode::SyntaxException(
+                    "PARTITION can only be specified with INSERT OVERWRITE",
+                ));
+            }
+            Some(partition) => Some(self.bind_partition_value(table.clone(), partition)?),
+            None => None,
+        };
+
         let input_source: Result<InsertInputSource> = match source.clone() {
             InsertSource::Values { rows } => {
                 let mut new_rows = Vec::with_capacity(rows.len());
@@ -123,6 +134,9 @@ impl Binder {
             InsertSource::RawValues { rest_str, start } => {
                 let values_str = rest_str.trim_end_matches(';').trim_start().to_owned();
                 match self.ctx.get_stage_attachment() {
+                    Some(_) if overwrite_partition.is_some() => Err(ErrorCode::Unimplemented(
+                        "INSERT OVERWRITE ... PARTITION with a stage attachment is not supported",
+                    )),
                     Some(attachment) => {
                         return self
                             .bind_copy_from_attachment(
@@ -158,6 +172,7 @@ impl Binder {
             table: table_name,
             schema,
             overwrite: *overwrite,
+            overwrite_partition,
             source: input_source?,
             table_info: None,
         };
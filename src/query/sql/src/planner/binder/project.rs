@@ -34,6 +34,8 @@ use databend_common_expression::Column;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::Scalar;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserPrivilegeType;
 use derive_visitor::DriveMut;
 use derive_visitor::VisitorMut;
 use itertools::Itertools;
@@ -338,6 +340,77 @@ impl Binder {
         })
     }
 
+    /// Get the columns of the table that `SELECT *` is allowed to be expanded to. Returns `None` if
+    /// the current user has the SELECT privilege on the whole table, or has not been granted SELECT
+    /// on any of its columns, in both cases the privilege check after binding decides the access.
+    fn permitted_star_columns(&self, table_index: IndexType) -> Result<Option<HashSet<String>>> {
+        let table_entry = self.metadata.read().table(table_index).clone();
+        if table_entry.is_source_of_view()
+            || table_entry.is_source_of_index()
+            || table_entry.is_source_of_stage()
+            || table_entry.table().is_temp()
+            || table_entry.database().eq_ignore_ascii_case("system")
+            || table_entry
+                .database()
+                .eq_ignore_ascii_case("information_schema")
+        {
+            return Ok(None);
+        }
+
+        let ctx = self.ctx.clone();
+        databend_common_base::runtime::block_on(async move {
+            let catalog_name = table_entry.catalog().to_string();
+            let table_object = GrantObject::Table(
+                catalog_name.clone(),
+                table_entry.database().to_string(),
+                table_entry.name().to_string(),
+            );
+            if ctx
+                .validate_privilege(&table_object, UserPrivilegeType::Select, false)
+                .await
+                .is_ok()
+            {
+                return Ok(None);
+            }
+
+            let catalog = ctx.get_catalog(&catalog_name).await?;
+            let Ok(database) = catalog
+                .get_database(&ctx.get_tenant(), table_entry.database())
+                .await
+            else {
+                return Ok(None);
+            };
+            let db_id = database.get_db_info().database_id.db_id;
+            let table = table_entry.table();
+            let table_object = GrantObject::TableById(catalog_name.clone(), db_id, table.get_id());
+            if ctx
+                .validate_privilege(&table_object, UserPrivilegeType::Select, false)
+                .await
+                .is_ok()
+            {
+                return Ok(None);
+            }
+
+            let mut permitted = HashSet::new();
+            for field in table.schema().fields() {
+                let column_object = GrantObject::Column(
+                    catalog_name.clone(),
+                    db_id,
+                    table.get_id(),
+                    field.column_id(),
+                );
+                if ctx
+                    .validate_privilege(&column_object, UserPrivilegeType::Select, false)
+                    .await
+                    .is_ok()
+                {
+                    permitted.insert(field.name().clone());
+                }
+            }
+            Ok((!permitted.is_empty()).then_some(permitted))
+        })
+    }
+
     fn resolve_star_columns<'a>(
         &self,
         span: Span,
@@ -388,6 +461,12 @@ impl Binder {
 
         let mut adds = 0;
 
+        let expand_to_permitted_columns = self
+            .ctx
+            .get_settings()
+            .get_enable_expand_star_to_permitted_columns()?;
+        let mut permitted_columns: HashMap<IndexType, Option<HashSet<String>>> = HashMap::new();
+
         for column_binding in input_context.all_column_bindings() {
             if column_binding.visibility != Visibility::Visible {
                 continue;
@@ -418,6 +497,20 @@ impl Binder {
                 continue;
             }
 
+            if let Some(table_index) = column_binding.table_index
+                && expand_to_permitted_columns
+            {
+                if !permitted_columns.contains_key(&table_index) {
+                    let permitted = self.permitted_star_columns(table_index)?;
+                    permitted_columns.insert(table_index, permitted);
+                }
+                if let Some(Some(permitted)) = permitted_columns.get(&table_index)
+                    && !permitted.contains(&column_binding.column_name)
+                {
+                    continue;
+                }
+            }
+
             // TODO: yangxiufeng refactor it with InVisible
             if star
                 && column_binding.column_name.starts_with("_$")
@@ -28,6 +28,8 @@ use databend_common_ast::ast::SetOperator;
 use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::TemporalClause;
 use databend_common_ast::ast::TimeTravelPoint;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::Span;
 use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use databend_common_catalog::table::NavigationPoint;
@@ -45,14 +47,20 @@ use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataField;
 use databend_common_expression::FunctionContext;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::ListIndexesReq;
+use databend_common_meta_app::schema::TableRowAccessPolicy;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::MetaId;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_data_mask_feature::get_row_access_policy_handler;
 use databend_storages_common_table_meta::table::ChangeType;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_ID;
 use log::info;
 use parking_lot::RwLock;
 
@@ -64,9 +72,13 @@ use crate::binder::Visibility;
 use crate::optimizer::SExpr;
 use crate::optimizer::StatInfo;
 use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::resolve_type_name_by_str;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
 use crate::plans::CteScan;
 use crate::plans::DummyTableScan;
+use crate::plans::Filter;
 use crate::plans::RecursiveCteScan;
 use crate::plans::RelOperator;
 use crate::plans::Scan;
@@ -75,6 +87,8 @@ use crate::BaseTableColumn;
 use crate::BindContext;
 use crate::ColumnEntry;
 use crate::IndexType;
+use crate::ScalarExpr;
+use crate::TableEntry;
 
 impl Binder {
     pub fn bind_dummy_table(
@@ -480,22 +494,144 @@ impl Binder {
             }
         }
 
-        Ok((
-            SExpr::create_leaf(Arc::new(
-                Scan {
-                    table_index,
-                    columns: columns.into_iter().map(|col| col.index()).collect(),
-                    statistics: Arc::new(Statistics::default()),
-                    change_type,
-                    sample: sample.clone(),
-                    ..Default::default()
+        let s_expr = SExpr::create_leaf(Arc::new(
+            Scan {
+                table_index,
+                columns: columns.into_iter().map(|col| col.index()).collect(),
+                statistics: Arc::new(Statistics::default()),
+                change_type,
+                sample: sample.clone(),
+                ..Default::default()
+            }
+            .into(),
+        ));
+        let s_expr = self.bind_row_access_policy(&bind_context, &table, s_expr)?;
+
+        Ok((s_expr, bind_context))
+    }
+
+    /// Filter the scan of a table with the predicate of its row access policy,
+    /// a stream is filtered with the policy of its source table.
+    fn bind_row_access_policy(
+        &self,
+        bind_context: &BindContext,
+        table: &TableEntry,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        if bind_context.planning_agg_index {
+            return Ok(s_expr);
+        }
+        let Some(row_access_policy) = self.resolve_row_access_policy(table)? else {
+            return Ok(s_expr);
+        };
+
+        LicenseManagerSwitch::instance()
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::RowAccessPolicy)?;
+
+        let tenant = self.ctx.get_tenant();
+        let policy = databend_common_base::runtime::block_on(async {
+            let meta_api = UserApiProvider::instance().get_meta_store_client();
+            get_row_access_policy_handler()
+                .get_row_access_policy(meta_api, &tenant, row_access_policy.policy.clone())
+                .await
+        })?;
+        if policy.args.len() != row_access_policy.columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Row access policy {} expects {} arguments, but table {} binds {} columns",
+                row_access_policy.policy,
+                policy.args.len(),
+                table.name(),
+                row_access_policy.columns.len()
+            )));
+        }
+
+        let column_not_null = !self.ctx.get_settings().get_ddl_column_type_nullable()?;
+        let mut aliases = Vec::with_capacity(policy.args.len());
+        for ((arg_name, arg_type), column_name) in
+            policy.args.iter().zip(row_access_policy.columns.iter())
+        {
+            let column = bind_context
+                .columns
+                .iter()
+                .find(|c| c.table_index == Some(table.index()) && &c.column_name == column_name)
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "Column {} of row access policy {} not found in table {}",
+                        column_name,
+                        row_access_policy.policy,
+                        table.name()
+                    ))
+                })?;
+            let mut scalar = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: column.clone(),
+            });
+            let arg_type = DataType::from(&resolve_type_name_by_str(arg_type, column_not_null)?);
+            if column.data_type.remove_nullable() != arg_type.remove_nullable() {
+                let target_type = if column.data_type.is_nullable() {
+                    arg_type.wrap_nullable()
+                } else {
+                    arg_type.remove_nullable()
+                };
+                scalar = ScalarExpr::CastExpr(CastExpr {
+                    span: None,
+                    is_try: false,
+                    argument: Box::new(scalar),
+                    target_type: Box::new(target_type),
+                });
+            }
+            aliases.push((arg_name.clone(), scalar));
+        }
+
+        let tokens = tokenize_sql(&policy.body)?;
+        let ast_expr = parse_expr(&tokens, self.dialect)?;
+        let mut policy_context = BindContext::new();
+        let mut type_checker = TypeChecker::try_create(
+            &mut policy_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &aliases,
+            false,
+        )?;
+        let box (predicate, data_type) = type_checker.resolve(&ast_expr)?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "Row access policy {} must return BOOLEAN, but got {}",
+                row_access_policy.policy, data_type
+            )));
+        }
+
+        Ok(SExpr::create_unary(
+            Arc::new(
+                Filter {
+                    predicates: vec![predicate],
                 }
                 .into(),
-            )),
-            bind_context,
+            ),
+            Arc::new(s_expr),
         ))
     }
 
+    fn resolve_row_access_policy(
+        &self,
+        table: &TableEntry,
+    ) -> Result<Option<TableRowAccessPolicy>> {
+        let table_meta = table.table();
+        let source_table_id = table_meta
+            .options()
+            .get(OPT_KEY_SOURCE_TABLE_ID)
+            .and_then(|id| id.parse::<u64>().ok());
+        let Some(source_table_id) = source_table_id.filter(|_| table_meta.is_stream()) else {
+            return Ok(table_meta.get_table_info().meta.row_access_policy.clone());
+        };
+        databend_common_base::runtime::block_on(async move {
+            let catalog = self.ctx.get_catalog(table.catalog()).await?;
+            let source_meta = catalog.get_table_meta_by_id(source_table_id).await?;
+            Ok(source_meta.and_then(|meta| meta.data.row_access_policy.clone()))
+        })
+    }
+
     pub fn resolve_data_source(
         &self,
         catalog_name: &str,
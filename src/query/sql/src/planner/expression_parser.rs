@@ -29,8 +29,10 @@ use databend_common_expression::type_check::check_function;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
+use databend_common_expression::ComputedExpr;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Evaluator;
 use databend_common_expression::Expr;
@@ -337,6 +339,51 @@ pub fn parse_computed_expr_to_string(
     Ok(format!("{:#}", ast))
 }
 
+/// Parses the `ttl` table option, which computes the expiration time of each row.
+/// The column refs of the returned expression are the field indexes of the table schema.
+pub fn parse_ttl_expr(
+    ctx: Arc<dyn TableContext>,
+    table_schema: TableSchemaRef,
+    sql: &str,
+) -> Result<Expr> {
+    let schema = Arc::new(DataSchema::from(table_schema.clone()));
+    let expr = parse_computed_expr(ctx, schema, sql)?;
+
+    if !matches!(
+        expr.data_type().remove_nullable(),
+        DataType::Timestamp | DataType::Date
+    ) {
+        return Err(ErrorCode::SemanticError(format!(
+            "ttl expression `{}` should return TIMESTAMP or DATE, but got {}",
+            sql,
+            expr.data_type()
+        )));
+    }
+    if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        return Err(ErrorCode::SemanticError(format!(
+            "ttl expression `{}` is not deterministic",
+            sql
+        )));
+    }
+    if expr.column_refs().is_empty() {
+        return Err(ErrorCode::SemanticError(format!(
+            "ttl expression `{}` should refer to at least one column",
+            sql
+        )));
+    }
+    for index in expr.column_refs().keys() {
+        let field = table_schema.field(*index);
+        if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+            return Err(ErrorCode::SemanticError(format!(
+                "ttl expression `{}` can't refer to virtual computed column {}",
+                sql,
+                field.name()
+            )));
+        }
+    }
+    Ok(expr)
+}
+
 pub fn parse_lambda_expr(
     ctx: Arc<dyn TableContext>,
     mut bind_context: BindContext,
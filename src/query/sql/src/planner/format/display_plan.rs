@@ -71,8 +71,11 @@ impl Plan {
             Plan::DropTableColumn(_) => Ok("DropTableColumn".to_string()),
             Plan::AlterTableClusterKey(_) => Ok("AlterTableClusterKey".to_string()),
             Plan::DropTableClusterKey(_) => Ok("DropTableClusterKey".to_string()),
+            Plan::AddTableRowAccessPolicy(_) => Ok("AddTableRowAccessPolicy".to_string()),
+            Plan::DropTableRowAccessPolicy(_) => Ok("DropTableRowAccessPolicy".to_string()),
             Plan::ReclusterTable { .. } => Ok("ReclusterTable".to_string()),
             Plan::TruncateTable(_) => Ok("TruncateTable".to_string()),
+            Plan::DropTablePartition(_) => Ok("DropTablePartition".to_string()),
             Plan::OptimizePurge(_) => Ok("OptimizePurge".to_string()),
             Plan::OptimizeCompactSegment(_) => Ok("OptimizeCompactSegment".to_string()),
             Plan::OptimizeCompactBlock { .. } => Ok("OptimizeCompactBlock".to_string()),
@@ -94,6 +97,7 @@ impl Plan {
 
             // Dynamic Tables
             Plan::CreateDynamicTable(_) => Ok("CreateDynamicTable".to_string()),
+            Plan::RefreshDynamicTable(_) => Ok("RefreshDynamicTable".to_string()),
 
             // Indexes
             Plan::CreateIndex(_) => Ok("CreateIndex".to_string()),
@@ -102,6 +106,7 @@ impl Plan {
             Plan::CreateTableIndex(_) => Ok("CreateTableIndex".to_string()),
             Plan::DropTableIndex(_) => Ok("DropTableIndex".to_string()),
             Plan::RefreshTableIndex(_) => Ok("RefreshTableIndex".to_string()),
+            Plan::RefreshTableNgramIndex(_) => Ok("RefreshTableNgramIndex".to_string()),
 
             // Virtual Columns
             Plan::CreateVirtualColumn(_) => Ok("CreateVirtualColumn".to_string()),
@@ -156,6 +161,11 @@ impl Plan {
             Plan::DropDatamaskPolicy(_) => Ok("DropDatamaskPolicy".to_string()),
             Plan::DescDatamaskPolicy(_) => Ok("DescDatamaskPolicy".to_string()),
 
+            // row access policy
+            Plan::CreateRowAccessPolicy(_) => Ok("CreateRowAccessPolicy".to_string()),
+            Plan::DropRowAccessPolicy(_) => Ok("DropRowAccessPolicy".to_string()),
+            Plan::DescRowAccessPolicy(_) => Ok("DescRowAccessPolicy".to_string()),
+
             // network policy
             Plan::CreateNetworkPolicy(_) => Ok("CreateNetworkPolicy".to_string()),
             Plan::AlterNetworkPolicy(_) => Ok("AlterNetworkPolicy".to_string()),
@@ -169,6 +179,11 @@ impl Plan {
             Plan::DropPasswordPolicy(_) => Ok("DropPasswordPolicy".to_string()),
             Plan::DescPasswordPolicy(_) => Ok("DescPasswordPolicy".to_string()),
 
+            // query hint
+            Plan::CreateQueryHint(_) => Ok("CreateQueryHint".to_string()),
+            Plan::DropQueryHint(_) => Ok("DropQueryHint".to_string()),
+            Plan::ShowQueryHints(_) => Ok("ShowQueryHints".to_string()),
+
             // task
             Plan::CreateTask(_) => Ok("CreateTask".to_string()),
             Plan::DropTask(_) => Ok("DropTask".to_string()),
@@ -203,6 +218,8 @@ impl Plan {
             // sequence
             Plan::CreateSequence(_) => Ok("CreateSequence".to_string()),
             Plan::DropSequence(_) => Ok("DropSequence".to_string()),
+            Plan::AlterSequence(_) => Ok("AlterSequence".to_string()),
+            Plan::DescSequence(_) => Ok("DescSequence".to_string()),
 
             Plan::SetPriority(_) => Ok("SetPriority".to_string()),
             Plan::System(_) => Ok("System".to_string()),
@@ -0,0 +1,155 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TableExt;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_meta_app::tenant::Tenant;
+use databend_storages_common_table_meta::table::DynamicTableRefreshState;
+use databend_storages_common_table_meta::table::OPT_KEY_AS_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_STATE;
+use log::info;
+use parking_lot::Mutex;
+
+/// How long the dynamic tables of a database are cached before they are listed again,
+/// to find the dynamic tables created or refreshed on other nodes.
+const REGISTRY_TTL: Duration = Duration::from_secs(60);
+
+static MATERIALIZED_VIEW_REGISTRY: LazyLock<MaterializedViewRegistry> =
+    LazyLock::new(MaterializedViewRegistry::default);
+
+/// (tenant, catalog, database)
+type DatabaseKey = (String, String, String);
+
+struct DatabaseViews {
+    loaded_at: Instant,
+    /// The refreshed dynamic tables of the database, by the ids of the tables they read.
+    by_base_table: HashMap<u64, Vec<Arc<dyn Table>>>,
+}
+
+/// The dynamic tables that may answer queries on their base tables, by base table.
+///
+/// A database is listed once per `REGISTRY_TTL`, not once per query. The dynamic tables
+/// created, dropped or refreshed on this node invalidate their database at once.
+#[derive(Default)]
+pub struct MaterializedViewRegistry {
+    databases: Mutex<HashMap<DatabaseKey, Arc<DatabaseViews>>>,
+}
+
+impl MaterializedViewRegistry {
+    pub fn instance() -> &'static MaterializedViewRegistry {
+        LazyLock::force(&MATERIALIZED_VIEW_REGISTRY)
+    }
+
+    /// Forget the dynamic tables of a database, after one of them is created, dropped or refreshed.
+    pub fn invalidate(&self, tenant: &Tenant, catalog: &str, database: &str) {
+        let key = (
+            tenant.tenant_name().to_string(),
+            catalog.to_string(),
+            database.to_string(),
+        );
+        self.databases.lock().remove(&key);
+    }
+
+    /// The dynamic tables of a database that read any of `base_tables`, with their latest meta.
+    #[async_backtrace::framed]
+    pub async fn views_of(
+        &self,
+        ctx: &dyn TableContext,
+        catalog: &str,
+        database: &str,
+        base_tables: &[u64],
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        let tenant = ctx.get_tenant();
+        let key = (
+            tenant.tenant_name().to_string(),
+            catalog.to_string(),
+            database.to_string(),
+        );
+        let cached = self
+            .databases
+            .lock()
+            .get(&key)
+            .filter(|views| views.loaded_at.elapsed() < REGISTRY_TTL)
+            .cloned();
+        let views = match cached {
+            Some(views) => views,
+            None => {
+                let views = Arc::new(Self::load(ctx, catalog, database).await?);
+                self.databases.lock().insert(key, views.clone());
+                views
+            }
+        };
+
+        let mut tables = Vec::new();
+        let mut seen = Vec::new();
+        for view in base_tables
+            .iter()
+            .filter_map(|id| views.by_base_table.get(id))
+            .flatten()
+        {
+            if seen.contains(&view.get_id()) {
+                continue;
+            }
+            seen.push(view.get_id());
+            // The cached meta is stale once the view is refreshed, reload it by id.
+            match view.refresh(ctx).await {
+                Ok(table) if table.get_table_info().meta.drop_on.is_none() => tables.push(table),
+                Ok(_) => {}
+                Err(cause) => {
+                    info!("Skip materialized view {}: {cause}", view.name());
+                }
+            }
+        }
+        Ok(tables)
+    }
+
+    async fn load(ctx: &dyn TableContext, catalog: &str, database: &str) -> Result<DatabaseViews> {
+        let mut by_base_table: HashMap<u64, Vec<Arc<dyn Table>>> = HashMap::new();
+        let tables = ctx
+            .get_catalog(catalog)
+            .await?
+            .list_tables(&ctx.get_tenant(), database)
+            .await?;
+        for table in tables {
+            let options = table.options();
+            let (Some(_), Some(state)) = (
+                options.get(OPT_KEY_AS_QUERY),
+                options.get(OPT_KEY_REFRESH_STATE),
+            ) else {
+                continue;
+            };
+            let Ok(state) = serde_json::from_str::<DynamicTableRefreshState>(state) else {
+                continue;
+            };
+            for base_table in state.sources.keys() {
+                by_base_table
+                    .entry(*base_table)
+                    .or_default()
+                    .push(table.clone());
+            }
+        }
+        Ok(DatabaseViews {
+            loaded_at: Instant::now(),
+            by_base_table,
+        })
+    }
+}
@@ -52,6 +52,17 @@ pub type ColumnSet = HashSet<IndexType>;
 /// Callers can clone this ref safely and cheaply.
 pub type MetadataRef = Arc<RwLock<Metadata>>;
 
+/// A dynamic table that may answer the aggregations of a query in place of its base tables.
+#[derive(Clone, Debug)]
+pub struct MaterializedViewEntry {
+    /// The index of the dynamic table, which is bound as a table of the query.
+    pub table_index: IndexType,
+    /// The query of the dynamic table, bound with the same metadata as the query.
+    pub s_expr: SExpr,
+    /// The output columns of `s_expr`, in the order of the columns of the dynamic table.
+    pub output_columns: Vec<IndexType>,
+}
+
 /// Metadata stores information about columns and tables used in a query.
 /// Tables and columns are identified with its unique index.
 /// Notice that index value of a column can be same with that of a table.
@@ -73,6 +84,7 @@ pub struct Metadata {
     /// Mappings from table index to _row_id column index.
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    materialized_views: Vec<MaterializedViewEntry>,
     max_column_position: usize, // for CSV
 }
 
@@ -329,6 +341,14 @@ impl Metadata {
         !self.agg_indexes.is_empty()
     }
 
+    pub fn add_materialized_view(&mut self, materialized_view: MaterializedViewEntry) {
+        self.materialized_views.push(materialized_view);
+    }
+
+    pub fn materialized_views(&self) -> &[MaterializedViewEntry] {
+        &self.materialized_views
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn add_table(
         &mut self,
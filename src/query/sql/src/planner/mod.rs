@@ -18,13 +18,16 @@ mod metadata;
 #[allow(clippy::module_inception)]
 mod planner;
 mod semantic;
+mod statistics_column_groups;
 
 pub mod binder;
 pub mod dataframe;
 mod expression_parser;
+mod materialized_view_registry;
 pub mod optimizer;
 mod planner_cache;
 pub mod plans;
+mod query_hint;
 mod stream_column;
 mod udf_validator;
 
@@ -39,6 +42,7 @@ pub use binder::Visibility;
 pub use bloom_index::BloomIndexColumns;
 pub use expression_parser::*;
 pub use format::format_scalar;
+pub use materialized_view_registry::MaterializedViewRegistry;
 pub use metadata::*;
 pub use planner::get_query_kind;
 pub use planner::PlanExtras;
@@ -48,5 +52,7 @@ pub use plans::ScalarExpr;
 pub use plans::DELETE_NAME;
 pub use plans::INSERT_NAME;
 pub use plans::UPDATE_NAME;
+pub use query_hint::*;
 pub use semantic::*;
+pub use statistics_column_groups::StatisticsColumnGroups;
 pub use stream_column::*;
@@ -0,0 +1,837 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::agg_index::format_scalar;
+use crate::optimizer::agg_index::range_predicates;
+use crate::optimizer::agg_index::EquivalenceClasses;
+use crate::optimizer::agg_index::PredicatesSplitter;
+use crate::optimizer::agg_index::RangeClasses;
+use crate::optimizer::agg_index::ResidualClasses;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::plans::Statistics;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::MaterializedViewEntry;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::Visibility;
+
+const STATE_SUFFIX: &str = "_state";
+const MERGE_SUFFIX: &str = "_merge";
+
+/// Rewrite aggregations to read the materialized views bound by the binder instead of
+/// their base tables, see `Binder::bind_query_materialized_views`.
+///
+/// Like the aggregating index rewriting, the matching is based on "Optimizing Queries Using
+/// Materialized Views: A Practical, Scalable Solution" by Goldstein and Larson: a view answers
+/// an aggregation if it joins the same tables, its predicates are implied by the predicates of
+/// the aggregation, and the group keys and the remaining predicates of the aggregation can be
+/// computed from the group keys of the view. If the aggregation groups by fewer keys than the
+/// view, the view is aggregated again, which requires the aggregate functions to be `sum`,
+/// `count`, `min`, `max` or to be stored as states by `<func>_state`.
+pub struct MaterializedViewRewriteOptimizer {
+    metadata: MetadataRef,
+}
+
+impl MaterializedViewRewriteOptimizer {
+    pub fn new(metadata: MetadataRef) -> Self {
+        MaterializedViewRewriteOptimizer { metadata }
+    }
+
+    #[recursive::recursive]
+    pub fn run(&self, s_expr: &SExpr) -> Result<SExpr> {
+        if let RelOperator::Aggregate(aggregate) = s_expr.plan() {
+            if let Some(result) = self.try_rewrite(aggregate, s_expr)? {
+                return Ok(result);
+            }
+        }
+
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            let child = self.run(child)?;
+            children.push(Arc::new(child));
+        }
+        Ok(s_expr.replace_children(children))
+    }
+
+    fn try_rewrite(&self, aggregate: &Aggregate, s_expr: &SExpr) -> Result<Option<SExpr>> {
+        if aggregate.mode != AggregateMode::Initial || aggregate.grouping_sets.is_some() {
+            return Ok(None);
+        }
+
+        let (query, views) = {
+            let metadata = self.metadata.read();
+            let Some(query) = SpjInfo::new(&metadata, s_expr.child(0)?) else {
+                return Ok(None);
+            };
+            let views = metadata
+                .materialized_views()
+                .iter()
+                .filter_map(|view| ViewInfo::new(&metadata, view))
+                .collect::<Vec<_>>();
+            (query, views)
+        };
+        if !query.range_classes.is_satisfiable() {
+            return Ok(None);
+        }
+
+        for view in views.iter() {
+            if view.spj.table_ids != query.table_ids {
+                continue;
+            }
+            if let Some(result) = self.try_rewrite_view(aggregate, &query, view)? {
+                return Ok(Some(result));
+            }
+        }
+        Ok(None)
+    }
+
+    fn try_rewrite_view(
+        &self,
+        aggregate: &Aggregate,
+        query: &SpjInfo,
+        view: &ViewInfo,
+    ) -> Result<Option<SExpr>> {
+        // The rows of the view must be a superset of the rows of the query.
+        if !query.equi_classes.check(&view.spj.equi_classes) {
+            return Ok(None);
+        }
+        let (matched, extra_ranges) = query.range_classes.check(&view.spj.range_classes);
+        if !matched {
+            return Ok(None);
+        }
+        let (matched, extra_residuals) = query.residual_classes.check(&view.spj.residual_classes);
+        if !matched {
+            return Ok(None);
+        }
+
+        // The compensating predicates which filter the rows of the view to the rows of the query.
+        let mut predicates = Vec::new();
+        for (left, right) in query.equi_columns_preds.iter() {
+            let left = ScalarExpr::BoundColumnRef(left.clone());
+            let right = ScalarExpr::BoundColumnRef(right.clone());
+            if view.is_equivalent(&display(&left), &display(&right)) {
+                continue;
+            }
+            let (Some(left), Some(right)) = (view.rewrite(&left), view.rewrite(&right)) else {
+                return Ok(None);
+            };
+            predicates.push(function_call("eq", vec![left, right]));
+        }
+        for (column, (lower_bound, upper_bound)) in extra_ranges.iter().flatten() {
+            let Some(position) = view.find_key(column) else {
+                return Ok(None);
+            };
+            predicates.extend(range_predicates(
+                &view.columns[position],
+                lower_bound,
+                upper_bound,
+            ));
+        }
+        for residual in extra_residuals.iter().flatten() {
+            let Some(residual) = view.rewrite(residual) else {
+                return Ok(None);
+            };
+            predicates.push(residual);
+        }
+
+        // The group keys of the query, computed from the group keys of the view.
+        let mut group_items = Vec::with_capacity(aggregate.group_items.len());
+        let mut group_positions = BTreeSet::new();
+        let mut exact_group = view.group_positions.is_some();
+        for item in aggregate.group_items.iter() {
+            let Some(scalar) = query.canonicalize(&item.scalar) else {
+                return Ok(None);
+            };
+            match view.find_key(&display(&scalar)) {
+                Some(position) => {
+                    group_positions.insert(position);
+                }
+                None => exact_group = false,
+            }
+            let Some(rewritten) = view.rewrite(&scalar) else {
+                return Ok(None);
+            };
+            group_items.push((item.index, rewritten, item.scalar.data_type()?));
+        }
+        exact_group &= view.group_positions.as_ref() == Some(&group_positions);
+
+        let mut aggregate_functions = Vec::with_capacity(aggregate.aggregate_functions.len());
+        for item in aggregate.aggregate_functions.iter() {
+            let Some(ScalarExpr::AggregateFunction(function)) = query.canonicalize(&item.scalar)
+            else {
+                return Ok(None);
+            };
+            aggregate_functions.push((item.index, function));
+        }
+
+        let exact = exact_group
+            && aggregate_functions
+                .iter()
+                .all(|(_, function)| view.aggregates.contains_key(&aggregate_display(function)));
+
+        let s_expr = if exact {
+            let mut items = Vec::with_capacity(group_items.len() + aggregate_functions.len());
+            for (index, scalar, data_type) in group_items {
+                items.push(ScalarItem {
+                    index,
+                    scalar: cast_to(scalar, &data_type)?,
+                });
+            }
+            for (index, function) in aggregate_functions.iter() {
+                let position = view.aggregates[&aggregate_display(function)];
+                items.push(ScalarItem {
+                    index: *index,
+                    scalar: cast_to(view.columns[position].clone(), &function.return_type)?,
+                });
+            }
+            let input = view.scan(&predicates, &items);
+            SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(input))
+        } else {
+            match self.rollup(view, &predicates, group_items, &aggregate_functions)? {
+                Some(s_expr) => s_expr,
+                None => return Ok(None),
+            }
+        };
+        Ok(Some(s_expr))
+    }
+
+    /// Aggregate the view again by the group keys of the query.
+    fn rollup(
+        &self,
+        view: &ViewInfo,
+        predicates: &[ScalarExpr],
+        group_items: Vec<(IndexType, ScalarExpr, DataType)>,
+        aggregate_functions: &[(IndexType, AggregateFunction)],
+    ) -> Result<Option<SExpr>> {
+        let factory = AggregateFunctionFactory::instance();
+        let mut metadata = self.metadata.write();
+
+        let mut input_items = Vec::new();
+        let mut new_group_items = Vec::with_capacity(group_items.len());
+        for (index, scalar, data_type) in group_items {
+            new_group_items.push(ScalarItem {
+                index,
+                scalar: column_ref(index, metadata.column(index).name(), data_type.clone()),
+            });
+            input_items.push(ScalarItem {
+                index,
+                scalar: cast_to(scalar, &data_type)?,
+            });
+        }
+
+        let mut new_aggregate_functions = Vec::with_capacity(aggregate_functions.len());
+        let mut output_items = Vec::with_capacity(aggregate_functions.len());
+        for (index, function) in aggregate_functions {
+            let state_key = if function.distinct {
+                None
+            } else {
+                view.states.get(&aggregate_display(function))
+            };
+            let (func_name, params, position) = match (
+                function.func_name.as_str(),
+                view.aggregates.get(&aggregate_display(function)),
+                state_key,
+            ) {
+                ("sum" | "min" | "max" | "count", Some(position), _) if !function.distinct => {
+                    let func_name = match function.func_name.as_str() {
+                        "count" => "sum",
+                        func_name => func_name,
+                    };
+                    (func_name.to_string(), vec![], *position)
+                }
+                ("min" | "max", Some(position), _) => {
+                    (function.func_name.clone(), vec![], *position)
+                }
+                (_, _, Some(position)) => (
+                    format!("{}{MERGE_SUFFIX}", function.func_name),
+                    function.params.clone(),
+                    *position,
+                ),
+                _ => return Ok(None),
+            };
+
+            let state = view.columns[position].clone();
+            let mut args = vec![state.clone()];
+            if func_name.ends_with(MERGE_SUFFIX) {
+                // The arguments of the state only tell the types the state is built for.
+                for (i, arg) in function.args.iter().enumerate() {
+                    let data_type = arg.data_type()?;
+                    let arg_name = format!("{func_name}_arg_{i}");
+                    let arg_index =
+                        metadata.add_derived_column(arg_name.clone(), data_type.clone(), None);
+                    let value = ScalarExpr::ConstantExpr(ConstantExpr {
+                        span: None,
+                        value: Scalar::default_value(&data_type),
+                    });
+                    input_items.push(ScalarItem {
+                        index: arg_index,
+                        scalar: cast_to(value, &data_type)?,
+                    });
+                    args.push(column_ref(arg_index, arg_name, data_type));
+                }
+            }
+
+            let arg_types = args
+                .iter()
+                .map(|arg| arg.data_type())
+                .collect::<Result<Vec<_>>>()?;
+            let Ok(return_type) = factory
+                .get(&func_name, params.clone(), arg_types)
+                .and_then(|function| function.return_type())
+            else {
+                return Ok(None);
+            };
+
+            let display_name = format!("{}({})", func_name, display(&state));
+            let new_index =
+                metadata.add_derived_column(display_name.clone(), return_type.clone(), None);
+            new_aggregate_functions.push(ScalarItem {
+                index: new_index,
+                scalar: ScalarExpr::AggregateFunction(AggregateFunction {
+                    span: None,
+                    func_name,
+                    distinct: false,
+                    params,
+                    args,
+                    return_type: Box::new(return_type.clone()),
+                    display_name: display_name.clone(),
+                }),
+            });
+
+            let mut result = column_ref(new_index, display_name, return_type);
+            if function.func_name == "count" && new_group_items.is_empty() {
+                // The sum of no rows is NULL, but the count of no rows is 0.
+                result = function_call("if", vec![
+                    function_call("is_not_null", vec![result.clone()]),
+                    result,
+                    ScalarExpr::ConstantExpr(ConstantExpr {
+                        span: None,
+                        value: Scalar::Number(NumberScalar::UInt64(0)),
+                    }),
+                ]);
+            }
+            output_items.push(ScalarItem {
+                index: *index,
+                scalar: cast_to(result, &function.return_type)?,
+            });
+        }
+        drop(metadata);
+
+        let mut used_items = input_items.clone();
+        used_items.extend(new_aggregate_functions.iter().cloned());
+        let mut s_expr = view.scan(predicates, &used_items);
+        if !input_items.is_empty() {
+            s_expr = SExpr::create_unary(
+                Arc::new(EvalScalar { items: input_items }.into()),
+                Arc::new(s_expr),
+            );
+        }
+        let aggregate = Aggregate {
+            mode: AggregateMode::Initial,
+            group_items: new_group_items,
+            aggregate_functions: new_aggregate_functions,
+            from_distinct: false,
+            rank_limit: None,
+            grouping_sets: None,
+        };
+        s_expr = SExpr::create_unary(Arc::new(aggregate.into()), Arc::new(s_expr));
+        Ok(Some(SExpr::create_unary(
+            Arc::new(
+                EvalScalar {
+                    items: output_items,
+                }
+                .into(),
+            ),
+            Arc::new(s_expr),
+        )))
+    }
+}
+
+/// The select-project-join block below an aggregation. Its columns are replaced by the
+/// columns of the base tables, which are named after the tables, so that the blocks of
+/// the query and of a view can be compared.
+struct SpjInfo {
+    table_ids: BTreeSet<u64>,
+    base_columns: HashMap<IndexType, ScalarExpr>,
+    derived_columns: HashMap<IndexType, ScalarExpr>,
+    equi_columns_preds: Vec<(BoundColumnRef, BoundColumnRef)>,
+    equi_classes: EquivalenceClasses,
+    range_classes: RangeClasses,
+    residual_classes: ResidualClasses,
+}
+
+impl SpjInfo {
+    fn new(metadata: &Metadata, s_expr: &SExpr) -> Option<SpjInfo> {
+        let mut info = SpjInfo {
+            table_ids: BTreeSet::new(),
+            base_columns: HashMap::new(),
+            derived_columns: HashMap::new(),
+            equi_columns_preds: vec![],
+            equi_classes: EquivalenceClasses::new(),
+            range_classes: RangeClasses::new(),
+            residual_classes: ResidualClasses::new(),
+        };
+
+        let mut predicates = Vec::new();
+        let mut table_indexes = Vec::new();
+        info.collect(s_expr, &mut predicates, &mut table_indexes)?;
+
+        for table_index in table_indexes {
+            let table_entry = metadata.table(table_index);
+            if !info.table_ids.insert(table_entry.table().get_id()) {
+                // Self joins can't be matched by the names of the columns.
+                return None;
+            }
+            let table_name = format!(
+                "{}.{}.{}",
+                table_entry.catalog(),
+                table_entry.database(),
+                table_entry.name()
+            );
+            for column in metadata.columns_by_table_index(table_index) {
+                if let ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    column_index,
+                    column_name,
+                    data_type,
+                    path_indices: None,
+                    ..
+                }) = column
+                {
+                    let column_binding = ColumnBindingBuilder::new(
+                        column_name,
+                        column_index,
+                        Box::new(DataType::from(&data_type)),
+                        Visibility::Visible,
+                    )
+                    .table_name(Some(table_name.clone()))
+                    .table_index(Some(table_index))
+                    .build();
+                    info.base_columns.insert(
+                        column_index,
+                        ScalarExpr::BoundColumnRef(BoundColumnRef {
+                            span: None,
+                            column: column_binding,
+                        }),
+                    );
+                }
+            }
+        }
+
+        // The predicates only reference the base columns after canonicalizing,
+        // so they are split without a column map.
+        let column_map = HashMap::new();
+        let mut splitter = PredicatesSplitter::new();
+        for predicate in predicates.iter() {
+            splitter.split(&info.canonicalize(predicate)?, &column_map);
+        }
+        for (left, right) in splitter.equi_columns_preds.iter() {
+            info.equi_classes.add_equivalence_class(left, right);
+        }
+        for (func_name, column, value) in splitter.range_preds.iter() {
+            info.range_classes.add_range_class(func_name, column, value);
+        }
+        for residual in splitter.residual_preds.iter() {
+            info.residual_classes
+                .add_residual_pred(display(residual), residual);
+        }
+        info.equi_columns_preds = splitter.equi_columns_preds;
+        Some(info)
+    }
+
+    fn collect(
+        &mut self,
+        s_expr: &SExpr,
+        predicates: &mut Vec<ScalarExpr>,
+        table_indexes: &mut Vec<IndexType>,
+    ) -> Option<()> {
+        match s_expr.plan() {
+            RelOperator::EvalScalar(eval_scalar) => {
+                for item in eval_scalar.items.iter() {
+                    self.derived_columns.insert(item.index, item.scalar.clone());
+                }
+            }
+            RelOperator::Filter(filter) => {
+                predicates.extend(filter.predicates.iter().cloned());
+            }
+            RelOperator::Join(join) => {
+                if !matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+                    || join.marker_index.is_some()
+                    || join.from_correlated_subquery
+                    || join.is_lateral
+                {
+                    return None;
+                }
+                for condition in join.equi_conditions.iter() {
+                    if condition.is_null_equal {
+                        return None;
+                    }
+                    predicates.push(function_call("eq", vec![
+                        condition.left.clone(),
+                        condition.right.clone(),
+                    ]));
+                }
+                predicates.extend(join.non_equi_conditions.iter().cloned());
+            }
+            RelOperator::Scan(scan) => {
+                if scan.prewhere.is_some()
+                    || scan.push_down_predicates.is_some()
+                    || scan.limit.is_some()
+                    || scan.agg_index.is_some()
+                    || scan.change_type.is_some()
+                    || scan.inverted_index.is_some()
+                    || scan.vector_index.is_some()
+                    || scan.sample.is_some()
+                {
+                    return None;
+                }
+                table_indexes.push(scan.table_index);
+                return Some(());
+            }
+            _ => return None,
+        }
+        for child in s_expr.children() {
+            self.collect(child, predicates, table_indexes)?;
+        }
+        Some(())
+    }
+
+    /// Replace the columns of `scalar` with the columns of the base tables, returns `None`
+    /// if `scalar` can't be compared with the scalars of other blocks.
+    fn canonicalize(&self, scalar: &ScalarExpr) -> Option<ScalarExpr> {
+        match scalar {
+            ScalarExpr::BoundColumnRef(column) => {
+                let index = column.column.index;
+                if let Some(base_column) = self.base_columns.get(&index) {
+                    return Some(base_column.clone());
+                }
+                match self.derived_columns.get(&index) {
+                    Some(ScalarExpr::BoundColumnRef(derived)) if derived.column.index == index => {
+                        None
+                    }
+                    Some(derived) => self.canonicalize(derived),
+                    None => None,
+                }
+            }
+            ScalarExpr::ConstantExpr(_) => Some(scalar.clone()),
+            ScalarExpr::FunctionCall(func) => Some(ScalarExpr::FunctionCall(FunctionCall {
+                arguments: func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.canonicalize(arg))
+                    .collect::<Option<Vec<_>>>()?,
+                ..func.clone()
+            })),
+            ScalarExpr::CastExpr(cast) => Some(ScalarExpr::CastExpr(CastExpr {
+                argument: Box::new(self.canonicalize(&cast.argument)?),
+                ..cast.clone()
+            })),
+            ScalarExpr::AggregateFunction(agg) => {
+                Some(ScalarExpr::AggregateFunction(AggregateFunction {
+                    args: agg
+                        .args
+                        .iter()
+                        .map(|arg| self.canonicalize(arg))
+                        .collect::<Option<Vec<_>>>()?,
+                    ..agg.clone()
+                }))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A materialized view whose outputs are the columns of its dynamic table.
+struct ViewInfo {
+    table_index: IndexType,
+    spj: SpjInfo,
+    /// The columns of the dynamic table.
+    columns: Vec<ScalarExpr>,
+    /// Mappings from the group keys of the view to the positions of their columns.
+    keys: HashMap<String, usize>,
+    /// Mappings from the aggregate functions of the view to the positions of their columns.
+    aggregates: HashMap<String, usize>,
+    /// Mappings from `<func>(args...)` to the position of the column of `<func>_state(args...)`.
+    states: HashMap<String, usize>,
+    /// The positions of the group keys of the view, `None` if some of them are not outputs.
+    group_positions: Option<BTreeSet<usize>>,
+}
+
+impl ViewInfo {
+    fn new(metadata: &Metadata, view: &MaterializedViewEntry) -> Option<ViewInfo> {
+        let mut projections = HashMap::new();
+        let mut s_expr = &view.s_expr;
+        let aggregate = loop {
+            match s_expr.plan() {
+                RelOperator::EvalScalar(eval_scalar) => {
+                    for item in eval_scalar.items.iter() {
+                        projections.insert(item.index, item.scalar.clone());
+                    }
+                }
+                RelOperator::Sort(_) => {}
+                RelOperator::Aggregate(aggregate) if aggregate.grouping_sets.is_none() => {
+                    break aggregate;
+                }
+                _ => return None,
+            }
+            s_expr = s_expr.child(0).ok()?;
+        };
+        let spj = SpjInfo::new(metadata, s_expr.child(0).ok()?)?;
+
+        let table_entry = metadata.table(view.table_index);
+        let columns = metadata
+            .columns_by_table_index(view.table_index)
+            .into_iter()
+            .filter_map(|column| match column {
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    column_index,
+                    column_name,
+                    data_type,
+                    path_indices: None,
+                    ..
+                }) => {
+                    let column_binding = ColumnBindingBuilder::new(
+                        column_name,
+                        column_index,
+                        Box::new(DataType::from(&data_type)),
+                        Visibility::Visible,
+                    )
+                    .table_name(Some(table_entry.name().to_string()))
+                    .database_name(Some(table_entry.database().to_string()))
+                    .table_index(Some(view.table_index))
+                    .build();
+                    Some(ScalarExpr::BoundColumnRef(BoundColumnRef {
+                        span: None,
+                        column: column_binding,
+                    }))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if columns.len() != view.output_columns.len() {
+            return None;
+        }
+
+        let mut info = ViewInfo {
+            table_index: view.table_index,
+            spj,
+            columns,
+            keys: HashMap::new(),
+            aggregates: HashMap::new(),
+            states: HashMap::new(),
+            group_positions: None,
+        };
+
+        let mut group_positions = BTreeSet::new();
+        for (position, output_column) in view.output_columns.iter().enumerate() {
+            // Skip the projections which only rename the columns.
+            let mut index = *output_column;
+            while let Some(ScalarExpr::BoundColumnRef(column)) = projections.get(&index) {
+                if column.column.index == index {
+                    break;
+                }
+                index = column.column.index;
+            }
+
+            if let Some(item) = aggregate
+                .group_items
+                .iter()
+                .find(|item| item.index == index)
+            {
+                let key = display(&info.spj.canonicalize(&item.scalar)?);
+                info.keys.entry(key).or_insert(position);
+                group_positions.insert(position);
+            } else if let Some(item) = aggregate
+                .aggregate_functions
+                .iter()
+                .find(|item| item.index == index)
+            {
+                let Some(ScalarExpr::AggregateFunction(mut function)) =
+                    info.spj.canonicalize(&item.scalar)
+                else {
+                    continue;
+                };
+                info.aggregates
+                    .entry(aggregate_display(&function))
+                    .or_insert(position);
+                if let Some(func_name) = function.func_name.strip_suffix(STATE_SUFFIX) {
+                    if !function.distinct {
+                        function.func_name = func_name.to_string();
+                        info.states
+                            .entry(aggregate_display(&function))
+                            .or_insert(position);
+                    }
+                }
+            }
+        }
+        if group_positions.len() == aggregate.group_items.len() {
+            info.group_positions = Some(group_positions);
+        }
+        Some(info)
+    }
+
+    fn is_equivalent(&self, left: &str, right: &str) -> bool {
+        left == right
+            || self
+                .spj
+                .equi_classes
+                .column_to_equivalence_class
+                .get(left)
+                .is_some_and(|class| class.contains(right))
+    }
+
+    /// Find the position of the group key of the view which is equal to `display`.
+    fn find_key(&self, display: &str) -> Option<usize> {
+        if let Some(position) = self.keys.get(display) {
+            return Some(*position);
+        }
+        self.spj
+            .equi_classes
+            .column_to_equivalence_class
+            .get(display)?
+            .iter()
+            .find_map(|column| self.keys.get(column).copied())
+    }
+
+    /// Compute the canonical `scalar` from the group keys of the view.
+    fn rewrite(&self, scalar: &ScalarExpr) -> Option<ScalarExpr> {
+        if let Some(position) = self.find_key(&display(scalar)) {
+            return Some(self.columns[position].clone());
+        }
+        match scalar {
+            ScalarExpr::ConstantExpr(_) => Some(scalar.clone()),
+            ScalarExpr::FunctionCall(func) => Some(ScalarExpr::FunctionCall(FunctionCall {
+                arguments: func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.rewrite(arg))
+                    .collect::<Option<Vec<_>>>()?,
+                ..func.clone()
+            })),
+            ScalarExpr::CastExpr(cast) => Some(ScalarExpr::CastExpr(CastExpr {
+                argument: Box::new(self.rewrite(&cast.argument)?),
+                ..cast.clone()
+            })),
+            _ => None,
+        }
+    }
+
+    /// Scan the columns of the view used by `items`, filtered by `predicates`.
+    fn scan(&self, predicates: &[ScalarExpr], items: &[ScalarItem]) -> SExpr {
+        let mut used_columns = ColumnSet::new();
+        for scalar in predicates
+            .iter()
+            .chain(items.iter().map(|item| &item.scalar))
+        {
+            used_columns.extend(scalar.used_columns());
+        }
+        let columns = self
+            .columns
+            .iter()
+            .flat_map(|column| column.used_columns())
+            .filter(|index| used_columns.contains(index))
+            .collect();
+
+        let mut s_expr = SExpr::create_leaf(Arc::new(
+            Scan {
+                table_index: self.table_index,
+                columns,
+                statistics: Arc::new(Statistics::default()),
+                ..Default::default()
+            }
+            .into(),
+        ));
+        if !predicates.is_empty() {
+            s_expr = SExpr::create_unary(
+                Arc::new(
+                    Filter {
+                        predicates: predicates.to_vec(),
+                    }
+                    .into(),
+                ),
+                Arc::new(s_expr),
+            );
+        }
+        s_expr
+    }
+}
+
+fn display(scalar: &ScalarExpr) -> String {
+    format_scalar(scalar, &HashMap::new())
+}
+
+fn aggregate_display(function: &AggregateFunction) -> String {
+    let display = display(&ScalarExpr::AggregateFunction(function.clone()));
+    if function.distinct {
+        format!("distinct {display}")
+    } else {
+        display
+    }
+}
+
+fn function_call(func_name: &str, arguments: Vec<ScalarExpr>) -> ScalarExpr {
+    ScalarExpr::FunctionCall(FunctionCall {
+        span: None,
+        func_name: func_name.to_string(),
+        params: vec![],
+        arguments,
+    })
+}
+
+fn column_ref(index: IndexType, name: String, data_type: DataType) -> ScalarExpr {
+    ScalarExpr::BoundColumnRef(BoundColumnRef {
+        span: None,
+        column: ColumnBindingBuilder::new(name, index, Box::new(data_type), Visibility::Visible)
+            .build(),
+    })
+}
+
+fn cast_to(scalar: ScalarExpr, data_type: &DataType) -> Result<ScalarExpr> {
+    if &scalar.data_type()? == data_type {
+        return Ok(scalar);
+    }
+    Ok(ScalarExpr::CastExpr(CastExpr {
+        span: None,
+        is_try: false,
+        argument: Box::new(scalar),
+        target_type: Box::new(data_type.clone()),
+    }))
+}
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod materialized_view_rewrite;
 mod normalize_aggregate;
 mod stats_aggregate;
 
+pub use materialized_view_rewrite::MaterializedViewRewriteOptimizer;
 pub use normalize_aggregate::RuleNormalizeAggregateOptimizer;
 pub use stats_aggregate::RuleStatsAggregateOptimizer;
@@ -0,0 +1,300 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::ColumnBindingBuilder;
+use crate::format_scalar;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::MetadataRef;
+use crate::Visibility;
+
+/// The CommonSubexpressionOptimizer evaluates the identical deterministic expressions
+/// in a chain of consecutive `Filter` and `EvalScalar` only once.
+///
+/// An expression is hoisted into a new `EvalScalar` directly below the lowest operator
+/// that always evaluates it, and every occurrence of the expression in that operator
+/// and the operators above it is replaced by the new column. The arguments of `if`,
+/// `and_filters` and `or_filters` except the first one, as well as the argument of
+/// `is_not_error`, are evaluated conditionally. Expressions only occurring in such
+/// arguments are never evaluated earlier than before, so the short-circuit semantics
+/// are kept.
+pub struct CommonSubexpressionOptimizer {
+    metadata: MetadataRef,
+}
+
+struct Candidate<'a> {
+    expr: &'a ScalarExpr,
+    // The lowest position in the chain where the expression is always evaluated.
+    placement: Option<usize>,
+    // The positions in the chain of each occurrence of the expression.
+    occurrences: Vec<usize>,
+}
+
+impl CommonSubexpressionOptimizer {
+    pub fn new(metadata: MetadataRef) -> Self {
+        CommonSubexpressionOptimizer { metadata }
+    }
+
+    pub fn run(self, s_expr: &SExpr) -> Result<SExpr> {
+        self.eliminate(s_expr)
+    }
+
+    #[recursive::recursive]
+    fn eliminate(&self, s_expr: &SExpr) -> Result<SExpr> {
+        // Collect the chain of consecutive `Filter` and `EvalScalar` from top to bottom.
+        let mut chain = vec![];
+        let mut bottom = s_expr;
+        while matches!(
+            bottom.plan(),
+            RelOperator::Filter(_) | RelOperator::EvalScalar(_)
+        ) {
+            chain.push(bottom.plan().clone());
+            bottom = bottom.child(0)?;
+        }
+
+        let mut children_changed = false;
+        let mut children = Vec::with_capacity(bottom.arity());
+        for child in bottom.children() {
+            let new_child = self.eliminate(child)?;
+            if !new_child.eq(child) {
+                children_changed = true;
+            }
+            children.push(Arc::new(new_child));
+        }
+
+        let chain_changed = self.eliminate_chain(&mut chain)?;
+        if !children_changed && !chain_changed {
+            return Ok(s_expr.clone());
+        }
+
+        let mut result = if children_changed {
+            bottom.replace_children(children)
+        } else {
+            bottom.clone()
+        };
+        for plan in chain.into_iter().rev() {
+            result = SExpr::create_unary(Arc::new(plan), Arc::new(result));
+        }
+        Ok(result)
+    }
+
+    fn eliminate_chain(&self, chain: &mut Vec<RelOperator>) -> Result<bool> {
+        let mut changed = false;
+        // Each round replaces at least two occurrences with a single one in the new
+        // `EvalScalar`, so the loop always terminates.
+        while let Some((expr, placement)) = Self::find_common_subexpression(chain) {
+            let display_name = format_scalar(&expr);
+            let data_type = expr.data_type()?;
+            let index = self.metadata.write().add_derived_column(
+                display_name.clone(),
+                data_type.clone(),
+                Some(expr.clone()),
+            );
+            let column = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: ColumnBindingBuilder::new(
+                    display_name,
+                    index,
+                    Box::new(data_type),
+                    Visibility::Visible,
+                )
+                .build(),
+            });
+
+            for plan in chain[..=placement].iter_mut() {
+                match plan {
+                    RelOperator::EvalScalar(eval_scalar) => {
+                        for item in eval_scalar.items.iter_mut() {
+                            Self::replace(&mut item.scalar, &expr, &column);
+                        }
+                    }
+                    RelOperator::Filter(filter) => {
+                        for predicate in filter.predicates.iter_mut() {
+                            Self::replace(predicate, &expr, &column);
+                        }
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            chain.insert(
+                placement + 1,
+                RelOperator::EvalScalar(EvalScalar {
+                    items: vec![ScalarItem {
+                        scalar: expr,
+                        index,
+                    }],
+                }),
+            );
+            changed = true;
+        }
+        Ok(changed)
+    }
+
+    // Find the largest expression that is evaluated more than once at or above the
+    // lowest operator always evaluating it.
+    fn find_common_subexpression(chain: &[RelOperator]) -> Option<(ScalarExpr, usize)> {
+        let mut candidates: Vec<Candidate> = vec![];
+        let mut candidate_index: HashMap<&ScalarExpr, usize> = HashMap::new();
+        for (position, plan) in chain.iter().enumerate() {
+            let mut occurrences = vec![];
+            match plan {
+                RelOperator::EvalScalar(eval_scalar) => {
+                    for item in eval_scalar.items.iter() {
+                        Self::collect(&item.scalar, false, &mut occurrences);
+                    }
+                }
+                RelOperator::Filter(filter) => {
+                    // The predicates are combined by `and_filters`.
+                    for (i, predicate) in filter.predicates.iter().enumerate() {
+                        Self::collect(predicate, i > 0, &mut occurrences);
+                    }
+                }
+                _ => unreachable!(),
+            }
+
+            for (expr, conditional) in occurrences {
+                let index = *candidate_index.entry(expr).or_insert_with(|| {
+                    candidates.push(Candidate {
+                        expr,
+                        placement: None,
+                        occurrences: vec![],
+                    });
+                    candidates.len() - 1
+                });
+                let candidate = &mut candidates[index];
+                candidate.occurrences.push(position);
+                if !conditional {
+                    candidate.placement = Some(position);
+                }
+            }
+        }
+
+        let mut best: Option<(&ScalarExpr, usize, usize)> = None;
+        for candidate in candidates.iter() {
+            let Some(placement) = candidate.placement else {
+                continue;
+            };
+            let count = candidate
+                .occurrences
+                .iter()
+                .filter(|position| **position <= placement)
+                .count();
+            if count < 2 {
+                continue;
+            }
+            let size = Self::expr_size(candidate.expr);
+            if let Some((_, _, best_size)) = best
+                && size <= best_size
+            {
+                continue;
+            }
+            best = Some((candidate.expr, placement, size));
+        }
+        best.map(|(expr, placement, _)| (expr.clone(), placement))
+    }
+
+    // Collect the candidate sub-expressions of `expr`, and whether they are evaluated
+    // conditionally.
+    fn collect<'a>(
+        expr: &'a ScalarExpr,
+        conditional: bool,
+        occurrences: &mut Vec<(&'a ScalarExpr, bool)>,
+    ) {
+        match expr {
+            ScalarExpr::FunctionCall(func) => {
+                if Self::is_candidate(expr) {
+                    occurrences.push((expr, conditional));
+                }
+                match func.func_name.as_str() {
+                    "if" | "and_filters" | "or_filters" => {
+                        for (i, arg) in func.arguments.iter().enumerate() {
+                            Self::collect(arg, conditional || i > 0, occurrences);
+                        }
+                    }
+                    "is_not_error" => {
+                        for arg in func.arguments.iter() {
+                            Self::collect(arg, true, occurrences);
+                        }
+                    }
+                    _ => {
+                        for arg in func.arguments.iter() {
+                            Self::collect(arg, conditional, occurrences);
+                        }
+                    }
+                }
+            }
+            ScalarExpr::CastExpr(cast) => {
+                if Self::is_candidate(expr) {
+                    occurrences.push((expr, conditional));
+                }
+                Self::collect(&cast.argument, conditional, occurrences);
+            }
+            _ => {}
+        }
+    }
+
+    fn replace(expr: &mut ScalarExpr, target: &ScalarExpr, column: &ScalarExpr) {
+        if expr == target {
+            *expr = column.clone();
+            return;
+        }
+        match expr {
+            ScalarExpr::FunctionCall(func) => {
+                for arg in func.arguments.iter_mut() {
+                    Self::replace(arg, target, column);
+                }
+            }
+            ScalarExpr::CastExpr(cast) => Self::replace(&mut cast.argument, target, column),
+            _ => {}
+        }
+    }
+
+    fn is_candidate(expr: &ScalarExpr) -> bool {
+        Self::is_deterministic(expr) && !expr.used_columns().is_empty()
+    }
+
+    fn is_deterministic(expr: &ScalarExpr) -> bool {
+        match expr {
+            ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_) => true,
+            ScalarExpr::FunctionCall(func) => {
+                BUILTIN_FUNCTIONS
+                    .get_property(&func.func_name)
+                    .is_some_and(|property| !property.non_deterministic)
+                    && func.arguments.iter().all(Self::is_deterministic)
+            }
+            ScalarExpr::CastExpr(cast) => Self::is_deterministic(&cast.argument),
+            _ => false,
+        }
+    }
+
+    fn expr_size(expr: &ScalarExpr) -> usize {
+        match expr {
+            ScalarExpr::FunctionCall(func) => {
+                1 + func.arguments.iter().map(Self::expr_size).sum::<usize>()
+            }
+            ScalarExpr::CastExpr(cast) => 1 + Self::expr_size(&cast.argument),
+            _ => 1,
+        }
+    }
+}
@@ -14,6 +14,7 @@
 
 mod aggregate;
 mod cascades;
+mod common_subexpression;
 mod cost;
 mod decorrelate;
 mod distributed;
@@ -22,6 +22,7 @@ use databend_common_exception::Result;
 use educe::Educe;
 use log::info;
 
+use super::aggregate::MaterializedViewRewriteOptimizer;
 use super::aggregate::RuleStatsAggregateOptimizer;
 use super::distributed::BroadcastToShuffleOptimizer;
 use super::format::display_memo;
@@ -31,6 +32,7 @@ use crate::binder::MutationStrategy;
 use crate::binder::MutationType;
 use crate::optimizer::aggregate::RuleNormalizeAggregateOptimizer;
 use crate::optimizer::cascades::CascadesOptimizer;
+use crate::optimizer::common_subexpression::CommonSubexpressionOptimizer;
 use crate::optimizer::decorrelate::decorrelate_subquery;
 use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::distributed::SortAndLimitPushDownOptimizer;
@@ -234,9 +236,16 @@ pub async fn optimize(mut opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan>
                 }
             }
         },
-        Plan::ExplainAnalyze { plan, partial } => Ok(Plan::ExplainAnalyze {
+        Plan::ExplainAnalyze {
+            plan,
             partial,
+            json,
+            query_hint,
+        } => Ok(Plan::ExplainAnalyze {
+            partial,
+            json,
             plan: Box::new(Box::pin(optimize(opt_ctx, *plan)).await?),
+            query_hint,
         }),
         Plan::CopyIntoLocation(CopyIntoLocationPlan {
             stage,
@@ -344,6 +353,11 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
         s_expr = decorrelate_subquery(opt_ctx.metadata.clone(), s_expr.clone())?;
     }
 
+    // Rewrite aggregations to read materialized views before collecting statistics.
+    if !opt_ctx.planning_agg_index && !opt_ctx.metadata.read().materialized_views().is_empty() {
+        s_expr = MaterializedViewRewriteOptimizer::new(opt_ctx.metadata.clone()).run(&s_expr)?;
+    }
+
     s_expr = RuleStatsAggregateOptimizer::new(opt_ctx.table_ctx.clone(), opt_ctx.metadata.clone())
         .run(&s_expr)
         .await?;
@@ -423,6 +437,15 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
     };
 
     if !opt_ctx.planning_agg_index {
+        // Evaluate the identical expressions in projections and filters only once.
+        if opt_ctx
+            .table_ctx
+            .get_settings()
+            .get_enable_common_subexpression_elimination()?
+        {
+            s_expr = CommonSubexpressionOptimizer::new(opt_ctx.metadata.clone()).run(&s_expr)?;
+        }
+
         s_expr = RecursiveOptimizer::new([RuleID::EliminateEvalScalar].as_slice(), opt_ctx)
             .run(&s_expr)?;
     }
@@ -443,6 +466,11 @@ async fn get_optimized_memo(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Res
         s_expr = decorrelate_subquery(opt_ctx.metadata.clone(), s_expr.clone())?;
     }
 
+    // Rewrite aggregations to read materialized views before collecting statistics.
+    if !opt_ctx.planning_agg_index && !opt_ctx.metadata.read().materialized_views().is_empty() {
+        s_expr = MaterializedViewRewriteOptimizer::new(opt_ctx.metadata.clone()).run(&s_expr)?;
+    }
+
     s_expr = RuleStatsAggregateOptimizer::new(opt_ctx.table_ctx.clone(), opt_ctx.metadata.clone())
         .run(&s_expr)
         .await?;
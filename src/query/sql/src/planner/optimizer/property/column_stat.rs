@@ -16,6 +16,7 @@ use std::collections::HashMap;
 
 use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
+use databend_common_storage::MostCommonValues;
 
 use crate::IndexType;
 
@@ -38,6 +39,57 @@ pub struct ColumnStat {
 
     /// Histogram of column
     pub histogram: Option<Histogram>,
+
+    /// Most common values of column
+    pub most_common_values: Option<MostCommonValues>,
+}
+
+#[derive(Debug, Clone)]
+/// Statistics information of a group of columns
+pub struct ColumnGroupStat {
+    /// Column indexes of the group
+    pub columns: Vec<IndexType>,
+
+    /// Number of distinct values of the columns together
+    pub ndv: f64,
+
+    /// Degree of the functional dependency of each column on the others,
+    /// column index -> degree
+    pub dependencies: HashMap<IndexType, f64>,
+}
+
+impl ColumnGroupStat {
+    /// Estimate the selectivity of the equality predicates on all the columns
+    /// of the group, `selectivities` holds the selectivity of each predicate.
+    ///
+    /// With the degree `d` of the column `a` that determines the others best,
+    /// the selectivity is `P(a) * (d + (1 - d) * P(others))`.
+    pub fn equality_selectivity(&self, selectivities: &HashMap<IndexType, f64>) -> Option<f64> {
+        if self.ndv == 0.0
+            || !self
+                .columns
+                .iter()
+                .all(|column| selectivities.contains_key(column))
+        {
+            return None;
+        }
+
+        let (determinant, degree) = self
+            .columns
+            .iter()
+            .map(|column| {
+                let degree = self.dependencies.get(column).copied().unwrap_or(0.0);
+                (*column, degree.clamp(0.0, 1.0))
+            })
+            .max_by(|l, r| l.1.total_cmp(&r.1))?;
+        let others: f64 = self
+            .columns
+            .iter()
+            .filter(|column| **column != determinant)
+            .map(|column| selectivities[column])
+            .product();
+        Some(selectivities[&determinant] * (degree + (1.0 - degree) * others))
+    }
 }
 
 #[derive(Debug, Clone)]
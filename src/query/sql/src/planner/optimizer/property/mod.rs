@@ -22,6 +22,7 @@ mod property;
 mod selectivity;
 
 pub use builder::RelExpr;
+pub use column_stat::ColumnGroupStat;
 pub use column_stat::ColumnStat;
 pub use column_stat::ColumnStatSet;
 pub use column_stat::NewStatistic;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 use databend_common_exception::ErrorCode;
@@ -64,6 +65,33 @@ impl<'a> SelectivityEstimator<'a> {
         }
     }
 
+    /// Compute the selectivity of the conjunction of predicates.
+    pub fn compute_conjunction_selectivity(
+        &mut self,
+        predicates: &[ScalarExpr],
+        update: bool,
+    ) -> Result<f64> {
+        let mut selectivity = MAX_SELECTIVITY;
+        let mut equalities = HashMap::new();
+        for pred in predicates.iter() {
+            // Compute selectivity for each conjunction
+            let pred_selectivity = self.compute_selectivity(pred, update)?;
+            if let Some(index) = equal_to_constant_column(pred) {
+                equalities.insert(index, pred_selectivity);
+            }
+            selectivity = selectivity.min(pred_selectivity);
+        }
+
+        // The equality predicates on a group of correlated columns are estimated
+        // by the extended statistics of the group.
+        for group in self.input_stat.column_groups.iter() {
+            if let Some(group_selectivity) = group.equality_selectivity(&equalities) {
+                selectivity = selectivity.min(group_selectivity);
+            }
+        }
+        Ok(selectivity)
+    }
+
     /// Compute the selectivity of a predicate.
     pub fn compute_selectivity(&mut self, predicate: &ScalarExpr, update: bool) -> Result<f64> {
         Ok(match predicate {
@@ -285,6 +313,18 @@ impl<'a> SelectivityEstimator<'a> {
 
     // Update other columns' statistic according to selectivity.
     pub fn update_other_statistic_by_selectivity(&mut self, selectivity: f64) {
+        let updated_column_indexes = &self.updated_column_indexes;
+        self.input_stat.column_groups.retain_mut(|group| {
+            if group
+                .columns
+                .iter()
+                .any(|column| updated_column_indexes.contains(column))
+            {
+                return false;
+            }
+            group.ndv = (group.ndv * selectivity).ceil();
+            true
+        });
         for (index, column_stat) in self.input_stat.column_stats.iter_mut() {
             if !self.updated_column_indexes.contains(index) {
                 let new_ndv = (column_stat.ndv * selectivity).ceil();
@@ -515,6 +555,22 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
                 return 0.0;
             }
         }
+
+        if let Some(mcv) = &column_stat.most_common_values {
+            // The frequencies are relative to the non-null rows.
+            let non_null_fraction = 1.0 - mcv.null_fraction;
+            if let Some(frequency) = mcv.frequency_of(constant_datum) {
+                return frequency * non_null_fraction;
+            }
+            if !mcv.is_empty() {
+                // The rest of the values are assumed to be uniformly distributed,
+                // and can't be more common than the least common value in the list.
+                let other_ndv = (column_stat.ndv - mcv.len() as f64).max(1.0);
+                let min_frequency = mcv.values_iter().last().unwrap().frequency;
+                let frequency = ((1.0 - mcv.total_frequency()) / other_ndv).min(min_frequency);
+                return frequency * non_null_fraction;
+            }
+        }
     }
 
     if column_stat.ndv == 0.0 {
@@ -543,7 +599,24 @@ fn update_statistic(
         // Todo: support unfixed buckets number for histogram and prune the histogram.
         column_stat.histogram = None;
     }
+    column_stat.most_common_values = None;
     column_stat.min = new_min.clone();
     column_stat.max = new_max.clone();
     Ok(())
 }
+
+// Returns the column index if the predicate is an equality between a column and a constant.
+fn equal_to_constant_column(predicate: &ScalarExpr) -> Option<IndexType> {
+    match predicate {
+        ScalarExpr::FunctionCall(func) if func.func_name == "eq" => {
+            match (&func.arguments[0], &func.arguments[1]) {
+                (ScalarExpr::BoundColumnRef(column_ref), ScalarExpr::ConstantExpr(_))
+                | (ScalarExpr::ConstantExpr(_), ScalarExpr::BoundColumnRef(column_ref)) => {
+                    Some(column_ref.column.index)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
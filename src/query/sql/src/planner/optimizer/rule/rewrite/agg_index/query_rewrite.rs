@@ -395,14 +395,14 @@ impl ViewInfo {
     }
 }
 
-struct PredicatesSplitter {
-    equi_columns_preds: Vec<(BoundColumnRef, BoundColumnRef)>,
-    range_preds: Vec<(String, BoundColumnRef, ConstantExpr)>,
-    residual_preds: Vec<ScalarExpr>,
+pub(crate) struct PredicatesSplitter {
+    pub(crate) equi_columns_preds: Vec<(BoundColumnRef, BoundColumnRef)>,
+    pub(crate) range_preds: Vec<(String, BoundColumnRef, ConstantExpr)>,
+    pub(crate) residual_preds: Vec<ScalarExpr>,
 }
 
 impl PredicatesSplitter {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
             equi_columns_preds: vec![],
             range_preds: vec![],
@@ -410,7 +410,7 @@ impl PredicatesSplitter {
         }
     }
 
-    fn split(&mut self, pred: &ScalarExpr, column_map: &HashMap<IndexType, ScalarExpr>) {
+    pub(crate) fn split(&mut self, pred: &ScalarExpr, column_map: &HashMap<IndexType, ScalarExpr>) {
         if let ScalarExpr::FunctionCall(func) = pred {
             match func.func_name.as_str() {
                 "and" => {
@@ -458,18 +458,18 @@ impl PredicatesSplitter {
     }
 }
 
-struct EquivalenceClasses {
-    column_to_equivalence_class: HashMap<String, HashSet<String>>,
+pub(crate) struct EquivalenceClasses {
+    pub(crate) column_to_equivalence_class: HashMap<String, HashSet<String>>,
 }
 
 impl EquivalenceClasses {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
             column_to_equivalence_class: HashMap::new(),
         }
     }
 
-    fn add_equivalence_class(&mut self, col1: &BoundColumnRef, col2: &BoundColumnRef) {
+    pub(crate) fn add_equivalence_class(&mut self, col1: &BoundColumnRef, col2: &BoundColumnRef) {
         let mut equivalence_columns = HashSet::new();
 
         let col1_name = format_col(&col1.column);
@@ -507,7 +507,7 @@ impl EquivalenceClasses {
     }
 
     // Equijoin subsumption test.
-    fn check(&self, view_equi_classes: &EquivalenceClasses) -> bool {
+    pub(crate) fn check(&self, view_equi_classes: &EquivalenceClasses) -> bool {
         for (col, view_equi_cols) in view_equi_classes.column_to_equivalence_class.iter() {
             if let Some(query_equi_cols) = self.column_to_equivalence_class.get(col) {
                 // checking whether every non-trivial view equivalence class
@@ -523,7 +523,7 @@ impl EquivalenceClasses {
 }
 
 #[derive(Eq, Clone, Debug)]
-enum BoundValue {
+pub(crate) enum BoundValue {
     // column >= scalar value or column <= scalar value
     Closed(Scalar),
     // column > scalar value or column < scalar value
@@ -678,18 +678,23 @@ impl RangeValues {
     }
 }
 
-struct RangeClasses {
+pub(crate) struct RangeClasses {
     column_to_range_class: BTreeMap<String, RangeValues>,
 }
 
 impl RangeClasses {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
             column_to_range_class: BTreeMap::new(),
         }
     }
 
-    fn add_range_class(&mut self, func_name: &str, col: &BoundColumnRef, val: &ConstantExpr) {
+    pub(crate) fn add_range_class(
+        &mut self,
+        func_name: &str,
+        col: &BoundColumnRef,
+        val: &ConstantExpr,
+    ) {
         let col_name = format_col(&col.column);
 
         let (lower_bound, upper_bound) = match func_name {
@@ -724,9 +729,16 @@ impl RangeClasses {
         }
     }
 
+    /// Whether every column can satisfy its range predicates.
+    pub(crate) fn is_satisfiable(&self) -> bool {
+        self.column_to_range_class
+            .values()
+            .all(|range_values| range_values.bounds.is_some())
+    }
+
     // Range subsumption test.
     #[allow(clippy::type_complexity)]
-    fn check(
+    pub(crate) fn check(
         &self,
         view_range_classes: &RangeClasses,
     ) -> (bool, Option<BTreeMap<String, (BoundValue, BoundValue)>>) {
@@ -810,23 +822,26 @@ impl RangeClasses {
     }
 }
 
-struct ResidualClasses {
+pub(crate) struct ResidualClasses {
     residual_preds: BTreeMap<String, ScalarExpr>,
 }
 
 impl ResidualClasses {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
             residual_preds: BTreeMap::new(),
         }
     }
 
-    fn add_residual_pred(&mut self, pred_display: String, pred: &ScalarExpr) {
+    pub(crate) fn add_residual_pred(&mut self, pred_display: String, pred: &ScalarExpr) {
         self.residual_preds.insert(pred_display, pred.clone());
     }
 
     // Residual subsumption test.
-    fn check(&self, view_residual_classes: &ResidualClasses) -> (bool, Option<Vec<ScalarExpr>>) {
+    pub(crate) fn check(
+        &self,
+        view_residual_classes: &ResidualClasses,
+    ) -> (bool, Option<Vec<ScalarExpr>>) {
         let mut extra_residual_preds = Vec::new();
         for (view_residual_key, _) in view_residual_classes.residual_preds.iter() {
             if !self.residual_preds.contains_key(view_residual_key) {
@@ -967,63 +982,7 @@ impl AggIndexRewriter {
             for (col, (lower_bound, upper_bound)) in extra_ranges.iter() {
                 // materialized view output must contains the column
                 if let Some((new_scalar, _)) = view_info.index_output_cols.get(col) {
-                    let lower = match lower_bound {
-                        BoundValue::Closed(val) => Some((val.clone(), "gte")),
-                        BoundValue::Open(val) => Some((val.clone(), "gt")),
-                        BoundValue::NegativeInfinite => None,
-                        _ => unreachable!(),
-                    };
-                    let upper = match upper_bound {
-                        BoundValue::Closed(val) => Some((val.clone(), "lte")),
-                        BoundValue::Open(val) => Some((val.clone(), "lt")),
-                        BoundValue::PositiveInfinite => None,
-                        _ => unreachable!(),
-                    };
-
-                    if let (Some((lower_val, "gte")), Some((upper_val, "lte"))) = (&lower, &upper) {
-                        // if lower and upper value equal, convert to equal function
-                        if lower_val.eq(upper_val) {
-                            let lower_val_scalar = ScalarExpr::ConstantExpr(ConstantExpr {
-                                span: None,
-                                value: lower_val.clone(),
-                            });
-                            let pred = ScalarExpr::FunctionCall(FunctionCall {
-                                span: None,
-                                func_name: "eq".to_string(),
-                                params: vec![],
-                                arguments: vec![new_scalar.clone(), lower_val_scalar],
-                            });
-                            new_predicates.push(pred);
-                            continue;
-                        }
-                    }
-
-                    if let Some((lower_val, func_name)) = lower {
-                        let lower_val_scalar = ScalarExpr::ConstantExpr(ConstantExpr {
-                            span: None,
-                            value: lower_val,
-                        });
-                        let pred = ScalarExpr::FunctionCall(FunctionCall {
-                            span: None,
-                            func_name: func_name.to_string(),
-                            params: vec![],
-                            arguments: vec![new_scalar.clone(), lower_val_scalar],
-                        });
-                        new_predicates.push(pred);
-                    }
-                    if let Some((upper_val, func_name)) = upper {
-                        let upper_val_scalar = ScalarExpr::ConstantExpr(ConstantExpr {
-                            span: None,
-                            value: upper_val,
-                        });
-                        let pred = ScalarExpr::FunctionCall(FunctionCall {
-                            span: None,
-                            func_name: func_name.to_string(),
-                            params: vec![],
-                            arguments: vec![new_scalar.clone(), upper_val_scalar],
-                        });
-                        new_predicates.push(pred);
-                    }
+                    new_predicates.extend(range_predicates(new_scalar, lower_bound, upper_bound));
                 } else {
                     return false;
                 }
@@ -1195,6 +1154,54 @@ impl AggIndexRewriter {
     }
 }
 
+/// The predicates that restrict `scalar` to the range between `lower_bound` and `upper_bound`.
+pub(crate) fn range_predicates(
+    scalar: &ScalarExpr,
+    lower_bound: &BoundValue,
+    upper_bound: &BoundValue,
+) -> Vec<ScalarExpr> {
+    let lower = match lower_bound {
+        BoundValue::Closed(val) => Some((val.clone(), "gte")),
+        BoundValue::Open(val) => Some((val.clone(), "gt")),
+        BoundValue::NegativeInfinite => None,
+        _ => unreachable!(),
+    };
+    let upper = match upper_bound {
+        BoundValue::Closed(val) => Some((val.clone(), "lte")),
+        BoundValue::Open(val) => Some((val.clone(), "lt")),
+        BoundValue::PositiveInfinite => None,
+        _ => unreachable!(),
+    };
+
+    let compare = |func_name: &str, value: Scalar| {
+        ScalarExpr::FunctionCall(FunctionCall {
+            span: None,
+            func_name: func_name.to_string(),
+            params: vec![],
+            arguments: vec![
+                scalar.clone(),
+                ScalarExpr::ConstantExpr(ConstantExpr { span: None, value }),
+            ],
+        })
+    };
+
+    if let (Some((lower_val, "gte")), Some((upper_val, "lte"))) = (&lower, &upper) {
+        // if lower and upper value equal, convert to equal function
+        if lower_val.eq(upper_val) {
+            return vec![compare("eq", lower_val.clone())];
+        }
+    }
+
+    let mut predicates = Vec::with_capacity(2);
+    if let Some((lower_val, func_name)) = lower {
+        predicates.push(compare(func_name, lower_val));
+    }
+    if let Some((upper_val, func_name)) = upper {
+        predicates.push(compare(func_name, upper_val));
+    }
+    predicates
+}
+
 fn to_index_scalar(index: IndexType, data_type: &DataType) -> ScalarExpr {
     let col = BoundColumnRef {
         span: None,
@@ -1232,7 +1239,7 @@ fn reverse_op(op: &str) -> String {
 }
 
 // replace derived column with actual ScalarExpr.
-fn actual_column_ref<'a>(
+pub(crate) fn actual_column_ref<'a>(
     col: &'a ScalarExpr,
     column_map: &'a HashMap<IndexType, ScalarExpr>,
 ) -> &'a ScalarExpr {
@@ -1244,7 +1251,10 @@ fn actual_column_ref<'a>(
     col
 }
 
-fn format_scalar(scalar: &ScalarExpr, column_map: &HashMap<IndexType, ScalarExpr>) -> String {
+pub(crate) fn format_scalar(
+    scalar: &ScalarExpr,
+    column_map: &HashMap<IndexType, ScalarExpr>,
+) -> String {
     match scalar {
         ScalarExpr::BoundColumnRef(_) => match actual_column_ref(scalar, column_map) {
             ScalarExpr::BoundColumnRef(col) => format_col(&col.column),
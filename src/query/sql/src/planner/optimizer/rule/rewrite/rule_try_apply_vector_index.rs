@@ -0,0 +1,193 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::VectorIndexInfo;
+use databend_common_exception::Result;
+use databend_common_expression::types::F32;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_meta_app::schema::TableIndexType;
+use databend_common_vector::DistanceType;
+
+use crate::optimizer::extract::Matcher;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::EvalScalar;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+
+/// Input:  Sort(limit)
+///           \
+///        EvalScalar(cosine_distance(column, const))
+///             \
+///             Scan
+///
+/// Output:
+///         Sort(limit)
+///           \
+///        EvalScalar
+///             \
+///             Scan(padding vector_index)
+///
+/// The vector index only prunes the blocks that can't contain the nearest rows,
+/// the distances of the remaining rows are still computed and sorted exactly.
+pub struct RuleTryApplyVectorIndex {
+    id: RuleID,
+    metadata: MetadataRef,
+    matchers: Vec<Matcher>,
+}
+
+impl RuleTryApplyVectorIndex {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::TryApplyVectorIndex,
+            metadata,
+            matchers: vec![Matcher::MatchOp {
+                op_type: RelOp::Sort,
+                children: vec![Matcher::MatchOp {
+                    op_type: RelOp::EvalScalar,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::Scan,
+                        children: vec![],
+                    }],
+                }],
+            }],
+        }
+    }
+
+    fn try_create_vector_index_info(
+        &self,
+        sort: &Sort,
+        eval_scalar: &EvalScalar,
+        scan: &Scan,
+    ) -> Option<VectorIndexInfo> {
+        // Rows filtered out can't be selected by the index.
+        if scan.vector_index.is_some()
+            || scan.push_down_predicates.is_some()
+            || scan.prewhere.is_some()
+        {
+            return None;
+        }
+        let limit = sort.limit?;
+        let [sort_item] = sort.items.as_slice() else {
+            return None;
+        };
+        // The rows without vectors are not in the index, they must be sorted last.
+        if !sort_item.asc || sort_item.nulls_first {
+            return None;
+        }
+        let item = eval_scalar
+            .items
+            .iter()
+            .find(|item| item.index == sort_item.index)?;
+        let ScalarExpr::FunctionCall(func) = &item.scalar else {
+            return None;
+        };
+        let distance_type = DistanceType::from_func_name(&func.func_name)?;
+        let (column, query_values) = match func.arguments.as_slice() {
+            [ScalarExpr::BoundColumnRef(column), query]
+            | [query, ScalarExpr::BoundColumnRef(column)] => (column, constant_vector(query)?),
+            _ => return None,
+        };
+        if column.column.table_index != Some(scan.table_index) {
+            return None;
+        }
+
+        let metadata = self.metadata.read();
+        let ColumnEntry::BaseTableColumn(base_column) = metadata.column(column.column.index) else {
+            return None;
+        };
+        let column_id = base_column.column_id?;
+        let table = metadata.table(scan.table_index).table();
+        let table_info = table.get_table_info();
+        let index = table_info.meta.indexes.values().find(|index| {
+            index.index_type == TableIndexType::Vector
+                && index.column_ids == [column_id]
+                && index.options.get("distance").map(|v| v.as_str())
+                    == Some(distance_type.as_option())
+        })?;
+
+        Some(VectorIndexInfo {
+            index_name: index.name.clone(),
+            index_version: index.version.clone(),
+            index_options: index.options.clone(),
+            column_id,
+            func_name: func.func_name.clone(),
+            query_values,
+            limit,
+        })
+    }
+}
+
+// Extract the query vector from a constant array, which may be casted to `Array(Float32)`.
+fn constant_vector(scalar: &ScalarExpr) -> Option<Vec<F32>> {
+    let value = match scalar {
+        ScalarExpr::ConstantExpr(constant) => &constant.value,
+        ScalarExpr::CastExpr(cast) => match cast.argument.as_ref() {
+            ScalarExpr::ConstantExpr(constant) => &constant.value,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let Scalar::Array(column) = value else {
+        return None;
+    };
+    column
+        .iter()
+        .map(|value| match value {
+            ScalarRef::Number(num) => Some(F32::from(num.to_f64().0 as f32)),
+            ScalarRef::Decimal(num) => Some(F32::from(num.to_float64() as f32)),
+            _ => None,
+        })
+        .collect()
+}
+
+impl Rule for RuleTryApplyVectorIndex {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let mut scan: Scan = eval_scalar_expr.child(0)?.plan().clone().try_into()?;
+
+        let Some(vector_index) = self.try_create_vector_index_info(&sort, &eval_scalar, &scan)
+        else {
+            return Ok(());
+        };
+        scan.vector_index = Some(vector_index);
+
+        let scan = SExpr::create_leaf(Arc::new(RelOperator::Scan(scan)));
+        let eval_scalar_expr = eval_scalar_expr.replace_children(vec![Arc::new(scan)]);
+        let mut result = s_expr.replace_children(vec![Arc::new(eval_scalar_expr)]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
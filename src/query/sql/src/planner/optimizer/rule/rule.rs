@@ -56,6 +56,7 @@ pub static DEFAULT_REWRITE_RULES: LazyLock<Vec<RuleID>> = LazyLock::new(|| {
         RuleID::PushDownFilterScan,
         RuleID::PushDownPrewhere, /* PushDownPrwhere should be after all rules except PushDownFilterScan */
         RuleID::PushDownSortScan, // PushDownSortScan should be after PushDownPrewhere
+        RuleID::TryApplyVectorIndex, // TryApplyVectorIndex should be after PushDownPrewhere
     ]
 });
 
@@ -108,6 +109,7 @@ pub enum RuleID {
     FoldCountAggregate,
     PushDownPrewhere,
     TryApplyAggIndex,
+    TryApplyVectorIndex,
     CommuteJoin,
 
     // Exploration rules
@@ -152,6 +154,7 @@ impl Display for RuleID {
             RuleID::LeftExchangeJoin => write!(f, "LeftExchangeJoin"),
             RuleID::EagerAggregation => write!(f, "EagerAggregation"),
             RuleID::TryApplyAggIndex => write!(f, "TryApplyAggIndex"),
+            RuleID::TryApplyVectorIndex => write!(f, "TryApplyVectorIndex"),
             RuleID::SemiToInnerJoin => write!(f, "SemiToInnerJoin"),
             RuleID::EliminateUnion => write!(f, "EliminateUnion"),
         }
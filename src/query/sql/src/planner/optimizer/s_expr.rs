@@ -21,10 +21,13 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use educe::Educe;
 
+use super::ColumnSet;
 use super::RelationalProperty;
 use crate::optimizer::rule::AppliedRules;
 use crate::optimizer::rule::RuleID;
 use crate::optimizer::StatInfo;
+use crate::plans::AsyncFunctionArgument;
+use crate::plans::AsyncFunctionCall;
 use crate::plans::Exchange;
 use crate::plans::RelOperator;
 use crate::plans::Scan;
@@ -351,6 +354,87 @@ impl SExpr {
         Ok(udfs)
     }
 
+    /// Collect the names of the sequences used by `nextval` in this expression.
+    #[recursive::recursive]
+    pub fn get_sequences(&self) -> HashSet<&String> {
+        let mut sequences = HashSet::new();
+        if let RelOperator::AsyncFunction(async_func) = self.plan.as_ref() {
+            for item in &async_func.items {
+                if let ScalarExpr::AsyncFunctionCall(AsyncFunctionCall {
+                    func_arg: AsyncFunctionArgument::SequenceFunction(sequence_name),
+                    ..
+                }) = &item.scalar
+                {
+                    sequences.insert(sequence_name);
+                }
+            }
+        }
+        for child in &self.children {
+            sequences.extend(child.get_sequences());
+        }
+        sequences
+    }
+
+    /// Collect the columns referenced by the scalars of the operators in this
+    /// expression. Unlike `Scan::columns`, columns that are only carried
+    /// through by a scan but never referenced are not included.
+    #[recursive::recursive]
+    pub fn get_used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = match self.plan.as_ref() {
+            RelOperator::Scan(scan) => {
+                let mut used_columns = ColumnSet::new();
+                if let Some(push_down_predicates) = &scan.push_down_predicates {
+                    for predicate in push_down_predicates {
+                        used_columns.extend(predicate.used_columns());
+                    }
+                }
+                if let Some(prewhere) = &scan.prewhere {
+                    used_columns.extend(prewhere.prewhere_columns.iter());
+                }
+                used_columns
+            }
+            RelOperator::Exchange(Exchange::Hash(hash)) => hash
+                .iter()
+                .flat_map(|scalar| scalar.used_columns())
+                .collect(),
+            RelOperator::Join(op) => op.used_columns()?,
+            RelOperator::EvalScalar(op) => op.used_columns()?,
+            RelOperator::Filter(op) => op.used_columns()?,
+            RelOperator::Aggregate(op) => op.used_columns()?,
+            RelOperator::Sort(op) => op.used_columns(),
+            RelOperator::Window(op) => op.used_columns()?,
+            RelOperator::ProjectSet(op) => op
+                .srfs
+                .iter()
+                .flat_map(|srf| srf.scalar.used_columns())
+                .collect(),
+            RelOperator::Udf(op) => op.used_columns()?,
+            RelOperator::AsyncFunction(op) => op.used_columns()?,
+            RelOperator::UnionAll(op) => op.used_columns()?,
+            RelOperator::MutationSource(mutation_source) => mutation_source
+                .filter
+                .as_ref()
+                .map(|filter| filter.used_columns())
+                .unwrap_or_default(),
+            RelOperator::Exchange(_)
+            | RelOperator::Limit(_)
+            | RelOperator::DummyTableScan(_)
+            | RelOperator::CteScan(_)
+            | RelOperator::MaterializedCte(_)
+            | RelOperator::ConstantTableScan(_)
+            | RelOperator::ExpressionScan(_)
+            | RelOperator::CacheScan(_)
+            | RelOperator::RecursiveCteScan(_)
+            | RelOperator::Mutation(_)
+            | RelOperator::Recluster(_)
+            | RelOperator::CompactBlock(_) => ColumnSet::new(),
+        };
+        for child in &self.children {
+            used_columns.extend(child.get_used_columns()?);
+        }
+        Ok(used_columns)
+    }
+
     // Add (table_index, column_index) into `Scan` node recursively.
     pub fn add_internal_column_index(
         expr: &SExpr,
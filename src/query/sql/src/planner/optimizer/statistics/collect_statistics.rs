@@ -22,6 +22,7 @@ use databend_common_expression::types::F64;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
 
+use crate::optimizer::ColumnGroupStat;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::optimizer::StatInfo;
@@ -76,6 +77,8 @@ impl CollectStatisticsOptimizer {
 
                 let mut column_stats = HashMap::new();
                 let mut histograms = HashMap::new();
+                let mut most_common_values = HashMap::new();
+                let mut column_indexes = HashMap::new();
                 for column in columns.iter() {
                     if let ColumnEntry::BaseTableColumn(BaseTableColumn {
                         column_index,
@@ -92,16 +95,44 @@ impl CollectStatisticsOptimizer {
                                 let histogram =
                                     column_statistics_provider.histogram(column_id as ColumnId);
                                 histograms.insert(*column_index, histogram);
+                                let mcv = column_statistics_provider
+                                    .most_common_values(column_id as ColumnId);
+                                most_common_values.insert(*column_index, mcv);
+                                column_indexes.insert(column_id as ColumnId, *column_index);
                             }
                         }
                     }
                 }
 
+                let column_groups = column_statistics_provider
+                    .column_groups()
+                    .into_iter()
+                    .filter_map(|group| {
+                        let columns = group
+                            .column_ids
+                            .iter()
+                            .map(|id| column_indexes.get(id).copied())
+                            .collect::<Option<Vec<_>>>()?;
+                        let dependencies = group
+                            .dependencies
+                            .iter()
+                            .filter_map(|(id, degree)| Some((*column_indexes.get(id)?, *degree)))
+                            .collect();
+                        Some(ColumnGroupStat {
+                            columns,
+                            ndv: group.ndv as f64,
+                            dependencies,
+                        })
+                    })
+                    .collect();
+
                 let mut scan = scan.clone();
                 scan.statistics = Arc::new(Statistics {
                     table_stats,
                     column_stats,
                     histograms,
+                    most_common_values,
+                    column_groups,
                 });
                 let mut s_expr = s_expr.replace_plan(Arc::new(RelOperator::Scan(scan.clone())));
                 if let Some(sample) = &scan.sample {
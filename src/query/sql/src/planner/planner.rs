@@ -162,6 +162,10 @@ impl Planner {
                     return Err(ErrorCode::SyntaxException("convert prql to sql failed."));
                 }
 
+                // Apply the query hint pinned to this query before the planner cache lookup,
+                // the cache is keyed on the setting changes as well.
+                let query_hint = self.apply_query_hint(&stmt).await?;
+
                 self.replace_stmt(&mut stmt)?;
 
                 // Step 3: Bind AST with catalog, and generate a pure logical SExpr
@@ -202,7 +206,15 @@ impl Planner {
                 // Indicate binder there is no need to collect column statistics for the binding table.
                 self.ctx
                     .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
-                let plan = binder.bind(&stmt).await?;
+                let mut plan = binder.bind(&stmt).await?;
+                match &mut plan {
+                    Plan::Explain { config, .. } => config.query_hint = query_hint,
+                    Plan::ExplainAnalyze {
+                        query_hint: plan_query_hint,
+                        ..
+                    } => *plan_query_hint = query_hint,
+                    _ => {}
+                }
                 // attach again to avoid the query kind is overwritten by the subquery
                 self.ctx
                     .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
@@ -21,10 +21,13 @@ use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::IdentifierType;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableReference;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::is_cacheable_function;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserPrivilegeType;
 use databend_common_settings::ChangeValue;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::CacheValue;
@@ -170,6 +173,56 @@ impl TableRefVisitor {
         }
     }
 
+    // `SELECT *` may be expanded according to the column-level privileges of the current user,
+    // so the plan can only be shared by the users having SELECT on the whole table.
+    async fn has_table_select_privilege(
+        &self,
+        catalog_name: &str,
+        database_name: &str,
+        table: &Arc<dyn Table>,
+    ) -> bool {
+        if !matches!(
+            self.ctx
+                .get_settings()
+                .get_enable_expand_star_to_permitted_columns(),
+            Ok(true)
+        ) {
+            return true;
+        }
+
+        let object = GrantObject::Table(
+            catalog_name.to_string(),
+            database_name.to_string(),
+            table.name().to_string(),
+        );
+        if self
+            .ctx
+            .validate_privilege(&object, UserPrivilegeType::Select, false)
+            .await
+            .is_ok()
+        {
+            return true;
+        }
+        let Ok(catalog) = self.ctx.get_catalog(catalog_name).await else {
+            return false;
+        };
+        let Ok(database) = catalog
+            .get_database(&self.ctx.get_tenant(), database_name)
+            .await
+        else {
+            return false;
+        };
+        let object = GrantObject::TableById(
+            catalog_name.to_string(),
+            database.get_db_info().database_id.db_id,
+            table.get_id(),
+        );
+        self.ctx
+            .validate_privilege(&object, UserPrivilegeType::Select, false)
+            .await
+            .is_ok()
+    }
+
     fn enter_table_reference(&mut self, table_ref: &TableReference) {
         if self.cache_miss {
             return;
@@ -214,7 +267,11 @@ impl TableRefVisitor {
                     if !table_meta.is_temp()
                         && !table_meta.is_stage_table()
                         && !table_meta.is_stream()
+                        && table_meta.get_table_info().meta.row_access_policy.is_none()
                         && let Some(sn) = table_meta.options().get(OPT_KEY_SNAPSHOT_LOCATION)
+                        && self
+                            .has_table_select_privilege(&catalog_name, &database_name, &table_meta)
+                            .await
                     {
                         self.schema_snapshots
                             .push((table_meta.schema(), sn.clone()));
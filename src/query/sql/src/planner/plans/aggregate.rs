@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
@@ -254,12 +255,31 @@ impl Operator for Aggregate {
             cardinality
         } else {
             // A upper bound
-            let res = self.group_items.iter().fold(1.0, |acc, item| {
+            let mut res = self.group_items.iter().fold(1.0, |acc, item| {
                 let item_stat = statistics.column_stats.get(&item.index).unwrap();
                 acc * item_stat.ndv
             });
+            // The distinct values of a group of correlated columns are much less
+            // than the product of the distinct values of each column.
+            let mut grouped_columns = HashSet::new();
+            for group in statistics.column_groups.iter() {
+                if group.columns.iter().all(|column| {
+                    !grouped_columns.contains(column)
+                        && self.group_items.iter().any(|item| item.index == *column)
+                }) {
+                    let ndv = group.columns.iter().fold(1.0, |acc, column| {
+                        acc * statistics.column_stats.get(column).unwrap().ndv
+                    });
+                    if group.ndv < ndv {
+                        res = res / ndv * group.ndv;
+                    }
+                    grouped_columns.extend(group.columns.iter().copied());
+                }
+            }
             for item in self.group_items.iter() {
                 let item_stat = statistics.column_stats.get_mut(&item.index).unwrap();
+                // Each group appears only once after aggregation.
+                item_stat.most_common_values = None;
                 if let Some(histogram) = &mut item_stat.histogram {
                     let mut num_values = 0.0;
                     let mut num_distinct = 0.0;
@@ -290,6 +310,7 @@ impl Operator for Aggregate {
             statistics: Statistics {
                 precise_cardinality,
                 column_stats: statistics.column_stats,
+                column_groups: statistics.column_groups,
             },
         }))
     }
@@ -207,6 +207,7 @@ impl Operator for ConstantTableScan {
                 ndv: ndv as f64,
                 null_count,
                 histogram,
+                most_common_values: None,
             };
             column_stats.insert(*index, column_stat);
         }
@@ -215,6 +216,7 @@ impl Operator for ConstantTableScan {
             statistics: Statistics {
                 precise_cardinality: Some(self.num_rows as u64),
                 column_stats,
+                column_groups: vec![],
             },
         }))
     }
@@ -41,3 +41,10 @@ pub struct CreateDynamicTablePlan {
     pub refresh_mode: RefreshMode,
     pub initialize: InitializeMode,
 }
+
+#[derive(Clone, Debug)]
+pub struct RefreshDynamicTablePlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
@@ -18,6 +18,7 @@ use databend_common_ast::ast::TableIndexType;
 use databend_common_expression::ColumnId;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::IndexMeta;
+use databend_common_meta_app::schema::TableIndexType as MetaTableIndexType;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_types::MetaId;
 use databend_storages_common_table_meta::meta::Location;
@@ -57,6 +58,7 @@ pub struct RefreshIndexPlan {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateTableIndexPlan {
     pub create_option: CreateOption,
+    pub index_type: MetaTableIndexType,
     pub catalog: String,
     pub index_name: String,
     pub column_ids: Vec<ColumnId>,
@@ -68,6 +70,7 @@ pub struct CreateTableIndexPlan {
 /// Drop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropTableIndexPlan {
+    pub index_type: MetaTableIndexType,
     pub if_exists: bool,
     pub catalog: String,
     pub index_name: String,
@@ -76,9 +79,18 @@ pub struct DropTableIndexPlan {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RefreshTableIndexPlan {
+    pub index_type: MetaTableIndexType,
     pub catalog: String,
     pub database: String,
     pub table: String,
     pub index_name: String,
     pub segment_locs: Option<Vec<Location>>,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshTableNgramIndexPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub limit: Option<u64>,
+}
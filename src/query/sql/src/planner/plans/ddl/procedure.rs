@@ -27,6 +27,8 @@ use databend_common_meta_app::tenant::Tenant;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ExecuteImmediatePlan {
     pub script: String,
+    /// The id of the called procedure if the script is the body of a procedure without arguments.
+    pub procedure_id: Option<u64>,
 }
 
 impl ExecuteImmediatePlan {
@@ -84,6 +86,7 @@ impl From<&DropProcedurePlan> for DropProcedureReq {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct CallProcedurePlan {
+    pub procedure_id: u64,
     pub script: String,
     pub arg_names: Vec<String>,
     pub args: Vec<Expr>,
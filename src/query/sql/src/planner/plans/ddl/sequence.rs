@@ -12,6 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_meta_app::schema::AlterSequenceAction;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::SequenceIdent;
 
@@ -19,6 +27,9 @@ use databend_common_meta_app::schema::SequenceIdent;
 pub struct CreateSequencePlan {
     pub create_option: CreateOption,
     pub ident: SequenceIdent,
+    pub start: u64,
+    pub step: i64,
+    pub cache: u64,
     pub comment: Option<String>,
 }
 
@@ -27,3 +38,30 @@ pub struct DropSequencePlan {
     pub ident: SequenceIdent,
     pub if_exists: bool,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterSequencePlan {
+    pub ident: SequenceIdent,
+    pub if_exists: bool,
+    pub action: AlterSequenceAction,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescSequencePlan {
+    pub ident: SequenceIdent,
+}
+
+impl DescSequencePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::new(vec![
+            DataField::new("name", DataType::String),
+            DataField::new("start", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("increment", DataType::Number(NumberDataType::Int64)),
+            DataField::new("next_value", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("cache", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("comment", DataType::String),
+            DataField::new("created_on", DataType::Timestamp),
+            DataField::new("updated_on", DataType::Timestamp),
+        ]))
+    }
+}
@@ -23,6 +23,7 @@ use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
@@ -54,6 +55,7 @@ pub struct CreateTablePlan {
     pub options: TableOptions,
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
+    pub partition_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
     pub inverted_indexes: Option<BTreeMap<String, TableIndex>>,
 }
@@ -404,6 +406,23 @@ impl TruncateTablePlan {
     }
 }
 
+/// Drop a partition of a partitioned table.
+#[derive(Clone, Debug)]
+pub struct DropTablePartitionPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub if_exists: bool,
+    /// The value of the partition key, cast to the type of the key.
+    pub partition_value: Scalar,
+}
+
+impl DropTablePartitionPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// Undrop.
 #[derive(Clone, Debug)]
 pub struct UndropTablePlan {
@@ -479,3 +498,36 @@ impl DropTableClusterKeyPlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+/// Add row access policy to a table.
+#[derive(Clone, Debug)]
+pub struct AddTableRowAccessPolicyPlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+impl AddTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Drop row access policy from a table.
+#[derive(Clone, Debug)]
+pub struct DropTableRowAccessPolicyPlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+}
+
+impl DropTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
@@ -89,6 +89,7 @@ pub struct CreateTaskPlan {
     pub when_condition: Option<String>,
     pub suspend_task_after_num_failures: Option<u64>,
     pub error_integration: Option<String>,
+    pub success_integration: Option<String>,
     pub session_parameters: BTreeMap<String, String>,
     pub sql: TaskSql,
     pub comment: Option<String>,
@@ -24,7 +24,6 @@ use crate::optimizer::RelationalProperty;
 use crate::optimizer::SelectivityEstimator;
 use crate::optimizer::StatInfo;
 use crate::optimizer::Statistics;
-use crate::optimizer::MAX_SELECTIVITY;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -88,25 +87,22 @@ impl Operator for Filter {
             (stat_info.cardinality, stat_info.statistics.clone());
         // Derive cardinality
         let mut sb = SelectivityEstimator::new(&mut statistics, HashSet::new());
-        let mut selectivity = MAX_SELECTIVITY;
-        for pred in self.predicates.iter() {
-            // Compute selectivity for each conjunction
-            selectivity = selectivity.min(sb.compute_selectivity(pred, true)?);
-        }
+        let selectivity = sb.compute_conjunction_selectivity(&self.predicates, true)?;
         // Update other columns's statistic according to selectivity.
         sb.update_other_statistic_by_selectivity(selectivity);
         let cardinality = input_cardinality * selectivity;
         // Derive column statistics
-        let column_stats = if cardinality == 0.0 {
-            HashMap::new()
+        let (column_stats, column_groups) = if cardinality == 0.0 {
+            (HashMap::new(), vec![])
         } else {
-            statistics.column_stats
+            (statistics.column_stats, statistics.column_groups)
         };
         Ok(Arc::new(StatInfo {
             cardinality,
             statistics: Statistics {
                 precise_cardinality: None,
                 column_stats,
+                column_groups,
             },
         }))
     }
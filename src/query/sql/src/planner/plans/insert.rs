@@ -52,6 +52,9 @@ pub struct Insert {
     pub table: String,
     pub schema: TableSchemaRef,
     pub overwrite: bool,
+    // The partition replaced by `INSERT OVERWRITE ... PARTITION`, the other
+    // partitions of the table are kept.
+    pub overwrite_partition: Option<Scalar>,
     pub source: InsertInputSource,
     // if a table with fixed table id, and version should be used,
     // it should be provided as some `table_info`.
@@ -90,6 +93,7 @@ impl Insert {
             table,
             schema,
             overwrite,
+            overwrite_partition,
             // table_info only used create table as select.
             table_info: _,
             source,
@@ -103,11 +107,16 @@ impl Insert {
             .collect::<Vec<_>>()
             .join(",");
 
-        let children = vec![
+        let mut children = vec![
             FormatTreeNode::new(format!("table: {table_name}")),
             FormatTreeNode::new(format!("inserted columns: [{inserted_columns}]")),
             FormatTreeNode::new(format!("overwrite: {overwrite}")),
         ];
+        if let Some(partition_value) = overwrite_partition {
+            children.push(FormatTreeNode::new(format!(
+                "overwrite partition: {partition_value}"
+            )));
+        }
 
         let formatted_plan = format_insert_source("InsertPlan", source, verbose, children)?;
 
@@ -214,6 +223,7 @@ impl std::fmt::Debug for Insert {
             .field("table", &self.table)
             .field("schema", &self.schema)
             .field("overwrite", &self.overwrite)
+            .field("overwrite_partition", &self.overwrite_partition)
             .finish()
     }
 }
@@ -252,6 +252,12 @@ impl Join {
         let mut join_card_updated = false;
         let mut left_column_index = 0;
         let mut right_column_index = 0;
+        let column_groups_card = self.evaluate_by_column_groups(
+            *left_cardinality,
+            *right_cardinality,
+            left_statistics,
+            right_statistics,
+        );
         for condition in self.equi_conditions.iter() {
             let left_condition = &condition.left;
             let right_condition = &condition.right;
@@ -289,47 +295,38 @@ impl Join {
                     let mut new_ndv = None;
                     let (new_min, new_max) = left_interval.intersection(&right_interval)?;
 
-                    if let Datum::Bytes(_) | Datum::Bool(_) = left_col_stat.min {
-                        let card = evaluate_by_ndv(
+                    let card = if let Some(card) = evaluate_by_most_common_values(
+                        left_col_stat,
+                        right_col_stat,
+                        *left_cardinality,
+                        *right_cardinality,
+                        &mut new_ndv,
+                    ) {
+                        card
+                    } else if let Datum::Bytes(_) | Datum::Bool(_) = left_col_stat.min {
+                        evaluate_by_ndv(
                             left_col_stat,
                             right_col_stat,
                             *left_cardinality,
                             *right_cardinality,
                             &mut new_ndv,
-                        );
-                        let (left_index, right_index) = update_statistic(
-                            left_statistics,
-                            right_statistics,
-                            left_condition,
-                            right_condition,
-                            NewStatistic {
-                                min: new_min,
-                                max: new_max,
-                                ndv: new_ndv,
-                            },
-                        );
-                        if card < join_card {
-                            join_card = card;
-                            join_card_updated = true;
-                            left_column_index = left_index;
-                            right_column_index = right_index;
-                        }
-                        continue;
-                    }
-                    let card = match (&left_col_stat.histogram, &right_col_stat.histogram) {
-                        (Some(left_hist), Some(right_hist)) => {
-                            // Evaluate join cardinality by histogram.
-                            let (left_hist, right_hist) =
-                                trim_buckets(left_hist, right_hist, &new_min, &new_max)?;
-                            evaluate_by_histogram(&left_hist, &right_hist, &mut new_ndv)?
+                        )
+                    } else {
+                        match (&left_col_stat.histogram, &right_col_stat.histogram) {
+                            (Some(left_hist), Some(right_hist)) => {
+                                // Evaluate join cardinality by histogram.
+                                let (left_hist, right_hist) =
+                                    trim_buckets(left_hist, right_hist, &new_min, &new_max)?;
+                                evaluate_by_histogram(&left_hist, &right_hist, &mut new_ndv)?
+                            }
+                            _ => evaluate_by_ndv(
+                                left_col_stat,
+                                right_col_stat,
+                                *left_cardinality,
+                                *right_cardinality,
+                                &mut new_ndv,
+                            ),
                         }
-                        _ => evaluate_by_ndv(
-                            left_col_stat,
-                            right_col_stat,
-                            *left_cardinality,
-                            *right_cardinality,
-                            &mut new_ndv,
-                        ),
                     };
                     let (left_index, right_index) = update_statistic(
                         left_statistics,
@@ -352,6 +349,9 @@ impl Join {
                 _ => continue,
             }
         }
+        if let Some(card) = column_groups_card {
+            join_card = join_card.min(card);
+        }
         if join_card_updated {
             for (idx, left) in left_statistics.column_stats.iter_mut() {
                 if *idx == left_column_index {
@@ -414,6 +414,38 @@ impl Join {
         Ok(join_card)
     }
 
+    // Evaluate the cardinality of the join on multiple columns by the distinct values of
+    // the column groups, the columns of the join keys are usually correlated.
+    fn evaluate_by_column_groups(
+        &self,
+        left_cardinality: f64,
+        right_cardinality: f64,
+        left_statistics: &Statistics,
+        right_statistics: &Statistics,
+    ) -> Option<f64> {
+        let mut left_columns = HashSet::new();
+        let mut right_columns = HashSet::new();
+        for condition in self.equi_conditions.iter() {
+            let left_used_columns = condition.left.used_columns();
+            let right_used_columns = condition.right.used_columns();
+            if left_used_columns.len() == 1 && right_used_columns.len() == 1 {
+                left_columns.extend(left_used_columns);
+                right_columns.extend(right_used_columns);
+            }
+        }
+        if left_columns.len() < 2 || right_columns.len() < 2 {
+            return None;
+        }
+
+        let (left_ndv, left_grouped) = joint_distinct_values(&left_columns, left_statistics);
+        let (right_ndv, right_grouped) = joint_distinct_values(&right_columns, right_statistics);
+        let max_ndv = f64::max(left_ndv, right_ndv);
+        if !(left_grouped || right_grouped) || max_ndv < 1.0 {
+            return None;
+        }
+        Some(left_cardinality * right_cardinality / max_ndv)
+    }
+
     pub fn has_null_equi_condition(&self) -> bool {
         self.equi_conditions
             .iter()
@@ -456,19 +488,22 @@ impl Join {
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
         };
         // Derive column statistics
-        let column_stats = if cardinality == 0.0 {
-            HashMap::new()
+        let (column_stats, column_groups) = if cardinality == 0.0 {
+            (HashMap::new(), vec![])
         } else {
             let mut column_stats = HashMap::new();
             column_stats.extend(left_statistics.column_stats);
             column_stats.extend(right_statistics.column_stats);
-            column_stats
+            let mut column_groups = left_statistics.column_groups;
+            column_groups.extend(right_statistics.column_groups);
+            (column_stats, column_groups)
         };
         Ok(Arc::new(StatInfo {
             cardinality,
             statistics: Statistics {
                 precise_cardinality: None,
                 column_stats,
+                column_groups,
             },
         }))
     }
@@ -902,9 +937,103 @@ fn update_statistic(
         left_col_stat.ndv = new_ndv;
         right_col_stat.ndv = new_ndv;
     }
+    // The frequencies of the values are changed after join.
+    left_col_stat.most_common_values = None;
+    right_col_stat.most_common_values = None;
     (left_index, right_index)
 }
 
+// Returns the lower bound of the joint distinct values of the columns, and whether
+// any column group within the columns is used.
+fn joint_distinct_values(columns: &HashSet<IndexType>, statistics: &Statistics) -> (f64, bool) {
+    let group_ndv = statistics
+        .column_groups
+        .iter()
+        .filter(|group| group.columns.iter().all(|column| columns.contains(column)))
+        .map(|group| group.ndv)
+        .max_by(|l, r| l.total_cmp(r));
+    let column_ndv = columns
+        .iter()
+        .filter_map(|column| statistics.column_stats.get(column))
+        .map(|stat| stat.ndv)
+        .max_by(|l, r| l.total_cmp(r))
+        .unwrap_or(0.0);
+    match group_ndv {
+        Some(group_ndv) => (group_ndv.max(column_ndv), true),
+        None => (column_ndv, false),
+    }
+}
+
+// Evaluate the cardinality by the most common values of both sides, it's the same
+// as `eqjoinsel` of PostgreSQL. The matched most common values are joined exactly,
+// and the rest of the values are assumed to be uniformly distributed.
+fn evaluate_by_most_common_values(
+    left_stat: &ColumnStat,
+    right_stat: &ColumnStat,
+    left_cardinality: f64,
+    right_cardinality: f64,
+    new_ndv: &mut Option<f64>,
+) -> Option<f64> {
+    let (Some(left_mcv), Some(right_mcv)) = (
+        &left_stat.most_common_values,
+        &right_stat.most_common_values,
+    ) else {
+        return None;
+    };
+    if left_mcv.is_empty() || right_mcv.is_empty() {
+        return None;
+    }
+
+    let mut matched_freq = 0.0;
+    let mut left_matched_freq = 0.0;
+    let mut right_matched_freq = 0.0;
+    let mut num_matched = 0.0;
+    for left_value in left_mcv.values_iter() {
+        if let Some(right_freq) = right_mcv.frequency_of(&left_value.value) {
+            matched_freq += left_value.frequency * right_freq;
+            left_matched_freq += left_value.frequency;
+            right_matched_freq += right_freq;
+            num_matched += 1.0;
+        }
+    }
+
+    let left_total_freq = left_mcv.total_frequency();
+    let right_total_freq = right_mcv.total_frequency();
+    let (left_unmatched_freq, right_unmatched_freq) = (
+        left_total_freq - left_matched_freq,
+        right_total_freq - right_matched_freq,
+    );
+    let (left_other_freq, right_other_freq) = (1.0 - left_total_freq, 1.0 - right_total_freq);
+    let (left_ndv, right_ndv) = (left_stat.ndv, right_stat.ndv);
+    let (left_len, right_len) = (left_mcv.len() as f64, right_mcv.len() as f64);
+
+    let mut left_selectivity = matched_freq;
+    if right_ndv > right_len {
+        left_selectivity += left_unmatched_freq * right_other_freq / (right_ndv - right_len);
+    }
+    if right_ndv > num_matched {
+        left_selectivity +=
+            left_other_freq * (right_other_freq + right_unmatched_freq) / (right_ndv - num_matched);
+    }
+    let mut right_selectivity = matched_freq;
+    if left_ndv > left_len {
+        right_selectivity += right_unmatched_freq * left_other_freq / (left_ndv - left_len);
+    }
+    if left_ndv > num_matched {
+        right_selectivity +=
+            right_other_freq * (left_other_freq + left_unmatched_freq) / (left_ndv - num_matched);
+    }
+
+    // Update column ndv
+    *new_ndv = Some(left_ndv.min(right_ndv));
+
+    // The frequencies are relative to the non-null rows, which are the only ones to match.
+    let selectivity = left_selectivity.min(right_selectivity).clamp(0.0, 1.0)
+        * (1.0 - left_mcv.null_fraction)
+        * (1.0 - right_mcv.null_fraction);
+    Some(left_cardinality * right_cardinality * selectivity)
+}
+
 fn trim_histogram_buckets(
     hist: &Histogram,
     min: &Option<Datum>,
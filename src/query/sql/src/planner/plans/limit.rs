@@ -83,6 +83,7 @@ impl Operator for Limit {
             statistics: Statistics {
                 precise_cardinality,
                 column_stats: Default::default(),
+                column_groups: vec![],
             },
         }))
     }
@@ -40,10 +40,12 @@ mod optimize;
 mod plan;
 mod presign;
 mod project_set;
+mod query_hint;
 mod r_cte_scan;
 mod recluster;
 mod replace;
 mod revert_table;
+mod row_access_policy;
 mod scalar_expr;
 mod scan;
 mod set;
@@ -87,10 +89,12 @@ pub use optimize::*;
 pub use plan::*;
 pub use presign::*;
 pub use project_set::*;
+pub use query_hint::*;
 pub use r_cte_scan::*;
 pub use recluster::Recluster;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
+pub use row_access_policy::*;
 pub use scalar_expr::*;
 pub use scan::*;
 pub use set::*;
@@ -31,9 +31,11 @@ use crate::binder::ExplainConfig;
 use crate::optimizer::SExpr;
 use crate::plans::copy_into_location::CopyIntoLocationPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterNotificationPlan;
 use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::AlterSequencePlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterTaskPlan;
 use crate::plans::AlterUDFPlan;
@@ -55,7 +57,9 @@ use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreateNotificationPlan;
 use crate::plans::CreatePasswordPolicyPlan;
 use crate::plans::CreateProcedurePlan;
+use crate::plans::CreateQueryHintPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRowAccessPolicyPlan;
 use crate::plans::CreateSequencePlan;
 use crate::plans::CreateStagePlan;
 use crate::plans::CreateStreamPlan;
@@ -71,6 +75,8 @@ use crate::plans::DescDatamaskPolicyPlan;
 use crate::plans::DescNetworkPolicyPlan;
 use crate::plans::DescNotificationPlan;
 use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
+use crate::plans::DescSequencePlan;
 use crate::plans::DescUserPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
@@ -85,14 +91,18 @@ use crate::plans::DropNetworkPolicyPlan;
 use crate::plans::DropNotificationPlan;
 use crate::plans::DropPasswordPolicyPlan;
 use crate::plans::DropProcedurePlan;
+use crate::plans::DropQueryHintPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
 use crate::plans::DropSequencePlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTableIndexPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
@@ -112,8 +122,10 @@ use crate::plans::ModifyTableCommentPlan;
 use crate::plans::OptimizeCompactSegmentPlan;
 use crate::plans::OptimizePurgePlan;
 use crate::plans::PresignPlan;
+use crate::plans::RefreshDynamicTablePlan;
 use crate::plans::RefreshIndexPlan;
 use crate::plans::RefreshTableIndexPlan;
+use crate::plans::RefreshTableNgramIndexPlan;
 use crate::plans::RefreshVirtualColumnPlan;
 use crate::plans::RelOperator;
 use crate::plans::RemoveStagePlan;
@@ -135,6 +147,7 @@ use crate::plans::ShowCreateDatabasePlan;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowNetworkPoliciesPlan;
+use crate::plans::ShowQueryHintsPlan;
 use crate::plans::ShowRolesPlan;
 use crate::plans::ShowTasksPlan;
 use crate::plans::SystemPlan;
@@ -176,7 +189,10 @@ pub enum Plan {
     },
     ExplainAnalyze {
         partial: bool,
+        json: bool,
         plan: Box<Plan>,
+        /// Fingerprint of the query hint applied to the explained statement.
+        query_hint: Option<String>,
     },
 
     // Call is rewrite into Query
@@ -209,12 +225,15 @@ pub enum Plan {
     ModifyTableColumn(Box<ModifyTableColumnPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
     ReclusterTable {
         s_expr: Box<SExpr>,
         is_final: bool,
     },
     RevertTable(Box<RevertTablePlan>),
     TruncateTable(Box<TruncateTablePlan>),
+    DropTablePartition(Box<DropTablePartitionPlan>),
     VacuumTable(Box<VacuumTablePlan>),
     VacuumDropTable(Box<VacuumDropTablePlan>),
     VacuumTemporaryFiles(Box<VacuumTemporaryFilesPlan>),
@@ -261,6 +280,7 @@ pub enum Plan {
     CreateTableIndex(Box<CreateTableIndexPlan>),
     DropTableIndex(Box<DropTableIndexPlan>),
     RefreshTableIndex(Box<RefreshTableIndexPlan>),
+    RefreshTableNgramIndex(Box<RefreshTableNgramIndexPlan>),
 
     // Virtual Columns
     CreateVirtualColumn(Box<CreateVirtualColumnPlan>),
@@ -321,6 +341,11 @@ pub enum Plan {
     DropDatamaskPolicy(Box<DropDatamaskPolicyPlan>),
     DescDatamaskPolicy(Box<DescDatamaskPolicyPlan>),
 
+    // Row access policy
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+    DescRowAccessPolicy(Box<DescRowAccessPolicyPlan>),
+
     // Network policy
     CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
     AlterNetworkPolicy(Box<AlterNetworkPolicyPlan>),
@@ -334,6 +359,11 @@ pub enum Plan {
     DropPasswordPolicy(Box<DropPasswordPolicyPlan>),
     DescPasswordPolicy(Box<DescPasswordPolicyPlan>),
 
+    // Query hint
+    CreateQueryHint(Box<CreateQueryHintPlan>),
+    DropQueryHint(Box<DropQueryHintPlan>),
+    ShowQueryHints(Box<ShowQueryHintsPlan>),
+
     // Task
     CreateTask(Box<CreateTaskPlan>),
     AlterTask(Box<AlterTaskPlan>),
@@ -343,6 +373,7 @@ pub enum Plan {
     ExecuteTask(Box<ExecuteTaskPlan>),
 
     CreateDynamicTable(Box<CreateDynamicTablePlan>),
+    RefreshDynamicTable(Box<RefreshDynamicTablePlan>),
 
     // Txn
     Begin,
@@ -366,6 +397,8 @@ pub enum Plan {
     // sequence
     CreateSequence(Box<CreateSequencePlan>),
     DropSequence(Box<DropSequencePlan>),
+    AlterSequence(Box<AlterSequencePlan>),
+    DescSequence(Box<DescSequencePlan>),
 
     // Dictionary
     CreateDictionary(Box<CreateDictionaryPlan>),
@@ -408,6 +441,7 @@ pub enum RewriteKind {
 
     Call,
     ShowProcedures,
+    ShowSequences,
 }
 
 impl Plan {
@@ -472,9 +506,14 @@ impl Plan {
             Plan::CreateDatamaskPolicy(plan) => plan.schema(),
             Plan::DropDatamaskPolicy(plan) => plan.schema(),
             Plan::DescDatamaskPolicy(plan) => plan.schema(),
+            Plan::CreateRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropRowAccessPolicy(plan) => plan.schema(),
+            Plan::DescRowAccessPolicy(plan) => plan.schema(),
+            Plan::DescSequence(plan) => plan.schema(),
             Plan::DescNetworkPolicy(plan) => plan.schema(),
             Plan::ShowNetworkPolicies(plan) => plan.schema(),
             Plan::DescPasswordPolicy(plan) => plan.schema(),
+            Plan::ShowQueryHints(plan) => plan.schema(),
             Plan::CopyIntoTable(plan) => plan.schema(),
             Plan::CopyIntoLocation(plan) => plan.schema(),
             Plan::CreateTask(plan) => plan.schema(),
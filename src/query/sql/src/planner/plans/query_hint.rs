@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_app::principal::QueryHint;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateQueryHintPlan {
+    pub create_option: CreateOption,
+    pub tenant: Tenant,
+    pub fingerprint: String,
+    pub query: String,
+    pub join_order: Option<String>,
+    pub join_distribution: Option<String>,
+    pub settings: BTreeMap<String, String>,
+    pub comment: Option<String>,
+}
+
+impl CreateQueryHintPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+impl From<CreateQueryHintPlan> for QueryHint {
+    fn from(p: CreateQueryHintPlan) -> Self {
+        QueryHint {
+            fingerprint: p.fingerprint,
+            query: p.query,
+            join_order: p.join_order,
+            join_distribution: p.join_distribution,
+            settings: p.settings,
+            comment: p.comment.unwrap_or_default(),
+            create_on: Utc::now(),
+            update_on: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropQueryHintPlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub fingerprint: String,
+}
+
+impl DropQueryHintPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShowQueryHintsPlan {}
+
+impl ShowQueryHintsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("Fingerprint", DataType::String),
+            DataField::new("Query", DataType::String),
+            DataField::new("Join Order", DataType::String),
+            DataField::new("Join Distribution", DataType::String),
+            DataField::new("Settings", DataType::String),
+            DataField::new("Comment", DataType::String),
+            DataField::new("Created On", DataType::Timestamp),
+        ])
+    }
+}
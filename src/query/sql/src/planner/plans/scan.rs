@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use databend_common_ast::ast::SampleConfig;
 use databend_common_catalog::plan::InvertedIndexInfo;
+use databend_common_catalog::plan::VectorIndexInfo;
 use databend_common_catalog::statistics::BasicColumnStatistics;
 use databend_common_catalog::table::TableStatistics;
 use databend_common_catalog::table_context::TableContext;
@@ -25,12 +26,14 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
 use databend_common_storage::Histogram;
+use databend_common_storage::MostCommonValues;
 use databend_common_storage::DEFAULT_HISTOGRAM_BUCKETS;
 use databend_storages_common_table_meta::table::ChangeType;
 use itertools::Itertools;
 
 use super::ScalarItem;
 use crate::optimizer::histogram_from_ndv;
+use crate::optimizer::ColumnGroupStat;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
 use crate::optimizer::ColumnStatSet;
@@ -42,7 +45,6 @@ use crate::optimizer::RequiredProperty;
 use crate::optimizer::SelectivityEstimator;
 use crate::optimizer::StatInfo;
 use crate::optimizer::Statistics as OpStatistics;
-use crate::optimizer::MAX_SELECTIVITY;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -89,6 +91,8 @@ pub struct Statistics {
     // statistics will be ignored in comparison and hashing
     pub column_stats: HashMap<IndexType, Option<BasicColumnStatistics>>,
     pub histograms: HashMap<IndexType, Option<Histogram>>,
+    pub most_common_values: HashMap<IndexType, Option<MostCommonValues>>,
+    pub column_groups: Vec<ColumnGroupStat>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -104,6 +108,7 @@ pub struct Scan {
     // Whether to update stream columns.
     pub update_stream_columns: bool,
     pub inverted_index: Option<InvertedIndexInfo>,
+    pub vector_index: Option<VectorIndexInfo>,
     // Lazy row fetch.
     pub is_lazy_table: bool,
     pub sample: Option<SampleConfig>,
@@ -129,6 +134,22 @@ impl Scan {
             .map(|(col, hist)| (*col, hist.clone()))
             .collect();
 
+        let most_common_values = self
+            .statistics
+            .most_common_values
+            .iter()
+            .filter(|(col, _)| columns.contains(*col))
+            .map(|(col, mcv)| (*col, mcv.clone()))
+            .collect();
+
+        let column_groups = self
+            .statistics
+            .column_groups
+            .iter()
+            .filter(|group| group.columns.iter().all(|col| columns.contains(col)))
+            .cloned()
+            .collect();
+
         Scan {
             table_index: self.table_index,
             columns,
@@ -139,12 +160,15 @@ impl Scan {
                 table_stats: self.statistics.table_stats,
                 column_stats,
                 histograms,
+                most_common_values,
+                column_groups,
             }),
             prewhere,
             agg_index: self.agg_index.clone(),
             change_type: self.change_type.clone(),
             update_stream_columns: self.update_stream_columns,
             inverted_index: self.inverted_index.clone(),
+            vector_index: self.vector_index.clone(),
             is_lazy_table: self.is_lazy_table,
             sample: self.sample.clone(),
         }
@@ -247,17 +271,33 @@ impl Operator for Scan {
                     )
                     .ok()
                 };
+                let most_common_values =
+                    self.statistics.most_common_values.get(k).cloned().flatten();
                 let column_stat = ColumnStat {
                     min,
                     max,
                     ndv: ndv as f64,
                     null_count: col_stat.null_count,
                     histogram,
+                    most_common_values,
                 };
                 column_stats.insert(*k as IndexType, column_stat);
             }
         }
 
+        let mut column_groups: Vec<ColumnGroupStat> = self
+            .statistics
+            .column_groups
+            .iter()
+            .filter(|group| {
+                group
+                    .columns
+                    .iter()
+                    .all(|col| column_stats.contains_key(col))
+            })
+            .cloned()
+            .collect();
+
         let precise_cardinality = self
             .statistics
             .table_stats
@@ -269,17 +309,15 @@ impl Operator for Scan {
                 let mut statistics = OpStatistics {
                     precise_cardinality: Some(precise_cardinality),
                     column_stats,
+                    column_groups,
                 };
                 // Derive cardinality
                 let mut sb = SelectivityEstimator::new(&mut statistics, HashSet::new());
-                let mut selectivity = MAX_SELECTIVITY;
-                for pred in prewhere.predicates.iter() {
-                    // Compute selectivity for each conjunction
-                    selectivity = selectivity.min(sb.compute_selectivity(pred, true)?);
-                }
+                let selectivity = sb.compute_conjunction_selectivity(&prewhere.predicates, true)?;
                 // Update other columns's statistic according to selectivity.
                 sb.update_other_statistic_by_selectivity(selectivity);
                 column_stats = statistics.column_stats;
+                column_groups = statistics.column_groups;
                 (precise_cardinality as f64) * selectivity
             }
             (Some(precise_cardinality), None) => precise_cardinality as f64,
@@ -297,6 +335,7 @@ impl Operator for Scan {
             statistics: OpStatistics {
                 precise_cardinality,
                 column_stats,
+                column_groups,
             },
         }))
     }
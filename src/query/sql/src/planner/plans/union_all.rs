@@ -135,6 +135,7 @@ impl Operator for UnionAll {
             statistics: Statistics {
                 precise_cardinality,
                 column_stats: Default::default(),
+                column_groups: vec![],
             },
         }))
     }
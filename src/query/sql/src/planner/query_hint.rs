@@ -0,0 +1,260 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::QueryHint;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_users::UserApiProvider;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+use futures::StreamExt;
+use log::info;
+use log::warn;
+use md5::Digest;
+use md5::Md5;
+use parking_lot::Mutex;
+
+use crate::Planner;
+
+/// Join order of a query hint that keeps the joins in the order written in the query.
+pub const QUERY_HINT_JOIN_ORDER_FIXED: &str = "FIXED";
+/// Join distribution of a query hint that broadcasts the build side to every node.
+pub const QUERY_HINT_JOIN_DISTRIBUTION_BROADCAST: &str = "BROADCAST";
+/// Join distribution of a query hint that shuffles both sides by the join keys.
+pub const QUERY_HINT_JOIN_DISTRIBUTION_SHUFFLE: &str = "SHUFFLE";
+
+/// How long the query hints of a tenant are cached before they are listed again,
+/// in case a change on another node is missed by the watch.
+const REGISTRY_TTL: Duration = Duration::from_secs(60);
+
+static QUERY_HINT_REGISTRY: LazyLock<QueryHintRegistry> = LazyLock::new(QueryHintRegistry::default);
+
+struct TenantQueryHints {
+    loaded_at: Instant,
+    /// The query hints of the tenant, by query fingerprint.
+    by_fingerprint: HashMap<String, QueryHint>,
+}
+
+/// The query hints of each tenant, by query fingerprint.
+///
+/// The query hints of a tenant are listed once per `REGISTRY_TTL`, not once per query.
+/// The query hints created or dropped on this node invalidate their tenant at once,
+/// the ones changed on other nodes are found by watching the query hints in meta.
+#[derive(Default)]
+pub struct QueryHintRegistry {
+    tenants: Mutex<HashMap<String, Arc<TenantQueryHints>>>,
+    /// The tenants whose query hints are watched.
+    watched: Mutex<HashSet<String>>,
+}
+
+impl QueryHintRegistry {
+    pub fn instance() -> &'static QueryHintRegistry {
+        LazyLock::force(&QUERY_HINT_REGISTRY)
+    }
+
+    /// Forget the query hints of a tenant, after one of them is created or dropped.
+    pub fn invalidate(&self, tenant: &Tenant) {
+        self.tenants.lock().remove(tenant.tenant_name());
+    }
+
+    /// The query hint pinned to `fingerprint`, if any.
+    #[async_backtrace::framed]
+    pub async fn get(
+        &'static self,
+        tenant: &Tenant,
+        fingerprint: &str,
+    ) -> Result<Option<QueryHint>> {
+        let cached = self
+            .tenants
+            .lock()
+            .get(tenant.tenant_name())
+            .filter(|hints| hints.loaded_at.elapsed() < REGISTRY_TTL)
+            .cloned();
+        let hints = match cached {
+            Some(hints) => hints,
+            None => {
+                self.spawn_watcher(tenant);
+                let by_fingerprint = UserApiProvider::instance()
+                    .get_query_hints(tenant)
+                    .await?
+                    .into_iter()
+                    .map(|hint| (hint.fingerprint.clone(), hint))
+                    .collect();
+                let hints = Arc::new(TenantQueryHints {
+                    loaded_at: Instant::now(),
+                    by_fingerprint,
+                });
+                self.tenants
+                    .lock()
+                    .insert(tenant.tenant_name().to_string(), hints.clone());
+                hints
+            }
+        };
+        Ok(hints.by_fingerprint.get(fingerprint).cloned())
+    }
+
+    /// Watch the query hints of the tenant in meta, unless they are watched already,
+    /// and invalidate the tenant when any of them is created or dropped by any node.
+    fn spawn_watcher(&'static self, tenant: &Tenant) {
+        if !self.watched.lock().insert(tenant.tenant_name().to_string()) {
+            return;
+        }
+        let tenant = tenant.clone();
+        GlobalIORuntime::instance().spawn(async move { self.watch_loop(tenant).await });
+    }
+
+    async fn watch_loop(&self, tenant: Tenant) {
+        loop {
+            match UserApiProvider::instance().watch_query_hints(&tenant).await {
+                // Embedded meta serves only this node, where the DDL invalidates the tenant.
+                Ok(None) => return,
+                Ok(Some(mut strm)) => {
+                    // The changes before the watch is established are unknown.
+                    self.invalidate(&tenant);
+                    while let Some(resp) = strm.next().await {
+                        if let Err(cause) = resp {
+                            warn!("Query hints watch stream error, cause {:?}", cause);
+                            break;
+                        }
+                        self.invalidate(&tenant);
+                    }
+                }
+                Err(cause) => warn!("Cannot watch query hints, cause {:?}", cause),
+            }
+
+            // The changes are missed until the watch is re-established.
+            self.invalidate(&tenant);
+            sleep(Duration::from_secs(1)).await;
+        }
+    }
+}
+
+/// Computes the normalized fingerprint of a statement: the md5 of the statement
+/// with all literals replaced by `NULL`, so that queries which only differ in
+/// constants share a fingerprint.
+///
+/// SELECT * FROM t1 WHERE name = 'data' => SELECT * FROM t1 WHERE name = NULL
+/// SELECT * FROM t1 WHERE name = 'bend' => SELECT * FROM t1 WHERE name = NULL
+pub fn query_fingerprint(stmt: &Statement) -> String {
+    #[derive(VisitorMut)]
+    #[visitor(Literal(enter))]
+    struct AstVisitor;
+
+    impl AstVisitor {
+        fn enter_literal(&mut self, lit: &mut Literal) {
+            *lit = Literal::Null;
+        }
+    }
+
+    let mut stmt = stmt.clone();
+    stmt.drive_mut(&mut AstVisitor);
+    format!("{:x}", Md5::digest(stmt.to_string()))
+}
+
+/// Returns the statement whose fingerprint is used to look up query hints,
+/// `EXPLAIN` statements are matched by the statement they explain.
+pub fn query_hint_target(stmt: &Statement) -> Option<&Statement> {
+    match stmt {
+        Statement::Explain { query, .. } | Statement::ExplainAnalyze { query, .. } => {
+            query_hint_target(query)
+        }
+        Statement::Query(_)
+        | Statement::Insert(_)
+        | Statement::Replace(_)
+        | Statement::Delete(_)
+        | Statement::Update(_)
+        | Statement::MergeInto(_)
+        | Statement::CopyIntoLocation(_) => Some(stmt),
+        _ => None,
+    }
+}
+
+/// Parses the SQL text of `CREATE QUERY HINT ... FOR '<sql>'` and returns its fingerprint.
+pub fn parse_query_fingerprint(sql: &str, dialect: Dialect) -> Result<String> {
+    let tokens = tokenize_sql(sql)?;
+    let (stmt, _) = parse_sql(&tokens, dialect)?;
+    match query_hint_target(&stmt) {
+        Some(target) => Ok(query_fingerprint(target)),
+        None => Err(ErrorCode::SemanticError(format!(
+            "query hints only support queries and DML statements, but got: {}",
+            stmt
+        ))),
+    }
+}
+
+/// Translates a query hint into the settings it overrides.
+pub fn query_hint_settings(query_hint: &QueryHint) -> HashMap<String, String> {
+    let mut settings: HashMap<String, String> = query_hint
+        .settings
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+
+    if query_hint.join_order.as_deref() == Some(QUERY_HINT_JOIN_ORDER_FIXED) {
+        settings.insert("disable_join_reorder".to_string(), "1".to_string());
+    }
+    match query_hint.join_distribution.as_deref() {
+        Some(QUERY_HINT_JOIN_DISTRIBUTION_BROADCAST) => {
+            settings.insert("enforce_broadcast_join".to_string(), "1".to_string());
+        }
+        Some(QUERY_HINT_JOIN_DISTRIBUTION_SHUFFLE) => {
+            settings.insert("enforce_shuffle_join".to_string(), "1".to_string());
+        }
+        _ => {}
+    }
+    settings
+}
+
+impl Planner {
+    /// Applies the query hint pinned to the fingerprint of `stmt` to the query settings.
+    /// Returns the fingerprint if a query hint was applied.
+    #[async_backtrace::framed]
+    pub(crate) async fn apply_query_hint(&self, stmt: &Statement) -> Result<Option<String>> {
+        let settings = self.ctx.get_settings();
+        if !settings.get_enable_query_hints()? {
+            return Ok(None);
+        }
+        let Some(target) = query_hint_target(stmt) else {
+            return Ok(None);
+        };
+
+        let fingerprint = query_fingerprint(target);
+        let tenant = self.ctx.get_tenant();
+        let Some(query_hint) = QueryHintRegistry::instance()
+            .get(&tenant, &fingerprint)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        info!("apply query hint {} to query", fingerprint);
+        settings.set_batch_settings(&query_hint_settings(&query_hint))?;
+        Ok(Some(fingerprint))
+    }
+}
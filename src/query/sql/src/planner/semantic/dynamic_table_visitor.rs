@@ -0,0 +1,676 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::ChangesInterval;
+use databend_common_ast::ast::ColumnFilter;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::GroupBy;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Indirection;
+use databend_common_ast::ast::JoinCondition;
+use databend_common_ast::ast::JoinOperator;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TemporalClause;
+use databend_common_ast::ast::TimeTravelPoint;
+use databend_common_ast::ast::CTE;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+use derive_visitor::Visitor;
+use derive_visitor::VisitorMut;
+
+use crate::normalize_identifier;
+use crate::planner::SUPPORTED_DYNAMIC_TABLE_INCREMENTAL_FUNCTIONS;
+use crate::NameResolutionContext;
+
+/// The columns that `CHANGES` adds to the columns of a table.
+const CHANGE_COLUMNS: [&str; 3] = ["change$action", "change$is_update", "change$row_id"];
+
+/// How the `AS` query of a dynamic table can be refreshed incrementally.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DynamicTableQueryShape {
+    /// Filters and projections over a table, or over inner joins of tables.
+    ///
+    /// The rows produced by the inserted rows of the base tables are appended.
+    Project,
+    /// `SUM`, `COUNT`, `MIN` and `MAX` grouped by the select items at `keys`.
+    ///
+    /// The groups touched by the changes of the base tables are recomputed and merged.
+    Aggregate { keys: Vec<usize> },
+}
+
+/// A base table of the `AS` query of a dynamic table, with normalized names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicTableSource {
+    pub catalog: Option<String>,
+    pub database: Option<String>,
+    pub table: String,
+}
+
+/// How a base table is read by a query derived from the `AS` query of a dynamic table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DynamicTableSourceRead {
+    /// The table at a snapshot, or the latest version if it is `None`.
+    Snapshot(Option<String>),
+    /// The rows changed after snapshot `begin` up to snapshot `end`.
+    /// All rows at `end` are read as inserted if `begin` is `None`.
+    Changes {
+        begin: Option<String>,
+        end: String,
+        append_only: bool,
+    },
+}
+
+/// The `AS` query of a dynamic table, along with the base tables it reads
+/// and whether it can be refreshed incrementally.
+#[derive(Debug, Clone)]
+pub struct DynamicTableQuery {
+    query: Query,
+    ctes: HashSet<String>,
+    sources: Vec<DynamicTableSource>,
+    shape: Result<DynamicTableQueryShape, String>,
+}
+
+impl DynamicTableQuery {
+    pub fn new(query: Query, name_resolution_ctx: &NameResolutionContext) -> Self {
+        let mut cte_collector = CteCollector::default();
+        query.drive(&mut cte_collector);
+        let ctes = cte_collector.names;
+
+        let mut source_collector = SourceCollector {
+            ctes: &ctes,
+            name_resolution_ctx,
+            sources: vec![],
+        };
+        query.drive(&mut source_collector);
+        let sources = source_collector.sources;
+
+        let shape = check_shape(&query);
+        DynamicTableQuery {
+            query,
+            ctes,
+            sources,
+            shape,
+        }
+    }
+
+    /// Qualify the base tables without a database with `current_database`,
+    /// so that the query does not depend on the session that refreshes it.
+    pub fn with_current_database(mut self, current_database: &str) -> Self {
+        let mut qualifier = SourceQualifier {
+            ctes: &self.ctes,
+            current_database,
+        };
+        self.query.drive_mut(&mut qualifier);
+        for source in self.sources.iter_mut() {
+            if source.database.is_none() {
+                source.database = Some(current_database.to_string());
+            }
+        }
+        self
+    }
+
+    pub fn query(&self) -> &Query {
+        &self.query
+    }
+
+    /// The base tables, in the order they appear in the query.
+    pub fn sources(&self) -> &[DynamicTableSource] {
+        &self.sources
+    }
+
+    /// The shape of the query, or the reason why it can not be refreshed incrementally.
+    pub fn shape(&self) -> Result<&DynamicTableQueryShape, &str> {
+        self.shape.as_ref().map_err(|reason| reason.as_str())
+    }
+
+    /// The query with the base tables read as `reads`, which is in the same order as [`Self::sources`].
+    pub fn query_with_sources(&self, reads: &[DynamicTableSourceRead]) -> Query {
+        let mut query = self.query.clone();
+        let mut rewriter = SourceReadRewriter {
+            ctes: &self.ctes,
+            reads,
+            next: 0,
+        };
+        query.drive_mut(&mut rewriter);
+        query
+    }
+
+    /// `SELECT DISTINCT` the group keys of an aggregation query, named as `names`,
+    /// with the base tables read as `reads`.
+    pub fn group_keys_with_sources(
+        &self,
+        reads: &[DynamicTableSourceRead],
+        names: &[Identifier],
+    ) -> Query {
+        let keys = self.group_keys();
+        let mut query = self.query_with_sources(reads);
+        if let SetExpr::Select(stmt) = &mut query.body {
+            stmt.select_list = keys
+                .iter()
+                .zip(names)
+                .map(|(key, name)| SelectTarget::AliasedExpr {
+                    expr: Box::new(key.clone()),
+                    alias: Some(name.clone()),
+                })
+                .collect();
+            stmt.distinct = true;
+            stmt.group_by = None;
+        }
+        query
+    }
+
+    /// The aggregation query restricted to the groups whose keys are listed in table `groups`,
+    /// in columns named as `names`, with the base tables read as `reads`.
+    ///
+    /// A group with NULL keys is always included, the result may contain more groups than listed.
+    pub fn query_in_groups(
+        &self,
+        reads: &[DynamicTableSourceRead],
+        groups: &Identifier,
+        names: &[Identifier],
+    ) -> Query {
+        let keys = self.group_keys();
+        let mut query = self.query_with_sources(reads);
+        if let SetExpr::Select(stmt) = &mut query.body {
+            for (key, name) in keys.into_iter().zip(names) {
+                let listed = Expr::InSubquery {
+                    span: None,
+                    expr: Box::new(key.clone()),
+                    subquery: Box::new(select_query(
+                        vec![SelectTarget::AliasedExpr {
+                            expr: Box::new(column_ref(name)),
+                            alias: None,
+                        }],
+                        table_ref(groups),
+                    )),
+                    not: false,
+                };
+                let is_null = Expr::IsNull {
+                    span: None,
+                    expr: Box::new(key),
+                    not: false,
+                };
+                let predicate = Expr::BinaryOp {
+                    span: None,
+                    op: BinaryOperator::Or,
+                    left: Box::new(listed),
+                    right: Box::new(is_null),
+                };
+                stmt.selection = Some(match stmt.selection.take() {
+                    None => predicate,
+                    Some(selection) => Expr::BinaryOp {
+                        span: None,
+                        op: BinaryOperator::And,
+                        left: Box::new(selection),
+                        right: Box::new(predicate),
+                    },
+                });
+            }
+        }
+        query
+    }
+
+    fn group_keys(&self) -> Vec<Expr> {
+        let (Ok(DynamicTableQueryShape::Aggregate { keys }), SetExpr::Select(stmt)) =
+            (&self.shape, &self.query.body)
+        else {
+            return vec![];
+        };
+        keys.iter()
+            .filter_map(|position| match &stmt.select_list[*position] {
+                SelectTarget::AliasedExpr { expr, .. } => Some((**expr).clone()),
+                SelectTarget::StarColumns { .. } => None,
+            })
+            .collect()
+    }
+}
+
+fn check_shape(query: &Query) -> Result<DynamicTableQueryShape, String> {
+    if query.with.is_some() {
+        return Err("common table expressions are not supported".to_string());
+    }
+    if !query.order_by.is_empty() || !query.limit.is_empty() || query.offset.is_some() {
+        return Err("ORDER BY, LIMIT and OFFSET are not supported".to_string());
+    }
+    let SetExpr::Select(stmt) = &query.body else {
+        return Err("set operations and VALUES are not supported".to_string());
+    };
+    if stmt.distinct || stmt.top_n.is_some() {
+        return Err("DISTINCT and TOP are not supported".to_string());
+    }
+    if stmt.having.is_some() || stmt.window_list.is_some() || stmt.qualify.is_some() {
+        return Err("HAVING, WINDOW and QUALIFY are not supported".to_string());
+    }
+    if stmt.from.is_empty() {
+        return Err("the query reads no table".to_string());
+    }
+    for table_ref in stmt.from.iter() {
+        check_table_reference(table_ref)?;
+    }
+
+    let mut checker = ExprChecker::default();
+    stmt.selection.drive(&mut checker);
+    stmt.group_by.drive(&mut checker);
+    if checker.has_aggregate {
+        return Err("aggregate functions in WHERE or GROUP BY are not supported".to_string());
+    }
+
+    let mut has_star = false;
+    let mut aggregate_items = Vec::with_capacity(stmt.select_list.len());
+    for target in stmt.select_list.iter() {
+        let mut item_checker = ExprChecker::default();
+        target.drive(&mut item_checker);
+        checker.not_support = checker.not_support.or(item_checker.not_support);
+        has_star |= matches!(target, SelectTarget::StarColumns { .. });
+        aggregate_items.push(item_checker.has_aggregate);
+    }
+    if let Some(reason) = checker.not_support {
+        return Err(reason);
+    }
+
+    let has_aggregate = aggregate_items.iter().any(|v| *v);
+    match &stmt.group_by {
+        None if !has_aggregate => Ok(DynamicTableQueryShape::Project),
+        None => Err("aggregate functions without GROUP BY are not supported".to_string()),
+        Some(_) if has_star => {
+            Err("* in the select list of an aggregation is not supported".to_string())
+        }
+        Some(GroupBy::All) => {
+            let keys = aggregate_items
+                .iter()
+                .enumerate()
+                .filter(|(_, is_aggregate)| !**is_aggregate)
+                .map(|(position, _)| position)
+                .collect();
+            Ok(DynamicTableQueryShape::Aggregate { keys })
+        }
+        Some(GroupBy::Normal(exprs)) => {
+            let mut keys = Vec::with_capacity(exprs.len());
+            for expr in exprs.iter() {
+                let Some(position) = find_select_item(stmt, expr) else {
+                    return Err(format!("GROUP BY item `{expr}` must be in the select list"));
+                };
+                if aggregate_items[position] {
+                    return Err(format!("GROUP BY item `{expr}` is an aggregation"));
+                }
+                if !keys.contains(&position) {
+                    keys.push(position);
+                }
+            }
+            keys.sort();
+            Ok(DynamicTableQueryShape::Aggregate { keys })
+        }
+        Some(_) => Err("GROUPING SETS, CUBE and ROLLUP are not supported".to_string()),
+    }
+}
+
+fn check_table_reference(table_ref: &TableReference) -> Result<(), String> {
+    match table_ref {
+        TableReference::Table {
+            temporal,
+            with_options,
+            pivot,
+            unpivot,
+            sample,
+            ..
+        } => {
+            if temporal.is_some() {
+                return Err("time travel and CHANGES are not supported".to_string());
+            }
+            if with_options.is_some() || pivot.is_some() || unpivot.is_some() || sample.is_some() {
+                return Err(
+                    "table options, PIVOT, UNPIVOT and SAMPLE are not supported".to_string()
+                );
+            }
+            Ok(())
+        }
+        TableReference::Join { join, .. } => {
+            if !matches!(join.op, JoinOperator::Inner | JoinOperator::CrossJoin) {
+                return Err(format!("{:?} join is not supported", join.op));
+            }
+            if let JoinCondition::On(condition) = &join.condition {
+                let mut checker = ExprChecker::default();
+                condition.drive(&mut checker);
+                if let Some(reason) = checker.not_support {
+                    return Err(reason);
+                }
+                if checker.has_aggregate {
+                    return Err(
+                        "aggregate functions in join conditions are not supported".to_string()
+                    );
+                }
+            }
+            check_table_reference(&join.left)?;
+            check_table_reference(&join.right)
+        }
+        _ => Err("subqueries and table functions in FROM are not supported".to_string()),
+    }
+}
+
+/// Find the select item that a `GROUP BY` item refers to, by position, by alias or by expression.
+fn find_select_item(stmt: &SelectStmt, expr: &Expr) -> Option<usize> {
+    if let Expr::Literal {
+        value: Literal::UInt64(position),
+        ..
+    } = expr
+    {
+        let position = (*position as usize).checked_sub(1)?;
+        return (position < stmt.select_list.len()).then_some(position);
+    }
+
+    let name = match expr {
+        Expr::ColumnRef {
+            column:
+                ColumnRef {
+                    database: None,
+                    table: None,
+                    column: ColumnID::Name(name),
+                },
+            ..
+        } => Some(name.name.to_lowercase()),
+        _ => None,
+    };
+    let text = expr.to_string();
+    stmt.select_list.iter().position(|target| match target {
+        SelectTarget::AliasedExpr { expr, alias } => {
+            matches!((alias, &name), (Some(alias), Some(name)) if alias.name.to_lowercase() == *name)
+                || expr.to_string() == text
+        }
+        SelectTarget::StarColumns { .. } => false,
+    })
+}
+
+fn is_source(table_ref: &TableReference, ctes: &HashSet<String>) -> bool {
+    match table_ref {
+        TableReference::Table {
+            database,
+            table,
+            temporal,
+            ..
+        } => {
+            temporal.is_none() && !(database.is_none() && ctes.contains(&table.name.to_lowercase()))
+        }
+        _ => false,
+    }
+}
+
+fn column_ref(name: &Identifier) -> Expr {
+    Expr::ColumnRef {
+        span: None,
+        column: ColumnRef {
+            database: None,
+            table: None,
+            column: ColumnID::Name(name.clone()),
+        },
+    }
+}
+
+fn table_ref(name: &Identifier) -> TableReference {
+    TableReference::Table {
+        span: None,
+        catalog: None,
+        database: None,
+        table: name.clone(),
+        alias: None,
+        temporal: None,
+        with_options: None,
+        pivot: None,
+        unpivot: None,
+        sample: None,
+    }
+}
+
+fn select_query(select_list: Vec<SelectTarget>, from: TableReference) -> Query {
+    Query {
+        span: None,
+        with: None,
+        body: SetExpr::Select(Box::new(SelectStmt {
+            span: None,
+            hints: None,
+            distinct: false,
+            top_n: None,
+            select_list,
+            from: vec![from],
+            selection: None,
+            group_by: None,
+            having: None,
+            window_list: None,
+            qualify: None,
+        })),
+        order_by: vec![],
+        limit: vec![],
+        offset: None,
+        ignore_result: false,
+    }
+}
+
+#[derive(Default, Visitor)]
+#[visitor(CTE(enter))]
+struct CteCollector {
+    names: HashSet<String>,
+}
+
+impl CteCollector {
+    fn enter_cte(&mut self, cte: &CTE) {
+        self.names.insert(cte.alias.name.name.to_lowercase());
+    }
+}
+
+#[derive(Visitor)]
+#[visitor(TableReference(enter))]
+struct SourceCollector<'a> {
+    ctes: &'a HashSet<String>,
+    name_resolution_ctx: &'a NameResolutionContext,
+    sources: Vec<DynamicTableSource>,
+}
+
+impl SourceCollector<'_> {
+    fn enter_table_reference(&mut self, table_ref: &TableReference) {
+        if !is_source(table_ref, self.ctes) {
+            return;
+        }
+        if let TableReference::Table {
+            catalog,
+            database,
+            table,
+            ..
+        } = table_ref
+        {
+            let normalize =
+                |ident: &Identifier| normalize_identifier(ident, self.name_resolution_ctx).name;
+            self.sources.push(DynamicTableSource {
+                catalog: catalog.as_ref().map(normalize),
+                database: database.as_ref().map(normalize),
+                table: normalize(table),
+            });
+        }
+    }
+}
+
+#[derive(VisitorMut)]
+#[visitor(TableReference(enter))]
+struct SourceQualifier<'a> {
+    ctes: &'a HashSet<String>,
+    current_database: &'a str,
+}
+
+impl SourceQualifier<'_> {
+    fn enter_table_reference(&mut self, table_ref: &mut TableReference) {
+        if !is_source(table_ref, self.ctes) {
+            return;
+        }
+        if let TableReference::Table { span, database, .. } = table_ref {
+            if database.is_none() {
+                *database = Some(Identifier::from_name(*span, self.current_database));
+            }
+        }
+    }
+}
+
+#[derive(VisitorMut)]
+#[visitor(TableReference(enter))]
+struct SourceReadRewriter<'a> {
+    ctes: &'a HashSet<String>,
+    reads: &'a [DynamicTableSourceRead],
+    next: usize,
+}
+
+impl SourceReadRewriter<'_> {
+    fn enter_table_reference(&mut self, table_ref: &mut TableReference) {
+        if !is_source(table_ref, self.ctes) {
+            return;
+        }
+        let Some(read) = self.reads.get(self.next) else {
+            return;
+        };
+        self.next += 1;
+
+        let TableReference::Table {
+            span,
+            table,
+            alias,
+            temporal,
+            ..
+        } = table_ref
+        else {
+            return;
+        };
+        match read {
+            DynamicTableSourceRead::Snapshot(None) => {}
+            DynamicTableSourceRead::Snapshot(Some(snapshot))
+            | DynamicTableSourceRead::Changes {
+                begin: None,
+                end: snapshot,
+                ..
+            } => {
+                *temporal = Some(TemporalClause::TimeTravel(TimeTravelPoint::Snapshot(
+                    snapshot.clone(),
+                )));
+            }
+            DynamicTableSourceRead::Changes {
+                begin: Some(begin),
+                end,
+                append_only,
+            } => {
+                // Read the changes in a subquery without the change columns,
+                // so that the rest of the query sees the columns of the table.
+                let span = *span;
+                let alias = alias.take().unwrap_or_else(|| TableAlias {
+                    name: table.clone(),
+                    columns: vec![],
+                });
+                *temporal = Some(TemporalClause::Changes(ChangesInterval {
+                    append_only: *append_only,
+                    at_point: TimeTravelPoint::Snapshot(begin.clone()),
+                    end_point: Some(TimeTravelPoint::Snapshot(end.clone())),
+                }));
+
+                let excludes = CHANGE_COLUMNS
+                    .iter()
+                    .map(|name| Identifier::from_name(None, *name))
+                    .collect();
+                let star = SelectTarget::StarColumns {
+                    qualified: vec![Indirection::Star(None)],
+                    column_filter: Some(ColumnFilter::Excludes(excludes)),
+                };
+                let changes = select_query(vec![star], table_ref.clone());
+                *table_ref = TableReference::Subquery {
+                    span,
+                    lateral: false,
+                    subquery: Box::new(changes),
+                    alias: Some(alias),
+                };
+            }
+        }
+    }
+}
+
+#[derive(Default, Visitor)]
+#[visitor(Expr(enter))]
+struct ExprChecker {
+    not_support: Option<String>,
+    has_aggregate: bool,
+}
+
+impl ExprChecker {
+    fn enter_expr(&mut self, expr: &Expr) {
+        if self.not_support.is_some() {
+            return;
+        }
+        match expr {
+            Expr::Subquery { .. } | Expr::Exists { .. } | Expr::InSubquery { .. } => {
+                self.not_support = Some("subqueries are not supported".to_string());
+            }
+            Expr::CountAll { window, .. } => {
+                if window.is_some() {
+                    self.not_support = Some("window functions are not supported".to_string());
+                }
+                self.has_aggregate = true;
+            }
+            Expr::FunctionCall {
+                func:
+                    FunctionCall {
+                        distinct,
+                        name,
+                        window,
+                        ..
+                    },
+                ..
+            } => {
+                if window.is_some() {
+                    self.not_support = Some("window functions are not supported".to_string());
+                    return;
+                }
+                let func_name = name.name.to_lowercase();
+                if AggregateFunctionFactory::instance().contains(&func_name) {
+                    self.has_aggregate = true;
+                    if *distinct
+                        || !SUPPORTED_DYNAMIC_TABLE_INCREMENTAL_FUNCTIONS.contains(&&*func_name)
+                    {
+                        self.not_support = Some(format!(
+                            "aggregate function `{}` is not supported",
+                            name.name
+                        ));
+                    }
+                    return;
+                }
+                let non_deterministic = BUILTIN_FUNCTIONS
+                    .get_property(&func_name)
+                    .map(|p| p.non_deterministic)
+                    .unwrap_or(false);
+                if non_deterministic {
+                    self.not_support = Some(format!(
+                        "non-deterministic function `{}` is not supported",
+                        name.name
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -17,6 +17,7 @@ mod aggregating_index_visitor;
 mod async_function_rewriter;
 mod count_set_ops;
 mod distinct_to_groupby;
+mod dynamic_table_visitor;
 mod grouping_check;
 mod lowering;
 mod name_resolution;
@@ -33,6 +34,10 @@ pub use aggregating_index_visitor::RefreshAggregatingIndexRewriter;
 pub(crate) use async_function_rewriter::AsyncFunctionRewriter;
 pub use count_set_ops::CountSetOps;
 pub use distinct_to_groupby::DistinctToGroupBy;
+pub use dynamic_table_visitor::DynamicTableQuery;
+pub use dynamic_table_visitor::DynamicTableQueryShape;
+pub use dynamic_table_visitor::DynamicTableSource;
+pub use dynamic_table_visitor::DynamicTableSourceRead;
 pub use grouping_check::GroupingChecker;
 pub use lowering::*;
 pub use name_resolution::compare_table_name;
@@ -52,3 +57,6 @@ pub use window_check::WindowChecker;
 
 pub(crate) const SUPPORTED_AGGREGATING_INDEX_FUNCTIONS: [&str; 6] =
     ["sum", "min", "max", "avg", "count", "approx_count_distinct"];
+
+pub(crate) const SUPPORTED_DYNAMIC_TABLE_INCREMENTAL_FUNCTIONS: [&str; 4] =
+    ["sum", "min", "max", "count"];
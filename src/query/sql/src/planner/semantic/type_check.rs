@@ -94,9 +94,11 @@ use databend_common_meta_app::principal::UDFDefinition;
 use databend_common_meta_app::principal::UDFScript;
 use databend_common_meta_app::principal::UDFServer;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
+use databend_common_meta_app::schema::is_auto_increment_sequence;
 use databend_common_meta_app::schema::DictionaryIdentity;
 use databend_common_meta_app::schema::GetSequenceReq;
 use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_storage::init_stage_operator;
 use databend_common_users::UserApiProvider;
 use derive_visitor::Drive;
@@ -2445,9 +2447,10 @@ impl<'a> TypeChecker<'a> {
         let mut index_schema = None;
         let mut index_options = BTreeMap::new();
         for table_index in table_indexes.values() {
-            if column_ids
-                .iter()
-                .all(|id| table_index.column_ids.contains(id))
+            if table_index.index_type == TableIndexType::Inverted
+                && column_ids
+                    .iter()
+                    .all(|id| table_index.column_ids.contains(id))
             {
                 index_name = table_index.name.clone();
                 index_version = table_index.version.clone();
@@ -3018,6 +3021,7 @@ impl<'a> TypeChecker<'a> {
             "currentuser",
             "current_user",
             "current_role",
+            "is_role_in_session",
             "connection_id",
             "timezone",
             "nullif",
@@ -3075,6 +3079,27 @@ impl<'a> TypeChecker<'a> {
                     ),
                 }),
             ),
+            ("is_role_in_session", &[role]) => {
+                let Expr::Literal {
+                    value: Literal::String(role),
+                    ..
+                } = role
+                else {
+                    return Some(Err(ErrorCode::SemanticError(
+                        "is_role_in_session only accepts a string literal role name",
+                    )
+                    .set_span(span)));
+                };
+                let roles =
+                    databend_common_base::runtime::block_on(self.ctx.get_all_effective_roles());
+                match roles {
+                    Ok(roles) => Some(self.resolve(&Expr::Literal {
+                        span,
+                        value: Literal::Boolean(roles.iter().any(|r| &r.name == role)),
+                    })),
+                    Err(e) => Some(Err(e)),
+                }
+            }
             ("connection_id", &[]) => Some(self.resolve(&Expr::Literal {
                 span,
                 value: Literal::String(self.ctx.get_connection_id()),
@@ -3867,6 +3892,13 @@ impl<'a> TypeChecker<'a> {
             .set_span(span));
         };
 
+        if is_auto_increment_sequence(&sequence_name) {
+            return Err(ErrorCode::SemanticError(format!(
+                "sequence `{sequence_name}` is reserved for AUTOINCREMENT columns"
+            ))
+            .set_span(span));
+        }
+
         let catalog = self.ctx.get_default_catalog()?;
         let req = GetSequenceReq {
             ident: SequenceIdent::new(self.ctx.get_tenant(), sequence_name.clone()),
@@ -0,0 +1,162 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use databend_common_ast::parser::parse_comma_separated_idents;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
+use databend_common_expression::ComputedExpr;
+use databend_common_expression::TableSchemaRef;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_settings::Settings;
+
+use crate::normalize_identifier;
+use crate::planner::semantic::NameResolutionContext;
+
+/// Groups of columns to collect extended statistics for, specified by the table
+/// option `statistics_column_groups`, e.g. `(city, zip), (make, model)`.
+#[derive(Clone, Debug, Default)]
+pub struct StatisticsColumnGroups(pub Vec<Vec<String>>);
+
+impl FromStr for StatisticsColumnGroups {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let settings = Settings::create(Tenant::new_literal("dummy"));
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let sql_dialect = Dialect::default();
+
+        let invalid = || {
+            ErrorCode::TableOptionInvalid(format!(
+                "Invalid statistics_column_groups '{}', expect groups like '(a, b), (c, d)'",
+                s
+            ))
+        };
+
+        let mut groups = vec![];
+        let mut rest = s.trim();
+        while !rest.is_empty() {
+            rest = rest.strip_prefix('(').ok_or_else(invalid)?;
+            let end = rest.find(')').ok_or_else(invalid)?;
+            let tokens = tokenize_sql(&rest[..end])?;
+            let idents = parse_comma_separated_idents(&tokens, sql_dialect)?;
+            groups.push(
+                idents
+                    .iter()
+                    .map(|ident| normalize_identifier(ident, &name_resolution_ctx).name)
+                    .collect(),
+            );
+
+            rest = rest[end + 1..].trim_start();
+            if let Some(next) = rest.strip_prefix(',') {
+                rest = next.trim_start();
+                if rest.is_empty() {
+                    return Err(invalid());
+                }
+            } else if !rest.is_empty() {
+                return Err(invalid());
+            }
+        }
+        Ok(StatisticsColumnGroups(groups))
+    }
+}
+
+impl StatisticsColumnGroups {
+    /// Verify the definition based on schema.
+    pub fn verify_definition(definition: &str, schema: TableSchemaRef) -> Result<()> {
+        let groups = Self::from_str(definition)?;
+        for group in groups.0.iter() {
+            if group.len() < 2 {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "A column group of statistics must contain at least 2 columns, but got ({})",
+                    group.join(", ")
+                )));
+            }
+
+            let mut names = HashSet::with_capacity(group.len());
+            for name in group {
+                if !names.insert(name) {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "Duplicated column '{}' in a column group of statistics",
+                        name
+                    )));
+                }
+
+                let field = schema.field_with_name(name).map_err(|_| {
+                    ErrorCode::TableOptionInvalid(format!(
+                        "Column '{}' in statistics_column_groups does not exist",
+                        name
+                    ))
+                })?;
+                if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "The value specified for computed column '{}' is not allowed for statistics column groups",
+                        name
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Rename the column in the groups.
+    pub fn rename_column(&mut self, old_name: &str, new_name: &str) {
+        for name in self.0.iter_mut().flatten() {
+            if name == old_name {
+                *name = new_name.to_string();
+            }
+        }
+    }
+
+    /// Remove the column from the groups, the groups left with less than 2
+    /// columns are removed.
+    pub fn remove_column(&mut self, column_name: &str) {
+        for group in self.0.iter_mut() {
+            group.retain(|name| name != column_name);
+        }
+        self.0.retain(|group| group.len() >= 2);
+    }
+
+    /// Get the column ids of each group based on the schema, the groups refer
+    /// to dropped columns are ignored.
+    pub fn column_ids(&self, schema: TableSchemaRef) -> Vec<Vec<ColumnId>> {
+        self.0
+            .iter()
+            .filter_map(|group| {
+                group
+                    .iter()
+                    .map(|name| schema.field_with_name(name).ok().map(|f| f.column_id()))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect()
+    }
+}
+
+impl Display for StatisticsColumnGroups {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let groups = self
+            .0
+            .iter()
+            .map(|group| format!("({})", group.join(", ")))
+            .collect::<Vec<_>>();
+        write!(f, "{}", groups.join(", "))
+    }
+}
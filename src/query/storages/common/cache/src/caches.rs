@@ -17,6 +17,8 @@ use std::sync::Arc;
 use databend_common_cache::MemSized;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
+use databend_common_vector::IvfIndexMeta;
+use databend_common_vector::IvfList;
 use databend_storages_common_index::filters::Xor8Filter;
 use databend_storages_common_index::BloomIndexMeta;
 use databend_storages_common_index::InvertedIndexFile;
@@ -50,6 +52,12 @@ pub type BloomIndexMetaCache = InMemoryLruCache<BloomIndexMeta>;
 pub type InvertedIndexMetaCache = InMemoryLruCache<InvertedIndexMeta>;
 pub type InvertedIndexFileCache = InMemoryLruCache<InvertedIndexFile>;
 
+/// In memory object cache of vector index meta, the centroids and the layout of the lists
+pub type VectorIndexMetaCache = InMemoryLruCache<IvfIndexMeta>;
+/// In memory object cache of vector index lists.
+/// For each indexed data block, the probed lists of the index are cached individually
+pub type VectorIndexListCache = InMemoryLruCache<IvfList>;
+
 /// In memory object cache of parquet FileMetaData of external parquet rs files
 pub type ParquetMetaDataCache = InMemoryLruCache<ParquetMetaData>;
 
@@ -143,6 +151,20 @@ impl CachedObject<InvertedIndexMeta> for InvertedIndexMeta {
     }
 }
 
+impl CachedObject<IvfIndexMeta> for IvfIndexMeta {
+    type Cache = VectorIndexMetaCache;
+    fn cache() -> Option<Self::Cache> {
+        CacheManager::instance().get_vector_index_meta_cache()
+    }
+}
+
+impl CachedObject<IvfList> for IvfList {
+    type Cache = VectorIndexListCache;
+    fn cache() -> Option<Self::Cache> {
+        CacheManager::instance().get_vector_index_list_cache()
+    }
+}
+
 pub struct CacheValue<T> {
     inner: Arc<T>,
     mem_bytes: usize,
@@ -234,6 +256,24 @@ impl From<InvertedIndexFile> for CacheValue<InvertedIndexFile> {
     }
 }
 
+impl From<IvfIndexMeta> for CacheValue<IvfIndexMeta> {
+    fn from(value: IvfIndexMeta) -> Self {
+        CacheValue {
+            inner: Arc::new(value),
+            mem_bytes: 0,
+        }
+    }
+}
+
+impl From<IvfList> for CacheValue<IvfList> {
+    fn from(value: IvfList) -> Self {
+        CacheValue {
+            mem_bytes: value.mem_bytes(),
+            inner: Arc::new(value),
+        }
+    }
+}
+
 impl From<ParquetMetaData> for CacheValue<ParquetMetaData> {
     fn from(value: ParquetMetaData) -> Self {
         CacheValue {
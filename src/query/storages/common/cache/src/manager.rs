@@ -34,6 +34,8 @@ use crate::caches::ParquetMetaDataCache;
 use crate::caches::PrunePartitionsCache;
 use crate::caches::TableSnapshotCache;
 use crate::caches::TableSnapshotStatisticCache;
+use crate::caches::VectorIndexListCache;
+use crate::caches::VectorIndexMetaCache;
 use crate::InMemoryLruCache;
 use crate::TableDataCache;
 use crate::TableDataCacheBuilder;
@@ -49,6 +51,8 @@ pub struct CacheManager {
     bloom_index_meta_cache: Option<BloomIndexMetaCache>,
     inverted_index_meta_cache: Option<InvertedIndexMetaCache>,
     inverted_index_file_cache: Option<InvertedIndexFileCache>,
+    vector_index_meta_cache: Option<VectorIndexMetaCache>,
+    vector_index_list_cache: Option<VectorIndexListCache>,
     prune_partitions_cache: Option<PrunePartitionsCache>,
     parquet_meta_data_cache: Option<ParquetMetaDataCache>,
     table_data_cache: Option<TableDataCache>,
@@ -121,6 +125,8 @@ impl CacheManager {
                 bloom_index_meta_cache: None,
                 inverted_index_meta_cache: None,
                 inverted_index_file_cache: None,
+                vector_index_meta_cache: None,
+                vector_index_list_cache: None,
                 prune_partitions_cache: None,
                 parquet_meta_data_cache: None,
                 table_statistic_cache: None,
@@ -166,6 +172,14 @@ impl CacheManager {
                 MEMORY_CACHE_INVERTED_INDEX_FILE,
                 inverted_index_file_size,
             );
+            let vector_index_meta_cache = Self::new_named_items_cache(
+                config.vector_index_meta_count as usize,
+                MEMORY_CACHE_VECTOR_INDEX_FILE_META_DATA,
+            );
+            let vector_index_list_cache = Self::new_named_bytes_cache(
+                MEMORY_CACHE_VECTOR_INDEX_LIST,
+                config.vector_index_filter_size as usize,
+            );
             let prune_partitions_cache = Self::new_named_items_cache(
                 config.table_prune_partitions_count as usize,
                 MEMORY_CACHE_PRUNE_PARTITIONS,
@@ -188,6 +202,8 @@ impl CacheManager {
                 bloom_index_meta_cache,
                 inverted_index_meta_cache,
                 inverted_index_file_cache,
+                vector_index_meta_cache,
+                vector_index_list_cache,
                 prune_partitions_cache,
                 table_statistic_cache,
                 table_data_cache,
@@ -236,6 +252,14 @@ impl CacheManager {
         self.inverted_index_file_cache.clone()
     }
 
+    pub fn get_vector_index_meta_cache(&self) -> Option<VectorIndexMetaCache> {
+        self.vector_index_meta_cache.clone()
+    }
+
+    pub fn get_vector_index_list_cache(&self) -> Option<VectorIndexListCache> {
+        self.vector_index_list_cache.clone()
+    }
+
     pub fn get_prune_partitions_cache(&self) -> Option<PrunePartitionsCache> {
         self.prune_partitions_cache.clone()
     }
@@ -303,6 +327,8 @@ const MEMORY_CACHE_PRUNE_PARTITIONS: &str = "memory_cache_prune_partitions";
 const MEMORY_CACHE_INVERTED_INDEX_FILE: &str = "memory_cache_inverted_index_file";
 const MEMORY_CACHE_INVERTED_INDEX_FILE_META_DATA: &str =
     "memory_cache_inverted_index_file_meta_data";
+const MEMORY_CACHE_VECTOR_INDEX_LIST: &str = "memory_cache_vector_index_list";
+const MEMORY_CACHE_VECTOR_INDEX_FILE_META_DATA: &str = "memory_cache_vector_index_file_meta_data";
 
 const MEMORY_CACHE_BLOOM_INDEX_FILE_META_DATA: &str = "memory_cache_bloom_index_file_meta_data";
 const MEMORY_CACHE_BLOOM_INDEX_FILTER: &str = "memory_cache_bloom_index_filter";
@@ -19,6 +19,7 @@ mod bloom_index;
 pub mod filters;
 mod index;
 mod inverted_index;
+mod ngram_index;
 mod page_index;
 mod range_index;
 
@@ -32,6 +33,8 @@ pub use inverted_index::DocIdsCollector;
 pub use inverted_index::InvertedIndexDirectory;
 pub use inverted_index::InvertedIndexFile;
 pub use inverted_index::InvertedIndexMeta;
+pub use ngram_index::NgramIndex;
+pub use ngram_index::NGRAM_SIZE;
 pub use page_index::PageIndex;
 pub use range_index::statistics_to_domain;
 pub use range_index::RangeIndex;
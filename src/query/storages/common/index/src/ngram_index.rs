@@ -0,0 +1,409 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_ast::Span;
+use databend_common_exception::Result;
+use databend_common_expression::types::boolean::BooleanDomain;
+use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::number::NumberDomain;
+use databend_common_expression::types::number::SimpleDomain;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Domain;
+use databend_common_expression::Expr;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::filters::Filter;
+use crate::filters::FilterBuilder;
+use crate::filters::Xor8Builder;
+use crate::filters::Xor8Filter;
+use crate::FilterEvalResult;
+
+/// The length in bytes of the grams indexed by [`NgramIndex`].
+pub const NGRAM_SIZE: usize = 3;
+
+/// The digest put in the filter of a column that has no gram, the digest of a gram never
+/// exceeds `NGRAM_SIZE` bytes.
+const EMPTY_NGRAM_DIGEST: u64 = u64::MAX;
+
+/// NgramIndex represents the per-column n-gram filters of a data block.
+///
+/// Each string value of an indexed column is split into overlapping grams of
+/// `NGRAM_SIZE` bytes, and all the distinct grams of the column are put into a xor filter.
+/// A substring predicate, e.g. `url LIKE '%checkout%'` or `position('checkout' IN url) > 0`,
+/// can not match any row of the block if one of the grams of the substring is not in the filter.
+///
+/// The filters are stored in a file besides the data block, with the same layout as the bloom
+/// index file, the filter of a column is named 'Ngram(column_id)'.
+pub struct NgramIndex {
+    pub func_ctx: FunctionContext,
+
+    /// The schema of the filter block.
+    pub filter_schema: TableSchemaRef,
+
+    /// filters.
+    pub filters: Vec<Arc<Xor8Filter>>,
+}
+
+impl NgramIndex {
+    /// Load the index from the filters read from the n-gram index file.
+    pub fn from_filter_block(
+        func_ctx: FunctionContext,
+        filter_schema: TableSchemaRef,
+        filters: Vec<Arc<Xor8Filter>>,
+    ) -> Self {
+        Self {
+            func_ctx,
+            filter_schema,
+            filters,
+        }
+    }
+
+    /// Create the n-gram filters of the columns in `ngram_columns_map` from source data.
+    ///
+    /// Every indexed column has a filter, so that the columns an index file was built for
+    /// can be told from its meta. The filter of a column that has no gram at all, e.g. all
+    /// the values are shorter than `NGRAM_SIZE`, only holds [`EMPTY_NGRAM_DIGEST`].
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        block: &DataBlock,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+    ) -> Result<Option<Self>> {
+        if block.num_rows() == 0 || block.num_columns() == 0 {
+            return Ok(None);
+        }
+
+        let mut filter_fields = vec![];
+        let mut filters = vec![];
+        for (index, field) in ngram_columns_map.into_iter() {
+            let column = block
+                .get_by_offset(index)
+                .to_column(block.num_rows())
+                .remove_nullable();
+            let Some(column) = StringType::try_downcast_column(&column) else {
+                continue;
+            };
+
+            let mut digests = HashSet::new();
+            for value in column.iter() {
+                digests.extend(ngram_digests(value.as_bytes()));
+            }
+            if digests.is_empty() {
+                digests.insert(EMPTY_NGRAM_DIGEST);
+            }
+
+            let mut filter_builder = Xor8Builder::create();
+            filter_builder.add_digests(digests.iter());
+            let filter = filter_builder.build()?;
+
+            let filter_name = Self::build_filter_column_name(&field);
+            filter_fields.push(TableField::new(&filter_name, TableDataType::Binary));
+            filters.push(Arc::new(filter));
+        }
+
+        if filter_fields.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            func_ctx,
+            filter_schema: Arc::new(TableSchema::new(filter_fields)),
+            filters,
+        }))
+    }
+
+    pub fn serialize_to_data_block(&self) -> Result<DataBlock> {
+        let fields = self.filter_schema.fields();
+        let mut filter_columns = Vec::with_capacity(fields.len());
+        for filter in &self.filters {
+            let serialized_bytes = filter.to_bytes()?;
+            let filter_value = Value::Scalar(Scalar::Binary(serialized_bytes));
+            filter_columns.push(BlockEntry::new(DataType::Binary, filter_value));
+        }
+        Ok(DataBlock::new(filter_columns, 1))
+    }
+
+    /// Apply the predicate expression, return the result.
+    ///
+    /// The substring predicates whose grams are not all in the filter of the column are
+    /// rewritten to a new column with a `no match` domain, if the whole expression is then
+    /// folded to `false`, the block can be skipped.
+    pub fn apply(
+        &self,
+        mut expr: Expr<String>,
+        column_stats: &StatisticsOfColumns,
+        data_schema: TableSchemaRef,
+    ) -> Result<FilterEvalResult> {
+        let mut new_col_id = 1;
+        let mut domains = ConstantFolder::full_input_domains(&expr);
+
+        visit_expr_column_substring(&mut expr, &mut |span, col_name, pattern, return_type| {
+            let field = data_schema.field_with_name(col_name)?;
+            let filter_column = Self::build_filter_column_name(field);
+            if !self.find(&filter_column, &pattern.digests()) {
+                return Ok(None);
+            }
+
+            let new_col_name = format!("__ngram_column_{}_{}", col_name, new_col_id);
+            new_col_id += 1;
+
+            let domain = match pattern {
+                SubstringPattern::Like(_) => Domain::Boolean(BooleanDomain {
+                    has_false: true,
+                    has_true: false,
+                }),
+                SubstringPattern::Position(_) => {
+                    Domain::Number(NumberDomain::UInt64(SimpleDomain { min: 0, max: 0 }))
+                }
+            };
+            let new_domain = if return_type.is_nullable() {
+                // generate `has_null` based on the `null_count` in column statistics.
+                let has_null = match column_stats.get(&field.column_id()) {
+                    Some(stat) => stat.null_count > 0,
+                    None => true,
+                };
+                Domain::Nullable(NullableDomain {
+                    has_null,
+                    value: Some(Box::new(domain)),
+                })
+            } else {
+                domain
+            };
+            domains.insert(new_col_name.clone(), new_domain);
+
+            Ok(Some(Expr::ColumnRef {
+                span,
+                id: new_col_name.clone(),
+                data_type: return_type.clone(),
+                display_name: new_col_name,
+            }))
+        })?;
+
+        let (new_expr, _) =
+            ConstantFolder::fold_with_domain(&expr, &domains, &self.func_ctx, &BUILTIN_FUNCTIONS);
+
+        match new_expr {
+            Expr::Constant {
+                scalar: Scalar::Boolean(false),
+                ..
+            } => Ok(FilterEvalResult::MustFalse),
+            _ => Ok(FilterEvalResult::Uncertain),
+        }
+    }
+
+    /// Find all the columns of `fields` that are searched by a substring predicate, like
+    /// `col LIKE '%<constant>%'` or `position(<constant> IN col)`.
+    pub fn find_substring_columns(expr: &Expr<String>, fields: &[TableField]) -> Vec<TableField> {
+        let mut cols: Vec<TableField> = Vec::new();
+        // the visitor never fails.
+        let _ = visit_expr_column_substring(&mut expr.clone(), &mut |_, col_name, _, _| {
+            if let Some(v) = fields.iter().find(|f| f.name() == col_name) {
+                if !cols.iter().any(|c| c.column_id() == v.column_id()) {
+                    cols.push(v.clone());
+                }
+            }
+            Ok(None)
+        });
+        cols
+    }
+
+    /// The filter of a column is stored with field name 'Ngram(column_id)'.
+    pub fn build_filter_column_name(field: &TableField) -> String {
+        format!("Ngram({})", field.column_id())
+    }
+
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        matches!(data_type.remove_nullable(), TableDataType::String)
+    }
+
+    /// Returns true if the column must not contain the grams.
+    fn find(&self, filter_column: &str, digests: &[u64]) -> bool {
+        let Ok(idx) = self.filter_schema.index_of(filter_column) else {
+            // The column doesn't have a filter.
+            return false;
+        };
+        let filter = &self.filters[idx];
+        digests
+            .iter()
+            .any(|digest| !filter.contains_digest(*digest))
+    }
+}
+
+/// The constant substring searched by a predicate.
+enum SubstringPattern {
+    /// The pattern of `LIKE`.
+    Like(String),
+    /// The needle of `position`, `locate` and `instr`.
+    Position(String),
+}
+
+impl SubstringPattern {
+    /// The digests of the grams that every matched value must contain.
+    fn digests(&self) -> Vec<u64> {
+        let mut digests = HashSet::new();
+        match self {
+            SubstringPattern::Like(pattern) => {
+                for fragment in like_pattern_fragments(pattern) {
+                    digests.extend(ngram_digests(&fragment));
+                }
+            }
+            SubstringPattern::Position(needle) => {
+                digests.extend(ngram_digests(needle.as_bytes()));
+            }
+        }
+        digests.into_iter().collect()
+    }
+}
+
+/// Splits the bytes into overlapping grams of `NGRAM_SIZE` bytes.
+fn ngram_digests(bytes: &[u8]) -> impl Iterator<Item = u64> + '_ {
+    bytes.windows(NGRAM_SIZE).map(|gram| {
+        gram.iter()
+            .fold(0u64, |digest, byte| (digest << 8) | *byte as u64)
+    })
+}
+
+/// Returns the literal fragments of a like pattern, that is the parts separated by
+/// the wildcards '%' and '_', with the escapes removed.
+fn like_pattern_fragments(pattern: &str) -> Vec<Vec<u8>> {
+    let mut fragments = vec![];
+    let mut fragment = vec![];
+    let mut bytes = pattern.bytes();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'%' | b'_' => {
+                if !fragment.is_empty() {
+                    fragments.push(std::mem::take(&mut fragment));
+                }
+            }
+            b'\\' => match bytes.next() {
+                Some(escaped) => fragment.push(escaped),
+                None => fragment.push(byte),
+            },
+            _ => fragment.push(byte),
+        }
+    }
+    if !fragment.is_empty() {
+        fragments.push(fragment);
+    }
+    fragments
+}
+
+fn visit_expr_column_substring(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, SubstringPattern, &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `Column LIKE <constant>`, `position(<constant> IN Column)`,
+    // `locate(<constant>, Column)` or `instr(Column, <constant>)`.
+    if let Expr::FunctionCall {
+        span,
+        id,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        let column_and_pattern = match (id.name().as_ref(), args.as_slice()) {
+            (
+                "like",
+                [
+                    Expr::ColumnRef {
+                        id: column,
+                        data_type,
+                        ..
+                    },
+                    Expr::Constant {
+                        scalar: Scalar::String(pattern),
+                        ..
+                    },
+                ],
+            ) => Some((column, data_type, SubstringPattern::Like(pattern.clone()))),
+            (
+                "position" | "locate",
+                [
+                    Expr::Constant {
+                        scalar: Scalar::String(needle),
+                        ..
+                    },
+                    Expr::ColumnRef {
+                        id: column,
+                        data_type,
+                        ..
+                    },
+                ],
+            )
+            | (
+                "instr",
+                [
+                    Expr::ColumnRef {
+                        id: column,
+                        data_type,
+                        ..
+                    },
+                    Expr::Constant {
+                        scalar: Scalar::String(needle),
+                        ..
+                    },
+                ],
+            ) => Some((
+                column,
+                data_type,
+                SubstringPattern::Position(needle.clone()),
+            )),
+            _ => None,
+        };
+
+        if let Some((column, data_type, pattern)) = column_and_pattern {
+            if data_type.remove_nullable() == DataType::String && !pattern.digests().is_empty() {
+                let col_name = column.clone();
+                if let Some(new_expr) = visitor(*span, &col_name, pattern, return_type)? {
+                    *expr = new_expr;
+                }
+            }
+            return Ok(());
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_substring(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_substring(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
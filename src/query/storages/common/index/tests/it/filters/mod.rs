@@ -13,4 +13,5 @@
 // limitations under the License.
 
 mod bloom_filter;
+mod ngram_filter;
 mod xor8;
@@ -0,0 +1,158 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::number::UInt8Type;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Expr;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_index::FilterEvalResult;
+use databend_storages_common_index::NgramIndex;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+
+#[test]
+fn test_ngram_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    let block = DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2, 3]),
+        StringType::from_data(vec![
+            "https://example.com/cart",
+            "https://example.com/checkout?step=1",
+            "ab",
+        ]),
+    ]);
+
+    let ngram_columns = BTreeMap::from([(1, schema.field(1).clone())]);
+    let index = NgramIndex::try_create(FunctionContext::default(), &block, ngram_columns)?.unwrap();
+    let fields = schema.fields.clone();
+    assert_eq!(
+        NgramIndex::find_substring_columns(&like_expr("%checkout%"), &fields).len(),
+        1
+    );
+
+    for (pattern, expected) in [
+        ("%checkout%", FilterEvalResult::Uncertain),
+        ("https://%/cart", FilterEvalResult::Uncertain),
+        ("%check_out%", FilterEvalResult::Uncertain),
+        ("%payment%", FilterEvalResult::MustFalse),
+        ("%cart%payment%", FilterEvalResult::MustFalse),
+        ("%pay\\%%", FilterEvalResult::MustFalse),
+        // the fragments are too short to be searched.
+        ("%pa%", FilterEvalResult::Uncertain),
+        ("%p_y_e_t%", FilterEvalResult::Uncertain),
+    ] {
+        let result = index.apply(
+            like_expr(pattern),
+            &StatisticsOfColumns::new(),
+            schema.clone(),
+        )?;
+        assert_eq!(expected, result, "pattern: {}", pattern);
+    }
+
+    for (needle, expected) in [
+        ("checkout", FilterEvalResult::Uncertain),
+        ("payment", FilterEvalResult::MustFalse),
+    ] {
+        let expr = check_function(
+            None,
+            "gt",
+            &[],
+            &[
+                check_function(
+                    None,
+                    "locate",
+                    &[],
+                    &[string_constant(needle), string_column("1")],
+                    &BUILTIN_FUNCTIONS,
+                )?,
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::Number(NumberScalar::UInt8(0)),
+                    data_type: DataType::Number(NumberDataType::UInt8),
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )?;
+        let result = index.apply(expr, &StatisticsOfColumns::new(), schema.clone())?;
+        assert_eq!(expected, result, "needle: {}", needle);
+    }
+
+    // a column without any gram still has a filter, none of its values holds a long substring.
+    let block = DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2]),
+        StringType::from_data(vec!["ab", ""]),
+    ]);
+    let ngram_columns = BTreeMap::from([(1, schema.field(1).clone())]);
+    let index = NgramIndex::try_create(FunctionContext::default(), &block, ngram_columns)?.unwrap();
+    assert_eq!(index.filter_schema.fields().len(), 1);
+    for (pattern, expected) in [
+        ("%checkout%", FilterEvalResult::MustFalse),
+        ("%ab%", FilterEvalResult::Uncertain),
+    ] {
+        let result = index.apply(
+            like_expr(pattern),
+            &StatisticsOfColumns::new(),
+            schema.clone(),
+        )?;
+        assert_eq!(expected, result, "pattern: {}", pattern);
+    }
+
+    Ok(())
+}
+
+fn like_expr(pattern: &str) -> Expr<String> {
+    check_function(
+        None,
+        "like",
+        &[],
+        &[string_column("1"), string_constant(pattern)],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap()
+}
+
+fn string_column(name: &str) -> Expr<String> {
+    Expr::ColumnRef {
+        span: None,
+        id: name.to_string(),
+        data_type: DataType::String,
+        display_name: name.to_string(),
+    }
+}
+
+fn string_constant(value: &str) -> Expr<String> {
+    Expr::Constant {
+        span: None,
+        scalar: Scalar::String(value.to_string()),
+        data_type: DataType::String,
+    }
+}
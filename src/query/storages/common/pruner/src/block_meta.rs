@@ -39,6 +39,8 @@ pub struct BlockMetaIndex {
     pub snapshot_location: Option<String>,
     // The search matched rows and optional scores in the block.
     pub matched_rows: Option<Vec<(usize, Option<F32>)>>,
+    // The sorted candidate rows of the vector index search in the block, other rows are skipped.
+    pub vector_candidate_rows: Option<Vec<usize>>,
 }
 
 #[typetag::serde(name = "block_meta_index")]
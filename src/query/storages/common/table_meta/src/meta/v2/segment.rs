@@ -22,6 +22,7 @@ use databend_common_arrow::native::ColumnMeta as NativeColumnMeta;
 use databend_common_expression::BlockMetaInfo;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::ColumnId;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableField;
 use enum_as_inner::EnumAsInner;
 use serde::Deserialize;
@@ -82,6 +83,23 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// The value of the partition key shared by all the rows of the block,
+    /// `None` if the table is not partitioned.
+    #[serde(
+        default,
+        serialize_with = "crate::meta::v2::statistics::serialize_index_scalar_option",
+        deserialize_with = "crate::meta::v2::statistics::deserialize_index_scalar_option"
+    )]
+    pub partition_value: Option<Scalar>,
+
+    /// location of the n-gram index written with the block, `None` if the table had no
+    /// n-gram index columns when the block was written. The indexes built by
+    /// `REFRESH NGRAM INDEX` are not recorded.
+    #[serde(default)]
+    pub ngram_index_location: Option<Location>,
+    #[serde(default)]
+    pub ngram_index_size: u64,
 }
 
 impl BlockMeta {
@@ -113,6 +131,9 @@ impl BlockMeta {
             inverted_index_size,
             compression,
             create_on,
+            partition_value: None,
+            ngram_index_location: None,
+            ngram_index_size: 0,
         }
     }
 
@@ -254,6 +275,9 @@ impl BlockMeta {
             compression: Compression::Lz4,
             inverted_index_size: None,
             create_on: None,
+            partition_value: None,
+            ngram_index_location: None,
+            ngram_index_size: 0,
         }
     }
 
@@ -278,6 +302,9 @@ impl BlockMeta {
             compression: s.compression,
             inverted_index_size: None,
             create_on: None,
+            partition_value: None,
+            ngram_index_location: None,
+            ngram_index_size: 0,
         }
     }
 }
@@ -81,6 +81,14 @@ pub struct Statistics {
     #[serde(deserialize_with = "crate::meta::v2::statistics::deserialize_col_stats")]
     pub col_stats: HashMap<ColumnId, ColumnStatistics>,
     pub cluster_stats: Option<ClusterStatistics>,
+    /// The partition value shared by all the blocks, `None` if the table is not
+    /// partitioned or the blocks span more than one partition.
+    #[serde(
+        default,
+        serialize_with = "crate::meta::v2::statistics::serialize_index_scalar_option",
+        deserialize_with = "crate::meta::v2::statistics::deserialize_index_scalar_option"
+    )]
+    pub partition_value: Option<Scalar>,
 }
 
 // conversions from old meta data
@@ -237,6 +245,7 @@ impl Statistics {
             index_size: v0.index_size,
             col_stats,
             cluster_stats: None,
+            partition_value: None,
         }
     }
 }
@@ -347,6 +356,40 @@ where D: serde::Deserializer<'de> {
         .transpose()
 }
 
+pub(crate) fn serialize_index_scalar_option<S>(
+    scalar: &Option<Scalar>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match scalar {
+        Some(scalar) => {
+            let index_scalar = IndexScalar::try_from(scalar.clone()).map_err(|e| {
+                serde::ser::Error::custom(format!(
+                    "Failed to convert scalar to IndexScalar: {:?}",
+                    e
+                ))
+            })?;
+            serializer.serialize_some(&index_scalar)
+        }
+        None => serializer.serialize_none(),
+    }
+}
+
+pub(crate) fn deserialize_index_scalar_option<'de, D>(
+    deserializer: D,
+) -> Result<Option<Scalar>, D::Error>
+where D: serde::Deserializer<'de> {
+    <Option<IndexScalar> as serde::Deserialize>::deserialize(deserializer)?
+        .map(|index_scalar| {
+            Scalar::try_from(index_scalar).map_err(|e| {
+                D::Error::custom(format!("Failed to convert IndexScalar to Scalar: {:?}", e))
+            })
+        })
+        .transpose()
+}
+
 /// Deserializes the `col_stats` field of the `BlockMeta` and `Statistics` struct.
 ///
 /// This function is designed to handle legacy `ColumnStatistics` items that incorrectly
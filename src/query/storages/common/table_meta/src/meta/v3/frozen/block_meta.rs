@@ -64,6 +64,9 @@ impl From<BlockMeta> for crate::meta::BlockMeta {
             inverted_index_size: None,
             compression: value.compression.into(),
             create_on: None,
+            partition_value: None,
+            ngram_index_location: None,
+            ngram_index_size: 0,
         }
     }
 }
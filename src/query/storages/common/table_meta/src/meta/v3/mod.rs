@@ -21,4 +21,5 @@ mod table_snapshot_statistics;
 
 pub use segment::SegmentInfo;
 pub use snapshot::TableSnapshot;
+pub use table_snapshot_statistics::ColumnGroupStatistics;
 pub use table_snapshot_statistics::TableSnapshotStatistics;
@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use databend_common_expression::ColumnId;
 use databend_common_storage::Histogram;
+use databend_common_storage::MostCommonValues;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -36,6 +38,38 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
     pub hll: HashMap<ColumnId, MetaHLL>,
     pub histograms: HashMap<ColumnId, Histogram>,
+    #[serde(default)]
+    pub most_common_values: HashMap<ColumnId, MostCommonValues>,
+    #[serde(default)]
+    pub column_groups: Vec<ColumnGroupStatistics>,
+}
+
+/// Extended statistics of a group of columns, declared by the table option
+/// `statistics_column_groups`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ColumnGroupStatistics {
+    pub column_ids: Vec<ColumnId>,
+    /// Joint distinct values of the columns.
+    pub hll: MetaHLL,
+    /// Degree of the functional dependency between each column and the other
+    /// columns of the group, ranges from 0 (independent) to 1 (the column
+    /// determines the others).
+    #[serde(default)]
+    pub dependencies: BTreeMap<ColumnId, f64>,
+}
+
+impl ColumnGroupStatistics {
+    pub fn new(column_ids: Vec<ColumnId>, hll: MetaHLL) -> Self {
+        Self {
+            column_ids,
+            hll,
+            dependencies: BTreeMap::new(),
+        }
+    }
+
+    pub fn distinct_values(&self) -> u64 {
+        self.hll.count() as u64
+    }
 }
 
 impl TableSnapshotStatistics {
@@ -49,6 +83,8 @@ impl TableSnapshotStatistics {
             snapshot_id,
             hll,
             histograms,
+            most_common_values: HashMap::new(),
+            column_groups: vec![],
         }
     }
 
@@ -62,6 +98,24 @@ impl TableSnapshotStatistics {
             .map(|hll| (*hll.0, hll.1.count() as u64))
             .collect()
     }
+
+    /// Fill the degrees of functional dependencies of the column groups
+    /// by the distinct values of the columns and the groups.
+    pub fn build_column_dependencies(&mut self) {
+        for group in self.column_groups.iter_mut() {
+            let group_ndv = group.hll.count() as f64;
+            group.dependencies.clear();
+            if group_ndv <= 0.0 {
+                continue;
+            }
+            for column_id in group.column_ids.iter() {
+                if let Some(hll) = self.hll.get(column_id) {
+                    let degree = (hll.count() as f64 / group_ndv).min(1.0);
+                    group.dependencies.insert(*column_id, degree);
+                }
+            }
+        }
+    }
 }
 
 impl From<v2::TableSnapshotStatistics> for TableSnapshotStatistics {
@@ -71,6 +125,8 @@ impl From<v2::TableSnapshotStatistics> for TableSnapshotStatistics {
             snapshot_id: value.snapshot_id,
             hll: HashMap::new(),
             histograms: HashMap::new(),
+            most_common_values: HashMap::new(),
+            column_groups: vec![],
         }
     }
 }
@@ -82,6 +138,8 @@ impl From<v1::TableSnapshotStatistics> for TableSnapshotStatistics {
             snapshot_id: value.snapshot_id,
             hll: HashMap::new(),
             histograms: HashMap::new(),
+            most_common_values: HashMap::new(),
+            column_groups: vec![],
         }
     }
 }
@@ -12,9 +12,50 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::table::OPT_KEY_SNAPSHOT_LOCATION;
+
 pub const OPT_KEY_AS_QUERY: &str = "as_query";
 pub const OPT_KEY_TARGET_LAG: &str = "target_lag";
 pub const OPT_KEY_REFRESH_MODE: &str = "refresh_mode";
 pub const OPT_KEY_INITIALIZE: &str = "initialize";
 pub const OPT_KEY_WAREHOUSE: &str = "warehouse";
 pub const OPT_KEY_LIFECYCLE: &str = "lifecycle";
+
+/// The snapshots of the dynamic table and of its base tables that the last refresh is based on,
+/// encoded in JSON. It is only set by the refresh of a dynamic table.
+pub const OPT_KEY_REFRESH_STATE: &str = "refresh_state";
+
+/// The snapshots that the content of a dynamic table is based on,
+/// stored in table option [`OPT_KEY_REFRESH_STATE`] after each refresh.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default)]
+pub struct DynamicTableRefreshState {
+    /// The snapshot of the dynamic table made by the refresh.
+    /// If it is not the current one, the table has been modified by others.
+    pub snapshot: Option<String>,
+    /// The snapshots of the base tables read by the refresh, by table id.
+    pub sources: BTreeMap<u64, Option<String>>,
+    /// The time when the refresh finished.
+    #[serde(default)]
+    pub refreshed_on: Option<DateTime<Utc>>,
+}
+
+impl DynamicTableRefreshState {
+    /// Whether the current snapshot of a table with `table_options` is the snapshot `id`.
+    ///
+    /// The snapshot files are named after their ids, so the snapshot location is enough to tell.
+    pub fn is_at_snapshot(id: Option<&str>, table_options: &BTreeMap<String, String>) -> bool {
+        match (id, table_options.get(OPT_KEY_SNAPSHOT_LOCATION)) {
+            (None, None) => true,
+            (Some(id), Some(location)) => location
+                .rsplit('/')
+                .next()
+                .is_some_and(|file_name| file_name.starts_with(id)),
+            _ => false,
+        }
+    }
+}
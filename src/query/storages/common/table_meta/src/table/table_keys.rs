@@ -16,6 +16,13 @@ use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::sync::LazyLock;
+
+use crate::table::OPT_KEY_AS_QUERY;
+use crate::table::OPT_KEY_INITIALIZE;
+use crate::table::OPT_KEY_REFRESH_MODE;
+use crate::table::OPT_KEY_REFRESH_STATE;
+use crate::table::OPT_KEY_TARGET_LAG;
+
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_STORAGE_PREFIX: &str = "storage_prefix";
 pub const OPT_KEY_TEMP_PREFIX: &str = "temp_prefix";
@@ -25,8 +32,13 @@ pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
 pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
+pub const OPT_KEY_NGRAM_INDEX_COLUMNS: &str = "ngram_index_columns";
+// The column groups to collect extended statistics for, e.g. `(a, b), (c, d)`.
+pub const OPT_KEY_STATISTICS_COLUMN_GROUPS: &str = "statistics_column_groups";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 pub const OPT_KEY_CHANGE_TRACKING_BEGIN_VER: &str = "begin_version";
+// The expiration time of each row of a fuse table, e.g. `ts + INTERVAL 90 DAY`.
+pub const OPT_KEY_TTL: &str = "ttl";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -54,6 +66,12 @@ pub const OPT_KEY_CLUSTER_TYPE: &str = "cluster_type";
 pub const LINEAR_CLUSTER_TYPE: &str = "linear";
 pub const HILBERT_CLUSTER_TYPE: &str = "hilbert";
 
+/// The normalized `PARTITION BY` expression of a fuse table, e.g. `(to_yyyymmdd(ts))`.
+///
+/// It is only set by `CREATE TABLE ... PARTITION BY (<expr>)` and rendered as a
+/// `PARTITION BY` clause by `show create table`.
+pub const OPT_KEY_PARTITION_BY: &str = "partition_by";
+
 /// Table option keys that reserved for internal usage only
 /// - Users are not allowed to specified this option keys in DDL
 /// - Should not be shown in `show create table` statement
@@ -61,6 +79,12 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_AS_QUERY);
+    r.insert(OPT_KEY_TARGET_LAG);
+    r.insert(OPT_KEY_REFRESH_MODE);
+    r.insert(OPT_KEY_INITIALIZE);
+    r.insert(OPT_KEY_REFRESH_STATE);
     r
 });
 
@@ -72,6 +96,8 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_ENGINE_META);
     r.insert(OPT_KEY_CHANGE_TRACKING_BEGIN_VER);
     r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_REFRESH_STATE);
     r
 });
 
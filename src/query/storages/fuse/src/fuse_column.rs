@@ -14,11 +14,14 @@
 
 use std::collections::HashMap;
 
+use databend_common_catalog::statistics::BasicColumnGroupStatistics;
 use databend_common_catalog::statistics::BasicColumnStatistics;
 use databend_common_catalog::table::ColumnStatisticsProvider;
 use databend_common_expression::ColumnId;
 use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
+use databend_common_storage::MostCommonValues;
+use databend_storages_common_table_meta::meta::ColumnGroupStatistics;
 use databend_storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
 
 /// A column statistics provider for fuse table.
@@ -26,6 +29,8 @@ use databend_storages_common_table_meta::meta::ColumnStatistics as FuseColumnSta
 pub struct FuseTableColumnStatisticsProvider {
     column_stats: HashMap<ColumnId, Option<BasicColumnStatistics>>,
     histograms: HashMap<ColumnId, Histogram>,
+    most_common_values: HashMap<ColumnId, MostCommonValues>,
+    column_groups: Vec<BasicColumnGroupStatistics>,
 }
 
 impl FuseTableColumnStatisticsProvider {
@@ -53,8 +58,27 @@ impl FuseTableColumnStatisticsProvider {
         Self {
             column_stats,
             histograms,
+            most_common_values: HashMap::new(),
+            column_groups: vec![],
         }
     }
+
+    pub fn with_extended_statistics(
+        mut self,
+        most_common_values: HashMap<ColumnId, MostCommonValues>,
+        column_groups: &[ColumnGroupStatistics],
+    ) -> Self {
+        self.most_common_values = most_common_values;
+        self.column_groups = column_groups
+            .iter()
+            .map(|group| BasicColumnGroupStatistics {
+                column_ids: group.column_ids.clone(),
+                ndv: group.distinct_values(),
+                dependencies: group.dependencies.clone(),
+            })
+            .collect();
+        self
+    }
 }
 
 impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
@@ -69,4 +93,12 @@ impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
     fn histogram(&self, column_id: ColumnId) -> Option<Histogram> {
         self.histograms.get(&column_id).cloned()
     }
+
+    fn most_common_values(&self, column_id: ColumnId) -> Option<MostCommonValues> {
+        self.most_common_values.get(&column_id).cloned()
+    }
+
+    fn column_groups(&self) -> Vec<BasicColumnGroupStatistics> {
+        self.column_groups.clone()
+    }
 }
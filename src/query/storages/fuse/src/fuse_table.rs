@@ -43,7 +43,9 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::AbortChecker;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::ColumnId;
+use databend_common_expression::Expr;
 use databend_common_expression::RemoteExpr;
+use databend_common_expression::TableSchema;
 use databend_common_expression::ORIGIN_BLOCK_ID_COL_NAME;
 use databend_common_expression::ORIGIN_BLOCK_ROW_NUM_COL_NAME;
 use databend_common_expression::ORIGIN_VERSION_COL_NAME;
@@ -58,6 +60,7 @@ use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::binder::STREAM_COLUMN_FACTORY;
 use databend_common_sql::parse_cluster_keys;
+use databend_common_sql::parse_exprs;
 use databend_common_sql::parse_hilbert_cluster_key;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_storage::init_operator;
@@ -65,6 +68,7 @@ use databend_common_storage::DataOperator;
 use databend_common_storage::StorageMetrics;
 use databend_common_storage::StorageMetricsLayer;
 use databend_storages_common_cache::LoadParams;
+use databend_storages_common_index::NgramIndex;
 use databend_storages_common_table_meta::meta::parse_storage_prefix;
 use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::CompactSegmentInfo;
@@ -80,6 +84,8 @@ use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -100,6 +106,7 @@ use crate::io::WriteSettings;
 use crate::operations::ChangesDesc;
 use crate::operations::TruncateMode;
 use crate::statistics::reduce_block_statistics;
+use crate::statistics::PartitionStatsGenerator;
 use crate::statistics::Trim;
 use crate::FuseStorageFormat;
 use crate::NavigationPoint;
@@ -124,6 +131,7 @@ pub struct FuseTable {
     pub(crate) storage_format: FuseStorageFormat,
     pub(crate) table_compression: TableCompression,
     pub(crate) bloom_index_cols: BloomIndexColumns,
+    pub(crate) ngram_index_cols: BloomIndexColumns,
 
     pub(crate) operator: Operator,
     pub(crate) data_metrics: Arc<StorageMetrics>,
@@ -219,6 +227,12 @@ impl FuseTable {
             .and_then(|s| s.parse::<BloomIndexColumns>().ok())
             .unwrap_or(BloomIndexColumns::All);
 
+        let ngram_index_cols = table_info
+            .options()
+            .get(OPT_KEY_NGRAM_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::None);
+
         let part_prefix = table_info.meta.part_prefix.clone();
 
         let meta_location_generator =
@@ -229,6 +243,7 @@ impl FuseTable {
             meta_location_generator,
             cluster_key_meta,
             bloom_index_cols,
+            ngram_index_cols,
             operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
@@ -422,6 +437,62 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    pub fn ngram_index_cols(&self) -> BloomIndexColumns {
+        self.ngram_index_cols.clone()
+    }
+
+    /// The ids of the columns with n-gram index, the n-gram index files are located by them.
+    pub fn ngram_index_column_ids(&self) -> Result<Vec<ColumnId>> {
+        Ok(self
+            .ngram_index_cols
+            .bloom_index_fields(self.schema(), NgramIndex::supported_type)?
+            .values()
+            .map(|field| field.column_id())
+            .collect())
+    }
+
+    /// The `PARTITION BY` expression of the table, the column refs are the field indexes of the table schema.
+    pub fn partition_key(&self, ctx: Arc<dyn TableContext>) -> Result<Option<Expr>> {
+        let Some(partition_key_str) = self.table_info.options().get(OPT_KEY_PARTITION_BY) else {
+            return Ok(None);
+        };
+        let mut exprs = parse_exprs(ctx, Arc::new(self.clone()), partition_key_str)?;
+        Ok(exprs.pop())
+    }
+
+    /// The `PARTITION BY` expression of the table used by pruning, the column refs are the column names.
+    pub fn partition_pruning_key(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<Option<Expr<String>>> {
+        let schema = self.schema();
+        Ok(self
+            .partition_key(ctx)?
+            .map(|key| key.project_column_ref(|index| schema.field(*index).name().to_string())))
+    }
+
+    /// Builds the [PartitionStatsGenerator] for the blocks of `source_schema`, `None` if the table is not partitioned.
+    pub fn partition_stats_gen(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source_schema: &TableSchema,
+    ) -> Result<Option<PartitionStatsGenerator>> {
+        let Some(partition_key) = self.partition_key(ctx.clone())? else {
+            return Ok(None);
+        };
+        let schema = self.schema();
+        let mut offsets = HashMap::new();
+        for index in partition_key.column_refs().into_keys() {
+            let name = schema.field(index).name();
+            offsets.insert(index, source_schema.index_of(name)?);
+        }
+        let partition_key = partition_key.project_column_ref(|index| offsets[index]);
+        Ok(Some(PartitionStatsGenerator::new(
+            partition_key,
+            ctx.get_function_context()?,
+        )))
+    }
+
     // Check if table is attached.
     pub fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -812,6 +883,10 @@ impl Table for FuseTable {
                     Some(table_statistics.column_distinct_values()),
                     snapshot.summary.row_count,
                 )
+                .with_extended_statistics(
+                    table_statistics.most_common_values.clone(),
+                    &table_statistics.column_groups,
+                )
             } else {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
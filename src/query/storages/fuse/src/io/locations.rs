@@ -15,7 +15,9 @@
 use std::marker::PhantomData;
 
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
+use databend_common_vector::IvfIndex;
 use databend_storages_common_table_meta::meta::trim_vacuum2_object_prefix;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
@@ -36,6 +38,8 @@ use crate::index::InvertedIndexFile;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
 use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_NGRAM_INDEX_PREFIX;
+use crate::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
 static SNAPSHOT_V1: SnapshotVersion = SnapshotVersion::V1(PhantomData);
@@ -197,6 +201,58 @@ impl TableMetaLocationGenerator {
             InvertedIndexFile::VERSION,
         )
     }
+
+    pub fn gen_vector_index_location_from_block_location(
+        loc: &str,
+        index_name: &str,
+        index_version: &str,
+    ) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = trim_vacuum2_object_prefix(splits[len - 1]);
+        let id: String = block_name.chars().take(32).collect();
+        let short_ver: String = index_version.chars().take(7).collect();
+        format!(
+            "{}/{}/{}/{}/{}_v{}.index",
+            prefix,
+            FUSE_TBL_VECTOR_INDEX_PREFIX,
+            index_name,
+            short_ver,
+            id,
+            IvfIndex::VERSION,
+        )
+    }
+
+    /// The n-gram index files are grouped by the ids of the indexed columns, an index file
+    /// is never overwritten and is stale once the indexed columns change.
+    pub fn gen_ngram_index_location_from_block_location(
+        loc: &str,
+        column_ids: impl IntoIterator<Item = ColumnId>,
+    ) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = trim_vacuum2_object_prefix(splits[len - 1]);
+        let id = block_name
+            .rsplit_once("_v")
+            .map_or(block_name, |(id, _)| id);
+        let mut column_ids = column_ids.into_iter().collect::<Vec<_>>();
+        column_ids.sort_unstable();
+        let columns = column_ids
+            .iter()
+            .map(|column_id| column_id.to_string())
+            .collect::<Vec<_>>()
+            .join("-");
+        format!(
+            "{}/{}/{}/{}_v{}.parquet",
+            prefix,
+            FUSE_TBL_NGRAM_INDEX_PREFIX,
+            columns,
+            id,
+            BlockFilter::VERSION,
+        )
+    }
 }
 
 trait SnapshotLocationCreator {
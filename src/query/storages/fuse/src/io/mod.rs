@@ -19,6 +19,7 @@ mod snapshots;
 mod write;
 
 pub use locations::TableMetaLocationGenerator;
+pub use read::load_ngram_filter;
 pub use read::AggIndexReader;
 pub use read::BlockReadResult;
 pub use read::BlockReader;
@@ -30,6 +31,7 @@ pub use read::NativeReaderExt;
 pub use read::NativeSourceData;
 pub use read::SnapshotHistoryReader;
 pub use read::TableSnapshotReader;
+pub use read::VectorIndexReader;
 pub use read::VirtualBlockReadResult;
 pub use read::VirtualColumnReader;
 pub use segments::SegmentsIO;
@@ -37,9 +39,11 @@ pub use segments::SerializedSegment;
 pub use snapshots::SnapshotLiteExtended;
 pub use snapshots::SnapshotsIO;
 pub(crate) use write::block_to_inverted_index;
+pub(crate) use write::collect_vectors;
 pub(crate) use write::create_index_schema;
 pub(crate) use write::create_inverted_index_builders;
 pub(crate) use write::create_tokenizer_manager;
+pub(crate) use write::create_vector_index_builders;
 pub use write::serialize_block;
 pub use write::write_data;
 pub use write::BlockBuilder;
@@ -51,4 +55,7 @@ pub use write::CachedMetaWriter;
 pub use write::InvertedIndexBuilder;
 pub use write::InvertedIndexWriter;
 pub use write::MetaWriter;
+pub use write::NgramIndexState;
+pub use write::VectorIndexBuilder;
+pub use write::VectorIndexState;
 pub use write::WriteSettings;
@@ -30,6 +30,7 @@ use crate::io::BlockReader;
 use crate::BlockReadResult;
 
 impl BlockReader {
+    #[fastrace::trace]
     #[async_backtrace::framed]
     pub async fn read_columns_data_by_merge_io(
         &self,
@@ -31,6 +31,7 @@ use crate::io::BlockReader;
 use crate::BlockReadResult;
 
 impl BlockReader {
+    #[fastrace::trace]
     pub fn sync_read_columns_data_by_merge_io(
         &self,
         settings: &ReadSettings,
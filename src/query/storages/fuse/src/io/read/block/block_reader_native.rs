@@ -52,6 +52,7 @@ pub type Reader = Box<dyn NativeReaderExt>;
 pub type NativeSourceData = BTreeMap<usize, Vec<NativeReader<Reader>>>;
 
 impl BlockReader {
+    #[fastrace::trace]
     #[async_backtrace::framed]
     pub async fn async_read_native_columns_data(
         &self,
@@ -137,6 +138,7 @@ impl BlockReader {
         Ok((index, native_readers))
     }
 
+    #[fastrace::trace]
     pub fn sync_read_native_columns_data(
         &self,
         part: &PartInfoPtr,
@@ -72,20 +72,35 @@ impl BloomBlockFilterReader for Location {
             BlockBloomFilterIndexVersion::V2(_)
             | BlockBloomFilterIndexVersion::V3(_)
             | BlockBloomFilterIndexVersion::V4(_) => {
-                let res = load_bloom_filter_by_columns(dal, columns, path, index_length).await?;
+                let res =
+                    load_bloom_filter_by_columns(dal, columns, path, Some(index_length)).await?;
                 Ok(res)
             }
         }
     }
 }
 
+/// Loads the filters of the n-gram index file of a block.
+///
+/// The n-gram index files share the caches of the bloom index files. The length of the
+/// file is fetched from the storage if it is not given and the index meta is not cached.
+#[async_backtrace::framed]
+pub async fn load_ngram_filter(
+    dal: Operator,
+    columns: &[String],
+    index_path: &str,
+    index_length: Option<u64>,
+) -> Result<BlockFilter> {
+    load_bloom_filter_by_columns(dal, columns, index_path, index_length).await
+}
+
 /// load index column data
 #[fastrace::trace]
 async fn load_bloom_filter_by_columns<'a>(
     dal: Operator,
     column_needed: &'a [String],
     index_path: &'a str,
-    index_length: u64,
+    index_length: Option<u64>,
 ) -> Result<BlockFilter> {
     // 1. load index meta
     let bloom_index_meta = load_index_meta(dal.clone(), index_path, index_length).await?;
@@ -172,7 +187,11 @@ async fn load_column_xor8_filter<'a>(
 /// Loads index meta data
 /// read data from cache, or populate cache items if possible
 #[fastrace::trace]
-async fn load_index_meta(dal: Operator, path: &str, length: u64) -> Result<Arc<BloomIndexMeta>> {
+async fn load_index_meta(
+    dal: Operator,
+    path: &str,
+    length: Option<u64>,
+) -> Result<Arc<BloomIndexMeta>> {
     let path_owned = path.to_owned();
     async move {
         let reader = MetaReaders::bloom_index_meta_reader(dal);
@@ -182,7 +201,7 @@ async fn load_index_meta(dal: Operator, path: &str, length: u64) -> Result<Arc<B
 
         let load_params = LoadParams {
             location: path_owned,
-            len_hint: Some(length),
+            len_hint: length,
             ver: version,
             put_cache: true,
         };
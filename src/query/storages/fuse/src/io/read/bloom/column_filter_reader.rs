@@ -86,6 +86,7 @@ impl BloomColumnFilterReader {
         }
     }
 
+    #[fastrace::trace(name = "BloomColumnFilterReader::read")]
     #[async_backtrace::framed]
     pub async fn read(&self) -> Result<Arc<Xor8Filter>> {
         self.cached_reader.read(&self.param).await
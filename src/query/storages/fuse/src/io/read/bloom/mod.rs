@@ -15,4 +15,5 @@
 pub mod block_filter_reader;
 mod column_filter_reader;
 
+pub use block_filter_reader::load_ngram_filter;
 pub use block_filter_reader::BloomBlockFilterReader;
@@ -20,6 +20,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_FOOTER_READ_SIZE;
+use databend_common_vector::IvfIndexMeta;
 use databend_storages_common_cache::CacheManager;
 use databend_storages_common_cache::InMemoryItemCacheReader;
 use databend_storages_common_cache::LoadParams;
@@ -51,6 +52,7 @@ pub type CompactSegmentInfoReader =
     InMemoryItemCacheReader<CompactSegmentInfo, LoaderWrapper<(Operator, TableSchemaRef)>>;
 pub type InvertedIndexMetaReader =
     InMemoryItemCacheReader<InvertedIndexMeta, LoaderWrapper<Operator>>;
+pub type VectorIndexMetaReader = InMemoryItemCacheReader<IvfIndexMeta, LoaderWrapper<Operator>>;
 
 pub struct MetaReaders;
 
@@ -100,6 +102,13 @@ impl MetaReaders {
             LoaderWrapper(dal),
         )
     }
+
+    pub fn vector_index_meta_reader(dal: Operator) -> VectorIndexMetaReader {
+        VectorIndexMetaReader::new(
+            CacheManager::instance().get_vector_index_meta_cache(),
+            LoaderWrapper(dal),
+        )
+    }
 }
 
 // workaround for the orphan rules
@@ -108,6 +117,7 @@ pub struct LoaderWrapper<T>(T);
 
 #[async_trait::async_trait]
 impl Loader<TableSnapshot> for LoaderWrapper<Operator> {
+    #[fastrace::trace(name = "TableSnapshotLoader::load")]
     #[async_backtrace::framed]
     async fn load(&self, params: &LoadParams) -> Result<TableSnapshot> {
         let reader = bytes_reader(&self.0, params.location.as_str(), params.len_hint).await?;
@@ -118,6 +128,7 @@ impl Loader<TableSnapshot> for LoaderWrapper<Operator> {
 
 #[async_trait::async_trait]
 impl Loader<TableSnapshotStatistics> for LoaderWrapper<Operator> {
+    #[fastrace::trace(name = "TableSnapshotStatisticsLoader::load")]
     #[async_backtrace::framed]
     async fn load(&self, params: &LoadParams) -> Result<TableSnapshotStatistics> {
         let version = TableSnapshotStatisticsVersion::try_from(params.ver)?;
@@ -128,6 +139,7 @@ impl Loader<TableSnapshotStatistics> for LoaderWrapper<Operator> {
 
 #[async_trait::async_trait]
 impl Loader<CompactSegmentInfo> for LoaderWrapper<(Operator, TableSchemaRef)> {
+    #[fastrace::trace(name = "CompactSegmentInfoLoader::load")]
     #[async_backtrace::framed]
     async fn load(&self, params: &LoadParams) -> Result<CompactSegmentInfo> {
         let version = SegmentInfoVersion::try_from(params.ver)?;
@@ -139,6 +151,7 @@ impl Loader<CompactSegmentInfo> for LoaderWrapper<(Operator, TableSchemaRef)> {
 
 #[async_trait::async_trait]
 impl Loader<BloomIndexMeta> for LoaderWrapper<Operator> {
+    #[fastrace::trace(name = "BloomIndexMetaLoader::load")]
     #[async_backtrace::framed]
     async fn load(&self, params: &LoadParams) -> Result<BloomIndexMeta> {
         // read the ThriftFileMetaData, omit unnecessary conversions
@@ -254,6 +267,28 @@ impl Loader<InvertedIndexMeta> for LoaderWrapper<Operator> {
     }
 }
 
+#[async_trait::async_trait]
+impl Loader<IvfIndexMeta> for LoaderWrapper<Operator> {
+    #[async_backtrace::framed]
+    async fn load(&self, params: &LoadParams) -> Result<IvfIndexMeta> {
+        let operator = &self.0;
+        // The meta is at the head of the file, its size is in the fixed header.
+        // Errors are converted from opendal as is, so a missing index is `StorageNotFound`.
+        let header = operator
+            .read_with(&params.location)
+            .range(0..IvfIndexMeta::HEADER_SIZE as u64)
+            .await?
+            .to_vec();
+        let meta_size = IvfIndexMeta::meta_size(&header)?;
+        let buffer = operator
+            .read_with(&params.location)
+            .range(0..meta_size as u64)
+            .await?
+            .to_vec();
+        IvfIndexMeta::from_bytes(&buffer)
+    }
+}
+
 async fn bytes_reader(op: &Operator, path: &str, len_hint: Option<u64>) -> Result<Buffer> {
     let reader = if let Some(len) = len_hint {
         op.read_with(path).range(0..len).await?
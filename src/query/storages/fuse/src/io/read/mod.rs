@@ -19,6 +19,7 @@ mod inverted_index;
 pub mod meta;
 mod snapshot_history_reader;
 mod utils;
+mod vector_index;
 mod virtual_column;
 
 pub use agg_index::AggIndexReader;
@@ -26,11 +27,13 @@ pub use block::BlockReadResult;
 pub use block::BlockReader;
 pub use block::NativeReaderExt;
 pub use block::NativeSourceData;
+pub use bloom::load_ngram_filter;
 pub use bloom::BloomBlockFilterReader;
 pub use inverted_index::InvertedIndexReader;
 pub use meta::CompactSegmentInfoReader;
 pub use meta::MetaReaders;
 pub use meta::TableSnapshotReader;
 pub use snapshot_history_reader::SnapshotHistoryReader;
+pub use vector_index::VectorIndexReader;
 pub use virtual_column::VirtualBlockReadResult;
 pub use virtual_column::VirtualColumnReader;
@@ -0,0 +1,144 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
+use databend_common_vector::IvfIndexMeta;
+use databend_common_vector::IvfList;
+use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache::CachedObject;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_io::MergeIOReader;
+use databend_storages_common_io::ReadSettings;
+use opendal::Operator;
+
+use crate::io::MetaReaders;
+
+/// Reads the vector index of a block.
+///
+/// The meta of the index is cached by the location, and each probed list is cached
+/// individually, so repeated searches only read the lists not probed before.
+pub struct VectorIndexReader {
+    operator: Operator,
+    settings: ReadSettings,
+}
+
+impl VectorIndexReader {
+    pub fn create(operator: Operator, settings: ReadSettings) -> Self {
+        Self { operator, settings }
+    }
+
+    /// Search the approximate `k` nearest rows of `query` in the `probes` nearest lists,
+    /// half of the lists are probed by default.
+    ///
+    /// Returns `None` if the index file does not exist,
+    /// or the dimension of the index is different from the query.
+    #[async_backtrace::framed]
+    pub async fn search(
+        &self,
+        location: &str,
+        query: &[f32],
+        k: usize,
+        probes: Option<usize>,
+    ) -> Result<Option<Vec<(u32, f32)>>> {
+        let meta = match self.load_meta(location).await {
+            Ok(meta) => meta,
+            // The block is written before the index is created and not refreshed yet.
+            Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        // The query fails later if the dimension mismatched.
+        if meta.dimension() != query.len() {
+            return Ok(None);
+        }
+
+        let probes = probes.unwrap_or_else(|| meta.num_lists().div_ceil(2));
+        let lists = meta.probe_lists(query, probes)?;
+        let lists = self.load_lists(location, &meta, lists).await?;
+        let lists = lists.iter().map(|list| list.as_ref()).collect::<Vec<_>>();
+        Ok(Some(meta.search(query, &lists, k)?))
+    }
+
+    async fn load_meta(&self, location: &str) -> Result<Arc<IvfIndexMeta>> {
+        let reader = MetaReaders::vector_index_meta_reader(self.operator.clone());
+        let load_params = LoadParams {
+            location: location.to_owned(),
+            len_hint: None,
+            ver: 0,
+            put_cache: true,
+        };
+        reader.read(&load_params).await
+    }
+
+    async fn load_lists(
+        &self,
+        location: &str,
+        meta: &IvfIndexMeta,
+        lists: Vec<usize>,
+    ) -> Result<Vec<Arc<IvfList>>> {
+        let list_cache = IvfList::cache();
+        let mut loaded = Vec::with_capacity(lists.len());
+        let mut ranges = vec![];
+        for list in lists {
+            let range = meta.list_range(list);
+            if range.is_empty() {
+                continue;
+            }
+            let cache_key = Self::cache_key_of_list(location, list);
+            if let Some(cached) = list_cache.get_sized(&cache_key, range.end - range.start) {
+                loaded.push(cached);
+                continue;
+            }
+            ranges.push((list as ColumnId, range));
+        }
+
+        if !ranges.is_empty() {
+            let merge_io_result = MergeIOReader::merge_io_read(
+                &self.settings,
+                self.operator.clone(),
+                location,
+                &ranges,
+            )
+            .await?;
+            for (list, (chunk_idx, range)) in &merge_io_result.columns_chunk_offsets {
+                let chunk = merge_io_result
+                    .owner_memory
+                    .get_chunk(*chunk_idx, &merge_io_result.block_path)?;
+                let data = chunk.slice(range.clone());
+                let list_data = IvfList::from_bytes(meta.dimension(), &data)?;
+                let cache_key = Self::cache_key_of_list(location, *list as usize);
+                loaded.push(list_cache.insert(cache_key, list_data));
+            }
+        }
+        Ok(loaded)
+    }
+
+    fn cache_key_of_list(index_path: &str, list: usize) -> String {
+        format!("{index_path}-{list}")
+    }
+
+    /// The cache keys of the meta and the lists of an index,
+    /// used to evict the cached items of a purged index.
+    pub fn cache_keys_of_index(index_path: &str) -> Vec<String> {
+        let num_lists = IvfIndexMeta::cache()
+            .get(index_path)
+            .map_or(0, |meta| meta.num_lists());
+        (0..num_lists)
+            .map(|list| Self::cache_key_of_list(index_path, list))
+            .collect()
+    }
+}
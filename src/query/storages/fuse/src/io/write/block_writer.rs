@@ -22,7 +22,10 @@ use databend_common_arrow::arrow::chunk::Chunk as ArrowChunk;
 use databend_common_arrow::native::write::NativeWriter;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::Column;
 use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
@@ -32,6 +35,7 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_metrics::storage::metrics_inc_block_index_write_milliseconds;
 use databend_common_metrics::storage::metrics_inc_block_index_write_nums;
@@ -41,8 +45,11 @@ use databend_common_metrics::storage::metrics_inc_block_inverted_index_write_mil
 use databend_common_metrics::storage::metrics_inc_block_inverted_index_write_nums;
 use databend_common_metrics::storage::metrics_inc_block_write_milliseconds;
 use databend_common_metrics::storage::metrics_inc_block_write_nums;
+use databend_common_vector::DistanceType;
+use databend_common_vector::IvfIndex;
 use databend_storages_common_blocks::blocks_to_parquet;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::NgramIndex;
 use databend_storages_common_io::ReadSettings;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
@@ -59,6 +66,7 @@ use crate::io::TableMetaLocationGenerator;
 use crate::operations::column_parquet_metas;
 use crate::statistics::gen_columns_statistics;
 use crate::statistics::ClusterStatsGenerator;
+use crate::statistics::PartitionStatsGenerator;
 use crate::FuseStorageFormat;
 
 pub fn serialize_block(
@@ -114,6 +122,7 @@ pub fn serialize_block(
 }
 
 /// Take ownership here to avoid extra copy.
+#[fastrace::trace]
 #[async_backtrace::framed]
 pub async fn write_data(data: Vec<u8>, data_accessor: &Operator, location: &str) -> Result<()> {
     data_accessor.write(location, data).await?;
@@ -235,6 +244,53 @@ impl BloomIndexState {
     }
 }
 
+pub struct NgramIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+impl NgramIndexState {
+    pub fn from_data_block(
+        ctx: Arc<dyn TableContext>,
+        block: &DataBlock,
+        block_location: &Location,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+    ) -> Result<Option<Self>> {
+        if ngram_columns_map.is_empty() {
+            return Ok(None);
+        }
+        let column_ids = ngram_columns_map
+            .values()
+            .map(|field| field.column_id())
+            .collect::<Vec<_>>();
+        let maybe_ngram_index =
+            NgramIndex::try_create(ctx.get_function_context()?, block, ngram_columns_map)?;
+        let Some(ngram_index) = maybe_ngram_index else {
+            return Ok(None);
+        };
+
+        let index_block = ngram_index.serialize_to_data_block()?;
+        let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+        let _ = blocks_to_parquet(
+            &ngram_index.filter_schema,
+            vec![index_block],
+            &mut data,
+            TableCompression::None,
+        )?;
+        let size = data.len() as u64;
+        let location = TableMetaLocationGenerator::gen_ngram_index_location_from_block_location(
+            &block_location.0,
+            column_ids,
+        );
+        Ok(Some(Self {
+            data,
+            size,
+            location: (location, block_location.1),
+        }))
+    }
+}
+
 #[derive(Clone)]
 pub struct InvertedIndexBuilder {
     pub(crate) name: String,
@@ -246,7 +302,7 @@ pub struct InvertedIndexBuilder {
 pub fn create_inverted_index_builders(table_meta: &TableMeta) -> Vec<InvertedIndexBuilder> {
     let mut inverted_index_builders = Vec::with_capacity(table_meta.indexes.len());
     for index in table_meta.indexes.values() {
-        if !index.sync_creation {
+        if !index.sync_creation || index.index_type != TableIndexType::Inverted {
             continue;
         }
         let mut index_fields = Vec::with_capacity(index.column_ids.len());
@@ -322,12 +378,167 @@ impl InvertedIndexState {
     }
 }
 
+#[derive(Clone)]
+pub struct VectorIndexBuilder {
+    pub(crate) name: String,
+    pub(crate) version: String,
+    pub(crate) column_name: String,
+    pub(crate) distance_type: DistanceType,
+    pub(crate) lists: usize,
+}
+
+impl VectorIndexBuilder {
+    pub fn try_create(
+        name: String,
+        version: String,
+        column_name: String,
+        options: &BTreeMap<String, String>,
+    ) -> Result<Self> {
+        let distance_type = match options.get("distance") {
+            Some(distance) => DistanceType::from_option(distance).ok_or_else(|| {
+                ErrorCode::IndexOptionInvalid(format!(
+                    "value `{distance}` is invalid index distance"
+                ))
+            })?,
+            None => DistanceType::Cosine,
+        };
+        let lists = match options.get("lists") {
+            Some(lists) => lists.parse::<usize>().map_err(|_| {
+                ErrorCode::IndexOptionInvalid(format!("value `{lists}` is invalid index lists"))
+            })?,
+            None => 0,
+        };
+        Ok(Self {
+            name,
+            version,
+            column_name,
+            distance_type,
+            lists,
+        })
+    }
+}
+
+pub fn create_vector_index_builders(table_meta: &TableMeta) -> Vec<VectorIndexBuilder> {
+    let mut vector_index_builders = Vec::new();
+    for index in table_meta.indexes.values() {
+        if !index.sync_creation || index.index_type != TableIndexType::Vector {
+            continue;
+        }
+        let Some(field) = table_meta
+            .schema
+            .fields
+            .iter()
+            .find(|field| index.column_ids.first() == Some(&field.column_id()))
+        else {
+            // ignore invalid index
+            continue;
+        };
+        // ignore invalid index
+        let Ok(vector_index_builder) = VectorIndexBuilder::try_create(
+            index.name.clone(),
+            index.version.clone(),
+            field.name().clone(),
+            &index.options,
+        ) else {
+            continue;
+        };
+        vector_index_builders.push(vector_index_builder);
+    }
+    vector_index_builders
+}
+
+/// Collect the non-null vectors of an `Array(Float32)` column.
+///
+/// Returns the dimension, the row ids and the continuously stored vectors,
+/// or `None` if the column has no vector or the vectors have different dimensions.
+pub fn collect_vectors(column: &Column) -> Option<(usize, Vec<u32>, Vec<f32>)> {
+    let (column, validity) = match column {
+        Column::Nullable(nullable) => (&nullable.column, Some(&nullable.validity)),
+        column => (column, None),
+    };
+    let Column::Array(array) = column else {
+        return None;
+    };
+    let Column::Number(NumberColumn::Float32(values)) = &array.values else {
+        return None;
+    };
+
+    let mut dimension = None;
+    let mut row_ids = Vec::new();
+    let mut vectors = Vec::with_capacity(values.len());
+    for (row, offsets) in array.offsets.windows(2).enumerate() {
+        if validity.is_some_and(|validity| !validity.get_bit(row)) {
+            continue;
+        }
+        let (start, end) = (offsets[0] as usize, offsets[1] as usize);
+        match dimension {
+            None => dimension = Some(end - start),
+            Some(dimension) if dimension != end - start => return None,
+            _ => {}
+        }
+        row_ids.push(row as u32);
+        vectors.extend(values[start..end].iter().map(|v| v.0));
+    }
+    match dimension {
+        Some(dimension) if dimension > 0 => Some((dimension, row_ids, vectors)),
+        _ => None,
+    }
+}
+
+pub struct VectorIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+impl VectorIndexState {
+    /// Build the vector index of a block.
+    ///
+    /// Returns `None` if the block has no indexable vectors, such blocks are never pruned.
+    pub fn try_create(
+        source_schema: &TableSchemaRef,
+        block: &DataBlock,
+        block_location: &Location,
+        vector_index_builder: &VectorIndexBuilder,
+    ) -> Result<Option<Self>> {
+        let field_index = source_schema.index_of(&vector_index_builder.column_name)?;
+        let column = block.get_by_offset(field_index).to_column(block.num_rows());
+        let Some((dimension, row_ids, vectors)) = collect_vectors(&column) else {
+            return Ok(None);
+        };
+        let index = IvfIndex::build(
+            vector_index_builder.distance_type,
+            dimension,
+            row_ids,
+            vectors,
+            vector_index_builder.lists,
+        )?;
+        let data = index.to_bytes();
+        let size = data.len() as u64;
+
+        let vector_index_location =
+            TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                &block_location.0,
+                &vector_index_builder.name,
+                &vector_index_builder.version,
+            );
+
+        Ok(Some(Self {
+            data,
+            size,
+            location: (vector_index_location, 0),
+        }))
+    }
+}
+
 pub struct BlockSerialization {
     pub block_raw_data: Vec<u8>,
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub ngram_index_state: Option<NgramIndexState>,
     pub inverted_index_states: Vec<InvertedIndexState>,
+    pub vector_index_states: Vec<VectorIndexState>,
 }
 
 #[derive(Clone)]
@@ -337,8 +548,11 @@ pub struct BlockBuilder {
     pub source_schema: TableSchemaRef,
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
+    pub partition_stats_gen: Option<PartitionStatsGenerator>,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     pub inverted_index_builders: Vec<InvertedIndexBuilder>,
+    pub vector_index_builders: Vec<VectorIndexBuilder>,
 }
 
 impl BlockBuilder {
@@ -346,6 +560,10 @@ impl BlockBuilder {
     where F: Fn(DataBlock, &ClusterStatsGenerator) -> Result<(Option<ClusterStatistics>, DataBlock)>
     {
         let (cluster_stats, data_block) = f(data_block, &self.cluster_stats_gen)?;
+        let partition_value = match &self.partition_stats_gen {
+            Some(partition_stats_gen) => partition_stats_gen.gen_stats(&data_block)?,
+            None => None,
+        };
         let (block_location, block_id) = self.meta_locations.gen_block_location();
 
         let bloom_index_location = self.meta_locations.block_bloom_index_location(&block_id);
@@ -359,6 +577,13 @@ impl BlockBuilder {
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
 
+        let ngram_index_state = NgramIndexState::from_data_block(
+            self.ctx.clone(),
+            &data_block,
+            &block_location,
+            self.ngram_columns_map.clone(),
+        )?;
+
         let mut inverted_index_states = Vec::with_capacity(self.inverted_index_builders.len());
         for inverted_index_builder in &self.inverted_index_builders {
             let inverted_index_state = InvertedIndexState::try_create(
@@ -370,6 +595,18 @@ impl BlockBuilder {
             inverted_index_states.push(inverted_index_state);
         }
 
+        let mut vector_index_states = Vec::with_capacity(self.vector_index_builders.len());
+        for vector_index_builder in &self.vector_index_builders {
+            if let Some(vector_index_state) = VectorIndexState::try_create(
+                &self.source_schema,
+                &data_block,
+                &block_location,
+                vector_index_builder,
+            )? {
+                vector_index_states.push(vector_index_state);
+            }
+        }
+
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
         let col_stats =
@@ -405,6 +642,12 @@ impl BlockBuilder {
             compression: self.write_settings.table_compression.into(),
             inverted_index_size,
             create_on: Some(Utc::now()),
+            partition_value,
+            ngram_index_location: ngram_index_state.as_ref().map(|v| v.location.clone()),
+            ngram_index_size: ngram_index_state
+                .as_ref()
+                .map(|v| v.size)
+                .unwrap_or_default(),
         };
 
         let serialized = BlockSerialization {
@@ -412,7 +655,9 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            ngram_index_state,
             inverted_index_states,
+            vector_index_states,
         };
         Ok(serialized)
     }
@@ -421,16 +666,20 @@ impl BlockBuilder {
 pub struct BlockWriter;
 
 impl BlockWriter {
+    #[fastrace::trace]
     pub async fn write_down(dal: &Operator, serialized: BlockSerialization) -> Result<BlockMeta> {
         let block_meta = serialized.block_meta;
 
         Self::write_down_data_block(dal, serialized.block_raw_data, &block_meta.location.0).await?;
         Self::write_down_bloom_index_state(dal, serialized.bloom_index_state).await?;
+        Self::write_down_ngram_index_state(dal, serialized.ngram_index_state).await?;
         Self::write_down_inverted_index_state(dal, serialized.inverted_index_states).await?;
+        Self::write_down_vector_index_state(dal, serialized.vector_index_states).await?;
 
         Ok(block_meta)
     }
 
+    #[fastrace::trace]
     pub async fn write_down_data_block(
         dal: &Operator,
         raw_block_data: Vec<u8>,
@@ -448,6 +697,7 @@ impl BlockWriter {
         Ok(())
     }
 
+    #[fastrace::trace]
     pub async fn write_down_bloom_index_state(
         dal: &Operator,
         bloom_index_state: Option<BloomIndexState>,
@@ -465,6 +715,25 @@ impl BlockWriter {
         Ok(())
     }
 
+    #[fastrace::trace]
+    pub async fn write_down_ngram_index_state(
+        dal: &Operator,
+        ngram_index_state: Option<NgramIndexState>,
+    ) -> Result<()> {
+        if let Some(index_state) = ngram_index_state {
+            let start = Instant::now();
+
+            let location = &index_state.location.0;
+            write_data(index_state.data, dal, location).await?;
+
+            metrics_inc_block_index_write_nums(1);
+            metrics_inc_block_index_write_nums(index_state.size);
+            metrics_inc_block_index_write_milliseconds(start.elapsed().as_millis() as u64);
+        }
+        Ok(())
+    }
+
+    #[fastrace::trace]
     pub async fn write_down_inverted_index_state(
         dal: &Operator,
         inverted_index_states: Vec<InvertedIndexState>,
@@ -481,4 +750,22 @@ impl BlockWriter {
         }
         Ok(())
     }
+
+    #[fastrace::trace]
+    pub async fn write_down_vector_index_state(
+        dal: &Operator,
+        vector_index_states: Vec<VectorIndexState>,
+    ) -> Result<()> {
+        for vector_index_state in vector_index_states {
+            let start = Instant::now();
+
+            let location = &vector_index_state.location.0;
+            write_data(vector_index_state.data, dal, location).await?;
+
+            metrics_inc_block_index_write_nums(1);
+            metrics_inc_block_index_write_nums(vector_index_state.size);
+            metrics_inc_block_index_write_milliseconds(start.elapsed().as_millis() as u64);
+        }
+        Ok(())
+    }
 }
@@ -30,6 +30,7 @@ pub trait MetaWriter<T> {
 impl<T> MetaWriter<T> for T
 where T: Marshal + Sync + Send
 {
+    #[fastrace::trace(name = "MetaWriter::write_meta")]
     #[async_backtrace::framed]
     async fn write_meta(&self, data_accessor: &Operator, location: &str) -> Result<()> {
         data_accessor.write(location, self.marshal()?).await?;
@@ -48,6 +49,7 @@ pub trait CachedMetaWriter<T> {
 
 #[async_trait::async_trait]
 impl CachedMetaWriter<SegmentInfo> for SegmentInfo {
+    #[fastrace::trace(name = "CachedMetaWriter::write_meta_through_cache")]
     #[async_backtrace::framed]
     async fn write_meta_through_cache(
         &self,
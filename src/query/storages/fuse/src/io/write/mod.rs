@@ -17,7 +17,9 @@ mod inverted_index_writer;
 mod meta_writer;
 mod write_settings;
 
+pub(crate) use block_writer::collect_vectors;
 pub(crate) use block_writer::create_inverted_index_builders;
+pub(crate) use block_writer::create_vector_index_builders;
 pub use block_writer::serialize_block;
 pub use block_writer::write_data;
 pub use block_writer::BlockBuilder;
@@ -26,6 +28,9 @@ pub use block_writer::BlockWriter;
 pub use block_writer::BloomIndexBuilder;
 pub use block_writer::BloomIndexState;
 pub use block_writer::InvertedIndexBuilder;
+pub use block_writer::NgramIndexState;
+pub use block_writer::VectorIndexBuilder;
+pub use block_writer::VectorIndexState;
 pub(crate) use inverted_index_writer::block_to_inverted_index;
 pub(crate) use inverted_index_writer::create_index_schema;
 pub(crate) use inverted_index_writer::create_tokenizer_manager;
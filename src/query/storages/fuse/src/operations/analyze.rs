@@ -39,7 +39,10 @@ use databend_common_pipeline_sinks::AsyncSinker;
 use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
 use databend_common_storage::HistogramBucket;
+use databend_common_storage::MostCommonValue;
+use databend_common_storage::MostCommonValues;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
+use databend_storages_common_table_meta::meta::ColumnGroupStatistics;
 use databend_storages_common_table_meta::meta::MetaHLL;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::SnapshotId;
@@ -56,6 +59,7 @@ use crate::FuseTable;
 enum AnalyzeStep {
     CollectNDV,
     CollectHistogram,
+    CollectMostCommonValues,
     CommitStatistics,
 }
 
@@ -70,6 +74,8 @@ impl FuseTable {
         snapshot_id: SnapshotId,
         pipeline: &mut Pipeline,
         histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+        mcv_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+        column_groups: Vec<Vec<ColumnId>>,
     ) -> Result<()> {
         pipeline.add_sink(|input| {
             SinkAnalyzeState::create(
@@ -81,6 +87,8 @@ impl FuseTable {
                 snapshot_id,
                 input,
                 histogram_info_receivers.clone(),
+                mcv_info_receivers.clone(),
+                column_groups.clone(),
             )
         })?;
         Ok(())
@@ -97,10 +105,14 @@ struct SinkAnalyzeState {
     table: String,
     snapshot_id: SnapshotId,
     histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+    mcv_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+    column_groups: Vec<Vec<ColumnId>>,
     input_data: Option<DataBlock>,
     committed: bool,
     ndv_states: HashMap<ColumnId, MetaHLL>,
+    group_ndv_states: Vec<ColumnGroupStatistics>,
     histograms: HashMap<ColumnId, Histogram>,
+    most_common_values: HashMap<ColumnId, (Vec<MostCommonValue>, f64)>,
     step: AnalyzeStep,
 }
 
@@ -115,6 +127,8 @@ impl SinkAnalyzeState {
         snapshot_id: SnapshotId,
         input: Arc<InputPort>,
         histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+        mcv_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+        column_groups: Vec<Vec<ColumnId>>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(SinkAnalyzeState {
             ctx,
@@ -125,10 +139,14 @@ impl SinkAnalyzeState {
             table: table.to_string(),
             snapshot_id,
             histogram_info_receivers,
+            mcv_info_receivers,
+            column_groups,
             input_data: None,
             committed: false,
             ndv_states: Default::default(),
+            group_ndv_states: Default::default(),
             histograms: Default::default(),
+            most_common_values: Default::default(),
             step: AnalyzeStep::CollectNDV,
         })))
     }
@@ -172,7 +190,10 @@ impl SinkAnalyzeState {
 
         let is_full = is_full.as_boolean().unwrap();
 
-        let mut ndv_states = table_statistics.map(|s| s.hll.clone()).unwrap_or_default();
+        let (mut ndv_states, prev_column_groups) = table_statistics
+            .map(|s| (s.hll.clone(), s.column_groups.clone()))
+            .unwrap_or_default();
+        let mut group_ndv_states = Vec::with_capacity(self.column_groups.len());
 
         let index_num = self.output_schema.num_fields() - 1;
 
@@ -184,12 +205,28 @@ impl SinkAnalyzeState {
             .zip(data_block.columns())
         {
             let name = f.name();
-            let index: u32 = name.strip_prefix("ndv_").unwrap().parse().unwrap();
-
             let col = col.value.index(0).unwrap();
             let col = col.as_binary().unwrap();
             let hll: MetaHLL = borsh_deserialize_from_slice(col)?;
 
+            if let Some(index) = name.strip_prefix("group_ndv_") {
+                let index: usize = index.parse().unwrap();
+                let column_ids = self.column_groups[index].clone();
+                let mut group = ColumnGroupStatistics::new(column_ids, hll);
+                if !is_full {
+                    if let Some(prev) = prev_column_groups
+                        .iter()
+                        .find(|prev| prev.column_ids == group.column_ids)
+                    {
+                        group.hll.merge(&prev.hll);
+                    }
+                }
+                group_ndv_states.push(group);
+                continue;
+            }
+
+            let index: u32 = name.strip_prefix("ndv_").unwrap().parse().unwrap();
+
             if !is_full {
                 ndv_states
                     .entry(index)
@@ -201,6 +238,7 @@ impl SinkAnalyzeState {
         }
 
         self.ndv_states = ndv_states;
+        self.group_ndv_states = group_ndv_states;
         Ok(())
     }
 
@@ -237,6 +275,26 @@ impl SinkAnalyzeState {
         Ok(())
     }
 
+    fn collect_most_common_values(&mut self, col_id: u32, data_block: DataBlock) -> Result<()> {
+        for row in 0..data_block.num_rows() {
+            let value = data_block.columns()[0].value.index(row).unwrap().to_owned();
+            let Some(value) = Datum::from_scalar(value) else {
+                continue;
+            };
+            let frequency = data_block.columns()[1].value.index(row).unwrap();
+            let frequency = frequency.as_number().unwrap().as_float64().unwrap();
+            let null_fraction = data_block.columns()[2].value.index(row).unwrap();
+            let null_fraction = null_fraction.as_number().unwrap().as_float64().unwrap();
+            let (values, fraction) = self.most_common_values.entry(col_id).or_default();
+            values.push(MostCommonValue {
+                value,
+                frequency: frequency.0,
+            });
+            *fraction = null_fraction.0;
+        }
+        Ok(())
+    }
+
     async fn commit_statistics(&self) -> Result<()> {
         let table = self.get_table().await?;
         let table = FuseTable::try_from_table(table.as_ref())?;
@@ -246,11 +304,23 @@ impl SinkAnalyzeState {
         }
         let snapshot = snapshot.unwrap();
         // 3. Generate new table statistics
-        let table_statistics = TableSnapshotStatistics::new(
+        let mut table_statistics = TableSnapshotStatistics::new(
             self.ndv_states.clone(),
             self.histograms.clone(),
             self.snapshot_id,
         );
+        table_statistics.most_common_values = self
+            .most_common_values
+            .iter()
+            .map(|(col_id, (values, null_fraction))| {
+                (
+                    *col_id,
+                    MostCommonValues::new(values.clone(), *null_fraction),
+                )
+            })
+            .collect();
+        table_statistics.column_groups = self.group_ndv_states.clone();
+        table_statistics.build_column_dependencies();
         let table_statistics_location = table
             .meta_location_generator
             .snapshot_statistics_location_from_uuid(
@@ -303,7 +373,7 @@ impl Processor for SinkAnalyzeState {
                     self.step = AnalyzeStep::CollectHistogram;
                     return Ok(Event::Async);
                 }
-                AnalyzeStep::CollectHistogram => {
+                AnalyzeStep::CollectHistogram | AnalyzeStep::CollectMostCommonValues => {
                     return Ok(Event::Async);
                 }
                 AnalyzeStep::CommitStatistics => {
@@ -344,6 +414,20 @@ impl Processor for SinkAnalyzeState {
                     }
                 }
                 if finished_count == self.histogram_info_receivers.len() {
+                    self.step = AnalyzeStep::CollectMostCommonValues;
+                }
+            }
+            AnalyzeStep::CollectMostCommonValues => {
+                let mut finished_count = 0;
+                let receivers = self.mcv_info_receivers.clone();
+                for (id, receiver) in receivers.iter() {
+                    if let Ok(res) = receiver.recv().await {
+                        self.collect_most_common_values(*id, res)?;
+                    } else {
+                        finished_count += 1;
+                    }
+                }
+                if finished_count == self.mcv_info_receivers.len() {
                     self.step = AnalyzeStep::CommitStatistics;
                 }
             }
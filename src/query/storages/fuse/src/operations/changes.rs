@@ -287,6 +287,7 @@ impl FuseTable {
                 (self.cluster_keys(ctx.clone()), self.cluster_key_meta())
             };
         let bloom_index_cols = self.bloom_index_cols();
+        let ngram_index_cols = self.ngram_index_cols();
         let mut pruner = FusePruner::create_with_pages(
             &ctx,
             self.get_operator(),
@@ -295,6 +296,8 @@ impl FuseTable {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            ngram_index_cols,
+            self.partition_pruning_key(ctx.clone())?,
             None,
         )?;
 
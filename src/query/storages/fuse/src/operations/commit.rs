@@ -24,6 +24,7 @@ use databend_common_catalog::table::TableExt;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -78,6 +79,53 @@ impl FuseTable {
         overwrite: bool,
         prev_snapshot_id: Option<SnapshotId>,
         deduplicated_label: Option<String>,
+    ) -> Result<()> {
+        let snapshot_gen = AppendGenerator::new(ctx.clone(), overwrite);
+        self.commit_with_generator(
+            ctx,
+            pipeline,
+            copied_files,
+            update_stream_meta,
+            snapshot_gen,
+            prev_snapshot_id,
+            deduplicated_label,
+        )
+    }
+
+    /// Commits the insertion that replaces the partition `partition_value` of the table,
+    /// the other partitions are kept.
+    #[async_backtrace::framed]
+    pub fn do_commit_overwrite_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        update_stream_meta: Vec<UpdateStreamMetaReq>,
+        partition_value: Scalar,
+        deduplicated_label: Option<String>,
+    ) -> Result<()> {
+        let snapshot_gen = AppendGenerator::new(ctx.clone(), false)
+            .with_overwrite_partition(self.clone(), partition_value);
+        self.commit_with_generator(
+            ctx,
+            pipeline,
+            None,
+            update_stream_meta,
+            snapshot_gen,
+            None,
+            deduplicated_label,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn commit_with_generator(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        copied_files: Option<UpsertTableCopiedFileReq>,
+        update_stream_meta: Vec<UpdateStreamMetaReq>,
+        snapshot_gen: AppendGenerator,
+        prev_snapshot_id: Option<SnapshotId>,
+        deduplicated_label: Option<String>,
     ) -> Result<()> {
         let block_thresholds = self.get_block_thresholds();
 
@@ -100,7 +148,6 @@ impl FuseTable {
             )
         });
 
-        let snapshot_gen = AppendGenerator::new(ctx.clone(), overwrite);
         pipeline.add_sink(|input| {
             CommitSink::try_create(
                 self,
@@ -36,26 +36,50 @@ use uuid::Uuid;
 use crate::operations::common::ConflictResolveContext;
 use crate::operations::common::SnapshotGenerator;
 use crate::operations::common::SnapshotMerged;
+use crate::operations::partition::PartitionSplit;
 use crate::statistics::reducers::merge_statistics_mut;
+use crate::FuseTable;
 
 #[derive(Clone)]
 pub struct AppendGenerator {
     ctx: Arc<dyn TableContext>,
     leaf_default_values: HashMap<ColumnId, Scalar>,
     overwrite: bool,
+    overwrite_partition: Option<OverwritePartition>,
     conflict_resolve_ctx: ConflictResolveContext,
 }
 
+#[derive(Clone)]
+struct OverwritePartition {
+    partition_value: Scalar,
+    table: FuseTable,
+    // The segments of the previous snapshot split by the replaced partition,
+    // refreshed on every commit attempt.
+    split: Option<PartitionSplit>,
+}
+
 impl AppendGenerator {
     pub fn new(ctx: Arc<dyn TableContext>, overwrite: bool) -> Self {
         AppendGenerator {
             ctx,
             leaf_default_values: HashMap::new(),
             overwrite,
+            overwrite_partition: None,
             conflict_resolve_ctx: ConflictResolveContext::None,
         }
     }
 
+    /// Replaces the partition `partition_value` of the table with the appended data,
+    /// instead of replacing the whole table.
+    pub fn with_overwrite_partition(mut self, table: FuseTable, partition_value: Scalar) -> Self {
+        self.overwrite_partition = Some(OverwritePartition {
+            partition_value,
+            table,
+            split: None,
+        });
+        self
+    }
+
     fn check_fill_default(&self, summary: &Statistics) -> Result<bool> {
         let mut fill_default_values = false;
         // check if need to fill default value in statistics
@@ -102,6 +126,17 @@ impl SnapshotGenerator for AppendGenerator {
         previous: &Option<Arc<TableSnapshot>>,
     ) -> Result<()> {
         if let Some(snapshot) = previous {
+            if let Some(overwrite_partition) = &mut self.overwrite_partition {
+                let split = overwrite_partition
+                    .table
+                    .split_segments_by_partition(
+                        self.ctx.clone(),
+                        &snapshot.segments,
+                        &overwrite_partition.partition_value,
+                    )
+                    .await?;
+                overwrite_partition.split = Some(split);
+            }
             if !self.overwrite && self.check_fill_default(&snapshot.summary)? {
                 let mut default_values = Vec::with_capacity(schema.num_fields());
                 for field in schema.fields() {
@@ -128,6 +163,18 @@ impl SnapshotGenerator for AppendGenerator {
                 expected_schema, schema
             )));
         }
+        if let Some(overwrite_partition) = &self.overwrite_partition {
+            let appended = &snapshot_merged.merged_statistics;
+            if appended.row_count != 0
+                && appended.partition_value.as_ref() != Some(&overwrite_partition.partition_value)
+            {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The inserted rows don't belong to the partition ({})",
+                    overwrite_partition.partition_value
+                )));
+            }
+        }
+
         let mut prev_timestamp = None;
         let mut prev_snapshot_id = None;
         let mut table_statistics_location = None;
@@ -141,6 +188,14 @@ impl SnapshotGenerator for AppendGenerator {
 
             if !self.overwrite {
                 let mut summary = snapshot.summary.clone();
+                let mut prev_segments = &snapshot.segments;
+                if let Some(overwrite_partition) = &self.overwrite_partition {
+                    let split = overwrite_partition.split.as_ref().ok_or_else(|| {
+                        ErrorCode::Internal("the overwritten partition is not split")
+                    })?;
+                    split.retained_summary(&mut summary, cluster_key_meta.clone().map(|v| v.0));
+                    prev_segments = &split.retained_segments;
+                }
 
                 let leaf_fields = schema.leaf_fields();
                 let column_data_types: HashMap<ColumnId, &TableDataType> =
@@ -189,7 +244,7 @@ impl SnapshotGenerator for AppendGenerator {
                 new_segments = snapshot_merged
                     .merged_segments
                     .iter()
-                    .chain(snapshot.segments.iter())
+                    .chain(prev_segments.iter())
                     .cloned()
                     .collect();
 
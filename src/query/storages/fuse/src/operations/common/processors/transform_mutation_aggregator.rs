@@ -25,6 +25,7 @@ use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoPtr;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_metrics::storage::metrics_inc_recluster_write_block_nums;
 use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
@@ -252,30 +253,40 @@ impl TableMutationAggregator {
             sort_by_cluster_stats(&a.cluster_stats, &b.cluster_stats, default_cluster_key_id)
         });
 
+        // The blocks of different partitions never share a segment.
+        let mut partitions: BTreeMap<Option<Scalar>, Vec<Arc<BlockMeta>>> = BTreeMap::new();
+        for block in std::mem::take(&mut self.recluster_merged_blocks) {
+            partitions
+                .entry(block.partition_value.clone())
+                .or_default()
+                .push(block);
+        }
+
         let mut tasks = Vec::new();
-        let merged_blocks = std::mem::take(&mut self.recluster_merged_blocks);
-        let segments_num = (merged_blocks.len() / self.block_per_seg).max(1);
-        let chunk_size = merged_blocks.len().div_ceil(segments_num);
         let default_cluster_key = Some(default_cluster_key_id);
         let thresholds = self.thresholds;
-        for chunk in &merged_blocks.into_iter().chunks(chunk_size) {
-            let new_blocks = chunk.collect::<Vec<_>>();
-            let all_perfect = new_blocks.len() > 1;
-
-            let location_gen = self.location_gen.clone();
-            let op = self.dal.clone();
-            tasks.push(async move {
-                write_segment(
-                    op,
-                    location_gen,
-                    new_blocks,
-                    thresholds,
-                    default_cluster_key,
-                    all_perfect,
-                    MutationKind::Recluster,
-                )
-                .await
-            });
+        for merged_blocks in partitions.into_values() {
+            let segments_num = (merged_blocks.len() / self.block_per_seg).max(1);
+            let chunk_size = merged_blocks.len().div_ceil(segments_num);
+            for chunk in &merged_blocks.into_iter().chunks(chunk_size) {
+                let new_blocks = chunk.collect::<Vec<_>>();
+                let all_perfect = new_blocks.len() > 1;
+
+                let location_gen = self.location_gen.clone();
+                let op = self.dal.clone();
+                tasks.push(async move {
+                    write_segment(
+                        op,
+                        location_gen,
+                        new_blocks,
+                        thresholds,
+                        default_cluster_key,
+                        all_perfect,
+                        MutationKind::Recluster,
+                    )
+                    .await
+                });
+            }
         }
 
         let threads_nums = self.ctx.get_settings().get_max_threads()? as usize;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 use databend_common_base::base::ProgressValues;
@@ -32,9 +33,11 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::PipeItem;
 use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::NgramIndex;
 use opendal::Operator;
 
 use crate::io::create_inverted_index_builders;
+use crate::io::create_vector_index_builders;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
 use crate::io::BlockWriter;
@@ -55,7 +58,7 @@ enum State {
         index: Option<BlockMetaIndex>,
     },
     Serialized {
-        serialized: BlockSerialization,
+        serialized: Vec<BlockSerialization>,
         index: Option<BlockMetaIndex>,
     },
 }
@@ -64,7 +67,7 @@ pub struct TransformSerializeBlock {
     state: State,
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
-    output_data: Option<DataBlock>,
+    output_data: VecDeque<DataBlock>,
 
     block_builder: BlockBuilder,
     dal: Operator,
@@ -126,8 +129,13 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_columns_map = table
+            .ngram_index_cols
+            .bloom_index_fields(source_schema.clone(), NgramIndex::supported_type)?;
 
         let inverted_index_builders = create_inverted_index_builders(&table.table_info.meta);
+        let vector_index_builders = create_vector_index_builders(&table.table_info.meta);
+        let partition_stats_gen = table.partition_stats_gen(ctx.clone(), &source_schema)?;
 
         let block_builder = BlockBuilder {
             ctx,
@@ -135,14 +143,17 @@ impl TransformSerializeBlock {
             source_schema,
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
+            partition_stats_gen,
             bloom_columns_map,
+            ngram_columns_map,
             inverted_index_builders,
+            vector_index_builders,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
             input,
             output,
-            output_data: None,
+            output_data: VecDeque::new(),
             block_builder,
             dal: table.get_operator(),
             table_id: if with_tid { Some(table.get_id()) } else { None },
@@ -171,6 +182,47 @@ impl TransformSerializeBlock {
         };
         DataBlock::empty_with_meta(Box::new(meta))
     }
+
+    async fn write_block(
+        &self,
+        serialized: BlockSerialization,
+        index: Option<BlockMetaIndex>,
+    ) -> Result<DataBlock> {
+        let block_meta = BlockWriter::write_down(&self.dal, serialized).await?;
+
+        let mutation_log_data_block = if let Some(index) = index {
+            // we are replacing the block represented by the `index`
+            Self::mutation_logs(MutationLogEntry::ReplacedBlock {
+                index,
+                block_meta: Arc::new(block_meta),
+            })
+        } else {
+            // appending new data block
+            let progress_values = ProgressValues {
+                rows: block_meta.row_count as usize,
+                bytes: block_meta.block_size as usize,
+            };
+            self.block_builder
+                .ctx
+                .get_write_progress()
+                .incr(&progress_values);
+
+            if let Some(tid) = self.table_id {
+                self.block_builder
+                    .ctx
+                    .update_multi_table_insert_status(tid, block_meta.row_count);
+            }
+
+            if matches!(self.kind, MutationKind::Recluster) {
+                Self::mutation_logs(MutationLogEntry::ReclusterAppendBlock {
+                    block_meta: Arc::new(block_meta),
+                })
+            } else {
+                DataBlock::empty_with_meta(Box::new(block_meta))
+            }
+        };
+        Ok(mutation_log_data_block)
+    }
 }
 
 #[async_trait::async_trait]
@@ -200,7 +252,7 @@ impl Processor for TransformSerializeBlock {
             return Ok(Event::NeedConsume);
         }
 
-        if let Some(data_block) = self.output_data.take() {
+        if let Some(data_block) = self.output_data.pop_front() {
             self.output.push_data(Ok(data_block));
             return Ok(Event::NeedConsume);
         }
@@ -281,16 +333,26 @@ impl Processor for TransformSerializeBlock {
                 // Check if the datablock is valid, this is needed to ensure data is correct
                 block.check_valid()?;
 
-                let serialized =
-                    self.block_builder
-                        .build(block, |block, generator| match &stats_type {
+                // The appended blocks of a partitioned table are split by partition,
+                // the replaced blocks already belong to a single partition.
+                let blocks = match (&self.block_builder.partition_stats_gen, &index) {
+                    (Some(partition_stats_gen), None) => partition_stats_gen.split(block)?,
+                    _ => vec![block],
+                };
+
+                let mut serialized = Vec::with_capacity(blocks.len());
+                for block in blocks {
+                    serialized.push(self.block_builder.build(block, |block, generator| {
+                        match &stats_type {
                             ClusterStatsGenType::Generally => generator.gen_stats_for_append(block),
                             ClusterStatsGenType::WithOrigin(origin_stats) => {
                                 let cluster_stats = generator
                                     .gen_with_origin_stats(&block, origin_stats.clone())?;
                                 Ok((cluster_stats, block))
                             }
-                        })?;
+                        }
+                    })?);
+                }
 
                 self.state = State::Serialized { serialized, index };
             }
@@ -303,40 +365,10 @@ impl Processor for TransformSerializeBlock {
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Consume) {
             State::Serialized { serialized, index } => {
-                let block_meta = BlockWriter::write_down(&self.dal, serialized).await?;
-
-                let mutation_log_data_block = if let Some(index) = index {
-                    // we are replacing the block represented by the `index`
-                    Self::mutation_logs(MutationLogEntry::ReplacedBlock {
-                        index,
-                        block_meta: Arc::new(block_meta),
-                    })
-                } else {
-                    // appending new data block
-                    let progress_values = ProgressValues {
-                        rows: block_meta.row_count as usize,
-                        bytes: block_meta.block_size as usize,
-                    };
-                    self.block_builder
-                        .ctx
-                        .get_write_progress()
-                        .incr(&progress_values);
-
-                    if let Some(tid) = self.table_id {
-                        self.block_builder
-                            .ctx
-                            .update_multi_table_insert_status(tid, block_meta.row_count);
-                    }
-
-                    if matches!(self.kind, MutationKind::Recluster) {
-                        Self::mutation_logs(MutationLogEntry::ReclusterAppendBlock {
-                            block_meta: Arc::new(block_meta),
-                        })
-                    } else {
-                        DataBlock::empty_with_meta(Box::new(block_meta))
-                    }
-                };
-                self.output_data = Some(mutation_log_data_block);
+                for serialized in serialized {
+                    let data_block = self.write_block(serialized, index.clone()).await?;
+                    self.output_data.push_back(data_block);
+                }
             }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
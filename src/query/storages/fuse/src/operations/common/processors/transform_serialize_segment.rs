@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -21,6 +22,7 @@ use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
@@ -35,6 +37,7 @@ use databend_storages_common_table_meta::meta::Versioned;
 use log::info;
 use opendal::Operator;
 
+use crate::io::write_data;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
@@ -45,7 +48,9 @@ use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 
 enum State {
     None,
-    GenerateSegment,
+    GenerateSegment {
+        partition_value: Option<Scalar>,
+    },
     SerializedSegment {
         data: Vec<u8>,
         location: String,
@@ -61,7 +66,8 @@ enum State {
 pub struct TransformSerializeSegment {
     data_accessor: Operator,
     meta_locations: TableMetaLocationGenerator,
-    accumulator: StatisticsAccumulator,
+    // The blocks of different partitions never share a segment.
+    accumulators: BTreeMap<Option<Scalar>, StatisticsAccumulator>,
     state: State,
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
@@ -87,7 +93,7 @@ impl TransformSerializeSegment {
             data_accessor: table.get_operator(),
             meta_locations: table.meta_location_generator().clone(),
             state: State::None,
-            accumulator: Default::default(),
+            accumulators: BTreeMap::new(),
             block_per_seg: table
                 .get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT)
                 as u64,
@@ -121,7 +127,7 @@ impl Processor for TransformSerializeSegment {
     fn event(&mut self) -> Result<Event> {
         if matches!(
             &self.state,
-            State::GenerateSegment | State::PreCommitSegment { .. }
+            State::GenerateSegment { .. } | State::PreCommitSegment { .. }
         ) {
             return Ok(Event::Sync);
         }
@@ -144,8 +150,13 @@ impl Processor for TransformSerializeSegment {
         }
 
         if self.input.is_finished() {
-            if self.accumulator.summary_row_count != 0 {
-                self.state = State::GenerateSegment;
+            let pending = self
+                .accumulators
+                .iter()
+                .find(|(_, acc)| acc.summary_row_count != 0)
+                .map(|(partition_value, _)| partition_value.clone());
+            if let Some(partition_value) = pending {
+                self.state = State::GenerateSegment { partition_value };
                 return Ok(Event::Sync);
             }
             self.output.finish();
@@ -165,9 +176,14 @@ impl Processor for TransformSerializeSegment {
                 .ok_or_else(|| ErrorCode::Internal("No commit meta. It's a bug"))?
                 .clone();
 
-            self.accumulator.add_with_block_meta(block_meta);
-            if self.accumulator.summary_block_count >= self.block_per_seg {
-                self.state = State::GenerateSegment;
+            let partition_value = block_meta.partition_value.clone();
+            let accumulator = self
+                .accumulators
+                .entry(partition_value.clone())
+                .or_default();
+            accumulator.add_with_block_meta(block_meta);
+            if accumulator.summary_block_count >= self.block_per_seg {
+                self.state = State::GenerateSegment { partition_value };
                 return Ok(Event::Sync);
             }
         }
@@ -178,8 +194,11 @@ impl Processor for TransformSerializeSegment {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::None) {
-            State::GenerateSegment => {
-                let acc = std::mem::take(&mut self.accumulator);
+            State::GenerateSegment { partition_value } => {
+                let acc = self
+                    .accumulators
+                    .remove(&partition_value)
+                    .unwrap_or_default();
                 let summary = acc.summary(self.thresholds, self.default_cluster_key_id);
 
                 let segment_info = SegmentInfo::new(acc.blocks_metas, summary);
@@ -225,7 +244,7 @@ impl Processor for TransformSerializeSegment {
                 location,
                 segment,
             } => {
-                self.data_accessor.write(&location, data).await?;
+                write_data(data, &self.data_accessor, &location).await?;
                 info!("fuse append wrote down segment {} ", location);
 
                 self.state = State::PreCommitSegment { location, segment };
@@ -23,6 +23,9 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::TableIndexType;
+use databend_common_vector::IvfIndexMeta;
+use databend_common_vector::IvfList;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::CachedObject;
 use databend_storages_common_cache::LoadParams;
@@ -45,6 +48,7 @@ use crate::io::SegmentsIO;
 use crate::io::SnapshotLiteExtended;
 use crate::io::SnapshotsIO;
 use crate::io::TableMetaLocationGenerator;
+use crate::io::VectorIndexReader;
 use crate::FuseTable;
 use crate::FUSE_TBL_SNAPSHOT_PREFIX;
 
@@ -405,6 +409,7 @@ impl FuseTable {
         inverted_indexes: &BTreeMap<String, TableIndex>,
     ) -> Result<()> {
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let ngram_index_column_ids = self.ngram_index_column_ids()?;
         // Purge segments&blocks by chunk size
         let mut count = 0;
         let segment_locations = Vec::from_iter(segments_to_be_purged);
@@ -417,11 +422,20 @@ impl FuseTable {
             let mut blocks_to_be_purged = HashSet::new();
             let mut agg_indexes_to_be_purged = HashSet::new();
             let mut inverted_indexes_to_be_purged = HashSet::new();
+            let mut ngram_indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
                 if locations_referenced_by_root.block_location.contains(loc) {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
+                if !ngram_index_column_ids.is_empty() {
+                    ngram_indexes_to_be_purged.insert(
+                        TableMetaLocationGenerator::gen_ngram_index_location_from_block_location(
+                            loc,
+                            ngram_index_column_ids.iter().copied(),
+                        ),
+                    );
+                }
                 for index_id in table_agg_index_ids {
                     agg_indexes_to_be_purged.insert(
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
@@ -431,17 +445,13 @@ impl FuseTable {
                 }
 
                 for idx in inverted_indexes.values() {
-                    inverted_indexes_to_be_purged.insert(
-                        TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
-                            loc,
-                            idx.name.as_str(),
-                            idx.version.as_str(),
-                        ),
-                    );
+                    inverted_indexes_to_be_purged
+                        .insert(gen_table_index_location_from_block_location(loc, idx));
                 }
             }
 
-            let mut blooms_to_be_purged = HashSet::new();
+            // the n-gram index files are in the same format as the bloom index files.
+            let mut blooms_to_be_purged = ngram_indexes_to_be_purged;
             for loc in &locations.bloom_location {
                 if locations_referenced_by_root.bloom_location.contains(loc) {
                     continue;
@@ -517,15 +527,24 @@ impl FuseTable {
         // such as, different versions of same (in the sense of name) inverted index.
         // we do not handle this one block multiple inverted indexes case now.
         for idx in inverted_indexes.values() {
-            inverted_indexes_to_be_purged.extend(root_location_tuple.block_location.iter().map(
-                |loc| {
-                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
-                        loc,
-                        idx.name.as_str(),
-                        idx.version.as_str(),
-                    )
-                },
-            ));
+            inverted_indexes_to_be_purged.extend(
+                root_location_tuple
+                    .block_location
+                    .iter()
+                    .map(|loc| gen_table_index_location_from_block_location(loc, idx)),
+            );
+        }
+
+        // the n-gram index files are in the same format as the bloom index files.
+        let mut blooms_to_be_purged = root_location_tuple.bloom_location;
+        let ngram_index_column_ids = self.ngram_index_column_ids()?;
+        if !ngram_index_column_ids.is_empty() {
+            blooms_to_be_purged.extend(root_location_tuple.block_location.iter().map(|loc| {
+                TableMetaLocationGenerator::gen_ngram_index_location_from_block_location(
+                    loc,
+                    ngram_index_column_ids.iter().copied(),
+                )
+            }));
         }
 
         self.purge_block_segments(
@@ -534,7 +553,7 @@ impl FuseTable {
             root_location_tuple.block_location,
             agg_indexes_to_be_purged,
             inverted_indexes_to_be_purged,
-            root_location_tuple.bloom_location,
+            blooms_to_be_purged,
             segment_locations_to_be_purged,
         )
         .await?;
@@ -592,6 +611,22 @@ impl FuseTable {
                 }
             }
 
+            // the vector index files are purged together with the inverted index files
+            if let Some(vector_index_cache) = IvfList::cache() {
+                for index_path in &inverted_indexes_to_be_purged {
+                    VectorIndexReader::cache_keys_of_index(index_path)
+                        .iter()
+                        .for_each(|cache_key| {
+                            vector_index_cache.evict(cache_key);
+                        })
+                }
+            }
+            if let Some(vector_index_meta_cache) = IvfIndexMeta::cache() {
+                for index_path in &inverted_indexes_to_be_purged {
+                    vector_index_meta_cache.evict(index_path);
+                }
+            }
+
             self.try_purge_location_files_and_cache::<InvertedIndexMeta, _>(
                 ctx.clone(),
                 inverted_indexes_to_be_purged,
@@ -750,6 +785,25 @@ impl FuseTable {
     }
 }
 
+fn gen_table_index_location_from_block_location(loc: &str, idx: &TableIndex) -> String {
+    match idx.index_type {
+        TableIndexType::Inverted => {
+            TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                loc,
+                idx.name.as_str(),
+                idx.version.as_str(),
+            )
+        }
+        TableIndexType::Vector => {
+            TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                loc,
+                idx.name.as_str(),
+                idx.version.as_str(),
+            )
+        }
+    }
+}
+
 struct RootSnapshotInfo {
     snapshot_location: String,
     referenced_locations: LocationTuple,
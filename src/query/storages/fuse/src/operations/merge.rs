@@ -21,11 +21,13 @@ use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
 use databend_common_pipeline_core::PipeItem;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::NgramIndex;
 use databend_storages_common_table_meta::meta::Location;
 
 use super::merge_into::MatchedAggregator;
 use super::mutation::SegmentIndex;
 use crate::io::create_inverted_index_builders;
+use crate::io::create_vector_index_builders;
 use crate::io::BlockBuilder;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
@@ -90,7 +92,12 @@ impl FuseTable {
         let bloom_columns_map = self
             .bloom_index_cols()
             .bloom_index_fields(new_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_columns_map = self
+            .ngram_index_cols()
+            .bloom_index_fields(new_schema.clone(), NgramIndex::supported_type)?;
         let inverted_index_builders = create_inverted_index_builders(&self.table_info.meta);
+        let vector_index_builders = create_vector_index_builders(&self.table_info.meta);
+        let partition_stats_gen = self.partition_stats_gen(ctx.clone(), &new_schema)?;
 
         let block_builder = BlockBuilder {
             ctx: ctx.clone(),
@@ -98,8 +105,11 @@ impl FuseTable {
             source_schema: new_schema,
             write_settings: self.get_write_settings(),
             cluster_stats_gen,
+            partition_stats_gen,
             bloom_columns_map,
+            ngram_columns_map,
             inverted_index_builders,
+            vector_index_builders,
         };
         let aggregator = MatchedAggregator::create(
             ctx,
@@ -26,6 +26,8 @@ mod merge_into;
 mod mutation;
 mod mutation_source;
 mod navigate;
+mod ngram_index;
+mod partition;
 mod read;
 mod read_data;
 mod read_partitions;
@@ -33,8 +35,11 @@ mod recluster;
 mod replace;
 mod replace_into;
 mod revert;
+mod rows_filter;
 mod truncate;
+mod ttl;
 mod util;
+mod vector_index;
 
 pub use agg_index_sink::AggIndexSink;
 pub use analyze::HistogramInfoSink;
@@ -142,6 +142,12 @@ impl BlockCompactMutator {
                     )
                 });
             }
+            // The segments of a partition are adjacent, the order inside a partition is kept.
+            segment_infos.sort_by(|a, b| {
+                a.1.summary
+                    .partition_value
+                    .cmp(&b.1.summary.partition_value)
+            });
 
             // Check the segment to be compacted.
             // Size of compacted segment should be in range R == [threshold, 2 * threshold)
@@ -343,25 +349,36 @@ impl SegmentCompactChecker {
         idx: SegmentIndex,
         segment: Arc<CompactSegmentInfo>,
     ) -> Vec<Vec<(SegmentIndex, Arc<CompactSegmentInfo>)>> {
+        let mut res = vec![];
+        // The segments of different partitions are never compacted together.
+        if self
+            .segments
+            .last()
+            .is_some_and(|(_, v)| v.summary.partition_value != segment.summary.partition_value)
+        {
+            self.total_block_count = 0;
+            res.push(std::mem::take(&mut self.segments));
+        }
+
         self.total_block_count += segment.summary.block_count;
         if self.total_block_count < self.block_threshold {
             self.segments.push((idx, segment));
-            return vec![];
+            return res;
         }
 
         if self.total_block_count > 2 * self.block_threshold {
             self.total_block_count = 0;
-            let trivial = vec![(idx, segment)];
-            if self.segments.is_empty() {
-                return vec![trivial];
-            } else {
-                return vec![std::mem::take(&mut self.segments), trivial];
+            if !self.segments.is_empty() {
+                res.push(std::mem::take(&mut self.segments));
             }
+            res.push(vec![(idx, segment)]);
+            return res;
         }
 
         self.total_block_count = 0;
         self.segments.push((idx, segment));
-        vec![std::mem::take(&mut self.segments)]
+        res.push(std::mem::take(&mut self.segments));
+        res
     }
 
     pub fn generate_part(
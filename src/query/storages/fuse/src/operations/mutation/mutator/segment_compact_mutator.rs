@@ -232,6 +232,12 @@ impl<'a> SegmentCompactor<'a> {
                     )
                 });
             }
+            // The segments of a partition are adjacent, the order inside a partition is kept.
+            segment_infos.sort_by(|a, b| {
+                a.0.summary
+                    .partition_value
+                    .cmp(&b.0.summary.partition_value)
+            });
 
             for (segment, location) in segment_infos.into_iter() {
                 if is_end {
@@ -296,6 +302,15 @@ impl<'a> SegmentCompactor<'a> {
             return Ok(());
         }
 
+        // the segments of different partitions are never compacted together
+        if self
+            .fragmented_segments
+            .last()
+            .is_some_and(|(v, _)| v.summary.partition_value != segment_info.summary.partition_value)
+        {
+            self.compact_fragments().await?;
+        }
+
         let s = self.accumulated_num_blocks + num_blocks_current_segment;
 
         if s < self.threshold {
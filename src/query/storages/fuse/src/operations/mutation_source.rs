@@ -235,6 +235,8 @@ impl FuseTable {
             self.schema_with_stream(),
             &push_down,
             self.bloom_index_cols(),
+            self.ngram_index_cols(),
+            self.partition_pruning_key(ctx.clone())?,
             None,
         )?;
 
@@ -0,0 +1,222 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_base::runtime::execute_futures_in_parallel;
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::TableField;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use databend_storages_common_index::NgramIndex;
+use databend_storages_common_io::ReadSettings;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::CompactSegmentInfo;
+use opendal::Operator;
+
+use super::inverted_index::InvertedIndexSink;
+use super::inverted_index::InvertedIndexSource;
+use crate::io::BlockWriter;
+use crate::io::NgramIndexState;
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Build the missing and stale ngram index files of the table's blocks.
+    ///
+    /// The ngram index files are located by the version of the index format and the ids
+    /// of the indexed columns, the index of a block is stale once either of them changes.
+    /// Blocks written before `ngram_index_columns` was set (or before the columns changed)
+    /// have no up-to-date index file, they are always kept by the pruner until refreshed.
+    /// The pipeline has the same shape as the inverted index refresh.
+    #[async_backtrace::framed]
+    pub async fn do_refresh_ngram_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        limit: Option<u64>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(());
+        };
+
+        let table_schema = &self.get_table_info().meta.schema;
+        let ngram_fields = self
+            .ngram_index_cols()
+            .bloom_index_fields(table_schema.clone(), NgramIndex::supported_type)?;
+        if ngram_fields.is_empty() {
+            return Ok(());
+        }
+        let column_ids = ngram_fields
+            .values()
+            .map(|field| field.column_id())
+            .collect::<Vec<_>>();
+
+        // The block read by projection only contains the ngram columns,
+        // so the field indices are remapped to the positions in the projection.
+        let mut field_indices = Vec::with_capacity(ngram_fields.len());
+        let mut ngram_columns_map = BTreeMap::new();
+        for (offset, (field_index, field)) in ngram_fields.into_iter().enumerate() {
+            field_indices.push(field_index);
+            ngram_columns_map.insert(offset as FieldIndex, field);
+        }
+        let projection = Projection::Columns(field_indices);
+        let block_reader =
+            self.create_block_reader(ctx.clone(), projection, false, false, false)?;
+
+        let segments_io =
+            SegmentsIO::create(ctx.clone(), self.get_operator(), table_schema.clone());
+        let operator = self.get_operator_ref();
+        let limit = limit.map(|v| v as usize).unwrap_or(usize::MAX);
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+
+        // Read the segment infos and collect the block metas that need to generate the index.
+        let mut block_metas = VecDeque::new();
+        for chunk in snapshot.segments.chunks(max_threads * 4) {
+            if block_metas.len() >= limit {
+                break;
+            }
+            let segments = segments_io
+                .read_segments::<Arc<CompactSegmentInfo>>(chunk, false)
+                .await?;
+
+            // The existence of the index files is checked concurrently, unless the
+            // up-to-date index was written with the block.
+            let mut tasks = Vec::new();
+            for segment in segments {
+                for block_meta in segment?.block_metas()? {
+                    let index_location =
+                        TableMetaLocationGenerator::gen_ngram_index_location_from_block_location(
+                            &block_meta.location.0,
+                            column_ids.iter().copied(),
+                        );
+                    if block_meta
+                        .ngram_index_location
+                        .as_ref()
+                        .is_some_and(|(location, _)| *location == index_location)
+                    {
+                        continue;
+                    }
+                    let operator = operator.clone();
+                    tasks.push(async move {
+                        let exists = operator.stat(&index_location).await.is_ok();
+                        (block_meta, exists)
+                    });
+                }
+            }
+            let checked = execute_futures_in_parallel(
+                tasks,
+                max_threads,
+                max_threads * 2,
+                "fuse-ngram-index-check-worker".to_owned(),
+            )
+            .await?;
+            block_metas.extend(
+                checked
+                    .into_iter()
+                    .filter(|(_, exists)| !exists)
+                    .map(|(block_meta, _)| block_meta),
+            );
+        }
+        block_metas.truncate(limit);
+        if block_metas.is_empty() {
+            return Ok(());
+        }
+
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let storage_format = self.get_write_settings().storage_format;
+
+        pipeline.add_source(
+            |output| {
+                let inner = InvertedIndexSource::new(
+                    settings,
+                    storage_format,
+                    block_reader.clone(),
+                    block_metas.clone(),
+                );
+                AsyncSourcer::create(ctx.clone(), output, inner)
+            },
+            1,
+        )?;
+
+        let block_nums = block_metas.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(block_nums, max_threads);
+        pipeline.try_resize(max_threads)?;
+        pipeline.add_async_transformer(|| {
+            NgramIndexTransform::new(ctx.clone(), ngram_columns_map.clone(), operator.clone())
+        });
+
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| InvertedIndexSink::try_create(input, block_nums))?;
+
+        Ok(())
+    }
+}
+
+/// `NgramIndexTransform` is used to generate ngram index for each blocks.
+pub struct NgramIndexTransform {
+    ctx: Arc<dyn TableContext>,
+    ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+    operator: Operator,
+}
+
+impl NgramIndexTransform {
+    pub fn new(
+        ctx: Arc<dyn TableContext>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+        operator: Operator,
+    ) -> Self {
+        Self {
+            ctx,
+            ngram_columns_map,
+            operator,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for NgramIndexTransform {
+    const NAME: &'static str = "NgramIndexTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
+        let block_meta = data_block
+            .get_meta()
+            .and_then(BlockMeta::downcast_ref_from)
+            .unwrap();
+
+        let ngram_index_state = NgramIndexState::from_data_block(
+            self.ctx.clone(),
+            &data_block,
+            &block_meta.location,
+            self.ngram_columns_map.clone(),
+        )?;
+        BlockWriter::write_down_ngram_index_state(&self.operator, ngram_index_state).await?;
+
+        let new_block = DataBlock::new(vec![], 0);
+        Ok(new_block)
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::CompactSegmentInfo;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::Statistics;
+use databend_storages_common_table_meta::meta::Versioned;
+
+use crate::io::CachedMetaWriter;
+use crate::io::SegmentsIO;
+use crate::operations::rows_filter::FilteredBlock;
+use crate::operations::rows_filter::RowsFilter;
+use crate::statistics::reduce_block_metas;
+use crate::statistics::reducers::deduct_statistics_mut;
+use crate::statistics::reducers::merge_statistics_mut;
+use crate::FuseTable;
+
+/// The segments of a table split by a partition.
+#[derive(Clone)]
+pub(crate) struct PartitionSplit {
+    /// The segments that hold no rows of the partition.
+    pub retained_segments: Vec<Location>,
+    /// The summary of the segments that are not retained.
+    pub removed_statistics: Statistics,
+    /// The summary of the segments rewritten without the rows of the partition,
+    /// they are part of `retained_segments`.
+    pub added_statistics: Statistics,
+}
+
+impl PartitionSplit {
+    pub fn is_empty(&self) -> bool {
+        self.removed_statistics.block_count == 0
+    }
+
+    pub fn removed_rows(&self) -> u64 {
+        self.removed_statistics.row_count - self.added_statistics.row_count
+    }
+
+    /// The summary of `retained_segments`, given the summary of all the segments.
+    pub fn retained_summary(&self, summary: &mut Statistics, default_cluster_key_id: Option<u32>) {
+        deduct_statistics_mut(summary, &self.removed_statistics);
+        merge_statistics_mut(summary, &self.added_statistics, default_cluster_key_id);
+    }
+}
+
+impl FuseTable {
+    /// Splits the segments by the partition `partition_value`.
+    ///
+    /// The segments written before the table kept partitions apart have no partition value
+    /// and may hold the rows of several partitions. Their blocks of the partition are removed,
+    /// and their blocks that span more than one partition are rewritten without the rows of
+    /// the partition.
+    #[async_backtrace::framed]
+    pub(crate) async fn split_segments_by_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        segments: &[Location],
+        partition_value: &Scalar,
+    ) -> Result<PartitionSplit> {
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let cluster_key_id = self.cluster_key_id();
+
+        let mut split = PartitionSplit {
+            retained_segments: Vec::with_capacity(segments.len()),
+            removed_statistics: Statistics::default(),
+            added_statistics: Statistics::default(),
+        };
+        let mut filter = None;
+        for chunk in segments.chunks(chunk_size.max(1)) {
+            let segment_infos = segments_io
+                .read_segments::<Arc<CompactSegmentInfo>>(chunk, false)
+                .await?;
+            for (location, segment) in chunk.iter().zip(segment_infos) {
+                let segment = segment?;
+                let summary = &segment.summary;
+                match &summary.partition_value {
+                    Some(v) if v == partition_value => {
+                        merge_statistics_mut(
+                            &mut split.removed_statistics,
+                            summary,
+                            cluster_key_id,
+                        );
+                    }
+                    None if summary.row_count != 0 => {
+                        let blocks = segment.block_metas()?;
+                        let filter = match &mut filter {
+                            Some(filter) => filter,
+                            None => filter.insert(RowsFilter::try_create(
+                                ctx.clone(),
+                                self,
+                                self.partition_filter(ctx.clone(), partition_value)?,
+                            )?),
+                        };
+                        let Some(blocks) =
+                            Self::remove_partition_blocks(filter, &blocks, partition_value).await?
+                        else {
+                            split.retained_segments.push(location.clone());
+                            continue;
+                        };
+
+                        merge_statistics_mut(
+                            &mut split.removed_statistics,
+                            summary,
+                            cluster_key_id,
+                        );
+                        if blocks.is_empty() {
+                            continue;
+                        }
+                        let new_summary = reduce_block_metas(
+                            &blocks,
+                            self.get_block_thresholds(),
+                            cluster_key_id,
+                        );
+                        merge_statistics_mut(
+                            &mut split.added_statistics,
+                            &new_summary,
+                            cluster_key_id,
+                        );
+                        let new_segment = SegmentInfo::new(blocks, new_summary);
+                        let new_location = self.meta_location_generator.gen_segment_info_location();
+                        new_segment
+                            .write_meta_through_cache(&self.operator, &new_location)
+                            .await?;
+                        split
+                            .retained_segments
+                            .push((new_location, SegmentInfo::VERSION));
+                    }
+                    _ => split.retained_segments.push(location.clone()),
+                }
+            }
+        }
+        Ok(split)
+    }
+
+    /// The predicate of the rows that do not belong to the partition `partition_value`,
+    /// the column refs are the field indexes of the table schema.
+    fn partition_filter(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        partition_value: &Scalar,
+    ) -> Result<Expr> {
+        let partition_key = self
+            .partition_key(ctx)?
+            .ok_or_else(|| ErrorCode::Internal("the table is not partitioned"))?;
+        if partition_value.is_null() {
+            return check_function(
+                None,
+                "is_not_null",
+                &[],
+                &[partition_key],
+                &BUILTIN_FUNCTIONS,
+            );
+        }
+        let value = Expr::Constant {
+            span: None,
+            scalar: partition_value.clone(),
+            data_type: partition_key.data_type().remove_nullable(),
+        };
+        let eq = check_function(None, "eq", &[], &[partition_key, value], &BUILTIN_FUNCTIONS)?;
+        let eq = check_function(None, "is_true", &[], &[eq], &BUILTIN_FUNCTIONS)?;
+        check_function(None, "not", &[], &[eq], &BUILTIN_FUNCTIONS)
+    }
+
+    /// Returns the blocks without the rows of the partition, `None` if none of the blocks
+    /// holds rows of the partition.
+    async fn remove_partition_blocks(
+        filter: &RowsFilter,
+        blocks: &[Arc<BlockMeta>],
+        partition_value: &Scalar,
+    ) -> Result<Option<Vec<Arc<BlockMeta>>>> {
+        let mut changed = false;
+        let mut new_blocks = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            match &block.partition_value {
+                Some(v) if v == partition_value => changed = true,
+                Some(_) => new_blocks.push(block.clone()),
+                None => match filter.apply(block).await? {
+                    FilteredBlock::Unchanged => new_blocks.push(block.clone()),
+                    FilteredBlock::Dropped => changed = true,
+                    FilteredBlock::Rewritten(new_block) => {
+                        changed = true;
+                        new_blocks.push(new_block);
+                    }
+                },
+            }
+        }
+        Ok(changed.then_some(new_blocks))
+    }
+
+    /// Drops the partition `partition_value` by removing its segments from the table.
+    ///
+    /// No data is rewritten, except the legacy segments that span more than one partition.
+    ///
+    /// Returns the number of the removed rows.
+    #[async_backtrace::framed]
+    pub async fn drop_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        partition_value: &Scalar,
+    ) -> Result<u64> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(0);
+        };
+
+        let split = self
+            .split_segments_by_partition(ctx.clone(), &snapshot.segments, partition_value)
+            .await?;
+        if split.is_empty() {
+            return Ok(0);
+        }
+
+        let mut summary = snapshot.summary.clone();
+        split.retained_summary(&mut summary, self.cluster_key_id());
+        self.commit_mutation(&ctx, snapshot, &split.retained_segments, summary, None)
+            .await?;
+        Ok(split.removed_rows())
+    }
+}
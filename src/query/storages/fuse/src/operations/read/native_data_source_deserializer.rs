@@ -32,6 +32,7 @@ use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::plan::TopK;
 use databend_common_catalog::plan::VirtualColumnInfo;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::eval_function;
 use databend_common_expression::filter_helper::FilterHelpers;
@@ -65,6 +66,7 @@ use xorf::BinaryFuse16;
 use super::native_data_source::NativeDataSource;
 use super::util::add_data_block_meta;
 use super::util::need_reserve_block_info;
+use super::util::vector_candidate_bitmap;
 use crate::fuse_part::FuseBlockPartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
@@ -684,7 +686,15 @@ impl NativeDeserializeDataTransform {
                 continue;
             }
 
-            // 5. read remain columns and generate a data block.
+            // 5. check the candidate rows of the vector index search.
+            if !self.read_and_check_vector_candidates()? {
+                // skip current pages.
+                self.skipped_pages += 1;
+                self.read_state.skip_pages();
+                continue;
+            }
+
+            // 6. read remain columns and generate a data block.
             if !self.read_remain_columns()? {
                 debug_assert!(self.read_state.is_finished());
                 return Ok(None);
@@ -693,14 +703,14 @@ impl NativeDeserializeDataTransform {
                 .block_reader
                 .build_block(&self.read_state.arrays, None)?;
 
-            // 6. fill missing fields with default values.
+            // 7. fill missing fields with default values.
             if self.read_state.if_need_fill_defaults {
                 block = self
                     .block_reader
                     .fill_missing_native_column_values(block, &self.read_state.read_column_ids)?;
             }
 
-            // 7. add optional virtual columns.
+            // 8. add optional virtual columns.
             self.add_virtual_columns(
                 &self.read_state.arrays,
                 &self.src_schema,
@@ -804,6 +814,7 @@ impl NativeDeserializeDataTransform {
     fn read_and_check_bloom_runtime_filter(&mut self) -> Result<bool> {
         if let Some(bloom_runtime_filter) = self.bloom_runtime_filter.as_ref() {
             let mut bitmaps = Vec::with_capacity(bloom_runtime_filter.len());
+            let mut num_rows = 0;
             for (idx, filter) in bloom_runtime_filter.iter() {
                 let array = if let Some((_, array)) =
                     self.read_state.arrays.iter().find(|(i, _)| i == idx)
@@ -823,9 +834,11 @@ impl NativeDeserializeDataTransform {
                 let mut bitmap = MutableBitmap::from_len_zeroed(probe_block.num_rows());
                 let probe_column = probe_block.get_last_column().clone();
                 update_bitmap_with_bloom_filter(probe_column, filter, &mut bitmap)?;
+                num_rows = bitmap.len();
                 let unset_bits = bitmap.unset_bits();
-                if unset_bits == bitmap.len() {
+                if unset_bits == num_rows {
                     // skip current page.
+                    Self::record_bloom_runtime_filter_profile(num_rows, num_rows);
                     return Ok(false);
                 }
                 if unset_bits != 0 {
@@ -837,6 +850,7 @@ impl NativeDeserializeDataTransform {
                     .into_iter()
                     .reduce(|acc, rf_filter| acc.bitand(&rf_filter.into()))
                     .unwrap();
+                Self::record_bloom_runtime_filter_profile(num_rows, rf_bitmap.unset_bits());
 
                 let filter_executor = self.filter_executor.as_mut().unwrap();
                 let filter_count = if let Some(count) = self.read_state.filtered_count {
@@ -845,12 +859,70 @@ impl NativeDeserializeDataTransform {
                     filter_executor.from_bitmap(rf_bitmap)
                 };
                 self.read_state.filtered_count = Some(filter_count);
+            } else {
+                Self::record_bloom_runtime_filter_profile(num_rows, 0);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Check the candidate rows of the vector index search, the other rows are filtered.
+    ///
+    /// Returns false if skip the current page or the partition is finished.
+    fn read_and_check_vector_candidates(&mut self) -> Result<bool> {
+        let fuse_part = FuseBlockPartInfo::from_part(&self.parts[0])?;
+        if fuse_part
+            .block_meta_index()
+            .map_or(true, |index| index.vector_candidate_rows.is_none())
+        {
+            return Ok(true);
+        }
+
+        // The rows count of current pages is known after reading a page.
+        if self.read_state.arrays.is_empty() {
+            let index = *self.read_state.array_iters.keys().next().ok_or_else(|| {
+                ErrorCode::Internal("No column to read for the vector index candidates")
+            })?;
+            if !self.read_state.read_page(index)? {
+                debug_assert!(self.read_state.is_finished());
+                return Ok(false);
             }
         }
+        let num_rows = self.read_state.arrays[0].1.len();
+        let bitmap = vector_candidate_bitmap(fuse_part, self.read_state.offset, num_rows)
+            .ok_or_else(|| ErrorCode::Internal("The vector index candidate rows are missing"))?;
+        if bitmap.unset_bits() == num_rows {
+            return Ok(false);
+        }
 
+        if self.filter_executor.is_none() {
+            self.filter_executor = Some(new_dummy_filter_executor(self.func_ctx.clone()));
+        }
+        let filter_executor = self.filter_executor.as_mut().unwrap();
+        let count = if let Some(count) = self.read_state.filtered_count {
+            filter_executor.select_bitmap(count, bitmap)
+        } else {
+            filter_executor.from_bitmap(bitmap)
+        };
+        if count == 0 {
+            return Ok(false);
+        }
+        self.read_state.filtered_count = Some(count);
         Ok(true)
     }
 
+    fn record_bloom_runtime_filter_profile(checked_rows: usize, filtered_rows: usize) {
+        Profile::record_usize_profile(
+            ProfileStatisticsName::RuntimeFilterBloomCheckedRows,
+            checked_rows,
+        );
+        Profile::record_usize_profile(
+            ProfileStatisticsName::RuntimeFilterBloomFilteredRows,
+            filtered_rows,
+        );
+    }
+
     /// Update the top-k heap with by the topk column.
     ///
     /// Returns false if skip the current page.
@@ -1116,7 +1188,8 @@ impl Processor for NativeDeserializeDataTransform {
 
 /// Build a dummy filter executor to retain a selection.
 ///
-/// This method may be used by `update_topk_heap` and `read_and_check_bloom_runtime_filter`.
+/// This method may be used by `update_topk_heap`, `read_and_check_bloom_runtime_filter`
+/// and `read_and_check_vector_candidates`.
 fn new_dummy_filter_executor(func_ctx: FunctionContext) -> FilterExecutor {
     let dummy_expr = Expr::Constant {
         span: None,
@@ -39,6 +39,7 @@ use crate::io::TableMetaLocationGenerator;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
 use crate::operations::read::runtime_filter_prunner::runtime_filter_pruner;
+use crate::operations::read::runtime_filter_prunner::topn_runtime_filter_pruner;
 use crate::FuseBlockPartInfo;
 
 pub struct ReadNativeDataSource<const BLOCKING_IO: bool> {
@@ -139,11 +140,19 @@ impl SyncSource for ReadNativeDataSource<true> {
                         .ctx
                         .get_min_max_runtime_filter_with_id(self.table_index),
                 );
+                let topn_filter = self
+                    .partitions
+                    .ctx
+                    .get_topn_runtime_filter_with_id(self.table_index);
                 if runtime_filter_pruner(
                     self.table_schema.clone(),
                     &part,
                     &filters,
                     &self.func_ctx,
+                )? || topn_runtime_filter_pruner(
+                    self.table_schema.clone(),
+                    &part,
+                    topn_filter.as_deref(),
                 )? {
                     return Ok(Some(DataBlock::empty()));
                 }
@@ -244,6 +253,10 @@ impl Processor for ReadNativeDataSource<false> {
                     .ctx
                     .get_min_max_runtime_filter_with_id(self.table_index),
             );
+            let topn_filter = self
+                .partitions
+                .ctx
+                .get_topn_runtime_filter_with_id(self.table_index);
             let mut native_part_infos = Vec::with_capacity(parts.len());
             for part in parts.into_iter() {
                 if runtime_filter_pruner(
@@ -251,6 +264,10 @@ impl Processor for ReadNativeDataSource<false> {
                     &part,
                     &filters,
                     &self.func_ctx,
+                )? || topn_runtime_filter_pruner(
+                    self.table_schema.clone(),
+                    &part,
+                    topn_filter.as_deref(),
                 )? {
                     continue;
                 }
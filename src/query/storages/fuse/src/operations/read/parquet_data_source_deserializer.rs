@@ -46,6 +46,7 @@ use xorf::BinaryFuse16;
 use super::parquet_data_source::ParquetDataSource;
 use super::util::add_data_block_meta;
 use super::util::need_reserve_block_info;
+use super::util::vector_candidate_bitmap;
 use crate::fuse_part::FuseBlockPartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
@@ -264,10 +265,34 @@ impl Processor for DeserializeDataTransform {
                     let origin_num_rows = data_block.num_rows();
 
                     let mut filter = None;
+                    if let Some(bitmap) = vector_candidate_bitmap(part, 0, origin_num_rows) {
+                        let bitmap: Bitmap = bitmap.into();
+                        data_block = data_block.filter_with_bitmap(&bitmap)?;
+                        filter = Some(bitmap);
+                    }
                     if self.ctx.has_bloom_runtime_filters(self.table_index) {
                         if let Some(bitmap) = self.runtime_filter(data_block.clone())? {
+                            let num_rows = data_block.num_rows();
                             data_block = data_block.filter_with_bitmap(&bitmap)?;
-                            filter = Some(bitmap);
+                            // The runtime filter is evaluated on the candidate rows.
+                            filter = Some(match filter {
+                                Some(candidates) => {
+                                    let mut bits = bitmap.iter();
+                                    MutableBitmap::from_iter(
+                                        candidates.iter().map(|c| c && bits.next().unwrap()),
+                                    )
+                                    .into()
+                                }
+                                None => bitmap,
+                            });
+                            Profile::record_usize_profile(
+                                ProfileStatisticsName::RuntimeFilterBloomCheckedRows,
+                                num_rows,
+                            );
+                            Profile::record_usize_profile(
+                                ProfileStatisticsName::RuntimeFilterBloomFilteredRows,
+                                num_rows - data_block.num_rows(),
+                            );
                         }
                     }
 
@@ -41,6 +41,7 @@ use crate::io::TableMetaLocationGenerator;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
 use crate::operations::read::runtime_filter_prunner::runtime_filter_pruner;
+use crate::operations::read::runtime_filter_prunner::topn_runtime_filter_pruner;
 
 pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
     func_ctx: FunctionContext,
@@ -127,11 +128,19 @@ impl SyncSource for ReadParquetDataSource<true> {
                         .ctx
                         .get_min_max_runtime_filter_with_id(self.table_index),
                 );
+                let topn_filter = self
+                    .partitions
+                    .ctx
+                    .get_topn_runtime_filter_with_id(self.table_index);
                 if runtime_filter_pruner(
                     self.table_schema.clone(),
                     &part,
                     &filters,
                     &self.func_ctx,
+                )? || topn_runtime_filter_pruner(
+                    self.table_schema.clone(),
+                    &part,
+                    topn_filter.as_deref(),
                 )? {
                     return Ok(Some(DataBlock::empty()));
                 }
@@ -240,6 +249,10 @@ impl Processor for ReadParquetDataSource<false> {
                     .ctx
                     .get_min_max_runtime_filter_with_id(self.table_index),
             );
+            let topn_filter = self
+                .partitions
+                .ctx
+                .get_topn_runtime_filter_with_id(self.table_index);
             let mut fuse_part_infos = Vec::with_capacity(parts.len());
             for part in parts.into_iter() {
                 if runtime_filter_pruner(
@@ -247,6 +260,10 @@ impl Processor for ReadParquetDataSource<false> {
                     &part,
                     &filters,
                     &self.func_ctx,
+                )? || topn_runtime_filter_pruner(
+                    self.table_schema.clone(),
+                    &part,
+                    topn_filter.as_deref(),
                 )? {
                     continue;
                 }
@@ -19,6 +19,7 @@ use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::runtime_filter_info::TopNRuntimeFilter;
 use databend_common_exception::Result;
 use databend_common_expression::types::NumberColumn;
 use databend_common_expression::Column;
@@ -100,6 +101,53 @@ pub fn runtime_filter_pruner(
     Ok(pruned)
 }
 
+pub fn topn_runtime_filter_pruner(
+    table_schema: Arc<TableSchema>,
+    part: &PartInfoPtr,
+    filter: Option<&TopNRuntimeFilter>,
+) -> Result<bool> {
+    let Some(filter) = filter else {
+        return Ok(false);
+    };
+    let Some(threshold) = filter.threshold() else {
+        return Ok(false);
+    };
+    let part = FuseBlockPartInfo::from_part(part)?;
+    let Some(stats) = &part.columns_stat else {
+        return Ok(false);
+    };
+    let column_ids = table_schema.leaf_columns_of(&filter.column_name().to_string());
+    if column_ids.len() != 1 {
+        return Ok(false);
+    }
+    let Some(stat) = stats.get(&column_ids[0]) else {
+        return Ok(false);
+    };
+
+    // Nulls sorted first may be in the top-N rows.
+    if filter.nulls_first() && stat.null_count > 0 {
+        return Ok(false);
+    }
+    let pruned = if stat.min.is_null() || stat.max.is_null() {
+        // All the values are nulls, which are sorted after the threshold.
+        !filter.nulls_first()
+    } else if filter.asc() {
+        stat.min > threshold
+    } else {
+        stat.max < threshold
+    };
+
+    if pruned {
+        info!(
+            "Pruned partition with {:?} rows by topn runtime filter",
+            part.nums_rows
+        );
+        Profile::record_usize_profile(ProfileStatisticsName::RuntimeFilterPruneParts, 1);
+    }
+
+    Ok(pruned)
+}
+
 pub(crate) fn update_bitmap_with_bloom_filter(
     column: Column,
     filter: &BinaryFuse16,
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_catalog::merge_into_join::MergeIntoJoinType;
 use databend_common_catalog::plan::gen_mutation_stream_meta;
 use databend_common_catalog::plan::InternalColumnMeta;
@@ -37,6 +38,28 @@ pub fn need_reserve_block_info(ctx: Arc<dyn TableContext>, table_idx: usize) ->
     )
 }
 
+/// Returns the bitmap of the vector index search candidates in the rows `offset..offset + num_rows`
+/// of the block, or `None` if the block is read fully.
+pub(crate) fn vector_candidate_bitmap(
+    fuse_part: &FuseBlockPartInfo,
+    offset: usize,
+    num_rows: usize,
+) -> Option<MutableBitmap> {
+    let rows = fuse_part
+        .block_meta_index()?
+        .vector_candidate_rows
+        .as_ref()?;
+    let mut bitmap = MutableBitmap::from_len_zeroed(num_rows);
+    let start = rows.partition_point(|row| *row < offset);
+    for row in rows[start..]
+        .iter()
+        .take_while(|row| **row < offset + num_rows)
+    {
+        bitmap.set(row - offset, true);
+    }
+    Some(bitmap)
+}
+
 pub(crate) fn add_data_block_meta(
     block: DataBlock,
     fuse_part: &FuseBlockPartInfo,
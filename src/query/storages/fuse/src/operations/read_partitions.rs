@@ -194,6 +194,8 @@ impl FuseTable {
                 table_schema.clone(),
                 &push_downs,
                 self.bloom_index_cols(),
+                self.ngram_index_cols(),
+                self.partition_pruning_key(ctx.clone())?,
                 bloom_index_builder,
             )?
         } else {
@@ -207,6 +209,8 @@ impl FuseTable {
                 self.cluster_key_meta.clone(),
                 cluster_keys,
                 self.bloom_index_cols(),
+                self.ngram_index_cols(),
+                self.partition_pruning_key(ctx.clone())?,
                 bloom_index_builder,
             )?
         };
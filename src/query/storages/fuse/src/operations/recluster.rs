@@ -250,6 +250,8 @@ impl FuseTable {
             None,
             vec![],
             BloomIndexColumns::None,
+            BloomIndexColumns::None,
+            None,
             max_concurrency,
             bloom_index_builder,
         )?;
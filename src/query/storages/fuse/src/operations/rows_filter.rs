@@ -0,0 +1,203 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_catalog::plan::build_origin_block_row_num;
+use databend_common_catalog::plan::gen_mutation_stream_meta;
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::StreamContext;
+use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::NgramIndex;
+use databend_storages_common_io::ReadSettings;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use opendal::Operator;
+
+use crate::io::create_inverted_index_builders;
+use crate::io::create_vector_index_builders;
+use crate::io::BlockBuilder;
+use crate::io::BlockReader;
+use crate::io::BlockWriter;
+use crate::operations::read_block;
+use crate::FuseTable;
+
+pub(crate) enum FilteredBlock {
+    /// All the rows are kept.
+    Unchanged,
+    /// None of the rows is kept.
+    Dropped,
+    /// The block with the kept rows only.
+    Rewritten(Arc<BlockMeta>),
+}
+
+/// Reads a block, removes the rows that do not satisfy `keep` and writes down the kept
+/// rows as a new block.
+pub(crate) struct RowsFilter {
+    func_ctx: FunctionContext,
+    // The column refs are the offsets of the columns read by `block_reader`.
+    keep: Expr,
+    block_reader: Arc<BlockReader>,
+    read_settings: ReadSettings,
+    block_builder: BlockBuilder,
+    operator: Operator,
+    stream_ctx: Option<StreamContext>,
+}
+
+impl RowsFilter {
+    /// The column refs of `keep` are the field indexes of the table schema.
+    pub(crate) fn try_create(
+        ctx: Arc<dyn TableContext>,
+        table: &FuseTable,
+        keep: Expr,
+    ) -> Result<Self> {
+        let func_ctx = ctx.get_function_context()?;
+        let schema = table.schema();
+        let source_schema: TableSchemaRef = table
+            .schema_with_stream()
+            .remove_virtual_computed_fields()
+            .into();
+
+        let offsets = keep
+            .column_refs()
+            .into_keys()
+            .map(|index| {
+                let name = schema.field(index).name();
+                let offset = source_schema.index_of(name).map_err(|_| {
+                    ErrorCode::Internal(format!("filtered column '{}' is not stored", name))
+                })?;
+                Ok((index, offset))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+        let keep = keep.project_column_ref(|index| offsets[index]);
+
+        let update_stream_columns = table.change_tracking_enabled();
+        let block_reader = table.create_block_reader(
+            ctx.clone(),
+            Projection::Columns((0..source_schema.num_fields()).collect()),
+            false,
+            update_stream_columns,
+            false,
+        )?;
+        let stream_ctx = if update_stream_columns {
+            Some(StreamContext::try_create(
+                func_ctx.clone(),
+                source_schema.clone(),
+                table.get_table_info().ident.seq,
+                true,
+                false,
+            )?)
+        } else {
+            None
+        };
+
+        let cluster_stats_gen =
+            table.get_cluster_stats_gen(ctx.clone(), 0, table.get_block_thresholds(), None)?;
+        let block_builder = BlockBuilder {
+            ctx: ctx.clone(),
+            meta_locations: table.meta_location_generator().clone(),
+            source_schema: source_schema.clone(),
+            write_settings: table.get_write_settings(),
+            cluster_stats_gen,
+            partition_stats_gen: table.partition_stats_gen(ctx.clone(), &source_schema)?,
+            bloom_columns_map: table
+                .bloom_index_cols
+                .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?,
+            ngram_columns_map: table
+                .ngram_index_cols
+                .bloom_index_fields(source_schema.clone(), NgramIndex::supported_type)?,
+            inverted_index_builders: create_inverted_index_builders(&table.table_info.meta),
+            vector_index_builders: create_vector_index_builders(&table.table_info.meta),
+        };
+
+        Ok(RowsFilter {
+            func_ctx,
+            keep,
+            block_reader,
+            read_settings: ReadSettings::from_ctx(&ctx)?,
+            block_builder,
+            operator: table.get_operator(),
+            stream_ctx,
+        })
+    }
+
+    pub(crate) async fn apply(&self, block_meta: &Arc<BlockMeta>) -> Result<ExpiredBlock> {
+        let mut data_block = read_block(
+            self.block_builder.write_settings.storage_format,
+            &self.block_reader,
+            block_meta,
+            &self.read_settings,
+        )
+        .await?;
+        let num_rows = data_block.num_rows();
+
+        let evaluator = Evaluator::new(&data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let predicates = evaluator
+            .run(&self.keep)
+            .map_err(|e| e.add_message("eval rows filter failed:"))?
+            .try_downcast::<BooleanType>()
+            .ok_or_else(|| ErrorCode::Internal("the rows filter must be a boolean"))?;
+        let kept_rows = match &predicates {
+            Value::Scalar(true) => num_rows,
+            Value::Scalar(false) => 0,
+            Value::Column(bitmap) => bitmap.len() - bitmap.unset_bits(),
+        };
+        if kept_rows == num_rows {
+            return Ok(FilteredBlock::Unchanged);
+        }
+        if kept_rows == 0 {
+            return Ok(FilteredBlock::Dropped);
+        }
+
+        if self.stream_ctx.is_some() {
+            data_block.add_column(build_origin_block_row_num(num_rows));
+        }
+        let mut data_block = data_block.filter_boolean_value(&predicates)?;
+        if let Some(stream_ctx) = &self.stream_ctx {
+            let stream_meta = gen_mutation_stream_meta(None, &block_meta.location.0)?;
+            data_block = stream_ctx.apply(data_block, &stream_meta)?;
+        }
+
+        // serialization and compression is cpu intensive, send them to dedicated thread pool
+        let block_builder = self.block_builder.clone();
+        let origin_stats = block_meta.cluster_stats.clone();
+        let serialized = GlobalIORuntime::instance()
+            .spawn(async move {
+                block_builder.build(data_block, |block, generator| {
+                    let cluster_stats =
+                        generator.gen_with_origin_stats(&block, origin_stats.clone())?;
+                    Ok((cluster_stats, block))
+                })
+            })
+            .await
+            .map_err(|e| {
+                ErrorCode::Internal("failed to serialize block when filtering rows")
+                    .add_message_back(e.to_string())
+            })??;
+        let new_block_meta = BlockWriter::write_down(&self.operator, serialized).await?;
+        Ok(FilteredBlock::Rewritten(Arc::new(new_block_meta)))
+    }
+}
@@ -0,0 +1,265 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::runtime::execute_futures_in_parallel;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::parse_ttl_expr;
+use databend_storages_common_index::RangeIndex;
+use databend_storages_common_pruner::RangePruner;
+use databend_storages_common_table_meta::meta::CompactSegmentInfo;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::Statistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use databend_storages_common_table_meta::meta::Versioned;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
+use log::info;
+
+use crate::io::CachedMetaWriter;
+use crate::io::SegmentsIO;
+use crate::operations::rows_filter::FilteredBlock;
+use crate::operations::rows_filter::RowsFilter;
+use crate::statistics::reduce_block_metas;
+use crate::statistics::reducers::merge_statistics_mut;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// The predicate of the rows that are not expired yet, `None` if the table has no ttl.
+    ///
+    /// The column refs are the field indexes of the table schema. Rows whose expiration
+    /// time is NULL never expire.
+    pub fn ttl_keep_predicate(&self, ctx: Arc<dyn TableContext>) -> Result<Option<Expr>> {
+        let Some(ttl) = self.table_info.options().get(OPT_KEY_TTL) else {
+            return Ok(None);
+        };
+        let now = ctx.get_function_context()?.now;
+        let mut expire_at = parse_ttl_expr(ctx, self.schema(), ttl)?;
+        let nullable = expire_at.data_type().is_nullable();
+        if expire_at.data_type().remove_nullable() == DataType::Date {
+            let dest_type = if nullable {
+                DataType::Timestamp.wrap_nullable()
+            } else {
+                DataType::Timestamp
+            };
+            expire_at = check_cast(None, false, expire_at, &dest_type, &BUILTIN_FUNCTIONS)?;
+        }
+
+        let now = Expr::Constant {
+            span: None,
+            scalar: Scalar::Timestamp(now.timestamp_micros()),
+            data_type: DataType::Timestamp,
+        };
+        let not_expired = check_function(
+            None,
+            "gte",
+            &[],
+            &[expire_at.clone(), now],
+            &BUILTIN_FUNCTIONS,
+        )?;
+        if !nullable {
+            return Ok(Some(not_expired));
+        }
+
+        let no_expiration = check_function(None, "is_null", &[], &[expire_at], &BUILTIN_FUNCTIONS)?;
+        let keep = check_function(
+            None,
+            "or",
+            &[],
+            &[no_expiration, not_expired],
+            &BUILTIN_FUNCTIONS,
+        )?;
+        Ok(Some(check_function(
+            None,
+            "is_true",
+            &[],
+            &[keep],
+            &BUILTIN_FUNCTIONS,
+        )?))
+    }
+
+    /// Removes the expired rows of the table.
+    ///
+    /// The segments and blocks whose rows are all expired, according to their column
+    /// statistics, are dropped without being read, and those that hold no expired row are
+    /// kept as they are, the block metas of a segment are only decoded if the summary of
+    /// the segment may hold expired rows. The blocks that may hold both alive and expired
+    /// rows are rewritten in parallel with the alive rows only, whatever their size is, so
+    /// that the expired rows of the blocks that compaction never picks are removed as well.
+    ///
+    /// Returns true if a new snapshot is committed.
+    #[async_backtrace::framed]
+    pub async fn do_expire(&self, ctx: Arc<dyn TableContext>) -> Result<bool> {
+        let Some(keep) = self.ttl_keep_predicate(ctx.clone())? else {
+            return Ok(false);
+        };
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(false);
+        };
+
+        let schema = self.schema();
+        let func_ctx = ctx.get_function_context()?;
+        let range_index = |predicate: &Expr| {
+            let predicate =
+                predicate.project_column_ref(|index| schema.field(*index).name().to_string());
+            RangeIndex::try_create(
+                func_ctx.clone(),
+                &predicate,
+                schema.clone(),
+                StatisticsOfColumns::default(),
+            )
+        };
+        // May the block hold alive rows.
+        let alive_index = range_index(&keep)?;
+        // May the block hold expired rows.
+        let expired = check_function(None, "not", &[], &[keep.clone()], &BUILTIN_FUNCTIONS)?;
+        let expired_index = range_index(&expired)?;
+        if !expired_index.should_keep(&snapshot.summary.col_stats, None) {
+            return Ok(false);
+        }
+
+        let filter = Arc::new(RowsFilter::try_create(ctx.clone(), self, keep)?);
+        let thresholds = self.get_block_thresholds();
+        let cluster_key_id = self.cluster_key_id();
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), schema.clone());
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let chunk_size = max_threads * 4;
+
+        let mut new_segments = Vec::with_capacity(snapshot.segments.len());
+        let mut new_summary = Statistics::default();
+        let mut dropped_blocks = 0;
+        let mut rewritten_blocks = 0;
+        for chunk in snapshot.segments.chunks(chunk_size.max(1)) {
+            let segments = segments_io
+                .read_segments::<Arc<CompactSegmentInfo>>(chunk, true)
+                .await?;
+
+            // The segments that may hold expired rows, with the expiry of their blocks.
+            let mut expiring = Vec::new();
+            let mut tasks = Vec::new();
+            for (location, segment) in chunk.iter().zip(segments) {
+                let segment = segment?;
+                if !alive_index.should_keep(&segment.summary.col_stats, None) {
+                    dropped_blocks += segment.summary.block_count as usize;
+                    continue;
+                }
+                if !expired_index.should_keep(&segment.summary.col_stats, None) {
+                    merge_statistics_mut(&mut new_summary, &segment.summary, cluster_key_id);
+                    new_segments.push(location.clone());
+                    continue;
+                }
+
+                let mut blocks = Vec::new();
+                for block in segment.block_metas()? {
+                    let expiry = if !alive_index.should_keep(&block.col_stats, None) {
+                        BlockExpiry::Expired
+                    } else if !expired_index.should_keep(&block.col_stats, None) {
+                        BlockExpiry::Alive
+                    } else {
+                        let filter = filter.clone();
+                        let block = block.clone();
+                        tasks.push(async move { filter.apply(&block).await });
+                        BlockExpiry::Mixed
+                    };
+                    blocks.push((block, expiry));
+                }
+                expiring.push((location, segment, blocks));
+            }
+
+            // The results are in the same order as the mixed blocks.
+            let mut filtered = execute_futures_in_parallel(
+                tasks,
+                max_threads,
+                max_threads * 2,
+                "fuse-expire-blocks-worker".to_owned(),
+            )
+            .await?
+            .into_iter();
+
+            for (location, segment, segment_blocks) in expiring {
+                let mut changed = false;
+                let mut blocks = Vec::with_capacity(segment_blocks.len());
+                for (block, expiry) in segment_blocks {
+                    let filtered_block = match expiry {
+                        BlockExpiry::Expired => FilteredBlock::Dropped,
+                        BlockExpiry::Alive => FilteredBlock::Unchanged,
+                        BlockExpiry::Mixed => filtered.next().ok_or_else(|| {
+                            ErrorCode::Internal("missing the result of an expired block rewrite")
+                        })??,
+                    };
+                    match filtered_block {
+                        FilteredBlock::Unchanged => blocks.push(block),
+                        FilteredBlock::Dropped => {
+                            dropped_blocks += 1;
+                            changed = true;
+                        }
+                        FilteredBlock::Rewritten(new_block) => {
+                            rewritten_blocks += 1;
+                            changed = true;
+                            blocks.push(new_block);
+                        }
+                    }
+                }
+                if !changed {
+                    merge_statistics_mut(&mut new_summary, &segment.summary, cluster_key_id);
+                    new_segments.push(location.clone());
+                    continue;
+                }
+                if blocks.is_empty() {
+                    continue;
+                }
+
+                let summary = reduce_block_metas(&blocks, thresholds, cluster_key_id);
+                merge_statistics_mut(&mut new_summary, &summary, cluster_key_id);
+                let new_segment = SegmentInfo::new(blocks, summary);
+                let location = self.meta_location_generator.gen_segment_info_location();
+                new_segment
+                    .write_meta_through_cache(&self.operator, &location)
+                    .await?;
+                new_segments.push((location, SegmentInfo::VERSION));
+            }
+        }
+
+        if dropped_blocks == 0 && rewritten_blocks == 0 {
+            return Ok(false);
+        }
+        info!(
+            "expire table {}: {} blocks dropped, {} blocks rewritten",
+            self.get_table_info().desc,
+            dropped_blocks,
+            rewritten_blocks
+        );
+        self.commit_mutation(&ctx, snapshot, &new_segments, new_summary, None)
+            .await?;
+        Ok(true)
+    }
+}
+
+enum BlockExpiry {
+    // All the rows are expired.
+    Expired,
+    // None of the rows is expired.
+    Alive,
+    // Some of the rows may be expired, the block has to be read.
+    Mixed,
+}
@@ -0,0 +1,213 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_io::ReadSettings;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::Location;
+use opendal::Operator;
+
+use super::inverted_index::InvertedIndexSink;
+use super::inverted_index::InvertedIndexSource;
+use crate::io::BlockWriter;
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::io::VectorIndexBuilder;
+use crate::io::VectorIndexState;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Build the missing vector index files of the table's blocks.
+    ///
+    /// The pipeline has the same shape as the inverted index refresh.
+    #[async_backtrace::framed]
+    pub async fn do_refresh_vector_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        index_name: String,
+        index_version: String,
+        index_options: &BTreeMap<String, String>,
+        index_schema: TableSchemaRef,
+        segment_locs: Option<Vec<Location>>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(());
+        };
+
+        let Some(index_field) = index_schema.fields.first() else {
+            return Err(ErrorCode::RefreshIndexError(format!(
+                "Vector index {} is invalid",
+                index_name
+            )));
+        };
+        let vector_index_builder = VectorIndexBuilder::try_create(
+            index_name.clone(),
+            index_version.clone(),
+            index_field.name().clone(),
+            index_options,
+        )?;
+
+        let table_schema = &self.get_table_info().meta.schema;
+        let field_index = table_schema.index_of(index_field.name())?;
+        let projection = Projection::Columns(vec![field_index]);
+        let block_reader =
+            self.create_block_reader(ctx.clone(), projection, false, false, false)?;
+
+        let segment_reader =
+            MetaReaders::segment_info_reader(self.get_operator(), table_schema.clone());
+
+        // If no segment locations are specified, iterates through all segments
+        let segment_locs = if let Some(segment_locs) = segment_locs {
+            segment_locs
+                .into_iter()
+                .filter(|s| snapshot.segments.contains(s))
+                .collect()
+        } else {
+            snapshot.segments.clone()
+        };
+
+        if segment_locs.is_empty() {
+            return Ok(());
+        }
+        let operator = self.get_operator_ref();
+
+        // Read the segment infos and collect the block metas that need to generate the index.
+        let mut block_metas = VecDeque::new();
+        for (segment_loc, ver) in &segment_locs {
+            let segment_info = segment_reader
+                .read(&LoadParams {
+                    location: segment_loc.to_string(),
+                    len_hint: None,
+                    ver: *ver,
+                    put_cache: false,
+                })
+                .await?;
+
+            for block_meta in segment_info.block_metas()? {
+                let index_location =
+                    TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                        &block_meta.location.0,
+                        &index_name,
+                        &index_version,
+                    );
+                // only generate vector index if it is not exist.
+                if (operator.stat(&index_location).await).is_err() {
+                    block_metas.push_back(block_meta);
+                }
+            }
+        }
+        if block_metas.is_empty() {
+            return Ok(());
+        }
+
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let storage_format = self.get_write_settings().storage_format;
+
+        pipeline.add_source(
+            |output| {
+                let inner = InvertedIndexSource::new(
+                    settings,
+                    storage_format,
+                    block_reader.clone(),
+                    block_metas.clone(),
+                );
+                AsyncSourcer::create(ctx.clone(), output, inner)
+            },
+            1,
+        )?;
+
+        let block_nums = block_metas.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(block_nums, max_threads);
+        pipeline.try_resize(max_threads)?;
+        pipeline.add_async_transformer(|| {
+            VectorIndexTransform::new(
+                vector_index_builder.clone(),
+                index_schema.clone(),
+                operator.clone(),
+            )
+        });
+
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| InvertedIndexSink::try_create(input, block_nums))?;
+
+        Ok(())
+    }
+}
+
+/// `VectorIndexTransform` is used to generate vector index for each blocks.
+pub struct VectorIndexTransform {
+    vector_index_builder: VectorIndexBuilder,
+    source_schema: TableSchemaRef,
+    operator: Operator,
+}
+
+impl VectorIndexTransform {
+    pub fn new(
+        vector_index_builder: VectorIndexBuilder,
+        source_schema: TableSchemaRef,
+        operator: Operator,
+    ) -> Self {
+        Self {
+            vector_index_builder,
+            source_schema,
+            operator,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for VectorIndexTransform {
+    const NAME: &'static str = "VectorIndexTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
+        let block_meta = data_block
+            .get_meta()
+            .and_then(BlockMeta::downcast_ref_from)
+            .unwrap();
+
+        let vector_index_state = VectorIndexState::try_create(
+            &self.source_schema,
+            &data_block,
+            &block_meta.location,
+            &self.vector_index_builder,
+        )?;
+        BlockWriter::write_down_vector_index_state(
+            &self.operator,
+            vector_index_state.into_iter().collect(),
+        )
+        .await?;
+
+        let new_block = DataBlock::new(vec![], 0);
+        Ok(new_block)
+    }
+}
@@ -273,6 +273,7 @@ impl BlockPruner {
                         segment_location: segment_location.location.0.clone(),
                         snapshot_location: segment_location.snapshot_loc.clone(),
                         matched_rows: prune_result.matched_rows.clone(),
+                        vector_candidate_rows: None,
                     },
                     block,
                 ))
@@ -340,6 +341,7 @@ impl BlockPruner {
                             segment_location: segment_location.location.0.clone(),
                             snapshot_location: segment_location.snapshot_loc.clone(),
                             matched_rows: None,
+                            vector_candidate_rows: None,
                         },
                         block_meta.clone(),
                     ))
@@ -15,6 +15,8 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
@@ -30,6 +32,7 @@ use databend_common_sql::BloomIndexColumns;
 use databend_storages_common_index::filters::BlockFilter;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_index::FilterEvalResult;
+use databend_storages_common_index::NgramIndex;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
@@ -37,9 +40,11 @@ use log::info;
 use log::warn;
 use opendal::Operator;
 
+use crate::io::load_ngram_filter;
 use crate::io::BlockWriter;
 use crate::io::BloomBlockFilterReader;
 use crate::io::BloomIndexBuilder;
+use crate::io::TableMetaLocationGenerator;
 
 #[async_trait::async_trait]
 pub trait BloomPruner {
@@ -60,6 +65,12 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// indices that should be loaded from n-gram filter block
+    ngram_index_fields: Vec<TableField>,
+
+    /// ids of all the columns with n-gram index, which locate the n-gram filter block
+    ngram_index_column_ids: Vec<ColumnId>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -74,6 +85,9 @@ pub struct BloomPrunerCreator {
 
     /// bloom index builder, if set to Some(_), missing bloom index will be built during pruning
     bloom_index_builder: Option<BloomIndexBuilder>,
+
+    /// whether a failure of the n-gram pruner has been logged, it is logged once per table
+    ngram_failure_logged: AtomicBool,
 }
 
 impl BloomPrunerCreator {
@@ -83,6 +97,7 @@ impl BloomPrunerCreator {
         dal: Operator,
         filter_expr: Option<&Expr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
         bloom_index_builder: Option<BloomIndexBuilder>,
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
@@ -91,7 +106,16 @@ impl BloomPrunerCreator {
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
             let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
 
-            if !point_query_cols.is_empty() {
+            let ngram_columns_map =
+                ngram_index_cols.bloom_index_fields(schema.clone(), NgramIndex::supported_type)?;
+            let ngram_column_fields = ngram_columns_map.values().cloned().collect::<Vec<_>>();
+            let ngram_index_column_ids = ngram_column_fields
+                .iter()
+                .map(|field| field.column_id())
+                .collect();
+            let ngram_index_fields = NgramIndex::find_substring_columns(expr, &ngram_column_fields);
+
+            if !point_query_cols.is_empty() || !ngram_index_fields.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -106,11 +130,14 @@ impl BloomPrunerCreator {
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    ngram_index_fields,
+                    ngram_index_column_ids,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
                     data_schema: schema.clone(),
                     bloom_index_builder,
+                    ngram_failure_logged: AtomicBool::new(false),
                 };
                 return Ok(Some(Arc::new(creator)));
             }
@@ -207,6 +234,56 @@ impl BloomPrunerCreator {
         }
     }
 
+    // Check a block is hit or not by the n-gram filters.
+    #[async_backtrace::framed]
+    pub async fn apply_ngram(
+        &self,
+        column_stats: &StatisticsOfColumns,
+        column_ids_of_indexed_block: &[ColumnId],
+        block_meta: &BlockMeta,
+    ) -> Result<bool> {
+        // filter out columns that no longer exist in the indexed block
+        let index_columns = self
+            .ngram_index_fields
+            .iter()
+            .filter(|field| column_ids_of_indexed_block.contains(&field.column_id()))
+            .map(NgramIndex::build_filter_column_name)
+            .collect::<Vec<_>>();
+        if index_columns.is_empty() {
+            return Ok(true);
+        }
+
+        let index_location =
+            TableMetaLocationGenerator::gen_ngram_index_location_from_block_location(
+                &block_meta.location.0,
+                self.ngram_index_column_ids.iter().copied(),
+            );
+        // the size is only known for the index written with the block
+        let index_length = block_meta
+            .ngram_index_location
+            .as_ref()
+            .filter(|(location, _)| *location == index_location)
+            .map(|_| block_meta.ngram_index_size);
+        let filter = load_ngram_filter(
+            self.dal.clone(),
+            &index_columns,
+            &index_location,
+            index_length,
+        )
+        .await?;
+
+        Ok(NgramIndex::from_filter_block(
+            self.func_ctx.clone(),
+            filter.filter_schema,
+            filter.filters,
+        )
+        .apply(
+            self.filter_expression.clone(),
+            column_stats,
+            self.data_schema.clone(),
+        )? != FilterEvalResult::MustFalse)
+    }
+
     async fn try_rebuild_missing_bloom_index(
         &self,
         block_meta: &BlockMeta,
@@ -273,21 +350,51 @@ impl BloomPruner for BloomPrunerCreator {
         column_ids: Vec<ColumnId>,
         block_meta: &BlockMeta,
     ) -> bool {
-        if let Some(loc) = index_location {
-            // load filter, and try pruning according to filter expression
-            match self
-                .apply(loc, index_length, column_stats, column_ids, block_meta)
-                .await
-            {
-                Ok(v) => v,
-                Err(e) => {
-                    // swallow exceptions intentionally, corrupted index should not prevent execution
-                    warn!("failed to apply bloom pruner, returning true. {}", e);
-                    true
+        let keep = match index_location {
+            Some(loc) if !self.index_fields.is_empty() => {
+                // load filter, and try pruning according to filter expression
+                match self
+                    .apply(
+                        loc,
+                        index_length,
+                        column_stats,
+                        column_ids.clone(),
+                        block_meta,
+                    )
+                    .await
+                {
+                    Ok(v) => v,
+                    Err(e) => {
+                        // swallow exceptions intentionally, corrupted index should not prevent execution
+                        warn!("failed to apply bloom pruner, returning true. {}", e);
+                        true
+                    }
                 }
             }
-        } else {
-            true
+            _ => true,
+        };
+
+        if !keep || self.ngram_index_fields.is_empty() {
+            return keep;
+        }
+
+        match self
+            .apply_ngram(column_stats, &column_ids, block_meta)
+            .await
+        {
+            Ok(v) => v,
+            Err(e) => {
+                // swallow exceptions intentionally, a missing or corrupted index should not
+                // prevent execution. The n-gram index of the block may not be built yet, e.g.
+                // the block was written before the index was declared and has not been refreshed.
+                if !self.ngram_failure_logged.swap(true, Ordering::Relaxed) {
+                    warn!(
+                        "failed to apply ngram pruner, returning true, the failures of the other blocks of the table are not logged. {}",
+                        e
+                    );
+                }
+                true
+            }
         }
     }
 }
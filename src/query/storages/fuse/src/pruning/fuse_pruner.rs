@@ -21,6 +21,7 @@ use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::Expr;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::SEGMENT_NAME_COL_NAME;
@@ -60,7 +61,9 @@ use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
 use crate::pruning::InvertedIndexPruner;
+use crate::pruning::PartitionPruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorIndexPruner;
 
 pub struct PruningContext {
     pub ctx: Arc<dyn TableContext>,
@@ -70,10 +73,12 @@ pub struct PruningContext {
 
     pub limit_pruner: Arc<dyn Limiter + Send + Sync>,
     pub range_pruner: Arc<dyn RangePruner + Send + Sync>,
+    pub partition_pruner: Option<Arc<PartitionPruner>>,
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
     pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
+    pub vector_index_pruner: Option<Arc<VectorIndexPruner>>,
 
     pub pruning_stats: Arc<FusePruningStatistics>,
 }
@@ -88,6 +93,8 @@ impl PruningContext {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
+        partition_key: Option<Expr<String>>,
         max_concurrency: usize,
         bloom_index_builder: Option<BloomIndexBuilder>,
     ) -> Result<Arc<PruningContext>> {
@@ -133,6 +140,11 @@ impl PruningContext {
             default_stats,
         )?;
 
+        // Partition pruner.
+        // None will be returned, if the table is not partitioned or the filter does not use the partition key
+        let partition_pruner =
+            PartitionPruner::try_create(func_ctx.clone(), partition_key, filter_expr.as_ref());
+
         // Bloom pruner.
         // None will be returned, if filter is not applicable (e.g. unsuitable filter expression, index not available, etc.)
         let bloom_pruner = BloomPrunerCreator::create(
@@ -141,6 +153,7 @@ impl PruningContext {
             dal.clone(),
             filter_expr.as_ref(),
             bloom_index_cols,
+            ngram_index_cols,
             bloom_index_builder,
         )?;
 
@@ -156,6 +169,10 @@ impl PruningContext {
         // inverted index pruner, used to search matched rows in block
         let inverted_index_pruner = InvertedIndexPruner::try_create(ctx, dal.clone(), push_down)?;
 
+        // vector index pruner, used to search the candidate nearest rows in block
+        let vector_index_pruner =
+            VectorIndexPruner::try_create(ctx, dal.clone(), push_down, max_concurrency)?;
+
         // Internal column pruner, if there are predicates using internal columns,
         // we can use them to prune segments and blocks.
         let internal_column_pruner =
@@ -179,10 +196,12 @@ impl PruningContext {
             pruning_semaphore,
             limit_pruner,
             range_pruner,
+            partition_pruner,
             bloom_pruner,
             page_pruner,
             internal_column_pruner,
             inverted_index_pruner,
+            vector_index_pruner,
             pruning_stats,
         });
         Ok(pruning_ctx)
@@ -207,6 +226,8 @@ impl FusePruner {
         table_schema: TableSchemaRef,
         push_down: &Option<PushDownInfo>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
+        partition_key: Option<Expr<String>>,
         bloom_index_builder: Option<BloomIndexBuilder>,
     ) -> Result<Self> {
         Self::create_with_pages(
@@ -217,6 +238,8 @@ impl FusePruner {
             None,
             vec![],
             bloom_index_cols,
+            ngram_index_cols,
+            partition_key,
             bloom_index_builder,
         )
     }
@@ -230,6 +253,8 @@ impl FusePruner {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
+        partition_key: Option<Expr<String>>,
         bloom_index_builder: Option<BloomIndexBuilder>,
     ) -> Result<Self> {
         let max_concurrency = {
@@ -255,6 +280,8 @@ impl FusePruner {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            ngram_index_cols,
+            partition_key,
             max_concurrency,
             bloom_index_builder,
         )?;
@@ -287,7 +314,7 @@ impl FusePruner {
     }
 
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> topn pruner -> vector index pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &mut self,
@@ -398,7 +425,8 @@ impl FusePruner {
             // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
             // will get here, we can prevent other mutations like update and so on.
             // TopN pruner.
-            self.topn_pruning(metas)
+            let metas = self.topn_pruning(metas)?;
+            self.vector_index_pruning(metas).await
         }
     }
 
@@ -470,7 +498,8 @@ impl FusePruner {
         // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
         // will get here, we can prevent other mutations like update and so on.
         // TopN pruner.
-        self.topn_pruning(metas)
+        let metas = self.topn_pruning(metas)?;
+        self.vector_index_pruning(metas).await
     }
 
     // topn pruner:
@@ -495,6 +524,19 @@ impl FusePruner {
         Ok(metas)
     }
 
+    // vector index pruner:
+    // if there are ordering by distance + limit clause and no filters, use vector index pruner
+    #[async_backtrace::framed]
+    async fn vector_index_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        match &self.pruning_ctx.vector_index_pruner {
+            Some(vector_index_pruner) => vector_index_pruner.prune(metas).await,
+            None => Ok(metas),
+        }
+    }
+
     // Pruning stats.
     pub fn pruning_stats(&self) -> databend_common_catalog::plan::PruningStatistics {
         let stats = self.pruning_ctx.pruning_stats.clone();
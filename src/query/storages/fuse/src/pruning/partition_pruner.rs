@@ -0,0 +1,188 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+// Every segment of a partitioned table holds the rows of a single partition, and records
+// the partition value in its summary. The occurrences of the `PARTITION BY` expression in
+// the filter are replaced by the partition value of the segment, if the filter is then
+// folded to false, none of the rows of the segment can match the filter.
+//
+// Legacy segments, written before the table was partitioned, have no partition value and
+// are always kept.
+pub struct PartitionPruner {
+    func_ctx: FunctionContext,
+    // The `PARTITION BY` expression, the column refs are the column names.
+    partition_key: Expr<String>,
+    filter: Expr<String>,
+}
+
+impl PartitionPruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        partition_key: Option<Expr<String>>,
+        filter: Option<&Expr<String>>,
+    ) -> Option<Arc<PartitionPruner>> {
+        let partition_key = partition_key?;
+        let filter = filter?;
+        if !contains_partition_key(filter, &partition_key) {
+            return None;
+        }
+        Some(Arc::new(PartitionPruner {
+            func_ctx,
+            partition_key,
+            filter: filter.clone(),
+        }))
+    }
+
+    pub fn should_keep(&self, partition_value: Option<&Scalar>) -> bool {
+        let Some(partition_value) = partition_value else {
+            return true;
+        };
+        let expr = replace_partition_key(&self.filter, &self.partition_key, partition_value);
+        let (folded, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        !matches!(folded, Expr::Constant {
+            scalar: Scalar::Boolean(false),
+            ..
+        })
+    }
+}
+
+// Spans and display names are ignored, the filter and the partition key are parsed apart.
+fn is_partition_key(expr: &Expr<String>, partition_key: &Expr<String>) -> bool {
+    match (expr, partition_key) {
+        (Expr::Constant { scalar: l, .. }, Expr::Constant { scalar: r, .. }) => l == r,
+        (Expr::ColumnRef { id: l, .. }, Expr::ColumnRef { id: r, .. }) => l == r,
+        (
+            Expr::Cast {
+                is_try: l_is_try,
+                expr: l_expr,
+                dest_type: l_dest_type,
+                ..
+            },
+            Expr::Cast {
+                is_try: r_is_try,
+                expr: r_expr,
+                dest_type: r_dest_type,
+                ..
+            },
+        ) => l_is_try == r_is_try && l_dest_type == r_dest_type && is_partition_key(l_expr, r_expr),
+        (
+            Expr::FunctionCall {
+                id: l_id,
+                generics: l_generics,
+                args: l_args,
+                ..
+            },
+            Expr::FunctionCall {
+                id: r_id,
+                generics: r_generics,
+                args: r_args,
+                ..
+            },
+        ) => {
+            l_id == r_id
+                && l_generics == r_generics
+                && l_args.len() == r_args.len()
+                && l_args
+                    .iter()
+                    .zip(r_args)
+                    .all(|(l, r)| is_partition_key(l, r))
+        }
+        _ => false,
+    }
+}
+
+fn contains_partition_key(expr: &Expr<String>, partition_key: &Expr<String>) -> bool {
+    if is_partition_key(expr, partition_key) {
+        return true;
+    }
+    match expr {
+        Expr::Cast { expr, .. } => contains_partition_key(expr, partition_key),
+        Expr::FunctionCall { args, .. } | Expr::LambdaFunctionCall { args, .. } => args
+            .iter()
+            .any(|arg| contains_partition_key(arg, partition_key)),
+        Expr::Constant { .. } | Expr::ColumnRef { .. } => false,
+    }
+}
+
+fn replace_partition_key(
+    expr: &Expr<String>,
+    partition_key: &Expr<String>,
+    partition_value: &Scalar,
+) -> Expr<String> {
+    if is_partition_key(expr, partition_key) {
+        return Expr::Constant {
+            span: expr.span(),
+            scalar: partition_value.clone(),
+            data_type: expr.data_type().clone(),
+        };
+    }
+    match expr {
+        Expr::Cast {
+            span,
+            is_try,
+            expr,
+            dest_type,
+        } => Expr::Cast {
+            span: *span,
+            is_try: *is_try,
+            expr: Box::new(replace_partition_key(expr, partition_key, partition_value)),
+            dest_type: dest_type.clone(),
+        },
+        Expr::FunctionCall {
+            span,
+            id,
+            function,
+            generics,
+            args,
+            return_type,
+        } => Expr::FunctionCall {
+            span: *span,
+            id: id.clone(),
+            function: function.clone(),
+            generics: generics.clone(),
+            args: args
+                .iter()
+                .map(|arg| replace_partition_key(arg, partition_key, partition_value))
+                .collect(),
+            return_type: return_type.clone(),
+        },
+        Expr::LambdaFunctionCall {
+            span,
+            name,
+            args,
+            lambda_expr,
+            lambda_display,
+            return_type,
+        } => Expr::LambdaFunctionCall {
+            span: *span,
+            name: name.clone(),
+            args: args
+                .iter()
+                .map(|arg| replace_partition_key(arg, partition_key, partition_value))
+                .collect(),
+            lambda_expr: lambda_expr.clone(),
+            lambda_display: lambda_display.clone(),
+            return_type: return_type.clone(),
+        },
+        Expr::Constant { .. } | Expr::ColumnRef { .. } => expr.clone(),
+    }
+}
@@ -52,6 +52,7 @@ impl SegmentPruner {
 
         let pruning_stats = self.pruning_ctx.pruning_stats.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
+        let partition_pruner = self.pruning_ctx.partition_pruner.clone();
 
         for segment_location in segment_locs {
             let info = SegmentsIO::read_compact_segment(
@@ -71,7 +72,11 @@ impl SegmentPruner {
                 pruning_stats.set_segments_range_pruning_before(1);
             }
 
-            if range_pruner.should_keep(&info.summary.col_stats, None) {
+            // The segments pruned by partition value are accounted as range pruned.
+            let keep = partition_pruner.as_ref().map_or(true, |p| {
+                p.should_keep(info.summary.partition_value.as_ref())
+            }) && range_pruner.should_keep(&info.summary.col_stats, None);
+            if keep {
                 // Perf.
                 {
                     metrics_inc_segments_range_pruning_after(1);
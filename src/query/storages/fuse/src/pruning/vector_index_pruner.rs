@@ -0,0 +1,156 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_exception::Result;
+use databend_storages_common_io::ReadSettings;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use opendal::Operator;
+
+use crate::io::TableMetaLocationGenerator;
+use crate::io::VectorIndexReader;
+use crate::TableContext;
+
+// The distances of the index are approximate, more candidates than the limit are
+// selected, and ranked by the exact distances after reading.
+const CANDIDATES_PER_LIMIT: usize = 2;
+
+// Each block file has a corresponding vector index file.
+// The index of each block returns its approximate nearest rows of the query vector,
+// the global nearest rows are selected from them as the candidates, the blocks without
+// any candidate are pruned, and only the candidate rows of the other blocks are read.
+// Blocks without an index file are always kept and read fully, they are ranked by the
+// sort after reading.
+pub struct VectorIndexPruner {
+    dal: Operator,
+    settings: ReadSettings,
+    index_name: String,
+    index_version: String,
+    query: Vec<f32>,
+    limit: usize,
+    probes: Option<usize>,
+    max_concurrency: usize,
+}
+
+impl VectorIndexPruner {
+    pub fn try_create(
+        ctx: &Arc<dyn TableContext>,
+        dal: Operator,
+        push_down: &Option<PushDownInfo>,
+        max_concurrency: usize,
+    ) -> Result<Option<Arc<VectorIndexPruner>>> {
+        // Rows filtered out may be the nearest rows, so filters disable the pruner.
+        let vector_index_info = push_down
+            .as_ref()
+            .filter(|p| p.filters.is_none() && p.prewhere.is_none())
+            .and_then(|p| p.vector_index.as_ref());
+        let Some(vector_index_info) = vector_index_info else {
+            return Ok(None);
+        };
+        let probes = vector_index_info
+            .index_options
+            .get("probes")
+            .and_then(|v| v.parse::<usize>().ok());
+
+        Ok(Some(Arc::new(VectorIndexPruner {
+            dal,
+            settings: ReadSettings::from_ctx(ctx)?,
+            index_name: vector_index_info.index_name.clone(),
+            index_version: vector_index_info.index_version.clone(),
+            query: vector_index_info.query_values.iter().map(|v| v.0).collect(),
+            limit: vector_index_info.limit,
+            probes,
+            max_concurrency: max_concurrency.max(1),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn prune(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let num_candidates = self.limit.saturating_mul(CANDIDATES_PER_LIMIT);
+        let reader = VectorIndexReader::create(self.dal.clone(), self.settings);
+
+        // The nearest rows of each indexed block, `None` if the block is not indexed.
+        let mut block_rows = Vec::with_capacity(metas.len());
+        for chunk in metas.chunks(self.max_concurrency) {
+            let futures = chunk.iter().map(|(_, block_meta)| {
+                let index_loc =
+                    TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                        &block_meta.location.0,
+                        &self.index_name,
+                        &self.index_version,
+                    );
+                let reader = &reader;
+                async move {
+                    reader
+                        .search(&index_loc, &self.query, num_candidates, self.probes)
+                        .await
+                }
+            });
+            block_rows.extend(futures::future::try_join_all(futures).await?);
+        }
+
+        let mut candidates = block_rows
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, rows)| rows.as_ref().map(|rows| (pos, rows)))
+            .flat_map(|(pos, rows)| rows.iter().map(move |(row, d)| (*d, pos, *row)))
+            .collect::<Vec<_>>();
+        // The rows not in the index, e.g. NULL vectors, are ranked after the indexed rows,
+        // they are needed only if the indexed rows are not enough.
+        if candidates.len() < self.limit {
+            return Ok(metas);
+        }
+        if candidates.len() > num_candidates {
+            // NaN distances are ordered last, the same as the sort.
+            candidates.select_nth_unstable_by(num_candidates, |a, b| {
+                (a.0.is_nan(), a.0)
+                    .partial_cmp(&(b.0.is_nan(), b.0))
+                    .unwrap_or(Ordering::Equal)
+            });
+            candidates.truncate(num_candidates);
+        }
+
+        let mut candidate_rows = block_rows
+            .iter()
+            .map(|rows| rows.as_ref().map(|_| vec![]))
+            .collect::<Vec<_>>();
+        for (_, pos, row) in candidates {
+            if let Some(rows) = &mut candidate_rows[pos] {
+                rows.push(row as usize);
+            }
+        }
+
+        Ok(metas
+            .into_iter()
+            .zip(candidate_rows)
+            .filter_map(|((mut index, block_meta), rows)| match rows {
+                // The block is not indexed.
+                None => Some((index, block_meta)),
+                Some(rows) if rows.is_empty() => None,
+                Some(mut rows) => {
+                    rows.sort_unstable();
+                    index.vector_candidate_rows = Some(rows);
+                    Some((index, block_meta))
+                }
+            })
+            .collect())
+    }
+}
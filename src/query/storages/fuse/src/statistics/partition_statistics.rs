@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+/// Evaluates the `PARTITION BY` expression of a table on the blocks to be written.
+///
+/// The column refs of `partition_key` are the offsets of the columns in the blocks.
+#[derive(Clone)]
+pub struct PartitionStatsGenerator {
+    partition_key: Expr,
+    func_ctx: FunctionContext,
+}
+
+impl PartitionStatsGenerator {
+    pub fn new(partition_key: Expr, func_ctx: FunctionContext) -> Self {
+        Self {
+            partition_key,
+            func_ctx,
+        }
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<Value<AnyType>> {
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        evaluator.run(&self.partition_key)
+    }
+
+    /// Splits the block so that every output block holds the rows of a single partition.
+    ///
+    /// The relative order of the rows inside a partition is kept.
+    pub fn split(&self, block: DataBlock) -> Result<Vec<DataBlock>> {
+        let num_rows = block.num_rows();
+        if num_rows == 0 {
+            return Ok(vec![block]);
+        }
+
+        let keys = match self.eval(&block)? {
+            // All the rows belong to the same partition.
+            Value::Scalar(_) => return Ok(vec![block]),
+            Value::Column(column) => column,
+        };
+
+        let mut partitions: BTreeMap<Scalar, Vec<u32>> = BTreeMap::new();
+        for (row, key) in keys.iter().enumerate() {
+            partitions
+                .entry(key.to_owned())
+                .or_default()
+                .push(row as u32);
+        }
+        if partitions.len() == 1 {
+            return Ok(vec![block]);
+        }
+
+        partitions
+            .values()
+            .map(|indices| block.take(indices, &mut None))
+            .collect()
+    }
+
+    /// Returns the partition value of a block, the rows of the block must belong to the same partition.
+    pub fn gen_stats(&self, block: &DataBlock) -> Result<Option<Scalar>> {
+        let num_rows = block.num_rows();
+        if num_rows == 0 {
+            return Ok(None);
+        }
+
+        let value = match self.eval(block)? {
+            Value::Scalar(scalar) => scalar,
+            Value::Column(column) => {
+                let first = column.index(0).unwrap().to_owned();
+                if column.iter().any(|key| key != first.as_ref()) {
+                    return Err(ErrorCode::Internal(
+                        "The rows of a block span more than one partition",
+                    ));
+                }
+                first
+            }
+        };
+        Ok(Some(value))
+    }
+}
@@ -146,12 +146,16 @@ pub fn merge_statistics_mut(
     if l.row_count == 0 {
         l.col_stats = r.col_stats.clone();
         l.cluster_stats = r.cluster_stats.clone();
+        l.partition_value = r.partition_value.clone();
     } else {
         l.col_stats = reduce_block_statistics(&[&l.col_stats, &r.col_stats]);
         l.cluster_stats = reduce_cluster_statistics(
             &[&l.cluster_stats, &r.cluster_stats],
             default_cluster_key_id,
         );
+        if r.row_count != 0 && l.partition_value != r.partition_value {
+            l.partition_value = None;
+        }
     }
 
     l.row_count += r.row_count;
@@ -177,6 +181,9 @@ pub fn deduct_statistics_mut(l: &mut Statistics, r: &Statistics) {
     l.uncompressed_byte_size -= r.uncompressed_byte_size;
     l.compressed_byte_size -= r.compressed_byte_size;
     l.index_size -= r.index_size;
+    if l.row_count == 0 {
+        l.partition_value = None;
+    }
     for (id, col_stats) in &mut l.col_stats {
         if let Some(r_col_stats) = r.col_stats.get(id) {
             // The MinMax of a column cannot be recalculated by the right statistics,
@@ -202,6 +209,7 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
     let len = block_metas.len();
     let mut col_stats = Vec::with_capacity(len);
     let mut cluster_stats = Vec::with_capacity(len);
+    let mut partition_values = Vec::with_capacity(len);
 
     block_metas.iter().for_each(|b| {
         let b = b.borrow();
@@ -218,6 +226,7 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
         }
         col_stats.push(&b.col_stats);
         cluster_stats.push(&b.cluster_stats);
+        partition_values.push(&b.partition_value);
     });
 
     let merged_col_stats = reduce_block_statistics(&col_stats);
@@ -232,5 +241,16 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
         index_size,
         col_stats: merged_col_stats,
         cluster_stats: merged_cluster_stats,
+        partition_value: reduce_partition_values(&partition_values),
+    }
+}
+
+/// Returns the partition value shared by all the blocks, `None` if they span more than one partition.
+fn reduce_partition_values(partition_values: &[&Option<Scalar>]) -> Option<Scalar> {
+    let (first, rest) = partition_values.split_first()?;
+    if rest.iter().all(|v| v == first) {
+        (*first).clone()
+    } else {
+        None
     }
 }
@@ -30,6 +30,8 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
+use databend_common_meta_app::schema::AlterSequenceReply;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::CatalogOption;
 use databend_common_meta_app::schema::CommitTableMetaReply;
@@ -82,8 +84,11 @@ use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
+use databend_common_meta_app::schema::SequenceMeta;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -562,6 +567,16 @@ impl Catalog for HiveCatalog {
         ))
     }
 
+    #[async_backtrace::framed]
+    async fn set_table_row_access_policy(
+        &self,
+        _req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot set_table_row_access_policy in HIVE catalog",
+        ))
+    }
+
     #[async_backtrace::framed]
     async fn get_table_copied_file_info(
         &self,
@@ -705,6 +720,10 @@ impl Catalog for HiveCatalog {
         unimplemented!()
     }
 
+    async fn list_sequences(&self, _tenant: &Tenant) -> Result<Vec<(String, SequenceMeta)>> {
+        unimplemented!()
+    }
+
     async fn get_sequence_next_value(
         &self,
         _req: GetSequenceNextValueReq,
@@ -712,6 +731,10 @@ impl Catalog for HiveCatalog {
         unimplemented!()
     }
 
+    async fn alter_sequence(&self, _req: AlterSequenceReq) -> Result<AlterSequenceReply> {
+        unimplemented!()
+    }
+
     async fn drop_sequence(&self, _req: DropSequenceReq) -> Result<DropSequenceReply> {
         unimplemented!()
     }
@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct DynamicTableRefreshHistoryTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for DynamicTableRefreshHistoryTable {
+    const NAME: &'static str = "system.dynamic_table_refresh_history";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let refreshes = UserApiProvider::instance()
+            .dynamic_table_refresh_api(&tenant)?
+            .list_refreshes(None)
+            .await?;
+
+        let mut databases = Vec::with_capacity(refreshes.len());
+        let mut tables = Vec::with_capacity(refreshes.len());
+        let mut table_ids = Vec::with_capacity(refreshes.len());
+        let mut refresh_ids = Vec::with_capacity(refreshes.len());
+        let mut refresh_actions = Vec::with_capacity(refreshes.len());
+        let mut refresh_triggers = Vec::with_capacity(refreshes.len());
+        let mut states = Vec::with_capacity(refreshes.len());
+        let mut messages = Vec::with_capacity(refreshes.len());
+        let mut started_ats = Vec::with_capacity(refreshes.len());
+        let mut completed_ats = Vec::with_capacity(refreshes.len());
+        for refresh in refreshes {
+            databases.push(refresh.database);
+            tables.push(refresh.table);
+            table_ids.push(refresh.table_id);
+            refresh_ids.push(refresh.refresh_id);
+            refresh_actions.push(refresh.refresh_action);
+            refresh_triggers.push(refresh.refresh_trigger);
+            states.push(refresh.state);
+            messages.push(refresh.message);
+            started_ats.push(refresh.started_at);
+            completed_ats.push(refresh.completed_at);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(databases),
+            StringType::from_data(tables),
+            UInt64Type::from_data(table_ids),
+            StringType::from_data(refresh_ids),
+            StringType::from_data(refresh_actions),
+            StringType::from_data(refresh_triggers),
+            StringType::from_data(states),
+            StringType::from_data(messages),
+            TimestampType::from_data(started_ats),
+            TimestampType::from_data(completed_ats),
+        ]))
+    }
+}
+
+impl DynamicTableRefreshHistoryTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("table_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("refresh_id", TableDataType::String),
+            TableField::new("refresh_action", TableDataType::String),
+            TableField::new("refresh_trigger", TableDataType::String),
+            TableField::new("state", TableDataType::String),
+            TableField::new("message", TableDataType::String),
+            TableField::new("started_at", TableDataType::Timestamp),
+            TableField::new("completed_at", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'dynamic_table_refresh_history'".to_string(),
+            name: "dynamic_table_refresh_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemDynamicTableRefreshHistory".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(DynamicTableRefreshHistoryTable { table_info })
+    }
+}
@@ -34,6 +34,7 @@ mod contributors_table;
 mod credits_table;
 mod databases_table;
 mod dictionaries_table;
+mod dynamic_table_refresh_history_table;
 mod engines_table;
 mod functions_table;
 mod indexes_table;
@@ -52,6 +53,7 @@ mod queries_profiling;
 mod query_cache_table;
 mod query_log_table;
 mod roles_table;
+mod sequences_table;
 mod settings_table;
 mod stages_table;
 mod streams_table;
@@ -82,6 +84,7 @@ pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
 pub use dictionaries_table::DictionariesTable;
+pub use dynamic_table_refresh_history_table::DynamicTableRefreshHistoryTable;
 pub use engines_table::EnginesTable;
 pub use functions_table::FunctionsTable;
 pub use indexes_table::IndexesTable;
@@ -108,6 +111,7 @@ pub use query_log_table::QueryLogElement;
 pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
 pub use roles_table::RolesTable;
+pub use sequences_table::SequencesTable;
 pub use settings_table::SettingsTable;
 pub use stages_table::StagesTable;
 pub use streams_table::FullStreamsTable;
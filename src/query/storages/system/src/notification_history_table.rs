@@ -94,9 +94,9 @@ impl AsyncSystemTable for NotificationHistoryTable {
         push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let config = GlobalConfig::instance();
-        if config.query.cloud_control_grpc_server_address.is_none() {
+        if !config.query.notification_service_enabled() {
             return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.notification_history table without cloud control enabled, please set cloud_control_grpc_server_address in config",
+                "cannot view system.notification_history table without cloud control enabled, please set cloud_control_grpc_server_address or enable_builtin_task_scheduler in config",
             ));
         }
 
@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::is_auto_increment_sequence;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct SequencesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for SequencesTable {
+    const NAME: &'static str = "system.sequences";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        // the sequences of AUTOINCREMENT columns are internal.
+        let sequences = catalog
+            .list_sequences(&tenant)
+            .await?
+            .into_iter()
+            .filter(|(name, _)| !is_auto_increment_sequence(name))
+            .collect::<Vec<_>>();
+
+        let mut names = Vec::with_capacity(sequences.len());
+        let mut starts = Vec::with_capacity(sequences.len());
+        let mut increments = Vec::with_capacity(sequences.len());
+        let mut next_values = Vec::with_capacity(sequences.len());
+        let mut caches = Vec::with_capacity(sequences.len());
+        let mut comments = Vec::with_capacity(sequences.len());
+        let mut created_ons = Vec::with_capacity(sequences.len());
+        let mut updated_ons = Vec::with_capacity(sequences.len());
+        for (name, meta) in sequences {
+            names.push(name);
+            starts.push(meta.start);
+            increments.push(meta.step);
+            next_values.push(meta.current);
+            caches.push(meta.cache);
+            comments.push(meta.comment.unwrap_or_default());
+            created_ons.push(meta.create_on.timestamp_micros());
+            updated_ons.push(meta.update_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(starts),
+            Int64Type::from_data(increments),
+            UInt64Type::from_data(next_values),
+            UInt64Type::from_data(caches),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_ons),
+            TimestampType::from_data(updated_ons),
+        ]))
+    }
+}
+
+impl SequencesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("start", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("increment", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new("next_value", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("cache", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("updated_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'sequences'".to_string(),
+            name: "sequences".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemSequences".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(SequencesTable { table_info })
+    }
+}
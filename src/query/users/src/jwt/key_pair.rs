@@ -0,0 +1,194 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use jwt_simple::prelude::Duration;
+use jwt_simple::prelude::Ed25519PublicKey;
+use jwt_simple::prelude::EdDSAPublicKeyLike;
+use jwt_simple::prelude::JWTClaims;
+use jwt_simple::prelude::NoCustomClaims;
+use jwt_simple::prelude::RS256PublicKey;
+use jwt_simple::prelude::RSAPublicKeyLike;
+use jwt_simple::prelude::VerificationOptions;
+use serde::Deserialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+const FINGERPRINT_PREFIX: &str = "SHA256:";
+
+/// Max allowed lifetime (`exp - iat`) of a key-pair JWT.
+pub const KEY_PAIR_TOKEN_MAX_LIFETIME_SECS: u64 = 3600;
+
+/// A public key registered on a user by `ALTER USER ... SET RSA_PUBLIC_KEY[_2] = '...'`.
+///
+/// Both RSA and Ed25519 keys are accepted, as PEM or as the base64 encoded DER body of a PEM.
+#[derive(Debug, Clone)]
+pub struct UserPublicKey {
+    key: UserPublicKeyKind,
+    fingerprint: String,
+}
+
+#[derive(Debug, Clone)]
+enum UserPublicKeyKind {
+    RSA256(RS256PublicKey),
+    Ed25519(Ed25519PublicKey),
+}
+
+impl UserPublicKey {
+    pub fn parse(key: &str) -> Result<Self> {
+        let body = key
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.starts_with("-----"))
+            .collect::<String>();
+        let body = body.split_whitespace().collect::<String>();
+        let der = general_purpose::STANDARD.decode(body).map_err(|e| {
+            ErrorCode::InvalidArgument(format!("public key is not valid base64: {}", e))
+        })?;
+
+        let key = if let Ok(pk) = RS256PublicKey::from_der(&der) {
+            UserPublicKeyKind::RSA256(pk)
+        } else if let Ok(pk) = Ed25519PublicKey::from_der(&der) {
+            UserPublicKeyKind::Ed25519(pk)
+        } else {
+            return Err(ErrorCode::InvalidArgument(
+                "public key must be a RSA or Ed25519 public key",
+            ));
+        };
+        let fingerprint = format!(
+            "{}{}",
+            FINGERPRINT_PREFIX,
+            general_purpose::STANDARD.encode(Sha256::digest(&der))
+        );
+        Ok(UserPublicKey { key, fingerprint })
+    }
+
+    /// `SHA256:<base64 of the sha256 digest of the DER encoded key>`.
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+
+    fn verify_token(
+        &self,
+        token: &str,
+        options: VerificationOptions,
+    ) -> Result<JWTClaims<NoCustomClaims>> {
+        let claims = match &self.key {
+            UserPublicKeyKind::RSA256(pk) => {
+                pk.verify_token::<NoCustomClaims>(token, Some(options))
+            }
+            UserPublicKeyKind::Ed25519(pk) => {
+                pk.verify_token::<NoCustomClaims>(token, Some(options))
+            }
+        };
+        claims.map_err(|e| ErrorCode::AuthenticateFailure(e.to_string()))
+    }
+}
+
+#[derive(Deserialize)]
+struct UnverifiedClaims {
+    iss: Option<String>,
+    sub: Option<String>,
+}
+
+/// Authenticates a user by a short-lived JWT signed with the private key of a key pair,
+/// whose public key was registered on the user.
+///
+/// The token must be issued by `<user>.SHA256:<fingerprint>` for subject `<user>`,
+/// and carry both `iat` and `exp`, at most [`KEY_PAIR_TOKEN_MAX_LIFETIME_SECS`] apart.
+pub struct KeyPairAuthenticator;
+
+impl KeyPairAuthenticator {
+    /// Returns the user name if `token` looks like a key-pair JWT, without verifying it.
+    pub fn parse_user(token: &str) -> Option<String> {
+        let claims = Self::unverified_claims(token)?;
+        let (user, _) = Self::split_issuer(&claims.iss?)?;
+        (claims.sub.as_deref() == Some(user.as_str())).then_some(user)
+    }
+
+    /// Verifies `token` with the one of `public_keys` whose fingerprint is in the issuer.
+    pub fn verify(token: &str, user_name: &str, public_keys: &[&String]) -> Result<()> {
+        if public_keys.is_empty() {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "user '{}' has no public key registered",
+                user_name
+            )));
+        }
+        let issuer = Self::unverified_claims(token)
+            .and_then(|c| c.iss)
+            .ok_or_else(|| ErrorCode::AuthenticateFailure("missing field `iss` in jwt"))?;
+        let fingerprint = match Self::split_issuer(&issuer) {
+            Some((user, fingerprint)) if user == user_name => fingerprint,
+            _ => {
+                return Err(ErrorCode::AuthenticateFailure(format!(
+                    "jwt issuer '{}' does not match user '{}'",
+                    issuer, user_name
+                )));
+            }
+        };
+
+        let mut key = None;
+        for public_key in public_keys {
+            let public_key = UserPublicKey::parse(public_key)?;
+            if public_key.fingerprint() == fingerprint {
+                key = Some(public_key);
+                break;
+            }
+        }
+        let key = key.ok_or_else(|| {
+            ErrorCode::AuthenticateFailure(format!(
+                "public key with fingerprint '{}' is not registered on user '{}'",
+                fingerprint, user_name
+            ))
+        })?;
+
+        let options = VerificationOptions {
+            allowed_issuers: Some([issuer].into_iter().collect()),
+            required_subject: Some(user_name.to_string()),
+            max_validity: Some(Duration::from_secs(KEY_PAIR_TOKEN_MAX_LIFETIME_SECS)),
+            ..Default::default()
+        };
+        let claims = key.verify_token(token, options)?;
+        match (claims.issued_at, claims.expires_at) {
+            (Some(iat), Some(exp))
+                if exp >= iat
+                    && exp - iat <= Duration::from_secs(KEY_PAIR_TOKEN_MAX_LIFETIME_SECS) =>
+            {
+                Ok(())
+            }
+            _ => Err(ErrorCode::AuthenticateFailure(format!(
+                "key-pair jwt must have `iat` and `exp` at most {} seconds apart",
+                KEY_PAIR_TOKEN_MAX_LIFETIME_SECS
+            ))),
+        }
+    }
+
+    fn unverified_claims(token: &str) -> Option<UnverifiedClaims> {
+        let payload = token.split('.').nth(1)?;
+        let payload = general_purpose::URL_SAFE_NO_PAD.decode(payload).ok()?;
+        serde_json::from_slice(&payload).ok()
+    }
+
+    /// Splits issuer `<user>.SHA256:<digest>` into `(<user>, SHA256:<digest>)`.
+    fn split_issuer(issuer: &str) -> Option<(String, String)> {
+        let (user, digest) = issuer.rsplit_once(&format!(".{}", FINGERPRINT_PREFIX))?;
+        Some((
+            user.to_string(),
+            format!("{}{}", FINGERPRINT_PREFIX, digest),
+        ))
+    }
+}
@@ -14,6 +14,7 @@
 
 mod authenticator;
 mod jwk;
+mod key_pair;
 
 pub use authenticator::CustomClaims;
 pub use authenticator::EnsureUser;
@@ -21,3 +22,6 @@ pub use authenticator::JwtAuthenticator;
 pub use authenticator::PubKey;
 pub use jwk::JwkKey;
 pub use jwk::JwkKeyStore;
+pub use key_pair::KeyPairAuthenticator;
+pub use key_pair::UserPublicKey;
+pub use key_pair::KEY_PAIR_TOKEN_MAX_LIFETIME_SECS;
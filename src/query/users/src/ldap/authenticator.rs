@@ -0,0 +1,166 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_config::LdapConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use ldap3::dn_escape;
+use ldap3::ldap_escape;
+
+use super::connection::Ldap3Connector;
+use super::connection::LdapConnector;
+use super::connection::LdapEntry;
+
+const USER_PLACEHOLDER: &str = "{user}";
+
+/// Verifies the password of users created with `IDENTIFIED WITH ldap`.
+///
+/// Two modes are supported:
+/// - simple bind: bind with the DN built from `bind_dn_template`.
+/// - search+bind: look up the user entry with `search_filter` under `search_base`
+///   (bound as `search_bind_dn`, or anonymously), then bind with the DN found.
+pub struct LdapAuthenticator {
+    config: LdapConfig,
+    connector: Arc<dyn LdapConnector>,
+}
+
+impl LdapAuthenticator {
+    pub fn create(config: &LdapConfig) -> Option<Self> {
+        if !config.is_enabled() {
+            return None;
+        }
+        let connector =
+            Ldap3Connector::new(config.url.clone(), Duration::from_secs(config.timeout_secs));
+        Some(Self::create_with_connector(
+            config.clone(),
+            Arc::new(connector),
+        ))
+    }
+
+    pub fn create_with_connector(config: LdapConfig, connector: Arc<dyn LdapConnector>) -> Self {
+        LdapAuthenticator { config, connector }
+    }
+
+    /// Binds as `user` with `password`, returns the roles mapped from the LDAP groups of the user.
+    #[async_backtrace::framed]
+    pub async fn authenticate(&self, user: &str, password: &str) -> Result<Vec<String>> {
+        // An empty password is an unauthenticated bind, which most servers accept.
+        if password.is_empty() {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "LDAP authentication of user '{}' requires a password",
+                user
+            )));
+        }
+
+        let group_attrs = self.group_attrs();
+        let mut conn = self.connector.connect().await?;
+
+        let (dn, mut entry) = if !self.config.bind_dn_template.is_empty() {
+            let dn = self
+                .config
+                .bind_dn_template
+                .replace(USER_PLACEHOLDER, &dn_escape(user));
+            (dn, None)
+        } else {
+            let search_bind_dn = &self.config.search_bind_dn;
+            if !search_bind_dn.is_empty()
+                && !conn
+                    .simple_bind(search_bind_dn, &self.config.search_bind_password)
+                    .await?
+            {
+                return Err(ErrorCode::AuthenticateFailure(format!(
+                    "LDAP search bind as '{}' was rejected",
+                    search_bind_dn
+                )));
+            }
+
+            let filter = self
+                .config
+                .search_filter
+                .replace(USER_PLACEHOLDER, &ldap_escape(user));
+            let mut entries = conn
+                .search(&self.config.search_base, &filter, &group_attrs)
+                .await?;
+            if entries.len() != 1 {
+                return Err(ErrorCode::AuthenticateFailure(format!(
+                    "LDAP search with filter '{}' found {} entries for user '{}', expect exactly one",
+                    filter,
+                    entries.len(),
+                    user
+                )));
+            }
+            let entry = entries.remove(0);
+            (entry.dn.clone(), Some(entry))
+        };
+
+        if !conn.simple_bind(&dn, password).await? {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "LDAP bind as '{}' was rejected for user '{}'",
+                dn, user
+            )));
+        }
+
+        if self.config.group_role_mapping.is_empty() {
+            return Ok(vec![]);
+        }
+        if entry.is_none() {
+            entry = conn.read_entry(&dn, &group_attrs).await?;
+        }
+        Ok(entry.map(|e| self.map_roles(&e)).unwrap_or_default())
+    }
+
+    fn group_attrs(&self) -> Vec<&str> {
+        if self.config.group_role_mapping.is_empty() {
+            vec![]
+        } else {
+            vec![self.config.group_attribute.as_str()]
+        }
+    }
+
+    fn map_roles(&self, entry: &LdapEntry) -> Vec<String> {
+        let groups = entry
+            .attrs
+            .iter()
+            .filter(|(k, _)| k.eq_ignore_ascii_case(&self.config.group_attribute))
+            .flat_map(|(_, v)| v.iter());
+
+        let mut roles = BTreeSet::new();
+        for group in groups {
+            let role =
+                self.config.group_role_mapping.get(group).or_else(|| {
+                    group_cn(group).and_then(|cn| self.config.group_role_mapping.get(cn))
+                });
+            if let Some(role) = role {
+                roles.insert(role.clone());
+            }
+        }
+        roles.into_iter().collect()
+    }
+}
+
+/// Returns the value of the leading `cn=` RDN of a group DN, e.g. `analysts` for
+/// `cn=analysts,ou=groups,dc=example,dc=com`.
+fn group_cn(group: &str) -> Option<&str> {
+    let rdn = group.split(',').next()?.trim();
+    let (attr, value) = rdn.split_once('=')?;
+    if attr.trim().eq_ignore_ascii_case("cn") {
+        Some(value.trim())
+    } else {
+        None
+    }
+}
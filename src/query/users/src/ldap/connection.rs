@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use databend_common_base::runtime::spawn;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use ldap3::LdapConnAsync;
+use ldap3::LdapConnSettings;
+use ldap3::LdapError;
+use ldap3::Scope;
+use ldap3::SearchEntry;
+use ldap3::SearchResult;
+use log::warn;
+
+/// Result code returned by the server when a bind is rejected.
+const LDAP_INVALID_CREDENTIALS: u32 = 49;
+/// Result code returned by the server when the search base does not exist.
+const LDAP_NO_SUCH_OBJECT: u32 = 32;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LdapEntry {
+    pub dn: String,
+    pub attrs: HashMap<String, Vec<String>>,
+}
+
+/// Opens connections to the directory. Abstracted so that the authentication
+/// flow can be exercised without a real LDAP server.
+#[async_trait::async_trait]
+pub trait LdapConnector: Send + Sync {
+    async fn connect(&self) -> Result<Box<dyn LdapConnection>>;
+}
+
+#[async_trait::async_trait]
+pub trait LdapConnection: Send {
+    /// Returns `Ok(false)` if the server rejects the credentials.
+    async fn simple_bind(&mut self, dn: &str, password: &str) -> Result<bool>;
+
+    /// Searches the subtree under `base`.
+    async fn search(&mut self, base: &str, filter: &str, attrs: &[&str]) -> Result<Vec<LdapEntry>>;
+
+    /// Reads the entry `dn` itself, `None` if it does not exist or is not readable.
+    async fn read_entry(&mut self, dn: &str, attrs: &[&str]) -> Result<Option<LdapEntry>>;
+}
+
+pub(crate) struct Ldap3Connector {
+    url: String,
+    timeout: Duration,
+}
+
+impl Ldap3Connector {
+    pub(crate) fn new(url: String, timeout: Duration) -> Self {
+        Self { url, timeout }
+    }
+}
+
+#[async_trait::async_trait]
+impl LdapConnector for Ldap3Connector {
+    #[async_backtrace::framed]
+    async fn connect(&self) -> Result<Box<dyn LdapConnection>> {
+        let settings = LdapConnSettings::new().set_conn_timeout(self.timeout);
+        let (conn, ldap) = LdapConnAsync::with_settings(settings, &self.url)
+            .await
+            .map_err(|e| ldap_error(&self.url, e))?;
+        spawn(async move {
+            if let Err(e) = conn.drive().await {
+                warn!("LDAP connection error: {}", e);
+            }
+        });
+        Ok(Box::new(Ldap3Connection {
+            ldap,
+            url: self.url.clone(),
+            timeout: self.timeout,
+        }))
+    }
+}
+
+struct Ldap3Connection {
+    ldap: ldap3::Ldap,
+    url: String,
+    timeout: Duration,
+}
+
+impl Ldap3Connection {
+    async fn search_scope(
+        &mut self,
+        base: &str,
+        scope: Scope,
+        filter: &str,
+        attrs: &[&str],
+    ) -> Result<Vec<LdapEntry>> {
+        let SearchResult(entries, res) = self
+            .ldap
+            .with_timeout(self.timeout)
+            .search(base, scope, filter, attrs.to_vec())
+            .await
+            .map_err(|e| ldap_error(&self.url, e))?;
+        if res.rc == LDAP_NO_SUCH_OBJECT {
+            return Ok(vec![]);
+        }
+        res.success().map_err(|e| ldap_error(&self.url, e))?;
+        Ok(entries
+            .into_iter()
+            .map(|e| {
+                let entry = SearchEntry::construct(e);
+                LdapEntry {
+                    dn: entry.dn,
+                    attrs: entry.attrs,
+                }
+            })
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl LdapConnection for Ldap3Connection {
+    #[async_backtrace::framed]
+    async fn simple_bind(&mut self, dn: &str, password: &str) -> Result<bool> {
+        let res = self
+            .ldap
+            .with_timeout(self.timeout)
+            .simple_bind(dn, password)
+            .await
+            .map_err(|e| ldap_error(&self.url, e))?;
+        match res.rc {
+            0 => Ok(true),
+            LDAP_INVALID_CREDENTIALS => Ok(false),
+            _ => Err(ldap_error(&self.url, LdapError::LdapResult { result: res })),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn search(&mut self, base: &str, filter: &str, attrs: &[&str]) -> Result<Vec<LdapEntry>> {
+        self.search_scope(base, Scope::Subtree, filter, attrs).await
+    }
+
+    #[async_backtrace::framed]
+    async fn read_entry(&mut self, dn: &str, attrs: &[&str]) -> Result<Option<LdapEntry>> {
+        let entries = self
+            .search_scope(dn, Scope::Base, "(objectClass=*)", attrs)
+            .await?;
+        Ok(entries.into_iter().next())
+    }
+}
+
+fn ldap_error(url: &str, e: LdapError) -> ErrorCode {
+    ErrorCode::AuthenticateFailure(format!("LDAP request to {} failed: {}", url, e))
+}
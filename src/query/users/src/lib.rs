@@ -18,8 +18,10 @@
 extern crate core;
 
 mod jwt;
+mod ldap;
 mod network_policy;
 mod password_policy;
+mod query_hint;
 mod role_mgr;
 mod user;
 mod user_api;
@@ -37,6 +39,7 @@ pub mod role_util;
 mod user_procedure;
 
 pub use jwt::*;
+pub use ldap::*;
 pub use password_policy::*;
 pub use role_cache_mgr::RoleCacheManager;
 pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
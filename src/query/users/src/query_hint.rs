@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::crud::CrudError;
+use databend_common_meta_app::principal::QueryHint;
+use databend_common_meta_app::principal::QueryHintIdent;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::DirName;
+use databend_common_meta_kvapi::kvapi::Key;
+use databend_common_meta_types::protobuf::watch_request::FilterType;
+use databend_common_meta_types::protobuf::WatchRequest;
+use databend_common_meta_types::MatchSeq;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new query hint.
+    #[async_backtrace::framed]
+    pub async fn add_query_hint(
+        &self,
+        tenant: &Tenant,
+        query_hint: QueryHint,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let client = self.query_hint_api(tenant);
+        client.add(query_hint, create_option).await?;
+        Ok(())
+    }
+
+    // Drop a query hint by query fingerprint.
+    #[async_backtrace::framed]
+    pub async fn drop_query_hint(
+        &self,
+        tenant: &Tenant,
+        fingerprint: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let client = self.query_hint_api(tenant);
+        match client.remove(fingerprint, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => match e {
+                CrudError::ApiError(meta_err) => {
+                    Err(ErrorCode::from(meta_err).add_message_back(" (while drop query hint)"))
+                }
+                CrudError::Business(unknown) => {
+                    if if_exists {
+                        Ok(())
+                    } else {
+                        Err(ErrorCode::from(unknown).add_message_back(" (while drop query hint)"))
+                    }
+                }
+            },
+        }
+    }
+
+    // Get the query hint pinned to a query fingerprint, if any.
+    #[async_backtrace::framed]
+    pub async fn get_query_hint(
+        &self,
+        tenant: &Tenant,
+        fingerprint: &str,
+    ) -> Result<Option<QueryHint>> {
+        let client = self.query_hint_api(tenant);
+        match client.get(fingerprint, MatchSeq::GE(0)).await {
+            Ok(seq_query_hint) => Ok(Some(seq_query_hint.data)),
+            Err(CrudError::Business(_)) => Ok(None),
+            Err(CrudError::ApiError(meta_err)) => {
+                Err(ErrorCode::from(meta_err).add_message_back(" (while get query hint)"))
+            }
+        }
+    }
+
+    // Get all query hints by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_query_hints(&self, tenant: &Tenant) -> Result<Vec<QueryHint>> {
+        let client = self.query_hint_api(tenant);
+        let query_hints = client.list().await.map_err(|e| {
+            let e = ErrorCode::from(e);
+            e.add_message_back(" (while get query hints).")
+        })?;
+        Ok(query_hints)
+    }
+
+    // Watch the query hints of a tenant, the stream yields once for every change.
+    //
+    // Returns None with an embedded meta, which serves only this node.
+    #[async_backtrace::framed]
+    pub async fn watch_query_hints(
+        &self,
+        tenant: &Tenant,
+    ) -> Result<Option<BoxStream<'static, Result<()>>>> {
+        let meta = self.get_meta_store_client();
+        if meta.is_local() {
+            return Ok(None);
+        }
+
+        let dir = DirName::new(QueryHintIdent::new(tenant, "dummy"));
+        let prefix = format!("{}/", dir.to_string_key());
+        let key_end = kvapi::prefix_to_range(&prefix).map(|(_, end)| end).ok();
+        let req = WatchRequest {
+            key: prefix,
+            key_end,
+            filter_type: FilterType::All.into(),
+            initial_flush: false,
+            since_seq: None,
+        };
+
+        let strm = meta.watch(req).await?;
+        let strm = strm.map(|resp| -> Result<()> {
+            resp?;
+            Ok(())
+        });
+        Ok(Some(strm.boxed()))
+    }
+}
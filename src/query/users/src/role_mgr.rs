@@ -207,6 +207,28 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while set role privileges)"))
     }
 
+    /// Grant the privileges on several objects to a role in a single update of the role,
+    /// so that either all or none of them are granted.
+    #[async_backtrace::framed]
+    pub async fn grant_privileges_on_objects_to_role(
+        &self,
+        tenant: &Tenant,
+        role: &String,
+        objects: Vec<GrantObject>,
+        privileges: UserPrivilegeSet,
+    ) -> Result<Option<u64>> {
+        let client = self.role_api(tenant);
+        client
+            .update_role_with(role, MatchSeq::GE(1), |ri: &mut RoleInfo| {
+                ri.update_role_time();
+                for object in &objects {
+                    ri.grants.grant_privileges(object, privileges);
+                }
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while set role privileges)"))
+    }
+
     #[async_backtrace::framed]
     pub async fn revoke_privileges_from_role(
         &self,
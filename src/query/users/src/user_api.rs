@@ -22,10 +22,12 @@ use databend_common_grpc::RpcClientConf;
 use databend_common_management::udf::UdfMgr;
 use databend_common_management::ClientSessionMgr;
 use databend_common_management::ConnectionMgr;
+use databend_common_management::DynamicTableRefreshMgr;
 use databend_common_management::FileFormatMgr;
 use databend_common_management::NetworkPolicyMgr;
 use databend_common_management::PasswordPolicyMgr;
 use databend_common_management::ProcedureMgr;
+use databend_common_management::QueryHintMgr;
 use databend_common_management::QuotaApi;
 use databend_common_management::QuotaMgr;
 use databend_common_management::RoleApi;
@@ -34,6 +36,7 @@ use databend_common_management::SettingApi;
 use databend_common_management::SettingMgr;
 use databend_common_management::StageApi;
 use databend_common_management::StageMgr;
+use databend_common_management::TtlTableMgr;
 use databend_common_management::UserApi;
 use databend_common_management::UserMgr;
 use databend_common_meta_app::principal::AuthInfo;
@@ -167,10 +170,22 @@ impl UserApiProvider {
         PasswordPolicyMgr::create(self.client.clone(), tenant)
     }
 
+    pub fn query_hint_api(&self, tenant: &Tenant) -> QueryHintMgr {
+        QueryHintMgr::create(self.client.clone(), tenant)
+    }
+
     pub fn client_session_api(&self, tenant: &Tenant) -> ClientSessionMgr {
         ClientSessionMgr::create(self.client.clone(), tenant)
     }
 
+    pub fn dynamic_table_refresh_api(&self, tenant: &Tenant) -> Result<DynamicTableRefreshMgr> {
+        DynamicTableRefreshMgr::create(self.meta.clone(), tenant.tenant_name())
+    }
+
+    pub fn ttl_table_api(&self, tenant: &Tenant) -> Result<TtlTableMgr> {
+        TtlTableMgr::create(self.meta.clone(), tenant.tenant_name())
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
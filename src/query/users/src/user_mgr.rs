@@ -191,6 +191,34 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while set user privileges)"))
     }
 
+    /// Grant the privileges on several objects to a user in a single update of the user,
+    /// so that either all or none of them are granted.
+    #[async_backtrace::framed]
+    pub async fn grant_privileges_on_objects_to_user(
+        &self,
+        tenant: &Tenant,
+        user: UserIdentity,
+        objects: Vec<GrantObject>,
+        privileges: UserPrivilegeSet,
+    ) -> Result<Option<u64>> {
+        if self.get_configured_user(&user.username).is_some() {
+            return Err(ErrorCode::UserAlreadyExists(format!(
+                "Cannot grant privileges to built-in user `{}`",
+                user.username
+            )));
+        }
+        let client = self.user_api(tenant);
+        client
+            .update_user_with(user, MatchSeq::GE(1), |ui: &mut UserInfo| {
+                ui.update_user_time();
+                for object in &objects {
+                    ui.grants.grant_privileges(object, privileges);
+                }
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while set user privileges)"))
+    }
+
     #[async_backtrace::framed]
     pub async fn revoke_privileges_from_user(
         &self,
@@ -55,14 +55,14 @@ impl UserApiProvider {
         Ok(procedure)
     }
 
-    // Drop a Procedure by name.
+    // Drop a Procedure by name, return the id of the dropped procedure.
     #[async_backtrace::framed]
     pub async fn drop_procedure(
         &self,
         tenant: &Tenant,
         req: DropProcedureReq,
         if_exists: bool,
-    ) -> Result<()> {
+    ) -> Result<Option<u64>> {
         let dropped = self
             .procedure_api(tenant)
             .drop_procedure(&req.name_ident)
@@ -73,6 +73,6 @@ impl UserApiProvider {
                 req.name_ident
             )));
         }
-        Ok(())
+        Ok(dropped.map(|(seq_id, _)| *seq_id.data))
     }
 }
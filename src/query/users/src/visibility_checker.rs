@@ -34,6 +34,7 @@ pub struct GrantObjectVisibilityChecker {
     granted_global_db_table: bool,
     granted_global_stage: bool,
     granted_global_read_stage: bool,
+    granted_global_usage: bool,
     granted_databases: HashSet<(String, String)>,
     granted_databases_id: HashSet<(String, u64)>,
     granted_tables: HashSet<(String, String, String)>,
@@ -44,6 +45,10 @@ pub struct GrantObjectVisibilityChecker {
     granted_udfs: HashSet<String>,
     granted_write_stages: HashSet<String>,
     granted_read_stages: HashSet<String>,
+    granted_sequences: HashSet<String>,
+    granted_procedures: HashSet<u64>,
+    granted_connections: HashSet<String>,
+    granted_file_formats: HashSet<String>,
 }
 
 impl GrantObjectVisibilityChecker {
@@ -56,6 +61,7 @@ impl GrantObjectVisibilityChecker {
         let mut granted_global_db_table = false;
         let mut granted_global_stage = false;
         let mut granted_global_read_stage = false;
+        let mut granted_global_usage = false;
         let mut granted_databases = HashSet::new();
         let mut granted_tables = HashSet::new();
         let mut granted_udfs = HashSet::new();
@@ -65,6 +71,10 @@ impl GrantObjectVisibilityChecker {
         let mut granted_databases_id = HashSet::new();
         let mut extra_databases_id = HashSet::new();
         let mut granted_tables_id = HashSet::new();
+        let mut granted_sequences = HashSet::new();
+        let mut granted_procedures = HashSet::new();
+        let mut granted_connections = HashSet::new();
+        let mut granted_file_formats = HashSet::new();
 
         let mut grant_sets: Vec<&UserGrantSet> = vec![&user.grants];
         for role in available_roles {
@@ -110,6 +120,16 @@ impl GrantObjectVisibilityChecker {
                             |privilege| privilege == UserPrivilegeType::Read,
                         );
 
+                        // sequence, procedure, connection and file format share the USAGE privilege
+                        check_privilege(
+                            &mut granted_global_usage,
+                            ent.privileges().iter(),
+                            |privilege| {
+                                UserPrivilegeSet::available_privileges_on_sequence(false)
+                                    .has_privilege(privilege)
+                            },
+                        );
+
                         check_privilege(
                             &mut granted_global_db_table,
                             ent.privileges().iter(),
@@ -134,7 +154,8 @@ impl GrantObjectVisibilityChecker {
                         // if table is visible, the table's database is also treated as visible
                         extra_databases.insert((catalog.to_string(), db.to_string()));
                     }
-                    GrantObject::TableById(catalog, db, table) => {
+                    GrantObject::TableById(catalog, db, table)
+                    | GrantObject::Column(catalog, db, table, _) => {
                         granted_tables_id.insert((catalog.to_string(), *db, *table));
                         // if table is visible, the table's database is also treated as visible
                         extra_databases_id.insert((catalog.to_string(), *db));
@@ -156,6 +177,18 @@ impl GrantObjectVisibilityChecker {
                             granted_read_stages.insert(stage.to_string());
                         }
                     }
+                    GrantObject::Sequence(sequence) => {
+                        granted_sequences.insert(sequence.to_string());
+                    }
+                    GrantObject::Procedure(procedure_id) => {
+                        granted_procedures.insert(*procedure_id);
+                    }
+                    GrantObject::Connection(connection) => {
+                        granted_connections.insert(connection.to_string());
+                    }
+                    GrantObject::FileFormat(file_format) => {
+                        granted_file_formats.insert(file_format.to_string());
+                    }
                 }
             }
         }
@@ -184,6 +217,18 @@ impl GrantObjectVisibilityChecker {
                 OwnershipObject::UDF { name } => {
                     granted_udfs.insert(name.to_string());
                 }
+                OwnershipObject::Sequence { name } => {
+                    granted_sequences.insert(name.to_string());
+                }
+                OwnershipObject::Procedure { procedure_id } => {
+                    granted_procedures.insert(*procedure_id);
+                }
+                OwnershipObject::Connection { name } => {
+                    granted_connections.insert(name.to_string());
+                }
+                OwnershipObject::FileFormat { name } => {
+                    granted_file_formats.insert(name.to_string());
+                }
             }
         }
 
@@ -192,6 +237,7 @@ impl GrantObjectVisibilityChecker {
             granted_global_db_table,
             granted_global_stage,
             granted_global_read_stage,
+            granted_global_usage,
             granted_databases,
             granted_databases_id,
             granted_tables,
@@ -201,6 +247,10 @@ impl GrantObjectVisibilityChecker {
             granted_udfs,
             granted_write_stages,
             granted_read_stages,
+            granted_sequences,
+            granted_procedures,
+            granted_connections,
+            granted_file_formats,
             sys_databases: HashSet::from([
                 ("default".to_string(), "information_schema".to_string()),
                 ("default".to_string(), "system".to_string()),
@@ -241,6 +291,22 @@ impl GrantObjectVisibilityChecker {
         false
     }
 
+    pub fn check_sequence_visibility(&self, sequence: &str) -> bool {
+        self.granted_global_usage || self.granted_sequences.contains(sequence)
+    }
+
+    pub fn check_procedure_visibility(&self, procedure_id: u64) -> bool {
+        self.granted_global_usage || self.granted_procedures.contains(&procedure_id)
+    }
+
+    pub fn check_connection_visibility(&self, connection: &str) -> bool {
+        self.granted_global_usage || self.granted_connections.contains(connection)
+    }
+
+    pub fn check_file_format_visibility(&self, file_format: &str) -> bool {
+        self.granted_global_usage || self.granted_file_formats.contains(file_format)
+    }
+
     pub fn check_database_visibility(&self, catalog: &str, db: &str, db_id: u64) -> bool {
         // skip information_schema privilege check
         if db.to_lowercase() == "information_schema" || db.to_lowercase() == "system" {
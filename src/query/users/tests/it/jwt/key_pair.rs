@@ -0,0 +1,124 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use databend_common_exception::Result;
+use databend_common_users::KeyPairAuthenticator;
+use databend_common_users::UserPublicKey;
+use jwt_simple::prelude::*;
+
+fn rsa_key_pair() -> (RS256KeyPair, String) {
+    let key_pair = RS256KeyPair::generate(2048).unwrap();
+    let pem = key_pair.public_key().to_pem().unwrap();
+    (key_pair, pem)
+}
+
+fn ed25519_key_pair() -> (Ed25519KeyPair, String) {
+    let key_pair = Ed25519KeyPair::generate();
+    // the base64 body of the PEM is also accepted
+    let der = key_pair.public_key().to_der();
+    (key_pair, general_purpose::STANDARD.encode(der))
+}
+
+fn claims(user: &str, public_key: &str, valid_for: Duration) -> JWTClaims<NoCustomClaims> {
+    let fingerprint = UserPublicKey::parse(public_key).unwrap();
+    Claims::create(valid_for)
+        .with_issuer(format!("{}.{}", user, fingerprint.fingerprint()))
+        .with_subject(user)
+}
+
+#[test]
+fn test_parse_public_key() -> Result<()> {
+    let (_, rsa) = rsa_key_pair();
+    let (_, ed25519) = ed25519_key_pair();
+    let rsa_key = UserPublicKey::parse(&rsa)?;
+    assert!(rsa_key.fingerprint().starts_with("SHA256:"));
+    assert_ne!(
+        rsa_key.fingerprint(),
+        UserPublicKey::parse(&ed25519)?.fingerprint()
+    );
+
+    assert!(UserPublicKey::parse("not a key").is_err());
+    assert!(UserPublicKey::parse("MCowBQYDK2VwAyEA").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_key_pair_auth() -> Result<()> {
+    let (rsa_pair, rsa) = rsa_key_pair();
+    let (ed_pair, ed25519) = ed25519_key_pair();
+    let (other_pair, other) = ed25519_key_pair();
+    let keys = vec![&rsa, &ed25519];
+
+    let token = rsa_pair
+        .sign(claims("ci", &rsa, Duration::from_mins(5)))
+        .unwrap();
+    assert_eq!(
+        KeyPairAuthenticator::parse_user(&token),
+        Some("ci".to_string())
+    );
+    KeyPairAuthenticator::verify(&token, "ci", &keys)?;
+
+    // the second key works as well, for rotation
+    let token = ed_pair
+        .sign(claims("ci", &ed25519, Duration::from_mins(5)))
+        .unwrap();
+    KeyPairAuthenticator::verify(&token, "ci", &keys)?;
+
+    // not registered
+    let token = other_pair
+        .sign(claims("ci", &other, Duration::from_mins(5)))
+        .unwrap();
+    assert!(KeyPairAuthenticator::verify(&token, "ci", &keys).is_err());
+
+    // signed by another private key
+    let token = other_pair
+        .sign(claims("ci", &ed25519, Duration::from_mins(5)))
+        .unwrap();
+    assert!(KeyPairAuthenticator::verify(&token, "ci", &keys).is_err());
+
+    // issued for another user
+    let token = ed_pair
+        .sign(claims("admin", &ed25519, Duration::from_mins(5)))
+        .unwrap();
+    assert_eq!(
+        KeyPairAuthenticator::parse_user(&token),
+        Some("admin".to_string())
+    );
+    assert!(KeyPairAuthenticator::verify(&token, "ci", &keys).is_err());
+
+    // long-lived tokens are rejected
+    let token = ed_pair
+        .sign(claims("ci", &ed25519, Duration::from_hours(24)))
+        .unwrap();
+    assert!(KeyPairAuthenticator::verify(&token, "ci", &keys).is_err());
+
+    // no key registered
+    let token = ed_pair
+        .sign(claims("ci", &ed25519, Duration::from_mins(5)))
+        .unwrap();
+    assert!(KeyPairAuthenticator::verify(&token, "ci", &[]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parse_user_of_other_jwt() {
+    let key_pair = Ed25519KeyPair::generate();
+    let token = key_pair
+        .sign(Claims::create(Duration::from_mins(5)).with_subject("ci"))
+        .unwrap();
+    assert_eq!(KeyPairAuthenticator::parse_user(&token), None);
+    assert_eq!(KeyPairAuthenticator::parse_user("bad token"), None);
+}
@@ -0,0 +1,333 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_base::base::tokio::net::TcpListener;
+use databend_common_base::base::tokio::net::TcpStream;
+use databend_common_config::LdapConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_users::LdapAuthenticator;
+use databend_common_users::LdapConnection;
+use databend_common_users::LdapConnector;
+use databend_common_users::LdapEntry;
+
+/// An in-memory directory standing in for an LDAP server.
+#[derive(Clone, Default)]
+struct MemoryDirectory {
+    // dn -> (password, entry)
+    entries: HashMap<String, (String, LdapEntry)>,
+}
+
+impl MemoryDirectory {
+    fn add(mut self, dn: &str, password: &str, uid: &str, groups: &[&str]) -> Self {
+        let mut attrs = HashMap::new();
+        attrs.insert("uid".to_string(), vec![uid.to_string()]);
+        attrs.insert(
+            "memberOf".to_string(),
+            groups.iter().map(|g| g.to_string()).collect(),
+        );
+        let entry = LdapEntry {
+            dn: dn.to_string(),
+            attrs,
+        };
+        self.entries
+            .insert(dn.to_string(), (password.to_string(), entry));
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl LdapConnector for MemoryDirectory {
+    async fn connect(&self) -> Result<Box<dyn LdapConnection>> {
+        Ok(Box::new(self.clone()))
+    }
+}
+
+#[async_trait::async_trait]
+impl LdapConnection for MemoryDirectory {
+    async fn simple_bind(&mut self, dn: &str, password: &str) -> Result<bool> {
+        Ok(self.entries.get(dn).is_some_and(|(pwd, _)| pwd == password))
+    }
+
+    // Only supports filters like `(uid=xxx)`.
+    async fn search(
+        &mut self,
+        base: &str,
+        filter: &str,
+        _attrs: &[&str],
+    ) -> Result<Vec<LdapEntry>> {
+        let (attr, value) = filter
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .split_once('=')
+            .ok_or_else(|| ErrorCode::BadArguments(format!("bad filter {}", filter)))?;
+        Ok(self
+            .entries
+            .values()
+            .filter(|(_, e)| e.dn.ends_with(base))
+            .filter(|(_, e)| {
+                e.attrs
+                    .get(attr)
+                    .is_some_and(|v| v.contains(&value.to_string()))
+            })
+            .map(|(_, e)| e.clone())
+            .collect())
+    }
+
+    async fn read_entry(&mut self, dn: &str, _attrs: &[&str]) -> Result<Option<LdapEntry>> {
+        Ok(self.entries.get(dn).map(|(_, e)| e.clone()))
+    }
+}
+
+fn directory() -> MemoryDirectory {
+    MemoryDirectory::default()
+        .add("cn=admin,dc=example,dc=com", "admin_pwd", "admin", &[])
+        .add(
+            "uid=alice,ou=people,dc=example,dc=com",
+            "alice_pwd",
+            "alice",
+            &[
+                "cn=analysts,ou=groups,dc=example,dc=com",
+                "cn=dev,ou=groups,dc=example,dc=com",
+            ],
+        )
+        .add("uid=bob,ou=people,dc=example,dc=com", "bob_pwd", "bob", &[])
+}
+
+fn role_mapping() -> HashMap<String, String> {
+    let mut mapping = HashMap::new();
+    mapping.insert("analysts".to_string(), "analyst_role".to_string());
+    mapping.insert(
+        "cn=dev,ou=groups,dc=example,dc=com".to_string(),
+        "dev_role".to_string(),
+    );
+    mapping
+}
+
+fn simple_bind_config() -> LdapConfig {
+    LdapConfig {
+        url: "ldap://127.0.0.1:389".to_string(),
+        bind_dn_template: "uid={user},ou=people,dc=example,dc=com".to_string(),
+        ..Default::default()
+    }
+}
+
+fn search_bind_config() -> LdapConfig {
+    LdapConfig {
+        url: "ldap://127.0.0.1:389".to_string(),
+        search_bind_dn: "cn=admin,dc=example,dc=com".to_string(),
+        search_bind_password: "admin_pwd".to_string(),
+        search_base: "ou=people,dc=example,dc=com".to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_ldap_disabled() {
+    assert!(LdapAuthenticator::create(&LdapConfig::default()).is_none());
+    assert!(LdapAuthenticator::create(&simple_bind_config()).is_some());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ldap_simple_bind() -> Result<()> {
+    let auth =
+        LdapAuthenticator::create_with_connector(simple_bind_config(), Arc::new(directory()));
+
+    let roles = auth.authenticate("alice", "alice_pwd").await?;
+    assert!(roles.is_empty());
+
+    let res = auth.authenticate("alice", "bad_pwd").await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::AuthenticateFailure("").code()
+    );
+
+    let res = auth.authenticate("carol", "carol_pwd").await;
+    assert!(res.is_err());
+
+    // empty password must never reach the server as an unauthenticated bind
+    let res = auth.authenticate("alice", "").await;
+    assert!(res.is_err());
+
+    // the user name is escaped when building the DN
+    let res = auth
+        .authenticate("alice,ou=people,dc=example,dc=com", "alice_pwd")
+        .await;
+    assert!(res.is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ldap_search_bind() -> Result<()> {
+    let auth =
+        LdapAuthenticator::create_with_connector(search_bind_config(), Arc::new(directory()));
+
+    let roles = auth.authenticate("bob", "bob_pwd").await?;
+    assert!(roles.is_empty());
+
+    let res = auth.authenticate("bob", "alice_pwd").await;
+    assert!(res.is_err());
+
+    let res = auth.authenticate("carol", "carol_pwd").await;
+    assert!(res.is_err());
+
+    // wrong service account password
+    let config = LdapConfig {
+        search_bind_password: "bad_pwd".to_string(),
+        ..search_bind_config()
+    };
+    let auth = LdapAuthenticator::create_with_connector(config, Arc::new(directory()));
+    let res = auth.authenticate("bob", "bob_pwd").await;
+    assert!(res.is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ldap_group_role_mapping() -> Result<()> {
+    for config in [simple_bind_config(), search_bind_config()] {
+        let config = LdapConfig {
+            group_role_mapping: role_mapping(),
+            ..config
+        };
+        let auth = LdapAuthenticator::create_with_connector(config, Arc::new(directory()));
+
+        let roles = auth.authenticate("alice", "alice_pwd").await?;
+        assert_eq!(roles, vec![
+            "analyst_role".to_string(),
+            "dev_role".to_string()
+        ]);
+
+        let roles = auth.authenticate("bob", "bob_pwd").await?;
+        assert!(roles.is_empty());
+    }
+    Ok(())
+}
+
+/// Reads one BER element from the stream, returns its tag and value.
+async fn read_ber(stream: &mut TcpStream) -> std::io::Result<(u8, Vec<u8>)> {
+    let tag = stream.read_u8().await?;
+    let mut len = stream.read_u8().await? as usize;
+    if len & 0x80 != 0 {
+        let mut bytes = vec![0u8; len & 0x7f];
+        stream.read_exact(&mut bytes).await?;
+        len = bytes.iter().fold(0, |acc, b| (acc << 8) | *b as usize);
+    }
+    let mut value = vec![0u8; len];
+    stream.read_exact(&mut value).await?;
+    Ok((tag, value))
+}
+
+/// Splits the first BER element off `buf`, only short lengths are expected in requests here.
+fn split_ber(buf: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (tag, len) = (*buf.first()?, *buf.get(1)? as usize);
+    if len & 0x80 != 0 || buf.len() < 2 + len {
+        return None;
+    }
+    Some((tag, &buf[2..2 + len], &buf[2 + len..]))
+}
+
+/// A minimal LDAP server that only answers simple binds, so that the `ldap3` client
+/// used by `LdapAuthenticator::create` is exercised over a real connection.
+async fn start_bind_server(dn: &'static str, password: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ldap://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                // LDAPMessage ::= SEQUENCE { messageID, protocolOp }
+                while let Ok((0x30, message)) = read_ber(&mut stream).await {
+                    let Some((0x02, id, op)) = split_ber(&message) else {
+                        return;
+                    };
+                    // BindRequest ::= [APPLICATION 0] SEQUENCE { version, name, simple [0] }
+                    let Some((0x60, bind, _)) = split_ber(op) else {
+                        return;
+                    };
+                    let Some((_, name, pwd)) = split_ber(bind)
+                        .and_then(|(_, _, rest)| split_ber(rest))
+                        .and_then(|(tag, name, rest)| {
+                            split_ber(rest).map(|(_, pwd, _)| (tag, name, pwd))
+                        })
+                    else {
+                        return;
+                    };
+                    // success(0) or invalidCredentials(49)
+                    let rc = if name == dn.as_bytes() && pwd == password.as_bytes() {
+                        0
+                    } else {
+                        49
+                    };
+                    // BindResponse ::= [APPLICATION 1] { resultCode, matchedDN, diagnosticMessage }
+                    let bind_response = [0x0a, 0x01, rc, 0x04, 0x00, 0x04, 0x00];
+                    let mut body = vec![0x02, id.len() as u8];
+                    body.extend_from_slice(id);
+                    body.extend_from_slice(&[0x61, bind_response.len() as u8]);
+                    body.extend_from_slice(&bind_response);
+                    let mut response = vec![0x30, body.len() as u8];
+                    response.extend_from_slice(&body);
+                    if stream.write_all(&response).await.is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+    });
+    url
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ldap3_simple_bind() -> Result<()> {
+    let url = start_bind_server("uid=alice,ou=people,dc=example,dc=com", "alice_pwd").await;
+    let config = LdapConfig {
+        url,
+        timeout_secs: 5,
+        ..simple_bind_config()
+    };
+    let auth = LdapAuthenticator::create(&config).unwrap();
+
+    let roles = auth.authenticate("alice", "alice_pwd").await?;
+    assert!(roles.is_empty());
+
+    let res = auth.authenticate("alice", "bad_pwd").await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::AuthenticateFailure("").code()
+    );
+
+    let res = auth.authenticate("bob", "alice_pwd").await;
+    assert!(res.is_err());
+
+    // nothing listens on the port
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+    let config = LdapConfig {
+        url: format!("ldap://{}", addr),
+        timeout_secs: 1,
+        ..simple_bind_config()
+    };
+    let auth = LdapAuthenticator::create(&config).unwrap();
+    let res = auth.authenticate("alice", "alice_pwd").await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::AuthenticateFailure("").code()
+    );
+    Ok(())
+}
@@ -88,6 +88,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             source,
             // TODO
             overwrite: false,
+            partition: None,
         }
     }
 
@@ -491,6 +492,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 columns,
                 source,
                 overwrite: false,
+                partition: None,
             })
         } else {
             None
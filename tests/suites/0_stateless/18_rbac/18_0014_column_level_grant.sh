@@ -0,0 +1,51 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+export TEST_USER_PASSWORD="password"
+export USER_A_CONNECT="bendsql --user=a --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+
+echo "drop user if exists a" | $BENDSQL_CLIENT_CONNECT
+echo "create user a identified by '$TEST_USER_PASSWORD'" | $BENDSQL_CLIENT_CONNECT
+echo "create or replace database col_db" | $BENDSQL_CLIENT_CONNECT
+echo "create table col_db.t(c1 int, c2 int, c3 string)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into col_db.t values(1, 2, 'a')" | $BENDSQL_CLIENT_CONNECT
+echo "grant select (c1, c2) on col_db.t to a" | $BENDSQL_CLIENT_CONNECT
+echo "grant insert (c1) on col_db.t to a" | $BENDSQL_CLIENT_CONNECT
+echo "grant update (c2) on col_db.t to a" | $BENDSQL_CLIENT_CONNECT
+echo "grant select (c4) on col_db.t to a" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -c "does not exist"
+
+echo "=== show grants for a ==="
+echo "show grants for a" | $BENDSQL_CLIENT_CONNECT | awk -F ' ' '{$3=""; print $0}'
+
+echo "=== select ==="
+echo "select c1, c2 from col_db.t" | $USER_A_CONNECT
+echo "select * from col_db.t" | $USER_A_CONNECT
+echo "select c1 from col_db.t where c2 > 0 order by c2" | $USER_A_CONNECT
+echo "select c3 from col_db.t" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "select c1 from col_db.t where c3 = 'a'" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "set enable_expand_star_to_permitted_columns = 0; select * from col_db.t" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+
+echo "=== insert and update ==="
+echo "insert into col_db.t(c1) values(3)" | $USER_A_CONNECT
+echo "insert into col_db.t(c1, c2) values(4, 4)" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "update col_db.t set c2 = 10 where c1 = 1" | $USER_A_CONNECT
+echo "update col_db.t set c3 = 'b'" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "update col_db.t set c2 = 1 where c3 = 'a'" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "update col_db.t set c2 = length(c3)" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "select * from col_db.t order by c1" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== revoke ==="
+echo "revoke select (c2) on col_db.t from a" | $BENDSQL_CLIENT_CONNECT
+echo "select * from col_db.t order by c1" | $USER_A_CONNECT
+echo "select c2 from col_db.t" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+
+echo "=== rename column ==="
+echo "alter table col_db.t rename column c1 to c0" | $BENDSQL_CLIENT_CONNECT
+echo "select c0 from col_db.t order by c0" | $USER_A_CONNECT
+
+echo "drop database col_db" | $BENDSQL_CLIENT_CONNECT
+echo "drop user a" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,74 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+export TEST_USER_PASSWORD="password"
+export USER_A_CONNECT="bendsql --user=a --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+
+echo "drop user if exists a" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists role1" | $BENDSQL_CLIENT_CONNECT
+echo "drop sequence if exists seq1" | $BENDSQL_CLIENT_CONNECT
+echo "drop connection if exists conn1" | $BENDSQL_CLIENT_CONNECT
+echo "drop file format if exists fmt1" | $BENDSQL_CLIENT_CONNECT
+echo "set global enable_experimental_procedure=1" | $BENDSQL_CLIENT_CONNECT
+echo "drop procedure if exists p1()" | $BENDSQL_CLIENT_CONNECT
+
+echo "create user a identified by '$TEST_USER_PASSWORD'" | $BENDSQL_CLIENT_CONNECT
+echo "create role role1" | $BENDSQL_CLIENT_CONNECT
+echo "grant role role1 to a" | $BENDSQL_CLIENT_CONNECT
+echo "alter user a with default_role='role1'" | $BENDSQL_CLIENT_CONNECT
+echo "create sequence seq1" | $BENDSQL_CLIENT_CONNECT
+echo "create connection conn1 storage_type='s3' access_key_id='ak' secret_access_key='sk'" | $BENDSQL_CLIENT_CONNECT
+echo "create file format fmt1 type=csv" | $BENDSQL_CLIENT_CONNECT
+echo "create procedure p1() returns int not null language sql as \$\$ begin return 1; end; \$\$" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== without usage ==="
+echo "select nextval(seq1)" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "desc connection conn1" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "drop file format fmt1" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "call procedure p1()" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+
+echo "=== grant usage ==="
+echo "grant usage on sequence seq1 to role role1" | $BENDSQL_CLIENT_CONNECT
+echo "grant usage on connection conn1 to role role1" | $BENDSQL_CLIENT_CONNECT
+echo "grant usage on file format fmt1 to role role1" | $BENDSQL_CLIENT_CONNECT
+echo "grant usage on procedure p1() to role role1" | $BENDSQL_CLIENT_CONNECT
+echo "grant usage on sequence seq2 to role role1" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -ci "unknown"
+echo "show grants for role role1" | $BENDSQL_CLIENT_CONNECT | awk -F ' ' '{$3=""; print $0}'
+echo "show grants on sequence seq1" | $BENDSQL_CLIENT_CONNECT | awk -F ' ' '{$3=""; print $0}'
+echo "show grants on procedure p1()" | $BENDSQL_CLIENT_CONNECT | awk -F ' ' '{$3=""; print $0}'
+
+echo "=== with usage ==="
+echo "select nextval(seq1)" | $USER_A_CONNECT
+echo "desc connection conn1" | $USER_A_CONNECT | wc -l | sed 's/ //g'
+echo "call procedure p1()" | $USER_A_CONNECT
+
+echo "=== usage does not allow drop or alter ==="
+echo "alter sequence seq1 restart with 100" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "drop sequence seq1" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "drop connection conn1" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "drop file format fmt1" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "drop procedure p1()" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "select nextval(seq1)" | $USER_A_CONNECT
+
+echo "=== revoke usage ==="
+echo "revoke usage on sequence seq1 from role role1" | $BENDSQL_CLIENT_CONNECT
+echo "revoke usage on procedure p1() from role role1" | $BENDSQL_CLIENT_CONNECT
+echo "select nextval(seq1)" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+echo "call procedure p1()" | $USER_A_CONNECT 2>&1 | grep -c "Permission denied"
+
+echo "=== ownership ==="
+echo "grant ownership on file format fmt1 to role role1" | $BENDSQL_CLIENT_CONNECT
+echo "show grants for role role1" | $BENDSQL_CLIENT_CONNECT | grep "OWNERSHIP" | awk -F ' ' '{$3=""; print $0}'
+echo "drop file format fmt1" | $USER_A_CONNECT
+echo "show grants for role role1" | $BENDSQL_CLIENT_CONNECT | grep -c "OWNERSHIP ON FILE FORMAT"
+
+echo "drop sequence seq1" | $BENDSQL_CLIENT_CONNECT
+echo "drop connection conn1" | $BENDSQL_CLIENT_CONNECT
+echo "drop procedure p1()" | $BENDSQL_CLIENT_CONNECT
+echo "drop role role1" | $BENDSQL_CLIENT_CONNECT
+echo "drop user a" | $BENDSQL_CLIENT_CONNECT
+echo "unset global enable_experimental_procedure" | $BENDSQL_CLIENT_CONNECT